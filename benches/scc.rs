@@ -0,0 +1,52 @@
+#![feature(test)]
+
+extern crate petgraph;
+extern crate test;
+
+use test::Bencher;
+
+use petgraph::algo::{kosaraju_scc, tarjan_scc};
+use petgraph::graph::DiGraph;
+
+#[cfg(feature = "rayon")]
+use petgraph::algo::par_scc;
+
+/// A pseudo-random graph with a large component, and many small ones.
+fn random_digraph(node_count: usize, edge_count: usize) -> DiGraph<(), ()> {
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let mut random = |bound: usize| {
+        seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (seed >> 33) as usize % bound
+    };
+    let mut g = DiGraph::with_capacity(node_count, edge_count);
+    for _ in 0..node_count {
+        g.add_node(());
+    }
+    let edges = (0..edge_count).map(|_| (random(node_count) as u32, random(node_count) as u32));
+    g.extend_with_edges(edges);
+    g
+}
+
+const NODE_COUNT: usize = 50_000;
+const EDGE_COUNT: usize = 75_000;
+
+#[bench]
+fn tarjan_scc_bench(bench: &mut Bencher) {
+    let g = random_digraph(NODE_COUNT, EDGE_COUNT);
+    bench.iter(|| tarjan_scc(&g));
+}
+
+#[bench]
+fn kosaraju_scc_bench(bench: &mut Bencher) {
+    let g = random_digraph(NODE_COUNT, EDGE_COUNT);
+    bench.iter(|| kosaraju_scc(&g));
+}
+
+#[bench]
+#[cfg(feature = "rayon")]
+fn par_scc_bench(bench: &mut Bencher) {
+    let g = random_digraph(NODE_COUNT, EDGE_COUNT);
+    bench.iter(|| par_scc(&g));
+}
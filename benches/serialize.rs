@@ -6,7 +6,8 @@ mod serialize {
     extern crate test;
 
     use petgraph::prelude::*;
-    use rand::Rng;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
     use test::Bencher;
 
     const NUM_NODES: usize = 1_000_000;
@@ -19,10 +20,10 @@ mod serialize {
             .map(|i| g.add_node(i as u32))
             .collect();
 
-        let mut rng = rand::thread_rng();
+        let mut rng = StdRng::seed_from_u64(0);
         g.extend_with_edges((0..NUM_EDGES).map(|_| {
-            let first = rng.gen_range(0, NUM_NODES + NUM_HOLES);
-            let second = rng.gen_range(0, NUM_NODES + NUM_HOLES - 1);
+            let first = rng.gen_range(0..NUM_NODES + NUM_HOLES);
+            let second = rng.gen_range(0..NUM_NODES + NUM_HOLES - 1);
             let second = second + (second >= first) as usize;
             let weight: u32 = rng.gen();
             (indices[first], indices[second], weight)
@@ -30,7 +31,7 @@ mod serialize {
 
         // Remove nodes to make the structure a bit more interesting
         while g.node_count() > NUM_NODES {
-            let idx = rng.gen_range(0, indices.len());
+            let idx = rng.gen_range(0..indices.len());
             g.remove_node(indices[idx]);
         }
 
@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+
+use petgraph::graph::{DiGraph, UnGraph};
+use petgraph::io::json::{Cytoscape, JsonGraphError, NodeLink};
+use petgraph::stable_graph::StableGraph;
+use petgraph::visit::EdgeRef;
+use serde_json::{json, Value};
+
+type Attributes = BTreeMap<String, Value>;
+
+#[test]
+fn node_link_from_networkx() {
+    // The output of `networkx.node_link_data(nx.MultiGraph([("a", "b"), ("a", "b")]))`.
+    let data = json!({
+        "directed": false,
+        "multigraph": true,
+        "graph": {"name": "pair"},
+        "nodes": [{"id": "a"}, {"id": "b", "color": "red"}],
+        "links": [
+            {"source": "a", "target": "b", "key": 0},
+            {"source": "a", "target": "b", "key": 1, "weight": 2.5}
+        ]
+    });
+    let node_link: NodeLink<String, Attributes, Attributes> = serde_json::from_value(data).unwrap();
+    assert!(node_link.multigraph);
+    let graph: UnGraph<Attributes, Attributes> = node_link.into_graph().unwrap();
+    assert_eq!(graph.node_count(), 2);
+    assert_eq!(graph.edge_count(), 2);
+    assert_eq!(graph.raw_nodes()[1].weight["color"], "red");
+    assert_eq!(graph.raw_edges()[1].weight["weight"], 2.5);
+}
+
+#[test]
+fn node_link_round_trip() {
+    let mut graph = StableGraph::<(), ()>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge(a, c, ());
+    graph.add_edge(a, c, ());
+    graph.remove_node(b);
+
+    let value = serde_json::to_value(NodeLink::from_graph(&graph)).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "directed": true,
+            "multigraph": true,
+            "graph": {},
+            "nodes": [{"id": 0}, {"id": 2}],
+            "links": [{"source": 0, "target": 2}, {"source": 0, "target": 2}]
+        })
+    );
+    let node_link: NodeLink<usize, (), ()> = serde_json::from_value(value).unwrap();
+    let parsed: DiGraph<(), ()> = node_link.into_graph().unwrap();
+    let edges: Vec<_> = parsed
+        .edge_references()
+        .map(|edge| (edge.source().index(), edge.target().index()))
+        .collect();
+    assert_eq!(edges, [(0, 1), (0, 1)]);
+}
+
+#[test]
+fn cytoscape_round_trip() {
+    let mut graph = DiGraph::<Attributes, Attributes>::new();
+    let a = graph.add_node([("label".to_string(), json!("A"))].into());
+    let b = graph.add_node(Attributes::new());
+    graph.add_edge(b, a, [("weight".to_string(), json!(3))].into());
+
+    let value = serde_json::to_value(Cytoscape::from_graph(&graph)).unwrap();
+    assert_eq!(
+        value["elements"]["edges"][0],
+        json!({"data": {"id": "e0", "source": "1", "target": "0", "weight": 3}})
+    );
+    let cytoscape: Cytoscape<Attributes, Attributes> = serde_json::from_value(value).unwrap();
+    let parsed: DiGraph<Attributes, Attributes> = cytoscape.into_graph().unwrap();
+    assert_eq!(parsed[a], graph[a]);
+    assert_eq!(
+        parsed.edge_weights().collect::<Vec<_>>(),
+        graph.edge_weights().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn errors() {
+    let cytoscape: Cytoscape<(), ()> = serde_json::from_value(json!({"elements": {
+        "nodes": [{"data": {"id": "a"}}],
+        "edges": [{"data": {"source": "a", "target": "b"}}]
+    }}))
+    .unwrap();
+    assert_eq!(
+        cytoscape.into_graph::<DiGraph<(), ()>>().unwrap_err(),
+        JsonGraphError::UnknownNode("\"b\"".into())
+    );
+    let node_link: NodeLink<u32, (), ()> = serde_json::from_value(json!({
+        "directed": true, "multigraph": false,
+        "nodes": [{"id": 1}, {"id": 1}], "links": []
+    }))
+    .unwrap();
+    assert_eq!(
+        node_link.into_graph::<DiGraph<(), ()>>().unwrap_err(),
+        JsonGraphError::DuplicateNode("1".into())
+    );
+}
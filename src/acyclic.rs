@@ -37,7 +37,7 @@ pub use order_map::TopologicalPosition;
 /// is maintained, i.e. no cycles can be created. This uses a topological order
 /// that is dynamically updated when edges are added. In the worst case, the
 /// runtime may be linear in the number of vertices, but it has been shown to
-/// be fast in practice, particularly on sparse graphs (Pierce and Kelly, 2004).
+/// be fast in practice, particularly on sparse graphs (Pearce and Kelly, 2004).
 ///
 /// To be modifiable (and hence to be useful), the graphs of generic type `G`
 /// should implement the [`Build`] trait. Good candidates for `G` are thus
@@ -46,7 +46,7 @@ pub use order_map::TopologicalPosition;
 /// ## Algorithm
 /// This implements the PK algorithm for dynamic topological sort described in
 /// "A Dynamic Topological Sort Algorithm for Directed Acyclic Graphs" by
-/// D. Pierce and P. Kelly, JEA, 2004. It maintains a topological order of the
+/// D. Pearce and P. Kelly, JEA, 2004. It maintains a topological order of the
 /// nodes that can be efficiently updated when edges are added. Achieves a good
 /// balance between simplicity and performance in practice, see the paper for
 /// discussions of the running time.
@@ -64,6 +64,7 @@ pub use order_map::TopologicalPosition;
 /// that would create a cycle. The [`Build::add_edge`] on the other hand method
 /// will return `None` if the edge cannot be added (either it already exists on
 /// a graph type that does not support it or would create a cycle).
+#[doc(alias = "IncrementalTopo")]
 #[derive(Clone, Debug)]
 pub struct Acyclic<G: Visitable> {
     /// The underlying graph, accessible through the `inner` method.
@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use crate::algo::{shortest_path_dag, Measure};
+use crate::visit::{IntoEdges, Visitable};
+
+/// Compute every shortest path from `start` to `goal`.
+///
+/// The [shortest path DAG](fn@crate::algo::shortest_path_dag) from `start`
+/// is computed first. Every path of that DAG from `start` to `goal` has the
+/// minimum cost, and they are enumerated by a depth-first search.
+///
+/// Costs are compared for equality to detect ties, so floating point costs
+/// that are only approximately equal are not considered ties.
+///
+/// The function `edge_cost` should return the cost for a particular edge.
+/// Edge costs must be non-negative. Parallel edges do not produce duplicate
+/// paths, since paths are reported as sequences of nodes.
+///
+/// The number of shortest paths can grow exponentially with the size of the
+/// graph, so `max_paths` can be used to stop the enumeration early.
+///
+/// # Arguments
+/// * `graph`: weighted graph.
+/// * `start`: the start node.
+/// * `goal`: the goal node.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+/// * `max_paths`: optional maximum number of paths to return.
+///
+/// # Returns
+/// * `Some((cost, paths))`: the cost of a shortest path and the list of
+///   shortest paths, each one starting with `start` and ending with `goal`.
+/// * `None`: if `goal` is not reachable from `start`.
+///
+/// # Complexity
+/// * Time complexity: **O((|V|+|E|)log(|V|) + p|V|)**.
+/// * Auxiliary space: **O(|V|+|E| + p|V|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges
+/// and **p** is the number of returned paths.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::all_shortest_paths;
+///
+/// //   b
+/// //  / \
+/// // a   d -- e
+/// //  \ /
+/// //   c
+/// let mut graph = Graph::<(), u32>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// let e = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (a, c, 2), (b, d, 2), (c, d, 1), (d, e, 1)]);
+///
+/// let (cost, mut paths) = all_shortest_paths(&graph, a, e, |e| *e.weight(), None).unwrap();
+/// paths.sort();
+/// assert_eq!(cost, 4);
+/// assert_eq!(paths, vec![vec![a, b, d, e], vec![a, c, d, e]]);
+///
+/// let (_, paths) = all_shortest_paths(&graph, a, e, |e| *e.weight(), Some(1)).unwrap();
+/// assert_eq!(paths.len(), 1);
+/// ```
+pub fn all_shortest_paths<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    edge_cost: F,
+    max_paths: Option<usize>,
+) -> Option<(K, Vec<Vec<G::NodeId>>)>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let dag = shortest_path_dag(graph, start, Some(goal), edge_cost);
+    let cost = dag.distance(goal)?;
+    Some((cost, dag.paths_to(goal, max_paths)))
+}
@@ -0,0 +1,372 @@
+//! Assortativity and degree correlations: whether the edges of a graph
+//! tend to join similar nodes.
+//!
+//! The assortativity coefficient of a graph is the correlation of a value
+//! of the nodes across its edges, from `-1` when the edges join dissimilar
+//! nodes (high degree nodes linked to low degree nodes, in a
+//! *disassortative* graph) to `1` when they join similar nodes. Each edge
+//! of an undirected graph is counted in both directions, and each edge of a
+//! directed graph from its source to its target.
+//!
+//! This module requires the `std` feature.
+
+use alloc::{vec, vec::Vec};
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use super::centrality::ScoreMap;
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+};
+
+/// The sums needed for a Pearson correlation.
+#[derive(Default)]
+struct Correlation {
+    count: f64,
+    x: f64,
+    y: f64,
+    xx: f64,
+    yy: f64,
+    xy: f64,
+}
+
+impl Correlation {
+    fn add(&mut self, x: f64, y: f64) {
+        self.count += 1.0;
+        self.x += x;
+        self.y += y;
+        self.xx += x * x;
+        self.yy += y * y;
+        self.xy += x * y;
+    }
+
+    fn coefficient(&self) -> Option<f64> {
+        if self.count == 0.0 {
+            return None;
+        }
+        let (mean_x, mean_y) = (self.x / self.count, self.y / self.count);
+        let covariance = self.xy / self.count - mean_x * mean_y;
+        let variance_x = self.xx / self.count - mean_x * mean_x;
+        let variance_y = self.yy / self.count - mean_y * mean_y;
+        let scale = (variance_x * variance_y).sqrt();
+        if scale > 0.0 {
+            Some(covariance / scale)
+        } else {
+            None
+        }
+    }
+}
+
+/// Call `pair` with the ends of every edge, in both directions for an
+/// undirected graph.
+fn for_each_pair<G, F>(graph: G, mut pair: F)
+where
+    G: IntoEdgeReferences + GraphProp,
+    F: FnMut(G::NodeId, G::NodeId),
+{
+    for edge in graph.edge_references() {
+        pair(edge.source(), edge.target());
+        if !graph.is_directed() {
+            pair(edge.target(), edge.source());
+        }
+    }
+}
+
+/// The out-degree and in-degree of each node index, which are both the
+/// degree for an undirected graph. A loop adds two to the degree of an
+/// undirected graph.
+fn degrees<G>(graph: G) -> (Vec<usize>, Vec<usize>)
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let mut out_degree = vec![0; graph.node_bound()];
+    let mut in_degree = vec![0; graph.node_bound()];
+    for_each_pair(graph, |a, b| {
+        out_degree[graph.to_index(a)] += 1;
+        in_degree[graph.to_index(b)] += 1;
+    });
+    (out_degree, in_degree)
+}
+
+/// \[Generic\] Compute the [degree assortativity][1] of a graph: the
+/// correlation of the degrees of the ends of its edges.
+///
+/// For a directed graph, the out-degree of the source of each edge is
+/// correlated with the in-degree of its target. Parallel edges are counted
+/// separately.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * `Some(r)`: the Pearson correlation coefficient, between `-1` and `1`.
+/// * `None`: if the graph has no edges, or if the degrees of the ends of
+///   the edges do not vary, as in a regular graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Assortativity
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::assortativity::degree_assortativity;
+/// use petgraph::graph::UnGraph;
+///
+/// // In a star, the center is only linked to leaves.
+/// let star = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+/// assert!((degree_assortativity(&star).unwrap() + 1.0).abs() < 1e-12);
+///
+/// // Two disjoint edges and a disjoint triangle.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (2, 3), (4, 5), (5, 6), (6, 4)]);
+/// assert!((degree_assortativity(&graph).unwrap() - 1.0).abs() < 1e-12);
+/// ```
+pub fn degree_assortativity<G>(graph: G) -> Option<f64>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let (out_degree, in_degree) = degrees(graph);
+    let mut correlation = Correlation::default();
+    for_each_pair(graph, |a, b| {
+        correlation.add(
+            out_degree[graph.to_index(a)] as f64,
+            in_degree[graph.to_index(b)] as f64,
+        )
+    });
+    correlation.coefficient()
+}
+
+/// \[Generic\] Compute the assortativity of a numeric attribute of the
+/// nodes of a graph: the correlation of its values at the ends of the
+/// edges.
+///
+/// Parallel edges are counted separately.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `value`: closure that returns the attribute of a node.
+///
+/// # Returns
+/// * `Some(r)`: the Pearson correlation coefficient, between `-1` and `1`.
+/// * `None`: if the graph has no edges, or if the values at the ends of the
+///   edges do not vary.
+///
+/// # Complexity
+/// * Time complexity: **O(|E|)**, and **O(|E|)** calls to `value`.
+/// * Auxiliary space: **O(1)**.
+///
+/// where **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::assortativity::numeric_assortativity;
+/// use petgraph::graph::UnGraph;
+///
+/// // Ages of people, and who knows whom.
+/// let mut graph = UnGraph::<u32, ()>::new_undirected();
+/// for age in [20, 22, 21, 60, 62, 65] {
+///     graph.add_node(age);
+/// }
+/// graph.extend_with_edges(&[(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (2, 3)]);
+/// let r = numeric_assortativity(&graph, |v| graph[v] as f64).unwrap();
+/// assert!(r > 0.5);
+/// ```
+pub fn numeric_assortativity<G, F>(graph: G, mut value: F) -> Option<f64>
+where
+    G: IntoEdgeReferences + GraphProp,
+    F: FnMut(G::NodeId) -> f64,
+{
+    let mut correlation = Correlation::default();
+    for_each_pair(graph, |a, b| correlation.add(value(a), value(b)));
+    correlation.coefficient()
+}
+
+/// \[Generic\] Compute the assortativity of a categorical attribute of the
+/// nodes of a graph, as defined by [Newman][1]: how much more often the
+/// edges join nodes of the same category than they would at random.
+///
+/// With **e_ij** the fraction of the edges from a node of category `i` to
+/// a node of category `j`, **a_i = Σ_j e_ij** and **b_j = Σ_i e_ij**, the
+/// coefficient is **(Σ e_ii - Σ a_i b_i) / (1 - Σ a_i b_i)**. It is `1`
+/// when all the edges join nodes of the same category. Parallel edges are
+/// counted separately.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `attribute`: closure that returns the category of a node.
+///
+/// # Returns
+/// * `Some(r)`: the assortativity coefficient, at most `1`.
+/// * `None`: if the graph has no edges, or if all the ends of the edges are
+///   in the same category.
+///
+/// # Complexity
+/// * Time complexity: **O(|E|)** expected, and **O(|E|)** calls to
+///   `attribute`.
+/// * Auxiliary space: **O(c²)**.
+///
+/// where **|E|** is the number of edges and **c** is the number of
+/// categories.
+///
+/// [1]: https://doi.org/10.1103/PhysRevE.67.026126
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::assortativity::attribute_assortativity;
+/// use petgraph::graph::UnGraph;
+///
+/// // Two teams, with a single edge between them.
+/// let mut graph = UnGraph::<&str, ()>::new_undirected();
+/// for team in ["red", "red", "red", "blue", "blue", "blue"] {
+///     graph.add_node(team);
+/// }
+/// graph.extend_with_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3)]);
+/// let r = attribute_assortativity(&graph, |v| graph[v]).unwrap();
+/// assert!((r - 5.0 / 7.0).abs() < 1e-12);
+/// ```
+pub fn attribute_assortativity<G, F, K>(graph: G, mut attribute: F) -> Option<f64>
+where
+    G: IntoEdgeReferences + GraphProp,
+    F: FnMut(G::NodeId) -> K,
+    K: Eq + Hash,
+{
+    // The categories are numbered in the order they are seen.
+    let mut categories = HashMap::new();
+    let mut mixing: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut total = 0;
+    for_each_pair(graph, |a, b| {
+        let count = categories.len();
+        let i = *categories.entry(attribute(a)).or_insert(count);
+        let count = categories.len();
+        let j = *categories.entry(attribute(b)).or_insert(count);
+        *mixing.entry((i, j)).or_insert(0) += 1;
+        total += 1;
+    });
+    if total == 0 {
+        return None;
+    }
+    let mut rows = vec![0; categories.len()];
+    let mut columns = vec![0; categories.len()];
+    let mut diagonal = 0;
+    for (&(i, j), &count) in &mixing {
+        rows[i] += count;
+        columns[j] += count;
+        if i == j {
+            diagonal += count;
+        }
+    }
+    let total = total as f64;
+    let expected: f64 = rows
+        .iter()
+        .zip(&columns)
+        .map(|(&a, &b)| a as f64 * b as f64 / (total * total))
+        .sum();
+    if expected >= 1.0 {
+        return None;
+    }
+    Some((diagonal as f64 / total - expected) / (1.0 - expected))
+}
+
+/// \[Generic\] Compute the average degree of the neighbors of each node of
+/// a graph.
+///
+/// For a directed graph, this is the average out-degree of the targets of
+/// the outgoing edges of each node. Parallel edges are counted separately,
+/// and the neighbors of a node without outgoing edges have the average
+/// degree `0`.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * The average neighbor degree of each node.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::assortativity::average_neighbor_degree;
+/// use petgraph::graph::UnGraph;
+///
+/// let star = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let average = average_neighbor_degree(&star);
+/// assert_eq!(average[0.into()], 1.0);
+/// assert_eq!(average[1.into()], 3.0);
+/// ```
+pub fn average_neighbor_degree<G>(graph: G) -> ScoreMap<G, f64>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+    G::NodeId: Eq + Hash,
+{
+    let (out_degree, _) = degrees(graph);
+    let mut sums = vec![0.0; graph.node_bound()];
+    for_each_pair(graph, |a, b| {
+        sums[graph.to_index(a)] += out_degree[graph.to_index(b)] as f64
+    });
+    for (sum, &degree) in sums.iter_mut().zip(&out_degree) {
+        if degree > 0 {
+            *sum /= degree as f64;
+        }
+    }
+    ScoreMap::from_indexed(graph, sums)
+}
+
+/// \[Generic\] Compute the average degree connectivity of a graph: for each
+/// degree `k`, the average degree of the neighbors of the nodes of degree
+/// `k`, a summary of the degree correlations.
+///
+/// It increases with `k` in an assortative graph and decreases in a
+/// disassortative graph. For a directed graph, out-degrees are used, as in
+/// [`average_neighbor_degree`]. Parallel edges are counted separately.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * The pairs of a degree `k > 0` of some node and the average neighbor
+///   degree of the nodes of degree `k`, by increasing degree.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::assortativity::average_degree_connectivity;
+/// use petgraph::graph::UnGraph;
+///
+/// // A path: the ends are linked to nodes of degree 2, and the inner
+/// // nodes to nodes of average degree 1.5 or 2.
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(average_degree_connectivity(&path), [(1, 2.0), (2, 1.5)]);
+/// ```
+pub fn average_degree_connectivity<G>(graph: G) -> Vec<(usize, f64)>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let (out_degree, _) = degrees(graph);
+    let max = out_degree.iter().copied().max().unwrap_or(0);
+    // For each degree, the sum of the degrees of the neighbors of its
+    // nodes, and the number of these neighbors.
+    let mut sums = vec![(0, 0); max + 1];
+    for_each_pair(graph, |a, b| {
+        let entry = &mut sums[out_degree[graph.to_index(a)]];
+        entry.0 += out_degree[graph.to_index(b)];
+        entry.1 += 1;
+    });
+    sums.iter()
+        .enumerate()
+        .filter(|&(_, &(_, count))| count > 0)
+        .map(|(k, &(sum, count))| (k, sum as f64 / count as f64))
+        .collect()
+}
@@ -0,0 +1,563 @@
+//! Centrality measures, and the [`ScoreMap`] type in which they, and other
+//! ranking algorithms, return a score for every node.
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::Hash;
+use core::ops::Index;
+
+use hashbrown::{hash_map, HashMap};
+
+use super::shortest_path_dag::shortest_path_dag;
+use super::{Measure, UnitMeasure};
+use crate::visit::{
+    GraphBase, GraphProp, IntoEdges, IntoNeighbors, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+    Visitable,
+};
+
+/// A score for every node of a graph, as returned by centrality and ranking
+/// algorithms.
+///
+/// The scores are stored in a `Vec` indexed by [`NodeIndexable::to_index`]
+/// when the node indices of the graph are compact, and in a `HashMap`
+/// otherwise. The map keeps a copy of the graph in the first case, so that
+/// for a graph reference `&G` it borrows the graph.
+///
+/// Beyond lookups by node, it can list the nodes by decreasing score
+/// ([`ranked`](Self::ranked)), extract the best ones
+/// ([`top_k`](Self::top_k)) and rescale the scores
+/// ([`normalize`](Self::normalize)).
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::centrality::{degree_centrality, Normalization, ScoreMap};
+/// use petgraph::algo::page_rank;
+/// use petgraph::graph::UnGraph;
+///
+/// // A star with center `0`, and an extra edge between two leaves.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3), (0, 4), (3, 4)]);
+/// let mut scores = degree_centrality(&graph);
+/// assert_eq!(scores[0.into()], 1.0);
+///
+/// let top = scores.top_k(3);
+/// assert_eq!(top[0], (0.into(), &1.0));
+/// assert_eq!(top[1].1, &0.5);
+///
+/// scores.normalize(Normalization::Sum);
+/// assert_eq!(scores.values().sum::<f64>(), 1.0);
+///
+/// // Results computed as a `Vec` indexed by node index can be wrapped too.
+/// let ranks = ScoreMap::from_indexed(&graph, page_rank(&graph, 0.85, 20));
+/// assert_eq!(ranks.ranked()[0].0, 0.into());
+/// ```
+pub struct ScoreMap<G: GraphBase, T> {
+    scores: Scores<G, T>,
+}
+
+enum Scores<G: GraphBase, T> {
+    Dense { graph: G, scores: Vec<T> },
+    Sparse(HashMap<G::NodeId, T>),
+}
+
+/// The ways to rescale the scores of a [`ScoreMap`], with
+/// [`ScoreMap::normalize`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Normalization {
+    /// Divide the scores by their sum, so that they sum to one.
+    Sum,
+    /// Divide the scores by the largest one, so that it becomes one.
+    Max,
+    /// Map the scores linearly so that the smallest becomes zero and the
+    /// largest one.
+    MinMax,
+}
+
+impl<G, T> ScoreMap<G, T>
+where
+    G: NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    /// Create a map from scores indexed by [`NodeIndexable::to_index`], with
+    /// one entry for each index below [`NodeIndexable::node_bound`].
+    ///
+    /// The scores at indices that are not used by a node are dropped.
+    ///
+    /// **Panics** if the length of `scores` is not the node bound of the
+    /// graph.
+    #[track_caller]
+    pub fn from_indexed(graph: G, scores: Vec<T>) -> Self
+    where
+        G: IntoNodeIdentifiers + NodeCount,
+    {
+        assert_eq!(
+            scores.len(),
+            graph.node_bound(),
+            "There must be one score for each node index"
+        );
+        if graph.node_count() == graph.node_bound() {
+            return ScoreMap {
+                scores: Scores::Dense { graph, scores },
+            };
+        }
+        let mut scores: Vec<_> = scores.into_iter().map(Some).collect();
+        let map = graph
+            .node_identifiers()
+            .map(|node| (node, scores[graph.to_index(node)].take().unwrap()))
+            .collect();
+        ScoreMap {
+            scores: Scores::Sparse(map),
+        }
+    }
+
+    /// Create a map from a score for each node.
+    pub fn from_map(scores: HashMap<G::NodeId, T>) -> Self {
+        ScoreMap {
+            scores: Scores::Sparse(scores),
+        }
+    }
+
+    /// Return the number of nodes with a score.
+    pub fn len(&self) -> usize {
+        match &self.scores {
+            Scores::Dense { scores, .. } => scores.len(),
+            Scores::Sparse(scores) => scores.len(),
+        }
+    }
+
+    /// Return `true` if there are no scores.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the score of `node`, or `None` if it has none.
+    pub fn get(&self, node: G::NodeId) -> Option<&T> {
+        match &self.scores {
+            Scores::Dense { graph, scores } => scores.get(graph.to_index(node)),
+            Scores::Sparse(scores) => scores.get(&node),
+        }
+    }
+
+    /// Return a mutable reference to the score of `node`, or `None` if it
+    /// has none.
+    pub fn get_mut(&mut self, node: G::NodeId) -> Option<&mut T> {
+        match &mut self.scores {
+            Scores::Dense { graph, scores } => scores.get_mut(graph.to_index(node)),
+            Scores::Sparse(scores) => scores.get_mut(&node),
+        }
+    }
+
+    /// Iterate over the nodes and their scores, in the order of the node
+    /// indices if they are compact, and in arbitrary order otherwise.
+    pub fn iter(&self) -> Iter<'_, G, T> {
+        Iter {
+            inner: match &self.scores {
+                Scores::Dense { graph, scores } => IterInner::Dense {
+                    graph,
+                    iter: scores.iter().enumerate(),
+                },
+                Scores::Sparse(scores) => IterInner::Sparse(scores.iter()),
+            },
+        }
+    }
+
+    /// Iterate over the scores, in the same order as [`iter`](Self::iter).
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.iter().map(|(_, score)| score)
+    }
+
+    /// Return the nodes and their scores, by decreasing score.
+    ///
+    /// Nodes with equal scores are in the order of [`iter`](Self::iter), and
+    /// incomparable scores, such as `NaN`, come last.
+    pub fn ranked(&self) -> Vec<(G::NodeId, &T)>
+    where
+        T: PartialOrd,
+    {
+        let mut ranked: Vec<_> = self.iter().collect();
+        ranked.sort_by(|a, b| by_decreasing_score(a.1, b.1));
+        ranked
+    }
+
+    /// Return the `k` nodes with the highest scores and their scores, by
+    /// decreasing score, or all of them if there are fewer than `k`.
+    ///
+    /// Ties are broken as in [`ranked`](Self::ranked), in **O(|V| + k log k)**
+    /// time.
+    pub fn top_k(&self, k: usize) -> Vec<(G::NodeId, &T)>
+    where
+        T: PartialOrd,
+    {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut ranked: Vec<_> = self.iter().enumerate().collect();
+        let order = |a: &(usize, (G::NodeId, &T)), b: &(usize, (G::NodeId, &T))| {
+            by_decreasing_score(a.1 .1, b.1 .1).then(a.0.cmp(&b.0))
+        };
+        if k < ranked.len() {
+            ranked.select_nth_unstable_by(k - 1, order);
+            ranked.truncate(k);
+        }
+        ranked.sort_unstable_by(order);
+        ranked.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// Rescale the scores as given by `normalization`.
+    ///
+    /// The scores are left unchanged if the divisor is zero, for instance
+    /// for [`Normalization::MinMax`] when all the scores are equal.
+    pub fn normalize(&mut self, normalization: Normalization)
+    where
+        T: UnitMeasure + Copy,
+    {
+        let mut values = self.values().copied();
+        let first = match values.next() {
+            Some(first) => first,
+            None => return,
+        };
+        let (mut min, mut max, mut sum) = (first, first, first);
+        for value in values {
+            if value < min {
+                min = value;
+            }
+            if value > max {
+                max = value;
+            }
+            sum = sum + value;
+        }
+        let (offset, divisor) = match normalization {
+            Normalization::Sum => (T::zero(), sum),
+            Normalization::Max => (T::zero(), max),
+            Normalization::MinMax => (min, max - min),
+        };
+        if divisor == T::zero() {
+            return;
+        }
+        let rescale = |score: &mut T| *score = (*score - offset) / divisor;
+        match &mut self.scores {
+            Scores::Dense { scores, .. } => scores.iter_mut().for_each(rescale),
+            Scores::Sparse(scores) => scores.values_mut().for_each(rescale),
+        }
+    }
+}
+
+fn by_decreasing_score<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    match b.partial_cmp(a) {
+        Some(order) => order,
+        // Sort the incomparable scores last.
+        None => {
+            let a_is_number = a.partial_cmp(a).is_some();
+            let b_is_number = b.partial_cmp(b).is_some();
+            b_is_number.cmp(&a_is_number)
+        }
+    }
+}
+
+impl<G, T> Index<G::NodeId> for ScoreMap<G, T>
+where
+    G: NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    type Output = T;
+
+    /// **Panics** if `node` has no score.
+    #[track_caller]
+    fn index(&self, node: G::NodeId) -> &T {
+        self.get(node).expect("ScoreMap: node has no score")
+    }
+}
+
+impl<G, T> From<HashMap<G::NodeId, T>> for ScoreMap<G, T>
+where
+    G: NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    fn from(scores: HashMap<G::NodeId, T>) -> Self {
+        ScoreMap::from_map(scores)
+    }
+}
+
+impl<'a, G, T> IntoIterator for &'a ScoreMap<G, T>
+where
+    G: NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    type Item = (G::NodeId, &'a T);
+    type IntoIter = Iter<'a, G, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<G, T> Clone for ScoreMap<G, T>
+where
+    G: GraphBase + Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        ScoreMap {
+            scores: match &self.scores {
+                Scores::Dense { graph, scores } => Scores::Dense {
+                    graph: graph.clone(),
+                    scores: scores.clone(),
+                },
+                Scores::Sparse(scores) => Scores::Sparse(scores.clone()),
+            },
+        }
+    }
+}
+
+impl<G, T> fmt::Debug for ScoreMap<G, T>
+where
+    G: NodeIndexable,
+    G::NodeId: Eq + Hash + fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over the nodes and scores of a [`ScoreMap`].
+pub struct Iter<'a, G: GraphBase, T> {
+    inner: IterInner<'a, G, T>,
+}
+
+enum IterInner<'a, G: GraphBase, T> {
+    Dense {
+        graph: &'a G,
+        iter: core::iter::Enumerate<core::slice::Iter<'a, T>>,
+    },
+    Sparse(hash_map::Iter<'a, G::NodeId, T>),
+}
+
+impl<'a, G, T> Iterator for Iter<'a, G, T>
+where
+    G: NodeIndexable,
+{
+    type Item = (G::NodeId, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IterInner::Dense { graph, iter } => iter
+                .next()
+                .map(|(index, score)| (graph.from_index(index), score)),
+            IterInner::Sparse(iter) => iter.next().map(|(&node, score)| (node, score)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            IterInner::Dense { iter, .. } => iter.size_hint(),
+            IterInner::Sparse(iter) => iter.size_hint(),
+        }
+    }
+}
+
+/// \[Generic\] Degree centrality of every node: its number of neighbors,
+/// divided by the number of other nodes.
+///
+/// For a directed graph, the neighbors are those of
+/// [`IntoNeighbors::neighbors`], usually the outgoing ones; use
+/// [`Reversed`](crate::visit::Reversed) for the in-degree centrality.
+/// Parallel edges count as many times as they appear.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * A [`ScoreMap`] of the centralities.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::centrality::degree_centrality;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2)]);
+/// let scores = degree_centrality(&graph);
+/// assert_eq!(scores[0.into()], 1.0);
+/// assert_eq!(scores[1.into()], 0.5);
+/// ```
+pub fn degree_centrality<G>(graph: G) -> ScoreMap<G, f64>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+    G::NodeId: Eq + Hash,
+{
+    let others = graph.node_count().saturating_sub(1).max(1) as f64;
+    let mut scores = vec![0.0; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        scores[graph.to_index(node)] = graph.neighbors(node).count() as f64 / others;
+    }
+    ScoreMap::from_indexed(graph, scores)
+}
+
+/// \[Generic\] Closeness centrality of every node, from the lengths of the
+/// shortest paths to the other nodes, in number of edges.
+///
+/// A node that reaches `r` other nodes at a total distance `d` has the
+/// centrality `(r / (|V| - 1)) * (r / d)`, the formula of Wasserman and Faust
+/// that accounts for nodes in small components. It is the inverse of the
+/// mean distance to the other nodes in a connected graph, and `0` for a node
+/// that reaches no other node.
+///
+/// For a directed graph, the distances are along the edges of
+/// [`IntoNeighbors::neighbors`], usually the outgoing ones; use
+/// [`Reversed`](crate::visit::Reversed) for the distances to the node.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * A [`ScoreMap`] of the centralities.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|(|V| + |E|))**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::centrality::closeness_centrality;
+/// use petgraph::graph::UnGraph;
+///
+/// // A path of 3 nodes.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// let scores = closeness_centrality(&graph);
+/// assert_eq!(scores[1.into()], 1.0);
+/// assert_eq!(scores[0.into()], 2.0 / 3.0);
+/// ```
+pub fn closeness_centrality<G>(graph: G) -> ScoreMap<G, f64>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+    G::NodeId: Eq + Hash,
+{
+    let others = graph.node_count().saturating_sub(1) as f64;
+    let mut scores = vec![0.0; graph.node_bound()];
+    let mut distances = vec![usize::MAX; graph.node_bound()];
+    let mut reached = Vec::new();
+    let mut queue = VecDeque::new();
+    for start in graph.node_identifiers() {
+        let start_index = graph.to_index(start);
+        distances[start_index] = 0;
+        reached.push(start_index);
+        queue.push_back(start);
+        let mut total = 0;
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[graph.to_index(node)] + 1;
+            for next in graph.neighbors(node) {
+                let next_index = graph.to_index(next);
+                if distances[next_index] == usize::MAX {
+                    distances[next_index] = distance;
+                    total += distance;
+                    reached.push(next_index);
+                    queue.push_back(next);
+                }
+            }
+        }
+        let r = (reached.len() - 1) as f64;
+        if total > 0 {
+            scores[start_index] = (r / others) * (r / total as f64);
+        }
+        for index in reached.drain(..) {
+            distances[index] = usize::MAX;
+        }
+    }
+    ScoreMap::from_indexed(graph, scores)
+}
+
+/// \[Generic\] Betweenness centrality of every node, with the algorithm of
+/// Brandes.
+///
+/// The betweenness of a node `v` is the sum, over the pairs of other nodes
+/// `s` and `t`, of the fraction of the shortest paths from `s` to `t` that
+/// go through `v`. Pairs are ordered for a directed graph and unordered for
+/// an undirected one. Paths are sequences of nodes, so parallel edges do not
+/// count as different paths.
+///
+/// The scores are not normalized; for a directed graph, dividing them by
+/// `(|V| - 1)(|V| - 2)` maps them to `[0, 1]`, and half that for an
+/// undirected graph.
+///
+/// The function `edge_cost` should return the cost for a particular edge,
+/// which must be non-negative. Costs are compared for equality to detect
+/// equally short paths, as in [`shortest_path_dag`].
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * A [`ScoreMap`] of the centralities.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|(|V| + |E|)log(|V|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::centrality::betweenness_centrality;
+/// use petgraph::graph::UnGraph;
+///
+/// // Two triangles sharing node `2`.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+/// let scores = betweenness_centrality(&graph, |_| 1);
+/// // All 4 paths between the triangles go through `2`.
+/// assert_eq!(scores[2.into()], 4.0);
+/// assert_eq!(scores[0.into()], 0.0);
+/// assert_eq!(scores.top_k(1), [(2.into(), &4.0)]);
+/// ```
+pub fn betweenness_centrality<G, F, K>(graph: G, mut edge_cost: F) -> ScoreMap<G, f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount + Visitable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut scores = vec![0.0; graph.node_bound()];
+    let mut paths = vec![0.0; graph.node_bound()];
+    let mut dependencies = vec![0.0; graph.node_bound()];
+    for start in graph.node_identifiers() {
+        let dag = shortest_path_dag(graph, start, None, &mut edge_cost);
+        paths[graph.to_index(start)] = 1.0;
+        for &node in &dag.nodes()[1..] {
+            paths[graph.to_index(node)] = dag
+                .distinct_predecessors(node)
+                .map(|pred| paths[graph.to_index(pred)])
+                .sum();
+        }
+        // Accumulate the dependencies in reverse topological order.
+        for &node in dag.nodes().iter().rev() {
+            let index = graph.to_index(node);
+            let share = (1.0 + dependencies[index]) / paths[index];
+            for pred in dag.distinct_predecessors(node) {
+                let pred_index = graph.to_index(pred);
+                dependencies[pred_index] += paths[pred_index] * share;
+            }
+            if node != start {
+                scores[index] += dependencies[index];
+            }
+        }
+        for &node in dag.nodes() {
+            let index = graph.to_index(node);
+            paths[index] = 0.0;
+            dependencies[index] = 0.0;
+        }
+    }
+    if !graph.is_directed() {
+        for score in &mut scores {
+            *score /= 2.0;
+        }
+    }
+    ScoreMap::from_indexed(graph, scores)
+}
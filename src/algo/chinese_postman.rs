@@ -0,0 +1,881 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Ordering;
+use core::ops::Div;
+
+use crate::algo::{BoundedMeasure, Measure};
+use crate::scored::MinScored;
+use crate::unionfind::UnionFind;
+use crate::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+/// Above this number of odd-degree nodes,
+/// [`christofides`](super::tsp::christofides) pairs them up greedily instead of
+/// using an exact minimum weight perfect matching, which takes **O(2ᵏk)** time.
+pub(crate) const EXACT_MATCHING_LIMIT: usize = 20;
+
+/// Solve the [Chinese postman problem][1] (also known as *route inspection*).
+///
+/// Compute a minimum cost closed walk that traverses every edge of the graph
+/// at least once. The graph is treated as if undirected.
+///
+/// The algorithm finds the nodes of odd degree, computes the shortest paths
+/// between them, and pairs them up with a minimum cost perfect matching. The
+/// edges along the matched shortest paths are then duplicated, which makes
+/// every degree even, and an Eulerian circuit of the augmented multigraph is
+/// returned.
+///
+/// The perfect matching is computed exactly with Edmonds' weighted blossom
+/// algorithm, so the returned walk always has the minimum cost.
+///
+/// The function `edge_cost` should return the cost for a particular edge.
+/// Edge costs must be non-negative, and four times the largest shortest path
+/// distance must fit in `K`.
+///
+/// # Arguments
+/// * `graph`: an input graph, treated as undirected.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * `Some((cost, walk))`: the total cost of the walk and the walk itself, as
+///   a sequence of nodes that starts and ends at the same node. If the graph
+///   has no edges, the walk is empty.
+/// * `None`: if the edges of the graph do not all belong to the same
+///   connected component, in which case no closed walk can cover them.
+///
+/// # Complexity
+/// * Time complexity: **O(k|E|log|V| + k³)**.
+/// * Auxiliary space: **O(|V| + |E| + k²)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges
+/// and **k** is the number of nodes of odd degree.
+///
+/// [1]: https://en.wikipedia.org/wiki/Chinese_postman_problem
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::chinese_postman;
+///
+/// // a --1-- b
+/// // |     / |
+/// // 1   1   3
+/// // | /     |
+/// // c --1-- d
+/// let mut graph = Graph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (a, c, 1), (b, c, 1), (b, d, 3), (c, d, 1)]);
+///
+/// // `b` and `c` have odd degree, so the edge between them is walked twice.
+/// let (cost, walk) = chinese_postman(&graph, |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 8);
+/// assert_eq!(walk.len(), 7);
+/// assert_eq!(walk.first(), walk.last());
+/// ```
+pub fn chinese_postman<G, F, K>(graph: G, mut edge_cost: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: BoundedMeasure + Copy + Div<K, Output = K>,
+{
+    let n = graph.node_bound();
+    let mut edges = Vec::new();
+    let mut total_cost = K::default();
+    for edge in graph.edge_references() {
+        let cost = edge_cost(edge);
+        total_cost = total_cost + cost;
+        edges.push((
+            graph.to_index(edge.source()),
+            graph.to_index(edge.target()),
+            cost,
+        ));
+    }
+    if edges.is_empty() {
+        return Some((total_cost, Vec::new()));
+    }
+
+    let mut degree = vec![0usize; n];
+    let mut components = UnionFind::new(n);
+    for &(a, b, _) in &edges {
+        degree[a] += 1;
+        degree[b] += 1;
+        components.union(a, b);
+    }
+    let root = components.find(edges[0].0);
+    if edges.iter().any(|&(a, _, _)| components.find(a) != root) {
+        return None;
+    }
+
+    let mut adjacency = vec![Vec::new(); n];
+    for (i, &(a, b, _)) in edges.iter().enumerate() {
+        adjacency[a].push(i);
+        adjacency[b].push(i);
+    }
+
+    let odd: Vec<usize> = (0..n).filter(|&v| degree[v] % 2 == 1).collect();
+    let trees: Vec<_> = odd
+        .iter()
+        .map(|&source| shortest_path_tree(&edges, &adjacency, source))
+        .collect();
+    let distances: Vec<Vec<K>> = trees
+        .iter()
+        .map(|(dist, _)| odd.iter().map(|&v| dist[v].unwrap()).collect())
+        .collect();
+
+    // Duplicate the edges of every matched shortest path.
+    let mut walk_edges: Vec<(usize, usize)> = edges.iter().map(|&(a, b, _)| (a, b)).collect();
+    for (i, j) in blossom_perfect_matching(&distances) {
+        total_cost = total_cost + distances[i][j];
+        let predecessor = &trees[i].1;
+        let mut node = odd[j];
+        while node != odd[i] {
+            let (a, b, _) = edges[predecessor[node].unwrap()];
+            walk_edges.push((a, b));
+            node = if a == node { b } else { a };
+        }
+    }
+
+    let circuit = eulerian_circuit(n, &walk_edges, edges[0].0);
+    let walk = circuit.into_iter().map(|i| graph.from_index(i)).collect();
+    Some((total_cost, walk))
+}
+
+/// Dijkstra over the undirected edge list, returning the distance and the
+/// predecessor edge of every node.
+fn shortest_path_tree<K>(
+    edges: &[(usize, usize, K)],
+    adjacency: &[Vec<usize>],
+    source: usize,
+) -> (Vec<Option<K>>, Vec<Option<usize>>)
+where
+    K: Measure + Copy,
+{
+    let mut dist = vec![None; adjacency.len()];
+    let mut predecessor = vec![None; adjacency.len()];
+    let mut done = vec![false; adjacency.len()];
+    let mut visit_next = BinaryHeap::new();
+    dist[source] = Some(K::default());
+    visit_next.push(MinScored(K::default(), source));
+    while let Some(MinScored(score, node)) = visit_next.pop() {
+        if done[node] {
+            continue;
+        }
+        done[node] = true;
+        for &e in &adjacency[node] {
+            let (a, b, cost) = edges[e];
+            let next = if a == node { b } else { a };
+            if done[next] {
+                continue;
+            }
+            let next_score = score + cost;
+            if dist[next].map_or(true, |d| next_score < d) {
+                dist[next] = Some(next_score);
+                predecessor[next] = Some(e);
+                visit_next.push(MinScored(next_score, next));
+            }
+        }
+    }
+    (dist, predecessor)
+}
+
+/// Compute a minimum weight perfect matching of the complete graph given by
+/// the symmetric distance matrix `dist`, which must have an even size.
+///
+/// The matching is exact and computed by dynamic programming over subsets of
+/// nodes, always matching the lowest unmatched node first, so the size must
+/// be at most [`EXACT_MATCHING_LIMIT`].
+pub(crate) fn min_weight_perfect_matching<K>(dist: &[Vec<K>]) -> Vec<(usize, usize)>
+where
+    K: Measure + Copy,
+{
+    let k = dist.len();
+    debug_assert!(k % 2 == 0);
+    assert!(
+        k <= EXACT_MATCHING_LIMIT,
+        "too many nodes for an exact matching"
+    );
+    if k == 0 {
+        return Vec::new();
+    }
+    let full = (1usize << k) - 1;
+    // best[mask] is the cost of matching the nodes in `mask` and the pair
+    // added last.
+    let mut best: Vec<Option<(K, (usize, usize))>> = vec![None; full + 1];
+    best[0] = Some((K::default(), (0, 0)));
+    for mask in 0..full {
+        let cost = match best[mask] {
+            Some((cost, _)) => cost,
+            None => continue,
+        };
+        let i = (!mask).trailing_zeros() as usize;
+        for (j, &d) in dist[i].iter().enumerate().skip(i + 1) {
+            if mask & (1 << j) != 0 {
+                continue;
+            }
+            let next = mask | (1 << i) | (1 << j);
+            let next_cost = cost + d;
+            if best[next].map_or(true, |(c, _)| next_cost < c) {
+                best[next] = Some((next_cost, (i, j)));
+            }
+        }
+    }
+
+    let mut pairs = Vec::with_capacity(k / 2);
+    let mut mask = full;
+    while mask != 0 {
+        let (_, (i, j)) = best[mask].unwrap();
+        pairs.push((i, j));
+        mask &= !((1 << i) | (1 << j));
+    }
+    pairs
+}
+
+/// Pair up `nodes` by repeatedly matching the closest remaining pair, and
+/// return the pairs as positions into `nodes`.
+pub(crate) fn greedy_perfect_matching<K, F>(nodes: &[usize], cost: F) -> Vec<(usize, usize)>
+where
+    K: Measure + Copy,
+    F: Fn(usize, usize) -> K,
+{
+    let mut candidates = Vec::with_capacity(nodes.len() * nodes.len() / 2);
+    for i in 0..nodes.len() {
+        for j in i + 1..nodes.len() {
+            candidates.push((cost(nodes[i], nodes[j]), i, j));
+        }
+    }
+    candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    let mut matched = vec![false; nodes.len()];
+    let mut pairs = Vec::with_capacity(nodes.len() / 2);
+    for (_, i, j) in candidates {
+        if !matched[i] && !matched[j] {
+            matched[i] = true;
+            matched[j] = true;
+            pairs.push((i, j));
+        }
+    }
+    pairs
+}
+
+/// Find an Eulerian circuit of the undirected multigraph with `n` nodes and
+/// the given edges using Hierholzer's algorithm.
+///
+/// Every node must have even degree and all edges must be connected.
+pub(crate) fn eulerian_circuit(n: usize, edges: &[(usize, usize)], start: usize) -> Vec<usize> {
+    let mut adjacency = vec![Vec::new(); n];
+    for (i, &(a, b)) in edges.iter().enumerate() {
+        adjacency[a].push(i);
+        adjacency[b].push(i);
+    }
+    let mut used = vec![false; edges.len()];
+    let mut circuit = Vec::with_capacity(edges.len() + 1);
+    let mut stack = vec![start];
+    while let Some(&node) = stack.last() {
+        match adjacency[node].pop() {
+            Some(e) if !used[e] => {
+                used[e] = true;
+                let (a, b) = edges[e];
+                stack.push(if a == node { b } else { a });
+            }
+            Some(_) => {}
+            None => circuit.extend(stack.pop()),
+        }
+    }
+    circuit.reverse();
+    circuit
+}
+
+/// Compute a minimum weight perfect matching of the complete graph given by
+/// the symmetric distance matrix `dist`, which must have an even size, with
+/// Edmonds' blossom algorithm in **O(k³)** time.
+///
+/// The distances are turned into the positive weights `max + 1 - dist`, whose
+/// maximum weight matching is perfect, since any two unmatched nodes could
+/// still be matched to each other.
+fn blossom_perfect_matching<K>(dist: &[Vec<K>]) -> Vec<(usize, usize)>
+where
+    K: BoundedMeasure + Copy + Div<K, Output = K>,
+{
+    let k = dist.len();
+    debug_assert!(k % 2 == 0);
+    let mut max = K::default();
+    for &d in dist.iter().flatten() {
+        if d > max {
+            max = d;
+        }
+    }
+    let bound = max + K::from_f64(1.0);
+    let mut edges = Vec::with_capacity(k * k.saturating_sub(1) / 2);
+    for (i, row) in dist.iter().enumerate() {
+        for (j, &d) in row.iter().enumerate().skip(i + 1) {
+            edges.push((i, j, bound - d));
+        }
+    }
+    let mate = MaxWeightMatching::new(k, edges).run();
+
+    let mut pairs: Vec<(usize, usize)> = (0..k)
+        .filter(|&i| mate[i] != NONE && i < mate[i])
+        .map(|i| (i, mate[i]))
+        .collect();
+    // Only rounding errors of floating point weights can leave nodes
+    // unmatched; pair them up in order so that the walk stays closed.
+    let unmatched: Vec<usize> = (0..k).filter(|&i| mate[i] == NONE).collect();
+    debug_assert!(unmatched.is_empty());
+    pairs.extend(unmatched.chunks(2).map(|pair| (pair[0], pair[1])));
+    pairs
+}
+
+/// Marker for a missing vertex, edge endpoint or blossom.
+const NONE: usize = usize::MAX;
+
+/// Free vertex or top-level blossom.
+const FREE: u8 = 0;
+/// Outer vertex or top-level blossom, at an even distance from a root.
+const OUTER: u8 = 1;
+/// Inner vertex or top-level blossom, at an odd distance from a root.
+const INNER: u8 = 2;
+/// Outer blossom visited while looking for a common base.
+const BREADCRUMB: u8 = 4;
+
+/// Maximum weight matching of a general graph, following the primal-dual
+/// blossom algorithm as described by Galil and implemented by Van Rantwijk.
+///
+/// Vertices are numbered `0..n` and blossoms `n..2n`. Edge `e` has the
+/// endpoints `2e` and `2e + 1`, so that `p ^ 1` is the other end of endpoint
+/// `p`. Dual variables are stored doubled, so that they stay integral with
+/// integer weights.
+struct MaxWeightMatching<K> {
+    n: usize,
+    edges: Vec<(usize, usize, K)>,
+    /// Vertex of every edge endpoint.
+    endpoint: Vec<usize>,
+    /// Remote endpoints of the edges incident to every vertex.
+    neighbors: Vec<Vec<usize>>,
+    /// Remote endpoint of the matched edge of every vertex.
+    mate: Vec<usize>,
+    label: Vec<u8>,
+    /// Endpoint through which a vertex or blossom got its label.
+    label_end: Vec<usize>,
+    /// Top-level blossom containing every vertex.
+    in_blossom: Vec<usize>,
+    blossom_parent: Vec<usize>,
+    /// Sub-blossoms of every blossom, in cycle order starting at the base.
+    blossom_children: Vec<Vec<usize>>,
+    blossom_base: Vec<usize>,
+    /// Endpoints connecting consecutive sub-blossoms.
+    blossom_endpoints: Vec<Vec<usize>>,
+    /// Least-slack edge to an outer vertex or blossom.
+    best_edge: Vec<usize>,
+    /// Least-slack edges from an outer blossom to other outer blossoms.
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    unused_blossoms: Vec<usize>,
+    dual: Vec<K>,
+    allowed: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+impl<K> MaxWeightMatching<K>
+where
+    K: BoundedMeasure + Copy + Div<K, Output = K>,
+{
+    fn new(n: usize, edges: Vec<(usize, usize, K)>) -> Self {
+        let mut endpoint = Vec::with_capacity(2 * edges.len());
+        let mut neighbors = vec![Vec::new(); n];
+        let mut max_weight = K::default();
+        for (e, &(i, j, w)) in edges.iter().enumerate() {
+            endpoint.push(i);
+            endpoint.push(j);
+            neighbors[i].push(2 * e + 1);
+            neighbors[j].push(2 * e);
+            if w > max_weight {
+                max_weight = w;
+            }
+        }
+        let mut dual = vec![max_weight; n];
+        dual.resize(2 * n, K::default());
+        MaxWeightMatching {
+            n,
+            allowed: vec![false; edges.len()],
+            edges,
+            endpoint,
+            neighbors,
+            mate: vec![NONE; n],
+            label: vec![FREE; 2 * n],
+            label_end: vec![NONE; 2 * n],
+            in_blossom: (0..n).collect(),
+            blossom_parent: vec![NONE; 2 * n],
+            blossom_children: vec![Vec::new(); 2 * n],
+            blossom_base: (0..n).chain(core::iter::repeat(NONE).take(n)).collect(),
+            blossom_endpoints: vec![Vec::new(); 2 * n],
+            best_edge: vec![NONE; 2 * n],
+            blossom_best_edges: vec![None; 2 * n],
+            unused_blossoms: (n..2 * n).collect(),
+            dual,
+            queue: Vec::new(),
+        }
+    }
+
+    /// Return the mate of every vertex, or [`NONE`].
+    fn run(mut self) -> Vec<usize> {
+        let n = self.n;
+        let zero = K::default();
+        for _ in 0..n {
+            self.label.iter_mut().for_each(|l| *l = FREE);
+            self.best_edge.iter_mut().for_each(|e| *e = NONE);
+            self.blossom_best_edges[n..]
+                .iter_mut()
+                .for_each(|b| *b = None);
+            self.allowed.iter_mut().for_each(|a| *a = false);
+            self.queue.clear();
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.in_blossom[v]] == FREE {
+                    self.assign_label(v, OUTER, NONE);
+                }
+            }
+
+            let augmented = loop {
+                if self.grow() {
+                    break true;
+                }
+                match self.dual_step() {
+                    Some(Delta::Vertex) => break false,
+                    Some(Delta::Edge(e)) => {
+                        self.allowed[e] = true;
+                        let (i, j, _) = self.edges[e];
+                        let i = if self.label[self.in_blossom[i]] == FREE {
+                            j
+                        } else {
+                            i
+                        };
+                        self.queue.push(i);
+                    }
+                    Some(Delta::Blossom(b)) => self.expand_blossom(b, false),
+                    None => break false,
+                }
+            };
+            if !augmented {
+                break;
+            }
+            for b in n..2 * n {
+                if self.blossom_parent[b] == NONE
+                    && self.blossom_base[b] != NONE
+                    && self.label[b] == OUTER
+                    && self.dual[b] == zero
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+        (0..n)
+            .map(|v| match self.mate[v] {
+                NONE => NONE,
+                p => self.endpoint[p],
+            })
+            .collect()
+    }
+
+    fn slack(&self, e: usize) -> K {
+        let (i, j, w) = self.edges[e];
+        self.dual[i] + self.dual[j] - (w + w)
+    }
+
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        let mut stack = vec![b];
+        while let Some(t) = stack.pop() {
+            if t < self.n {
+                leaves.push(t);
+            } else {
+                stack.extend(self.blossom_children[t].iter().copied());
+            }
+        }
+        leaves
+    }
+
+    /// Scan the outer vertices of the queue along tight edges, labeling new
+    /// vertices, forming blossoms, and returning `true` after an augmentation.
+    fn grow(&mut self) -> bool {
+        let zero = K::default();
+        while let Some(v) = self.queue.pop() {
+            for idx in 0..self.neighbors[v].len() {
+                let p = self.neighbors[v][idx];
+                let e = p / 2;
+                let w = self.endpoint[p];
+                if self.in_blossom[v] == self.in_blossom[w] {
+                    continue;
+                }
+                let mut slack = zero;
+                if !self.allowed[e] {
+                    slack = self.slack(e);
+                    if slack <= zero {
+                        self.allowed[e] = true;
+                    }
+                }
+                let bw = self.in_blossom[w];
+                if self.allowed[e] {
+                    if self.label[bw] == FREE {
+                        self.assign_label(w, INNER, p ^ 1);
+                    } else if self.label[bw] == OUTER {
+                        let base = self.scan_blossom(v, w);
+                        if base != NONE {
+                            self.add_blossom(base, e);
+                        } else {
+                            self.augment_matching(e);
+                            return true;
+                        }
+                    } else if self.label[w] == FREE {
+                        self.label[w] = INNER;
+                        self.label_end[w] = p ^ 1;
+                    }
+                } else if self.label[bw] == OUTER {
+                    let b = self.in_blossom[v];
+                    if self.best_edge[b] == NONE || slack < self.slack(self.best_edge[b]) {
+                        self.best_edge[b] = e;
+                    }
+                } else if self.label[w] == FREE
+                    && (self.best_edge[w] == NONE || slack < self.slack(self.best_edge[w]))
+                {
+                    self.best_edge[w] = e;
+                }
+            }
+        }
+        false
+    }
+
+    /// Apply the largest dual change that keeps the duals feasible, and
+    /// return what limited it.
+    fn dual_step(&mut self) -> Option<Delta> {
+        let n = self.n;
+        let two = K::from_f64(2.0);
+        let mut delta = *self.dual[..n]
+            .iter()
+            .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))?;
+        let mut kind = Delta::Vertex;
+        for v in 0..n {
+            if self.label[self.in_blossom[v]] == FREE && self.best_edge[v] != NONE {
+                let d = self.slack(self.best_edge[v]);
+                if d < delta {
+                    delta = d;
+                    kind = Delta::Edge(self.best_edge[v]);
+                }
+            }
+        }
+        for b in 0..2 * n {
+            if self.blossom_parent[b] == NONE && self.label[b] == OUTER && self.best_edge[b] != NONE
+            {
+                // The slack between two outer blossoms is even for integer
+                // weights, so halving it is exact.
+                let d = self.slack(self.best_edge[b]) / two;
+                if d < delta {
+                    delta = d;
+                    kind = Delta::Edge(self.best_edge[b]);
+                }
+            }
+        }
+        for b in n..2 * n {
+            if self.blossom_base[b] != NONE
+                && self.blossom_parent[b] == NONE
+                && self.label[b] == INNER
+                && self.dual[b] < delta
+            {
+                delta = self.dual[b];
+                kind = Delta::Blossom(b);
+            }
+        }
+
+        for v in 0..n {
+            match self.label[self.in_blossom[v]] {
+                OUTER => self.dual[v] = self.dual[v] - delta,
+                INNER => self.dual[v] = self.dual[v] + delta,
+                _ => {}
+            }
+        }
+        for b in n..2 * n {
+            if self.blossom_base[b] != NONE && self.blossom_parent[b] == NONE {
+                match self.label[b] {
+                    OUTER => self.dual[b] = self.dual[b] + delta,
+                    INNER => self.dual[b] = self.dual[b] - delta,
+                    _ => {}
+                }
+            }
+        }
+        Some(kind)
+    }
+
+    fn assign_label(&mut self, w: usize, label: u8, p: usize) {
+        let b = self.in_blossom[w];
+        self.label[w] = label;
+        self.label[b] = label;
+        self.label_end[w] = p;
+        self.label_end[b] = p;
+        self.best_edge[w] = NONE;
+        self.best_edge[b] = NONE;
+        if label == OUTER {
+            let leaves = self.leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            let m = self.mate[self.blossom_base[b]];
+            self.assign_label(self.endpoint[m], OUTER, m ^ 1);
+        }
+    }
+
+    /// Trace back from `v` and `w` to find the base of a new blossom, or
+    /// return [`NONE`] if they lead to different roots.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = Vec::new();
+        let mut base = NONE;
+        while v != NONE {
+            let b = self.in_blossom[v];
+            if self.label[b] & BREADCRUMB != 0 {
+                base = self.blossom_base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = OUTER | BREADCRUMB;
+            if self.label_end[b] == NONE {
+                v = NONE;
+            } else {
+                let t = self.in_blossom[self.endpoint[self.label_end[b]]];
+                v = self.endpoint[self.label_end[t]];
+            }
+            if w != NONE {
+                core::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = OUTER;
+        }
+        base
+    }
+
+    /// Form a new blossom with the given base, closed by edge `e`.
+    fn add_blossom(&mut self, base: usize, e: usize) {
+        let (v, w, _) = self.edges[e];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+        let b = self.unused_blossoms.pop().unwrap();
+        self.blossom_base[b] = base;
+        self.blossom_parent[b] = NONE;
+        self.blossom_parent[bb] = b;
+        let mut children = Vec::new();
+        let mut endpoints = Vec::new();
+        while bv != bb {
+            self.blossom_parent[bv] = b;
+            children.push(bv);
+            endpoints.push(self.label_end[bv]);
+            bv = self.in_blossom[self.endpoint[self.label_end[bv]]];
+        }
+        children.push(bb);
+        children.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * e);
+        while bw != bb {
+            self.blossom_parent[bw] = b;
+            children.push(bw);
+            endpoints.push(self.label_end[bw] ^ 1);
+            bw = self.in_blossom[self.endpoint[self.label_end[bw]]];
+        }
+        self.label[b] = OUTER;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = K::default();
+        self.blossom_children[b] = children.clone();
+        self.blossom_endpoints[b] = endpoints;
+        for v in self.leaves(b) {
+            if self.label[self.in_blossom[v]] == INNER {
+                self.queue.push(v);
+            }
+            self.in_blossom[v] = b;
+        }
+
+        let mut best_edge_to = vec![NONE; 2 * self.n];
+        for bv in children {
+            let lists: Vec<Vec<usize>> = match self.blossom_best_edges[bv].take() {
+                Some(list) => vec![list],
+                None => self
+                    .leaves(bv)
+                    .into_iter()
+                    .map(|v| self.neighbors[v].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+            for e in lists.into_iter().flatten() {
+                let (i, j, _) = self.edges[e];
+                let j = if self.in_blossom[j] == b { i } else { j };
+                let bj = self.in_blossom[j];
+                if bj != b
+                    && self.label[bj] == OUTER
+                    && (best_edge_to[bj] == NONE || self.slack(e) < self.slack(best_edge_to[bj]))
+                {
+                    best_edge_to[bj] = e;
+                }
+            }
+            self.best_edge[bv] = NONE;
+        }
+        let best_edges: Vec<usize> = best_edge_to.into_iter().filter(|&e| e != NONE).collect();
+        self.best_edge[b] = NONE;
+        for &e in &best_edges {
+            if self.best_edge[b] == NONE || self.slack(e) < self.slack(self.best_edge[b]) {
+                self.best_edge[b] = e;
+            }
+        }
+        self.blossom_best_edges[b] = Some(best_edges);
+    }
+
+    /// Dissolve blossom `b` into its sub-blossoms, relabeling them if it was
+    /// an inner blossom in the middle of a stage.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        let zero = K::default();
+        let children = core::mem::take(&mut self.blossom_children[b]);
+        let endpoints = core::mem::take(&mut self.blossom_endpoints[b]);
+        for &s in &children {
+            self.blossom_parent[s] = NONE;
+            if s < self.n {
+                self.in_blossom[s] = s;
+            } else if end_stage && self.dual[s] == zero {
+                self.expand_blossom(s, end_stage);
+            } else {
+                for v in self.leaves(s) {
+                    self.in_blossom[v] = s;
+                }
+            }
+        }
+
+        if !end_stage && self.label[b] == INNER {
+            let len = children.len() as isize;
+            let child = |j: isize| children[j.rem_euclid(len) as usize];
+            let entry_child = self.in_blossom[self.endpoint[self.label_end[b] ^ 1]];
+            let mut j = children.iter().position(|&c| c == entry_child).unwrap() as isize;
+            // Walk from the entry child to the base along the even-length
+            // side of the cycle.
+            let (step, trick) = if j & 1 != 0 {
+                j -= len;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            let endpoint_at = |j: isize| endpoints[(j - trick as isize).rem_euclid(len) as usize];
+            let mut p = self.label_end[b];
+            while j != 0 {
+                let q = endpoint_at(j);
+                self.label[self.endpoint[p ^ 1]] = FREE;
+                self.label[self.endpoint[q ^ trick ^ 1]] = FREE;
+                self.assign_label(self.endpoint[p ^ 1], INNER, p);
+                self.allowed[q / 2] = true;
+                j += step;
+                p = endpoint_at(j) ^ trick;
+                self.allowed[p / 2] = true;
+                j += step;
+            }
+            let bv = child(j);
+            self.label[self.endpoint[p ^ 1]] = INNER;
+            self.label[bv] = INNER;
+            self.label_end[self.endpoint[p ^ 1]] = p;
+            self.label_end[bv] = p;
+            self.best_edge[bv] = NONE;
+            j += step;
+            // Relabel the sub-blossoms on the odd-length side that were
+            // reached from outside the blossom.
+            while child(j) != entry_child {
+                let bv = child(j);
+                j += step;
+                if self.label[bv] == OUTER {
+                    continue;
+                }
+                let reached = self.leaves(bv).into_iter().find(|&v| self.label[v] != FREE);
+                if let Some(v) = reached {
+                    self.label[v] = FREE;
+                    self.label[self.endpoint[self.mate[self.blossom_base[bv]]]] = FREE;
+                    self.assign_label(v, INNER, self.label_end[v]);
+                }
+            }
+        }
+
+        self.label[b] = FREE;
+        self.label_end[b] = NONE;
+        self.blossom_base[b] = NONE;
+        self.blossom_best_edges[b] = None;
+        self.best_edge[b] = NONE;
+        self.unused_blossoms.push(b);
+    }
+
+    /// Swap matched and unmatched edges along the even-length path from
+    /// vertex `v` to the base of blossom `b`, making `v` its new base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.blossom_parent[t] != b {
+            t = self.blossom_parent[t];
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+        let children = self.blossom_children[b].clone();
+        let endpoints = self.blossom_endpoints[b].clone();
+        let len = children.len() as isize;
+        let i = children.iter().position(|&c| c == t).unwrap();
+        let mut j = i as isize;
+        let (step, trick) = if i & 1 != 0 {
+            j -= len;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+        let child = |j: isize| children[j.rem_euclid(len) as usize];
+        let endpoint_at = |j: isize| endpoints[(j - trick as isize).rem_euclid(len) as usize];
+        while j != 0 {
+            j += step;
+            let p = endpoint_at(j) ^ trick;
+            let t = child(j);
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += step;
+            let t = child(j);
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+        self.blossom_children[b].rotate_left(i);
+        self.blossom_endpoints[b].rotate_left(i);
+        self.blossom_base[b] = self.blossom_base[self.blossom_children[b][0]];
+    }
+
+    /// Augment the matching along the path through edge `e` between two
+    /// outer vertices of different trees.
+    fn augment_matching(&mut self, e: usize) {
+        let (v, w, _) = self.edges[e];
+        for (mut s, mut p) in [(v, 2 * e + 1), (w, 2 * e)] {
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.label_end[bs] == NONE {
+                    break;
+                }
+                let t = self.endpoint[self.label_end[bs]];
+                let bt = self.in_blossom[t];
+                s = self.endpoint[self.label_end[bt]];
+                let j = self.endpoint[self.label_end[bt] ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = self.label_end[bt];
+                p = self.label_end[bt] ^ 1;
+            }
+        }
+    }
+}
+
+/// What limited a dual change of [`MaxWeightMatching`].
+enum Delta {
+    /// An outer vertex reached a zero dual, so no augmenting path can
+    /// increase the weight anymore.
+    Vertex,
+    /// An edge became tight.
+    Edge(usize),
+    /// An inner blossom reached a zero dual and must be expanded.
+    Blossom(usize),
+}
@@ -1,10 +1,15 @@
-use alloc::{collections::BinaryHeap, vec};
-use core::hash::Hash;
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::{fmt, hash::Hash};
 
+use fixedbitset::FixedBitSet;
 use hashbrown::{HashMap, HashSet};
 
+use crate::algo::covering::Adjacency;
 use crate::scored::MaxScored;
-use crate::visit::{IntoEdges, IntoNodeIdentifiers, NodeIndexable, VisitMap, Visitable};
+use crate::trace::Counter;
+use crate::visit::{
+    EdgeRef, IntoEdgeReferences, IntoEdges, IntoNodeIdentifiers, NodeIndexable, VisitMap, Visitable,
+};
 
 /// [DStatur algorithm][1] to properly color a non weighted undirected graph.
 ///
@@ -65,6 +70,7 @@ where
     G: IntoEdges + IntoNodeIdentifiers + Visitable + NodeIndexable,
     G::NodeId: Eq + Hash,
 {
+    trace_span!("dsatur_coloring");
     let ix = |v| graph.to_index(v);
     let n = graph.node_bound();
 
@@ -104,5 +110,811 @@ where
         }
     }
 
+    trace_event!(colors = max_color + 1, "dsatur_coloring finished");
     (colored, max_color + 1)
 }
+
+/// Find a coloring of a non weighted undirected graph with the minimum number
+/// of colors, its [chromatic number][1].
+///
+/// Uses the exact [DSATUR branch and bound][2] algorithm: nodes are colored
+/// in DSATUR order, trying every color that keeps the coloring proper, and
+/// branches that cannot use fewer colors than the best coloring found so far
+/// are pruned. The search starts from a greedy clique, which gives a lower
+/// bound, and from an [`rlf_coloring`], which gives an upper bound.
+///
+/// Graph coloring is NP-hard, so this is only practical for small graphs (up
+/// to about a hundred nodes, depending on the graph). Loops and edge
+/// directions are ignored.
+///
+/// # Arguments
+/// * `graph`: undirected graph without loops.
+///
+/// # Returns
+/// Returns a tuple of:
+/// * [`struct@hashbrown::HashMap`] that associates to each `NodeId` its color.
+/// * `usize`: the number of used colors, which is the chromatic number of the
+///   graph (`0` if the graph is empty).
+///
+/// # Complexity
+/// * Time complexity: **O(k^|V| · |V|)** in the worst case.
+/// * Auxiliary space: **O(|V|k + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **k** is the chromatic number.
+///
+/// [1]: https://en.wikipedia.org/wiki/Graph_coloring#Chromatic_number
+/// [2]: https://doi.org/10.1016/j.cor.2011.10.008
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::minimum_coloring;
+/// use petgraph::graph::UnGraph;
+///
+/// // The Petersen graph has chromatic number 3.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+///     (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+///     (5, 7), (7, 9), (9, 6), (6, 8), (8, 5),
+/// ]);
+///
+/// let (coloring, nb_colors) = minimum_coloring(&graph);
+/// assert_eq!(nb_colors, 3);
+/// for edge in graph.edge_indices() {
+///     let (a, b) = graph.edge_endpoints(edge).unwrap();
+///     assert_ne!(coloring[&a], coloring[&b]);
+/// }
+/// ```
+pub fn minimum_coloring<G>(graph: G) -> (HashMap<G::NodeId, usize>, usize)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    trace_span!("minimum_coloring");
+    let graph = Adjacency::new(graph);
+    let n = graph.len();
+    let (best, best_count) = recursive_largest_first(&graph);
+    let clique = greedy_clique(&graph);
+    trace_event!(
+        upper_bound = best_count,
+        lower_bound = clique.len(),
+        "minimum_coloring bounds"
+    );
+
+    let mut search = ColoringSearch {
+        neighbors: &graph.neighbors,
+        lower_bound: clique.len(),
+        best,
+        best_count,
+        colors: vec![usize::MAX; n],
+        counts: vec![vec![0; best_count]; n],
+        saturation: vec![0; n],
+        used: clique.len(),
+        explored: Counter::default(),
+        pruned: Counter::default(),
+    };
+    if search.best_count > search.lower_bound {
+        // The nodes of a clique all get different colors, so coloring them
+        // first removes symmetric branches.
+        for (color, &v) in clique.iter().enumerate() {
+            search.assign(v, color);
+        }
+        search.expand(n - clique.len());
+    }
+    trace_event!(
+        states_explored = search.explored.get(),
+        states_pruned = search.pruned.get(),
+        colors = search.best_count,
+        "minimum_coloring finished"
+    );
+    let coloring = graph.nodes.iter().copied().zip(search.best).collect();
+    (coloring, search.best_count)
+}
+
+/// [Recursive largest first][1] algorithm to properly color a non weighted
+/// undirected graph.
+///
+/// Colors are assigned one at a time: each color class is grown greedily as
+/// a maximal independent set of the uncolored nodes, starting from the node
+/// with the most uncolored neighbors, and then adding the node with the most
+/// neighbors that can no longer join the class. This is a heuristic, which
+/// usually uses fewer colors than [`dsatur_coloring`], but is slower.
+///
+/// Loops and edge directions are ignored.
+///
+/// # Arguments
+/// * `graph`: undirected graph without loops.
+///
+/// # Returns
+/// Returns a tuple of:
+/// * [`struct@hashbrown::HashMap`] that associates to each `NodeId` its color.
+/// * `usize`: the number of used colors (`0` if the graph is empty).
+///
+/// # Complexity
+/// * Time complexity: **O(k(|V|² + |E|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **k** is the number of used colors.
+///
+/// [1]: https://doi.org/10.6028/jres.084.024
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::rlf_coloring;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // A wheel with 5 spokes needs 4 colors.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3), (0, 4), (0, 5),
+///     (1, 2), (2, 3), (3, 4), (4, 5), (5, 1),
+/// ]);
+///
+/// let (coloring, nb_colors) = rlf_coloring(&graph);
+/// assert_eq!(nb_colors, 4);
+/// assert_ne!(coloring[&NodeIndex::new(0)], coloring[&NodeIndex::new(1)]);
+/// ```
+pub fn rlf_coloring<G>(graph: G) -> (HashMap<G::NodeId, usize>, usize)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let graph = Adjacency::new(graph);
+    let (colors, count) = recursive_largest_first(&graph);
+    let coloring = graph.nodes.iter().copied().zip(colors).collect();
+    (coloring, count)
+}
+
+/// [Misra–Gries algorithm][1] to properly color the edges of an undirected
+/// simple graph with at most **Δ + 1** colors.
+///
+/// A proper edge coloring gives different colors to edges that share an
+/// endpoint. By Vizing's theorem every simple graph has one with **Δ** or
+/// **Δ + 1** colors, where **Δ** is the maximum degree, and this algorithm
+/// constructively finds one with at most **Δ + 1** colors: each edge is
+/// colored in turn, after rotating the colors of a fan of edges around one
+/// endpoint and swapping the colors of an alternating path.
+///
+/// Edge directions are ignored.
+///
+/// # Arguments
+/// * `graph`: undirected graph without loops or parallel edges.
+///
+/// # Returns
+/// Returns a tuple of:
+/// * [`struct@hashbrown::HashMap`] that associates to each `EdgeId` its color.
+/// * `usize`: the number of used colors.
+///
+/// # Panics
+/// Panics if the graph has a loop, or two edges between the same nodes (in
+/// any direction).
+///
+/// # Complexity
+/// * Time complexity: **O(|V||E|)**.
+/// * Auxiliary space: **O(|V|Δ + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **Δ** is the maximum degree.
+///
+/// [1]: https://doi.org/10.1016/0020-0190(92)90041-S
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::misra_gries_edge_coloring;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::visit::EdgeRef;
+///
+/// // The complete graph on 4 nodes has maximum degree 3.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+///
+/// let (coloring, nb_colors) = misra_gries_edge_coloring(&graph);
+/// assert!(nb_colors <= 4);
+/// for node in graph.node_indices() {
+///     let mut colors: Vec<_> = graph.edges(node).map(|e| coloring[&e.id()]).collect();
+///     colors.sort();
+///     colors.dedup();
+///     assert_eq!(colors.len(), 3);
+/// }
+/// ```
+pub fn misra_gries_edge_coloring<G>(graph: G) -> (HashMap<G::EdgeId, usize>, usize)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    G::EdgeId: Eq + Hash,
+{
+    let mut position = vec![usize::MAX; graph.node_bound()];
+    for (i, node) in graph.node_identifiers().enumerate() {
+        position[graph.to_index(node)] = i;
+    }
+    let n = graph.node_identifiers().count();
+
+    let mut ids = Vec::new();
+    let mut endpoints = Vec::new();
+    let mut incident: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    let mut pairs = HashSet::new();
+    for edge in graph.edge_references() {
+        let a = position[graph.to_index(edge.source())];
+        let b = position[graph.to_index(edge.target())];
+        assert!(a != b, "edge coloring: the graph has a loop");
+        assert!(
+            pairs.insert((a.min(b), a.max(b))),
+            "edge coloring: the graph has parallel edges"
+        );
+        let e = ids.len();
+        ids.push(edge.id());
+        endpoints.push((a, b));
+        incident[a].push((b, e));
+        incident[b].push((a, e));
+    }
+    let max_degree = incident.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut coloring = EdgeColoring {
+        endpoints,
+        colors: vec![usize::MAX; ids.len()],
+        at: vec![vec![usize::MAX; max_degree + 1]; n],
+    };
+    for e in 0..ids.len() {
+        let (x, f) = coloring.endpoints[e];
+        coloring.color_edge(&incident[x], x, f, e);
+    }
+
+    let count = coloring.colors.iter().map(|&c| c + 1).max().unwrap_or(0);
+    (ids.into_iter().zip(coloring.colors).collect(), count)
+}
+
+struct EdgeColoring {
+    endpoints: Vec<(usize, usize)>,
+    /// The color of each edge, or `usize::MAX`.
+    colors: Vec<usize>,
+    /// The edge of each color at each node, or `usize::MAX`.
+    at: Vec<Vec<usize>>,
+}
+
+impl EdgeColoring {
+    fn is_free(&self, node: usize, color: usize) -> bool {
+        self.at[node][color] == usize::MAX
+    }
+
+    fn free_color(&self, node: usize) -> usize {
+        (0..self.at[node].len())
+            .find(|&c| self.is_free(node, c))
+            .unwrap()
+    }
+
+    fn set(&mut self, e: usize, color: usize) {
+        let (a, b) = self.endpoints[e];
+        self.colors[e] = color;
+        self.at[a][color] = e;
+        self.at[b][color] = e;
+    }
+
+    fn unset(&mut self, e: usize) -> usize {
+        let (a, b) = self.endpoints[e];
+        let color = core::mem::replace(&mut self.colors[e], usize::MAX);
+        self.at[a][color] = usize::MAX;
+        self.at[b][color] = usize::MAX;
+        color
+    }
+
+    /// Color the uncolored edge `e` between `x` and `f`, given the edges
+    /// incident to `x` with their other endpoint.
+    fn color_edge(&mut self, incident: &[(usize, usize)], x: usize, f: usize, e: usize) {
+        // A maximal fan of x starting at f: the edge to each node of the fan
+        // has a color that is free on the previous node.
+        let mut fan = vec![(f, e)];
+        loop {
+            let last = fan.last().unwrap().0;
+            let next = incident.iter().copied().find(|&(v, edge)| {
+                self.colors[edge] != usize::MAX
+                    && self.is_free(last, self.colors[edge])
+                    && fan.iter().all(|&(u, _)| u != v)
+            });
+            match next {
+                Some(next) => fan.push(next),
+                None => break,
+            }
+        }
+
+        let c = self.free_color(x);
+        let d = self.free_color(fan.last().unwrap().0);
+        self.invert_path(x, c, d);
+
+        // Find a prefix of the fan that is still a fan and ends at a node
+        // where d is free.
+        let mut end = 0;
+        for i in 0..fan.len() {
+            if i > 0 && !self.is_free(fan[i - 1].0, self.colors[fan[i].1]) {
+                break;
+            }
+            if self.is_free(fan[i].0, d) {
+                end = i;
+                break;
+            }
+        }
+        // Rotate the colors of the prefix, freeing the edge to its end.
+        for i in 0..end {
+            let color = self.unset(fan[i + 1].1);
+            self.set(fan[i].1, color);
+        }
+        self.set(fan[end].1, d);
+    }
+
+    /// Swap the colors `c` and `d` on the maximal path starting at `x` with
+    /// colors `d`, `c`, `d`, ..., where `c` is free on `x`.
+    fn invert_path(&mut self, x: usize, c: usize, d: usize) {
+        let mut path = Vec::new();
+        let (mut node, mut color) = (x, d);
+        while self.at[node][color] != usize::MAX {
+            let e = self.at[node][color];
+            path.push(e);
+            let (a, b) = self.endpoints[e];
+            node = if a == node { b } else { a };
+            color = if color == c { d } else { c };
+        }
+        for &e in &path {
+            self.unset(e);
+        }
+        // Edges were colored d, c, d, ... and become c, d, c, ...
+        let mut color = c;
+        for &e in &path {
+            self.set(e, color);
+            color = if color == c { d } else { c };
+        }
+    }
+}
+
+/// The error type for [`equitable_coloring`] and [`list_coloring`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColoringError<N> {
+    /// The node has an empty list of allowed colors.
+    EmptyList(N),
+    /// No coloring satisfies the constraints.
+    Infeasible,
+}
+
+#[cfg(feature = "std")]
+impl<N: fmt::Debug> std::error::Error for ColoringError<N> {}
+
+#[cfg(not(feature = "std"))]
+impl<N: fmt::Debug> core::error::Error for ColoringError<N> {}
+
+impl<N: fmt::Debug> fmt::Display for ColoringError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColoringError::EmptyList(node) => {
+                write!(f, "The node {node:?} has no allowed color.")
+            }
+            ColoringError::Infeasible => {
+                write!(f, "No coloring satisfies the constraints.")
+            }
+        }
+    }
+}
+
+/// Find an [equitable coloring][1] of a non weighted undirected graph with
+/// `k` colors: a proper coloring in which the numbers of nodes of any two
+/// colors differ by at most one.
+///
+/// By the Hajnal–Szemerédi theorem, an equitable coloring with `k` colors
+/// exists whenever `k` is greater than the maximum degree. This function
+/// searches for one with a backtracking search, coloring first the node
+/// with the fewest available colors, so it also finds equitable colorings
+/// with fewer colors when they exist, or proves that there are none. The
+/// search is fast when `k` is greater than the maximum degree, but can take
+/// exponential time otherwise.
+///
+/// Loops and edge directions are ignored.
+///
+/// # Arguments
+/// * `graph`: undirected graph without loops.
+/// * `k`: the number of colors.
+///
+/// # Returns
+/// * `Ok`: a tuple of a [`struct@hashbrown::HashMap`] that associates to each
+///   `NodeId` its color, in `0..k`, and the number of colors `k`.
+/// * `Err`: [`ColoringError::Infeasible`] if the graph has no equitable
+///   coloring with `k` colors.
+///
+/// # Complexity
+/// * Time complexity: **O(k^|V| · |V|k)** in the worst case, and
+///   **O(|V|²k + |E|)** if `k` is greater than the maximum degree and no
+///   backtracking is needed.
+/// * Auxiliary space: **O(|V|k + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Equitable_coloring
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{equitable_coloring, ColoringError};
+/// use petgraph::graph::UnGraph;
+///
+/// // A star with 4 leaves is 2-colorable, but not equitably.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+/// assert_eq!(equitable_coloring(&graph, 2), Err(ColoringError::Infeasible));
+///
+/// let (coloring, k) = equitable_coloring(&graph, 3).unwrap();
+/// assert_eq!(k, 3);
+/// let mut sizes = vec![0; 3];
+/// for &color in coloring.values() {
+///     sizes[color] += 1;
+/// }
+/// sizes.sort();
+/// assert_eq!(sizes, vec![1, 2, 2]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn equitable_coloring<G>(
+    graph: G,
+    k: usize,
+) -> Result<(HashMap<G::NodeId, usize>, usize), ColoringError<G::NodeId>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let graph = Adjacency::new(graph);
+    let n = graph.len();
+    if k == 0 {
+        return if n == 0 {
+            Ok((HashMap::new(), 0))
+        } else {
+            Err(ColoringError::Infeasible)
+        };
+    }
+    let mut all = FixedBitSet::with_capacity(k);
+    all.insert_range(..);
+    let mut search = ConstrainedColoring::new(&graph.neighbors, vec![all; n], k);
+    search.capacity = Some((n / k, n % k));
+    search.interchangeable = true;
+    if !search.solve() {
+        return Err(ColoringError::Infeasible);
+    }
+    let coloring = graph.nodes.iter().copied().zip(search.colors).collect();
+    Ok((coloring, k))
+}
+
+/// Find a [list coloring][1] of a non weighted undirected graph: a proper
+/// coloring in which every node gets one of its allowed colors.
+///
+/// The function `allowed` returns the allowed colors of each node. This
+/// function searches for a coloring with a backtracking search, coloring
+/// first the node with the fewest available colors, so it finds a coloring
+/// if there is one, or proves that there are none. List coloring is
+/// NP-hard, and the search can take exponential time.
+///
+/// Loops and edge directions are ignored.
+///
+/// # Arguments
+/// * `graph`: undirected graph without loops.
+/// * `allowed`: closure that returns the allowed colors of a node.
+///
+/// # Returns
+/// * `Ok`: a [`struct@hashbrown::HashMap`] that associates to each `NodeId`
+///   its color.
+/// * `Err`: [`ColoringError::EmptyList`] if a node has no allowed color, or
+///   [`ColoringError::Infeasible`] if there is no list coloring.
+///
+/// # Complexity
+/// * Time complexity: **O(L^|V| · |V|L)** in the worst case.
+/// * Auxiliary space: **O(|V|L + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **L** is the number of distinct allowed colors.
+///
+/// [1]: https://en.wikipedia.org/wiki/List_coloring
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::list_coloring;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // Frequencies for three mutually interfering transmitters.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let allowed = |node: NodeIndex| match node.index() {
+///     0 => vec![101, 102],
+///     1 => vec![101],
+///     _ => vec![101, 102, 103],
+/// };
+///
+/// let coloring = list_coloring(&graph, allowed).unwrap();
+/// assert_eq!(coloring[&NodeIndex::new(0)], 102);
+/// assert_eq!(coloring[&NodeIndex::new(1)], 101);
+/// assert_eq!(coloring[&NodeIndex::new(2)], 103);
+/// ```
+pub fn list_coloring<G, F, I>(
+    graph: G,
+    mut allowed: F,
+) -> Result<HashMap<G::NodeId, usize>, ColoringError<G::NodeId>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::NodeId) -> I,
+    I: IntoIterator<Item = usize>,
+{
+    let graph = Adjacency::new(graph);
+    // Number the distinct colors densely.
+    let mut palette = Vec::new();
+    let mut index = HashMap::new();
+    let mut lists = Vec::with_capacity(graph.len());
+    for &node in &graph.nodes {
+        let list: Vec<usize> = allowed(node)
+            .into_iter()
+            .map(|color| {
+                *index.entry(color).or_insert_with(|| {
+                    palette.push(color);
+                    palette.len() - 1
+                })
+            })
+            .collect();
+        if list.is_empty() {
+            return Err(ColoringError::EmptyList(node));
+        }
+        lists.push(list);
+    }
+    let lists = lists
+        .into_iter()
+        .map(|list| {
+            let mut set = FixedBitSet::with_capacity(palette.len());
+            set.extend(list);
+            set
+        })
+        .collect();
+
+    let mut search = ConstrainedColoring::new(&graph.neighbors, lists, palette.len());
+    if !search.solve() {
+        return Err(ColoringError::Infeasible);
+    }
+    Ok(graph
+        .nodes
+        .iter()
+        .zip(search.colors)
+        .map(|(&node, color)| (node, palette[color]))
+        .collect())
+}
+
+/// Backtracking search for a proper coloring with colors `0..k`, in which
+/// each node gets one of its allowed colors.
+struct ConstrainedColoring<'a> {
+    neighbors: &'a [Vec<usize>],
+    allowed: Vec<FixedBitSet>,
+    colors: Vec<usize>,
+    /// Number of neighbors of each node with each color.
+    counts: Vec<Vec<usize>>,
+    sizes: Vec<usize>,
+    /// If set, the size `s` and the number `r` of color classes that may
+    /// have one more node: every class has `s` or `s + 1` nodes.
+    capacity: Option<(usize, usize)>,
+    /// Number of classes with `s + 1` nodes.
+    full: usize,
+    /// Whether all colors are allowed for all nodes, so that only one unused
+    /// color needs to be tried.
+    interchangeable: bool,
+}
+
+impl<'a> ConstrainedColoring<'a> {
+    fn new(neighbors: &'a [Vec<usize>], allowed: Vec<FixedBitSet>, k: usize) -> Self {
+        let n = neighbors.len();
+        ConstrainedColoring {
+            neighbors,
+            allowed,
+            colors: vec![usize::MAX; n],
+            counts: vec![vec![0; k]; n],
+            sizes: vec![0; k],
+            capacity: None,
+            full: 0,
+            interchangeable: false,
+        }
+    }
+
+    fn is_available(&self, v: usize, color: usize) -> bool {
+        self.allowed[v].contains(color)
+            && self.counts[v][color] == 0
+            && self.capacity.map_or(true, |(size, extra)| {
+                self.sizes[color] < size || (self.sizes[color] == size && self.full < extra)
+            })
+    }
+
+    fn assign(&mut self, v: usize, color: usize) {
+        self.colors[v] = color;
+        self.sizes[color] += 1;
+        if let Some((size, _)) = self.capacity {
+            if self.sizes[color] == size + 1 {
+                self.full += 1;
+            }
+        }
+        let neighbors = self.neighbors;
+        for &w in &neighbors[v] {
+            self.counts[w][color] += 1;
+        }
+    }
+
+    fn unassign(&mut self, v: usize) {
+        let color = core::mem::replace(&mut self.colors[v], usize::MAX);
+        if let Some((size, _)) = self.capacity {
+            if self.sizes[color] == size + 1 {
+                self.full -= 1;
+            }
+        }
+        self.sizes[color] -= 1;
+        let neighbors = self.neighbors;
+        for &w in &neighbors[v] {
+            self.counts[w][color] -= 1;
+        }
+    }
+
+    /// Color all the uncolored nodes, and return whether it succeeded.
+    fn solve(&mut self) -> bool {
+        let k = self.sizes.len();
+        // The uncolored node with the fewest available colors.
+        let next = (0..self.colors.len())
+            .filter(|&v| self.colors[v] == usize::MAX)
+            .map(|v| ((0..k).filter(|&c| self.is_available(v, c)).count(), v))
+            .min();
+        let v = match next {
+            None => return true,
+            Some((0, _)) => return false,
+            Some((_, v)) => v,
+        };
+        let mut tried_unused = false;
+        let choices: Vec<usize> = (0..k).filter(|&c| self.is_available(v, c)).collect();
+        for color in choices {
+            if self.interchangeable && self.sizes[color] == 0 {
+                // All unused colors are equivalent.
+                if tried_unused {
+                    continue;
+                }
+                tried_unused = true;
+            }
+            self.assign(v, color);
+            if self.solve() {
+                return true;
+            }
+            self.unassign(v);
+        }
+        false
+    }
+}
+
+/// Return the color of each node and the number of colors of an RLF
+/// coloring.
+fn recursive_largest_first<N: Copy>(graph: &Adjacency<N>) -> (Vec<usize>, usize) {
+    let n = graph.len();
+    let mut colors = vec![usize::MAX; n];
+    // Candidates are uncolored nodes that can join the current class.
+    let mut candidate = vec![false; n];
+    // Number of uncolored neighbors, and of uncolored neighbors adjacent to
+    // the current class.
+    let mut uncolored_degree: Vec<usize> = graph.neighbors.iter().map(Vec::len).collect();
+    let mut excluded_degree = vec![0usize; n];
+    let mut remaining = n;
+    let mut color = 0;
+    while remaining > 0 {
+        for v in 0..n {
+            candidate[v] = colors[v] == usize::MAX;
+            excluded_degree[v] = 0;
+        }
+
+        let mut next = (0..n)
+            .filter(|&v| candidate[v])
+            .max_by_key(|&v| (uncolored_degree[v], core::cmp::Reverse(v)));
+        while let Some(v) = next {
+            colors[v] = color;
+            candidate[v] = false;
+            remaining -= 1;
+            for &w in &graph.neighbors[v] {
+                uncolored_degree[w] -= 1;
+                if candidate[w] {
+                    candidate[w] = false;
+                    for &x in &graph.neighbors[w] {
+                        excluded_degree[x] += 1;
+                    }
+                }
+            }
+            // Prefer candidates with many excluded neighbors, then with few
+            // candidate neighbors.
+            next = (0..n).filter(|&u| candidate[u]).max_by_key(|&u| {
+                let candidate_degree = uncolored_degree[u] - excluded_degree[u];
+                (
+                    excluded_degree[u],
+                    core::cmp::Reverse(candidate_degree),
+                    core::cmp::Reverse(u),
+                )
+            });
+        }
+        color += 1;
+    }
+    (colors, color)
+}
+
+/// Return a maximal clique, built greedily from nodes of high degree.
+fn greedy_clique<N: Copy>(graph: &Adjacency<N>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..graph.len()).collect();
+    order.sort_by_key(|&v| core::cmp::Reverse(graph.neighbors[v].len()));
+    let mut clique = Vec::new();
+    for v in order {
+        if clique.iter().all(|&u| graph.is_adjacent(u, v)) {
+            clique.push(v);
+        }
+    }
+    clique
+}
+
+struct ColoringSearch<'a> {
+    neighbors: &'a [Vec<usize>],
+    lower_bound: usize,
+    best: Vec<usize>,
+    best_count: usize,
+    colors: Vec<usize>,
+    /// Number of neighbors of each node with each color.
+    counts: Vec<Vec<usize>>,
+    /// Number of distinct colors among the neighbors of each node.
+    saturation: Vec<usize>,
+    used: usize,
+    explored: Counter,
+    pruned: Counter,
+}
+
+impl ColoringSearch<'_> {
+    fn assign(&mut self, v: usize, color: usize) {
+        self.colors[v] = color;
+        let neighbors = self.neighbors;
+        for &w in &neighbors[v] {
+            if self.counts[w][color] == 0 {
+                self.saturation[w] += 1;
+            }
+            self.counts[w][color] += 1;
+        }
+    }
+
+    fn unassign(&mut self, v: usize) {
+        let color = self.colors[v];
+        self.colors[v] = usize::MAX;
+        let neighbors = self.neighbors;
+        for &w in &neighbors[v] {
+            self.counts[w][color] -= 1;
+            if self.counts[w][color] == 0 {
+                self.saturation[w] -= 1;
+            }
+        }
+    }
+
+    /// Color the `remaining` uncolored nodes.
+    fn expand(&mut self, remaining: usize) {
+        self.explored.incr();
+        if remaining == 0 {
+            self.best.clone_from(&self.colors);
+            self.best_count = self.used;
+            return;
+        }
+        let uncolored_degree = |v: usize| {
+            self.neighbors[v]
+                .iter()
+                .filter(|&&w| self.colors[w] == usize::MAX)
+                .count()
+        };
+        let v = (0..self.colors.len())
+            .filter(|&v| self.colors[v] == usize::MAX)
+            .max_by_key(|&v| (self.saturation[v], uncolored_degree(v)))
+            .unwrap();
+
+        for color in 0..self.used {
+            if self.counts[v][color] == 0 {
+                self.assign(v, color);
+                self.expand(remaining - 1);
+                self.unassign(v);
+                if self.best_count == self.lower_bound || self.used >= self.best_count {
+                    self.pruned.incr();
+                    return;
+                }
+            }
+        }
+        if self.used + 1 < self.best_count {
+            self.assign(v, self.used);
+            self.used += 1;
+            self.expand(remaining - 1);
+            self.used -= 1;
+            self.unassign(v);
+        } else {
+            self.pruned.incr();
+        }
+    }
+}
@@ -0,0 +1,809 @@
+//! Independent sets, vertex covers and dominating sets.
+//!
+//! An *independent set* is a set of nodes of which no two are adjacent, and a
+//! *vertex cover* is a set of nodes that touches every edge. The complement of
+//! an independent set is a vertex cover, so a maximum independent set and a
+//! minimum vertex cover are found together. A *dominating set* is a set of
+//! nodes such that every node is in the set or adjacent to it.
+//!
+//! These problems are NP-hard. The exact functions use a branch and reduce
+//! search that is practical for graphs of up to about a hundred nodes (more if
+//! the graph is sparse). The approximate functions run in polynomial time and
+//! are suitable for large graphs.
+//!
+//! Edge directions are ignored, and a node with a loop is never part of an
+//! independent set (so it is always part of a vertex cover). Loops do not
+//! matter for dominating sets.
+
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Reverse;
+
+use fixedbitset::FixedBitSet;
+
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// The undirected simple graph underlying a graph, with nodes numbered by
+/// their position in `nodes`.
+pub(crate) struct Adjacency<N> {
+    pub(crate) nodes: Vec<N>,
+    /// Sorted neighbor lists, without loops or parallel edges.
+    pub(crate) neighbors: Vec<Vec<usize>>,
+    pub(crate) looped: Vec<bool>,
+}
+
+impl<N: Copy> Adjacency<N> {
+    pub(crate) fn new<G>(g: G) -> Self
+    where
+        G: IntoNeighbors<NodeId = N> + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let nodes: Vec<N> = g.node_identifiers().collect();
+        let mut position = vec![usize::MAX; g.node_bound()];
+        for (i, &node) in nodes.iter().enumerate() {
+            position[g.to_index(node)] = i;
+        }
+        let mut neighbors = vec![Vec::new(); nodes.len()];
+        let mut looped = vec![false; nodes.len()];
+        for (i, &node) in nodes.iter().enumerate() {
+            for next in g.neighbors(node) {
+                let j = position[g.to_index(next)];
+                if i == j {
+                    looped[i] = true;
+                } else {
+                    neighbors[i].push(j);
+                    neighbors[j].push(i);
+                }
+            }
+        }
+        for list in &mut neighbors {
+            list.sort_unstable();
+            list.dedup();
+        }
+        Adjacency {
+            nodes,
+            neighbors,
+            looped,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub(crate) fn is_adjacent(&self, a: usize, b: usize) -> bool {
+        self.neighbors[a].binary_search(&b).is_ok()
+    }
+
+    pub(crate) fn bitsets(&self) -> Vec<FixedBitSet> {
+        self.neighbors
+            .iter()
+            .map(|list| {
+                let mut set = FixedBitSet::with_capacity(self.len());
+                set.extend(list.iter().copied());
+                set
+            })
+            .collect()
+    }
+
+    /// Return the closed neighborhood of every node, as bitsets.
+    pub(crate) fn closed_bitsets(&self) -> Vec<FixedBitSet> {
+        let mut sets = self.bitsets();
+        for (v, set) in sets.iter_mut().enumerate() {
+            set.insert(v);
+        }
+        sets
+    }
+
+    pub(crate) fn to_nodes(&self, members: impl IntoIterator<Item = usize>) -> Vec<N> {
+        members.into_iter().map(|i| self.nodes[i]).collect()
+    }
+
+    /// Return the nodes whose index is not in `members`.
+    pub(crate) fn complement(&self, members: &[usize]) -> Vec<N> {
+        let mut excluded = FixedBitSet::with_capacity(self.len());
+        excluded.extend(members.iter().copied());
+        self.to_nodes((0..self.len()).filter(|&i| !excluded.contains(i)))
+    }
+}
+
+/// Find a maximum independent set of the graph.
+///
+/// Uses a branch and reduce search: nodes of degree at most one are always
+/// taken, otherwise the search branches on a node of maximum degree (taking
+/// it or not). Branches are pruned with an upper bound given by a greedy
+/// partition of the remaining nodes into cliques, since an independent set
+/// contains at most one node of each clique.
+///
+/// # Arguments
+/// * `g`: an input graph, whose edge directions are ignored.
+///
+/// # Returns
+/// * `Vec`: the nodes of a maximum independent set.
+///
+/// # Complexity
+/// * Time complexity: **O(2^|V| · |V|²)** in the worst case.
+/// * Auxiliary space: **O(|V|² + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::covering::maximum_independent_set;
+/// use petgraph::graph::UnGraph;
+///
+/// // A cycle of 5 nodes with a pendant node.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (0, 5)]);
+///
+/// let set = maximum_independent_set(&graph);
+/// assert_eq!(set.len(), 3);
+/// ```
+pub fn maximum_independent_set<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let graph = Adjacency::new(g);
+    graph.to_nodes(exact_independent_set(&graph))
+}
+
+/// Find a minimum vertex cover of the graph.
+///
+/// This is the complement of a [`maximum_independent_set`], found with the
+/// same branch and reduce search.
+///
+/// # Arguments
+/// * `g`: an input graph, whose edge directions are ignored.
+///
+/// # Returns
+/// * `Vec`: the nodes of a minimum vertex cover.
+///
+/// # Complexity
+/// * Time complexity: **O(2^|V| · |V|²)** in the worst case.
+/// * Auxiliary space: **O(|V|² + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::covering::minimum_vertex_cover;
+/// use petgraph::graph::UnGraph;
+///
+/// // A star is covered by its center.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+///
+/// assert_eq!(minimum_vertex_cover(&graph), vec![0.into()]);
+/// ```
+pub fn minimum_vertex_cover<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let graph = Adjacency::new(g);
+    graph.complement(&exact_independent_set(&graph))
+}
+
+/// Find a large independent set of the graph.
+///
+/// A maximal independent set is built greedily, taking nodes of minimum
+/// degree first, and then improved by local search with *(1,2)-swaps*
+/// (removing one node of the set to insert two), as described by
+/// [Andrade, Resende and Werneck][1], until no swap applies.
+///
+/// # Arguments
+/// * `g`: an input graph, whose edge directions are ignored.
+///
+/// # Returns
+/// * `Vec`: the nodes of a maximal independent set.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| · Σ deg(v)²)** in the worst case.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://doi.org/10.1007/s10732-012-9196-4
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::covering::approximate_maximum_independent_set;
+/// use petgraph::graph::UnGraph;
+///
+/// // A path of 7 nodes.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6)]);
+///
+/// let set = approximate_maximum_independent_set(&graph);
+/// assert_eq!(set.len(), 4);
+/// ```
+pub fn approximate_maximum_independent_set<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let graph = Adjacency::new(g);
+    graph.to_nodes(local_search_independent_set(&graph))
+}
+
+/// Find a small vertex cover of the graph, at most twice as large as a
+/// minimum one.
+///
+/// The endpoints of a maximal matching form a [2-approximate vertex cover][1].
+/// Nodes whose neighbors are all in the cover are then removed from it while
+/// possible, which keeps the approximation guarantee and usually improves the
+/// result considerably.
+///
+/// # Arguments
+/// * `g`: an input graph, whose edge directions are ignored.
+///
+/// # Returns
+/// * `Vec`: the nodes of a minimal vertex cover.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Vertex_cover#Approximate_evaluation
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::covering::approximate_minimum_vertex_cover;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (3, 4)]);
+///
+/// let cover = approximate_minimum_vertex_cover(&graph);
+/// assert!(cover.len() <= 4);
+/// for (a, b) in [(0, 1), (0, 2), (0, 3), (3, 4)] {
+///     assert!(cover.contains(&a.into()) || cover.contains(&b.into()));
+/// }
+/// ```
+pub fn approximate_minimum_vertex_cover<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let graph = Adjacency::new(g);
+    let (cover, _) = matching_vertex_cover(&graph);
+    graph.to_nodes(cover)
+}
+
+/// A small cover computed by an approximation algorithm, with a lower bound
+/// on the size of a minimum one.
+///
+/// The bound makes the quality of the cover known for the given graph, which
+/// is usually much better than the worst case guarantee of the algorithm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoundedCover<N> {
+    /// The nodes of the cover.
+    pub nodes: Vec<N>,
+    /// A lower bound on the number of nodes of a minimum cover.
+    pub lower_bound: usize,
+}
+
+impl<N> BoundedCover<N> {
+    /// Return an upper bound on the ratio between the size of `nodes` and the
+    /// size of a minimum cover.
+    ///
+    /// This is `1.0` when the cover is empty.
+    pub fn ratio(&self) -> f64 {
+        if self.nodes.is_empty() {
+            1.0
+        } else {
+            self.nodes.len() as f64 / self.lower_bound.max(1) as f64
+        }
+    }
+}
+
+/// Find a small vertex cover of the graph, at most twice as large as a
+/// minimum one, and a lower bound on the size of a minimum one.
+///
+/// The cover is the one of [`approximate_minimum_vertex_cover`]. The lower
+/// bound is the size of the maximal matching it is built from, plus the
+/// number of nodes with a loop: a vertex cover contains an endpoint of every
+/// edge of a matching, and every node with a loop.
+///
+/// # Arguments
+/// * `g`: an input graph, whose edge directions are ignored.
+///
+/// # Returns
+/// * [`BoundedCover`]: the nodes of a minimal vertex cover, and a lower bound
+///   that is at least half its size.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::covering::approximate_minimum_vertex_cover_with_bound;
+/// use petgraph::graph::UnGraph;
+///
+/// // A cycle of 8 nodes has a minimum vertex cover of 4 nodes.
+/// let graph = UnGraph::<(), ()>::from_edges((0..8).map(|i| (i, (i + 1) % 8)));
+///
+/// let cover = approximate_minimum_vertex_cover_with_bound(&graph);
+/// assert!(cover.lower_bound <= 4 && 4 <= cover.nodes.len());
+/// assert!(cover.ratio() <= 2.0);
+/// ```
+pub fn approximate_minimum_vertex_cover_with_bound<G>(g: G) -> BoundedCover<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let graph = Adjacency::new(g);
+    let (cover, matched) = matching_vertex_cover(&graph);
+    let looped = graph.looped.iter().filter(|&&looped| looped).count();
+    BoundedCover {
+        nodes: graph.to_nodes(cover),
+        lower_bound: looped + matched,
+    }
+}
+
+/// Return a minimal vertex cover built from a maximal matching, and the
+/// number of edges of the matching.
+fn matching_vertex_cover<N: Copy>(graph: &Adjacency<N>) -> (Vec<usize>, usize) {
+    let n = graph.len();
+    let mut in_cover = graph.looped.clone();
+    let mut matched = 0;
+    for v in 0..n {
+        if in_cover[v] {
+            continue;
+        }
+        if let Some(&w) = graph.neighbors[v].iter().find(|&&w| !in_cover[w]) {
+            in_cover[v] = true;
+            in_cover[w] = true;
+            matched += 1;
+        }
+    }
+    // Remove redundant nodes; the matched pairs keep at least one endpoint.
+    for v in 0..n {
+        if in_cover[v] && !graph.looped[v] && graph.neighbors[v].iter().all(|&w| in_cover[w]) {
+            in_cover[v] = false;
+        }
+    }
+    ((0..n).filter(|&v| in_cover[v]).collect(), matched)
+}
+
+/// Find a minimum dominating set of the graph.
+///
+/// Uses a branch and bound search: an undominated node with the fewest
+/// possible dominators is chosen, and the search branches on which of them
+/// dominates it. Branches are pruned with the lower bound given by the
+/// number of undominated nodes divided by the largest number of them a
+/// single node can dominate. The search starts from the result of
+/// [`approximate_minimum_dominating_set`].
+///
+/// # Arguments
+/// * `g`: an input graph, whose edge directions are ignored.
+///
+/// # Returns
+/// * `Vec`: the nodes of a minimum dominating set.
+///
+/// # Complexity
+/// * Time complexity: **O(Δ^|V| · |V|²)** in the worst case.
+/// * Auxiliary space: **O(|V|² + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **Δ** is the maximum degree.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::covering::minimum_dominating_set;
+/// use petgraph::graph::UnGraph;
+///
+/// // A path of 6 nodes is dominated by its second and fifth nodes.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+///
+/// let mut set = minimum_dominating_set(&graph);
+/// set.sort();
+/// assert_eq!(set, vec![1.into(), 4.into()]);
+/// ```
+pub fn minimum_dominating_set<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let graph = Adjacency::new(g);
+    let n = graph.len();
+    let mut search = DominatingSetSearch {
+        closed: graph.closed_bitsets(),
+        best: greedy_dominating_set(&graph),
+    };
+    let mut undominated = FixedBitSet::with_capacity(n);
+    undominated.insert_range(..);
+    let mut allowed = undominated.clone();
+    search.expand(&mut Vec::new(), &undominated, &mut allowed);
+    graph.to_nodes(search.best)
+}
+
+/// Find a small dominating set of the graph, at most **1 + ln(Δ + 1)** times
+/// as large as a minimum one.
+///
+/// The [greedy algorithm][1] repeatedly adds the node that dominates the most
+/// nodes not yet dominated, which gives the best approximation ratio possible
+/// in polynomial time, up to lower order terms. Nodes whose closed
+/// neighborhood is dominated by the other nodes of the set are then removed
+/// from it.
+///
+/// # Arguments
+/// * `g`: an input graph, whose edge directions are ignored.
+///
+/// # Returns
+/// * `Vec`: the nodes of a minimal dominating set.
+///
+/// # Complexity
+/// * Time complexity: **O((|V| + |E|) log|V|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **Δ** is the maximum degree.
+///
+/// [1]: https://doi.org/10.1016/S0022-0000(74)80044-9
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::covering::approximate_minimum_dominating_set;
+/// use petgraph::graph::UnGraph;
+///
+/// // Two stars joined by their centers.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3), (0, 4),
+///     (4, 5), (4, 6), (4, 7),
+/// ]);
+///
+/// let mut set = approximate_minimum_dominating_set(&graph);
+/// set.sort();
+/// assert_eq!(set, vec![0.into(), 4.into()]);
+/// ```
+pub fn approximate_minimum_dominating_set<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let graph = Adjacency::new(g);
+    graph.to_nodes(greedy_dominating_set(&graph))
+}
+
+/// Find a small dominating set of the graph, at most **1 + ln(Δ + 1)** times
+/// as large as a minimum one, and a lower bound on the size of a minimum
+/// one.
+///
+/// The set is the one of [`approximate_minimum_dominating_set`]. The lower
+/// bound is the largest of:
+/// * the number of nodes of a *2-packing* (a set of nodes whose closed
+///   neighborhoods are pairwise disjoint), found greedily: each of them must
+///   be dominated by a different node;
+/// * the size of the greedy set before the removal of redundant nodes,
+///   divided by the harmonic number **H(Δ + 1)**, which bounds the ratio of
+///   the [greedy algorithm][1];
+/// * **|V| / (Δ + 1)**, since a node dominates at most **Δ + 1** nodes.
+///
+/// # Arguments
+/// * `g`: an input graph, whose edge directions are ignored.
+///
+/// # Returns
+/// * [`BoundedCover`]: the nodes of a minimal dominating set, and a lower
+///   bound on the domination number.
+///
+/// # Complexity
+/// * Time complexity: **O((|V| + |E|) log|V|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **Δ** is the maximum degree.
+///
+/// [1]: https://doi.org/10.1016/S0022-0000(74)80044-9
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::covering::approximate_minimum_dominating_set_with_bound;
+/// use petgraph::graph::UnGraph;
+///
+/// // A path of 9 nodes is dominated by 3 nodes, and not fewer.
+/// let graph = UnGraph::<(), ()>::from_edges((0..8).map(|i| (i, i + 1)));
+///
+/// let set = approximate_minimum_dominating_set_with_bound(&graph);
+/// assert_eq!(set.nodes.len(), 3);
+/// assert_eq!(set.lower_bound, 3);
+/// assert_eq!(set.ratio(), 1.0);
+/// ```
+pub fn approximate_minimum_dominating_set_with_bound<G>(g: G) -> BoundedCover<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let graph = Adjacency::new(g);
+    let n = graph.len();
+    let greedy = greedy_dominators(&graph);
+    let max_closed = graph.neighbors.iter().map(|list| list.len() + 1).max();
+    let max_closed = max_closed.unwrap_or(1);
+    let harmonic: f64 = (1..=max_closed).map(|k| 1.0 / k as f64).sum();
+    // Round down small errors of the floating point division before rounding
+    // up, so that the bound stays valid.
+    let bound = greedy.len() as f64 / harmonic - 1e-9;
+    let mut greedy_bound = bound as usize;
+    if (greedy_bound as f64) < bound {
+        greedy_bound += 1;
+    }
+    let lower_bound = two_packing(&graph)
+        .max(greedy_bound)
+        .max((n + max_closed - 1) / max_closed);
+    BoundedCover {
+        nodes: graph.to_nodes(remove_redundant_dominators(&graph, greedy)),
+        lower_bound,
+    }
+}
+
+/// Return the size of a maximal 2-packing, chosen greedily by increasing
+/// degree.
+fn two_packing<N: Copy>(graph: &Adjacency<N>) -> usize {
+    let n = graph.len();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by_key(|&v| graph.neighbors[v].len());
+    // Nodes at distance at most 2 from a chosen node.
+    let mut blocked = vec![false; n];
+    let mut size = 0;
+    for v in order {
+        if blocked[v] {
+            continue;
+        }
+        size += 1;
+        blocked[v] = true;
+        for &w in &graph.neighbors[v] {
+            blocked[w] = true;
+            for &x in &graph.neighbors[w] {
+                blocked[x] = true;
+            }
+        }
+    }
+    size
+}
+
+fn greedy_dominating_set<N: Copy>(graph: &Adjacency<N>) -> Vec<usize> {
+    remove_redundant_dominators(graph, greedy_dominators(graph))
+}
+
+/// Return the dominating set chosen by the greedy algorithm, in the order the
+/// nodes were chosen.
+fn greedy_dominators<N: Copy>(graph: &Adjacency<N>) -> Vec<usize> {
+    let n = graph.len();
+    let mut dominated = vec![false; n];
+    // Number of nodes not yet dominated in the closed neighborhood.
+    let mut gain: Vec<usize> = (0..n).map(|v| graph.neighbors[v].len() + 1).collect();
+    let mut queue: BinaryHeap<(usize, Reverse<usize>)> =
+        (0..n).map(|v| (gain[v], Reverse(v))).collect();
+    let mut set = Vec::new();
+    // Gains only decrease, so outdated queue entries are refreshed lazily.
+    while let Some((g, Reverse(v))) = queue.pop() {
+        if g != gain[v] {
+            if gain[v] > 0 {
+                queue.push((gain[v], Reverse(v)));
+            }
+            continue;
+        }
+        if g == 0 {
+            break;
+        }
+        set.push(v);
+        for x in core::iter::once(v).chain(graph.neighbors[v].iter().copied()) {
+            if !dominated[x] {
+                dominated[x] = true;
+                gain[x] -= 1;
+                for &y in &graph.neighbors[x] {
+                    gain[y] -= 1;
+                }
+            }
+        }
+    }
+    set
+}
+
+/// Remove redundant nodes from a dominating set, the ones last in `set`
+/// first.
+fn remove_redundant_dominators<N: Copy>(graph: &Adjacency<N>, set: Vec<usize>) -> Vec<usize> {
+    let n = graph.len();
+    let mut dominators = vec![0usize; n];
+    for &v in &set {
+        dominators[v] += 1;
+        for &w in &graph.neighbors[v] {
+            dominators[w] += 1;
+        }
+    }
+    let mut in_set = vec![false; n];
+    for &v in &set {
+        in_set[v] = true;
+    }
+    for &v in set.iter().rev() {
+        let closed = || core::iter::once(v).chain(graph.neighbors[v].iter().copied());
+        if closed().all(|x| dominators[x] >= 2) {
+            in_set[v] = false;
+            for x in closed() {
+                dominators[x] -= 1;
+            }
+        }
+    }
+    (0..n).filter(|&v| in_set[v]).collect()
+}
+
+struct DominatingSetSearch {
+    /// The closed neighborhood of each node.
+    closed: Vec<FixedBitSet>,
+    best: Vec<usize>,
+}
+
+impl DominatingSetSearch {
+    /// Extend `set` to dominate `undominated`, using only `allowed` nodes.
+    fn expand(
+        &mut self,
+        set: &mut Vec<usize>,
+        undominated: &FixedBitSet,
+        allowed: &mut FixedBitSet,
+    ) {
+        let remaining = undominated.count_ones(..);
+        if remaining == 0 {
+            if set.len() < self.best.len() {
+                self.best = set.clone();
+            }
+            return;
+        }
+        let coverage = |w: usize| self.closed[w].intersection(undominated).count();
+        let max_coverage = allowed.ones().map(coverage).max().unwrap_or(0);
+        if max_coverage == 0 {
+            return;
+        }
+        let lower_bound = (remaining + max_coverage - 1) / max_coverage;
+        if set.len() + lower_bound >= self.best.len() {
+            return;
+        }
+
+        // Branch on the dominator of the node with the fewest choices. Each
+        // branch excludes the dominators tried before it.
+        let u = undominated
+            .ones()
+            .min_by_key(|&u| self.closed[u].intersection(allowed).count())
+            .unwrap();
+        let mut choices: Vec<usize> = self.closed[u].intersection(allowed).collect();
+        choices.sort_by_key(|&w| Reverse(coverage(w)));
+        for &w in &choices {
+            allowed.set(w, false);
+            let mut rest = undominated.clone();
+            rest.difference_with(&self.closed[w]);
+            set.push(w);
+            self.expand(set, &rest, allowed);
+            set.pop();
+        }
+        for &w in &choices {
+            allowed.insert(w);
+        }
+    }
+}
+
+fn exact_independent_set<N: Copy>(graph: &Adjacency<N>) -> Vec<usize> {
+    let mut search = IndependentSetSearch {
+        adjacency: graph.bitsets(),
+        best: local_search_independent_set(graph),
+    };
+    let mut candidates = FixedBitSet::with_capacity(graph.len());
+    candidates.extend((0..graph.len()).filter(|&v| !graph.looped[v]));
+    search.expand(&mut Vec::new(), candidates);
+    search.best
+}
+
+struct IndependentSetSearch {
+    adjacency: Vec<FixedBitSet>,
+    best: Vec<usize>,
+}
+
+impl IndependentSetSearch {
+    fn expand(&mut self, set: &mut Vec<usize>, mut candidates: FixedBitSet) {
+        let depth = set.len();
+        // Nodes with at most one candidate neighbor are always in some
+        // maximum independent set.
+        loop {
+            let low = candidates
+                .ones()
+                .find(|&v| self.adjacency[v].intersection(&candidates).take(2).count() <= 1);
+            match low {
+                Some(v) => {
+                    set.push(v);
+                    candidates.set(v, false);
+                    candidates.difference_with(&self.adjacency[v]);
+                }
+                None => break,
+            }
+        }
+
+        if candidates.is_clear() {
+            if set.len() > self.best.len() {
+                self.best = set.clone();
+            }
+        } else if set.len() + self.clique_cover_bound(&candidates) > self.best.len() {
+            let v = candidates
+                .ones()
+                .max_by_key(|&v| self.adjacency[v].intersection(&candidates).count())
+                .unwrap();
+
+            let mut with_v = candidates.clone();
+            with_v.set(v, false);
+            with_v.difference_with(&self.adjacency[v]);
+            set.push(v);
+            self.expand(set, with_v);
+            set.pop();
+
+            candidates.set(v, false);
+            self.expand(set, candidates);
+        }
+        set.truncate(depth);
+    }
+
+    /// Return the number of cliques of a greedy clique partition of
+    /// `candidates`.
+    fn clique_cover_bound(&self, candidates: &FixedBitSet) -> usize {
+        let mut cliques: Vec<FixedBitSet> = Vec::new();
+        for v in candidates.ones() {
+            match cliques.iter_mut().find(|c| c.is_subset(&self.adjacency[v])) {
+                Some(clique) => clique.insert(v),
+                None => {
+                    let mut clique = FixedBitSet::with_capacity(candidates.len());
+                    clique.insert(v);
+                    cliques.push(clique);
+                }
+            }
+        }
+        cliques.len()
+    }
+}
+
+fn local_search_independent_set<N: Copy>(graph: &Adjacency<N>) -> Vec<usize> {
+    let n = graph.len();
+    let mut in_set = vec![false; n];
+    // Number of neighbors of each node that are in the set.
+    let mut tightness = vec![0usize; n];
+
+    let mut order: Vec<usize> = (0..n).filter(|&v| !graph.looped[v]).collect();
+    order.sort_by_key(|&v| graph.neighbors[v].len());
+    let insert = |v: usize, in_set: &mut [bool], tightness: &mut [usize]| {
+        in_set[v] = true;
+        for &w in &graph.neighbors[v] {
+            tightness[w] += 1;
+        }
+    };
+    for &v in &order {
+        if tightness[v] == 0 && !in_set[v] {
+            insert(v, &mut in_set, &mut tightness);
+        }
+    }
+
+    // (1,2)-swaps: replace `x` with two non-adjacent neighbors whose only
+    // neighbor in the set is `x`.
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for x in 0..n {
+            if !in_set[x] {
+                continue;
+            }
+            let free: Vec<usize> = graph.neighbors[x]
+                .iter()
+                .copied()
+                .filter(|&v| tightness[v] == 1 && !graph.looped[v])
+                .collect();
+            let pair = free.iter().enumerate().find_map(|(i, &u)| {
+                free[i + 1..]
+                    .iter()
+                    .find(|&&w| !graph.is_adjacent(u, w))
+                    .map(|&w| (u, w))
+            });
+            if let Some((u, w)) = pair {
+                in_set[x] = false;
+                for &v in &graph.neighbors[x] {
+                    tightness[v] -= 1;
+                }
+                insert(u, &mut in_set, &mut tightness);
+                insert(w, &mut in_set, &mut tightness);
+                // Other neighbors of `x` may have become free.
+                for &v in &graph.neighbors[x] {
+                    if tightness[v] == 0 && !in_set[v] && !graph.looped[v] {
+                        insert(v, &mut in_set, &mut tightness);
+                    }
+                }
+                improved = true;
+            }
+        }
+    }
+    (0..n).filter(|&v| in_set[v]).collect()
+}
@@ -0,0 +1,435 @@
+//! The degree distribution of a graph, weighted degrees, and the rich-club
+//! coefficient, which measures how densely the nodes of high degree are
+//! linked to each other.
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use super::Measure;
+use crate::visit::{
+    Degree, EdgeRef, GraphProp, IntoEdgeReferences, IntoEdges, IntoEdgesDirected,
+    IntoNodeIdentifiers, NodeIndexable,
+};
+use crate::{Incoming, Outgoing};
+
+/// \[Generic\] Return the degree histogram of a graph: the number of nodes
+/// of each degree.
+///
+/// The degrees are those of [`Degree::degree`]: a self loop counts twice,
+/// parallel edges count separately, and the degree of a node of a directed
+/// graph is the sum of its in- and out-degree.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * The number of nodes of degree `k`, for `k` from `0` to the largest
+///   degree, or an empty vector for a graph without nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** for graphs that compute a degree in
+///   time proportional to it.
+/// * Auxiliary space: **O(Δ)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **Δ** is the largest degree.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::degree_distribution::degree_histogram;
+/// use petgraph::graph::UnGraph;
+///
+/// let mut star = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// star.add_node(());
+/// assert_eq!(degree_histogram(&star), [1, 3, 0, 1]);
+/// ```
+pub fn degree_histogram<G>(graph: G) -> Vec<usize>
+where
+    G: IntoNodeIdentifiers + Degree,
+{
+    let mut histogram = Vec::new();
+    for node in graph.node_identifiers() {
+        let degree = graph.degree(node);
+        if degree >= histogram.len() {
+            histogram.resize(degree + 1, 0);
+        }
+        histogram[degree] += 1;
+    }
+    histogram
+}
+
+/// \[Generic\] Return the complementary cumulative degree distribution of a
+/// graph: the fraction of the nodes of degree at least `k`, for each `k`.
+///
+/// It is the usual way to plot a heavy tailed degree distribution, on
+/// logarithmic scales, without binning. The degrees are those of
+/// [`degree_histogram`].
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * The fraction of the nodes of degree at least `k`, for `k` from `0`
+///   (where it is `1`) to the largest degree, or an empty vector for a graph
+///   without nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** for graphs that compute a degree in
+///   time proportional to it.
+/// * Auxiliary space: **O(Δ)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **Δ** is the largest degree.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::degree_distribution::degree_ccdf;
+/// use petgraph::graph::UnGraph;
+///
+/// let star = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(degree_ccdf(&star), [1.0, 1.0, 0.25, 0.25]);
+/// ```
+pub fn degree_ccdf<G>(graph: G) -> Vec<f64>
+where
+    G: IntoNodeIdentifiers + Degree,
+{
+    let histogram = degree_histogram(graph);
+    let total: usize = histogram.iter().sum();
+    let mut at_least = total;
+    histogram
+        .iter()
+        .map(|&count| {
+            let fraction = at_least as f64 / total as f64;
+            at_least -= count;
+            fraction
+        })
+        .collect()
+}
+
+/// \[Generic\] Return the degree sequence of a graph: the degrees of its
+/// nodes, from the largest to the smallest.
+///
+/// The degrees are those of [`Degree::degree`], as for
+/// [`degree_histogram`].
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * The degrees of the nodes, in non-increasing order.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| log |V| + |E|)** for graphs that compute a
+///   degree in time proportional to it.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::degree_distribution::degree_sequence;
+/// use petgraph::graph::UnGraph;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(degree_sequence(&path), [2, 2, 1, 1]);
+/// ```
+pub fn degree_sequence<G>(graph: G) -> Vec<usize>
+where
+    G: IntoNodeIdentifiers + Degree,
+{
+    let mut degrees: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.degree(node))
+        .collect();
+    degrees.sort_unstable_by(|a, b| b.cmp(a));
+    degrees
+}
+
+/// \[Generic\] Return the weighted degree of a node: the sum of the weights
+/// of its edges.
+///
+/// The edges are counted as for [`Degree::degree`]: a self loop counts
+/// twice, and the weighted degree of a node of a directed graph is the sum
+/// of the weights of its outgoing and incoming edges.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `node`: the node.
+/// * `edge_weight`: closure that returns the weight of an edge.
+///
+/// # Returns
+/// * The sum of the weights of the edges of `node`.
+///
+/// # Complexity
+/// * Time complexity: **O(e')**.
+/// * Auxiliary space: **O(1)**.
+///
+/// where **e'** is the number of edges of `node`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::degree_distribution::weighted_degree;
+/// use petgraph::graph::DiGraph;
+/// use petgraph::visit::EdgeRef;
+///
+/// let graph = DiGraph::<(), f64>::from_edges(&[(0, 1, 0.5), (2, 1, 2.0), (1, 3, 1.0)]);
+/// assert_eq!(weighted_degree(&graph, 1.into(), |e| *e.weight()), 3.5);
+/// ```
+pub fn weighted_degree<G, F, K>(graph: G, node: G::NodeId, mut edge_weight: F) -> K
+where
+    G: IntoEdgesDirected + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut degree = K::default();
+    if graph.is_directed() {
+        for edge in graph
+            .edges_directed(node, Outgoing)
+            .chain(graph.edges_directed(node, Incoming))
+        {
+            degree = degree + edge_weight(edge);
+        }
+    } else {
+        for edge in graph.edges(node) {
+            let weight = edge_weight(edge);
+            degree = degree + weight;
+            if edge.source() == edge.target() {
+                degree = degree + weight;
+            }
+        }
+    }
+    degree
+}
+
+/// \[Generic\] Return the weighted degrees of all the nodes of a graph, the
+/// sums of the weights of their edges, indexed by
+/// [`NodeIndexable::to_index`].
+///
+/// The edges are counted as for [`weighted_degree`], which this computes
+/// for all the nodes at once, also for graphs that only list the outgoing
+/// edges of a node, like [`Csr`](crate::csr::Csr).
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `edge_weight`: closure that returns the weight of an edge.
+///
+/// # Returns
+/// * The weighted degree of each node, at the position of its index, and
+///   the default value at the positions without a node.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::degree_distribution::weighted_degrees;
+/// use petgraph::csr::Csr;
+/// use petgraph::visit::EdgeRef;
+///
+/// let mut graph = Csr::<(), u32>::with_nodes(3);
+/// graph.add_edge(0, 1, 2);
+/// graph.add_edge(2, 1, 3);
+/// graph.add_edge(2, 2, 4);
+/// assert_eq!(weighted_degrees(&graph, |e| *e.weight()), [2, 5, 11]);
+/// ```
+pub fn weighted_degrees<G, F, K>(graph: G, mut edge_weight: F) -> Vec<K>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut degrees = vec![K::default(); graph.node_bound()];
+    let directed = graph.is_directed();
+    for node in graph.node_identifiers() {
+        let i = graph.to_index(node);
+        for edge in graph.edges(node) {
+            let weight = edge_weight(edge);
+            degrees[i] = degrees[i] + weight;
+            // The incoming edges of a directed graph, and the other end of
+            // the self loops of an undirected graph.
+            if directed || edge.source() == edge.target() {
+                let j = graph.to_index(edge.target());
+                degrees[j] = degrees[j] + weight;
+            }
+        }
+    }
+    degrees
+}
+
+/// The distinct edges of a graph, as pairs `(a, b)` of node indices with
+/// `a < b`, ignoring directions and loops.
+fn simple_edges<G>(graph: G) -> Vec<(usize, usize)>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut edges: Vec<(usize, usize)> = graph
+        .edge_references()
+        .map(|edge| (graph.to_index(edge.source()), graph.to_index(edge.target())))
+        .filter(|&(a, b)| a != b)
+        .map(|(a, b)| (a.min(b), a.max(b)))
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+    edges
+}
+
+/// Return the rich-club coefficients of the simple graph of `n` nodes with
+/// the edges `edges`.
+fn rich_club(n: usize, edges: &[(usize, usize)]) -> Vec<f64> {
+    let mut degree = vec![0; n];
+    for &(a, b) in edges {
+        degree[a] += 1;
+        degree[b] += 1;
+    }
+    // The number of nodes, and of edges between nodes, of degree greater
+    // than k, from the counts of nodes, and edges, whose smallest end has
+    // degree exactly k.
+    let max = degree.iter().copied().max().unwrap_or(0);
+    let mut nodes = vec![0usize; max + 1];
+    let mut links = vec![0usize; max + 1];
+    for &d in &degree {
+        nodes[d] += 1;
+    }
+    for &(a, b) in edges {
+        links[degree[a].min(degree[b])] += 1;
+    }
+    let (mut rich_nodes, mut rich_links) = (n, edges.len());
+    let mut coefficients = Vec::new();
+    for k in 0..=max {
+        rich_nodes -= nodes[k];
+        rich_links -= links[k];
+        if rich_nodes < 2 {
+            break;
+        }
+        let pairs = rich_nodes * (rich_nodes - 1) / 2;
+        coefficients.push(rich_links as f64 / pairs as f64);
+    }
+    coefficients
+}
+
+/// \[Generic\] Return the [rich-club coefficients][1] of a graph: for each
+/// degree `k`, the density of the subgraph induced by the nodes of degree
+/// greater than `k`.
+///
+/// The coefficient for `k` is **2 E_k / (N_k (N_k - 1))**, where **N_k** is
+/// the number of nodes of degree greater than `k` and **E_k** the number of
+/// edges between them. It is meant for simple undirected graphs: the
+/// directions of the edges are ignored, as well as self loops and parallel
+/// edges, also for the degrees. Since the coefficient grows with `k` even
+/// in random graphs, it is usually compared with the coefficient of
+/// random graphs with the same degrees, see
+/// [`normalized_rich_club_coefficient`].
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * The coefficient for each `k` from `0`, as long as at least two nodes
+///   have a degree greater than `k`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Rich-club_coefficient
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::degree_distribution::rich_club_coefficient;
+/// use petgraph::graph::UnGraph;
+///
+/// // A triangle of hubs, each with two leaves.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0),
+///     (0, 3), (0, 4), (1, 5), (1, 6), (2, 7), (2, 8),
+/// ]);
+/// let coefficients = rich_club_coefficient(&graph);
+/// assert_eq!(coefficients[0], 9.0 / 36.0);
+/// assert_eq!(coefficients[1..], [1.0, 1.0, 1.0]);
+/// ```
+pub fn rich_club_coefficient<G>(graph: G) -> Vec<f64>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    rich_club(graph.node_bound(), &simple_edges(graph))
+}
+
+/// \[Generic\] Return the rich-club coefficients of a graph, normalized by
+/// those of a random graph with the same degrees.
+///
+/// The random graph is obtained by `swaps_per_edge` times **|E|** attempts
+/// of double edge swaps, which replace two edges `a - b` and `c - d` by
+/// `a - d` and `c - b` and keep the degrees, and the graph simple. A
+/// normalized coefficient greater than `1` shows that the nodes of high
+/// degree are more densely linked than the degrees alone explain: a *rich
+/// club*. As for [`rich_club_coefficient`], the directions of the edges,
+/// self loops and parallel edges are ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `swaps_per_edge`: the number of swap attempts per edge, typically
+///   `10` to `100`.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * For each `k` from `0` as for [`rich_club_coefficient`], the ratio of
+///   the coefficients, or `None` where the coefficient of the random graph
+///   is `0`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| log |E| + s · |E|)** expected.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **s** is the number of swap attempts per edge.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::degree_distribution::normalized_rich_club_coefficient;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::SeededRng;
+///
+/// // Five hubs forming a clique, each with five leaves, and a ring
+/// // through the leaves.
+/// let mut edges = Vec::new();
+/// for hub in 0..5 {
+///     edges.extend((hub + 1..5).map(|other| (hub, other)));
+/// }
+/// for leaf in 5..30 {
+///     edges.push((leaf % 5, leaf));
+///     edges.push((leaf, if leaf == 29 { 5 } else { leaf + 1 }));
+/// }
+/// let graph = UnGraph::<(), ()>::from_edges(&edges);
+/// let normalized = normalized_rich_club_coefficient(&graph, 20, &mut SeededRng::new(1));
+/// // The hubs, the nodes of degree greater than 3, are more densely linked
+/// // than in random graphs with the same degrees.
+/// assert!(normalized[3].unwrap() > 1.0);
+/// ```
+#[cfg(feature = "rand")]
+pub fn normalized_rich_club_coefficient<G, R>(
+    graph: G,
+    swaps_per_edge: usize,
+    rng: &mut R,
+) -> Vec<Option<f64>>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    R: Rng + ?Sized,
+{
+    let n = graph.node_bound();
+    let mut edges = simple_edges(graph);
+    let coefficients = rich_club(n, &edges);
+    let attempts = swaps_per_edge.saturating_mul(edges.len());
+    crate::generators::double_edge_swaps(&mut edges, false, usize::MAX, attempts, rng);
+    coefficients
+        .iter()
+        .zip(rich_club(n, &edges))
+        .map(|(&coefficient, random)| (random > 0.0).then_some(coefficient / random))
+        .collect()
+}
@@ -1,4 +1,7 @@
 use alloc::collections::BinaryHeap;
+#[cfg(feature = "rayon")]
+use alloc::vec;
+use alloc::vec::Vec;
 use core::hash::Hash;
 
 use hashbrown::hash_map::{
@@ -8,6 +11,9 @@ use hashbrown::hash_map::{
 
 use crate::algo::Measure;
 use crate::scored::MinScored;
+use crate::trace::Counter;
+#[cfg(feature = "rayon")]
+use crate::visit::NodeIndexable;
 use crate::visit::{EdgeRef, IntoEdges, VisitMap, Visitable};
 
 /// Dijkstra's shortest path algorithm.
@@ -97,6 +103,9 @@ where
     F: FnMut(G::EdgeRef) -> K,
     K: Measure + Copy,
 {
+    trace_span!("dijkstra");
+    let mut expanded = Counter::default();
+    let mut relaxed = Counter::default();
     let mut visited = graph.visit_map();
     let mut scores = HashMap::new();
     //let mut predecessor = HashMap::new();
@@ -111,6 +120,7 @@ where
         if goal.as_ref() == Some(&node) {
             break;
         }
+        expanded.incr();
         for edge in graph.edges(node) {
             let next = edge.target();
             if visited.is_visited(&next) {
@@ -122,17 +132,179 @@ where
                     if next_score < *ent.get() {
                         *ent.into_mut() = next_score;
                         visit_next.push(MinScored(next_score, next));
+                        relaxed.incr();
                         //predecessor.insert(next.clone(), node.clone());
                     }
                 }
                 Vacant(ent) => {
                     ent.insert(next_score);
                     visit_next.push(MinScored(next_score, next));
+                    relaxed.incr();
                     //predecessor.insert(next.clone(), node.clone());
                 }
             }
         }
         visited.visit(node);
     }
+    trace_event!(
+        nodes_expanded = expanded.get(),
+        edges_relaxed = relaxed.get(),
+        "dijkstra finished"
+    );
     scores
 }
+
+/// Return the `k` nodes closest to `source` by shortest path distance.
+///
+/// Runs [`dijkstra`] from `source` and stops as soon as `k` other nodes are
+/// settled, so only the neighborhood of `source` is explored. The nodes are
+/// returned with their distances, in increasing order of distance; ties are
+/// broken arbitrarily. `source` itself is not included. Fewer than `k`
+/// nodes are returned if fewer are reachable. Edge costs must be
+/// non-negative.
+///
+/// # Arguments
+/// * `graph`: weighted graph.
+/// * `source`: the node to measure distances from.
+/// * `k`: the number of nodes to return.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * `Vec<(NodeId, K)>`: the closest nodes and their distances.
+///
+/// # Complexity
+/// * Time complexity: **O((|V'|+|E'|)log(|V'|))**.
+/// * Auxiliary space: **O(|V'|+|E'|)**.
+///
+/// where **|V'|** is the number of nodes at most as far as the `k`-th
+/// closest node and **|E'|** is the number of their edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::k_nearest_nodes;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // Stops along a line, with the travel time between them.
+/// let graph = UnGraph::<(), u32>::from_edges(&[(0, 1, 4), (1, 2, 1), (0, 3, 2), (3, 4, 9)]);
+/// let nearest = k_nearest_nodes(&graph, NodeIndex::new(0), 3, |e| *e.weight());
+/// assert_eq!(nearest, [(NodeIndex::new(3), 2), (NodeIndex::new(1), 4), (NodeIndex::new(2), 5)]);
+/// ```
+pub fn k_nearest_nodes<G, F, K>(
+    graph: G,
+    source: G::NodeId,
+    k: usize,
+    mut edge_cost: F,
+) -> Vec<(G::NodeId, K)>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut nearest = Vec::with_capacity(k);
+    if k == 0 {
+        return nearest;
+    }
+    let mut visited = graph.visit_map();
+    let mut scores = HashMap::new();
+    let mut visit_next = BinaryHeap::new();
+    scores.insert(source, K::default());
+    visit_next.push(MinScored(K::default(), source));
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if !visited.visit(node) {
+            continue;
+        }
+        if node != source {
+            nearest.push((node, node_score));
+            if nearest.len() == k {
+                break;
+            }
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+            let next_score = node_score + edge_cost(edge);
+            match scores.entry(next) {
+                Occupied(ent) => {
+                    if next_score < *ent.get() {
+                        *ent.into_mut() = next_score;
+                        visit_next.push(MinScored(next_score, next));
+                    }
+                }
+                Vacant(ent) => {
+                    ent.insert(next_score);
+                    visit_next.push(MinScored(next_score, next));
+                }
+            }
+        }
+    }
+    nearest
+}
+
+/// Dijkstra's shortest path algorithm from many sources, in parallel.
+///
+/// Run [`dijkstra`] from each node of `sources` independently, on a
+/// [`rayon`] thread pool that shares the graph, and return the distances as
+/// a source-major matrix: `distances[i][graph.to_index(v)]` is the length
+/// of the shortest path from `sources[i]` to `v`, or `None` if `v` is not
+/// reachable from it. Edge costs must be non-negative.
+///
+/// This is the building block of closeness centrality, Voronoi partitions
+/// and landmark selection, which all need the distances from many nodes: an
+/// all-to-many workload, where each source is an independent task.
+///
+/// # Arguments
+/// * `graph`: weighted graph.
+/// * `sources`: the start nodes.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * `Vec<Vec<Option<K>>>`: a row of distances for each source, indexed by
+///   node index.
+///
+/// # Complexity
+/// * Time complexity: **O(|S|(|V|+|E|)log(|V|))** work, split between the threads.
+/// * Auxiliary space: **O(|S||V| + |E|)**.
+///
+/// where **|S|** is the number of sources, **|V|** is the number of nodes and
+/// **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::par_multi_source_dijkstra;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let graph = UnGraph::<(), u32>::from_edges(&[(0, 1, 2), (1, 2, 3), (0, 2, 7), (3, 4, 1)]);
+/// let sources = [NodeIndex::new(0), NodeIndex::new(2)];
+/// let distances = par_multi_source_dijkstra(&graph, &sources, |e| *e.weight());
+/// assert_eq!(distances[0], [Some(0), Some(2), Some(5), None, None]);
+/// assert_eq!(distances[1], [Some(5), Some(3), Some(0), None, None]);
+/// ```
+#[cfg(feature = "rayon")]
+#[doc(alias = "batch_dijkstra")]
+pub fn par_multi_source_dijkstra<G, F, K>(
+    graph: G,
+    sources: &[G::NodeId],
+    edge_cost: F,
+) -> Vec<Vec<Option<K>>>
+where
+    G: IntoEdges + Visitable + NodeIndexable + Sync,
+    G::NodeId: Eq + Hash + Send + Sync,
+    F: Fn(G::EdgeRef) -> K + Sync,
+    K: Measure + Copy + Send,
+{
+    use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+    sources
+        .par_iter()
+        .map(|&source| {
+            let mut row = vec![None; graph.node_bound()];
+            for (node, distance) in dijkstra(graph, source, None, &edge_cost) {
+                row[graph.to_index(node)] = Some(distance);
+            }
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,298 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::hash::Hash;
+
+use hashbrown::{hash_map::Entry, HashMap, HashSet};
+use rand::Rng;
+
+use crate::algo::Measure;
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers};
+
+/// An approximate distance oracle, computed by [`distance_oracle`].
+///
+/// The oracle stores, for every node, its *pivots* (the nearest node of each
+/// sampled level) and its *bunch* (the nodes of each level that are closer
+/// than the pivot of the next level), which answers distance queries in
+/// **O(k)** time without storing all pairs distances.
+#[derive(Clone, Debug)]
+pub struct DistanceOracle<N, K> {
+    k: usize,
+    /// The nearest node of level `i` and its distance, for every level.
+    pivots: HashMap<N, Vec<Option<(N, K)>>>,
+    bunches: HashMap<N, HashMap<N, K>>,
+}
+
+impl<N, K> DistanceOracle<N, K>
+where
+    N: Copy + Eq + Hash,
+    K: Measure + Copy,
+{
+    /// Return the number of levels `k` of the oracle.
+    pub fn levels(&self) -> usize {
+        self.k
+    }
+
+    /// Return the stretch of the oracle, `2k - 1`: an upper bound of the
+    /// ratio between the estimated and the exact distances.
+    pub fn stretch(&self) -> usize {
+        2 * self.k - 1
+    }
+
+    /// Return an estimate of the distance between `a` and `b`, or `None` if
+    /// they are not connected or are not nodes of the graph.
+    ///
+    /// The estimate is never less than the exact distance, and at most
+    /// [`stretch`](Self::stretch) times larger. It is exact if `a` and `b`
+    /// are in each other's bunch, and in particular if `k` is `1`.
+    pub fn distance(&self, a: N, b: N) -> Option<K> {
+        let (mut u, mut v) = (a, b);
+        let mut w = u;
+        let mut dist_wu = K::default();
+        for level in 0..self.k {
+            if level > 0 {
+                core::mem::swap(&mut u, &mut v);
+                let (pivot, dist) = self.pivots.get(&u)?[level]?;
+                w = pivot;
+                dist_wu = dist;
+            }
+            if let Some(&dist_wv) = self.bunches.get(&v)?.get(&w) {
+                return Some(dist_wu + dist_wv);
+            }
+        }
+        None
+    }
+
+    /// Return the bunch of `node`: the nodes whose exact distance to `node`
+    /// is stored, with that distance.
+    pub fn bunch(&self, node: N) -> Option<&HashMap<N, K>> {
+        self.bunches.get(&node)
+    }
+
+    /// Return the total number of distances stored in the bunches, which is
+    /// expected to be **O(k|V|^(1+1/k))**.
+    pub fn size(&self) -> usize {
+        self.bunches.values().map(HashMap::len).sum()
+    }
+}
+
+/// Compute a [Thorup–Zwick][1] approximate distance oracle of an undirected
+/// graph.
+///
+/// The number of levels `k` trades space for accuracy: the oracle stores an
+/// expected **O(k|V|^(1+1/k))** distances, and answers queries in **O(k)**
+/// time with an estimate that is at most `2k - 1` times the exact distance.
+/// With `k = 1` the oracle stores all pairs distances; with `k = 2` it stores
+/// **O(|V|^1.5)** distances and has stretch 3.
+///
+/// Levels are sampled at random using `rng`, so the size of the oracle (but
+/// not its stretch guarantee) depends on the random number generator. The
+/// same seed gives the same oracle.
+///
+/// The graph should be undirected, or directed with symmetric edges. The
+/// function `edge_cost` should return the cost for a particular edge. Edge
+/// costs must be non-negative.
+///
+/// # Arguments
+/// * `graph`: an undirected weighted graph.
+/// * `k`: the number of levels, at least `1`.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+/// * `rng`: the random number generator used to sample the levels.
+///
+/// # Returns
+/// * [`DistanceOracle`]: the distance oracle.
+///
+/// # Panics
+/// Panics if `k` is `0`.
+///
+/// # Complexity
+/// * Time complexity: expected **O(k|V|^(1/k)(|E| + |V|log|V|)log|V|)**.
+/// * Auxiliary space: expected **O(k|V|^(1+1/k) + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://doi.org/10.1145/1044731.1044732
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::distance_oracle;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::SeededRng;
+///
+/// // A cycle of 100 nodes.
+/// let graph = UnGraph::<(), u32>::from_edges((0..100).map(|i| (i, (i + 1) % 100, 1)));
+///
+/// let oracle = distance_oracle(&graph, 2, |e| *e.weight(), &mut SeededRng::new(3));
+/// assert_eq!(oracle.stretch(), 3);
+///
+/// let estimate = oracle.distance(0.into(), 40.into()).unwrap();
+/// assert!((40..=120).contains(&estimate));
+/// assert_eq!(oracle.distance(7.into(), 7.into()), Some(0));
+/// ```
+pub fn distance_oracle<G, F, K, R>(
+    graph: G,
+    k: usize,
+    mut edge_cost: F,
+    rng: &mut R,
+) -> DistanceOracle<G::NodeId, K>
+where
+    G: IntoEdges + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+    R: Rng + ?Sized,
+{
+    assert!(k > 0, "a distance oracle needs at least one level");
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+
+    // levels[i] is the set A_i, with A_0 = V and A_k empty.
+    let probability = inverse_root(nodes.len(), k);
+    let mut levels: Vec<Vec<G::NodeId>> = vec![nodes.clone()];
+    for _ in 1..k {
+        let sample = levels
+            .last()
+            .unwrap()
+            .iter()
+            .copied()
+            .filter(|_| rng.gen_bool(probability))
+            .collect();
+        levels.push(sample);
+    }
+    levels.push(Vec::new());
+
+    let nearest: Vec<_> = levels
+        .iter()
+        .map(|level| nearest_sources(graph, level, &mut edge_cost))
+        .collect();
+
+    let mut pivots = HashMap::with_capacity(nodes.len());
+    for &node in &nodes {
+        let node_pivots = (0..k).map(|i| nearest[i].get(&node).copied()).collect();
+        pivots.insert(node, node_pivots);
+    }
+
+    // The bunch of v holds the nodes w of A_i \ A_(i+1) such that
+    // d(w, v) < d(A_(i+1), v). Equivalently, v is in the cluster of w.
+    let mut bunches: HashMap<G::NodeId, HashMap<G::NodeId, K>> =
+        nodes.iter().map(|&n| (n, HashMap::new())).collect();
+    for i in 0..k {
+        let next_level: HashSet<G::NodeId> = levels[i + 1].iter().copied().collect();
+        for &w in levels[i].iter().filter(|&w| !next_level.contains(w)) {
+            let bound = |v: G::NodeId| nearest[i + 1].get(&v).map(|&(_, d)| d);
+            for (v, dist) in cluster(graph, w, bound, &mut edge_cost) {
+                bunches.entry(v).or_default().insert(w, dist);
+            }
+        }
+    }
+
+    DistanceOracle { k, pivots, bunches }
+}
+
+/// Return `n^(-1/k)`, computed without floating point powers.
+fn inverse_root(n: usize, k: usize) -> f64 {
+    let n = n.max(1) as f64;
+    let (mut low, mut high) = (0.0f64, 1.0f64);
+    for _ in 0..64 {
+        let mid = (low + high) / 2.0;
+        let power = (0..k).fold(1.0, |acc, _| acc * mid);
+        if power * n < 1.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    high
+}
+
+/// Multi-source Dijkstra: the nearest source of every reachable node, with
+/// its distance.
+fn nearest_sources<G, F, K>(
+    graph: G,
+    sources: &[G::NodeId],
+    edge_cost: &mut F,
+) -> HashMap<G::NodeId, (G::NodeId, K)>
+where
+    G: IntoEdges,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut nearest = HashMap::new();
+    let mut visit_next = BinaryHeap::new();
+    for &source in sources {
+        nearest.insert(source, (source, K::default()));
+        visit_next.push(MinScored(K::default(), (source, source)));
+    }
+    let mut settled = HashSet::new();
+    while let Some(MinScored(dist, (node, source))) = visit_next.pop() {
+        if !settled.insert(node) {
+            continue;
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            let next_dist = dist + edge_cost(edge);
+            match nearest.entry(next) {
+                Entry::Occupied(mut ent) => {
+                    if next_dist < ent.get().1 {
+                        ent.insert((source, next_dist));
+                        visit_next.push(MinScored(next_dist, (next, source)));
+                    }
+                }
+                Entry::Vacant(ent) => {
+                    ent.insert((source, next_dist));
+                    visit_next.push(MinScored(next_dist, (next, source)));
+                }
+            }
+        }
+    }
+    nearest
+}
+
+/// Dijkstra from `center`, restricted to the nodes `v` that are strictly
+/// closer to `center` than `bound(v)` (no bound means infinity).
+fn cluster<G, B, F, K>(
+    graph: G,
+    center: G::NodeId,
+    bound: B,
+    edge_cost: &mut F,
+) -> HashMap<G::NodeId, K>
+where
+    G: IntoEdges,
+    G::NodeId: Eq + Hash,
+    B: Fn(G::NodeId) -> Option<K>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let within = |v: G::NodeId, dist: K| bound(v).map_or(true, |b| dist < b);
+    let mut distances = HashMap::new();
+    let mut settled = HashSet::new();
+    let mut visit_next = BinaryHeap::new();
+    if within(center, K::default()) {
+        distances.insert(center, K::default());
+        visit_next.push(MinScored(K::default(), center));
+    }
+    while let Some(MinScored(dist, node)) = visit_next.pop() {
+        if !settled.insert(node) {
+            continue;
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            let next_dist = dist + edge_cost(edge);
+            if !within(next, next_dist) {
+                continue;
+            }
+            match distances.entry(next) {
+                Entry::Occupied(mut ent) => {
+                    if next_dist < *ent.get() {
+                        ent.insert(next_dist);
+                        visit_next.push(MinScored(next_dist, next));
+                    }
+                }
+                Entry::Vacant(ent) => {
+                    ent.insert(next_dist);
+                    visit_next.push(MinScored(next_dist, next));
+                }
+            }
+        }
+    }
+    distances
+}
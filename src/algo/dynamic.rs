@@ -0,0 +1,622 @@
+//! Fully dynamic connectivity of an undirected graph.
+//!
+//! [`DynamicConnectivity`] tells whether two nodes are connected while edges
+//! are inserted and deleted, without traversing the graph after every change.
+//! When the edges are only ever inserted, [`UnionFind`] does the same more
+//! simply.
+//!
+//! [`UnionFind`]: crate::unionfind::UnionFind
+
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::graph::IndexType;
+use crate::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+const NIL: usize = usize::MAX;
+
+/// The flag of the vertices with tree edges of the level of the forest.
+const TREE: u8 = 1;
+/// The flag of the vertices with non-tree edges of the level of the forest.
+const NON_TREE: u8 = 2;
+
+/// A node of the treap of an Euler tour: a vertex, or an arc of a tree edge.
+#[derive(Clone, Debug)]
+struct TourNode {
+    left: usize,
+    right: usize,
+    parent: usize,
+    priority: u64,
+    /// The number of nodes of the subtree.
+    count: usize,
+    /// The number of vertices of the subtree.
+    vertices: usize,
+    /// The vertex, or `NIL` for an arc.
+    vertex: usize,
+    flags: u8,
+    /// The union of the flags of the subtree.
+    subtree_flags: u8,
+}
+
+/// The spanning forest of a level, as the Euler tours of its trees.
+#[derive(Clone, Debug, Default)]
+struct Level {
+    /// The tour node of each vertex, or `NIL` for the vertices that have
+    /// never had a tree edge of the level, alone in their tree.
+    vertices: Vec<usize>,
+    /// The tour nodes of the arcs `(a, b)` and `(b, a)` of each tree edge.
+    arcs: HashMap<(usize, usize), usize>,
+}
+
+/// The edges of a vertex, of a level.
+#[derive(Clone, Debug, Default)]
+struct Adjacent {
+    tree: HashSet<usize>,
+    non_tree: HashSet<usize>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct EdgeState {
+    level: usize,
+    tree: bool,
+}
+
+/// A fully dynamic connectivity structure for an undirected graph, with the
+/// algorithm of [Holm, de Lichtenberg and Thorup][1].
+///
+/// It tracks *n* nodes indexed from *0* to *n - 1*, with the scalar type
+/// `K`, and a set of undirected edges between them, without self loops or
+/// parallel edges. It keeps a spanning forest of the graph, as Euler tour
+/// trees: a deleted tree edge is replaced by another edge between its two
+/// sides, if there is one, searched for from the smaller side and raising
+/// the level of the edges that are examined, so that each edge is examined
+/// at most **O(log n)** times.
+///
+/// Queries compute in **O(log n)** expected time, and updates in
+/// **O(log² n)** expected amortized time.
+///
+/// [1]: https://doi.org/10.1145/502090.502095
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::dynamic::DynamicConnectivity;
+///
+/// let mut connectivity = DynamicConnectivity::<usize>::new(4);
+/// connectivity.insert_edge(0, 1);
+/// connectivity.insert_edge(1, 2);
+/// connectivity.insert_edge(2, 0);
+/// connectivity.insert_edge(2, 3);
+/// assert!(connectivity.connected(0, 3));
+///
+/// // The cycle keeps 0 and 1 connected, but 3 is cut off.
+/// connectivity.delete_edge(0, 1);
+/// connectivity.delete_edge(2, 3);
+/// assert!(connectivity.connected(0, 1));
+/// assert!(!connectivity.connected(0, 3));
+/// assert_eq!(connectivity.component_count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DynamicConnectivity<K> {
+    nodes: Vec<TourNode>,
+    free_nodes: Vec<usize>,
+    seed: u64,
+    /// The spanning forest of the edges of each level and above.
+    levels: Vec<Level>,
+    /// The edges of each vertex, by level.
+    adjacency: Vec<Vec<Adjacent>>,
+    edges: HashMap<(usize, usize), EdgeState>,
+    components: usize,
+    ty: PhantomData<K>,
+}
+
+impl<K> Default for DynamicConnectivity<K> {
+    fn default() -> Self {
+        DynamicConnectivity {
+            nodes: Vec::new(),
+            free_nodes: Vec::new(),
+            seed: 0x9E37_79B9_7F4A_7C15,
+            levels: Vec::new(),
+            adjacency: Vec::new(),
+            edges: HashMap::new(),
+            components: 0,
+            ty: PhantomData,
+        }
+    }
+}
+
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    (a.min(b), a.max(b))
+}
+
+impl<K> DynamicConnectivity<K>
+where
+    K: IndexType,
+{
+    /// Create a new `DynamicConnectivity` of `n` nodes, without edges.
+    pub fn new(n: usize) -> Self {
+        DynamicConnectivity {
+            adjacency: vec![Vec::new(); n],
+            components: n,
+            ..DynamicConnectivity::default()
+        }
+    }
+
+    /// Create a `DynamicConnectivity` with the nodes and edges of an
+    /// undirected graph, with the node indices of
+    /// [`NodeIndexable::to_index`].
+    ///
+    /// The indices without a node are isolated nodes. Self loops and
+    /// parallel edges are ignored.
+    pub fn from_graph<G>(graph: G) -> Self
+    where
+        G: IntoEdgeReferences + NodeIndexable,
+    {
+        let mut connectivity = DynamicConnectivity::new(graph.node_bound());
+        for edge in graph.edge_references() {
+            connectivity.insert_edge(
+                K::new(graph.to_index(edge.source())),
+                K::new(graph.to_index(edge.target())),
+            );
+        }
+        connectivity
+    }
+
+    /// Return the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Return the number of edges.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Return the number of connected components.
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Add a new node, without edges, and return its index.
+    pub fn add_node(&mut self) -> K {
+        let index = self.adjacency.len();
+        self.adjacency.push(Vec::new());
+        for level in &mut self.levels {
+            level.vertices.push(NIL);
+        }
+        self.components += 1;
+        K::new(index)
+    }
+
+    /// Return `true` if there is an edge between `a` and `b`.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn contains_edge(&self, a: K, b: K) -> bool {
+        let (a, b) = self.check_bounds(a, b);
+        self.edges.contains_key(&edge_key(a, b))
+    }
+
+    /// Return `true` if `a` and `b` are connected.
+    ///
+    /// Computes in **O(log n)** expected time.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn connected(&self, a: K, b: K) -> bool {
+        let (a, b) = self.check_bounds(a, b);
+        self.same_tree(0, a, b)
+    }
+
+    /// Return the number of nodes of the connected component of `a`.
+    ///
+    /// **Panics** if `a` is out of bounds.
+    #[track_caller]
+    pub fn component_size(&self, a: K) -> usize {
+        let (a, _) = self.check_bounds(a, a);
+        self.tree_size(0, a)
+    }
+
+    /// Insert an edge between `a` and `b`.
+    ///
+    /// Return `false` if the edge is already there, or if `a` and `b` are the
+    /// same node, in which case nothing changes.
+    ///
+    /// Computes in **O(log² n)** expected amortized time.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn insert_edge(&mut self, a: K, b: K) -> bool {
+        let (a, b) = self.check_bounds(a, b);
+        if a == b || self.edges.contains_key(&edge_key(a, b)) {
+            return false;
+        }
+        let tree = !self.same_tree(0, a, b);
+        self.edges
+            .insert(edge_key(a, b), EdgeState { level: 0, tree });
+        if tree {
+            self.link(0, a, b);
+            self.add_edge_at(0, a, b, true);
+            self.components -= 1;
+        } else {
+            self.add_edge_at(0, a, b, false);
+        }
+        true
+    }
+
+    /// Delete the edge between `a` and `b`.
+    ///
+    /// Return `false` if there is no such edge.
+    ///
+    /// Computes in **O(log² n)** expected amortized time.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn delete_edge(&mut self, a: K, b: K) -> bool {
+        let (a, b) = self.check_bounds(a, b);
+        let state = match self.edges.remove(&edge_key(a, b)) {
+            Some(state) => state,
+            None => return false,
+        };
+        self.remove_edge_at(state.level, a, b, state.tree);
+        if !state.tree {
+            return true;
+        }
+        for level in 0..=state.level {
+            self.cut(level, a, b);
+        }
+        for level in (0..=state.level).rev() {
+            if self.replace(level, a, b) {
+                return true;
+            }
+        }
+        self.components += 1;
+        true
+    }
+
+    #[track_caller]
+    fn check_bounds(&self, a: K, b: K) -> (usize, usize) {
+        let (a, b) = (a.index(), b.index());
+        let n = self.adjacency.len();
+        assert!(
+            a < n && b < n,
+            "DynamicConnectivity: node index out of bounds"
+        );
+        (a, b)
+    }
+
+    /// Look for an edge to replace the deleted tree edge between `a` and
+    /// `b` of level `level`, and return `true` if there is one.
+    fn replace(&mut self, level: usize, a: usize, b: usize) -> bool {
+        let (small, big) = if self.tree_size(level, a) <= self.tree_size(level, b) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        // Raise the tree edges of the smaller tree, which is at most half as
+        // large as the trees of the level can be, to the next level.
+        while let Some(x) = self.find_flagged(level, small, TREE) {
+            let others: Vec<usize> = self.adjacency[x][level].tree.iter().copied().collect();
+            for y in others {
+                self.remove_edge_at(level, x, y, true);
+                self.edges.get_mut(&edge_key(x, y)).unwrap().level = level + 1;
+                self.link(level + 1, x, y);
+                self.add_edge_at(level + 1, x, y, true);
+            }
+        }
+        // Each non-tree edge of the smaller tree either reconnects it, or is
+        // raised to the next level.
+        while let Some(x) = self.find_flagged(level, small, NON_TREE) {
+            let others: Vec<usize> = self.adjacency[x][level].non_tree.iter().copied().collect();
+            for y in others {
+                self.remove_edge_at(level, x, y, false);
+                let reconnects = self.same_tree(level, y, big);
+                let state = self.edges.get_mut(&edge_key(x, y)).unwrap();
+                if reconnects {
+                    state.tree = true;
+                    for l in 0..=level {
+                        self.link(l, x, y);
+                    }
+                    self.add_edge_at(level, x, y, true);
+                    return true;
+                }
+                state.level = level + 1;
+                self.add_edge_at(level + 1, x, y, false);
+            }
+        }
+        false
+    }
+
+    fn add_edge_at(&mut self, level: usize, a: usize, b: usize, tree: bool) {
+        for (x, y) in [(a, b), (b, a)] {
+            let adjacent = &mut self.adjacency[x];
+            if adjacent.len() <= level {
+                adjacent.resize_with(level + 1, Adjacent::default);
+            }
+            if tree {
+                adjacent[level].tree.insert(y);
+            } else {
+                adjacent[level].non_tree.insert(y);
+            }
+            self.refresh_flags(level, x);
+        }
+    }
+
+    fn remove_edge_at(&mut self, level: usize, a: usize, b: usize, tree: bool) {
+        for (x, y) in [(a, b), (b, a)] {
+            let adjacent = &mut self.adjacency[x][level];
+            if tree {
+                adjacent.tree.remove(&y);
+            } else {
+                adjacent.non_tree.remove(&y);
+            }
+            self.refresh_flags(level, x);
+        }
+    }
+
+    /// Update the flags of the tour node of `v` at `level` from its edges.
+    fn refresh_flags(&mut self, level: usize, v: usize) {
+        let flags = match self.adjacency[v].get(level) {
+            Some(adjacent) => {
+                (if adjacent.tree.is_empty() { 0 } else { TREE })
+                    | (if adjacent.non_tree.is_empty() {
+                        0
+                    } else {
+                        NON_TREE
+                    })
+            }
+            None => 0,
+        };
+        let mut x = if flags == 0 {
+            match self.levels.get(level) {
+                Some(forest) => forest.vertices[v],
+                None => NIL,
+            }
+        } else {
+            self.vertex_node(level, v)
+        };
+        if x == NIL {
+            return;
+        }
+        self.nodes[x].flags = flags;
+        while x != NIL {
+            self.update(x);
+            x = self.nodes[x].parent;
+        }
+    }
+
+    /// Return a vertex of the tree of `v` at `level` with the flag `flag`.
+    fn find_flagged(&self, level: usize, v: usize, flag: u8) -> Option<usize> {
+        let mut x = self.tree_root(level, v);
+        if x == NIL || self.nodes[x].subtree_flags & flag == 0 {
+            return None;
+        }
+        loop {
+            let node = &self.nodes[x];
+            if node.flags & flag != 0 {
+                return Some(node.vertex);
+            }
+            x = if node.left != NIL && self.nodes[node.left].subtree_flags & flag != 0 {
+                node.left
+            } else {
+                node.right
+            };
+        }
+    }
+
+    fn tree_root(&self, level: usize, v: usize) -> usize {
+        match self.levels.get(level) {
+            Some(forest) if forest.vertices[v] != NIL => self.root(forest.vertices[v]),
+            _ => NIL,
+        }
+    }
+
+    fn same_tree(&self, level: usize, a: usize, b: usize) -> bool {
+        if a == b {
+            return true;
+        }
+        let (root_a, root_b) = (self.tree_root(level, a), self.tree_root(level, b));
+        root_a != NIL && root_a == root_b
+    }
+
+    fn tree_size(&self, level: usize, v: usize) -> usize {
+        match self.tree_root(level, v) {
+            NIL => 1,
+            root => self.nodes[root].vertices,
+        }
+    }
+
+    /// Return the tour node of `v` at `level`, creating it if needed.
+    fn vertex_node(&mut self, level: usize, v: usize) -> usize {
+        while self.levels.len() <= level {
+            self.levels.push(Level {
+                vertices: vec![NIL; self.adjacency.len()],
+                arcs: HashMap::new(),
+            });
+        }
+        match self.levels[level].vertices[v] {
+            NIL => {
+                let x = self.new_node(v);
+                self.levels[level].vertices[v] = x;
+                x
+            }
+            x => x,
+        }
+    }
+
+    /// Make the tour of the tree of `v` at `level` start at `v`, and return
+    /// its root.
+    fn reroot(&mut self, level: usize, v: usize) -> usize {
+        let x = self.vertex_node(level, v);
+        let root = self.root(x);
+        let (before, after) = self.split(root, self.position(x));
+        self.merge(after, before)
+    }
+
+    /// Join the trees of `a` and `b` at `level` with a tree edge.
+    fn link(&mut self, level: usize, a: usize, b: usize) {
+        let tour_a = self.reroot(level, a);
+        let tour_b = self.reroot(level, b);
+        let arc_ab = self.new_node(NIL);
+        let arc_ba = self.new_node(NIL);
+        let arcs = &mut self.levels[level].arcs;
+        arcs.insert((a, b), arc_ab);
+        arcs.insert((b, a), arc_ba);
+        let tour = self.merge(tour_a, arc_ab);
+        let tour = self.merge(tour, tour_b);
+        self.merge(tour, arc_ba);
+    }
+
+    /// Remove the tree edge between `a` and `b` at `level`.
+    fn cut(&mut self, level: usize, a: usize, b: usize) {
+        let arcs = &mut self.levels[level].arcs;
+        let arc_ab = arcs.remove(&(a, b)).unwrap();
+        let arc_ba = arcs.remove(&(b, a)).unwrap();
+        let root = self.root(arc_ab);
+        let (mut first, mut second) = (self.position(arc_ab), self.position(arc_ba));
+        if first > second {
+            core::mem::swap(&mut first, &mut second);
+        }
+        // The tour is `left, arc, inner, arc, right`, where `inner` is the
+        // tour of one side, and `right, left` the tour of the other.
+        let (left, rest) = self.split(root, first);
+        let (middle, right) = self.split(rest, second - first + 1);
+        let (_, middle) = self.split(middle, 1);
+        let (_inner, _) = self.split(middle, self.nodes[middle].count - 1);
+        self.free_nodes.extend([arc_ab, arc_ba]);
+        self.merge(left, right);
+    }
+
+    fn new_node(&mut self, vertex: usize) -> usize {
+        // xorshift64
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        let node = TourNode {
+            left: NIL,
+            right: NIL,
+            parent: NIL,
+            priority: self.seed,
+            count: 1,
+            vertices: (vertex != NIL) as usize,
+            vertex,
+            flags: 0,
+            subtree_flags: 0,
+        };
+        match self.free_nodes.pop() {
+            Some(x) => {
+                self.nodes[x] = node;
+                x
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn update(&mut self, x: usize) {
+        let node = &self.nodes[x];
+        let mut count = 1;
+        let mut vertices = (node.vertex != NIL) as usize;
+        let mut subtree_flags = node.flags;
+        for child in [node.left, node.right] {
+            if child != NIL {
+                let child = &self.nodes[child];
+                count += child.count;
+                vertices += child.vertices;
+                subtree_flags |= child.subtree_flags;
+            }
+        }
+        let node = &mut self.nodes[x];
+        node.count = count;
+        node.vertices = vertices;
+        node.subtree_flags = subtree_flags;
+    }
+
+    fn root(&self, mut x: usize) -> usize {
+        while self.nodes[x].parent != NIL {
+            x = self.nodes[x].parent;
+        }
+        x
+    }
+
+    /// Return the position of `x` in its tour.
+    fn position(&self, mut x: usize) -> usize {
+        let count = |x: usize| if x == NIL { 0 } else { self.nodes[x].count };
+        let mut position = count(self.nodes[x].left);
+        while self.nodes[x].parent != NIL {
+            let parent = self.nodes[x].parent;
+            if self.nodes[parent].right == x {
+                position += count(self.nodes[parent].left) + 1;
+            }
+            x = parent;
+        }
+        position
+    }
+
+    /// Concatenate the tours of the roots `a` and `b`, and return the root.
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if self.nodes[a].priority > self.nodes[b].priority {
+            let right = self.merge(self.nodes[a].right, b);
+            self.nodes[a].right = right;
+            self.nodes[right].parent = a;
+            self.update(a);
+            a
+        } else {
+            let left = self.merge(a, self.nodes[b].left);
+            self.nodes[b].left = left;
+            self.nodes[left].parent = b;
+            self.update(b);
+            b
+        }
+    }
+
+    /// Split the tour of the root `x` after its first `k` nodes, and return
+    /// the roots of both parts.
+    fn split(&mut self, x: usize, k: usize) -> (usize, usize) {
+        let (a, b) = self.split_rec(x, k);
+        for root in [a, b] {
+            if root != NIL {
+                self.nodes[root].parent = NIL;
+            }
+        }
+        (a, b)
+    }
+
+    fn split_rec(&mut self, x: usize, k: usize) -> (usize, usize) {
+        if x == NIL {
+            return (NIL, NIL);
+        }
+        let left = self.nodes[x].left;
+        let left_count = if left == NIL {
+            0
+        } else {
+            self.nodes[left].count
+        };
+        if k <= left_count {
+            let (a, b) = self.split_rec(left, k);
+            self.nodes[x].left = b;
+            if b != NIL {
+                self.nodes[b].parent = x;
+            }
+            self.update(x);
+            (a, x)
+        } else {
+            let (a, b) = self.split_rec(self.nodes[x].right, k - left_count - 1);
+            self.nodes[x].right = a;
+            if a != NIL {
+                self.nodes[a].parent = x;
+            }
+            self.update(x);
+            (x, b)
+        }
+    }
+}
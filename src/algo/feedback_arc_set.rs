@@ -1,11 +1,12 @@
-use alloc::{collections::VecDeque, vec::Vec};
+use alloc::{collections::VecDeque, vec, vec::Vec};
 use core::ops::{Index, IndexMut};
 
 use hashbrown::HashMap;
 
 use crate::{
-    graph::{GraphIndex, NodeIndex},
-    visit::{EdgeRef, GraphProp, IntoEdgeReferences},
+    algo::{tarjan_scc, Measure},
+    graph::{DiGraph, GraphIndex, NodeIndex},
+    visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable},
     Directed,
 };
 
@@ -14,8 +15,9 @@ use self::linked_list::{LinkedList, LinkedListEntry};
 /// Finds a [feedback arc set]: a set of edges in the given directed graph, which when
 /// removed, make the graph acyclic.
 ///
-/// Uses a [greedy heuristic algorithm] to select a small number of edges, but does not necessarily
-/// find the minimum feedback arc set.
+/// Uses the [greedy heuristic algorithm] of Eades, Lin and Smyth to select a small number of edges,
+/// but does not necessarily find the minimum feedback arc set. Use [`exact_feedback_arc_set`] to
+/// find a minimum one in small graphs.
 ///
 /// Does not consider edge/node weights when selecting edges for the feedback arc set.
 ///
@@ -87,6 +89,177 @@ where
         .filter(move |e| node_seq[&e.source().index()] >= node_seq[&e.target().index()])
 }
 
+/// Finds a minimum weight [feedback arc set]: a set of edges in the given directed graph, which
+/// when removed, make the graph acyclic, and whose total cost is as small as possible.
+///
+/// A feedback arc set is the set of backward edges of some ordering of the nodes, so this function
+/// searches for the best ordering by dynamic programming over the subsets of nodes, independently
+/// in each strongly connected component. The problem is NP-hard and the running time is
+/// exponential in the size of the largest strongly connected component, so it is only practical
+/// when every component has at most about 20 nodes. For larger graphs, use
+/// [`greedy_feedback_arc_set`].
+///
+/// The function `edge_cost` should return the cost for a particular edge; use `|_| 1` to minimize
+/// the number of edges. Edge costs must be non-negative.
+///
+/// Loops (edges to and from the same node) are always included in the returned set.
+///
+/// # Arguments
+/// * `g`: a directed graph.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * `Vec`: the edge references `G::EdgeRef` in the feedback arc set.
+///
+/// # Panics
+/// Panics if a strongly connected component has more than 25 nodes, as the tables of the dynamic
+/// program would then take gigabytes of memory.
+///
+/// # Complexity
+/// * Time complexity: **O(2^|C| · (|V| + |E|))**.
+/// * Auxiliary space: **O(2^|C| + |V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and **|C|** is the number
+/// of nodes of the largest strongly connected component.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::{
+///     algo::{exact_feedback_arc_set, greedy_feedback_arc_set, is_cyclic_directed},
+///     graph::DiGraph,
+///     visit::EdgeRef,
+/// };
+///
+/// let mut g: DiGraph<(), u32> = DiGraph::from_edges(&[
+///     (0, 1, 1),
+///     (1, 2, 1),
+///     (2, 0, 5),
+///     (2, 3, 1),
+///     (3, 1, 1),
+/// ]);
+///
+/// let fas: Vec<_> = exact_feedback_arc_set(&g, |e| *e.weight())
+///     .iter()
+///     .map(|e| e.id())
+///     .collect();
+/// // Removing the edge from 1 to 2 breaks both cycles.
+/// assert_eq!(fas.len(), 1);
+/// assert_eq!(g.edge_endpoints(fas[0]), Some((1.into(), 2.into())));
+///
+/// g.remove_edge(fas[0]);
+/// assert!(!is_cyclic_directed(&g));
+/// ```
+///
+/// [feedback arc set]: https://en.wikipedia.org/wiki/Feedback_arc_set
+pub fn exact_feedback_arc_set<G, F, K>(g: G, mut edge_cost: F) -> Vec<G::EdgeRef>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp<EdgeType = Directed>,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut position = vec![usize::MAX; g.node_bound()];
+    let mut condensed = DiGraph::<(), ()>::new();
+    for node in g.node_identifiers() {
+        position[g.to_index(node)] = condensed.add_node(()).index();
+    }
+    let edges: Vec<(usize, usize)> = g
+        .edge_references()
+        .map(|e| {
+            (
+                position[g.to_index(e.source())],
+                position[g.to_index(e.target())],
+            )
+        })
+        .collect();
+    for &(a, b) in &edges {
+        condensed.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+    }
+
+    // Index of the component of each node, and rank of the node in the best
+    // ordering of its component.
+    let sccs = tarjan_scc(&condensed);
+    let mut component = vec![0; condensed.node_count()];
+    let mut local = vec![0; condensed.node_count()];
+    for (c, scc) in sccs.iter().enumerate() {
+        for (i, node) in scc.iter().enumerate() {
+            component[node.index()] = c;
+            local[node.index()] = i;
+        }
+    }
+    let mut out_edges: Vec<Vec<(usize, K)>> = vec![Vec::new(); condensed.node_count()];
+    for (e, &(a, b)) in g.edge_references().zip(&edges) {
+        if a != b && component[a] == component[b] {
+            out_edges[a].push((local[b], edge_cost(e)));
+        }
+    }
+
+    let mut rank = vec![0; condensed.node_count()];
+    for scc in sccs.iter().filter(|scc| scc.len() > 1) {
+        let out: Vec<&[(usize, K)]> = scc.iter().map(|n| &out_edges[n.index()][..]).collect();
+        for (r, i) in best_ordering(&out).into_iter().enumerate() {
+            rank[scc[i].index()] = r;
+        }
+    }
+
+    g.edge_references()
+        .zip(edges)
+        .filter(|&(_, (a, b))| a == b || (component[a] == component[b] && rank[a] > rank[b]))
+        .map(|(e, _)| e)
+        .collect()
+}
+
+/// Largest strongly connected component accepted by [`exact_feedback_arc_set`].
+const MAX_COMPONENT_SIZE: usize = 25;
+
+/// Find an ordering of the nodes that minimizes the cost of the backward
+/// edges, where `out[v]` lists the targets and costs of the edges from `v`.
+fn best_ordering<K: Measure + Copy>(out: &[&[(usize, K)]]) -> Vec<usize> {
+    let k = out.len();
+    assert!(
+        k <= MAX_COMPONENT_SIZE,
+        "strongly connected component too large for an exact feedback arc set"
+    );
+    let full = (1usize << k) - 1;
+    // `best[mask]` is the minimum cost of placing the nodes of `mask` first,
+    // and `last[mask]` the last of them in such an ordering.
+    let mut best: Vec<Option<K>> = vec![None; full + 1];
+    let mut last = vec![0u8; full + 1];
+    best[0] = Some(K::default());
+    for mask in 0..full {
+        let cost = match best[mask] {
+            Some(cost) => cost,
+            None => continue,
+        };
+        for (v, edges) in out
+            .iter()
+            .enumerate()
+            .filter(|&(v, _)| mask & (1 << v) == 0)
+        {
+            // Edges from `v` to nodes placed before it are backward.
+            let next_cost = edges
+                .iter()
+                .filter(|&&(u, _)| mask & (1 << u) != 0)
+                .fold(cost, |acc, &(_, w)| acc + w);
+            let next = mask | (1 << v);
+            if best[next].map_or(true, |c| next_cost < c) {
+                best[next] = Some(next_cost);
+                last[next] = v as u8;
+            }
+        }
+    }
+
+    let mut order = Vec::with_capacity(k);
+    let mut mask = full;
+    while mask != 0 {
+        let v = last[mask] as usize;
+        order.push(v);
+        mask &= !(1 << v);
+    }
+    order.reverse();
+    order
+}
+
 fn good_node_sequence(
     edge_refs: impl Iterator<Item = (NodeIndex<usize>, NodeIndex<usize>)>,
 ) -> HashMap<usize, usize> {
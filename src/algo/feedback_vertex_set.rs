@@ -0,0 +1,266 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+use crate::unionfind::UnionFind;
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// Finds a [feedback vertex set]: a set of nodes in the given graph, which when
+/// removed, make the graph acyclic.
+///
+/// For undirected graphs, the [algorithm of Becker and Geiger] is used, which
+/// returns a set at most twice as large as the minimum one. Nodes that cannot
+/// be on a cycle (of degree at most one) are repeatedly discarded, and the node
+/// with the best weight to degree ratio is selected, until no cycle remains.
+/// Parallel edges form a cycle, as in [`is_cyclic_undirected`].
+///
+/// For directed graphs, a greedy heuristic is used, which does not guarantee
+/// any approximation ratio: nodes without incoming or outgoing edges are
+/// repeatedly discarded, and the node with the largest product of in-degree and
+/// out-degree is selected.
+///
+/// In both cases, a final pass drops every selected node that is not needed to
+/// break all cycles, so the returned set is minimal (but not necessarily
+/// minimum). Nodes with a loop are always included in the returned set.
+///
+/// [`greedy_feedback_arc_set`](fn@crate::algo::greedy_feedback_arc_set) is
+/// the edge counterpart of this function.
+///
+/// # Arguments
+/// * `g`: a directed or undirected graph.
+///
+/// # Returns
+/// * `Vec`: the nodes of the feedback vertex set.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |V||E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [feedback vertex set]: https://en.wikipedia.org/wiki/Feedback_vertex_set
+/// [algorithm of Becker and Geiger]: https://doi.org/10.1016/0004-3702(95)00004-6
+/// [`is_cyclic_undirected`]: fn@crate::algo::is_cyclic_undirected
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::{feedback_vertex_set, is_cyclic_directed};
+///
+/// // Two directed cycles sharing node 1.
+/// let mut g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (1, 3), (3, 4), (4, 1)]);
+///
+/// let fvs = feedback_vertex_set(&g);
+/// assert_eq!(fvs, vec![1.into()]);
+///
+/// g.remove_node(fvs[0]);
+/// assert!(!is_cyclic_directed(&g));
+/// ```
+pub fn feedback_vertex_set<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let nodes: Vec<G::NodeId> = g.node_identifiers().collect();
+    let mut position = vec![usize::MAX; g.node_bound()];
+    for (i, &node) in nodes.iter().enumerate() {
+        position[g.to_index(node)] = i;
+    }
+
+    let n = nodes.len();
+    let mut looped = vec![false; n];
+    let mut edges = Vec::new();
+    for edge in g.edge_references() {
+        let a = position[g.to_index(edge.source())];
+        let b = position[g.to_index(edge.target())];
+        if a == b {
+            looped[a] = true;
+        } else {
+            edges.push((a, b));
+        }
+    }
+
+    let directed = g.is_directed();
+    let mut graph = Residual::new(n, &edges, directed);
+    for v in (0..n).filter(|&v| looped[v]) {
+        graph.remove(v);
+    }
+    let selected = if directed {
+        graph.select_directed()
+    } else {
+        graph.select_undirected()
+    };
+
+    // Reverse deletion: drop every selected node that is not needed.
+    let mut in_set = looped;
+    for &v in &selected {
+        in_set[v] = true;
+    }
+    for &v in selected.iter().rev() {
+        in_set[v] = false;
+        if !is_acyclic_without(n, &edges, &in_set, directed) {
+            in_set[v] = true;
+        }
+    }
+
+    (0..n).filter(|&v| in_set[v]).map(|v| nodes[v]).collect()
+}
+
+/// The part of the graph that may still contain cycles.
+struct Residual {
+    alive: Vec<bool>,
+    out_edges: Vec<Vec<usize>>,
+    in_edges: Vec<Vec<usize>>,
+    out_degree: Vec<usize>,
+    in_degree: Vec<usize>,
+    directed: bool,
+}
+
+impl Residual {
+    fn new(n: usize, edges: &[(usize, usize)], directed: bool) -> Self {
+        let mut out_edges = vec![Vec::new(); n];
+        let mut in_edges = vec![Vec::new(); n];
+        for &(a, b) in edges {
+            out_edges[a].push(b);
+            in_edges[b].push(a);
+        }
+        if !directed {
+            // Store every undirected edge in both directions in `out_edges`.
+            for (b, sources) in in_edges.iter_mut().enumerate() {
+                for a in sources.drain(..) {
+                    out_edges[b].push(a);
+                }
+            }
+        }
+        let out_degree = out_edges.iter().map(Vec::len).collect();
+        let in_degree = in_edges.iter().map(Vec::len).collect();
+        Residual {
+            alive: vec![true; n],
+            out_edges,
+            in_edges,
+            out_degree,
+            in_degree,
+            directed,
+        }
+    }
+
+    fn remove(&mut self, v: usize) {
+        if !self.alive[v] {
+            return;
+        }
+        self.alive[v] = false;
+        for &w in &self.out_edges[v] {
+            if !self.alive[w] {
+                continue;
+            }
+            if self.directed {
+                self.in_degree[w] -= 1;
+            } else {
+                self.out_degree[w] -= 1;
+            }
+        }
+        if self.directed {
+            for &u in &self.in_edges[v] {
+                if self.alive[u] {
+                    self.out_degree[u] -= 1;
+                }
+            }
+        }
+    }
+
+    /// Whether `v` cannot lie on a cycle of the residual graph.
+    fn is_trivial(&self, v: usize) -> bool {
+        if self.directed {
+            self.in_degree[v] == 0 || self.out_degree[v] == 0
+        } else {
+            self.out_degree[v] <= 1
+        }
+    }
+
+    /// Repeatedly remove nodes that cannot lie on a cycle.
+    fn reduce(&mut self) {
+        let mut queue: VecDeque<usize> = (0..self.alive.len())
+            .filter(|&v| self.alive[v] && self.is_trivial(v))
+            .collect();
+        while let Some(v) = queue.pop_front() {
+            if !self.alive[v] {
+                continue;
+            }
+            self.remove(v);
+            let neighbors = self.out_edges[v].iter().chain(&self.in_edges[v]);
+            for &w in neighbors {
+                if self.alive[w] && self.is_trivial(w) {
+                    queue.push_back(w);
+                }
+            }
+        }
+    }
+
+    fn select_undirected(&mut self) -> Vec<usize> {
+        let n = self.alive.len();
+        let mut weight = vec![1.0f64; n];
+        let mut selected = Vec::new();
+        self.reduce();
+        while let Some(v) = (0..n).filter(|&v| self.alive[v]).min_by(|&a, &b| {
+            let ratio = |v: usize| weight[v] / (self.out_degree[v] - 1) as f64;
+            ratio(a).partial_cmp(&ratio(b)).unwrap()
+        }) {
+            let gamma = weight[v] / (self.out_degree[v] - 1) as f64;
+            for u in (0..n).filter(|&u| self.alive[u]) {
+                weight[u] -= gamma * (self.out_degree[u] - 1) as f64;
+            }
+            selected.push(v);
+            self.remove(v);
+            self.reduce();
+        }
+        selected
+    }
+
+    fn select_directed(&mut self) -> Vec<usize> {
+        let n = self.alive.len();
+        let mut selected = Vec::new();
+        self.reduce();
+        while let Some(v) = (0..n).filter(|&v| self.alive[v]).max_by_key(|&v| {
+            (
+                self.in_degree[v] * self.out_degree[v],
+                core::cmp::Reverse(v),
+            )
+        }) {
+            selected.push(v);
+            self.remove(v);
+            self.reduce();
+        }
+        selected
+    }
+}
+
+/// Check whether the graph without the nodes in `removed` is acyclic.
+fn is_acyclic_without(
+    n: usize,
+    edges: &[(usize, usize)],
+    removed: &[bool],
+    directed: bool,
+) -> bool {
+    let mut kept = edges.iter().filter(|&&(a, b)| !removed[a] && !removed[b]);
+    if !directed {
+        let mut sets = UnionFind::new(n);
+        return kept.all(|&(a, b)| sets.union(a, b));
+    }
+
+    // Kahn's algorithm: the graph is acyclic iff every node gets removed.
+    let mut out_edges = vec![Vec::new(); n];
+    let mut in_degree = vec![0usize; n];
+    for &(a, b) in kept {
+        out_edges[a].push(b);
+        in_degree[b] += 1;
+    }
+    let mut queue: Vec<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+    let mut count = 0;
+    while let Some(v) = queue.pop() {
+        count += 1;
+        for &w in &out_edges[v] {
+            in_degree[w] -= 1;
+            if in_degree[w] == 0 {
+                queue.push(w);
+            }
+        }
+    }
+    count == n
+}
@@ -11,6 +11,7 @@ use crate::{
 
 use super::{EdgeRef, PositiveMeasure};
 use crate::prelude::Direction;
+use crate::trace::Counter;
 
 fn residual_capacity<N>(
     network: N,
@@ -175,10 +176,13 @@ where
         + Visitable,
     N::EdgeWeight: Sub<Output = N::EdgeWeight> + PositiveMeasure,
 {
+    trace_span!("ford_fulkerson");
+    let mut augmenting_paths = Counter::default();
     let mut edge_to = vec![None; network.node_count()];
     let mut flows = vec![N::EdgeWeight::zero(); network.edge_bound()];
     let mut max_flow = N::EdgeWeight::zero();
     while has_augmented_path(&network, source, destination, &mut edge_to, &flows) {
+        augmenting_paths.incr();
         let mut path_flow = N::EdgeWeight::max();
 
         // Find the bottleneck capacity of the path
@@ -209,5 +213,9 @@ where
         }
         max_flow = max_flow + path_flow;
     }
+    trace_event!(
+        augmenting_paths = augmenting_paths.get(),
+        "ford_fulkerson finished"
+    );
     (max_flow, flows)
 }
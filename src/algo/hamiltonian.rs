@@ -0,0 +1,217 @@
+use alloc::{vec, vec::Vec};
+
+use fixedbitset::FixedBitSet;
+
+use crate::visit::{GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// Find a [Hamiltonian path][1] of the graph, if one exists.
+///
+/// A Hamiltonian path visits every node of the graph exactly once. The
+/// search follows the outgoing edges of directed graphs.
+///
+/// This is an exact backtracking search. Neighbors with the fewest remaining
+/// options are tried first, and branches are cut as soon as some unvisited
+/// node can no longer be entered or left. The problem is NP-complete, so the
+/// search is only practical for small graphs.
+///
+/// # Arguments
+/// * `graph`: an input graph.
+///
+/// # Returns
+/// * `Some(path)`: the nodes of a Hamiltonian path in visiting order.
+/// * `None`: if the graph is empty or has no Hamiltonian path.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|!)** in the worst case.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Hamiltonian_path
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::hamiltonian_path;
+///
+/// let mut graph = Graph::<(), ()>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.extend_with_edges(&[(b, a), (a, c), (b, c)]);
+///
+/// assert_eq!(hamiltonian_path(&graph), Some(vec![b, a, c]));
+/// ```
+pub fn hamiltonian_path<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let search = Search::new(graph);
+    let n = search.nodes.len();
+    (0..n).find_map(|start| search.run(start, false))
+}
+
+/// Find a [Hamiltonian cycle][1] of the graph, if one exists.
+///
+/// A Hamiltonian cycle visits every node of the graph exactly once and
+/// returns to its first node. The search follows the outgoing edges of
+/// directed graphs. A cycle of an undirected graph needs at least three
+/// nodes, since it cannot use the same edge twice.
+///
+/// This is an exact backtracking search with the same pruning as
+/// [`hamiltonian_path`], so it is only practical for small graphs.
+///
+/// # Arguments
+/// * `graph`: an input graph.
+///
+/// # Returns
+/// * `Some(cycle)`: the nodes of a Hamiltonian cycle in visiting order. The
+///   first node is not repeated at the end.
+/// * `None`: if the graph is empty or has no Hamiltonian cycle.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|!)** in the worst case.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Hamiltonian_path
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::hamiltonian_cycle;
+///
+/// let mut graph = UnGraph::<(), ()>::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b), (b, c), (c, d), (d, a), (a, c)]);
+///
+/// let cycle = hamiltonian_cycle(&graph).unwrap();
+/// assert_eq!(cycle.len(), 4);
+///
+/// graph.remove_edge(graph.find_edge(d, a).unwrap());
+/// assert_eq!(hamiltonian_cycle(&graph), None);
+/// ```
+pub fn hamiltonian_cycle<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let search = Search::new(graph);
+    let min_nodes = if graph.is_directed() { 2 } else { 3 };
+    if search.nodes.len() < min_nodes {
+        return None;
+    }
+    // Every node lies on a Hamiltonian cycle, so starting anywhere is enough.
+    search.run(0, true)
+}
+
+struct Search<N> {
+    nodes: Vec<N>,
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl<N: Copy> Search<N> {
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoNeighbors<NodeId = N> + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        let mut position = vec![usize::MAX; graph.node_bound()];
+        for (i, &node) in nodes.iter().enumerate() {
+            position[graph.to_index(node)] = i;
+        }
+        let mut successors = vec![Vec::new(); nodes.len()];
+        let mut predecessors = vec![Vec::new(); nodes.len()];
+        for (i, &node) in nodes.iter().enumerate() {
+            for next in graph.neighbors(node) {
+                let j = position[graph.to_index(next)];
+                if i != j && !successors[i].contains(&j) {
+                    successors[i].push(j);
+                    predecessors[j].push(i);
+                }
+            }
+        }
+        Search {
+            nodes,
+            successors,
+            predecessors,
+        }
+    }
+
+    fn run(&self, start: usize, cycle: bool) -> Option<Vec<N>> {
+        let n = self.nodes.len();
+        let mut visited = FixedBitSet::with_capacity(n);
+        visited.insert(start);
+        let mut path = vec![start];
+        if self.extend(&mut path, &mut visited, cycle) {
+            Some(path.into_iter().map(|i| self.nodes[i]).collect())
+        } else {
+            None
+        }
+    }
+
+    fn extend(&self, path: &mut Vec<usize>, visited: &mut FixedBitSet, cycle: bool) -> bool {
+        let n = self.nodes.len();
+        let last = *path.last().unwrap();
+        if path.len() == n {
+            return !cycle || self.successors[last].contains(&path[0]);
+        }
+        if !self.feasible(path, visited, cycle) {
+            return false;
+        }
+
+        let free_degree = |v: usize| {
+            self.successors[v]
+                .iter()
+                .filter(|&&w| !visited.contains(w))
+                .count()
+        };
+        let mut candidates: Vec<(usize, usize)> = self.successors[last]
+            .iter()
+            .filter(|&&v| !visited.contains(v))
+            .map(|&v| (free_degree(v), v))
+            .collect();
+        candidates.sort_unstable();
+
+        for (_, next) in candidates {
+            visited.insert(next);
+            path.push(next);
+            if self.extend(path, visited, cycle) {
+                return true;
+            }
+            path.pop();
+            visited.set(next, false);
+        }
+        false
+    }
+
+    /// Check that every unvisited node can still be entered and left.
+    fn feasible(&self, path: &[usize], visited: &FixedBitSet, cycle: bool) -> bool {
+        let first = path[0];
+        let last = *path.last().unwrap();
+        let mut dead_ends = 0;
+        for v in (0..self.nodes.len()).filter(|&v| !visited.contains(v)) {
+            let can_enter = self.predecessors[v]
+                .iter()
+                .any(|&u| u == last || !visited.contains(u));
+            if !can_enter {
+                return false;
+            }
+            let can_leave = self.successors[v]
+                .iter()
+                .any(|&w| (cycle && w == first) || !visited.contains(w));
+            if !can_leave {
+                // Without a cycle, the last node of the path may be a dead end.
+                dead_ends += 1;
+                if cycle || dead_ends > 1 {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
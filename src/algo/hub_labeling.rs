@@ -0,0 +1,203 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use crate::algo::Measure;
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+
+/// Hub labels of a graph, computed by [`pruned_landmark_labeling`].
+///
+/// Every node stores a label: a list of hubs with their exact distance to
+/// the node. Any two connected nodes share a hub on one of their shortest
+/// paths, so the distance between them is found by scanning their two labels.
+#[derive(Clone, Debug)]
+pub struct HubLabels<N, K> {
+    /// The nodes, ordered by rank: `nodes[r]` is the hub of rank `r`.
+    nodes: Vec<N>,
+    /// The index of each node in `labels`.
+    index: HashMap<N, usize>,
+    /// The label of each node, as pairs of a hub rank and a distance, sorted
+    /// by rank.
+    labels: Vec<Vec<(usize, K)>>,
+}
+
+impl<N, K> HubLabels<N, K>
+where
+    N: Copy + Eq + Hash,
+    K: Measure + Copy,
+{
+    /// Return the exact distance between `a` and `b`, or `None` if they are
+    /// not connected or are not nodes of the graph.
+    pub fn distance(&self, a: N, b: N) -> Option<K> {
+        let a = self.labels.get(*self.index.get(&a)?)?;
+        let b = self.labels.get(*self.index.get(&b)?)?;
+        query(a, b)
+    }
+
+    /// Return the label of `node`, as pairs of a hub and its distance to
+    /// `node`, ordered by decreasing importance of the hubs.
+    pub fn label(&self, node: N) -> impl Iterator<Item = (N, K)> + '_ {
+        let label = self.index.get(&node).map_or(&[][..], |&i| &self.labels[i]);
+        label
+            .iter()
+            .map(move |&(rank, dist)| (self.nodes[rank], dist))
+    }
+
+    /// Return the number of hubs in the label of `node`.
+    pub fn label_size(&self, node: N) -> usize {
+        self.index.get(&node).map_or(0, |&i| self.labels[i].len())
+    }
+
+    /// Return the total number of hubs in all labels.
+    pub fn total_label_size(&self) -> usize {
+        self.labels.iter().map(Vec::len).sum()
+    }
+
+    /// Return the largest number of hubs in a label.
+    pub fn max_label_size(&self) -> usize {
+        self.labels.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Return the average number of hubs in a label, or `0.0` if the graph
+    /// is empty.
+    pub fn average_label_size(&self) -> f64 {
+        if self.labels.is_empty() {
+            0.0
+        } else {
+            self.total_label_size() as f64 / self.labels.len() as f64
+        }
+    }
+}
+
+/// Compute the minimum of `a[hub] + b[hub]` over the hubs of both labels.
+fn query<K: Measure + Copy>(a: &[(usize, K)], b: &[(usize, K)]) -> Option<K> {
+    let mut best: Option<K> = None;
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let (ra, da) = a[i];
+        let (rb, db) = b[j];
+        if ra < rb {
+            i += 1;
+        } else if rb < ra {
+            j += 1;
+        } else {
+            let dist = da + db;
+            if best.map_or(true, |d| dist < d) {
+                best = Some(dist);
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+    best
+}
+
+/// Compute exact [hub labels][1] of an undirected graph with pruned landmark
+/// labeling.
+///
+/// Nodes are processed by decreasing degree, so that hubs of social-network
+/// like graphs come first. A Dijkstra search is run from each node, and is
+/// pruned at every node whose distance is already given by the labels
+/// computed so far. Distance queries then only scan two labels, which are
+/// usually small on graphs with a few highly connected nodes.
+///
+/// The graph should be undirected, or directed with symmetric edges. The
+/// function `edge_cost` should return the cost for a particular edge; use
+/// `|_| 1` for an unweighted graph. Edge costs must be non-negative.
+///
+/// # Arguments
+/// * `graph`: an undirected weighted graph.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * [`HubLabels`]: the labels of all nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|(|E| + |V|log|V|)L)** in the worst case, and
+///   much less in practice.
+/// * Auxiliary space: **O(|V|L + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **L** is the average label size.
+///
+/// [1]: https://doi.org/10.1145/2463676.2465315
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::pruned_landmark_labeling;
+/// use petgraph::graph::UnGraph;
+///
+/// // A star with center 0, and a path 3 - 5 - 6.
+/// let graph = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (0, 2, 1), (0, 3, 1), (0, 4, 1),
+///     (3, 5, 2), (5, 6, 2),
+/// ]);
+///
+/// let labels = pruned_landmark_labeling(&graph, |e| *e.weight());
+/// assert_eq!(labels.distance(1.into(), 6.into()), Some(6));
+/// assert_eq!(labels.distance(2.into(), 2.into()), Some(0));
+/// // The center is a hub of every node.
+/// assert!(labels.label(4.into()).any(|(hub, _)| hub == 0.into()));
+/// ```
+pub fn pruned_landmark_labeling<G, F, K>(graph: G, mut edge_cost: F) -> HubLabels<G::NodeId, K>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    nodes.sort_by_key(|&n| core::cmp::Reverse(graph.edges(n).count()));
+    let n = nodes.len();
+    let mut position = vec![usize::MAX; graph.node_bound()];
+    for (rank, &node) in nodes.iter().enumerate() {
+        position[graph.to_index(node)] = rank;
+    }
+
+    let mut labels: Vec<Vec<(usize, K)>> = vec![Vec::new(); n];
+    let mut dist: Vec<Option<K>> = vec![None; n];
+    let mut touched = Vec::new();
+    let mut visit_next = BinaryHeap::new();
+    for rank in 0..n {
+        dist[rank] = Some(K::default());
+        touched.push(rank);
+        visit_next.push(MinScored(K::default(), rank));
+        while let Some(MinScored(d, v)) = visit_next.pop() {
+            if dist[v].map_or(false, |best| d > best) {
+                continue;
+            }
+            // Prune if the labels computed so far already give the distance.
+            if query(&labels[rank], &labels[v]).map_or(false, |known| known <= d) {
+                continue;
+            }
+            labels[v].push((rank, d));
+            for edge in graph.edges(nodes[v]) {
+                let w = position[graph.to_index(edge.target())];
+                // Nodes of lower rank already have complete labels.
+                if w < rank {
+                    continue;
+                }
+                let next = d + edge_cost(edge);
+                if dist[w].map_or(true, |best| next < best) {
+                    if dist[w].is_none() {
+                        touched.push(w);
+                    }
+                    dist[w] = Some(next);
+                    visit_next.push(MinScored(next, w));
+                }
+            }
+        }
+        for v in touched.drain(..) {
+            dist[v] = None;
+        }
+    }
+
+    let index = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    HubLabels {
+        nodes,
+        index,
+        labels,
+    }
+}
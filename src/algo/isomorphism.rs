@@ -2,6 +2,7 @@ use alloc::{vec, vec::Vec};
 use core::convert::TryFrom;
 
 use crate::data::DataMap;
+use crate::trace::Counter;
 use crate::visit::EdgeCount;
 use crate::visit::EdgeRef;
 use crate::visit::GetAdjacencyMatrix;
@@ -578,6 +579,9 @@ mod matching {
         // A "depth first" search of a valid mapping from graph 1 to graph 2
         // F(s, n, m) -- evaluate state s and add mapping n <-> m
         // Find least T1out node (in st.out[1] but not in M[1])
+        trace_span!("vf2", match_subgraph);
+        let mut explored = Counter::default();
+        let mut pruned = Counter::default();
         let mut result = None;
         while let Some(frame) = stack.pop() {
             match frame {
@@ -607,6 +611,7 @@ mod matching {
                 },
                 Frame::Inner { nodes, open_list } => {
                     if is_feasible(st, nodes, node_match, edge_match) {
+                        explored.incr();
                         push_state(st, nodes);
                         if st.0.is_complete() {
                             result = Some(st.0.mapping.clone());
@@ -626,6 +631,7 @@ mod matching {
                         }
                         pop_state(st, nodes);
                     }
+                    pruned.incr();
                     match next_from_ix(st, nodes.1, open_list) {
                         None => continue,
                         Some(nx) => {
@@ -639,9 +645,15 @@ mod matching {
                 }
             }
             if result.is_some() {
-                return result;
+                break;
             }
         }
+        trace_event!(
+            states_explored = explored.get(),
+            states_pruned = pruned.get(),
+            found = result.is_some(),
+            "vf2 finished"
+        );
         result
     }
 
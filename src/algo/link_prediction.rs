@@ -0,0 +1,277 @@
+//! Link prediction: similarity scores of node pairs from their
+//! neighborhoods, which rank the missing edges of a graph by how likely they
+//! are to appear.
+//!
+//! All scores treat the graph as undirected: the neighbors of a node are
+//! the nodes it shares an edge with, in either direction, without
+//! repetitions and without the node itself.
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use fixedbitset::FixedBitSet;
+
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// A similarity score of two nodes `u` and `v`, with neighborhoods **Γ(u)**
+/// and **Γ(v)**, for [`link_scores`] and [`predict_links`].
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LinkScore {
+    /// The number of common neighbors, **|Γ(u) ∩ Γ(v)|**.
+    CommonNeighbors,
+    /// The [Jaccard coefficient][1] of the neighborhoods,
+    /// **|Γ(u) ∩ Γ(v)| / |Γ(u) ∪ Γ(v)|**, or `0` if both are empty.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Jaccard_index
+    Jaccard,
+    /// The [Adamic–Adar index][1], the sum of **1 / ln |Γ(w)|** over the
+    /// common neighbors `w`, which gives more weight to the common neighbors
+    /// of low degree.
+    ///
+    /// This score requires the `std` feature.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Adamic%E2%80%93Adar_index
+    #[cfg(feature = "std")]
+    AdamicAdar,
+    /// The resource allocation index, the sum of **1 / |Γ(w)|** over the
+    /// common neighbors `w`.
+    ResourceAllocation,
+    /// The preferential attachment score, **|Γ(u)| · |Γ(v)|**.
+    PreferentialAttachment,
+}
+
+impl LinkScore {
+    /// Return `true` if the score of two nodes without common neighbors is
+    /// `0`.
+    fn needs_common_neighbors(self) -> bool {
+        self != LinkScore::PreferentialAttachment
+    }
+
+    /// Return the contribution of a common neighbor of degree `degree` to
+    /// the sum of the score.
+    fn contribution(self, degree: usize) -> f64 {
+        match self {
+            #[cfg(feature = "std")]
+            LinkScore::AdamicAdar => 1.0 / (degree as f64).ln(),
+            LinkScore::ResourceAllocation => 1.0 / degree as f64,
+            _ => 1.0,
+        }
+    }
+
+    /// Return the score from the sum of the contributions of the common
+    /// neighbors, and the degrees of the two nodes.
+    fn finish(self, sum: f64, common: usize, degrees: (usize, usize)) -> f64 {
+        match self {
+            LinkScore::Jaccard => {
+                let union = degrees.0 + degrees.1 - common;
+                if union == 0 {
+                    0.0
+                } else {
+                    common as f64 / union as f64
+                }
+            }
+            LinkScore::PreferentialAttachment => (degrees.0 * degrees.1) as f64,
+            _ => sum,
+        }
+    }
+}
+
+/// The sorted neighbors of each node index, ignoring directions, loops and
+/// parallel edges.
+fn neighborhoods<G>(graph: G) -> Vec<Vec<usize>>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut neighbors = vec![Vec::new(); graph.node_bound()];
+    for edge in graph.edge_references() {
+        let a = graph.to_index(edge.source());
+        let b = graph.to_index(edge.target());
+        if a != b {
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+    }
+    for list in &mut neighbors {
+        list.sort_unstable();
+        list.dedup();
+    }
+    neighbors
+}
+
+/// \[Generic\] Compute a link prediction score for each pair of nodes of
+/// `pairs`.
+///
+/// Directions, loops and parallel edges are ignored (see the
+/// [module documentation](self)). Whether the nodes of a pair are adjacent
+/// does not matter.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `pairs`: the node pairs to score.
+/// * `score`: the score to compute.
+///
+/// # Returns
+/// * The score of each pair, in the order of `pairs`.
+///
+/// # Complexity
+/// * Time complexity: **O(|E| log |E| + P · Δ)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges,
+/// **P** is the number of pairs and **Δ** is the maximum degree.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::link_prediction::{link_scores, LinkScore};
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // 0 and 3 share the neighbors 1 and 2, and 3 has one more neighbor.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (3, 1), (3, 2), (3, 4)]);
+/// let pairs = [(NodeIndex::new(0), NodeIndex::new(3))];
+///
+/// assert_eq!(link_scores(&graph, &pairs, LinkScore::CommonNeighbors), [2.0]);
+/// assert_eq!(link_scores(&graph, &pairs, LinkScore::Jaccard), [2.0 / 3.0]);
+/// assert_eq!(link_scores(&graph, &pairs, LinkScore::ResourceAllocation), [1.0]);
+/// assert_eq!(link_scores(&graph, &pairs, LinkScore::PreferentialAttachment), [6.0]);
+/// ```
+pub fn link_scores<G>(graph: G, pairs: &[(G::NodeId, G::NodeId)], score: LinkScore) -> Vec<f64>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let neighbors = neighborhoods(graph);
+    pairs
+        .iter()
+        .map(|&(u, v)| pair_score(&neighbors, graph.to_index(u), graph.to_index(v), score))
+        .collect()
+}
+
+/// Return the score of the nodes of indices `u` and `v`, by merging their
+/// sorted neighborhoods.
+fn pair_score(neighbors: &[Vec<usize>], u: usize, v: usize, score: LinkScore) -> f64 {
+    let (a, b) = (&neighbors[u], &neighbors[v]);
+    let (mut i, mut j) = (0, 0);
+    let (mut sum, mut common) = (0.0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                sum += score.contribution(neighbors[a[i]].len());
+                common += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    score.finish(sum, common, (a.len(), b.len()))
+}
+
+/// \[Generic\] Find the pairs of distinct, non adjacent nodes whose link
+/// prediction score is greater than `threshold`, the most likely missing
+/// edges of the graph.
+///
+/// Directions, loops and parallel edges are ignored (see the
+/// [module documentation](self)), and each pair is reported once. For the
+/// scores that are `0` for nodes without common neighbors and a
+/// non-negative `threshold`, only the pairs of nodes at distance two are
+/// scored; otherwise all pairs are.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `score`: the score to compute.
+/// * `threshold`: the score that the pairs must exceed.
+///
+/// # Returns
+/// * The pairs and their scores, by decreasing score, and by node indices
+///   for equal scores.
+///
+/// # Complexity
+/// * Time complexity: **O(|E| log |E| + Σ deg(w)² + R log R)**, or
+///   **O(|E| log |E| + |V|² · Δ)** if all pairs are scored.
+/// * Auxiliary space: **O(|V| + |E| + R)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges,
+/// the sum is over all nodes, **Δ** is the maximum degree and **R** is the
+/// number of reported pairs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::link_prediction::{predict_links, LinkScore};
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // A square with one diagonal, and a pendant node.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (3, 4)]);
+/// let links = predict_links(&graph, LinkScore::CommonNeighbors, 0.0);
+/// assert_eq!(links[0], (NodeIndex::new(1), NodeIndex::new(3), 2.0));
+/// assert_eq!(links.len(), 3);
+/// ```
+pub fn predict_links<G>(
+    graph: G,
+    score: LinkScore,
+    threshold: f64,
+) -> Vec<(G::NodeId, G::NodeId, f64)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let neighbors = neighborhoods(graph);
+    let n = graph.node_bound();
+    let mut present = FixedBitSet::with_capacity(n);
+    for node in graph.node_identifiers() {
+        present.insert(graph.to_index(node));
+    }
+    let mut adjacent = FixedBitSet::with_capacity(n);
+    let mut links = Vec::new();
+
+    if score.needs_common_neighbors() && threshold >= 0.0 {
+        // Accumulate the contributions of the common neighbors of `u` and
+        // every later node at distance two.
+        let mut sums = vec![0.0; n];
+        let mut common = vec![0; n];
+        let mut touched = Vec::new();
+        for u in present.ones() {
+            adjacent.clear();
+            adjacent.extend(neighbors[u].iter().copied());
+            for &w in &neighbors[u] {
+                let contribution = score.contribution(neighbors[w].len());
+                for &v in neighbors[w].iter().filter(|&&v| v > u && !adjacent[v]) {
+                    if common[v] == 0 {
+                        touched.push(v);
+                    }
+                    sums[v] += contribution;
+                    common[v] += 1;
+                }
+            }
+            for v in touched.drain(..) {
+                let degrees = (neighbors[u].len(), neighbors[v].len());
+                let value = score.finish(sums[v], common[v], degrees);
+                if value > threshold {
+                    links.push((u, v, value));
+                }
+                sums[v] = 0.0;
+                common[v] = 0;
+            }
+        }
+    } else {
+        for u in present.ones() {
+            for v in present.ones().filter(|&v| v > u) {
+                if neighbors[u].binary_search(&v).is_err() {
+                    let value = pair_score(&neighbors, u, v, score);
+                    if value > threshold {
+                        links.push((u, v, value));
+                    }
+                }
+            }
+        }
+    }
+
+    links.sort_by(|a, b| {
+        b.2.partial_cmp(&a.2)
+            .unwrap_or(Ordering::Equal)
+            .then((a.0, a.1).cmp(&(b.0, b.1)))
+    });
+    links
+        .into_iter()
+        .map(|(u, v, value)| (graph.from_index(u), graph.from_index(v), value))
+        .collect()
+}
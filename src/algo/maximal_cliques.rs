@@ -1,9 +1,11 @@
+use crate::algo::Measure;
 use crate::visit::{
     GetAdjacencyMatrix, IntoEdges, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable, Visitable,
 };
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use core::hash::Hash;
 use core::iter::FromIterator;
+use fixedbitset::FixedBitSet;
 use hashbrown::{HashMap, HashSet};
 
 /// Finds maximal cliques containing all the vertices in r, some of the
@@ -180,6 +182,210 @@ where
     mccreesh_prosser(g)
 }
 
+/// Finds a maximum clique of an undirected graph: a clique with the largest
+/// number of nodes.
+///
+/// This is [`maximum_weight_clique`] with unit weights, so it uses the same
+/// branch and bound search. It returns the same size as
+/// [`largest_maximal_clique`], but is usually faster on dense graphs.
+///
+/// This method may also be called on directed graphs, but one needs to ensure that
+/// if an edge (u, v) exists, then (v, u) also exists.
+///
+/// # Arguments
+/// * `g`: The graph to find a maximum clique in.
+///
+/// # Returns
+/// * `Vec`: the nodes of a maximum clique, empty if the graph is empty.
+///
+/// # Complexity
+/// * Time complexity: **O(2^|V| · |V|²)** in the worst case.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::maximum_clique;
+/// use petgraph::graph::UnGraph;
+///
+/// // 0 --- 2 -- 3
+/// //  \   /
+/// //   \ /
+/// //    1       4
+/// let mut g = UnGraph::<i32, ()>::from_edges([(0, 1), (0, 2), (1, 2), (2, 3)]);
+/// g.add_node(4);
+///
+/// let mut clique = maximum_clique(&g);
+/// clique.sort();
+/// assert_eq!(clique, vec![0.into(), 1.into(), 2.into()]);
+/// ```
+pub fn maximum_clique<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    maximum_weight_clique(g, |_| 1usize).1
+}
+
+/// Finds a maximum weight clique of an undirected graph: a clique whose total
+/// node weight is as large as possible.
+///
+/// Uses a branch and bound search, where the bound is given by a greedy
+/// coloring of the candidate nodes: a clique contains at most one node of
+/// each color, so its weight is at most the sum of the largest weight of each
+/// color. Branches that cannot beat the best clique found so far are pruned,
+/// which is much faster than enumerating all [maximal cliques][maximal_cliques].
+///
+/// The function `node_weight` should return the weight of a node. Weights
+/// must be non-negative. Loops are ignored.
+///
+/// This method may also be called on directed graphs, but one needs to ensure that
+/// if an edge (u, v) exists, then (v, u) also exists.
+///
+/// # Arguments
+/// * `g`: The graph to find a maximum weight clique in.
+/// * `node_weight`: closure that returns the weight of a node.
+///
+/// # Returns
+/// * `(weight, clique)`: the total weight and the nodes of a maximum weight
+///   clique. The clique is empty if the graph is empty.
+///
+/// # Complexity
+/// * Time complexity: **O(2^|V| · |V|²)** in the worst case.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::algo::maximum_weight_clique;
+/// use petgraph::graph::UnGraph;
+///
+/// // The triangle {a, b, c} is the largest clique, but the edge {c, d} is
+/// // heavier.
+/// let mut g = UnGraph::<u32, ()>::new_undirected();
+/// let a = g.add_node(1);
+/// let b = g.add_node(1);
+/// let c = g.add_node(2);
+/// let d = g.add_node(5);
+/// g.extend_with_edges(&[(a, b), (b, c), (c, a), (c, d)]);
+///
+/// let (weight, mut clique) = maximum_weight_clique(&g, |n| g[n]);
+/// clique.sort();
+/// assert_eq!(weight, 7);
+/// assert_eq!(clique, vec![c, d]);
+/// ```
+pub fn maximum_weight_clique<G, F, K>(g: G, mut node_weight: F) -> (K, Vec<G::NodeId>)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> K,
+    K: Measure + Copy,
+{
+    let nodes: Vec<G::NodeId> = g.node_identifiers().collect();
+    let mut position = vec![usize::MAX; g.node_bound()];
+    for (i, &node) in nodes.iter().enumerate() {
+        position[g.to_index(node)] = i;
+    }
+    let n = nodes.len();
+    let mut adjacency = vec![FixedBitSet::with_capacity(n); n];
+    for (i, &node) in nodes.iter().enumerate() {
+        for neighbor in g.neighbors(node) {
+            let j = position[g.to_index(neighbor)];
+            if i != j {
+                adjacency[i].insert(j);
+                adjacency[j].insert(i);
+            }
+        }
+    }
+
+    let search = CliqueSearch {
+        weights: nodes.iter().map(|&node| node_weight(node)).collect(),
+        adjacency,
+    };
+    // Heavy nodes of high degree are the most likely to be in a heavy
+    // clique, so they are tried first.
+    let mut candidates: Vec<usize> = (0..n).collect();
+    candidates.sort_by(|&a, &b| {
+        let key = |v: usize| (search.weights[v], search.adjacency[v].count_ones(..));
+        key(b)
+            .partial_cmp(&key(a))
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    let mut best = (K::default(), Vec::new());
+    search.expand(&mut Vec::new(), K::default(), candidates, &mut best);
+    (best.0, best.1.into_iter().map(|i| nodes[i]).collect())
+}
+
+struct CliqueSearch<K> {
+    weights: Vec<K>,
+    adjacency: Vec<FixedBitSet>,
+}
+
+impl<K: Measure + Copy> CliqueSearch<K> {
+    fn expand(
+        &self,
+        clique: &mut Vec<usize>,
+        weight: K,
+        candidates: Vec<usize>,
+        best: &mut (K, Vec<usize>),
+    ) {
+        if candidates.is_empty() {
+            if best.1.is_empty() || weight > best.0 {
+                *best = (weight, clique.clone());
+            }
+            return;
+        }
+        let (mut order, bounds) = self.color_bounds(&candidates);
+        while let Some(v) = order.pop() {
+            if weight + bounds[order.len()] <= best.0 && !best.1.is_empty() {
+                return;
+            }
+            clique.push(v);
+            let next: Vec<usize> = order
+                .iter()
+                .copied()
+                .filter(|&u| self.adjacency[v].contains(u))
+                .collect();
+            self.expand(clique, weight + self.weights[v], next, best);
+            clique.pop();
+        }
+    }
+
+    /// Greedily color `candidates` and return them ordered by color, with
+    /// the upper bound of the weight of a clique among the first `i + 1`
+    /// nodes of that order.
+    fn color_bounds(&self, candidates: &[usize]) -> (Vec<usize>, Vec<K>) {
+        let mut classes: Vec<Vec<usize>> = Vec::new();
+        for &v in candidates {
+            let class = classes
+                .iter_mut()
+                .find(|class| class.iter().all(|&u| !self.adjacency[v].contains(u)));
+            match class {
+                Some(class) => class.push(v),
+                None => classes.push(vec![v]),
+            }
+        }
+
+        let mut order = Vec::with_capacity(candidates.len());
+        let mut bounds = Vec::with_capacity(candidates.len());
+        let mut total = K::default();
+        for class in classes {
+            let heaviest = class
+                .iter()
+                .map(|&v| self.weights[v])
+                .fold(K::default(), |a, b| if b > a { b } else { a });
+            total = total + heaviest;
+            for v in class {
+                order.push(v);
+                bounds.push(total);
+            }
+        }
+        (order, bounds)
+    }
+}
+
 fn mccreesh_prosser<G>(g: G) -> HashSet<G::NodeId>
 where
     G: IntoEdges + IntoNodeIdentifiers + Visitable + NodeIndexable,
@@ -0,0 +1,334 @@
+//! Randomized minimum cuts by edge contraction.
+
+use alloc::{vec, vec::Vec};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::unionfind::UnionFind;
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// A cut of a graph found by [`karger_min_cut`] or [`karger_stein_min_cut`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinCut<N> {
+    /// The number of edges between the two sides of the cut.
+    pub cut_size: usize,
+    /// The two sides of the cut, which are not empty.
+    pub partition: (Vec<N>, Vec<N>),
+    /// A lower bound of the probability that the cut is a minimum cut, from
+    /// the analysis of the algorithm and the number of trials.
+    pub success_probability: f64,
+}
+
+/// An undirected multigraph on the nodes `0..n`, without self loops.
+struct Multigraph {
+    n: usize,
+    edges: Vec<(usize, usize)>,
+}
+
+impl Multigraph {
+    fn new<G>(graph: G, nodes: &[G::NodeId]) -> Self
+    where
+        G: IntoEdgeReferences + NodeIndexable,
+    {
+        let mut compact = vec![usize::MAX; graph.node_bound()];
+        for (i, &v) in nodes.iter().enumerate() {
+            compact[graph.to_index(v)] = i;
+        }
+        let edges = graph
+            .edge_references()
+            .map(|e| {
+                (
+                    compact[graph.to_index(e.source())],
+                    compact[graph.to_index(e.target())],
+                )
+            })
+            .filter(|(a, b)| a != b)
+            .collect();
+        Multigraph {
+            n: nodes.len(),
+            edges,
+        }
+    }
+
+    /// Contract random edges until `target` nodes are left, and return the
+    /// node of the contracted graph that each node was merged into.
+    fn contract<R>(&self, target: usize, rng: &mut R) -> (Vec<usize>, Multigraph)
+    where
+        R: Rng + ?Sized,
+    {
+        // Contracting the edges of a random permutation that join two
+        // different nodes picks each of them uniformly among the remaining
+        // edges, like Kruskal's algorithm.
+        let mut order = self.edges.clone();
+        order.shuffle(rng);
+        let mut merged = UnionFind::<usize>::new(self.n);
+        let mut count = self.n;
+        for &(a, b) in &order {
+            if count <= target {
+                break;
+            }
+            if merged.union(a, b) {
+                count -= 1;
+            }
+        }
+        // A disconnected graph runs out of edges: merge whole components.
+        for v in 1..self.n {
+            if count <= target {
+                break;
+            }
+            if merged.union(0, v) {
+                count -= 1;
+            }
+        }
+
+        let mut label = vec![usize::MAX; self.n];
+        let mut labels = Vec::with_capacity(self.n);
+        let mut next = 0;
+        for v in 0..self.n {
+            let root = merged.find_mut(v);
+            if label[root] == usize::MAX {
+                label[root] = next;
+                next += 1;
+            }
+            labels.push(label[root]);
+        }
+        let edges = order
+            .iter()
+            .map(|&(a, b)| (labels[a], labels[b]))
+            .filter(|(a, b)| a != b)
+            .collect();
+        (labels, Multigraph { n: count, edges })
+    }
+
+    /// Find a minimum cut by trying every partition, for a small graph.
+    fn exact_min_cut(&self) -> (usize, Vec<bool>) {
+        // The last node is always on the `false` side.
+        let sides = |mask: usize| (0..self.n).map(move |v| mask >> v & 1 == 1);
+        let (cut, mask) = (1..1usize << (self.n - 1))
+            .map(|mask| {
+                let side: Vec<bool> = sides(mask).collect();
+                let cut = self.edges.iter().filter(|&&(a, b)| side[a] != side[b]);
+                (cut.count(), mask)
+            })
+            .min()
+            .unwrap();
+        (cut, sides(mask).collect())
+    }
+
+    /// The recursive contraction of Karger and Stein.
+    fn recursive_min_cut<R>(&self, rng: &mut R) -> (usize, Vec<bool>)
+    where
+        R: Rng + ?Sized,
+    {
+        if self.n <= KARGER_STEIN_BASE {
+            return self.exact_min_cut();
+        }
+        let target = karger_stein_target(self.n);
+        let mut best: Option<(usize, Vec<bool>)> = None;
+        for _ in 0..2 {
+            let (labels, contracted) = self.contract(target, rng);
+            let (cut, side) = contracted.recursive_min_cut(rng);
+            if best.as_ref().map_or(true, |&(best, _)| cut < best) {
+                best = Some((cut, labels.iter().map(|&l| side[l]).collect()));
+            }
+        }
+        best.unwrap()
+    }
+}
+
+/// The size under which [`karger_stein_min_cut`] tries every partition.
+const KARGER_STEIN_BASE: usize = 6;
+
+/// The number of nodes left by a contraction step of Karger–Stein,
+/// `⌈1 + n / √2⌉`, with which a minimum cut survives with probability at
+/// least 1/2.
+fn karger_stein_target(n: usize) -> usize {
+    1 + (n * 70_711 + 99_999) / 100_000
+}
+
+/// Return `1 - (1 - p)^trials`.
+fn repeated(p: f64, trials: usize) -> f64 {
+    let (mut failure, mut base, mut exponent) = (1.0, 1.0 - p, trials);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            failure *= base;
+        }
+        base *= base;
+        exponent >>= 1;
+    }
+    1.0 - failure
+}
+
+/// Run `trials` trials of `trial`, and return the best cut.
+fn best_cut<G, F>(
+    graph: G,
+    trials: usize,
+    mut trial: F,
+    probability: f64,
+) -> Option<MinCut<G::NodeId>>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(&Multigraph) -> (usize, Vec<bool>),
+{
+    assert!(trials > 0, "min cut: at least one trial is needed");
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    if nodes.len() < 2 {
+        return None;
+    }
+    let multigraph = Multigraph::new(graph, &nodes);
+    let (cut_size, side) = (0..trials)
+        .map(|_| trial(&multigraph))
+        .min_by_key(|&(cut, _)| cut)
+        .unwrap();
+    let (mut first, mut second) = (Vec::new(), Vec::new());
+    for (&v, in_first) in nodes.iter().zip(side) {
+        if in_first {
+            first.push(v);
+        } else {
+            second.push(v);
+        }
+    }
+    Some(MinCut {
+        cut_size,
+        partition: (first, second),
+        success_probability: repeated(probability, trials),
+    })
+}
+
+/// \[Generic\] Find a minimum cut of a graph with [Karger's algorithm][1]:
+/// random edges are contracted until two nodes are left, which gives a
+/// minimum cut with probability at least **2 / (|V|(|V| - 1))**, and the
+/// best cut of `trials` independent trials is returned.
+///
+/// The cut separates the nodes into two non empty sides with as few edges
+/// as possible between them. The direction and the weights of the edges
+/// are ignored, parallel edges are counted separately, and self loops are
+/// ignored. The trials are independent, and about **|V|² ln |V| / 2**
+/// trials find a minimum cut with high probability. For large graphs,
+/// [`karger_stein_min_cut`] needs much fewer trials.
+///
+/// **Panics** if `trials` is `0`.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `trials`: the number of independent contractions.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * `Some(MinCut)`: the smallest cut found, and a lower bound of the
+///   probability that it is a minimum cut.
+/// * `None`: if the graph has fewer than two nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(trials · |E| α(|V|))**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **α** is the inverse Ackermann function.
+///
+/// [1]: https://en.wikipedia.org/wiki/Karger%27s_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::karger_min_cut;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::SeededRng;
+///
+/// // Two squares with both diagonals, joined by a single edge.
+/// let mut edges = vec![(3, 4)];
+/// for offset in [0, 4] {
+///     for a in 0..4 {
+///         for b in a + 1..4 {
+///             edges.push((offset + a, offset + b));
+///         }
+///     }
+/// }
+/// let graph = UnGraph::<(), ()>::from_edges(&edges);
+///
+/// let cut = karger_min_cut(&graph, 200, &mut SeededRng::new(1)).unwrap();
+/// assert_eq!(cut.cut_size, 1);
+/// assert_eq!(cut.partition.0.len(), 4);
+/// assert!(cut.success_probability > 0.99);
+/// ```
+pub fn karger_min_cut<G, R>(graph: G, trials: usize, rng: &mut R) -> Option<MinCut<G::NodeId>>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    R: Rng + ?Sized,
+{
+    let n = graph.node_identifiers().count() as f64;
+    let trial = |multigraph: &Multigraph| {
+        let (labels, contracted) = multigraph.contract(2, rng);
+        (
+            contracted.edges.len(),
+            labels.iter().map(|&l| l == 0).collect(),
+        )
+    };
+    best_cut(graph, trials, trial, 2.0 / (n * (n - 1.0)))
+}
+
+/// \[Generic\] Find a minimum cut of a graph with the [Karger–Stein][1]
+/// recursive contraction algorithm, and return the best cut of `trials`
+/// independent trials.
+///
+/// Each trial contracts random edges until about **|V| / √2** nodes are
+/// left, twice independently, and continues recursively on both contracted
+/// graphs, which gives a minimum cut with probability
+/// **Ω(1 / log |V|)**. About **log² |V|** trials find a minimum cut with
+/// high probability.
+///
+/// The cut separates the nodes into two non empty sides with as few edges
+/// as possible between them. The direction and the weights of the edges
+/// are ignored, parallel edges are counted separately, and self loops are
+/// ignored.
+///
+/// **Panics** if `trials` is `0`.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `trials`: the number of independent recursive contractions.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * `Some(MinCut)`: the smallest cut found, and a lower bound of the
+///   probability that it is a minimum cut.
+/// * `None`: if the graph has fewer than two nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(trials · |E| log |V|)** for a sparse graph.
+/// * Auxiliary space: **O(|V| + |E| log |V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://doi.org/10.1145/234533.234534
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::karger_stein_min_cut;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::SeededRng;
+///
+/// // Two cycles of 50 nodes, joined by two edges.
+/// let mut edges: Vec<_> = (0..100).map(|i| (i, i / 50 * 50 + (i + 1) % 50)).collect();
+/// edges.extend([(0, 50), (25, 75)]);
+/// let graph = UnGraph::<(), ()>::from_edges(&edges);
+///
+/// let cut = karger_stein_min_cut(&graph, 20, &mut SeededRng::new(1)).unwrap();
+/// assert_eq!(cut.cut_size, 2);
+/// ```
+pub fn karger_stein_min_cut<G, R>(graph: G, trials: usize, rng: &mut R) -> Option<MinCut<G::NodeId>>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    R: Rng + ?Sized,
+{
+    // A level of recursion keeps a minimum cut with probability at least
+    // 1/2 in each branch, so the success probability p of a level is at
+    // least 1 - (1 - p' / 2)² from the p' of the level below.
+    let mut n = graph.node_identifiers().count();
+    let mut probability = 1.0;
+    while n > KARGER_STEIN_BASE {
+        probability -= probability * probability / 4.0;
+        n = karger_stein_target(n);
+    }
+    let trial = |multigraph: &Multigraph| multigraph.recursive_min_cut(rng);
+    best_cut(graph, trials, trial, probability)
+}
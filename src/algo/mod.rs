@@ -4,29 +4,62 @@
 //! so that they are generally applicable. For now, some of these still require
 //! the `Graph` type.
 
+pub mod all_shortest_paths;
 pub mod articulation_points;
+#[cfg(feature = "std")]
+pub mod assortativity;
 pub mod astar;
 pub mod bellman_ford;
 pub mod bridges;
+pub mod centrality;
+pub mod chinese_postman;
 pub mod coloring;
+pub mod covering;
+pub mod degree_distribution;
 pub mod dijkstra;
+#[cfg(feature = "rand")]
+pub mod distance_oracle;
 pub mod dominators;
+pub mod dynamic;
 pub mod feedback_arc_set;
+pub mod feedback_vertex_set;
 pub mod floyd_warshall;
 pub mod ford_fulkerson;
+pub mod hamiltonian;
+pub mod hub_labeling;
 pub mod isomorphism;
 pub mod johnson;
 pub mod k_shortest_path;
+pub mod link_prediction;
 pub mod matching;
 pub mod maximal_cliques;
+#[cfg(feature = "rand")]
+pub mod min_cut;
 pub mod min_spanning_tree;
+pub mod morphology;
+pub mod motifs;
 pub mod page_rank;
+#[cfg(feature = "rayon")]
+pub mod par_bfs;
+pub mod partition;
+pub mod planarity;
+#[cfg(feature = "rayon")]
+pub mod pregel;
+pub mod rcm;
 pub mod scc;
+pub mod shortest_path_dag;
+pub mod shortest_path_tree;
 pub mod simple_paths;
+pub mod simrank;
+#[cfg(feature = "linalg")]
+pub mod spectral;
 pub mod spfa;
 #[cfg(feature = "stable_graph")]
 pub mod steiner_tree;
 pub mod tred;
+pub mod treewidth;
+pub mod tsp;
+pub mod unit_flow;
 
 use alloc::{vec, vec::Vec};
 
@@ -41,37 +74,82 @@ use super::visit::{
 use super::EdgeType;
 use crate::visit::Walker;
 
+pub use all_shortest_paths::all_shortest_paths;
+#[cfg(feature = "std")]
+pub use assortativity::{
+    attribute_assortativity, average_degree_connectivity, average_neighbor_degree,
+    degree_assortativity, numeric_assortativity,
+};
 pub use astar::astar;
 pub use bellman_ford::{bellman_ford, find_negative_cycle};
 pub use bridges::bridges;
-pub use coloring::dsatur_coloring;
-pub use dijkstra::dijkstra;
-pub use feedback_arc_set::greedy_feedback_arc_set;
+pub use centrality::{
+    betweenness_centrality, closeness_centrality, degree_centrality, Normalization, ScoreMap,
+};
+pub use chinese_postman::chinese_postman;
+pub use coloring::{
+    dsatur_coloring, equitable_coloring, list_coloring, minimum_coloring,
+    misra_gries_edge_coloring, rlf_coloring, ColoringError,
+};
+#[cfg(feature = "rand")]
+pub use degree_distribution::normalized_rich_club_coefficient;
+pub use degree_distribution::{
+    degree_ccdf, degree_histogram, degree_sequence, rich_club_coefficient, weighted_degree,
+    weighted_degrees,
+};
+pub use dijkstra::{dijkstra, k_nearest_nodes};
+#[cfg(feature = "rand")]
+pub use distance_oracle::{distance_oracle, DistanceOracle};
+pub use dynamic::DynamicConnectivity;
+pub use feedback_arc_set::{exact_feedback_arc_set, greedy_feedback_arc_set};
+pub use feedback_vertex_set::feedback_vertex_set;
 pub use floyd_warshall::floyd_warshall;
 pub use ford_fulkerson::ford_fulkerson;
+pub use hamiltonian::{hamiltonian_cycle, hamiltonian_path};
+pub use hub_labeling::{pruned_landmark_labeling, HubLabels};
 pub use isomorphism::{
     is_isomorphic, is_isomorphic_matching, is_isomorphic_subgraph, is_isomorphic_subgraph_matching,
     subgraph_isomorphisms_iter,
 };
 pub use johnson::johnson;
 pub use k_shortest_path::k_shortest_path;
+pub use link_prediction::{link_scores, predict_links, LinkScore};
 pub use matching::{greedy_matching, maximum_matching, Matching};
-pub use maximal_cliques::maximal_cliques;
+pub use maximal_cliques::{maximal_cliques, maximum_clique, maximum_weight_clique};
+#[cfg(feature = "rand")]
+pub use min_cut::{karger_min_cut, karger_stein_min_cut, MinCut};
 pub use min_spanning_tree::{min_spanning_tree, min_spanning_tree_prim};
-pub use page_rank::page_rank;
+pub use motifs::{
+    graphlet_census, graphlet_counts, triad_census, Graphlet, GraphletCensus, Orbit, Triad,
+};
+pub use page_rank::{page_rank, page_rank_of_nodes};
+pub use planarity::{is_planar, planarity};
+pub use rcm::{cuthill_mckee, rcm};
+#[cfg(feature = "rayon")]
+pub use scc::par_scc::par_scc;
 #[allow(deprecated)]
 pub use scc::scc;
 pub use scc::{
+    incremental_scc::IncrementalScc,
     kosaraju_scc::kosaraju_scc,
     tarjan_scc::{tarjan_scc, TarjanScc},
 };
+pub use shortest_path_dag::{shortest_path_dag, ShortestPathDag};
+pub use shortest_path_tree::{shortest_path_tree, ShortestPathTree};
 pub use simple_paths::all_simple_paths;
+pub use simrank::{simrank, simrank_pairs, SimRankConfig};
 pub use spfa::spfa;
 #[cfg(feature = "stable_graph")]
 pub use steiner_tree::steiner_tree;
+pub use treewidth::tree_decomposition;
+pub use unit_flow::{approximate_unit_maximum_flow, ApproximateFlow};
 
+#[cfg(feature = "rayon")]
+pub use dijkstra::par_multi_source_dijkstra;
 #[cfg(feature = "rayon")]
 pub use johnson::parallel_johnson;
+#[cfg(feature = "rayon")]
+pub use par_bfs::{par_bfs, par_multi_source_bfs};
 
 /// Return the number of connected components of the graph.
 ///
@@ -183,6 +261,11 @@ where
 /// [`DfsPostOrder`](struct@crate::visit::DfsPostOrder)
 ///   instead of this function.
 ///
+/// To keep a topological order while edges are added, rather than sorting the
+/// graph again after each one, use [`Acyclic`](crate::acyclic::Acyclic),
+/// which updates its order incrementally and rejects the edges that would
+/// create a cycle.
+///
 /// The implementation is iterative.
 ///
 /// # Arguments
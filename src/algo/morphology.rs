@@ -0,0 +1,269 @@
+//! Morphological operations on sets of nodes: dilation, erosion,
+//! boundaries and closure under a predicate.
+//!
+//! Sets of nodes are [`FixedBitSet`]s indexed by the node indices of the
+//! graph (see [`NodeIndexable`]). Bits beyond the node bound of the graph
+//! are ignored, and the returned sets have the length of the node bound.
+//!
+//! The neighborhood of a node is given by [`IntoNeighbors`]: for a directed
+//! graph, the targets of its outgoing edges.
+
+use alloc::vec::Vec;
+
+use fixedbitset::FixedBitSet;
+
+use crate::visit::{IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use crate::Direction::Incoming;
+
+/// Return a copy of `set` with the length of the node bound of `graph`.
+fn resized<G: NodeIndexable>(graph: G, set: &FixedBitSet) -> FixedBitSet {
+    let mut set = set.clone();
+    set.grow(graph.node_bound());
+    if set.len() > graph.node_bound() {
+        set = set.ones().filter(|&i| i < graph.node_bound()).collect();
+        set.grow(graph.node_bound());
+    }
+    set
+}
+
+/// Add to `set` the nodes at most `k` steps away from it, where the steps
+/// of a node are given by `step`.
+fn expand<G, F, I>(graph: G, set: &mut FixedBitSet, k: usize, mut step: F)
+where
+    G: NodeIndexable,
+    F: FnMut(G::NodeId) -> I,
+    I: Iterator<Item = G::NodeId>,
+{
+    let mut frontier: Vec<usize> = set.ones().collect();
+    let mut next = Vec::new();
+    for _ in 0..k {
+        if frontier.is_empty() {
+            break;
+        }
+        for &v in &frontier {
+            for w in step(graph.from_index(v)) {
+                let w = graph.to_index(w);
+                if !set.put(w) {
+                    next.push(w);
+                }
+            }
+        }
+        core::mem::swap(&mut frontier, &mut next);
+        next.clear();
+    }
+}
+
+/// \[Generic\] Return the dilation of a set of nodes by `k` steps: the nodes
+/// that can be reached from the set in at most `k` steps.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `set`: the set of nodes, as a bitset of node indices.
+/// * `k`: the number of steps.
+///
+/// # Returns
+/// * The dilated set, which contains `set`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use fixedbitset::FixedBitSet;
+/// use petgraph::algo::morphology::dilate;
+/// use petgraph::graph::UnGraph;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+/// let set: FixedBitSet = [2].into_iter().collect();
+/// assert_eq!(dilate(&path, &set, 2).ones().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+/// ```
+pub fn dilate<G>(graph: G, set: &FixedBitSet, k: usize) -> FixedBitSet
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    let mut dilated = resized(graph, set);
+    expand(graph, &mut dilated, k, |v| graph.neighbors(v));
+    dilated
+}
+
+/// \[Generic\] Return the erosion of a set of nodes by `k` steps: the nodes
+/// of the set from which every node that can be reached in at most `k` steps
+/// is in the set.
+///
+/// Erosion is the dual of [`dilate`]: the nodes outside of the erosion are
+/// those that reach the outside of the set in at most `k` steps.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `set`: the set of nodes, as a bitset of node indices.
+/// * `k`: the number of steps.
+///
+/// # Returns
+/// * The eroded set, which is contained in `set`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use fixedbitset::FixedBitSet;
+/// use petgraph::algo::morphology::erode;
+/// use petgraph::graph::UnGraph;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+/// let set: FixedBitSet = [0, 1, 2, 3, 4].into_iter().collect();
+/// // Node 0 has no neighbor outside of the set.
+/// assert_eq!(erode(&path, &set, 1).ones().collect::<Vec<_>>(), [0, 1, 2, 3]);
+/// assert_eq!(erode(&path, &set, 2).ones().collect::<Vec<_>>(), [0, 1, 2]);
+/// ```
+pub fn erode<G>(graph: G, set: &FixedBitSet, k: usize) -> FixedBitSet
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let set = resized(graph, set);
+    // The nodes that reach the outside within k steps, found by searching
+    // backwards from it.
+    let mut outside = FixedBitSet::with_capacity(graph.node_bound());
+    for v in graph.node_identifiers() {
+        let i = graph.to_index(v);
+        outside.set(i, !set.contains(i));
+    }
+    expand(graph, &mut outside, k, |v| {
+        graph.neighbors_directed(v, Incoming)
+    });
+    let mut eroded = set;
+    eroded.difference_with(&outside);
+    eroded
+}
+
+/// \[Generic\] Return the inner boundary of a set of nodes: the nodes of the
+/// set that have a neighbor outside of it.
+///
+/// This is the set minus its [erosion](erode) by one step.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+pub fn inner_boundary<G>(graph: G, set: &FixedBitSet) -> FixedBitSet
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    let set = resized(graph, set);
+    let mut boundary = FixedBitSet::with_capacity(graph.node_bound());
+    for v in set.ones() {
+        if graph
+            .neighbors(graph.from_index(v))
+            .any(|w| !set.contains(graph.to_index(w)))
+        {
+            boundary.insert(v);
+        }
+    }
+    boundary
+}
+
+/// \[Generic\] Return the outer boundary of a set of nodes: the nodes outside
+/// of the set that are neighbors of a node of the set.
+///
+/// This is the [dilation](dilate) of the set by one step, minus the set.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use fixedbitset::FixedBitSet;
+/// use petgraph::algo::morphology::{inner_boundary, outer_boundary};
+/// use petgraph::graph::UnGraph;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let set: FixedBitSet = [1, 2, 3].into_iter().collect();
+/// assert_eq!(inner_boundary(&path, &set).ones().collect::<Vec<_>>(), [1, 3]);
+/// assert_eq!(outer_boundary(&path, &set).ones().collect::<Vec<_>>(), [0, 4]);
+/// ```
+pub fn outer_boundary<G>(graph: G, set: &FixedBitSet) -> FixedBitSet
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    let set = resized(graph, set);
+    let mut boundary = dilate(graph, &set, 1);
+    boundary.difference_with(&set);
+    boundary
+}
+
+/// \[Generic\] Return the closure of a set of nodes under a predicate: the
+/// set, and every node that can be reached from it through nodes for which
+/// `predicate` returns `true`.
+///
+/// This is a flood fill: starting from the set, it repeatedly adds the
+/// neighbors that satisfy the predicate, until no more nodes can be added.
+/// The nodes of the set are kept whether they satisfy the predicate or not.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `set`: the set of nodes, as a bitset of node indices.
+/// * `predicate`: closure that returns whether a node can be added, called
+///   at most once for each node.
+///
+/// # Returns
+/// * The closed set, which contains `set`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use fixedbitset::FixedBitSet;
+/// use petgraph::algo::morphology::closure;
+/// use petgraph::graph::UnGraph;
+///
+/// // Pixels of a row of an image, with their intensities.
+/// let mut row = UnGraph::<u8, ()>::default();
+/// let pixels: Vec<_> = [9, 8, 2, 7, 9, 9, 1, 9].map(|x| row.add_node(x)).to_vec();
+/// for pair in pixels.windows(2) {
+///     row.add_edge(pair[0], pair[1], ());
+/// }
+///
+/// // Grow the region of pixel 4 over the bright pixels.
+/// let seed: FixedBitSet = [4].into_iter().collect();
+/// let region = closure(&row, &seed, |v| row[v] > 5);
+/// assert_eq!(region.ones().collect::<Vec<_>>(), [3, 4, 5]);
+/// ```
+pub fn closure<G, F>(graph: G, set: &FixedBitSet, mut predicate: F) -> FixedBitSet
+where
+    G: IntoNeighbors + NodeIndexable,
+    F: FnMut(G::NodeId) -> bool,
+{
+    let mut closed = resized(graph, set);
+    // The nodes that failed the predicate, so that it is called only once.
+    let mut rejected = FixedBitSet::with_capacity(graph.node_bound());
+    let mut stack: Vec<usize> = closed.ones().collect();
+    while let Some(v) = stack.pop() {
+        for w in graph.neighbors(graph.from_index(v)) {
+            let i = graph.to_index(w);
+            if closed.contains(i) || rejected.contains(i) {
+                continue;
+            }
+            if predicate(w) {
+                closed.insert(i);
+                stack.push(i);
+            } else {
+                rejected.insert(i);
+            }
+        }
+    }
+    closed
+}
@@ -0,0 +1,678 @@
+//! Exact counts of the small connected subgraphs of a graph: the triad
+//! census of a directed graph, and the graphlets with up to four nodes of
+//! an undirected graph, with the [orbits][1] of each node.
+//!
+//! The counts are computed combinatorially, from the degrees, the triangles
+//! and the 4-cycles around each node, instead of matching every pattern.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Graphlets
+
+use alloc::{vec, vec::Vec};
+
+use hashbrown::HashSet;
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeCount, NodeIndexable};
+
+/// The 16 isomorphism classes of directed graphs on three nodes, in the
+/// order of the triad census.
+///
+/// The names are the M-A-N labels of Holland and Leinhardt: the numbers of
+/// mutual, asymmetric and null dyads, followed by a letter that tells the
+/// classes with the same numbers apart: **D**own, **U**p, **C**yclic or
+/// **T**ransitive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Triad {
+    /// No arc.
+    T003,
+    /// A single arc.
+    T012,
+    /// A single mutual dyad.
+    T102,
+    /// A node with arcs to the two others.
+    T021D,
+    /// A node with arcs from the two others.
+    T021U,
+    /// A path of two arcs.
+    T021C,
+    /// A mutual dyad and an arc from the third node.
+    T111D,
+    /// A mutual dyad and an arc to the third node.
+    T111U,
+    /// A transitive triangle of arcs.
+    T030T,
+    /// A cycle of arcs.
+    T030C,
+    /// Two mutual dyads.
+    T201,
+    /// A mutual dyad and arcs from the third node to both of its nodes.
+    T120D,
+    /// A mutual dyad and arcs from both of its nodes to the third node.
+    T120U,
+    /// A mutual dyad and a path of two arcs through the third node.
+    T120C,
+    /// Two mutual dyads and an arc.
+    T210,
+    /// Three mutual dyads.
+    T300,
+}
+
+impl Triad {
+    /// All the classes, in the order of the census.
+    pub const ALL: [Triad; 16] = [
+        Triad::T003,
+        Triad::T012,
+        Triad::T102,
+        Triad::T021D,
+        Triad::T021U,
+        Triad::T021C,
+        Triad::T111D,
+        Triad::T111U,
+        Triad::T030T,
+        Triad::T030C,
+        Triad::T201,
+        Triad::T120D,
+        Triad::T120U,
+        Triad::T120C,
+        Triad::T210,
+        Triad::T300,
+    ];
+
+    /// Return the M-A-N label of the class, such as `"021D"`.
+    pub fn name(self) -> &'static str {
+        [
+            "003", "012", "102", "021D", "021U", "021C", "111D", "111U", "030T", "030C", "201",
+            "120D", "120U", "120C", "210", "300",
+        ][self as usize]
+    }
+}
+
+/// The class of the triad `(v, u, w)`, from the code of its arcs: `v -> u`
+/// counts 1, `u -> v` 2, `v -> w` 4, `w -> v` 8, `u -> w` 16 and `w -> u`
+/// 32.
+const TRIAD_CODES: [u8; 64] = [
+    0, 1, 1, 2, 1, 3, 5, 7, 1, 5, 4, 6, 2, 7, 6, 10, 1, 5, 3, 7, 4, 8, 8, 12, 5, 9, 8, 13, 6, 13,
+    11, 14, 1, 4, 5, 6, 5, 8, 9, 13, 3, 8, 8, 11, 7, 12, 13, 14, 2, 6, 7, 10, 6, 11, 13, 14, 7, 13,
+    12, 14, 10, 14, 14, 15,
+];
+
+/// \[Generic\] Count the triads of a directed graph: the number of sets of
+/// three nodes that induce each of the 16 [`Triad`] classes.
+///
+/// Uses the algorithm of [Batagelj and Mrvar][1], which only looks at the
+/// triads with at least one arc and deduces the number of empty triads.
+/// Self loops and parallel arcs are ignored, and each edge of an undirected
+/// graph counts as a mutual dyad.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * The number of triads of each class, indexed by `Triad as usize`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| Δ)** expected.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **Δ** is the largest degree.
+///
+/// [1]: https://doi.org/10.1016/S0378-8733(01)00035-1
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{triad_census, Triad};
+/// use petgraph::graph::DiGraph;
+///
+/// // A cycle of arcs, and a fourth node with an arc to one of them.
+/// let graph = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 0)]);
+/// let census = triad_census(&graph);
+/// assert_eq!(census[Triad::T030C as usize], 1);
+/// assert_eq!(census[Triad::T021C as usize], 1);
+/// assert_eq!(census[Triad::T021U as usize], 1);
+/// assert_eq!(census[Triad::T012 as usize], 1);
+/// assert_eq!(census.iter().sum::<u64>(), 4);
+/// ```
+pub fn triad_census<G>(graph: G) -> [u64; 16]
+where
+    G: IntoEdgeReferences + NodeIndexable + NodeCount + GraphProp,
+{
+    let bound = graph.node_bound();
+    let mut arcs = HashSet::new();
+    let mut neighbors = vec![Vec::new(); bound];
+    for edge in graph.edge_references() {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        if a == b {
+            continue;
+        }
+        arcs.insert((a, b));
+        if !graph.is_directed() {
+            arcs.insert((b, a));
+        }
+        neighbors[a].push(b);
+        neighbors[b].push(a);
+    }
+    for list in &mut neighbors {
+        list.sort_unstable();
+        list.dedup();
+    }
+    let arc = |a: usize, b: usize| arcs.contains(&(a, b));
+    let code = |v: usize, u: usize, w: usize| {
+        [
+            (v, u, 1),
+            (u, v, 2),
+            (v, w, 4),
+            (w, v, 8),
+            (u, w, 16),
+            (w, u, 32),
+        ]
+        .iter()
+        .filter(|&&(a, b, _)| arc(a, b))
+        .map(|&(_, _, bit)| bit)
+        .sum::<usize>()
+    };
+
+    let n = graph.node_count() as u64;
+    let mut census = [0u64; 16];
+    let mut union = Vec::new();
+    for v in 0..bound {
+        for &u in neighbors[v].iter().filter(|&&u| v < u) {
+            // The nodes adjacent to v or u.
+            union.clear();
+            union.extend(neighbors[v].iter().chain(&neighbors[u]).copied());
+            union.sort_unstable();
+            union.dedup();
+            union.retain(|&w| w != v && w != u);
+            let dyad = if arc(v, u) && arc(u, v) {
+                Triad::T102
+            } else {
+                Triad::T012
+            };
+            census[dyad as usize] += n - union.len() as u64 - 2;
+            // Each connected triad is counted from its smallest dyad.
+            for &w in &union {
+                if u < w || (v < w && neighbors[v].binary_search(&w).is_err()) {
+                    census[TRIAD_CODES[code(v, u, w)] as usize] += 1;
+                }
+            }
+        }
+    }
+    let all = if n < 3 { 0 } else { n * (n - 1) * (n - 2) / 6 };
+    census[Triad::T003 as usize] = all - census.iter().sum::<u64>();
+    census
+}
+
+/// The connected graphs with two to four nodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Graphlet {
+    /// A single edge.
+    Edge,
+    /// A path of two edges.
+    Path3,
+    /// A triangle.
+    Triangle,
+    /// A path of three edges.
+    Path4,
+    /// A node adjacent to three others, the claw.
+    Star,
+    /// A cycle of four nodes.
+    Cycle4,
+    /// A triangle with a pendant edge, the paw.
+    TailedTriangle,
+    /// A complete graph on four nodes without one edge.
+    Diamond,
+    /// A complete graph on four nodes.
+    Clique4,
+}
+
+impl Graphlet {
+    /// All the graphlets.
+    pub const ALL: [Graphlet; 9] = [
+        Graphlet::Edge,
+        Graphlet::Path3,
+        Graphlet::Triangle,
+        Graphlet::Path4,
+        Graphlet::Star,
+        Graphlet::Cycle4,
+        Graphlet::TailedTriangle,
+        Graphlet::Diamond,
+        Graphlet::Clique4,
+    ];
+}
+
+/// The positions of a node in a [`Graphlet`], up to its automorphisms.
+///
+/// The orbits are ordered by graphlet, and within a graphlet by the degree
+/// of the node in it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Orbit {
+    /// An end of an [`Edge`](Graphlet::Edge).
+    Edge,
+    /// An end of a [`Path3`](Graphlet::Path3).
+    Path3End,
+    /// The middle of a [`Path3`](Graphlet::Path3).
+    Path3Center,
+    /// A node of a [`Triangle`](Graphlet::Triangle).
+    Triangle,
+    /// An end of a [`Path4`](Graphlet::Path4).
+    Path4End,
+    /// An inner node of a [`Path4`](Graphlet::Path4).
+    Path4Inner,
+    /// A leaf of a [`Star`](Graphlet::Star).
+    StarLeaf,
+    /// The center of a [`Star`](Graphlet::Star).
+    StarCenter,
+    /// A node of a [`Cycle4`](Graphlet::Cycle4).
+    Cycle4,
+    /// The end of the tail of a [`TailedTriangle`](Graphlet::TailedTriangle).
+    TailedTriangleTail,
+    /// A node of the triangle of a [`TailedTriangle`](Graphlet::TailedTriangle)
+    /// away from the tail.
+    TailedTriangleSide,
+    /// The node of a [`TailedTriangle`](Graphlet::TailedTriangle) that has
+    /// the tail.
+    TailedTriangleCenter,
+    /// A node of degree 2 of a [`Diamond`](Graphlet::Diamond).
+    DiamondOuter,
+    /// A node of degree 3 of a [`Diamond`](Graphlet::Diamond).
+    DiamondInner,
+    /// A node of a [`Clique4`](Graphlet::Clique4).
+    Clique4,
+}
+
+impl Orbit {
+    /// All the orbits.
+    pub const ALL: [Orbit; 15] = [
+        Orbit::Edge,
+        Orbit::Path3End,
+        Orbit::Path3Center,
+        Orbit::Triangle,
+        Orbit::Path4End,
+        Orbit::Path4Inner,
+        Orbit::StarLeaf,
+        Orbit::StarCenter,
+        Orbit::Cycle4,
+        Orbit::TailedTriangleTail,
+        Orbit::TailedTriangleSide,
+        Orbit::TailedTriangleCenter,
+        Orbit::DiamondOuter,
+        Orbit::DiamondInner,
+        Orbit::Clique4,
+    ];
+
+    /// Return the graphlet of the orbit.
+    pub fn graphlet(self) -> Graphlet {
+        match self {
+            Orbit::Edge => Graphlet::Edge,
+            Orbit::Path3End | Orbit::Path3Center => Graphlet::Path3,
+            Orbit::Triangle => Graphlet::Triangle,
+            Orbit::Path4End | Orbit::Path4Inner => Graphlet::Path4,
+            Orbit::StarLeaf | Orbit::StarCenter => Graphlet::Star,
+            Orbit::Cycle4 => Graphlet::Cycle4,
+            Orbit::TailedTriangleTail | Orbit::TailedTriangleSide | Orbit::TailedTriangleCenter => {
+                Graphlet::TailedTriangle
+            }
+            Orbit::DiamondOuter | Orbit::DiamondInner => Graphlet::Diamond,
+            Orbit::Clique4 => Graphlet::Clique4,
+        }
+    }
+}
+
+/// The graphlet counts of a graph, as computed by [`graphlet_census`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphletCensus {
+    counts: [u64; 9],
+    orbits: Vec<[u64; 15]>,
+}
+
+impl GraphletCensus {
+    /// Return the number of induced copies of `graphlet` in the graph.
+    pub fn count(&self, graphlet: Graphlet) -> u64 {
+        self.counts[graphlet as usize]
+    }
+
+    /// Return the number of induced copies of all the graphlets, indexed by
+    /// `Graphlet as usize`.
+    pub fn counts(&self) -> &[u64; 9] {
+        &self.counts
+    }
+
+    /// Return the number of induced graphlets where the node of index
+    /// `node` is in the orbit `orbit`.
+    ///
+    /// **Panics** if `node` is not below the node bound of the graph.
+    pub fn orbit_count(&self, node: usize, orbit: Orbit) -> u64 {
+        self.orbits[node][orbit as usize]
+    }
+
+    /// Return the counts of all the orbits of the node of index `node`,
+    /// indexed by `Orbit as usize`: its graphlet degree vector.
+    ///
+    /// **Panics** if `node` is not below the node bound of the graph.
+    pub fn orbit_counts(&self, node: usize) -> &[u64; 15] {
+        &self.orbits[node]
+    }
+}
+
+fn choose2(n: u64) -> u64 {
+    n * n.saturating_sub(1) / 2
+}
+
+fn choose3(n: u64) -> u64 {
+    n * n.saturating_sub(1) * n.saturating_sub(2) / 6
+}
+
+/// \[Generic\] Count the graphlets of a graph: the number of sets of two to
+/// four nodes that induce each connected [`Graphlet`], and the number of
+/// times each node is in each [`Orbit`].
+///
+/// The direction of the edges, self loops and parallel edges are ignored.
+/// For each node, the number of subgraphs that contain it in a given
+/// position, which may not be induced, is counted from the degrees and the
+/// triangles of its neighborhood, and the induced counts are deduced from
+/// how many such subgraphs each graphlet contains.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * The counts of the graphlets, and of the orbits of each node by node
+///   index.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + Σ deg(v)² + K)**, where **K** is the cost
+///   of listing the 4-cliques, at most **O(|E| Δ²)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges,
+/// **deg(v)** is the degree of `v` and **Δ** is the largest degree.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{graphlet_census, Graphlet, Orbit};
+/// use petgraph::graph::UnGraph;
+///
+/// // A triangle with a tail.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let census = graphlet_census(&graph);
+/// assert_eq!(census.count(Graphlet::Triangle), 1);
+/// assert_eq!(census.count(Graphlet::Path3), 2);
+/// assert_eq!(census.count(Graphlet::TailedTriangle), 1);
+/// assert_eq!(census.count(Graphlet::Path4), 0);
+/// assert_eq!(census.orbit_count(2, Orbit::TailedTriangleCenter), 1);
+/// assert_eq!(census.orbit_count(3, Orbit::Path3End), 2);
+/// ```
+pub fn graphlet_census<G>(graph: G) -> GraphletCensus
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut orbits = vec![[0u64; 15]; graph.node_bound()];
+    visit_orbits(graph, |v, counts| orbits[v] = counts);
+    let mut totals = [0u64; 15];
+    for counts in &orbits {
+        for (total, &count) in totals.iter_mut().zip(counts) {
+            *total += count;
+        }
+    }
+    GraphletCensus {
+        counts: graphlet_totals(&totals),
+        orbits,
+    }
+}
+
+/// \[Generic\] Count the graphlets of a graph: the number of sets of two to
+/// four nodes that induce each connected [`Graphlet`], without keeping the
+/// orbit counts of each node.
+///
+/// The counts are those of [`graphlet_census`], which also keeps the
+/// **15 · |V|** orbit counts, for when only the totals are needed.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * The number of induced copies of each graphlet, indexed by
+///   `Graphlet as usize`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + Σ deg(v)² + K)**, where **K** is the cost
+///   of listing the 4-cliques, at most **O(|E| Δ²)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges,
+/// **deg(v)** is the degree of `v` and **Δ** is the largest degree.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{graphlet_counts, Graphlet};
+/// use petgraph::graph::UnGraph;
+///
+/// // A cycle of four nodes with a chord.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+/// let counts = graphlet_counts(&graph);
+/// assert_eq!(counts[Graphlet::Diamond as usize], 1);
+/// assert_eq!(counts[Graphlet::Triangle as usize], 2);
+/// assert_eq!(counts[Graphlet::Cycle4 as usize], 0);
+/// ```
+pub fn graphlet_counts<G>(graph: G) -> [u64; 9]
+where
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut totals = [0u64; 15];
+    visit_orbits(graph, |_, counts| {
+        for (total, &count) in totals.iter_mut().zip(&counts) {
+            *total += count;
+        }
+    });
+    graphlet_totals(&totals)
+}
+
+/// Return the graphlet counts from the orbit counts summed over all nodes.
+fn graphlet_totals(totals: &[u64; 15]) -> [u64; 9] {
+    let total = |orbit: Orbit| totals[orbit as usize];
+    [
+        total(Orbit::Edge) / 2,
+        total(Orbit::Path3Center),
+        total(Orbit::Triangle) / 3,
+        total(Orbit::Path4End) / 2,
+        total(Orbit::StarCenter),
+        total(Orbit::Cycle4) / 4,
+        total(Orbit::TailedTriangleCenter),
+        total(Orbit::DiamondInner) / 2,
+        total(Orbit::Clique4) / 4,
+    ]
+}
+
+/// Compute the orbit counts of each node, indexed by `Orbit as usize`, and
+/// call `visit` with the index of the node and its counts.
+fn visit_orbits<G, F>(graph: G, mut visit: F)
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    F: FnMut(usize, [u64; 15]),
+{
+    let n = graph.node_bound();
+    let mut adjacency = vec![Vec::new(); n];
+    for edge in graph.edge_references() {
+        let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+        if a != b {
+            adjacency[a].push(b);
+            adjacency[b].push(a);
+        }
+    }
+    for list in &mut adjacency {
+        list.sort_unstable();
+        list.dedup();
+    }
+    let degree: Vec<u64> = adjacency.iter().map(|list| list.len() as u64).collect();
+
+    // The triangles on each edge, in the order of the adjacency lists, and
+    // on each node.
+    let mut marked = vec![false; n];
+    let mut edge_triangles: Vec<Vec<u64>> = Vec::with_capacity(n);
+    for v in 0..n {
+        for &a in &adjacency[v] {
+            marked[a] = true;
+        }
+        edge_triangles.push(
+            adjacency[v]
+                .iter()
+                .map(|&a| adjacency[a].iter().filter(|&&b| marked[b]).count() as u64)
+                .collect(),
+        );
+        for &a in &adjacency[v] {
+            marked[a] = false;
+        }
+    }
+    let triangles: Vec<u64> = edge_triangles
+        .iter()
+        .map(|list| list.iter().sum::<u64>() / 2)
+        .collect();
+
+    // The 4-cliques of each node, each listed once from its nodes in
+    // increasing order.
+    let mut cliques = vec![0u64; n];
+    let mut common = Vec::new();
+    let mut in_common = vec![false; n];
+    for v in 0..n {
+        for &a in &adjacency[v] {
+            marked[a] = true;
+        }
+        for &a in adjacency[v].iter().filter(|&&a| a > v) {
+            common.clear();
+            common.extend(adjacency[a].iter().copied().filter(|&b| b > a && marked[b]));
+            for &b in &common {
+                in_common[b] = true;
+            }
+            for &b in &common {
+                for &c in adjacency[b].iter().filter(|&&c| c > b && in_common[c]) {
+                    for x in [v, a, b, c] {
+                        cliques[x] += 1;
+                    }
+                }
+            }
+            for &b in &common {
+                in_common[b] = false;
+            }
+        }
+        for &a in &adjacency[v] {
+            marked[a] = false;
+        }
+    }
+
+    // (the number of neighbors of each node) - 1, summed over its neighbors.
+    let two_paths: Vec<u64> = adjacency
+        .iter()
+        .map(|list| list.iter().map(|&a| degree[a] - 1).sum())
+        .collect();
+
+    let mut paths_to = vec![0u64; n];
+    let mut reached = Vec::new();
+    for v in 0..n {
+        let d = degree[v];
+        let t = triangles[v];
+        let neighbors = &adjacency[v];
+
+        // Subgraphs, not necessarily induced, by the orbit of v.
+        let path_end = neighbors
+            .iter()
+            .map(|&a| two_paths[a] - (d - 1))
+            .sum::<u64>()
+            - 2 * t;
+        let path_inner = neighbors
+            .iter()
+            .map(|&a| (d - 1) * (degree[a] - 1))
+            .sum::<u64>()
+            - 2 * t;
+        let star_center = choose3(d);
+        let star_leaf = neighbors
+            .iter()
+            .map(|&a| choose2(degree[a] - 1))
+            .sum::<u64>();
+        let tail_center = t * d.saturating_sub(2);
+        let mut tail_side = 0;
+        let mut tail_end = 0;
+        let mut diamond_inner = 0;
+        for (&a, &ta) in neighbors.iter().zip(&edge_triangles[v]) {
+            tail_side += ta * degree[a].saturating_sub(2);
+            tail_end += triangles[a] - ta;
+            diamond_inner += choose2(ta);
+        }
+
+        // The 4-cycles through v are pairs of paths v - a - w, and the
+        // diamonds with v outside of the middle edge a - b are the other
+        // common neighbors of a and b.
+        for &a in neighbors {
+            marked[a] = true;
+        }
+        let mut diamond_outer = 0;
+        for &a in neighbors {
+            for (&w, &tw) in adjacency[a].iter().zip(&edge_triangles[a]) {
+                if w == v {
+                    continue;
+                }
+                if paths_to[w] == 0 {
+                    reached.push(w);
+                }
+                paths_to[w] += 1;
+                if marked[w] {
+                    diamond_outer += tw - 1;
+                }
+            }
+        }
+        diamond_outer /= 2;
+        let mut cycle = 0;
+        for w in reached.drain(..) {
+            cycle += choose2(paths_to[w]);
+            paths_to[w] = 0;
+        }
+        for &a in neighbors {
+            marked[a] = false;
+        }
+
+        // Deduce the induced counts, from the largest graphlets down.
+        let clique = cliques[v];
+        let diamond_inner = diamond_inner - 3 * clique;
+        let diamond_outer = diamond_outer - 3 * clique;
+        let cycle = cycle - diamond_inner - diamond_outer - 3 * clique;
+        let tail_center = tail_center - 2 * diamond_inner - 3 * clique;
+        let tail_side = tail_side - 2 * diamond_inner - 2 * diamond_outer - 6 * clique;
+        let tail_end = tail_end - 2 * diamond_outer - 3 * clique;
+        let star_center = star_center - tail_center - diamond_inner - clique;
+        let star_leaf =
+            star_leaf - tail_end - tail_side - 2 * diamond_outer - diamond_inner - 3 * clique;
+        let path_end = path_end
+            - 2 * cycle
+            - 2 * tail_end
+            - tail_side
+            - 4 * diamond_outer
+            - 2 * diamond_inner
+            - 6 * clique;
+        let path_inner = path_inner
+            - 2 * cycle
+            - 2 * tail_center
+            - tail_side
+            - 2 * diamond_outer
+            - 4 * diamond_inner
+            - 6 * clique;
+
+        visit(
+            v,
+            [
+                d,
+                two_paths[v] - 2 * t,
+                choose2(d) - t,
+                t,
+                path_end,
+                path_inner,
+                star_leaf,
+                star_center,
+                cycle,
+                tail_end,
+                tail_side,
+                tail_center,
+                diamond_outer,
+                diamond_inner,
+                clique,
+            ],
+        );
+    }
+}
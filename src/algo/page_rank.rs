@@ -1,8 +1,10 @@
 use alloc::{vec, vec::Vec};
 
 use super::UnitMeasure;
-use crate::visit::{EdgeRef, IntoEdges, NodeCount, NodeIndexable};
+use crate::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable};
 
+#[cfg(feature = "rayon")]
+use super::pregel::{Pregel, VertexProgram};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
@@ -16,7 +18,14 @@ use rayon::prelude::*;
 /// * `nb_iter`: number of iterations of the main loop.
 ///
 /// # Returns
-/// * A `Vec` mapping each node index to its rank.
+/// * A `Vec` mapping each node index to its rank. It can be wrapped in a
+///   [`ScoreMap`](crate::algo::ScoreMap) with
+///   [`ScoreMap::from_indexed`](crate::algo::ScoreMap::from_indexed) to rank
+///   the nodes.
+///
+/// The node indices should range from `0` to `node_count - 1`. For a graph
+/// with holes in its node indices, like a `StableGraph` after removing nodes,
+/// use [`page_rank_of_nodes`].
 ///
 /// # Panics
 /// The damping factor should be a measure (like `f32` or `f64`) between 0 and 1 (0 and 1 included). Otherwise, it panics.
@@ -65,7 +74,58 @@ where
     G: NodeCount + IntoEdges + NodeIndexable,
     D: UnitMeasure + Copy,
 {
-    let node_count = graph.node_count();
+    let nodes = (0..graph.node_count())
+        .map(|i| graph.from_index(i))
+        .collect();
+    page_rank_over(graph, nodes, damping_factor, nb_iter)
+}
+
+/// Page Rank algorithm, for graphs with holes in their node indices.
+///
+/// See [`page_rank`], which this matches on graphs without holes. The ranks
+/// are in the order of [`IntoNodeIdentifiers::node_identifiers`], and only
+/// the nodes of the graph get a rank.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::page_rank_of_nodes;
+/// use petgraph::visit::NodeFiltered;
+/// use petgraph::Graph;
+///
+/// let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 1)]);
+/// // Without node 0, the nodes 1, 2 and 3 form a cycle and rank the same.
+/// let cycle = NodeFiltered::from_fn(&g, |n| n.index() != 0);
+/// let ranks = page_rank_of_nodes(&cycle, 0.85_f64, 20);
+/// assert_eq!(ranks.len(), 3);
+/// assert!(ranks.iter().all(|r| (r - 1.0 / 3.0).abs() < 1e-9));
+/// ```
+#[track_caller]
+pub fn page_rank_of_nodes<G, D>(graph: G, damping_factor: D, nb_iter: usize) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers,
+    D: UnitMeasure + Copy,
+{
+    page_rank_over(
+        graph,
+        graph.node_identifiers().collect(),
+        damping_factor,
+        nb_iter,
+    )
+}
+
+/// Page Rank over the given nodes, with the ranks in their order.
+#[track_caller]
+fn page_rank_over<G, D>(
+    graph: G,
+    nodes: Vec<G::NodeId>,
+    damping_factor: D,
+    nb_iter: usize,
+) -> Vec<D>
+where
+    G: IntoEdges,
+    D: UnitMeasure + Copy,
+{
+    let node_count = nodes.len();
     if node_count == 0 {
         return vec![];
     }
@@ -75,21 +135,21 @@ where
     );
     let nb = D::from_usize(node_count);
     let mut ranks = vec![D::one() / nb; node_count];
-    let nodeix = |i| graph.from_index(i);
-    let out_degrees: Vec<D> = (0..node_count)
-        .map(|i| graph.edges(nodeix(i)).map(|_| D::one()).sum::<D>())
+    let out_degrees: Vec<D> = nodes
+        .iter()
+        .map(|&a| graph.edges(a).map(|_| D::one()).sum::<D>())
         .collect();
 
     for _ in 0..nb_iter {
-        let pi = (0..node_count)
-            .enumerate()
-            .map(|(v, _)| {
+        let pi = nodes
+            .iter()
+            .map(|&v| {
                 ranks
                     .iter()
                     .enumerate()
                     .map(|(w, r)| {
-                        let mut w_out_edges = graph.edges(nodeix(w));
-                        if w_out_edges.any(|e| e.target() == nodeix(v)) {
+                        let mut w_out_edges = graph.edges(nodes[w]);
+                        if w_out_edges.any(|e| e.target() == v) {
                             damping_factor * *r / out_degrees[w]
                         } else if out_degrees[w] == D::zero() {
                             damping_factor * *r / nb // stochastic matrix condition
@@ -106,30 +166,49 @@ where
     ranks
 }
 
-#[allow(dead_code)]
-fn out_edges_info<G, D>(graph: G, index_w: usize, index_v: usize) -> (D, bool)
+/// One iteration of Page Rank, as a vertex program: each node sends its rank,
+/// and its share of it, to each of its successors, once whatever the number of
+/// edges to it.
+#[cfg(feature = "rayon")]
+struct PageRankStep<D> {
+    damping_factor: D,
+    nb: D,
+    /// The total rank of the nodes with outgoing edges.
+    linked: D,
+    /// The total rank of the nodes without outgoing edges.
+    dangling: D,
+}
+
+#[cfg(feature = "rayon")]
+impl<D> VertexProgram for PageRankStep<D>
 where
-    G: NodeCount + IntoEdges + NodeIndexable + core::marker::Sync,
-    D: UnitMeasure + Copy + core::marker::Send + core::marker::Sync,
+    D: UnitMeasure + Copy + Send + Sync,
 {
-    let node_w = graph.from_index(index_w);
-    let node_v = graph.from_index(index_v);
-    let mut out_edges = graph.edges(node_w);
-    let mut out_edge = out_edges.next();
-    let mut out_degree = D::zero();
-    let mut flag_points_to = false;
-    while let Some(edge) = out_edge {
-        out_degree = out_degree + D::one();
-        if edge.target() == node_v {
-            flag_points_to = true;
-        }
-        out_edge = out_edges.next();
+    type Value = D;
+    type Message = (D, D);
+
+    fn message(&self, rank: &D, out_degree: usize) -> Option<(D, D)> {
+        Some((*rank / D::from_usize(out_degree), *rank))
+    }
+
+    fn combine(&self, a: (D, D), b: (D, D)) -> (D, D) {
+        (a.0 + b.0, a.1 + b.1)
+    }
+
+    fn compute(&self, _: usize, _: &D, message: Option<(D, D)>) -> D {
+        let (share, linked_to) = message.unwrap_or((D::zero(), D::zero()));
+        self.damping_factor * share
+            + (D::one() - self.damping_factor) * (self.linked - linked_to) / self.nb // random jumps
+            + self.damping_factor * self.dangling / self.nb // stochastic matrix condition
     }
-    (out_degree, flag_points_to)
 }
+
 /// Parallel Page Rank algorithm.
 ///
-/// See [`page_rank`].
+/// See [`page_rank`]. The iterations run as a [`VertexProgram`] on
+/// [`Pregel`], in **O(|V| + |E|)** time each, and stop early once the squared
+/// distance between the ranks of two iterations is at most `tol`, by default
+/// *1e-6*.
 #[cfg(feature = "rayon")]
 pub fn parallel_page_rank<G, D>(
     graph: G,
@@ -141,7 +220,44 @@ where
     G: NodeCount + IntoEdges + NodeIndexable + core::marker::Sync,
     D: UnitMeasure + Copy + core::marker::Send + core::marker::Sync,
 {
-    let node_count = graph.node_count();
+    let nodes = (0..graph.node_count())
+        .map(|i| graph.from_index(i))
+        .collect();
+    parallel_page_rank_over(graph, nodes, damping_factor, nb_iter, tol)
+}
+
+/// Parallel Page Rank algorithm, for graphs with holes in their node indices.
+///
+/// See [`parallel_page_rank`] and [`page_rank_of_nodes`].
+#[cfg(feature = "rayon")]
+pub fn parallel_page_rank_of_nodes<G, D>(
+    graph: G,
+    damping_factor: D,
+    nb_iter: usize,
+    tol: Option<D>,
+) -> Vec<D>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    D: UnitMeasure + Copy + core::marker::Send + core::marker::Sync,
+{
+    let nodes = graph.node_identifiers().collect();
+    parallel_page_rank_over(graph, nodes, damping_factor, nb_iter, tol)
+}
+
+/// Parallel Page Rank over the given nodes, with the ranks in their order.
+#[cfg(feature = "rayon")]
+fn parallel_page_rank_over<G, D>(
+    graph: G,
+    nodes: Vec<G::NodeId>,
+    damping_factor: D,
+    nb_iter: usize,
+    tol: Option<D>,
+) -> Vec<D>
+where
+    G: IntoEdges + NodeIndexable,
+    D: UnitMeasure + Copy + core::marker::Send + core::marker::Sync,
+{
+    let node_count = nodes.len();
     if node_count == 0 {
         return vec![];
     }
@@ -149,47 +265,44 @@ where
         D::zero() <= damping_factor && damping_factor <= D::one(),
         "Damping factor should be between 0 et 1."
     );
-    let mut tolerance = D::default_tol();
-    if let Some(_tol) = tol {
-        tolerance = _tol;
-    }
+    let tolerance = tol.unwrap_or_else(D::default_tol);
     let nb = D::from_usize(node_count);
-    let mut ranks: Vec<D> = (0..node_count)
-        .into_par_iter()
-        .map(|_| D::one() / nb)
-        .collect();
+    let mut pregel = Pregel::with_nodes(graph, nodes, |_| D::one() / nb);
+    pregel.dedup_edges();
+    let dangling_nodes: Vec<bool> = (0..node_count).map(|i| pregel.out_degree(i) == 0).collect();
     for _ in 0..nb_iter {
-        let pi = (0..node_count)
-            .into_par_iter()
-            .map(|v| {
-                ranks
-                    .iter()
-                    .enumerate()
-                    .map(|(w, r)| {
-                        let (out_deg, w_points_to_v) = out_edges_info(graph, w, v);
-                        if w_points_to_v {
-                            damping_factor * *r / out_deg
-                        } else if out_deg == D::zero() {
-                            damping_factor * *r / nb // stochastic matrix condition
-                        } else {
-                            (D::one() - damping_factor) * *r / nb // random jumps
-                        }
-                    })
-                    .sum::<D>()
+        let ranks = pregel.values().to_vec();
+        let (linked, dangling) = ranks
+            .par_iter()
+            .zip(&dangling_nodes)
+            .map(|(r, &dangling)| {
+                if dangling {
+                    (D::zero(), *r)
+                } else {
+                    (*r, D::zero())
+                }
             })
-            .collect::<Vec<D>>();
-        let sum = pi.par_iter().map(|score| *score).sum::<D>();
-        let new_ranks = pi.par_iter().map(|r| *r / sum).collect::<Vec<D>>();
-        let squared_norm_2 = new_ranks
+            .reduce(|| (D::zero(), D::zero()), |a, b| (a.0 + b.0, a.1 + b.1));
+        pregel.superstep(&PageRankStep {
+            damping_factor,
+            nb,
+            linked,
+            dangling,
+        });
+        let sum = pregel.values().par_iter().copied().sum::<D>();
+        pregel
+            .values_mut()
+            .par_iter_mut()
+            .for_each(|r| *r = *r / sum);
+        let squared_norm_2 = pregel
+            .values()
             .par_iter()
             .zip(&ranks)
             .map(|(new, old)| (*new - *old) * (*new - *old))
             .sum::<D>();
         if squared_norm_2 <= tolerance {
             return ranks;
-        } else {
-            ranks = new_ranks;
         }
     }
-    ranks
+    pregel.into_values()
 }
@@ -0,0 +1,154 @@
+//! Parallel breadth-first search.
+
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use crate::visit::{IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use crate::Direction::Incoming;
+
+/// The number of edges of the unvisited nodes, over that of the frontier,
+/// under which the search switches to bottom-up steps.
+const ALPHA: usize = 14;
+/// The number of nodes, over that of the frontier, above which the search
+/// switches back to top-down steps.
+const BETA: usize = 24;
+
+const UNVISITED: usize = usize::MAX;
+
+/// \[Generic\] Compute the distances, in number of edges, from `source` to
+/// every node, with a parallel breadth-first search.
+///
+/// See [`par_multi_source_bfs`].
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::par_bfs;
+/// use petgraph::graph::{DiGraph, NodeIndex};
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (0, 2), (2, 3), (4, 0)]);
+/// let distances = par_bfs(&graph, NodeIndex::new(0));
+/// assert_eq!(distances, [Some(0), Some(1), Some(1), Some(2), None]);
+/// ```
+pub fn par_bfs<G>(graph: G, source: G::NodeId) -> Vec<Option<usize>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Sync,
+    G::NodeId: Send + Sync,
+{
+    par_multi_source_bfs(graph, &[source])
+}
+
+/// \[Generic\] Compute the distances, in number of edges, from the nearest of
+/// `sources` to every node, with a parallel breadth-first search.
+///
+/// The search is level-synchronous: it finds the nodes of each distance from
+/// the nodes of the previous distance, the frontier, in parallel. It is also
+/// direction-optimizing, after [Beamer, Asanović and Patterson][1]: while the
+/// frontier is small, it follows the outgoing edges of the frontier, and when
+/// it has more edges than a fraction of the unvisited nodes, it looks instead
+/// for an incoming edge from the frontier for each unvisited node, which
+/// stops at the first one it finds.
+///
+/// # Arguments
+/// * `graph`: a graph.
+/// * `sources`: the nodes to start the search from, at distance *0*.
+///
+/// # Returns
+/// * A vector of the distance of each node, indexed by
+///   [`NodeIndexable::to_index`], or `None` for the nodes that cannot be
+///   reached from the sources and the indices without a node.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** for the top-down steps, and **O(|V|)**
+///   more for each of the bottom-up steps, in total over the threads.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://doi.org/10.1109/SC.2012.50
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::par_multi_source_bfs;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (5, 6)]);
+/// let sources = [NodeIndex::new(0), NodeIndex::new(4)];
+/// let distances = par_multi_source_bfs(&graph, &sources);
+/// assert_eq!(
+///     distances,
+///     [Some(0), Some(1), Some(2), Some(1), Some(0), None, None]
+/// );
+/// ```
+pub fn par_multi_source_bfs<G>(graph: G, sources: &[G::NodeId]) -> Vec<Option<usize>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Sync,
+    G::NodeId: Send + Sync,
+{
+    let distance: Vec<AtomicUsize> = (0..graph.node_bound())
+        .map(|_| AtomicUsize::new(UNVISITED))
+        .collect();
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let out_degree = |a: G::NodeId| graph.neighbors(a).count();
+
+    let mut frontier: Vec<G::NodeId> = Vec::new();
+    for &source in sources {
+        if distance[graph.to_index(source)].swap(0, Ordering::Relaxed) == UNVISITED {
+            frontier.push(source);
+        }
+    }
+    let mut unvisited_edges: usize = nodes.par_iter().map(|&a| out_degree(a)).sum();
+    let mut bottom_up = false;
+    let mut level = 0;
+    while !frontier.is_empty() {
+        let frontier_edges: usize = frontier.par_iter().map(|&a| out_degree(a)).sum();
+        unvisited_edges = unvisited_edges.saturating_sub(frontier_edges);
+        if !bottom_up && frontier_edges > unvisited_edges / ALPHA {
+            bottom_up = true;
+        } else if bottom_up && frontier.len() < nodes.len() / BETA {
+            bottom_up = false;
+        }
+
+        let next = level + 1;
+        frontier = if bottom_up {
+            nodes
+                .par_iter()
+                .copied()
+                .filter(|&b| {
+                    let slot = &distance[graph.to_index(b)];
+                    if slot.load(Ordering::Relaxed) != UNVISITED {
+                        return false;
+                    }
+                    let found = graph
+                        .neighbors_directed(b, Incoming)
+                        .any(|a| distance[graph.to_index(a)].load(Ordering::Relaxed) == level);
+                    if found {
+                        slot.store(next, Ordering::Relaxed);
+                    }
+                    found
+                })
+                .collect()
+        } else {
+            frontier
+                .par_iter()
+                .flat_map_iter(|&a| {
+                    graph.neighbors(a).filter(|&b| {
+                        distance[graph.to_index(b)]
+                            .compare_exchange(UNVISITED, next, Ordering::Relaxed, Ordering::Relaxed)
+                            .is_ok()
+                    })
+                })
+                .collect()
+        };
+        level = next;
+    }
+
+    distance
+        .into_iter()
+        .map(|d| match d.into_inner() {
+            UNVISITED => None,
+            d => Some(d),
+        })
+        .collect()
+}
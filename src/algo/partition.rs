@@ -0,0 +1,595 @@
+//! Graph partitioning: split the nodes of a graph into parts of about the
+//! same size, cutting as few edges as possible.
+//!
+//! [`kernighan_lin_bisection`] splits a graph in two and improves the split
+//! with Kernighan–Lin refinement. [`multilevel_partition`] handles large
+//! graphs and any number of parts: it coarsens the graph by contracting a
+//! matching of heavy edges, bisects the coarsest graph, and refines the
+//! bisection while projecting it back to the original graph, recursively
+//! for each half.
+
+use alloc::{collections::BinaryHeap, collections::VecDeque, vec, vec::Vec};
+use core::cmp::Reverse;
+
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+const NONE: usize = usize::MAX;
+
+/// The number of nodes below which a graph is not coarsened further.
+const COARSEST: usize = 40;
+
+/// The number of starting nodes tried for the initial bisection.
+const STARTS: usize = 4;
+
+/// The maximum number of refinement passes per level.
+const PASSES: usize = 8;
+
+/// A partition of the nodes of a graph, computed by
+/// [`kernighan_lin_bisection`] or [`multilevel_partition`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Partition {
+    /// The part of each node, from `0` to `parts - 1`, indexed by the graph's
+    /// node indices (see [`NodeIndexable`]). Indices without a node have the
+    /// label `usize::MAX`.
+    pub labels: Vec<usize>,
+    /// The number of parts.
+    pub parts: usize,
+    /// The total weight of the edges whose endpoints are in different parts.
+    pub cut: usize,
+}
+
+impl Partition {
+    /// Return the number of nodes in each part.
+    pub fn part_sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![0; self.parts];
+        for &label in self.labels.iter().filter(|&&label| label != NONE) {
+            sizes[label] += 1;
+        }
+        sizes
+    }
+}
+
+/// \[Generic\] Split the nodes of a graph in two parts of about the same
+/// size, with few edges between them, using [Kernighan–Lin][1] refinement.
+///
+/// A first split is grown by a breadth-first search from a few starting
+/// nodes. It is then improved by passes of the refinement, in the form
+/// given by Fiduccia and Mattheyses: each pass moves every node once, the
+/// move that reduces the cut the most first, and keeps the best prefix of
+/// the moves. Passes stop when they no longer improve the cut.
+///
+/// Each part has at most **(1 + `imbalance`) · |V| / 2** nodes. Edge
+/// directions are ignored and loops never count in the cut.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `imbalance`: the tolerated relative excess of the size of a part, for
+///   example `0.03` for 3%.
+/// * `edge_weight`: closure that returns the weight of an edge.
+///
+/// # Returns
+/// * A [`Partition`] in two parts (one of them empty if the graph has fewer
+///   than two nodes), with its cut.
+///
+/// # Complexity
+/// * Time complexity: **O(p · (|V| + |E|) log |V|)** for **p** passes.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Kernighan%E2%80%93Lin_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::partition::kernighan_lin_bisection;
+/// use petgraph::graph::UnGraph;
+///
+/// // Two cliques of 5 nodes joined by a single edge.
+/// let mut edges = Vec::new();
+/// for a in 0..5 {
+///     for b in a + 1..5 {
+///         edges.push((a, b));
+///         edges.push((a + 5, b + 5));
+///     }
+/// }
+/// edges.push((0, 5));
+/// let graph = UnGraph::<(), ()>::from_edges(&edges);
+///
+/// let partition = kernighan_lin_bisection(&graph, 0.0, |_| 1);
+/// assert_eq!(partition.cut, 1);
+/// assert_eq!(partition.part_sizes(), vec![5, 5]);
+/// assert!(partition.labels[..5].iter().all(|&l| l == partition.labels[0]));
+/// ```
+pub fn kernighan_lin_bisection<G, F>(graph: G, imbalance: f64, edge_weight: F) -> Partition
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> usize,
+{
+    let (weighted, nodes) = WeightedGraph::new(graph, edge_weight);
+    let limits = Limits::new(weighted.total_weight(), 1, 2, imbalance);
+    let sides = initial_bisection(&weighted, &limits);
+    finish(graph, &weighted, &nodes, sides, 2)
+}
+
+/// \[Generic\] Split the nodes of a graph in `k` parts of about the same
+/// size, with few edges between them, by multilevel recursive bisection.
+///
+/// Each bisection coarsens the graph by contracting a maximal matching of
+/// heavy edges, repeatedly, until it has a few dozen nodes. The coarsest
+/// graph is bisected as in [`kernighan_lin_bisection`], and the bisection is
+/// projected back to each finer graph and refined there. The halves are
+/// then partitioned recursively, in `⌊k / 2⌋` and `⌈k / 2⌉` parts.
+///
+/// The `imbalance` applies to each bisection: each half of a bisection has
+/// at most **(1 + `imbalance`)** times its share of the nodes, so a part of
+/// the result can exceed **|V| / k** by a little more after several levels
+/// of recursion. Edge directions are ignored and loops never count in the
+/// cut.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `k`: the number of parts.
+/// * `imbalance`: the tolerated relative excess of the size of a part, for
+///   example `0.03` for 3%.
+/// * `edge_weight`: closure that returns the weight of an edge.
+///
+/// # Returns
+/// * A [`Partition`] in `k` parts, with its cut.
+///
+/// **Panics** if `k` is `0`.
+///
+/// # Complexity
+/// * Time complexity: **O((|V| + |E|) log |V| log k)** in practice.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::partition::multilevel_partition;
+/// use petgraph::graph::UnGraph;
+///
+/// // A grid of 16 by 16 nodes, cut in four.
+/// let mut edges = Vec::new();
+/// for r in 0..16u32 {
+///     for c in 0..16u32 {
+///         if c + 1 < 16 {
+///             edges.push((r * 16 + c, r * 16 + c + 1));
+///         }
+///         if r + 1 < 16 {
+///             edges.push((r * 16 + c, (r + 1) * 16 + c));
+///         }
+///     }
+/// }
+/// let graph = UnGraph::<(), ()>::from_edges(&edges);
+///
+/// let partition = multilevel_partition(&graph, 4, 0.05, |_| 1);
+/// assert_eq!(partition.parts, 4);
+/// assert!(partition.part_sizes().iter().all(|&size| size <= 71));
+/// // Cutting the grid in four squares cuts 32 edges.
+/// assert!(partition.cut <= 48);
+/// ```
+pub fn multilevel_partition<G, F>(graph: G, k: usize, imbalance: f64, edge_weight: F) -> Partition
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> usize,
+{
+    assert!(
+        k > 0,
+        "multilevel_partition: the number of parts must be positive"
+    );
+    let (weighted, nodes) = WeightedGraph::new(graph, edge_weight);
+    let mut labels = vec![0; weighted.len()];
+    let members: Vec<usize> = (0..weighted.len()).collect();
+    recursive_bisection(&weighted, &members, k, 0, imbalance, &mut labels);
+    finish(graph, &weighted, &nodes, labels, k)
+}
+
+fn finish<G>(
+    graph: G,
+    weighted: &WeightedGraph,
+    nodes: &[G::NodeId],
+    labels: Vec<usize>,
+    parts: usize,
+) -> Partition
+where
+    G: NodeIndexable,
+{
+    let cut = weighted.cut(&labels);
+    let mut by_index = vec![NONE; graph.node_bound()];
+    for (&node, label) in nodes.iter().zip(labels) {
+        by_index[graph.to_index(node)] = label;
+    }
+    Partition {
+        labels: by_index,
+        parts,
+        cut,
+    }
+}
+
+fn recursive_bisection(
+    graph: &WeightedGraph,
+    members: &[usize],
+    k: usize,
+    first_label: usize,
+    imbalance: f64,
+    labels: &mut [usize],
+) {
+    if k == 1 {
+        for &v in members {
+            labels[v] = first_label;
+        }
+        return;
+    }
+    let k0 = k / 2;
+    let limits = Limits::new(graph.total_weight(), k0, k, imbalance);
+    let sides = multilevel_bisection(graph, &limits);
+    for side in 0..2 {
+        let part: Vec<usize> = (0..graph.len()).filter(|&v| sides[v] == side).collect();
+        let sub = graph.induced(&part);
+        let sub_members: Vec<usize> = part.iter().map(|&v| members[v]).collect();
+        let (sub_k, sub_label) = if side == 0 {
+            (k0, first_label)
+        } else {
+            (k - k0, first_label + k0)
+        };
+        recursive_bisection(&sub, &sub_members, sub_k, sub_label, imbalance, labels);
+    }
+}
+
+/// The target and maximum weights of the two sides of a bisection.
+struct Limits {
+    target: [usize; 2],
+    max: [usize; 2],
+}
+
+impl Limits {
+    /// Limits for a bisection where the first side gets `share` parts out of
+    /// `parts`.
+    fn new(total: usize, share: usize, parts: usize, imbalance: f64) -> Self {
+        let first = total * share / parts;
+        let target = [first, total - first];
+        let max = target.map(|t| {
+            let max = t as f64 * (1.0 + imbalance.max(0.0));
+            (max as usize).max(t)
+        });
+        Limits { target, max }
+    }
+
+    /// Return how much the sides exceed their maximum weights.
+    fn excess(&self, weights: [usize; 2]) -> usize {
+        (0..2).map(|s| weights[s].saturating_sub(self.max[s])).sum()
+    }
+}
+
+/// An undirected graph with weighted nodes and edges, without loops or
+/// parallel edges.
+#[derive(Clone, Debug)]
+struct WeightedGraph {
+    node_weights: Vec<usize>,
+    /// The neighbors of each node and the weights of the edges to them.
+    neighbors: Vec<Vec<(usize, usize)>>,
+}
+
+impl WeightedGraph {
+    fn new<G, F>(graph: G, mut edge_weight: F) -> (Self, Vec<G::NodeId>)
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> usize,
+    {
+        let nodes: Vec<_> = graph.node_identifiers().collect();
+        let mut position = vec![NONE; graph.node_bound()];
+        for (i, &node) in nodes.iter().enumerate() {
+            position[graph.to_index(node)] = i;
+        }
+        let edges = graph.edge_references().map(|edge| {
+            let a = position[graph.to_index(edge.source())];
+            let b = position[graph.to_index(edge.target())];
+            (a, b, edge_weight(edge))
+        });
+        (Self::from_edges(vec![1; nodes.len()], edges), nodes)
+    }
+
+    fn from_edges(
+        node_weights: Vec<usize>,
+        edges: impl Iterator<Item = (usize, usize, usize)>,
+    ) -> Self {
+        let mut neighbors = vec![Vec::new(); node_weights.len()];
+        for (a, b, weight) in edges {
+            if a != b {
+                neighbors[a].push((b, weight));
+                neighbors[b].push((a, weight));
+            }
+        }
+        for list in &mut neighbors {
+            list.sort_unstable_by_key(|&(b, _)| b);
+            list.dedup_by(|later, earlier| {
+                let same = later.0 == earlier.0;
+                if same {
+                    earlier.1 += later.1;
+                }
+                same
+            });
+        }
+        WeightedGraph {
+            node_weights,
+            neighbors,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.node_weights.len()
+    }
+
+    fn total_weight(&self) -> usize {
+        self.node_weights.iter().sum()
+    }
+
+    fn cut(&self, labels: &[usize]) -> usize {
+        let mut cut = 0;
+        for (v, list) in self.neighbors.iter().enumerate() {
+            for &(w, weight) in list {
+                if v < w && labels[v] != labels[w] {
+                    cut += weight;
+                }
+            }
+        }
+        cut
+    }
+
+    /// Return the subgraph induced by `members`, numbered in their order.
+    fn induced(&self, members: &[usize]) -> WeightedGraph {
+        let mut position = vec![NONE; self.len()];
+        for (i, &v) in members.iter().enumerate() {
+            position[v] = i;
+        }
+        let node_weights = members.iter().map(|&v| self.node_weights[v]).collect();
+        let mut neighbors = Vec::with_capacity(members.len());
+        for &v in members {
+            let list: Vec<_> = self.neighbors[v]
+                .iter()
+                .filter(|&&(w, _)| position[w] != NONE)
+                .map(|&(w, weight)| (position[w], weight))
+                .collect();
+            neighbors.push(list);
+        }
+        WeightedGraph {
+            node_weights,
+            neighbors,
+        }
+    }
+
+    /// Contract a maximal matching of heavy edges, between nodes whose total
+    /// weight is at most `max_weight`. Return the coarse graph and the coarse
+    /// node of each node.
+    fn coarsen(&self, max_weight: usize) -> (WeightedGraph, Vec<usize>) {
+        let n = self.len();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by_key(|&v| self.neighbors[v].len());
+        let mut mate = vec![NONE; n];
+        for v in order {
+            if mate[v] != NONE {
+                continue;
+            }
+            let heaviest = self.neighbors[v]
+                .iter()
+                .filter(|&&(w, _)| {
+                    mate[w] == NONE && self.node_weights[v] + self.node_weights[w] <= max_weight
+                })
+                .max_by_key(|&&(w, weight)| (weight, Reverse(w)));
+            let partner = heaviest.map_or(v, |&(w, _)| w);
+            mate[v] = partner;
+            mate[partner] = v;
+        }
+
+        let mut coarse = vec![NONE; n];
+        let mut node_weights = Vec::new();
+        for v in 0..n {
+            if coarse[v] == NONE {
+                coarse[v] = node_weights.len();
+                coarse[mate[v]] = node_weights.len();
+                let weight = if mate[v] == v {
+                    self.node_weights[v]
+                } else {
+                    self.node_weights[v] + self.node_weights[mate[v]]
+                };
+                node_weights.push(weight);
+            }
+        }
+        let edges = self.neighbors.iter().enumerate().flat_map(|(v, list)| {
+            let coarse = &coarse;
+            list.iter()
+                .filter(move |&&(w, _)| v < w)
+                .map(move |&(w, weight)| (coarse[v], coarse[w], weight))
+        });
+        (WeightedGraph::from_edges(node_weights, edges), coarse)
+    }
+}
+
+fn multilevel_bisection(graph: &WeightedGraph, limits: &Limits) -> Vec<usize> {
+    // Coarse nodes are kept light enough to be moved between the sides.
+    let max_weight = (graph.total_weight() * 3 / (2 * COARSEST)).max(1);
+    let mut levels: Vec<(WeightedGraph, Vec<usize>)> = Vec::new();
+    loop {
+        let current = levels.last().map_or(graph, |(coarse, _)| coarse);
+        if current.len() <= COARSEST {
+            break;
+        }
+        let (coarse, map) = current.coarsen(max_weight);
+        if coarse.len() * 10 > current.len() * 9 {
+            break;
+        }
+        levels.push((coarse, map));
+    }
+
+    let coarsest = levels.last().map_or(graph, |(coarse, _)| coarse);
+    let mut sides = initial_bisection(coarsest, limits);
+    for i in (0..levels.len()).rev() {
+        let finer = if i == 0 { graph } else { &levels[i - 1].0 };
+        let map = &levels[i].1;
+        sides = map.iter().map(|&c| sides[c]).collect();
+        refine(finer, &mut sides, limits);
+    }
+    sides
+}
+
+/// Grow a bisection from a few starting nodes, refine each, and return the
+/// best one.
+fn initial_bisection(graph: &WeightedGraph, limits: &Limits) -> Vec<usize> {
+    let n = graph.len();
+    let mut best: Option<(usize, usize, Vec<usize>)> = None;
+    for i in 0..STARTS.min(n.max(1)) {
+        let mut sides = grow(graph, i * n / STARTS, limits.target[0]);
+        refine(graph, &mut sides, limits);
+        let weights = side_weights(graph, &sides);
+        let key = (limits.excess(weights), graph.cut(&sides));
+        if best.as_ref().map_or(true, |b| key < (b.0, b.1)) {
+            best = Some((key.0, key.1, sides));
+        }
+    }
+    best.map_or_else(Vec::new, |b| b.2)
+}
+
+/// Put the nodes first reached by a breadth-first search from `start` on side
+/// `0`, until it weighs `target`, and the others on side `1`.
+fn grow(graph: &WeightedGraph, start: usize, target: usize) -> Vec<usize> {
+    let n = graph.len();
+    let mut sides = vec![1; n];
+    let mut seen = vec![false; n];
+    let mut queue = VecDeque::new();
+    let mut weight = 0;
+    let mut next_start = 0;
+    if n > 0 {
+        seen[start] = true;
+        queue.push_back(start);
+    }
+    while weight < target {
+        let v = match queue.pop_front() {
+            Some(v) => v,
+            None => {
+                // Continue in another connected component.
+                while next_start < n && seen[next_start] {
+                    next_start += 1;
+                }
+                if next_start == n {
+                    break;
+                }
+                seen[next_start] = true;
+                next_start
+            }
+        };
+        sides[v] = 0;
+        weight += graph.node_weights[v];
+        for &(w, _) in &graph.neighbors[v] {
+            if !seen[w] {
+                seen[w] = true;
+                queue.push_back(w);
+            }
+        }
+    }
+    sides
+}
+
+fn side_weights(graph: &WeightedGraph, sides: &[usize]) -> [usize; 2] {
+    let mut weights = [0, 0];
+    for (v, &side) in sides.iter().enumerate() {
+        weights[side] += graph.node_weights[v];
+    }
+    weights
+}
+
+/// Improve a bisection with passes of Kernighan–Lin refinement, in the
+/// single move form of Fiduccia and Mattheyses. Sides that exceed their
+/// maximum weight are balanced first.
+fn refine(graph: &WeightedGraph, sides: &mut [usize], limits: &Limits) {
+    let n = graph.len();
+    let mut gain = vec![0i64; n];
+    let mut locked = vec![false; n];
+    for _ in 0..PASSES {
+        let mut weights = side_weights(graph, sides);
+        let mut cut = graph.cut(sides);
+        for v in 0..n {
+            gain[v] = graph.neighbors[v]
+                .iter()
+                .map(|&(w, weight)| {
+                    if sides[w] == sides[v] {
+                        -(weight as i64)
+                    } else {
+                        weight as i64
+                    }
+                })
+                .sum();
+        }
+        locked.iter_mut().for_each(|l| *l = false);
+        let mut heaps = [BinaryHeap::new(), BinaryHeap::new()];
+        for v in 0..n {
+            heaps[sides[v]].push((gain[v], Reverse(v)));
+        }
+
+        let start_key = (limits.excess(weights), cut);
+        let mut best_key = start_key;
+        let mut best_len = 0;
+        let mut moves = Vec::new();
+        loop {
+            // The best movable node of each side.
+            let mut candidates = [None, None];
+            for side in 0..2 {
+                while let Some(&(g, Reverse(v))) = heaps[side].peek() {
+                    if locked[v] || g != gain[v] || sides[v] != side {
+                        heaps[side].pop();
+                        continue;
+                    }
+                    let other = 1 - side;
+                    let fits = weights[other] + graph.node_weights[v] <= limits.max[other];
+                    let helps = weights[side] > limits.max[side];
+                    if fits || helps {
+                        candidates[side] = Some((g, v));
+                    }
+                    break;
+                }
+            }
+            let chosen = match candidates {
+                [Some(a), Some(b)] => {
+                    // Prefer the larger gain, then the heavier side.
+                    if (a.0, weights[0]) >= (b.0, weights[1]) {
+                        a
+                    } else {
+                        b
+                    }
+                }
+                [Some(a), None] => a,
+                [None, Some(b)] => b,
+                [None, None] => break,
+            };
+            let (g, v) = chosen;
+            let from = sides[v];
+            let to = 1 - from;
+            heaps[from].pop();
+            locked[v] = true;
+            sides[v] = to;
+            weights[from] -= graph.node_weights[v];
+            weights[to] += graph.node_weights[v];
+            cut = (cut as i64 - g) as usize;
+            moves.push(v);
+            for &(w, weight) in &graph.neighbors[v] {
+                if locked[w] {
+                    continue;
+                }
+                let delta = 2 * weight as i64;
+                gain[w] += if sides[w] == to { -delta } else { delta };
+                heaps[sides[w]].push((gain[w], Reverse(w)));
+            }
+            let key = (limits.excess(weights), cut);
+            if key < best_key {
+                best_key = key;
+                best_len = moves.len();
+            }
+        }
+        for &v in moves[best_len..].iter().rev() {
+            sides[v] = 1 - sides[v];
+        }
+        if best_key >= start_key {
+            break;
+        }
+    }
+}
@@ -0,0 +1,817 @@
+//! Planarity testing and planar embeddings.
+//!
+//! A graph is *planar* if it can be drawn in the plane without crossing
+//! edges. [`planarity`] uses the left-right planarity test to either find a
+//! combinatorial embedding of the graph, given as the clockwise order of the
+//! neighbors around every node (a *rotation system*), or a subgraph that is a
+//! subdivision of `K5` or `K3,3`, which proves by Kuratowski's theorem that
+//! the graph is not planar.
+//!
+//! Edge directions, loops and parallel edges do not change planarity, so they
+//! are ignored.
+
+use alloc::{vec, vec::Vec};
+use core::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::algo::covering::Adjacency;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// A combinatorial embedding of a planar graph: the clockwise order of the
+/// neighbors around every node.
+///
+/// The embedding determines the faces of a drawing of the graph without
+/// crossings, which are returned by [`faces`](PlanarEmbedding::faces).
+#[derive(Debug, Clone)]
+pub struct PlanarEmbedding<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    rotation: Vec<Vec<usize>>,
+}
+
+impl<N: Copy + Eq + Hash> PlanarEmbedding<N> {
+    /// Return the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Return the number of edges, without loops and parallel edges.
+    pub fn edge_count(&self) -> usize {
+        self.rotation.iter().map(Vec::len).sum::<usize>() / 2
+    }
+
+    /// Return an iterator over the nodes.
+    pub fn nodes(&self) -> impl Iterator<Item = N> + '_ {
+        self.nodes.iter().copied()
+    }
+
+    /// Return an iterator over the neighbors of `node` in clockwise order,
+    /// starting from an arbitrary one.
+    ///
+    /// **Panics** if `node` is not in the embedding.
+    pub fn neighbors(&self, node: N) -> impl Iterator<Item = N> + '_ {
+        self.rotation[self.index[&node]]
+            .iter()
+            .map(move |&i| self.nodes[i])
+    }
+
+    /// Return the faces of the embedding, each as the sequence of nodes
+    /// along its boundary.
+    ///
+    /// A face keeps the edges on its left: the face after the edge `u → v`
+    /// continues with the neighbor of `v` that comes just before `u` in the
+    /// clockwise order around `v`. A node can appear several times on a
+    /// face, for example on the single face of a tree.
+    ///
+    /// Every connected component with at least one edge satisfies Euler's
+    /// formula **|V| - |E| + |F| = 2**, with the outer face counted in each
+    /// component.
+    pub fn faces(&self) -> Vec<Vec<N>> {
+        let mut position = HashMap::new();
+        for (v, neighbors) in self.rotation.iter().enumerate() {
+            for (k, &w) in neighbors.iter().enumerate() {
+                position.insert((v, w), k);
+            }
+        }
+        let mut visited = HashSet::new();
+        let mut faces = Vec::new();
+        for (v, neighbors) in self.rotation.iter().enumerate() {
+            for &w in neighbors {
+                if visited.contains(&(v, w)) {
+                    continue;
+                }
+                let mut face = Vec::new();
+                let (mut a, mut b) = (v, w);
+                loop {
+                    visited.insert((a, b));
+                    face.push(self.nodes[a]);
+                    let around = &self.rotation[b];
+                    let k = position[&(b, a)];
+                    let c = around[(k + around.len() - 1) % around.len()];
+                    a = b;
+                    b = c;
+                    if (a, b) == (v, w) {
+                        break;
+                    }
+                }
+                faces.push(face);
+            }
+        }
+        faces
+    }
+}
+
+/// The kind of a [`KuratowskiSubgraph`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KuratowskiKind {
+    /// A subdivision of the complete graph on five nodes.
+    K5,
+    /// A subdivision of the complete bipartite graph on three and three
+    /// nodes.
+    K33,
+}
+
+/// A subgraph that is a subdivision of `K5` or `K3,3`, which proves that a
+/// graph is not planar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KuratowskiSubgraph<N> {
+    /// Whether the subgraph is a subdivision of `K5` or `K3,3`.
+    pub kind: KuratowskiKind,
+    /// The nodes of degree three or more in the subgraph, that is the nodes
+    /// of `K5` or `K3,3`.
+    pub branch_nodes: Vec<N>,
+    /// The edges of the subgraph.
+    pub edges: Vec<(N, N)>,
+}
+
+/// The result of [`planarity`].
+#[derive(Debug, Clone)]
+pub enum Planarity<N> {
+    /// The graph is planar, with this embedding.
+    Planar(PlanarEmbedding<N>),
+    /// The graph is not planar, as shown by this subgraph.
+    NonPlanar(KuratowskiSubgraph<N>),
+}
+
+impl<N> Planarity<N> {
+    /// Return `true` if the graph is planar.
+    pub fn is_planar(&self) -> bool {
+        matches!(self, Planarity::Planar(_))
+    }
+}
+
+/// \[Generic\] Return `true` if the graph is planar.
+///
+/// Uses the [left-right planarity test][1] of de Fraysseix and Rosenstiehl,
+/// in the formulation of Brandes. Edge directions, loops and parallel edges
+/// are ignored.
+///
+/// # Arguments
+/// * `graph`: the graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**, after sorting the neighbors of the
+///   nodes to remove parallel edges.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Left-right_planarity_test
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::planarity::is_planar;
+/// use petgraph::graph::UnGraph;
+///
+/// // K4 is planar, K5 is not.
+/// let k4 = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+/// assert!(is_planar(&k4));
+///
+/// let mut k5 = k4.clone();
+/// k5.extend_with_edges([(4, 0), (4, 1), (4, 2), (4, 3)]);
+/// assert!(!is_planar(&k5));
+/// ```
+pub fn is_planar<G>(graph: G) -> bool
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let adjacency = Adjacency::new(graph);
+    LrPlanarity::new(&adjacency.neighbors).is_planar()
+}
+
+/// \[Generic\] Test whether the graph is planar, and return either a planar
+/// embedding of it or a Kuratowski subgraph.
+///
+/// Uses the [left-right planarity test][1] of de Fraysseix and Rosenstiehl,
+/// in the formulation of Brandes. Edge directions, loops and parallel edges
+/// are ignored.
+///
+/// If the graph is not planar, the Kuratowski subgraph is found by removing
+/// every edge whose removal leaves the graph non-planar, which takes one
+/// planarity test per edge.
+///
+/// # Arguments
+/// * `graph`: the graph.
+///
+/// # Returns
+/// * [`Planarity::Planar`] with the clockwise order of the neighbors of every
+///   node, if the graph is planar.
+/// * [`Planarity::NonPlanar`] with a subdivision of `K5` or `K3,3` contained
+///   in the graph otherwise.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** for planar graphs, and
+///   **O(|E| (|V| + |E|))** for non-planar graphs.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Left-right_planarity_test
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::planarity::{planarity, KuratowskiKind, Planarity};
+/// use petgraph::graph::UnGraph;
+///
+/// // The cube graph is planar: it has 6 faces.
+/// let cube = UnGraph::<(), ()>::from_edges([
+///     (0, 1), (1, 2), (2, 3), (3, 0),
+///     (4, 5), (5, 6), (6, 7), (7, 4),
+///     (0, 4), (1, 5), (2, 6), (3, 7),
+/// ]);
+/// match planarity(&cube) {
+///     Planarity::Planar(embedding) => assert_eq!(embedding.faces().len(), 6),
+///     Planarity::NonPlanar(_) => unreachable!(),
+/// }
+///
+/// // The Petersen graph contains a subdivision of K3,3.
+/// let petersen = UnGraph::<(), ()>::from_edges([
+///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+///     (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+///     (5, 7), (7, 9), (9, 6), (6, 8), (8, 5),
+/// ]);
+/// match planarity(&petersen) {
+///     Planarity::Planar(_) => unreachable!(),
+///     Planarity::NonPlanar(witness) => {
+///         assert_eq!(witness.kind, KuratowskiKind::K33);
+///         assert_eq!(witness.branch_nodes.len(), 6);
+///     }
+/// }
+/// ```
+pub fn planarity<G>(graph: G) -> Planarity<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let adjacency = Adjacency::new(graph);
+    let mut lr = LrPlanarity::new(&adjacency.neighbors);
+    if lr.is_planar() {
+        let rotation = lr.embedding();
+        let index = adjacency
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &node)| (node, i))
+            .collect();
+        return Planarity::Planar(PlanarEmbedding {
+            nodes: adjacency.nodes,
+            index,
+            rotation,
+        });
+    }
+
+    // Remove every edge that is not needed for non-planarity. What remains is
+    // a minimal non-planar graph, so a subdivision of K5 or K3,3.
+    let mut neighbors = adjacency.neighbors.clone();
+    for (v, list) in adjacency.neighbors.iter().enumerate() {
+        for &w in list.iter().filter(|&&w| w > v) {
+            let i = neighbors[v].binary_search(&w).unwrap();
+            let j = neighbors[w].binary_search(&v).unwrap();
+            neighbors[v].remove(i);
+            neighbors[w].remove(j);
+            if LrPlanarity::new(&neighbors).is_planar() {
+                neighbors[v].insert(i, w);
+                neighbors[w].insert(j, v);
+            }
+        }
+    }
+    let branch: Vec<usize> = (0..neighbors.len())
+        .filter(|&v| neighbors[v].len() > 2)
+        .collect();
+    let kind = if branch.len() == 5 {
+        KuratowskiKind::K5
+    } else {
+        KuratowskiKind::K33
+    };
+    let nodes = &adjacency.nodes;
+    let edges = neighbors
+        .iter()
+        .enumerate()
+        .flat_map(|(v, list)| {
+            list.iter()
+                .filter(move |&&w| w > v)
+                .map(move |&w| (nodes[v], nodes[w]))
+        })
+        .collect();
+    Planarity::NonPlanar(KuratowskiSubgraph {
+        kind,
+        branch_nodes: adjacency.to_nodes(branch),
+        edges,
+    })
+}
+
+const NONE: usize = usize::MAX;
+
+/// A sequence of return edges on one side, from `high` to `low` through
+/// `reference`.
+#[derive(Copy, Clone)]
+struct Interval {
+    low: usize,
+    high: usize,
+}
+
+impl Interval {
+    const EMPTY: Interval = Interval {
+        low: NONE,
+        high: NONE,
+    };
+
+    fn is_empty(&self) -> bool {
+        self.low == NONE && self.high == NONE
+    }
+}
+
+/// Return edges that must be on different sides.
+#[derive(Copy, Clone)]
+struct ConflictPair {
+    left: Interval,
+    right: Interval,
+}
+
+impl ConflictPair {
+    fn swap(&mut self) {
+        core::mem::swap(&mut self.left, &mut self.right);
+    }
+}
+
+/// The state of the left-right planarity test.
+///
+/// Edges are half-edges, numbered by their position in the concatenation of
+/// the neighbor lists. Only the half-edge in the direction of the DFS
+/// orientation is used by the test; the embedding uses both.
+struct LrPlanarity<'a> {
+    neighbors: &'a [Vec<usize>],
+    offsets: Vec<usize>,
+    source: Vec<usize>,
+    target: Vec<usize>,
+    twin: Vec<usize>,
+    oriented: Vec<bool>,
+    roots: Vec<usize>,
+    height: Vec<usize>,
+    parent_edge: Vec<usize>,
+    lowpt: Vec<usize>,
+    lowpt2: Vec<usize>,
+    nesting_depth: Vec<isize>,
+    /// The oriented edges out of every node, by increasing nesting depth.
+    ordered: Vec<Vec<usize>>,
+    reference: Vec<usize>,
+    side: Vec<isize>,
+    lowpt_edge: Vec<usize>,
+    stack: Vec<ConflictPair>,
+    stack_bottom: Vec<usize>,
+}
+
+impl<'a> LrPlanarity<'a> {
+    /// `neighbors` must be sorted, symmetric and without loops or parallel
+    /// edges.
+    fn new(neighbors: &'a [Vec<usize>]) -> Self {
+        let n = neighbors.len();
+        let mut offsets = Vec::with_capacity(n + 1);
+        let mut source = Vec::new();
+        let mut target = Vec::new();
+        for (v, list) in neighbors.iter().enumerate() {
+            offsets.push(target.len());
+            source.extend(list.iter().map(|_| v));
+            target.extend_from_slice(list);
+        }
+        offsets.push(target.len());
+        // Neighbor lists are sorted, so the half-edges into every node are
+        // found in the order of their sources.
+        let m = target.len();
+        let mut twin = vec![NONE; m];
+        let mut cursor = offsets.clone();
+        for h in 0..m {
+            let w = target[h];
+            twin[h] = cursor[w];
+            cursor[w] += 1;
+        }
+        LrPlanarity {
+            neighbors,
+            offsets,
+            source,
+            target,
+            twin,
+            oriented: vec![false; m],
+            roots: Vec::new(),
+            height: vec![NONE; n],
+            parent_edge: vec![NONE; n],
+            lowpt: vec![0; m],
+            lowpt2: vec![0; m],
+            nesting_depth: vec![0; m],
+            ordered: vec![Vec::new(); n],
+            reference: vec![NONE; m],
+            side: vec![1; m],
+            lowpt_edge: vec![NONE; m],
+            stack: Vec::new(),
+            stack_bottom: vec![0; m],
+        }
+    }
+
+    fn is_planar(&mut self) -> bool {
+        let n = self.neighbors.len();
+        let m = self.target.len() / 2;
+        if n > 2 && m > 3 * n - 6 {
+            return false;
+        }
+        self.orient();
+        self.order_by_nesting_depth();
+        self.test()
+    }
+
+    /// Orient the edges along a DFS, and compute the lowpoints and nesting
+    /// depths.
+    fn orient(&mut self) {
+        let mut dfs = Vec::new();
+        for root in 0..self.neighbors.len() {
+            if self.height[root] != NONE {
+                continue;
+            }
+            self.height[root] = 0;
+            self.roots.push(root);
+            dfs.push((root, self.offsets[root]));
+            while let Some(frame) = dfs.last_mut() {
+                let v = frame.0;
+                if frame.1 == self.offsets[v + 1] {
+                    dfs.pop();
+                    let e = self.parent_edge[v];
+                    if e != NONE {
+                        self.finish_orientation(e);
+                    }
+                    continue;
+                }
+                let vw = frame.1;
+                frame.1 += 1;
+                if self.oriented[vw] || self.oriented[self.twin[vw]] {
+                    continue;
+                }
+                self.oriented[vw] = true;
+                self.lowpt[vw] = self.height[v];
+                self.lowpt2[vw] = self.height[v];
+                let w = self.target[vw];
+                if self.height[w] == NONE {
+                    // Tree edge.
+                    self.parent_edge[w] = vw;
+                    self.height[w] = self.height[v] + 1;
+                    dfs.push((w, self.offsets[w]));
+                } else {
+                    // Back edge.
+                    self.lowpt[vw] = self.height[w];
+                    self.finish_orientation(vw);
+                }
+            }
+        }
+    }
+
+    /// Compute the nesting depth of `vw` and update the lowpoints of the
+    /// parent edge of `v`.
+    fn finish_orientation(&mut self, vw: usize) {
+        let v = self.source[vw];
+        self.nesting_depth[vw] = 2 * self.lowpt[vw] as isize;
+        if self.lowpt2[vw] < self.height[v] {
+            // Chordal.
+            self.nesting_depth[vw] += 1;
+        }
+        let e = self.parent_edge[v];
+        if e == NONE {
+            return;
+        }
+        if self.lowpt[vw] < self.lowpt[e] {
+            self.lowpt2[e] = self.lowpt[e].min(self.lowpt2[vw]);
+            self.lowpt[e] = self.lowpt[vw];
+        } else if self.lowpt[vw] > self.lowpt[e] {
+            self.lowpt2[e] = self.lowpt2[e].min(self.lowpt[vw]);
+        } else {
+            self.lowpt2[e] = self.lowpt2[e].min(self.lowpt2[vw]);
+        }
+    }
+
+    /// Sort the oriented edges out of every node by nesting depth, with a
+    /// bucket sort.
+    fn order_by_nesting_depth(&mut self) {
+        let n = self.neighbors.len() as isize;
+        let shift = 2 * n + 1;
+        let mut buckets = vec![Vec::new(); (2 * shift + 1) as usize];
+        for e in (0..self.target.len()).filter(|&e| self.oriented[e]) {
+            buckets[(self.nesting_depth[e] + shift) as usize].push(e);
+        }
+        for list in &mut self.ordered {
+            list.clear();
+        }
+        for e in buckets.into_iter().flatten() {
+            self.ordered[self.source[e]].push(e);
+        }
+    }
+
+    fn test(&mut self) -> bool {
+        let mut dfs = Vec::new();
+        for r in 0..self.roots.len() {
+            dfs.push((self.roots[r], 0));
+            while let Some(frame) = dfs.last_mut() {
+                let v = frame.0;
+                let ei = match self.ordered[v].get(frame.1) {
+                    Some(&ei) => ei,
+                    None => {
+                        dfs.pop();
+                        let e = self.parent_edge[v];
+                        if e != NONE {
+                            self.remove_back_edges(e);
+                            if !self.integrate(e) {
+                                return false;
+                            }
+                        }
+                        continue;
+                    }
+                };
+                frame.1 += 1;
+                self.stack_bottom[ei] = self.stack.len();
+                let w = self.target[ei];
+                if ei == self.parent_edge[w] {
+                    dfs.push((w, 0));
+                } else {
+                    self.lowpt_edge[ei] = ei;
+                    self.stack.push(ConflictPair {
+                        left: Interval::EMPTY,
+                        right: Interval { low: ei, high: ei },
+                    });
+                    if !self.integrate(ei) {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Integrate the return edges of `ei` into the constraints of the parent
+    /// edge of its source.
+    fn integrate(&mut self, ei: usize) -> bool {
+        let v = self.source[ei];
+        if self.lowpt[ei] >= self.height[v] {
+            return true;
+        }
+        let e = self.parent_edge[v];
+        if self.ordered[v][0] == ei {
+            self.lowpt_edge[e] = self.lowpt_edge[ei];
+            true
+        } else {
+            self.add_constraints(ei, e)
+        }
+    }
+
+    fn conflicting(&self, interval: Interval, b: usize) -> bool {
+        !interval.is_empty() && self.lowpt[interval.high] > self.lowpt[b]
+    }
+
+    fn lowest(&self, pair: &ConflictPair) -> usize {
+        if pair.left.is_empty() {
+            self.lowpt[pair.right.low]
+        } else if pair.right.is_empty() {
+            self.lowpt[pair.left.low]
+        } else {
+            self.lowpt[pair.left.low].min(self.lowpt[pair.right.low])
+        }
+    }
+
+    fn add_constraints(&mut self, ei: usize, e: usize) -> bool {
+        let mut p = ConflictPair {
+            left: Interval::EMPTY,
+            right: Interval::EMPTY,
+        };
+        // Merge the return edges of `ei` into `p.right`.
+        loop {
+            let mut q = self.stack.pop().unwrap();
+            if !q.left.is_empty() {
+                q.swap();
+            }
+            if !q.left.is_empty() {
+                return false;
+            }
+            if self.lowpt[q.right.low] > self.lowpt[e] {
+                if p.right.is_empty() {
+                    p.right = q.right;
+                } else {
+                    self.reference[p.right.low] = q.right.high;
+                }
+                p.right.low = q.right.low;
+            } else {
+                // Align.
+                self.reference[q.right.low] = self.lowpt_edge[e];
+            }
+            if self.stack.len() == self.stack_bottom[ei] {
+                break;
+            }
+        }
+        // Merge the conflicting return edges of the previous siblings into
+        // `p.left`.
+        while let Some(&top) = self.stack.last() {
+            if !self.conflicting(top.left, ei) && !self.conflicting(top.right, ei) {
+                break;
+            }
+            let mut q = self.stack.pop().unwrap();
+            if self.conflicting(q.right, ei) {
+                q.swap();
+            }
+            if self.conflicting(q.right, ei) {
+                return false;
+            }
+            if p.right.low != NONE {
+                self.reference[p.right.low] = q.right.high;
+            }
+            if q.right.low != NONE {
+                p.right.low = q.right.low;
+            }
+            if p.left.is_empty() {
+                p.left = q.left;
+            } else {
+                self.reference[p.left.low] = q.left.high;
+            }
+            p.left.low = q.left.low;
+        }
+        if !p.left.is_empty() || !p.right.is_empty() {
+            self.stack.push(p);
+        }
+        true
+    }
+
+    /// Remove the back edges that return to the source of the tree edge `e`.
+    fn remove_back_edges(&mut self, e: usize) {
+        let u = self.source[e];
+        // Drop the conflict pairs that only return to `u`.
+        while let Some(top) = self.stack.last() {
+            if self.lowest(top) != self.height[u] {
+                break;
+            }
+            let p = self.stack.pop().unwrap();
+            if p.left.low != NONE {
+                self.side[p.left.low] = -1;
+            }
+        }
+        // Trim the top conflict pair.
+        if let Some(mut p) = self.stack.pop() {
+            while p.left.high != NONE && self.target[p.left.high] == u {
+                p.left.high = self.reference[p.left.high];
+            }
+            if p.left.high == NONE && p.left.low != NONE {
+                self.reference[p.left.low] = p.right.low;
+                self.side[p.left.low] = -1;
+                p.left.low = NONE;
+            }
+            while p.right.high != NONE && self.target[p.right.high] == u {
+                p.right.high = self.reference[p.right.high];
+            }
+            if p.right.high == NONE && p.right.low != NONE {
+                self.reference[p.right.low] = p.left.low;
+                self.side[p.right.low] = -1;
+                p.right.low = NONE;
+            }
+            self.stack.push(p);
+        }
+        // The side of `e` is the side of its highest return edge.
+        if self.lowpt[e] < self.height[u] {
+            let top = self.stack.last().unwrap();
+            let (hl, hr) = (top.left.high, top.right.high);
+            self.reference[e] = if hl != NONE && (hr == NONE || self.lowpt[hl] > self.lowpt[hr]) {
+                hl
+            } else {
+                hr
+            };
+        }
+    }
+
+    /// Resolve the side of `e` relative to the sides of the edges it refers
+    /// to.
+    fn sign(&mut self, e: usize, chain: &mut Vec<usize>) -> isize {
+        chain.clear();
+        let mut x = e;
+        while self.reference[x] != NONE {
+            chain.push(x);
+            x = self.reference[x];
+        }
+        let mut side = self.side[x];
+        for &y in chain.iter().rev() {
+            self.side[y] *= side;
+            self.reference[y] = NONE;
+            side = self.side[y];
+        }
+        self.side[e]
+    }
+
+    /// Return the clockwise order of the neighbors of every node. The test
+    /// must have succeeded.
+    fn embedding(&mut self) -> Vec<Vec<usize>> {
+        let mut chain = Vec::new();
+        for e in 0..self.target.len() {
+            if self.oriented[e] {
+                self.nesting_depth[e] *= self.sign(e, &mut chain);
+            }
+        }
+        self.order_by_nesting_depth();
+
+        // Circular lists of half-edges around every node.
+        let mut rotation = Rotation {
+            cw: vec![NONE; self.target.len()],
+            ccw: vec![NONE; self.target.len()],
+            first: vec![NONE; self.neighbors.len()],
+        };
+        for v in 0..self.neighbors.len() {
+            let mut previous = NONE;
+            for &e in &self.ordered[v] {
+                rotation.insert_cw(v, e, previous);
+                previous = e;
+            }
+        }
+
+        let mut left_ref = vec![NONE; self.neighbors.len()];
+        let mut right_ref = vec![NONE; self.neighbors.len()];
+        let mut dfs = Vec::new();
+        for &root in &self.roots {
+            dfs.push((root, 0));
+            while let Some(frame) = dfs.last_mut() {
+                let v = frame.0;
+                let ei = match self.ordered[v].get(frame.1) {
+                    Some(&ei) => ei,
+                    None => {
+                        dfs.pop();
+                        continue;
+                    }
+                };
+                frame.1 += 1;
+                let w = self.target[ei];
+                let wv = self.twin[ei];
+                if ei == self.parent_edge[w] {
+                    rotation.insert_first(w, wv);
+                    left_ref[v] = ei;
+                    right_ref[v] = ei;
+                    dfs.push((w, 0));
+                } else if self.side[ei] == 1 {
+                    rotation.insert_cw(w, wv, right_ref[w]);
+                } else {
+                    rotation.insert_ccw(w, wv, left_ref[w]);
+                    left_ref[w] = wv;
+                }
+            }
+        }
+
+        (0..self.neighbors.len())
+            .map(|v| {
+                let mut order = Vec::with_capacity(self.neighbors[v].len());
+                let first = rotation.first[v];
+                if first != NONE {
+                    let mut h = first;
+                    loop {
+                        order.push(self.target[h]);
+                        h = rotation.cw[h];
+                        if h == first {
+                            break;
+                        }
+                    }
+                }
+                order
+            })
+            .collect()
+    }
+}
+
+/// Circular lists of the half-edges around every node, being built.
+struct Rotation {
+    cw: Vec<usize>,
+    ccw: Vec<usize>,
+    first: Vec<usize>,
+}
+
+impl Rotation {
+    /// Insert the half-edge `h` out of `v` just after `reference` in
+    /// clockwise order, or as the only one if `reference` is `NONE`.
+    fn insert_cw(&mut self, v: usize, h: usize, reference: usize) {
+        if reference == NONE {
+            self.cw[h] = h;
+            self.ccw[h] = h;
+            self.first[v] = h;
+            return;
+        }
+        let next = self.cw[reference];
+        self.cw[reference] = h;
+        self.cw[h] = next;
+        self.ccw[next] = h;
+        self.ccw[h] = reference;
+    }
+
+    /// Insert the half-edge `h` out of `v` just before `reference` in
+    /// clockwise order, or as the only one if `reference` is `NONE`.
+    fn insert_ccw(&mut self, v: usize, h: usize, reference: usize) {
+        if reference == NONE {
+            self.insert_cw(v, h, NONE);
+            return;
+        }
+        self.insert_cw(v, h, self.ccw[reference]);
+        if self.first[v] == reference {
+            self.first[v] = h;
+        }
+    }
+
+    /// Insert the half-edge `h` out of `v` first in clockwise order.
+    fn insert_first(&mut self, v: usize, h: usize) {
+        self.insert_ccw(v, h, self.first[v]);
+    }
+}
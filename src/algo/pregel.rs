@@ -0,0 +1,296 @@
+//! A vertex-centric framework for parallel iterative algorithms.
+//!
+//! An algorithm is written as a [`VertexProgram`], which [`Pregel`] runs in
+//! *supersteps*, after [Malewicz et al.][1]: in each superstep, every node
+//! sends a message along its outgoing edges, the messages to each node are
+//! combined, and every node computes its next value from its current value
+//! and its combined message. All three phases run in parallel over the nodes.
+//!
+//! [1]: https://doi.org/10.1145/1807167.1807184
+
+use alloc::{vec, vec::Vec};
+
+use rayon::prelude::*;
+
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoEdges, IntoNodeIdentifiers, NodeIndexable,
+};
+
+/// A vertex program, run by [`Pregel`].
+///
+/// # Example
+/// The connected components, labelled by their smallest node index: each node
+/// sends its label along its edges while it changes.
+/// ```rust
+/// use petgraph::algo::pregel::{Pregel, VertexProgram};
+/// use petgraph::graph::UnGraph;
+///
+/// struct MinLabel;
+///
+/// impl VertexProgram for MinLabel {
+///     /// The label of the node, and whether it changed in the last superstep.
+///     type Value = (usize, bool);
+///     type Message = usize;
+///
+///     fn message(&self, &(label, changed): &(usize, bool), _out_degree: usize) -> Option<usize> {
+///         if changed {
+///             Some(label)
+///         } else {
+///             None
+///         }
+///     }
+///
+///     fn combine(&self, a: usize, b: usize) -> usize {
+///         a.min(b)
+///     }
+///
+///     fn compute(&self, _index: usize, &(label, _): &(usize, bool), message: Option<usize>) -> (usize, bool) {
+///         match message {
+///             Some(m) if m < label => (m, true),
+///             _ => (label, false),
+///         }
+///     }
+/// }
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (4, 5)]);
+/// let mut pregel = Pregel::new(&graph, |a| (a.index(), true));
+/// while pregel.superstep(&MinLabel) {}
+/// let labels: Vec<usize> = pregel.values().iter().map(|&(label, _)| label).collect();
+/// assert_eq!(labels, [0, 0, 0, 0, 4, 4]);
+/// ```
+pub trait VertexProgram: Sync {
+    /// The value of each node.
+    type Value: Send + Sync;
+    /// The messages sent along the edges.
+    type Message: Clone + Send + Sync;
+
+    /// The message that a node of value `value` sends along each of its
+    /// `out_degree` outgoing edges, if any.
+    fn message(&self, value: &Self::Value, out_degree: usize) -> Option<Self::Message>;
+
+    /// Combine two messages sent to the same node.
+    ///
+    /// It should be associative and commutative, as the messages are combined
+    /// in an arbitrary order.
+    fn combine(&self, a: Self::Message, b: Self::Message) -> Self::Message;
+
+    /// The next value of the node at `index` in [`Pregel::nodes`], from its
+    /// current `value` and the combination of the messages it received, if
+    /// any.
+    fn compute(
+        &self,
+        index: usize,
+        value: &Self::Value,
+        message: Option<Self::Message>,
+    ) -> Self::Value;
+}
+
+/// The state of a [`VertexProgram`] run over a graph: its nodes and edges,
+/// and the value of each node.
+///
+/// The nodes, and their values, are in the order of
+/// [`IntoNodeIdentifiers::node_identifiers`]. An undirected edge carries
+/// messages both ways.
+#[derive(Clone, Debug)]
+pub struct Pregel<N, V> {
+    nodes: Vec<N>,
+    values: Vec<V>,
+    out_degree: Vec<usize>,
+    /// The sources of the incoming edges of the node at `i` are
+    /// `sources[offsets[i]..offsets[i + 1]]`.
+    offsets: Vec<usize>,
+    sources: Vec<usize>,
+}
+
+impl<N, V> Pregel<N, V>
+where
+    N: Copy,
+    V: Send + Sync,
+{
+    /// Prepare to run a vertex program over `graph`, with `init(a)` as the
+    /// initial value of node `a`.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn new<G, F>(graph: G, init: F) -> Self
+    where
+        G: IntoEdgeReferences<NodeId = N> + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+        F: FnMut(N) -> V,
+    {
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        let mut position = vec![usize::MAX; graph.node_bound()];
+        for (i, &a) in nodes.iter().enumerate() {
+            position[graph.to_index(a)] = i;
+        }
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for edge in graph.edge_references() {
+            let a = position[graph.to_index(edge.source())];
+            let b = position[graph.to_index(edge.target())];
+            edges.push((a, b));
+            if !graph.is_directed() && a != b {
+                edges.push((b, a));
+            }
+        }
+        Self::from_edges(nodes, &edges, init)
+    }
+
+    /// Prepare to run a vertex program over the given `nodes` of `graph`,
+    /// with `init(a)` as the initial value of node `a`.
+    ///
+    /// The nodes, and their values, are in the order of `nodes`, and the edges
+    /// are those of [`IntoEdges::edges`] from each of them. Edges to nodes
+    /// missing from `nodes` are ignored.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn with_nodes<G, F>(graph: G, nodes: Vec<N>, init: F) -> Self
+    where
+        G: IntoEdges<NodeId = N> + NodeIndexable,
+        F: FnMut(N) -> V,
+    {
+        let mut position = vec![usize::MAX; graph.node_bound()];
+        for (i, &a) in nodes.iter().enumerate() {
+            position[graph.to_index(a)] = i;
+        }
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for (a, &node) in nodes.iter().enumerate() {
+            for edge in graph.edges(node) {
+                match position.get(graph.to_index(edge.target())) {
+                    Some(&b) if b != usize::MAX => edges.push((a, b)),
+                    _ => {}
+                }
+            }
+        }
+        Self::from_edges(nodes, &edges, init)
+    }
+
+    /// Build the incoming edge lists of `edges`, given as positions in
+    /// `nodes`.
+    fn from_edges<F>(nodes: Vec<N>, edges: &[(usize, usize)], mut init: F) -> Self
+    where
+        F: FnMut(N) -> V,
+    {
+        let n = nodes.len();
+        let mut out_degree = vec![0; n];
+        let mut offsets = vec![0; n + 1];
+        for &(a, b) in edges {
+            out_degree[a] += 1;
+            offsets[b + 1] += 1;
+        }
+        for i in 0..n {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut next = offsets.clone();
+        let mut sources = vec![0; edges.len()];
+        for &(a, b) in edges {
+            sources[next[b]] = a;
+            next[b] += 1;
+        }
+
+        Pregel {
+            values: nodes.iter().map(|&a| init(a)).collect(),
+            nodes,
+            out_degree,
+            offsets,
+            sources,
+        }
+    }
+
+    /// Merge the parallel edges, so that each node receives a single message
+    /// from each of its predecessors, whatever the number of edges between
+    /// them. The out-degrees still count every edge.
+    ///
+    /// Computes in **O(|V| + |E| log |E|)** time.
+    pub fn dedup_edges(&mut self) {
+        let mut start = 0;
+        let mut len = 0;
+        for i in 0..self.nodes.len() {
+            let end = self.offsets[i + 1];
+            let sources = &mut self.sources[start..end];
+            sources.sort_unstable();
+            let mut last = None;
+            for j in start..end {
+                let a = self.sources[j];
+                if last != Some(a) {
+                    self.sources[len] = a;
+                    len += 1;
+                    last = Some(a);
+                }
+            }
+            start = end;
+            self.offsets[i + 1] = len;
+        }
+        self.sources.truncate(len);
+    }
+
+    /// Return the nodes, in the order of their values.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Return the values of the nodes.
+    pub fn values(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Return the values of the nodes, to change them between supersteps.
+    pub fn values_mut(&mut self) -> &mut [V] {
+        &mut self.values
+    }
+
+    /// Consume the state, and return the values of the nodes.
+    pub fn into_values(self) -> Vec<V> {
+        self.values
+    }
+
+    /// Return the number of outgoing edges of the node at `i` in
+    /// [`Pregel::nodes`].
+    ///
+    /// **Panics** if `i` is out of bounds.
+    pub fn out_degree(&self, i: usize) -> usize {
+        self.out_degree[i]
+    }
+
+    /// Run a superstep of `program`: compute the messages of all nodes, and
+    /// then their next values.
+    ///
+    /// Return `true` if any node received a message, so that a program whose
+    /// nodes stop sending messages once they are done runs until
+    /// `superstep` returns `false`.
+    ///
+    /// Computes in **O(|V| + |E|)** time, in total over the threads.
+    pub fn superstep<P>(&mut self, program: &P) -> bool
+    where
+        P: VertexProgram<Value = V>,
+    {
+        let messages: Vec<Option<P::Message>> = self
+            .values
+            .par_iter()
+            .zip(&self.out_degree)
+            .map(|(value, &out_degree)| {
+                if out_degree == 0 {
+                    None
+                } else {
+                    program.message(value, out_degree)
+                }
+            })
+            .collect();
+        let received: Vec<Option<P::Message>> = self
+            .offsets
+            .par_windows(2)
+            .map(|range| {
+                self.sources[range[0]..range[1]]
+                    .iter()
+                    .filter_map(|&a| messages[a].clone())
+                    .reduce(|a, b| program.combine(a, b))
+            })
+            .collect();
+        let any = received.par_iter().any(Option::is_some);
+        self.values = self
+            .values
+            .par_iter()
+            .zip(received)
+            .enumerate()
+            .map(|(i, (value, message))| program.compute(i, value, message))
+            .collect();
+        any
+    }
+}
@@ -0,0 +1,284 @@
+//! Cuthill–McKee orderings, which reduce the bandwidth of the adjacency
+//! matrix.
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+use super::covering::Adjacency;
+use crate::data::DataMap;
+use crate::graph::{Graph, NodeIndex};
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable,
+};
+
+/// \[Generic\] Compute the reverse [Cuthill–McKee][1] ordering of the nodes of
+/// a graph.
+///
+/// Numbering the nodes in this order gives an adjacency matrix whose nonzero
+/// entries are close to the diagonal (a small *bandwidth*), which speeds up
+/// sparse solvers and improves the cache locality of traversals. It is the
+/// reverse of [`cuthill_mckee`], which has the same bandwidth but usually
+/// less fill-in when the matrix is factorized.
+///
+/// Edge directions, loops and parallel edges are ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * `Vec`: every node of the graph, in the new order.
+///
+/// # Complexity
+/// * Time complexity: **O(k · (|V| + |E|) + |E| log Δ)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges,
+/// **Δ** is the maximum degree and **k** is the number of breadth-first
+/// searches run to find a starting node in each connected component
+/// (usually a few).
+///
+/// [1]: https://en.wikipedia.org/wiki/Cuthill%E2%80%93McKee_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::rcm::{bandwidth, rcm, reindexed};
+/// use petgraph::graph::UnGraph;
+///
+/// // A path whose nodes are numbered out of order.
+/// let graph = UnGraph::<u32, ()>::from_edges(&[(0, 4), (4, 1), (1, 3), (3, 2)]);
+/// assert_eq!(bandwidth(&graph, graph.node_indices()), 4);
+///
+/// let order = rcm(&graph);
+/// assert_eq!(bandwidth(&graph, order.iter().copied()), 1);
+///
+/// // A copy of the graph with the nodes numbered in that order.
+/// let reordered = reindexed(&graph, &order);
+/// assert_eq!(bandwidth(&reordered, reordered.node_indices()), 1);
+/// ```
+pub fn rcm<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut order = cuthill_mckee(graph);
+    order.reverse();
+    order
+}
+
+/// \[Generic\] Compute the [Cuthill–McKee][1] ordering of the nodes of a
+/// graph.
+///
+/// Each connected component is numbered by a breadth-first search from a
+/// *pseudo-peripheral* node (a node far from the others, found with the
+/// heuristic of George and Liu), that visits the neighbors of each node by
+/// increasing degree. Components are numbered one after the other.
+///
+/// See [`rcm`] for the reverse ordering, which is usually preferred.
+///
+/// Edge directions, loops and parallel edges are ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+///
+/// # Returns
+/// * `Vec`: every node of the graph, in the new order.
+///
+/// # Complexity
+/// * Time complexity: **O(k · (|V| + |E|) + |E| log Δ)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges,
+/// **Δ** is the maximum degree and **k** is the number of breadth-first
+/// searches run to find a starting node in each connected component.
+///
+/// [1]: https://en.wikipedia.org/wiki/Cuthill%E2%80%93McKee_algorithm
+pub fn cuthill_mckee<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut adjacency = Adjacency::new(graph);
+    let n = adjacency.len();
+    let degrees: Vec<usize> = adjacency.neighbors.iter().map(Vec::len).collect();
+    for list in &mut adjacency.neighbors {
+        list.sort_by_key(|&w| (degrees[w], w));
+    }
+
+    let mut starts: Vec<usize> = (0..n).collect();
+    starts.sort_by_key(|&v| (degrees[v], v));
+    let mut placed = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut search = Search {
+        distance: vec![usize::MAX; n],
+        queue: VecDeque::new(),
+        visited: Vec::new(),
+    };
+    for start in starts {
+        if placed[start] {
+            continue;
+        }
+        let root = search.pseudo_peripheral(&adjacency, &degrees, start);
+        search.run(&adjacency, root);
+        for &v in &search.visited {
+            placed[v] = true;
+        }
+        order.extend_from_slice(&search.visited);
+    }
+    adjacency.to_nodes(order)
+}
+
+/// A reusable breadth-first search over an [`Adjacency`].
+struct Search {
+    distance: Vec<usize>,
+    queue: VecDeque<usize>,
+    /// The nodes reached by the last search, in order.
+    visited: Vec<usize>,
+}
+
+impl Search {
+    /// Run a breadth-first search from `root`, and return its eccentricity.
+    fn run<N>(&mut self, adjacency: &Adjacency<N>, root: usize) -> usize {
+        for &v in &self.visited {
+            self.distance[v] = usize::MAX;
+        }
+        self.visited.clear();
+        self.distance[root] = 0;
+        self.queue.push_back(root);
+        while let Some(v) = self.queue.pop_front() {
+            self.visited.push(v);
+            for &w in &adjacency.neighbors[v] {
+                if self.distance[w] == usize::MAX {
+                    self.distance[w] = self.distance[v] + 1;
+                    self.queue.push_back(w);
+                }
+            }
+        }
+        self.visited.last().map_or(0, |&v| self.distance[v])
+    }
+
+    /// Find a pseudo-peripheral node in the component of `start`: move to a
+    /// node of minimum degree among the farthest ones while that increases
+    /// the eccentricity.
+    fn pseudo_peripheral<N>(
+        &mut self,
+        adjacency: &Adjacency<N>,
+        degrees: &[usize],
+        start: usize,
+    ) -> usize {
+        let mut root = start;
+        let mut eccentricity = self.run(adjacency, root);
+        loop {
+            let farthest = self
+                .visited
+                .iter()
+                .copied()
+                .filter(|&v| self.distance[v] == eccentricity)
+                .min_by_key(|&v| (degrees[v], v))
+                .unwrap();
+            let next = self.run(adjacency, farthest);
+            if next <= eccentricity {
+                return root;
+            }
+            root = farthest;
+            eccentricity = next;
+        }
+    }
+}
+
+/// \[Generic\] Return the bandwidth of the adjacency matrix of a graph whose
+/// nodes are numbered in `order`: the largest difference between the
+/// positions of the endpoints of an edge.
+///
+/// Edges with an endpoint that is not in `order` are ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `order`: the nodes of the graph, in order.
+///
+/// # Returns
+/// * `usize`: the bandwidth, `0` for a graph without edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+pub fn bandwidth<G, I>(graph: G, order: I) -> usize
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    I: IntoIterator<Item = G::NodeId>,
+{
+    let mut position = vec![usize::MAX; graph.node_bound()];
+    for (i, v) in order.into_iter().enumerate() {
+        position[graph.to_index(v)] = i;
+    }
+    graph
+        .edge_references()
+        .map(|edge| {
+            let a = position[graph.to_index(edge.source())];
+            let b = position[graph.to_index(edge.target())];
+            if a == usize::MAX || b == usize::MAX {
+                0
+            } else {
+                a.abs_diff(b)
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// \[Generic\] Return a copy of a graph with its nodes numbered in `order`,
+/// for example the one given by [`rcm`].
+///
+/// The node at position `i` of `order` has index `i` in the copy. The edges
+/// are added sorted by the new indices of their source and target, so that
+/// the edges of each node are close together in memory. Nodes that are not
+/// in `order` are left out, with their edges.
+///
+/// **Panics** if a node appears twice in `order`.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `order`: the nodes of the graph, in their new order.
+///
+/// # Returns
+/// * `Graph`: the reindexed copy.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+pub fn reindexed<G>(
+    graph: G,
+    order: &[G::NodeId],
+) -> Graph<G::NodeWeight, G::EdgeWeight, G::EdgeType>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp + DataMap,
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone,
+{
+    let mut position = vec![usize::MAX; graph.node_bound()];
+    for (i, &v) in order.iter().enumerate() {
+        let slot = &mut position[graph.to_index(v)];
+        assert!(*slot == usize::MAX, "reindexed: a node appears twice");
+        *slot = i;
+    }
+    let mut copy = Graph::with_capacity(order.len(), 0);
+    for &v in order {
+        let weight = graph.node_weight(v).expect("reindexed: node not in graph");
+        copy.add_node(weight.clone());
+    }
+
+    let mut edges: Vec<_> = graph
+        .edge_references()
+        .filter_map(|edge| {
+            let a = position[graph.to_index(edge.source())];
+            let b = position[graph.to_index(edge.target())];
+            (a != usize::MAX && b != usize::MAX).then_some((a, b, edge))
+        })
+        .collect();
+    edges.sort_by_key(|&(a, b, _)| (a, b));
+    copy.reserve_edges(edges.len());
+    for (a, b, edge) in edges {
+        copy.add_edge(NodeIndex::new(a), NodeIndex::new(b), edge.weight().clone());
+    }
+    copy
+}
@@ -0,0 +1,289 @@
+use alloc::{vec, vec::Vec};
+
+use fixedbitset::FixedBitSet;
+
+use crate::graph::IndexType;
+use crate::unionfind::UnionFind;
+use crate::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+/// The *strongly connected components* of a directed graph, kept up to date
+/// as edges are inserted.
+///
+/// It tracks *n* nodes indexed from *0* to *n - 1*, with the scalar type
+/// `K`, and the edges inserted between them. It keeps the condensation of the
+/// graph, with a topological order of its components: inserting an edge that
+/// goes forward in that order changes nothing, and inserting one that goes
+/// backward only searches the components between its endpoints in the order,
+/// merging those on a new cycle, and reordering the others as in the dynamic
+/// topological sort of [Pearce and Kelly][1]. Edges cannot be deleted.
+///
+/// [1]: https://doi.org/10.1145/1187436.1210590
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::IncrementalScc;
+///
+/// let mut sccs = IncrementalScc::<usize>::new(4);
+/// sccs.insert_edge(0, 1);
+/// sccs.insert_edge(1, 2);
+/// sccs.insert_edge(2, 3);
+/// assert_eq!(sccs.component_count(), 4);
+///
+/// // Closing the cycle 1 -> 2 -> 1 merges their components.
+/// assert!(sccs.insert_edge(2, 1));
+/// assert!(sccs.same_component(1, 2));
+/// assert!(!sccs.same_component(0, 1));
+///
+/// let components: Vec<Vec<usize>> = sccs
+///     .components()
+///     .into_iter()
+///     .map(|c| {
+///         let mut c = c.to_vec();
+///         c.sort();
+///         c
+///     })
+///     .collect();
+/// assert_eq!(components, vec![vec![0], vec![1, 2], vec![3]]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IncrementalScc<K> {
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+    edge_count: usize,
+    components: UnionFind<K>,
+    component_count: usize,
+    /// The nodes of each component, at the index of its representative.
+    members: Vec<Vec<K>>,
+    /// The position of each component in the topological order, at the index
+    /// of its representative.
+    position: Vec<usize>,
+    next_position: usize,
+    forward: FixedBitSet,
+    backward: FixedBitSet,
+}
+
+impl<K> IncrementalScc<K>
+where
+    K: IndexType,
+{
+    /// Create a new `IncrementalScc` of `n` nodes, without edges.
+    pub fn new(n: usize) -> Self {
+        IncrementalScc {
+            successors: vec![Vec::new(); n],
+            predecessors: vec![Vec::new(); n],
+            edge_count: 0,
+            components: UnionFind::new(n),
+            component_count: n,
+            members: (0..n).map(|a| vec![K::new(a)]).collect(),
+            position: (0..n).collect(),
+            next_position: n,
+            forward: FixedBitSet::with_capacity(n),
+            backward: FixedBitSet::with_capacity(n),
+        }
+    }
+
+    /// Create an `IncrementalScc` with the nodes and edges of a directed
+    /// graph, with the node indices of [`NodeIndexable::to_index`].
+    ///
+    /// The indices without a node are nodes without edges.
+    pub fn from_graph<G>(graph: G) -> Self
+    where
+        G: IntoEdgeReferences + NodeIndexable,
+    {
+        let mut sccs = IncrementalScc::new(graph.node_bound());
+        for edge in graph.edge_references() {
+            sccs.insert_edge(
+                K::new(graph.to_index(edge.source())),
+                K::new(graph.to_index(edge.target())),
+            );
+        }
+        sccs
+    }
+
+    /// Return the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.successors.len()
+    }
+
+    /// Return the number of edges.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Return the number of strongly connected components.
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+
+    /// Add a new node, without edges, and return its index.
+    pub fn add_node(&mut self) -> K {
+        let a = self.components.new_set();
+        self.successors.push(Vec::new());
+        self.predecessors.push(Vec::new());
+        self.members.push(vec![a]);
+        self.position.push(self.next_position);
+        self.next_position += 1;
+        self.component_count += 1;
+        self.forward.grow(self.successors.len());
+        self.backward.grow(self.successors.len());
+        a
+    }
+
+    /// Return the representative of the component of `a`.
+    ///
+    /// The representative of a component can change when it is merged with
+    /// others.
+    ///
+    /// **Panics** if `a` is out of bounds.
+    pub fn component(&self, a: K) -> K {
+        self.components.find(a)
+    }
+
+    /// Return `true` if `a` and `b` are in the same component.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    pub fn same_component(&self, a: K, b: K) -> bool {
+        self.components.equiv(a, b)
+    }
+
+    /// Return the nodes of the component of `a`, in no particular order.
+    ///
+    /// **Panics** if `a` is out of bounds.
+    pub fn component_nodes(&self, a: K) -> &[K] {
+        &self.members[self.components.find(a).index()]
+    }
+
+    /// Return the components in topological order: there is no edge from a
+    /// component to one before it.
+    ///
+    /// This is the reverse of the order of [`tarjan_scc`][super::tarjan_scc()].
+    ///
+    /// Computes in **O(|V| log |V|)** time.
+    pub fn components(&self) -> Vec<&[K]> {
+        let mut representatives: Vec<usize> = (0..self.node_count())
+            .filter(|&a| self.components.find(K::new(a)).index() == a)
+            .collect();
+        representatives.sort_unstable_by_key(|&c| self.position[c]);
+        representatives
+            .into_iter()
+            .map(|c| &self.members[c][..])
+            .collect()
+    }
+
+    /// Insert an edge from `a` to `b`, and return `true` if it merged
+    /// components.
+    ///
+    /// Only the components between those of `b` and `a` in the topological
+    /// order are searched, and only if the edge goes backward in it.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    pub fn insert_edge(&mut self, a: K, b: K) -> bool {
+        let source = self.components.find(a).index();
+        let target = self.components.find(b).index();
+        self.successors[a.index()].push(b.index());
+        self.predecessors[b.index()].push(a.index());
+        self.edge_count += 1;
+        if source == target || self.position[source] < self.position[target] {
+            return false;
+        }
+        let (lower, upper) = (self.position[target], self.position[source]);
+
+        // The components reachable from the target that are not after the
+        // source, and those that reach the source and are not before the
+        // target. Those in both are on a new cycle.
+        let forward = self.search(target, true, upper);
+        let backward = self.search(source, false, lower);
+        let mut before = Vec::new();
+        let mut merged = Vec::new();
+        for &c in &backward {
+            if self.forward.contains(c) {
+                merged.push(c);
+            } else {
+                before.push(c);
+            }
+        }
+        let mut after: Vec<usize> = forward
+            .iter()
+            .copied()
+            .filter(|&c| !self.backward.contains(c))
+            .collect();
+        for &c in forward.iter().chain(&backward) {
+            self.forward.set(c, false);
+            self.backward.set(c, false);
+        }
+
+        let mut positions: Vec<usize> = before
+            .iter()
+            .chain(&merged)
+            .chain(&after)
+            .map(|&c| self.position[c])
+            .collect();
+        positions.sort_unstable();
+        before.sort_unstable_by_key(|&c| self.position[c]);
+        after.sort_unstable_by_key(|&c| self.position[c]);
+
+        // The components before move down, and those after move up; the
+        // merged component, if any, takes a position between them.
+        for (&c, &p) in before.iter().zip(&positions) {
+            self.position[c] = p;
+        }
+        for (&c, &p) in after.iter().rev().zip(positions.iter().rev()) {
+            self.position[c] = p;
+        }
+        if merged.is_empty() {
+            return false;
+        }
+        let mut nodes = Vec::new();
+        for &c in &merged {
+            nodes.append(&mut self.members[c]);
+            self.components.union(K::new(c), K::new(source));
+        }
+        let representative = self.components.find(K::new(source)).index();
+        self.members[representative] = nodes;
+        self.position[representative] = positions[before.len()];
+        self.component_count -= merged.len() - 1;
+        true
+    }
+
+    /// Return the components found by a depth-first search from the
+    /// component `start`, along the edges if `forward` or against them
+    /// otherwise, through the components not past the position `bound`, and
+    /// mark them in `self.forward` or `self.backward`.
+    fn search(&mut self, start: usize, forward: bool, bound: usize) -> Vec<usize> {
+        let mut marks = core::mem::take(if forward {
+            &mut self.forward
+        } else {
+            &mut self.backward
+        });
+        marks.insert(start);
+        let mut found = vec![start];
+        let mut stack = vec![start];
+        while let Some(c) = stack.pop() {
+            for &a in &self.members[c] {
+                let neighbors = if forward {
+                    &self.successors[a.index()]
+                } else {
+                    &self.predecessors[a.index()]
+                };
+                for &b in neighbors {
+                    let d = self.components.find(K::new(b)).index();
+                    let within = if forward {
+                        self.position[d] <= bound
+                    } else {
+                        self.position[d] >= bound
+                    };
+                    if within && !marks.put(d) {
+                        found.push(d);
+                        stack.push(d);
+                    }
+                }
+            }
+        }
+        if forward {
+            self.forward = marks;
+        } else {
+            self.backward = marks;
+        }
+        found
+    }
+}
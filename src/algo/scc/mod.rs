@@ -1,6 +1,12 @@
+pub mod incremental_scc;
 pub mod kosaraju_scc;
+#[cfg(feature = "rayon")]
+pub mod par_scc;
 pub mod tarjan_scc;
 
+pub use incremental_scc::IncrementalScc;
 #[allow(deprecated)]
 pub use kosaraju_scc::{kosaraju_scc, scc};
+#[cfg(feature = "rayon")]
+pub use par_scc::par_scc;
 pub use tarjan_scc::{tarjan_scc, TarjanScc};
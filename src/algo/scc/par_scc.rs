@@ -0,0 +1,354 @@
+use alloc::{vec, vec::Vec};
+use core::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+use hashbrown::HashMap;
+use rayon::prelude::*;
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// The subproblems smaller than this are solved with Tarjan's algorithm.
+const SEQUENTIAL_THRESHOLD: usize = 1 << 10;
+/// The frontiers of breadth-first searches larger than this are expanded in
+/// parallel.
+const PARALLEL_FRONTIER: usize = 1 << 8;
+
+const FORWARD: u8 = 1;
+const BACKWARD: u8 = 2;
+/// The label of the nodes whose component is found.
+const DONE: usize = usize::MAX;
+
+/// \[Generic\] Compute the *strongly connected components* of a graph in
+/// parallel, with the forward-backward algorithm.
+///
+/// The forward-backward algorithm picks a pivot node, and searches the nodes
+/// it reaches and those that reach it: the nodes found by both searches are
+/// its component, and the other nodes split into three sets, those found by
+/// one of the searches only and those found by neither, whose components are
+/// computed independently, in parallel. The nodes without predecessors or
+/// successors in their set are trimmed first, as components of their own, and
+/// the small sets are finished with Tarjan's algorithm.
+///
+/// For an undirected graph, the sccs are simply the connected components.
+///
+/// # Arguments
+/// * `g`: a graph.
+///
+/// # Returns
+/// * A vector of the components, each a vector of its nodes. The order of
+///   the components, and of the nodes of each, is arbitrary; unlike
+///   [`tarjan_scc`][crate::algo::tarjan_scc] and
+///   [`kosaraju_scc`][crate::algo::kosaraju_scc], it is not a topological
+///   order.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| log |V| + |E| log |V|)** expected, in total
+///   over the threads.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::par_scc;
+/// use petgraph::graph::{DiGraph, NodeIndex};
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3)]);
+/// let mut sccs: Vec<Vec<usize>> = par_scc(&graph)
+///     .into_iter()
+///     .map(|scc| {
+///         let mut scc: Vec<usize> = scc.into_iter().map(NodeIndex::index).collect();
+///         scc.sort();
+///         scc
+///     })
+///     .collect();
+/// sccs.sort();
+/// assert_eq!(sccs, vec![vec![0, 1, 2], vec![3, 4]]);
+/// ```
+pub fn par_scc<G>(g: G) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp + Sync,
+    G::NodeId: Send,
+{
+    let graph = IndexedGraph::new(g);
+    let nodes: Vec<usize> = g.node_identifiers().map(|n| g.to_index(n)).collect();
+    for &a in &nodes {
+        graph.label[a].store(0, Ordering::Relaxed);
+    }
+    graph
+        .solve(nodes, 0)
+        .into_iter()
+        .map(|scc| scc.into_iter().map(|a| g.from_index(a)).collect())
+        .collect()
+}
+
+/// The edges of a graph in both directions, by node index, and the state of
+/// the subproblems.
+struct IndexedGraph {
+    successor_offsets: Vec<usize>,
+    successors: Vec<usize>,
+    predecessor_offsets: Vec<usize>,
+    predecessors: Vec<usize>,
+    /// The subproblem of each node, or `DONE`.
+    label: Vec<AtomicUsize>,
+    /// The searches that found each node, in its subproblem.
+    marks: Vec<AtomicU8>,
+    next_label: AtomicUsize,
+}
+
+impl IndexedGraph {
+    fn new<G>(g: G) -> Self
+    where
+        G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    {
+        let n = g.node_bound();
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for edge in g.edge_references() {
+            let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+            edges.push((a, b));
+            if !g.is_directed() {
+                edges.push((b, a));
+            }
+        }
+        let (successor_offsets, successors) = compress(n, edges.iter().copied());
+        let (predecessor_offsets, predecessors) = compress(n, edges.iter().map(|&(a, b)| (b, a)));
+        IndexedGraph {
+            successor_offsets,
+            successors,
+            predecessor_offsets,
+            predecessors,
+            label: (0..n).map(|_| AtomicUsize::new(DONE)).collect(),
+            marks: (0..n).map(|_| AtomicU8::new(0)).collect(),
+            next_label: AtomicUsize::new(1),
+        }
+    }
+
+    fn neighbors(&self, a: usize, forward: bool) -> &[usize] {
+        if forward {
+            &self.successors[self.successor_offsets[a]..self.successor_offsets[a + 1]]
+        } else {
+            &self.predecessors[self.predecessor_offsets[a]..self.predecessor_offsets[a + 1]]
+        }
+    }
+
+    fn has_neighbor_in(&self, a: usize, label: usize, forward: bool) -> bool {
+        self.neighbors(a, forward)
+            .iter()
+            .any(|&b| b != a && self.label[b].load(Ordering::Relaxed) == label)
+    }
+
+    fn relabel(&self, nodes: &[usize]) -> usize {
+        let label = self.next_label.fetch_add(1, Ordering::Relaxed);
+        for &a in nodes {
+            self.label[a].store(label, Ordering::Relaxed);
+        }
+        label
+    }
+
+    /// Return the components of the nodes of the subproblem `label`.
+    fn solve(&self, mut nodes: Vec<usize>, label: usize) -> Vec<Vec<usize>> {
+        let mut sccs = Vec::new();
+        // Trim the nodes without predecessors or successors in the
+        // subproblem, until there is none.
+        loop {
+            let trimmed: Vec<usize> = nodes
+                .par_iter()
+                .copied()
+                .filter(|&a| {
+                    !self.has_neighbor_in(a, label, true) || !self.has_neighbor_in(a, label, false)
+                })
+                .collect();
+            if trimmed.is_empty() {
+                break;
+            }
+            for &a in &trimmed {
+                self.label[a].store(DONE, Ordering::Relaxed);
+                sccs.push(vec![a]);
+            }
+            nodes.retain(|&a| self.label[a].load(Ordering::Relaxed) == label);
+            // Further passes only pay off on large subproblems.
+            if nodes.len() < SEQUENTIAL_THRESHOLD {
+                break;
+            }
+        }
+        if nodes.is_empty() {
+            return sccs;
+        }
+        if nodes.len() < SEQUENTIAL_THRESHOLD {
+            sccs.extend(self.tarjan(&nodes, label));
+            return sccs;
+        }
+
+        let pivot = nodes[nodes.len() / 2];
+        let forward = self.search(pivot, label, true);
+        let backward = self.search(pivot, label, false);
+        let scc: Vec<usize> = forward
+            .iter()
+            .copied()
+            .filter(|&a| self.marks[a].load(Ordering::Relaxed) & BACKWARD != 0)
+            .collect();
+        let forward_only: Vec<usize> = forward
+            .into_iter()
+            .filter(|&a| self.marks[a].load(Ordering::Relaxed) & BACKWARD == 0)
+            .collect();
+        let backward_only: Vec<usize> = backward
+            .into_iter()
+            .filter(|&a| self.marks[a].load(Ordering::Relaxed) & FORWARD == 0)
+            .collect();
+        let rest: Vec<usize> = nodes
+            .into_iter()
+            .filter(|&a| self.marks[a].load(Ordering::Relaxed) == 0)
+            .collect();
+        for &a in scc.iter().chain(&forward_only).chain(&backward_only) {
+            self.marks[a].store(0, Ordering::Relaxed);
+        }
+        for &a in &scc {
+            self.label[a].store(DONE, Ordering::Relaxed);
+        }
+        let forward_label = self.relabel(&forward_only);
+        let backward_label = self.relabel(&backward_only);
+        let rest_label = self.relabel(&rest);
+        sccs.push(scc);
+
+        let (forward_sccs, (backward_sccs, rest_sccs)) = rayon::join(
+            || self.solve(forward_only, forward_label),
+            || {
+                rayon::join(
+                    || self.solve(backward_only, backward_label),
+                    || self.solve(rest, rest_label),
+                )
+            },
+        );
+        sccs.extend(forward_sccs);
+        sccs.extend(backward_sccs);
+        sccs.extend(rest_sccs);
+        sccs
+    }
+
+    /// Return the nodes of the subproblem `label` reachable from `start`,
+    /// along the edges if `forward` or against them otherwise, and mark them.
+    fn search(&self, start: usize, label: usize, forward: bool) -> Vec<usize> {
+        let bit = if forward { FORWARD } else { BACKWARD };
+        let visit = |b: usize| {
+            self.label[b].load(Ordering::Relaxed) == label
+                && self.marks[b].fetch_or(bit, Ordering::Relaxed) & bit == 0
+        };
+        self.marks[start].fetch_or(bit, Ordering::Relaxed);
+        let mut found = vec![start];
+        let mut frontier = vec![start];
+        while !frontier.is_empty() {
+            let next: Vec<usize> = if frontier.len() > PARALLEL_FRONTIER {
+                frontier
+                    .par_iter()
+                    .flat_map_iter(|&a| {
+                        self.neighbors(a, forward)
+                            .iter()
+                            .copied()
+                            .filter(|&b| visit(b))
+                    })
+                    .collect()
+            } else {
+                frontier
+                    .iter()
+                    .flat_map(|&a| self.neighbors(a, forward).iter().copied())
+                    .filter(|&b| visit(b))
+                    .collect()
+            };
+            found.extend_from_slice(&next);
+            frontier = next;
+        }
+        found
+    }
+
+    /// Return the components of the nodes of the subproblem `label`, with an
+    /// iterative Tarjan's algorithm.
+    fn tarjan(&self, nodes: &[usize], label: usize) -> Vec<Vec<usize>> {
+        let local: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &a)| (a, i)).collect();
+        let n = nodes.len();
+        let mut index = vec![usize::MAX; n];
+        let mut lowlink = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut sccs = Vec::new();
+        let mut next_index = 0;
+        // The nodes being visited, with the position of their next successor.
+        let mut call_stack: Vec<(usize, usize)> = Vec::new();
+        for root in 0..n {
+            if index[root] != usize::MAX {
+                continue;
+            }
+            call_stack.push((root, 0));
+            while let Some(&(v, next)) = call_stack.last() {
+                if index[v] == usize::MAX {
+                    index[v] = next_index;
+                    lowlink[v] = next_index;
+                    next_index += 1;
+                    stack.push(v);
+                    on_stack[v] = true;
+                }
+                let successors = self.neighbors(nodes[v], true);
+                let mut next = next;
+                let mut child = None;
+                while next < successors.len() {
+                    let b = successors[next];
+                    next += 1;
+                    if self.label[b].load(Ordering::Relaxed) != label {
+                        continue;
+                    }
+                    let w = local[&b];
+                    if index[w] == usize::MAX {
+                        child = Some(w);
+                        break;
+                    } else if on_stack[w] {
+                        lowlink[v] = lowlink[v].min(index[w]);
+                    }
+                }
+                if let Some(w) = child {
+                    call_stack.last_mut().unwrap().1 = next;
+                    call_stack.push((w, 0));
+                    continue;
+                }
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    lowlink[parent] = lowlink[parent].min(lowlink[v]);
+                }
+                if lowlink[v] == index[v] {
+                    let mut scc = Vec::new();
+                    loop {
+                        let w = stack.pop().unwrap();
+                        on_stack[w] = false;
+                        scc.push(nodes[w]);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    sccs.push(scc);
+                }
+            }
+        }
+        for &a in nodes {
+            self.label[a].store(DONE, Ordering::Relaxed);
+        }
+        sccs
+    }
+}
+
+/// Return the edges grouped by source, as offsets into the targets.
+fn compress<I>(n: usize, edges: I) -> (Vec<usize>, Vec<usize>)
+where
+    I: Iterator<Item = (usize, usize)> + Clone,
+{
+    let mut offsets = vec![0; n + 1];
+    for (a, _) in edges.clone() {
+        offsets[a + 1] += 1;
+    }
+    for i in 0..n {
+        offsets[i + 1] += offsets[i];
+    }
+    let mut position = offsets.clone();
+    let mut targets = vec![0; offsets[n]];
+    for (a, b) in edges {
+        targets[position[a]] = b;
+        position[a] += 1;
+    }
+    (offsets, targets)
+}
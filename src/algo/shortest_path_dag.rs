@@ -0,0 +1,303 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::hash::Hash;
+
+use hashbrown::hash_map::{
+    Entry::{Occupied, Vacant},
+    HashMap,
+};
+
+use crate::algo::Measure;
+use crate::graph::{DiGraph, NodeIndex};
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, IntoEdges, VisitMap, Visitable};
+
+/// The shortest path DAG of a graph from a source node, computed by
+/// [`shortest_path_dag`].
+///
+/// It contains every edge that lies on *some* shortest path from the source,
+/// so that every path of the DAG starting at the source is a shortest path
+/// and every shortest path of the graph is a path of the DAG. It is the
+/// basis for counting and enumerating shortest paths, and for centrality
+/// measures such as betweenness.
+#[derive(Clone, Debug)]
+pub struct ShortestPathDag<N, E, K> {
+    source: N,
+    distances: HashMap<N, K>,
+    predecessors: HashMap<N, Vec<(N, E)>>,
+    /// Reached nodes by non-decreasing distance, which is a topological
+    /// order of the DAG.
+    order: Vec<N>,
+}
+
+impl<N, E, K> ShortestPathDag<N, E, K>
+where
+    N: Copy + Eq + Hash,
+    E: Copy,
+    K: Copy,
+{
+    /// Return the source node of the shortest paths.
+    pub fn source(&self) -> N {
+        self.source
+    }
+
+    /// Return the shortest path distance from the source to `node`, or
+    /// `None` if it was not reached.
+    pub fn distance(&self, node: N) -> Option<K> {
+        self.distances.get(&node).copied()
+    }
+
+    /// Return the reached nodes, ordered by non-decreasing distance from the
+    /// source. This is a topological order of the DAG.
+    pub fn nodes(&self) -> &[N] {
+        &self.order
+    }
+
+    /// Return the DAG edges entering `node`, as pairs of the predecessor
+    /// node and the id of the edge in the original graph.
+    ///
+    /// Parallel edges of the original graph that are all on a shortest path
+    /// are all reported.
+    pub fn predecessor_edges(&self, node: N) -> &[(N, E)] {
+        self.predecessors.get(&node).map_or(&[], |p| p.as_slice())
+    }
+
+    /// Iterate over every edge of the DAG, as triples of the source node,
+    /// the target node and the id of the edge in the original graph.
+    pub fn edges(&self) -> impl Iterator<Item = (N, N, E)> + '_ {
+        self.order.iter().flat_map(move |&node| {
+            self.predecessor_edges(node)
+                .iter()
+                .map(move |&(pred, edge)| (pred, node, edge))
+        })
+    }
+
+    /// Return the number of distinct shortest paths from the source to
+    /// `target`, where paths are sequences of nodes, so that parallel edges
+    /// do not count as different paths.
+    ///
+    /// Returns `0` if `target` was not reached. The count saturates at
+    /// `usize::MAX`.
+    pub fn count_paths(&self, target: N) -> usize {
+        if !self.distances.contains_key(&target) {
+            return 0;
+        }
+        let mut counts: HashMap<N, usize> = HashMap::with_capacity(self.order.len());
+        for &node in &self.order {
+            let count = if node == self.source {
+                1
+            } else {
+                self.distinct_predecessors(node)
+                    .fold(0usize, |acc, pred| acc.saturating_add(counts[&pred]))
+            };
+            if node == target {
+                return count;
+            }
+            counts.insert(node, count);
+        }
+        0
+    }
+
+    /// Enumerate the distinct shortest paths from the source to `target`, as
+    /// sequences of nodes starting with the source and ending with `target`.
+    ///
+    /// At most `max_paths` paths are returned, if given. Returns an empty list
+    /// if `target` was not reached.
+    pub fn paths_to(&self, target: N, max_paths: Option<usize>) -> Vec<Vec<N>> {
+        let limit = max_paths.unwrap_or(usize::MAX);
+        let mut paths = Vec::new();
+        if limit == 0 || !self.distances.contains_key(&target) {
+            return paths;
+        }
+
+        // Walk the DAG backwards from `target`. Each stack entry holds a node
+        // of the current partial path and the index of its next predecessor
+        // edge to explore.
+        let mut stack = vec![(target, 0)];
+        while let Some(&mut (node, ref mut next_pred)) = stack.last_mut() {
+            if node == self.source {
+                paths.push(stack.iter().rev().map(|&(n, _)| n).collect());
+                if paths.len() >= limit {
+                    break;
+                }
+                stack.pop();
+                continue;
+            }
+            let preds = self.predecessor_edges(node);
+            // Skip parallel edges to a predecessor that was already explored.
+            while *next_pred < preds.len()
+                && preds[..*next_pred]
+                    .iter()
+                    .any(|&(p, _)| p == preds[*next_pred].0)
+            {
+                *next_pred += 1;
+            }
+            match preds.get(*next_pred) {
+                Some(&(pred, _)) => {
+                    *next_pred += 1;
+                    stack.push((pred, 0));
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        paths
+    }
+
+    /// Materialize the DAG as a new directed graph.
+    ///
+    /// Each node is weighted by the corresponding node id of the original
+    /// graph, and each edge by the original edge id. Nodes are added in
+    /// order of non-decreasing distance, so the source has index `0`.
+    pub fn to_graph(&self) -> DiGraph<N, E> {
+        let mut graph = DiGraph::with_capacity(self.order.len(), self.order.len());
+        let mut index: HashMap<N, NodeIndex> = HashMap::with_capacity(self.order.len());
+        for &node in &self.order {
+            index.insert(node, graph.add_node(node));
+        }
+        for (pred, node, edge) in self.edges() {
+            graph.add_edge(index[&pred], index[&node], edge);
+        }
+        graph
+    }
+
+    pub(crate) fn distinct_predecessors(&self, node: N) -> impl Iterator<Item = N> + '_ {
+        let preds = self.predecessor_edges(node);
+        preds
+            .iter()
+            .enumerate()
+            .filter(move |&(i, &(p, _))| !preds[..i].iter().any(|&(q, _)| q == p))
+            .map(|(_, &(p, _))| p)
+    }
+}
+
+/// Compute the shortest path DAG from `start`.
+///
+/// Runs Dijkstra's algorithm and records, for every node, *all* of its
+/// incoming edges that lie on some shortest path from `start`. For an
+/// unweighted graph, use `|_| 1` as the edge cost to get the breadth-first
+/// search DAG.
+///
+/// Costs are compared for equality to detect ties, so floating point costs
+/// that are only approximately equal are not considered ties. Zero cost
+/// edges between nodes at the same distance are only included in the
+/// direction in which the nodes were settled, which keeps the result acyclic.
+///
+/// If `goal` is not `None`, the search stops once every node at the same
+/// distance as `goal` has been settled. The DAG is then complete for those
+/// nodes, but may miss nodes that are farther away.
+///
+/// The function `edge_cost` should return the cost for a particular edge.
+/// Edge costs must be non-negative.
+///
+/// # Arguments
+/// * `graph`: weighted graph.
+/// * `start`: the start node.
+/// * `goal`: optional *goal* node.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * [`ShortestPathDag`] rooted at `start`.
+///
+/// # Complexity
+/// * Time complexity: **O((|V|+|E|)log(|V|))**.
+/// * Auxiliary space: **O(|V|+|E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::shortest_path_dag;
+///
+/// //   b
+/// //  / \
+/// // a   d
+/// //  \ /
+/// //   c
+/// let mut graph = Graph::<(), u32>::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (a, c, 1), (b, d, 1), (c, d, 1), (b, c, 1)]);
+///
+/// let dag = shortest_path_dag(&graph, a, None, |e| *e.weight());
+/// assert_eq!(dag.distance(d), Some(2));
+/// assert_eq!(dag.count_paths(d), 2);
+/// // The edge from `b` to `c` is not on any shortest path.
+/// assert_eq!(dag.edges().count(), 4);
+/// assert_eq!(dag.to_graph().edge_count(), 4);
+/// ```
+pub fn shortest_path_dag<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    mut edge_cost: F,
+) -> ShortestPathDag<G::NodeId, G::EdgeId, K>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut visited = graph.visit_map();
+    let mut scores = HashMap::new();
+    let mut predecessors = HashMap::new();
+    let mut order = Vec::new();
+    let mut visit_next = BinaryHeap::new();
+    let zero_score = K::default();
+    scores.insert(start, zero_score);
+    visit_next.push(MinScored(zero_score, start));
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if visited.is_visited(&node) {
+            continue;
+        }
+        // Nodes at the same distance as `goal` may still reach it through
+        // zero cost edges, so stop only once the distance grows past it.
+        if let Some(goal_score) = goal.and_then(|goal| scores.get(&goal)) {
+            if node_score > *goal_score {
+                break;
+            }
+        }
+        visited.visit(node);
+        order.push(node);
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+            let next_score = node_score + edge_cost(edge);
+            match scores.entry(next) {
+                Occupied(ent) => {
+                    if next_score < *ent.get() {
+                        *ent.into_mut() = next_score;
+                        visit_next.push(MinScored(next_score, next));
+                        predecessors.insert(next, vec![(node, edge.id())]);
+                    } else if next_score == *ent.get() {
+                        predecessors
+                            .entry(next)
+                            .or_default()
+                            .push((node, edge.id()));
+                    }
+                }
+                Vacant(ent) => {
+                    ent.insert(next_score);
+                    visit_next.push(MinScored(next_score, next));
+                    predecessors.insert(next, vec![(node, edge.id())]);
+                }
+            }
+        }
+    }
+
+    // Only keep settled nodes, so that every node of the DAG has its final
+    // distance and complete set of predecessors.
+    scores.retain(|node, _| visited.is_visited(node));
+    predecessors.retain(|node, _| visited.is_visited(node));
+    ShortestPathDag {
+        source: start,
+        distances: scores,
+        predecessors,
+        order,
+    }
+}
@@ -0,0 +1,206 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::iter::FusedIterator;
+
+use fixedbitset::FixedBitSet;
+
+use crate::algo::Measure;
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, IntoEdges, NodeIndexable};
+
+const NONE: usize = usize::MAX;
+
+/// A shortest path tree of a graph from a source node, computed by
+/// [`shortest_path_tree`].
+///
+/// The tree is stored as two flat arrays indexed by the graph's node indices
+/// (see [`NodeIndexable`]): the parent of each node on a shortest path from
+/// the source, and its distance. Compared to the maps returned by
+/// [`dijkstra`](super::dijkstra()), this uses a fraction of the memory on
+/// large graphs, and paths to any target are reconstructed without
+/// allocation by walking the parents.
+#[derive(Clone, Debug)]
+pub struct ShortestPathTree<K> {
+    source: usize,
+    /// The parent of each node, the source for the source itself, and
+    /// `NONE` for nodes that were not reached.
+    parents: Vec<usize>,
+    distances: Vec<K>,
+}
+
+impl<K: Copy> ShortestPathTree<K> {
+    /// Return the index of the source node.
+    pub fn source_index(&self) -> usize {
+        self.source
+    }
+
+    /// Return the number of node indices the tree covers, the `node_bound`
+    /// of the graph.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Return `true` if the graph had no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Return `true` if the node at `index` was reached from the source.
+    pub fn is_reached(&self, index: usize) -> bool {
+        self.parents.get(index).map_or(false, |&p| p != NONE)
+    }
+
+    /// Return the index of the parent of the node at `index`, or `None` for
+    /// the source and for the nodes that were not reached.
+    pub fn parent(&self, index: usize) -> Option<usize> {
+        self.parents
+            .get(index)
+            .copied()
+            .filter(|&p| p != NONE && index != self.source)
+    }
+
+    /// Return the shortest path distance from the source to the node at
+    /// `index`, or `None` if it was not reached.
+    pub fn distance(&self, index: usize) -> Option<K> {
+        self.is_reached(index).then(|| self.distances[index])
+    }
+
+    /// Iterate over the indices of the nodes of a shortest path from the
+    /// source to the node at `index`, *in reverse*: from the node at `index`
+    /// to the source.
+    ///
+    /// The iterator is empty if the node was not reached.
+    pub fn path_indices(&self, index: usize) -> PathIndices<'_, K> {
+        PathIndices {
+            tree: self,
+            next: if self.is_reached(index) { index } else { NONE },
+        }
+    }
+
+    /// Iterate over the nodes of a shortest path from the source to
+    /// `target`, *in reverse*: from `target` to the source.
+    ///
+    /// `graph` must be the graph the tree was computed on. The iterator is
+    /// empty if `target` was not reached.
+    pub fn path<'a, G>(
+        &'a self,
+        graph: G,
+        target: G::NodeId,
+    ) -> impl Iterator<Item = G::NodeId> + 'a
+    where
+        G: NodeIndexable + 'a,
+    {
+        self.path_indices(graph.to_index(target))
+            .map(move |i| graph.from_index(i))
+    }
+}
+
+/// An iterator over the node indices of a path in a [`ShortestPathTree`],
+/// from its end to the source.
+#[derive(Clone, Debug)]
+pub struct PathIndices<'a, K> {
+    tree: &'a ShortestPathTree<K>,
+    next: usize,
+}
+
+impl<K> Iterator for PathIndices<'_, K> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.next == NONE {
+            return None;
+        }
+        let index = self.next;
+        self.next = if index == self.tree.source {
+            NONE
+        } else {
+            self.tree.parents[index]
+        };
+        Some(index)
+    }
+}
+
+impl<K> FusedIterator for PathIndices<'_, K> {}
+
+/// \[Generic\] Compute a shortest path tree from `start`, stored as a parent
+/// array.
+///
+/// Runs Dijkstra's algorithm, and records for each reached node its parent
+/// on a shortest path and its distance, in arrays indexed by the graph's
+/// node indices. This is the representation to prefer for all-targets
+/// queries on large graphs, where [`dijkstra`](super::dijkstra()) and
+/// [`ShortestPathDag`](super::ShortestPathDag) spend most of their time and
+/// memory in hash maps.
+///
+/// The function `edge_cost` should return the cost for a particular edge.
+/// Edge costs must be non-negative.
+///
+/// # Arguments
+/// * `graph`: weighted graph.
+/// * `start`: the start node.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * [`ShortestPathTree`] rooted at `start`.
+///
+/// # Complexity
+/// * Time complexity: **O((|V|+|E|)log(|V|))**.
+/// * Auxiliary space: **O(|V|+|E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::shortest_path_tree;
+/// use petgraph::graph::{DiGraph, NodeIndex};
+///
+/// let graph = DiGraph::<(), u32>::from_edges(&[(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1)]);
+///
+/// let tree = shortest_path_tree(&graph, NodeIndex::new(0), |e| *e.weight());
+/// assert_eq!(tree.distance(3), Some(4));
+/// assert_eq!(tree.parent(1), Some(2));
+///
+/// let mut path: Vec<_> = tree.path(&graph, NodeIndex::new(3)).collect();
+/// path.reverse();
+/// assert_eq!(path, [0, 2, 1, 3].map(NodeIndex::new));
+/// ```
+pub fn shortest_path_tree<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    mut edge_cost: F,
+) -> ShortestPathTree<K>
+where
+    G: IntoEdges + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let n = graph.node_bound();
+    let source = graph.to_index(start);
+    let mut parents = vec![NONE; n];
+    let mut distances = vec![K::default(); n];
+    let mut settled = FixedBitSet::with_capacity(n);
+    let mut visit_next = BinaryHeap::new();
+    parents[source] = source;
+    visit_next.push(MinScored(K::default(), source));
+    while let Some(MinScored(score, index)) = visit_next.pop() {
+        if settled.put(index) {
+            continue;
+        }
+        for edge in graph.edges(graph.from_index(index)) {
+            let next = graph.to_index(edge.target());
+            if settled.contains(next) {
+                continue;
+            }
+            let next_score = score + edge_cost(edge);
+            if parents[next] == NONE || next_score < distances[next] {
+                parents[next] = index;
+                distances[next] = next_score;
+                visit_next.push(MinScored(next_score, next));
+            }
+        }
+    }
+    ShortestPathTree {
+        source,
+        parents,
+        distances,
+    }
+}
@@ -0,0 +1,269 @@
+//! SimRank, a structural similarity of the nodes of a graph: two nodes are
+//! similar if their in-neighbors are similar.
+
+use alloc::{vec, vec::Vec};
+
+use hashbrown::HashMap;
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+
+/// The parameters of [`simrank`] and [`simrank_pairs`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SimRankConfig {
+    /// The decay factor **C**, strictly between `0` and `1`: the similarity
+    /// of two nodes is at most **C** times the average similarity of their
+    /// in-neighbors.
+    pub decay: f64,
+    /// The iteration stops when the scores change by at most `tolerance`.
+    pub tolerance: f64,
+    /// The maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+impl Default for SimRankConfig {
+    /// A decay factor of `0.8`, a tolerance of `1e-4` and 100 iterations.
+    fn default() -> Self {
+        SimRankConfig {
+            decay: 0.8,
+            tolerance: 1e-4,
+            max_iterations: 100,
+        }
+    }
+}
+
+/// The in-neighbors of each node index, without repetitions, which are all
+/// the neighbors for an undirected graph.
+fn in_neighbors<G>(graph: G) -> Vec<Vec<usize>>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let mut neighbors = vec![Vec::new(); graph.node_bound()];
+    for edge in graph.edge_references() {
+        let a = graph.to_index(edge.source());
+        let b = graph.to_index(edge.target());
+        neighbors[b].push(a);
+        if !graph.is_directed() && a != b {
+            neighbors[a].push(b);
+        }
+    }
+    for list in &mut neighbors {
+        list.sort_unstable();
+        list.dedup();
+    }
+    neighbors
+}
+
+fn check(config: &SimRankConfig) {
+    assert!(
+        config.decay > 0.0 && config.decay < 1.0,
+        "SimRank: the decay factor must be strictly between 0 and 1"
+    );
+}
+
+/// \[Generic\] Compute the [SimRank][1] similarity of all pairs of nodes of
+/// a graph.
+///
+/// The similarity of a node with itself is `1`, and the similarity of two
+/// distinct nodes `a` and `b` is
+/// **C / (|I(a)| |I(b)|) · Σ s(i, j)**, over the in-neighbors `i` of `a` and
+/// `j` of `b`, or `0` if one of them has no in-neighbors. The scores are
+/// computed by iterating this equation from the identity, until they change
+/// by at most the tolerance. For an undirected graph, the in-neighbors are
+/// all the neighbors. Parallel edges are counted once.
+///
+/// The matrix of the scores takes **O(|V|²)** memory; see
+/// [`simrank_pairs`] for the similarity of a few pairs of a large graph.
+///
+/// **Panics** if the decay factor is not strictly between `0` and `1`.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `config`: the decay factor and the stopping criterion.
+///
+/// # Returns
+/// * A symmetric matrix of size **|V|** of the scores, between `0` and
+///   `1`, indexed by the node indices.
+///
+/// # Complexity
+/// * Time complexity: **O(k · |V| · |E|)**.
+/// * Auxiliary space: **O(|V|² + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **k** is the number of iterations.
+///
+/// [1]: https://en.wikipedia.org/wiki/SimRank
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::simrank::{simrank, SimRankConfig};
+/// use petgraph::graph::DiGraph;
+///
+/// // Two pages linked from the same two pages are similar, and so are the
+/// // two pages that link to them.
+/// let graph = DiGraph::<(), ()>::from_edges(&[(0, 2), (0, 3), (1, 2), (1, 3), (4, 0), (4, 1)]);
+/// let scores = simrank(&graph, &SimRankConfig::default());
+/// assert_eq!(scores[0][1], 0.8);
+/// assert!((scores[2][3] - 0.8 * (2.0 + 2.0 * 0.8) / 4.0).abs() < 1e-9);
+/// assert_eq!(scores[0][2], 0.0);
+/// ```
+pub fn simrank<G>(graph: G, config: &SimRankConfig) -> Vec<Vec<f64>>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    check(config);
+    let neighbors = in_neighbors(graph);
+    let n = neighbors.len();
+    let mut scores: Vec<Vec<f64>> = (0..n)
+        .map(|a| (0..n).map(|b| if a == b { 1.0 } else { 0.0 }).collect())
+        .collect();
+    // The sums of the scores of the in-neighbors of `a` and `j`, for every
+    // node `j`, so that each score sums over the in-neighbors of `b` only.
+    let mut partial = vec![0.0; n];
+    for _ in 0..config.max_iterations {
+        let mut next = vec![vec![0.0; n]; n];
+        let mut change: f64 = 0.0;
+        for a in 0..n {
+            next[a][a] = 1.0;
+            if neighbors[a].is_empty() {
+                continue;
+            }
+            partial.iter_mut().for_each(|x| *x = 0.0);
+            for &i in &neighbors[a] {
+                for (x, &s) in partial.iter_mut().zip(&scores[i]) {
+                    *x += s;
+                }
+            }
+            for b in a + 1..n {
+                if neighbors[b].is_empty() {
+                    continue;
+                }
+                let sum: f64 = neighbors[b].iter().map(|&j| partial[j]).sum();
+                let score = config.decay * sum / (neighbors[a].len() * neighbors[b].len()) as f64;
+                next[a][b] = score;
+                next[b][a] = score;
+                // `f64::abs` is not in `core` before Rust 1.85.
+                let d = score - scores[a][b];
+                change = change.max(if d < 0.0 { -d } else { d });
+            }
+        }
+        scores = next;
+        if change <= config.tolerance {
+            break;
+        }
+    }
+    scores
+}
+
+/// \[Generic\] Compute the [SimRank][1] similarity of the pairs of nodes of
+/// `pairs` only.
+///
+/// The scores are those of [`simrank`], after **k** iterations, where **k**
+/// is the smallest number such that **C^(k+1)** is at most the tolerance,
+/// which bounds the error, or the maximum number of iterations. Instead of
+/// all pairs of nodes, only the pairs that the scores of `pairs` depend on
+/// in the first **k** iterations are computed: the pairs of nodes from
+/// which a pair of `pairs` can be reached by walking **k** or fewer edges
+/// forward from both nodes at the same time. Their number, which bounds
+/// the memory used, is small for a few pairs of a sparse graph, or for a
+/// large tolerance, and at most **|V|²** in any case.
+///
+/// **Panics** if the decay factor is not strictly between `0` and `1`.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `pairs`: the node pairs to score.
+/// * `config`: the decay factor and the stopping criterion.
+///
+/// # Returns
+/// * The score of each pair, in the order of `pairs`.
+///
+/// # Complexity
+/// * Time complexity: **O(|E| log |E| + k · P · Δ²)**.
+/// * Auxiliary space: **O(|E| + k · P)**.
+///
+/// where **|E|** is the number of edges, **Δ** is the maximum in-degree,
+/// **k** is the number of iterations and **P** is the largest number of
+/// pairs computed in an iteration.
+///
+/// [1]: https://en.wikipedia.org/wiki/SimRank
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::simrank::{simrank, simrank_pairs, SimRankConfig};
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+/// let config = SimRankConfig { tolerance: 1e-6, ..SimRankConfig::default() };
+///
+/// let scores = simrank_pairs(&graph, &[(NodeIndex::new(1), NodeIndex::new(2))], &config);
+/// let all = simrank(&graph, &config);
+/// assert!((scores[0] - all[1][2]).abs() < 1e-5);
+/// ```
+pub fn simrank_pairs<G>(
+    graph: G,
+    pairs: &[(G::NodeId, G::NodeId)],
+    config: &SimRankConfig,
+) -> Vec<f64>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    check(config);
+    let neighbors = in_neighbors(graph);
+    let mut iterations = 0;
+    let mut error = config.decay;
+    while error > config.tolerance && iterations < config.max_iterations {
+        error *= config.decay;
+        iterations += 1;
+    }
+
+    let ordered = |a: usize, b: usize| if a < b { (a, b) } else { (b, a) };
+    // The pairs of distinct nodes whose score is needed after each number
+    // of remaining iterations, from `iterations` down to `0`.
+    let mut levels: Vec<HashMap<(usize, usize), f64>> = Vec::with_capacity(iterations + 1);
+    let mut level: HashMap<(usize, usize), f64> = pairs
+        .iter()
+        .map(|&(a, b)| ordered(graph.to_index(a), graph.to_index(b)))
+        .filter(|&(a, b)| a != b)
+        .map(|pair| (pair, 0.0))
+        .collect();
+    for _ in 0..iterations {
+        let mut next = HashMap::new();
+        for &(a, b) in level.keys() {
+            for &i in &neighbors[a] {
+                for &j in neighbors[b].iter().filter(|&&j| j != i) {
+                    next.insert(ordered(i, j), 0.0);
+                }
+            }
+        }
+        levels.push(level);
+        level = next;
+    }
+
+    // With no iteration left, distinct nodes have the score `0`.
+    for mut upper in levels.into_iter().rev() {
+        for (&(a, b), score) in upper.iter_mut() {
+            let mut sum = 0.0;
+            for &i in &neighbors[a] {
+                for &j in &neighbors[b] {
+                    sum += if i == j { 1.0 } else { level[&ordered(i, j)] };
+                }
+            }
+            if sum > 0.0 {
+                *score = config.decay * sum / (neighbors[a].len() * neighbors[b].len()) as f64;
+            }
+        }
+        level = upper;
+    }
+
+    pairs
+        .iter()
+        .map(|&(a, b)| {
+            let (a, b) = (graph.to_index(a), graph.to_index(b));
+            if a == b {
+                1.0
+            } else {
+                level[&ordered(a, b)]
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,817 @@
+//! Spectral graph theory: Laplacian matrices, the Fiedler vector and
+//! spectral bisection.
+//!
+//! The *Laplacian* of an undirected weighted graph is **L = D - A**, where
+//! **A** is the weighted adjacency matrix and **D** the diagonal matrix of
+//! the weighted degrees. Its second smallest eigenvalue, the *algebraic
+//! connectivity*, is zero exactly when the graph is disconnected, and the
+//! corresponding eigenvector, the *Fiedler vector*, orders the nodes so
+//! that cutting it in two gives a good bisection of the graph.
+//!
+//! Seeing the edges as resistors, the *effective resistance* between two
+//! nodes is the resistance of the whole network between them, a distance
+//! that accounts for all the paths between the nodes: it is smaller for
+//! nodes joined by many short paths than for nodes joined by a single one.
+//!
+//! This module requires the `linalg` feature.
+
+use alloc::{vec, vec::Vec};
+
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeCompactIndexable};
+
+/// Which Laplacian matrix of a graph to use.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LaplacianKind {
+    /// The combinatorial Laplacian **L = D - A**.
+    Combinatorial,
+    /// The symmetric normalized Laplacian **D^(-1/2) L D^(-1/2)**, whose
+    /// eigenvalues are between `0` and `2`. Rows and columns of isolated
+    /// nodes are zero.
+    Normalized,
+}
+
+/// A square sparse matrix, in compressed sparse row form.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMatrix {
+    size: usize,
+    /// The start of each row in `columns` and `values`, and their length.
+    offsets: Vec<usize>,
+    /// The columns of the nonzero entries of each row, sorted.
+    columns: Vec<usize>,
+    values: Vec<f64>,
+}
+
+impl SparseMatrix {
+    /// Return the number of rows (and columns) of the matrix.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Return the number of stored entries.
+    pub fn nonzero_count(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Return the entry at `row` and `column`.
+    ///
+    /// **Panics** if `row` is out of bounds.
+    pub fn get(&self, row: usize, column: usize) -> f64 {
+        let range = self.offsets[row]..self.offsets[row + 1];
+        match self.columns[range.clone()].binary_search(&column) {
+            Ok(i) => self.values[range.start + i],
+            Err(_) => 0.0,
+        }
+    }
+
+    /// Iterate over the stored entries of `row`, as pairs of a column and a
+    /// value, by increasing column.
+    ///
+    /// **Panics** if `row` is out of bounds.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let range = self.offsets[row]..self.offsets[row + 1];
+        self.columns[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    /// Return the product of the matrix and the vector `x`.
+    ///
+    /// **Panics** if the length of `x` is not the size of the matrix.
+    pub fn mul_vec(&self, x: &[f64]) -> Vec<f64> {
+        assert_eq!(x.len(), self.size);
+        (0..self.size)
+            .map(|row| self.row(row).map(|(column, value)| value * x[column]).sum())
+            .collect()
+    }
+
+    /// Return the matrix as a dense matrix, a vector of rows.
+    pub fn to_dense(&self) -> Vec<Vec<f64>> {
+        let mut dense = vec![vec![0.0; self.size]; self.size];
+        for (row, dense_row) in dense.iter_mut().enumerate() {
+            for (column, value) in self.row(row) {
+                dense_row[column] = value;
+            }
+        }
+        dense
+    }
+}
+
+/// \[Generic\] Return the Laplacian matrix of a graph, with rows and columns
+/// indexed by the node indices.
+///
+/// Edge directions are ignored: an edge contributes its weight to both
+/// directions, and parallel edges add up. Loops are ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `kind`: the Laplacian to compute.
+/// * `edge_weight`: closure that returns the weight of an edge, which should
+///   be non-negative.
+///
+/// # Returns
+/// * A [`SparseMatrix`] of size **|V|**, that [`SparseMatrix::to_dense`]
+///   turns into a dense matrix.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| log |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::spectral::{laplacian_matrix, LaplacianKind};
+/// use petgraph::graph::UnGraph;
+///
+/// let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 2.0), (1, 2, 1.0)]);
+/// let laplacian = laplacian_matrix(&graph, LaplacianKind::Combinatorial, |e| *e.weight());
+/// assert_eq!(
+///     laplacian.to_dense(),
+///     vec![
+///         vec![2.0, -2.0, 0.0],
+///         vec![-2.0, 3.0, -1.0],
+///         vec![0.0, -1.0, 1.0],
+///     ]
+/// );
+/// ```
+pub fn laplacian_matrix<G, F>(graph: G, kind: LaplacianKind, edge_weight: F) -> SparseMatrix
+where
+    G: IntoEdgeReferences + NodeCompactIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    laplacian_and_degrees(graph, kind, edge_weight).0
+}
+
+/// Return the Laplacian matrix of a graph and the weighted degrees of its
+/// nodes.
+fn laplacian_and_degrees<G, F>(
+    graph: G,
+    kind: LaplacianKind,
+    mut edge_weight: F,
+) -> (SparseMatrix, Vec<f64>)
+where
+    G: IntoEdgeReferences + NodeCompactIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let n = graph.node_bound();
+    let mut entries: Vec<(usize, usize, f64)> = Vec::new();
+    let mut degrees = vec![0.0; n];
+    for edge in graph.edge_references() {
+        let a = graph.to_index(edge.source());
+        let b = graph.to_index(edge.target());
+        if a != b {
+            let weight = edge_weight(edge);
+            entries.push((a, b, -weight));
+            entries.push((b, a, -weight));
+            degrees[a] += weight;
+            degrees[b] += weight;
+        }
+    }
+    entries.extend((0..n).map(|v| (v, v, degrees[v])));
+    // A stable sort adds up parallel edges in the same order on both sides,
+    // so the matrix is exactly symmetric.
+    entries.sort_by_key(|&(row, column, _)| (row, column));
+
+    let scale: Vec<f64> = match kind {
+        LaplacianKind::Combinatorial => vec![1.0; n],
+        LaplacianKind::Normalized => degrees
+            .iter()
+            .map(|&d| if d > 0.0 { 1.0 / d.sqrt() } else { 0.0 })
+            .collect(),
+    };
+    // Every row has a diagonal entry, so every row is nonempty.
+    let mut offsets = vec![0; n + 1];
+    let mut columns = Vec::with_capacity(entries.len());
+    let mut values: Vec<f64> = Vec::with_capacity(entries.len());
+    for (row, column, value) in entries {
+        let value = value * scale[row] * scale[column];
+        if columns.len() > offsets[row] && columns.last() == Some(&column) {
+            *values.last_mut().unwrap() += value;
+        } else {
+            columns.push(column);
+            values.push(value);
+        }
+        offsets[row + 1] = columns.len();
+    }
+    let laplacian = SparseMatrix {
+        size: n,
+        offsets,
+        columns,
+        values,
+    };
+    (laplacian, degrees)
+}
+
+/// The parameters of the Lanczos iteration of [`fiedler_vector`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LanczosConfig {
+    /// The iteration stops when the residual **‖Lx - λx‖** is at most
+    /// `tolerance` times the largest possible eigenvalue.
+    pub tolerance: f64,
+    /// The number of Lanczos vectors kept before a restart.
+    pub krylov_dimension: usize,
+    /// The maximum number of products of the Laplacian with a vector.
+    pub max_iterations: usize,
+}
+
+impl Default for LanczosConfig {
+    /// A tolerance of `1e-8`, 40 Lanczos vectors and 10000 iterations.
+    fn default() -> Self {
+        LanczosConfig {
+            tolerance: 1e-8,
+            krylov_dimension: 40,
+            max_iterations: 10000,
+        }
+    }
+}
+
+/// The Fiedler vector of a graph and its eigenvalue, computed by
+/// [`fiedler_vector`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FiedlerVector {
+    /// The second smallest eigenvalue of the Laplacian, the algebraic
+    /// connectivity of the graph.
+    pub value: f64,
+    /// A unit eigenvector of the eigenvalue, indexed by the node indices.
+    pub vector: Vec<f64>,
+    /// The norm of the residual **Lx - λx**, which is small if the
+    /// iteration converged.
+    pub residual: f64,
+}
+
+/// \[Generic\] Compute the Fiedler vector of a graph: an eigenvector of the
+/// second smallest eigenvalue of its Laplacian.
+///
+/// Uses the restarted [Lanczos method][1] with full reorthogonalization, on
+/// the orthogonal complement of the eigenvector of the eigenvalue `0` (the
+/// constant vector for the combinatorial Laplacian). Only products of the
+/// sparse Laplacian with vectors are computed, so large sparse graphs are
+/// handled.
+///
+/// If the graph is disconnected, the eigenvalue is `0`. Edge directions
+/// are ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `kind`: the Laplacian to use.
+/// * `edge_weight`: closure that returns the weight of an edge, which should
+///   be non-negative.
+/// * `config`: the parameters of the iteration.
+///
+/// # Returns
+/// * `Some(FiedlerVector)`: the eigenvalue, the eigenvector and the residual.
+/// * `None`: if the graph has fewer than two nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(i · (|V| · k + |E|))**.
+/// * Auxiliary space: **O(|V| · k + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges,
+/// **k** is the Krylov dimension and **i** the number of iterations.
+///
+/// [1]: https://en.wikipedia.org/wiki/Lanczos_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::spectral::{fiedler_vector, LaplacianKind, LanczosConfig};
+/// use petgraph::graph::UnGraph;
+///
+/// // A path of 4 nodes has algebraic connectivity 2 - √2.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let fiedler = fiedler_vector(
+///     &graph,
+///     LaplacianKind::Combinatorial,
+///     |_| 1.0,
+///     &LanczosConfig::default(),
+/// )
+/// .unwrap();
+/// assert!((fiedler.value - (2.0 - 2f64.sqrt())).abs() < 1e-8);
+/// // The vector is monotone along the path.
+/// let v = &fiedler.vector;
+/// assert!(v.windows(2).all(|w| w[0] < w[1]) || v.windows(2).all(|w| w[0] > w[1]));
+/// ```
+pub fn fiedler_vector<G, F>(
+    graph: G,
+    kind: LaplacianKind,
+    edge_weight: F,
+    config: &LanczosConfig,
+) -> Option<FiedlerVector>
+where
+    G: IntoEdgeReferences + NodeCompactIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (laplacian, degrees) = laplacian_and_degrees(graph, kind, edge_weight);
+    fiedler(&laplacian, &degrees, kind, config)
+}
+
+fn fiedler(
+    laplacian: &SparseMatrix,
+    degrees: &[f64],
+    kind: LaplacianKind,
+    config: &LanczosConfig,
+) -> Option<FiedlerVector> {
+    let n = laplacian.size();
+    if n < 2 {
+        return None;
+    }
+
+    // The eigenvector of the eigenvalue 0 that is removed from the search.
+    let mut null: Vec<f64> = match kind {
+        LaplacianKind::Combinatorial => vec![1.0; n],
+        LaplacianKind::Normalized => degrees.iter().map(|&d| d.max(0.0).sqrt()).collect(),
+    };
+    if normalize(&mut null) == 0.0 {
+        null[0] = 1.0;
+    }
+    // Gershgorin's bound on the largest eigenvalue.
+    let spectral_bound = (0..n)
+        .map(|v| laplacian.row(v).map(|(_, value)| value.abs()).sum::<f64>())
+        .fold(0.0, f64::max)
+        .max(f64::MIN_POSITIVE);
+    let threshold = config.tolerance * spectral_bound;
+
+    // A deterministic start vector with components in every direction.
+    let mut x: Vec<f64> = (0..n)
+        .map(|i| ((i as u64).wrapping_mul(2654435761) % 1000) as f64 / 1000.0 - 0.5)
+        .collect();
+    let dimension = config.krylov_dimension.max(2).min(n - 1);
+    let mut iterations = 0;
+    loop {
+        orthogonalize(&mut x, &null);
+        if normalize(&mut x) == 0.0 {
+            x = (0..n).map(|i| i as f64).collect();
+            orthogonalize(&mut x, &null);
+            normalize(&mut x);
+        }
+        let (value, vector) = lanczos(laplacian, &null, x, dimension, &mut iterations);
+        let mut product = laplacian.mul_vec(&vector);
+        iterations += 1;
+        for (p, &v) in product.iter_mut().zip(&vector) {
+            *p -= value * v;
+        }
+        let residual = norm(&product);
+        if residual <= threshold || iterations >= config.max_iterations || dimension == n - 1 {
+            return Some(FiedlerVector {
+                value,
+                vector,
+                residual,
+            });
+        }
+        x = vector;
+    }
+}
+
+/// \[Generic\] Split the nodes of a graph in two halves, by the order of
+/// their components in the Fiedler vector.
+///
+/// The nodes are sorted by their component in the [`fiedler_vector`], and
+/// the first half (rounded down) of them forms the first part. For the
+/// normalized Laplacian, the components are scaled by **D^(-1/2)** first,
+/// which gives the eigenvector of the random walk Laplacian.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `kind`: the Laplacian to use.
+/// * `edge_weight`: closure that returns the weight of an edge, which should
+///   be non-negative.
+/// * `config`: the parameters of the iteration.
+///
+/// # Returns
+/// * The two parts, as lists of nodes.
+///
+/// # Complexity
+/// * Time complexity: that of [`fiedler_vector`], and **O(|V| log |V|)**.
+/// * Auxiliary space: that of [`fiedler_vector`].
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::spectral::{spectral_bisection, LanczosConfig, LaplacianKind};
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // Two squares with diagonals, joined by a single edge.
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (1, 3),
+///     (4, 5), (5, 6), (6, 7), (7, 4), (4, 6), (5, 7),
+///     (3, 4),
+/// ]);
+/// let (mut a, mut b) = spectral_bisection(
+///     &graph,
+///     LaplacianKind::Combinatorial,
+///     |_| 1.0,
+///     &LanczosConfig::default(),
+/// );
+/// a.sort();
+/// b.sort();
+/// let (first, second): (Vec<_>, Vec<_>) = (0..4).map(|i| (NodeIndex::new(i), NodeIndex::new(i + 4))).unzip();
+/// assert!((a == first && b == second) || (a == second && b == first));
+/// ```
+pub fn spectral_bisection<G, F>(
+    graph: G,
+    kind: LaplacianKind,
+    edge_weight: F,
+    config: &LanczosConfig,
+) -> (Vec<G::NodeId>, Vec<G::NodeId>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeCompactIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let mut nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let (laplacian, degrees) = laplacian_and_degrees(graph, kind, edge_weight);
+    let mut key = match fiedler(&laplacian, &degrees, kind, config) {
+        Some(fiedler) => fiedler.vector,
+        None => return (Vec::new(), nodes),
+    };
+    if kind == LaplacianKind::Normalized {
+        for (k, &degree) in key.iter_mut().zip(&degrees) {
+            if degree > 0.0 {
+                *k /= degree.sqrt();
+            }
+        }
+    }
+    nodes.sort_by(|&a, &b| {
+        let (a, b) = (graph.to_index(a), graph.to_index(b));
+        key[a]
+            .partial_cmp(&key[b])
+            .unwrap_or(core::cmp::Ordering::Equal)
+            .then(a.cmp(&b))
+    });
+    let second = nodes.split_off(nodes.len() / 2);
+    (nodes, second)
+}
+
+/// \[Generic\] Return the [effective resistance][1] between two nodes of a
+/// graph, seen as an electrical network whose edges are resistors.
+///
+/// The weight of an edge is its conductance, the inverse of its
+/// resistance. The effective resistance is the potential difference
+/// between `source` and `target` when a unit current flows from one to the
+/// other, found by solving the Laplacian system **Lx = e_s - e_t** with the
+/// conjugate gradient method. Multiplied by twice the total weight of the
+/// edges, it is the *commute time*, the expected number of steps of a
+/// random walk from `source` to `target` and back.
+///
+/// Edge directions are ignored, parallel edges add up and loops are
+/// ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `source`, `target`: the two nodes.
+/// * `edge_weight`: closure that returns the weight of an edge, which should
+///   be positive.
+///
+/// # Returns
+/// * The effective resistance, `0` if `source == target` and infinite if
+///   the nodes are not connected.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| · (|V| + |E|))** in the worst case, and much
+///   less for well connected graphs.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Resistance_distance
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::spectral::effective_resistance;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// // A square: two paths of two unit resistors in parallel.
+/// let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0), (3, 0, 1.0)]);
+/// let resistance = effective_resistance(&graph, NodeIndex::new(0), NodeIndex::new(2), |e| *e.weight());
+/// assert!((resistance - 1.0).abs() < 1e-9);
+/// ```
+pub fn effective_resistance<G, F>(
+    graph: G,
+    source: G::NodeId,
+    target: G::NodeId,
+    edge_weight: F,
+) -> f64
+where
+    G: IntoEdgeReferences + NodeCompactIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (source, target) = (graph.to_index(source), graph.to_index(target));
+    if source == target {
+        return 0.0;
+    }
+    let laplacian = laplacian_matrix(graph, LaplacianKind::Combinatorial, edge_weight);
+    if component(&laplacian, source)[target] == NONE {
+        return f64::INFINITY;
+    }
+
+    // The right hand side is orthogonal to the kernel of the Laplacian on
+    // the component, so the conjugate gradient converges on it.
+    let n = laplacian.size();
+    let mut x = vec![0.0; n];
+    let mut r = vec![0.0; n];
+    r[source] = 1.0;
+    r[target] = -1.0;
+    let mut p = r.clone();
+    let mut rr = dot(&r, &r);
+    let threshold = RESISTANCE_TOLERANCE * RESISTANCE_TOLERANCE * rr;
+    for _ in 0..10 * n {
+        let lp = laplacian.mul_vec(&p);
+        let alpha = rr / dot(&p, &lp);
+        for i in 0..n {
+            x[i] += alpha * p[i];
+            r[i] -= alpha * lp[i];
+        }
+        let next = dot(&r, &r);
+        if next <= threshold {
+            break;
+        }
+        for i in 0..n {
+            p[i] = r[i] + next / rr * p[i];
+        }
+        rr = next;
+    }
+    x[source] - x[target]
+}
+
+/// \[Generic\] Return the matrix of the [effective resistances][1] between
+/// all pairs of nodes of a graph, seen as an electrical network whose edges
+/// are resistors, indexed by the node indices.
+///
+/// The weight of an edge is its conductance, the inverse of its
+/// resistance. For each connected component, one node is grounded and the
+/// rest of the Laplacian of the component is inverted, which gives the
+/// potentials of all the unit currents at once. See
+/// [`effective_resistance`] for a single pair in a large graph.
+///
+/// Edge directions are ignored, parallel edges add up and loops are
+/// ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `edge_weight`: closure that returns the weight of an edge, which should
+///   be positive.
+///
+/// # Returns
+/// * A symmetric matrix of size **|V|**, with a zero diagonal, and infinite
+///   resistances between nodes that are not connected.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|³ + |E| log |E|)**.
+/// * Auxiliary space: **O(|V|² + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Resistance_distance
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::spectral::resistance_matrix;
+/// use petgraph::graph::UnGraph;
+///
+/// // In a tree, the effective resistance is the length of the path.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (1, 3)]);
+/// let resistance = resistance_matrix(&graph, |_| 1.0);
+/// assert!((resistance[0][2] - 2.0).abs() < 1e-9);
+/// assert!((resistance[2][3] - 2.0).abs() < 1e-9);
+/// assert!((resistance[1][3] - 1.0).abs() < 1e-9);
+/// ```
+pub fn resistance_matrix<G, F>(graph: G, edge_weight: F) -> Vec<Vec<f64>>
+where
+    G: IntoEdgeReferences + NodeCompactIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let laplacian = laplacian_matrix(graph, LaplacianKind::Combinatorial, edge_weight);
+    let n = laplacian.size();
+    let mut resistance = vec![vec![f64::INFINITY; n]; n];
+    let mut done = vec![false; n];
+    for ground in 0..n {
+        if done[ground] {
+            continue;
+        }
+        // The nodes of the component, with their positions in the reduced
+        // Laplacian, which leaves out the grounded node.
+        let position = component(&laplacian, ground);
+        let mut members = vec![NONE; n];
+        let mut nodes = Vec::new();
+        for v in 0..n {
+            if position[v] != NONE {
+                done[v] = true;
+                if v != ground {
+                    members[v] = nodes.len();
+                    nodes.push(v);
+                }
+            }
+        }
+        let mut reduced = vec![vec![0.0; nodes.len()]; nodes.len()];
+        for (i, &v) in nodes.iter().enumerate() {
+            for (column, value) in laplacian.row(v) {
+                if members[column] != NONE {
+                    reduced[i][members[column]] = value;
+                }
+            }
+        }
+        let inverse = invert_positive_definite(reduced);
+        resistance[ground][ground] = 0.0;
+        for (i, &v) in nodes.iter().enumerate() {
+            resistance[v][ground] = inverse[i][i];
+            resistance[ground][v] = inverse[i][i];
+            for (j, &w) in nodes.iter().enumerate() {
+                resistance[v][w] = inverse[i][i] + inverse[j][j] - 2.0 * inverse[i][j];
+            }
+        }
+    }
+    resistance
+}
+
+/// The relative precision to which [`effective_resistance`] solves the
+/// Laplacian system.
+const RESISTANCE_TOLERANCE: f64 = 1e-12;
+
+/// The label of a node outside of a component.
+const NONE: usize = usize::MAX;
+
+/// Return, for each node, its position in a depth-first search of the
+/// connected component of `start` in the graph of the matrix, or [`NONE`]
+/// if it is not in the component.
+fn component(matrix: &SparseMatrix, start: usize) -> Vec<usize> {
+    let mut position = vec![NONE; matrix.size()];
+    let mut stack = vec![start];
+    let mut count = 0;
+    position[start] = 0;
+    while let Some(v) = stack.pop() {
+        for (w, value) in matrix.row(v) {
+            if value != 0.0 && position[w] == NONE {
+                count += 1;
+                position[w] = count;
+                stack.push(w);
+            }
+        }
+    }
+    position
+}
+
+/// Invert a symmetric positive definite matrix by Gauss–Jordan
+/// elimination, which needs no pivoting for such a matrix.
+fn invert_positive_definite(mut matrix: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let n = matrix.len();
+    let mut inverse: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    for k in 0..n {
+        let pivot = matrix[k][k];
+        for j in 0..n {
+            matrix[k][j] /= pivot;
+            inverse[k][j] /= pivot;
+        }
+        for i in 0..n {
+            let factor = matrix[i][k];
+            if i == k || factor == 0.0 {
+                continue;
+            }
+            for j in 0..n {
+                matrix[i][j] -= factor * matrix[k][j];
+                inverse[i][j] -= factor * inverse[k][j];
+            }
+        }
+    }
+    inverse
+}
+
+/// Run `dimension` steps of the Lanczos iteration from the unit vector `x`,
+/// orthogonal to the unit vector `null`, and return the smallest Ritz value
+/// and its Ritz vector.
+fn lanczos(
+    matrix: &SparseMatrix,
+    null: &[f64],
+    x: Vec<f64>,
+    dimension: usize,
+    iterations: &mut usize,
+) -> (f64, Vec<f64>) {
+    let mut basis: Vec<Vec<f64>> = vec![x];
+    let mut alpha = Vec::with_capacity(dimension);
+    let mut beta: Vec<f64> = Vec::with_capacity(dimension);
+    while alpha.len() < dimension {
+        let q = basis.last().unwrap();
+        let mut w = matrix.mul_vec(q);
+        *iterations += 1;
+        alpha.push(dot(q, &w));
+        // Full reorthogonalization, twice for numerical stability.
+        for _ in 0..2 {
+            orthogonalize(&mut w, null);
+            for q in &basis {
+                orthogonalize(&mut w, q);
+            }
+        }
+        let b = normalize(&mut w);
+        if b <= 1e-12 || alpha.len() == dimension {
+            break;
+        }
+        beta.push(b);
+        basis.push(w);
+    }
+
+    let (values, vectors) = tridiagonal_eigen(&alpha, &beta);
+    let smallest = (0..values.len())
+        .min_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap())
+        .unwrap();
+    let mut ritz = vec![0.0; matrix.size()];
+    for (q, row) in basis.iter().zip(&vectors) {
+        let coefficient = row[smallest];
+        for (r, &value) in ritz.iter_mut().zip(q) {
+            *r += coefficient * value;
+        }
+    }
+    orthogonalize(&mut ritz, null);
+    normalize(&mut ritz);
+    (values[smallest], ritz)
+}
+
+/// Compute the eigenvalues and eigenvectors of the symmetric tridiagonal
+/// matrix with diagonal `diagonal` and off-diagonal `off`, with the
+/// implicit QL algorithm. Return the eigenvalues and the matrix whose
+/// columns are the eigenvectors.
+fn tridiagonal_eigen(diagonal: &[f64], off: &[f64]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let n = diagonal.len();
+    let mut d = diagonal.to_vec();
+    let mut e = vec![0.0; n];
+    e[..n - 1].copy_from_slice(&off[..n - 1]);
+    let mut z = vec![vec![0.0; n]; n];
+    for (i, row) in z.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+
+    for l in 0..n {
+        for _ in 0..60 {
+            let mut m = l;
+            while m + 1 < n {
+                let scale = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * scale {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+            let mut r = g.hypot(1.0);
+            g = d[m] - d[l] + e[l] / (g + if g >= 0.0 { r } else { -r });
+            let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+            let mut underflow = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    d[i + 1] -= p;
+                    e[m] = 0.0;
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                for row in z.iter_mut() {
+                    let f = row[i + 1];
+                    row[i + 1] = s * row[i] + c * f;
+                    row[i] = c * row[i] - s * f;
+                }
+            }
+            if underflow {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.0;
+        }
+    }
+    (d, z)
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn norm(a: &[f64]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// Scale `a` to unit norm, unless it is zero, and return its norm.
+fn normalize(a: &mut [f64]) -> f64 {
+    let length = norm(a);
+    if length > 0.0 {
+        a.iter_mut().for_each(|x| *x /= length);
+    }
+    length
+}
+
+/// Remove from `a` its component along the unit vector `u`.
+fn orthogonalize(a: &mut [f64], u: &[f64]) {
+    let projection = dot(a, u);
+    for (x, &y) in a.iter_mut().zip(u) {
+        *x -= projection * y;
+    }
+}
@@ -0,0 +1,188 @@
+//! Tree decompositions of small width, from elimination orderings.
+
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Reverse;
+
+use hashbrown::HashSet;
+
+use super::covering::Adjacency;
+use crate::graph::{NodeIndex, UnGraph};
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// The heuristic used by [`tree_decomposition`] to choose the next node to
+/// eliminate.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EliminationHeuristic {
+    /// Eliminate a node of minimum degree. Fast, and good on most sparse
+    /// graphs.
+    MinDegree,
+    /// Eliminate a node whose elimination adds the fewest edges, breaking
+    /// ties by degree. Slower, and usually gives a smaller width.
+    MinFillIn,
+}
+
+/// A tree decomposition of a graph, computed by [`tree_decomposition`].
+///
+/// The nodes of `tree` are *bags* of nodes of the graph, such that every
+/// node and every edge of the graph is in some bag, and the bags that
+/// contain a given node form a connected subtree. The *width* is the size
+/// of the largest bag minus one, an upper bound on the treewidth of the
+/// graph.
+#[derive(Clone, Debug)]
+pub struct TreeDecomposition<N> {
+    /// The width of the decomposition.
+    pub width: usize,
+    /// The tree of bags. Each bag is sorted by the order of the nodes in
+    /// [`IntoNodeIdentifiers`].
+    pub tree: UnGraph<Vec<N>, ()>,
+    /// The order in which the nodes were eliminated.
+    pub elimination_order: Vec<N>,
+}
+
+/// \[Generic\] Compute a tree decomposition of a graph with an elimination
+/// ordering heuristic.
+///
+/// The nodes are eliminated one by one, in the order chosen by the
+/// `heuristic`: eliminating a node makes its remaining neighbors a clique
+/// and removes it, and the node and these neighbors form a bag of the
+/// decomposition. The bag of a node is attached to the bag of its neighbor
+/// that is eliminated first after it. Bags of different connected
+/// components are attached to each other, so that the result is always a
+/// tree, with one bag for each node of the graph.
+///
+/// Dynamic programming over the bags solves many NP-hard problems in time
+/// exponential only in the width, which is small for many sparse graphs:
+/// trees have width `1` and series-parallel graphs width `2`.
+///
+/// Edge directions, loops and parallel edges are ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `heuristic`: the heuristic that chooses the next node to eliminate.
+///
+/// # Returns
+/// * A [`TreeDecomposition`] of the graph, with its width. A graph without
+///   nodes has an empty tree and width `0`.
+///
+/// # Complexity
+/// * Time complexity: **O((|V| + F) Δ log |V|)** with
+///   [`EliminationHeuristic::MinDegree`] and **O((|V| + F) Δ³ log |V|)** with
+///   [`EliminationHeuristic::MinFillIn`].
+/// * Auxiliary space: **O(|V| + |E| + F)**.
+///
+/// where **|V|** is the number of nodes, **|E|** the number of edges, **F**
+/// the number of edges added by the eliminations and **Δ** the largest
+/// degree during the eliminations.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::treewidth::{tree_decomposition, EliminationHeuristic};
+/// use petgraph::graph::UnGraph;
+///
+/// // A cycle of 6 nodes has treewidth 2.
+/// let graph = UnGraph::<(), ()>::from_edges((0..6).map(|i| (i, (i + 1) % 6)));
+/// let decomposition = tree_decomposition(&graph, EliminationHeuristic::MinFillIn);
+/// assert_eq!(decomposition.width, 2);
+/// assert_eq!(decomposition.tree.node_count(), 6);
+/// assert_eq!(decomposition.tree.edge_count(), 5);
+/// assert!(decomposition.tree.node_weights().all(|bag| bag.len() <= 3));
+/// ```
+pub fn tree_decomposition<G>(
+    graph: G,
+    heuristic: EliminationHeuristic,
+) -> TreeDecomposition<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let adjacency = Adjacency::new(graph);
+    let n = adjacency.len();
+    let mut neighbors: Vec<HashSet<usize>> = adjacency
+        .neighbors
+        .iter()
+        .map(|list| list.iter().copied().collect())
+        .collect();
+
+    let score = |neighbors: &[HashSet<usize>], v: usize| match heuristic {
+        EliminationHeuristic::MinDegree => (neighbors[v].len(), 0),
+        EliminationHeuristic::MinFillIn => (fill_in(neighbors, v), neighbors[v].len()),
+    };
+    let mut current: Vec<_> = (0..n).map(|v| score(&neighbors, v)).collect();
+    let mut heap: BinaryHeap<_> = (0..n).map(|v| Reverse((current[v], v))).collect();
+    let mut eliminated = vec![false; n];
+    let mut position = vec![0; n];
+    let mut order = Vec::with_capacity(n);
+    let mut bags = Vec::with_capacity(n);
+
+    while let Some(Reverse((v_score, v))) = heap.pop() {
+        if eliminated[v] || v_score != current[v] {
+            continue;
+        }
+        eliminated[v] = true;
+        position[v] = order.len();
+        order.push(v);
+        let around: Vec<usize> = neighbors[v].drain().collect();
+        for (i, &a) in around.iter().enumerate() {
+            neighbors[a].remove(&v);
+            for &b in &around[i + 1..] {
+                if neighbors[a].insert(b) {
+                    neighbors[b].insert(a);
+                }
+            }
+        }
+        let mut bag = around.clone();
+        bag.push(v);
+        bag.sort_unstable();
+        bags.push(bag);
+
+        // Update the scores that may have changed.
+        let mut touched: HashSet<usize> = around.iter().copied().collect();
+        if heuristic == EliminationHeuristic::MinFillIn {
+            for &a in &around {
+                touched.extend(neighbors[a].iter().copied());
+            }
+        }
+        for w in touched {
+            let w_score = score(&neighbors, w);
+            if w_score != current[w] {
+                current[w] = w_score;
+                heap.push(Reverse((w_score, w)));
+            }
+        }
+    }
+
+    let mut tree = UnGraph::with_capacity(n, n.saturating_sub(1));
+    for bag in &bags {
+        tree.add_node(adjacency.to_nodes(bag.iter().copied()));
+    }
+    for (i, bag) in bags.iter().enumerate() {
+        // The neighbor eliminated first after the node, or the next bag for
+        // the last bag of a connected component.
+        let parent = bag
+            .iter()
+            .map(|&u| position[u])
+            .filter(|&p| p > i)
+            .min()
+            .or_else(|| (i + 1 < n).then_some(i + 1));
+        if let Some(parent) = parent {
+            tree.add_edge(NodeIndex::new(i), NodeIndex::new(parent), ());
+        }
+    }
+    TreeDecomposition {
+        width: bags.iter().map(|bag| bag.len() - 1).max().unwrap_or(0),
+        tree,
+        elimination_order: adjacency.to_nodes(order),
+    }
+}
+
+/// Return the number of edges that eliminating `v` would add.
+fn fill_in(neighbors: &[HashSet<usize>], v: usize) -> usize {
+    let around: Vec<_> = neighbors[v].iter().copied().collect();
+    let mut missing = 0;
+    for (i, &a) in around.iter().enumerate() {
+        missing += around[i + 1..]
+            .iter()
+            .filter(|&b| !neighbors[a].contains(b))
+            .count();
+    }
+    missing
+}
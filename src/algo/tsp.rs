@@ -0,0 +1,366 @@
+//! Heuristics for the [travelling salesman problem][1].
+//!
+//! All functions treat the graph as undirected: the cost of travelling
+//! between two nodes is the cost of the cheapest edge connecting them, in
+//! either direction. A tour visits every node exactly once and returns to
+//! its first node; it is reported as the list of nodes in visiting order,
+//! without repeating the first node at the end.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Travelling_salesman_problem
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use super::chinese_postman::{
+    eulerian_circuit, greedy_perfect_matching, min_weight_perfect_matching, EXACT_MATCHING_LIMIT,
+};
+use crate::algo::Measure;
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// Build a tour with the nearest neighbor heuristic.
+///
+/// Starting from `start`, the tour repeatedly moves to the closest node that
+/// has not been visited yet.
+///
+/// # Arguments
+/// * `graph`: an input graph, treated as undirected.
+/// * `start`: the first node of the tour.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * `Some((cost, tour))`: the cost of the tour, including the edge back to
+///   `start`, and the tour itself.
+/// * `None`: if the heuristic reaches a node that has no edge to any
+///   unvisited node, or the last node has no edge back to `start`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E|)**.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::tsp::nearest_neighbor_tour;
+///
+/// let mut graph = Graph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[
+///     (a, b, 1), (b, c, 1), (c, d, 1), (d, a, 1), (a, c, 5), (b, d, 5),
+/// ]);
+///
+/// let (cost, tour) = nearest_neighbor_tour(&graph, a, |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 4);
+/// assert_eq!(tour.len(), 4);
+/// ```
+pub fn nearest_neighbor_tour<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    edge_cost: F,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let costs = CostMatrix::new(graph, edge_cost);
+    let start = costs.position[graph.to_index(start)];
+    let tour = costs.nearest_neighbor(start)?;
+    costs.into_result(tour)
+}
+
+/// Improve a tour with the 2-opt local search.
+///
+/// Segments of the tour are repeatedly reversed as long as that makes the
+/// tour cheaper, until no such move remains. Only moves using existing edges
+/// are considered.
+///
+/// # Arguments
+/// * `graph`: an input graph, treated as undirected.
+/// * `tour`: an initial tour visiting every node of the graph exactly once.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * `Some((cost, tour))`: the cost of the improved tour and the tour itself,
+///   which starts with the same node as the given one.
+/// * `None`: if `tour` is not a valid tour of the graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|²)** per improving pass, plus **O(|E|)**.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::tsp::two_opt;
+///
+/// let mut graph = Graph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[
+///     (a, b, 1), (b, c, 1), (c, d, 1), (d, a, 1), (a, c, 5), (b, d, 5),
+/// ]);
+///
+/// // The crossing tour a, c, b, d costs 12.
+/// let (cost, _) = two_opt(&graph, &[a, c, b, d], |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 4);
+/// ```
+pub fn two_opt<G, F, K>(graph: G, tour: &[G::NodeId], edge_cost: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let costs = CostMatrix::new(graph, edge_cost);
+    let mut tour: Vec<usize> = tour
+        .iter()
+        .map(|&node| costs.position.get(graph.to_index(node)).copied())
+        .collect::<Option<_>>()?;
+    let mut seen = vec![false; costs.nodes.len()];
+    for &i in &tour {
+        if i == usize::MAX || seen[i] {
+            return None;
+        }
+        seen[i] = true;
+    }
+    if tour.len() != costs.nodes.len() {
+        return None;
+    }
+    costs.tour_cost(&tour)?;
+    costs.two_opt(&mut tour);
+    costs.into_result(tour)
+}
+
+/// Build a tour with the [Christofides algorithm][1].
+///
+/// A minimum spanning tree is computed and its odd-degree nodes are paired up
+/// by a minimum weight perfect matching. The union of both is Eulerian, and
+/// the tour is obtained by skipping repeated nodes of an Eulerian circuit.
+///
+/// The graph should be complete and its costs should satisfy the triangle
+/// inequality. On such *metric* instances, the tour costs at most 3/2 times
+/// the optimum. The matching is exact as long as the spanning tree has at
+/// most 20 nodes of odd degree; above that, a greedy matching is used, which
+/// keeps the result a valid tour but loses the approximation guarantee.
+///
+/// # Arguments
+/// * `graph`: a complete graph, treated as undirected.
+/// * `edge_cost`: closure that returns cost of a particular edge.
+///
+/// # Returns
+/// * `Some((cost, tour))`: the cost of the tour and the tour itself.
+/// * `None`: if the graph is empty or not complete.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E| + 2ᵏk)**.
+/// * Auxiliary space: **O(|V|² + 2ᵏ)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges
+/// and **k ≤ 20** is the number of odd-degree nodes in the spanning tree.
+///
+/// [1]: https://en.wikipedia.org/wiki/Christofides_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::tsp::christofides;
+///
+/// // Four points on a unit square, with diagonals of (rounded up) length 2.
+/// let mut graph = Graph::new_undirected();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[
+///     (a, b, 1), (b, c, 1), (c, d, 1), (d, a, 1), (a, c, 2), (b, d, 2),
+/// ]);
+///
+/// let (cost, tour) = christofides(&graph, |e| *e.weight()).unwrap();
+/// assert!(cost <= 6);
+/// assert_eq!(tour.len(), 4);
+/// ```
+pub fn christofides<G, F, K>(graph: G, edge_cost: F) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let costs = CostMatrix::new(graph, edge_cost);
+    let n = costs.nodes.len();
+    if n == 0 || costs.cost.iter().flatten().filter(|c| c.is_some()).count() < n * (n - 1) {
+        return None;
+    }
+    let cost = |i: usize, j: usize| costs.cost[i][j].unwrap();
+
+    // Prim's algorithm on the dense cost matrix.
+    let mut in_tree = vec![false; n];
+    let mut best: Vec<Option<(K, usize)>> = vec![None; n];
+    let mut tree_edges = Vec::with_capacity(n + n / 2);
+    in_tree[0] = true;
+    for (j, b) in best.iter_mut().enumerate().skip(1) {
+        *b = Some((cost(0, j), 0));
+    }
+    for _ in 1..n {
+        let (next, (_, parent)) = (0..n)
+            .filter(|&j| !in_tree[j])
+            .map(|j| (j, best[j].unwrap()))
+            .min_by(|a, b| (a.1).0.partial_cmp(&(b.1).0).unwrap_or(Ordering::Equal))
+            .unwrap();
+        in_tree[next] = true;
+        tree_edges.push((parent, next));
+        for j in (0..n).filter(|&j| !in_tree[j]) {
+            if cost(next, j) < best[j].unwrap().0 {
+                best[j] = Some((cost(next, j), next));
+            }
+        }
+    }
+
+    let mut degree = vec![0usize; n];
+    for &(a, b) in &tree_edges {
+        degree[a] += 1;
+        degree[b] += 1;
+    }
+    let odd: Vec<usize> = (0..n).filter(|&v| degree[v] % 2 == 1).collect();
+    let pairs = if odd.len() <= EXACT_MATCHING_LIMIT {
+        let dist: Vec<Vec<K>> = odd
+            .iter()
+            .map(|&a| {
+                odd.iter()
+                    .map(|&b| if a == b { K::default() } else { cost(a, b) })
+                    .collect()
+            })
+            .collect();
+        min_weight_perfect_matching(&dist)
+    } else {
+        greedy_perfect_matching(&odd, cost)
+    };
+    tree_edges.extend(pairs.into_iter().map(|(i, j)| (odd[i], odd[j])));
+
+    let mut seen = vec![false; n];
+    let tour: Vec<usize> = eulerian_circuit(n, &tree_edges, 0)
+        .into_iter()
+        .filter(|&v| !core::mem::replace(&mut seen[v], true))
+        .collect();
+    costs.into_result(tour)
+}
+
+/// Dense symmetric matrix of the cheapest edge cost between any two nodes.
+struct CostMatrix<N, K> {
+    nodes: Vec<N>,
+    /// Position of a node in `nodes`, indexed by `NodeIndexable::to_index`.
+    position: Vec<usize>,
+    cost: Vec<Vec<Option<K>>>,
+}
+
+impl<N: Copy, K: Measure + Copy> CostMatrix<N, K> {
+    fn new<G, F>(graph: G, mut edge_cost: F) -> Self
+    where
+        G: IntoEdgeReferences<NodeId = N> + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> K,
+    {
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        let mut position = vec![usize::MAX; graph.node_bound()];
+        for (i, &node) in nodes.iter().enumerate() {
+            position[graph.to_index(node)] = i;
+        }
+        let mut cost = vec![vec![None; nodes.len()]; nodes.len()];
+        for edge in graph.edge_references() {
+            let a = position[graph.to_index(edge.source())];
+            let b = position[graph.to_index(edge.target())];
+            if a == b {
+                continue;
+            }
+            let c = edge_cost(edge);
+            if cost[a][b].map_or(true, |old| c < old) {
+                cost[a][b] = Some(c);
+                cost[b][a] = Some(c);
+            }
+        }
+        CostMatrix {
+            nodes,
+            position,
+            cost,
+        }
+    }
+
+    fn nearest_neighbor(&self, start: usize) -> Option<Vec<usize>> {
+        let n = self.nodes.len();
+        let mut visited = vec![false; n];
+        let mut tour = Vec::with_capacity(n);
+        let mut current = start;
+        visited[start] = true;
+        tour.push(start);
+        for _ in 1..n {
+            let mut closest: Option<(K, usize)> = None;
+            for (j, c) in self.cost[current].iter().enumerate() {
+                if let Some(c) = *c {
+                    if !visited[j] && closest.map_or(true, |(best, _)| c < best) {
+                        closest = Some((c, j));
+                    }
+                }
+            }
+            let (_, next) = closest?;
+            visited[next] = true;
+            tour.push(next);
+            current = next;
+        }
+        Some(tour)
+    }
+
+    fn two_opt(&self, tour: &mut [usize]) {
+        let n = tour.len();
+        if n < 4 {
+            return;
+        }
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 0..n - 2 {
+                for j in i + 2..n {
+                    // Replace edges (a, b) and (c, d) by (a, c) and (b, d).
+                    let (a, b) = (tour[i], tour[i + 1]);
+                    let (c, d) = (tour[j], tour[(j + 1) % n]);
+                    if a == d {
+                        continue;
+                    }
+                    let costs = (
+                        self.cost[a][b],
+                        self.cost[c][d],
+                        self.cost[a][c],
+                        self.cost[b][d],
+                    );
+                    if let (Some(ab), Some(cd), Some(ac), Some(bd)) = costs {
+                        if ac + bd < ab + cd {
+                            tour[i + 1..=j].reverse();
+                            improved = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn tour_cost(&self, tour: &[usize]) -> Option<K> {
+        let mut total = K::default();
+        for (i, &a) in tour.iter().enumerate() {
+            let b = tour[(i + 1) % tour.len()];
+            if a != b {
+                total = total + self.cost[a][b]?;
+            }
+        }
+        Some(total)
+    }
+
+    fn into_result(self, tour: Vec<usize>) -> Option<(K, Vec<N>)> {
+        let total = self.tour_cost(&tour)?;
+        Some((total, tour.into_iter().map(|i| self.nodes[i]).collect()))
+    }
+}
@@ -0,0 +1,219 @@
+//! Approximate maximum flow on unit capacity networks.
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+use crate::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+/// A flow computed by [`approximate_unit_maximum_flow`], with an upper bound
+/// on the value of a maximum flow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApproximateFlow {
+    /// The value of the flow: the number of edge-disjoint paths from the
+    /// source to the destination.
+    pub value: usize,
+    /// An upper bound on the value of a maximum flow, given by a cut of the
+    /// residual network.
+    pub upper_bound: usize,
+    /// Whether each edge carries a unit of flow, indexed by the graph's edge
+    /// indices.
+    pub edge_flows: Vec<bool>,
+    /// The number of blocking flow phases run.
+    pub phases: usize,
+}
+
+impl ApproximateFlow {
+    /// Return `true` if the flow is known to be a maximum flow.
+    pub fn is_exact(&self) -> bool {
+        self.value == self.upper_bound
+    }
+}
+
+/// \[Generic\] Compute a flow of at least **(1 - ε)** times the maximum
+/// flow from `source` to `destination`, in a network where every edge has
+/// capacity one, or stop after a number of phases.
+///
+/// Runs [Dinic's algorithm][1]: each phase computes the distances from the
+/// source in the residual network and then a blocking flow along the
+/// shortest augmenting paths. On a unit capacity network a phase takes
+/// **O(|E|)** time, and the distance grows with each phase.
+///
+/// The edges between two consecutive layers of distances form a cut of the
+/// residual network, so the smallest of these cuts bounds the flow that is
+/// still missing. The search stops as soon as the flow reaches **(1 - ε)**
+/// times this bound, or after `max_phases` phases, and reports the flow and
+/// the bound. With an `epsilon` of `0.0` and no phase budget, the result is
+/// a maximum flow.
+///
+/// Edge weights are ignored, and each edge can carry a unit of flow from its
+/// source to its target. Loops never carry flow.
+///
+/// # Arguments
+/// * `network`: a directed graph.
+/// * `source`: the source of the flow.
+/// * `destination`: the destination of the flow.
+/// * `epsilon`: the tolerated relative gap between the flow and its upper
+///   bound, between `0.0` and `1.0`.
+/// * `max_phases`: the maximum number of blocking flow phases, or `None` for
+///   no limit.
+///
+/// # Returns
+/// * An [`ApproximateFlow`] with the value of the flow, an upper bound on
+///   the maximum flow and the flow of each edge. If `source` and
+///   `destination` are the same node, the flow is empty.
+///
+/// # Complexity
+/// * Time complexity: **O(k · (|V| + |E|))** for **k** phases, and at most
+///   **O(min(|V|^(2/3), |E|^(1/2)) · (|V| + |E|))** for a maximum flow on a
+///   graph without parallel edges.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Dinic%27s_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::approximate_unit_maximum_flow;
+/// use petgraph::graph::{DiGraph, NodeIndex};
+///
+/// // Three disjoint paths from 0 to 5, and a detour through 4.
+/// let graph = DiGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 5),
+///     (0, 2), (2, 5),
+///     (0, 3), (3, 4), (4, 5), (3, 5),
+/// ]);
+/// let (source, destination) = (NodeIndex::new(0), NodeIndex::new(5));
+///
+/// let flow = approximate_unit_maximum_flow(&graph, source, destination, 0.0, None);
+/// assert_eq!(flow.value, 3);
+/// assert!(flow.is_exact());
+///
+/// // A single phase already finds the three shortest paths.
+/// let flow = approximate_unit_maximum_flow(&graph, source, destination, 0.5, Some(1));
+/// assert_eq!(flow.phases, 1);
+/// assert_eq!(flow.value, 3);
+/// ```
+pub fn approximate_unit_maximum_flow<G>(
+    network: G,
+    source: G::NodeId,
+    destination: G::NodeId,
+    epsilon: f64,
+    max_phases: Option<usize>,
+) -> ApproximateFlow
+where
+    G: IntoEdgeReferences + NodeIndexable + EdgeIndexable,
+{
+    let n = network.node_bound();
+    let (s, t) = (
+        NodeIndexable::to_index(&network, source),
+        NodeIndexable::to_index(&network, destination),
+    );
+    // The arcs of the residual network at each node: an edge index, and
+    // whether the arc goes along the edge.
+    let mut endpoints = vec![(0, 0); network.edge_bound()];
+    let mut arcs: Vec<Vec<(usize, bool)>> = vec![Vec::new(); n];
+    for edge in network.edge_references() {
+        let e = EdgeIndexable::to_index(&network, edge.id());
+        let (a, b) = (
+            NodeIndexable::to_index(&network, edge.source()),
+            NodeIndexable::to_index(&network, edge.target()),
+        );
+        if a != b {
+            endpoints[e] = (a, b);
+            arcs[a].push((e, true));
+            arcs[b].push((e, false));
+        }
+    }
+
+    let mut flows = vec![false; network.edge_bound()];
+    let head = |(e, forward): (usize, bool)| {
+        if forward {
+            endpoints[e].1
+        } else {
+            endpoints[e].0
+        }
+    };
+    let usable = |flows: &[bool], (e, forward): (usize, bool)| flows[e] != forward;
+
+    let mut value = 0;
+    let mut phases = 0;
+    let mut level = vec![usize::MAX; n];
+    let mut next_arc = vec![0; n];
+    let mut queue = VecDeque::new();
+    let upper_bound = loop {
+        if s == t {
+            break 0;
+        }
+        level.iter_mut().for_each(|l| *l = usize::MAX);
+        level[s] = 0;
+        queue.push_back(s);
+        while let Some(u) = queue.pop_front() {
+            for &arc in &arcs[u] {
+                let v = head(arc);
+                if level[v] == usize::MAX && usable(&flows, arc) {
+                    level[v] = level[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+        if level[t] == usize::MAX {
+            break value;
+        }
+
+        // Count the residual arcs between consecutive layers before the
+        // destination: each layer is a cut.
+        let mut layers = vec![0; level[t]];
+        for u in (0..n).filter(|&u| level[u] < level[t]) {
+            for &arc in &arcs[u] {
+                if level[head(arc)] == level[u] + 1 && usable(&flows, arc) {
+                    layers[level[u]] += 1;
+                }
+            }
+        }
+        let bound = value + layers.iter().copied().min().unwrap_or(0);
+        if value as f64 >= (1.0 - epsilon) * bound as f64 || max_phases == Some(phases) {
+            break bound;
+        }
+
+        // Find a blocking flow with a depth-first search along the layers.
+        // Nodes that cannot reach the destination are cut off by resetting
+        // their level.
+        next_arc.iter_mut().for_each(|i| *i = 0);
+        let mut path: Vec<(usize, bool)> = Vec::new();
+        loop {
+            let u = path.last().map_or(s, |&arc| head(arc));
+            if u == t {
+                for (e, forward) in path.drain(..) {
+                    flows[e] = forward;
+                }
+                value += 1;
+                continue;
+            }
+            let mut advanced = false;
+            while next_arc[u] < arcs[u].len() {
+                let arc = arcs[u][next_arc[u]];
+                let v = head(arc);
+                if level[v] == level[u] + 1 && level[v] <= level[t] && usable(&flows, arc) {
+                    path.push(arc);
+                    advanced = true;
+                    break;
+                }
+                next_arc[u] += 1;
+            }
+            if !advanced {
+                level[u] = usize::MAX;
+                if path.pop().is_none() {
+                    break;
+                }
+            }
+        }
+        phases += 1;
+    };
+
+    ApproximateFlow {
+        value,
+        upper_bound,
+        edge_flows: flows,
+        phases,
+    }
+}
@@ -0,0 +1,441 @@
+//! Bipartite graphs, and their projections onto one side.
+//!
+//! A [`BipartiteGraph`] is an undirected [`Graph`] whose nodes are split into
+//! a left and a right side, with edges only between the sides: for example
+//! the people and the groups they belong to of an affiliation network. Its
+//! [`projection`](BipartiteGraph::projection) onto a side links the nodes of
+//! that side that share a neighbor on the other side, such as the people who
+//! belong to a common group, weighted by a [`ProjectionWeight`].
+//!
+//! [`Graph`]: crate::graph::Graph
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::{fmt, iter::Enumerate, ops::Deref, slice};
+
+use crate::graph::{DefaultIx, EdgeIndex, IndexType, NodeIndex, UnGraph};
+
+/// A side of a [`BipartiteGraph`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Side {
+    /// The left side.
+    Left,
+    /// The right side.
+    Right,
+}
+
+impl Side {
+    /// Return the other side.
+    pub fn opposite(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// An error while adding an edge to, or creating, a [`BipartiteGraph`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BipartiteError {
+    /// The endpoints of the edge are both on this side.
+    SameSide(Side),
+    /// One of the endpoints of the edge is not a node of the graph.
+    NodeOutBounds,
+    /// The graph has an odd cycle, so its nodes can't be split into two
+    /// sides.
+    NotBipartite,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BipartiteError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for BipartiteError {}
+
+impl fmt::Display for BipartiteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BipartiteError::SameSide(side) => {
+                write!(f, "The endpoints of the edge are both on the {side:?} side")
+            }
+            BipartiteError::NodeOutBounds => write!(f, "Node indices out of bounds"),
+            BipartiteError::NotBipartite => write!(f, "The graph is not bipartite"),
+        }
+    }
+}
+
+/// The weight of the edge between two nodes of a projection, which share
+/// the neighbors `w` on the other side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProjectionWeight {
+    /// The number of shared neighbors.
+    SharedNeighbors,
+    /// Newman's collaboration weight: the sum of `1 / (deg(w) - 1)` over the
+    /// shared neighbors, so that a group of many members links each pair of
+    /// them less strongly.
+    Newman,
+    /// The Jaccard index of the neighborhoods: the number of shared
+    /// neighbors divided by the number of neighbors of either node.
+    Jaccard,
+}
+
+/// An undirected graph whose nodes are on a left or a right side, with edges
+/// only between the sides.
+///
+/// `BipartiteGraph` wraps a [`Graph`] and dereferences to it, so that its
+/// read-only methods and the algorithms can use it directly, through
+/// [`.graph()`](BipartiteGraph::graph). The nodes and edges are only added
+/// and removed through the methods of `BipartiteGraph`, which keep track of
+/// the sides and reject the edges between two nodes of the same side.
+///
+/// Like a `Graph`, it allows parallel edges, and removing a node moves the
+/// last node into its index.
+///
+/// ```
+/// use petgraph::bipartite::{BipartiteError, BipartiteGraph, ProjectionWeight, Side};
+///
+/// let mut graph = BipartiteGraph::<&str, ()>::new();
+/// let alice = graph.add_left("alice");
+/// let bob = graph.add_left("bob");
+/// let carol = graph.add_left("carol");
+/// let chess = graph.add_right("chess club");
+/// let choir = graph.add_right("choir");
+/// graph.add_edge(alice, chess, ()).unwrap();
+/// graph.add_edge(bob, chess, ()).unwrap();
+/// graph.add_edge(bob, choir, ()).unwrap();
+/// graph.add_edge(carol, choir, ()).unwrap();
+/// assert_eq!(graph.add_edge(alice, bob, ()), Err(BipartiteError::SameSide(Side::Left)));
+///
+/// // People are linked through the groups they share.
+/// let people = graph.projection(Side::Left, ProjectionWeight::SharedNeighbors);
+/// assert_eq!(people.edge_count(), 2);
+/// let links: Vec<_> = people
+///     .raw_edges()
+///     .iter()
+///     .map(|e| (graph[people[e.source()]], graph[people[e.target()]], e.weight))
+///     .collect();
+/// assert_eq!(links, [("alice", "bob", 1.), ("bob", "carol", 1.)]);
+/// ```
+///
+/// [`Graph`]: crate::graph::Graph
+pub struct BipartiteGraph<N, E, Ix = DefaultIx> {
+    graph: UnGraph<N, E, Ix>,
+    sides: Vec<Side>,
+}
+
+impl<N: Clone, E: Clone, Ix: IndexType> Clone for BipartiteGraph<N, E, Ix> {
+    fn clone(&self) -> Self {
+        BipartiteGraph {
+            graph: self.graph.clone(),
+            sides: self.sides.clone(),
+        }
+    }
+}
+
+impl<N: fmt::Debug, E: fmt::Debug, Ix: IndexType> fmt::Debug for BipartiteGraph<N, E, Ix> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BipartiteGraph")
+            .field("graph", &self.graph)
+            .field("sides", &self.sides)
+            .finish()
+    }
+}
+
+impl<N, E, Ix: IndexType> Default for BipartiteGraph<N, E, Ix> {
+    fn default() -> Self {
+        BipartiteGraph::with_capacity(0, 0)
+    }
+}
+
+impl<N, E> BipartiteGraph<N, E> {
+    /// Create a new, empty `BipartiteGraph`.
+    pub fn new() -> Self {
+        BipartiteGraph::default()
+    }
+}
+
+impl<N, E, Ix: IndexType> Deref for BipartiteGraph<N, E, Ix> {
+    type Target = UnGraph<N, E, Ix>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.graph
+    }
+}
+
+impl<N, E, Ix: IndexType> BipartiteGraph<N, E, Ix> {
+    /// Create a new, empty `BipartiteGraph` with estimated capacity.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        BipartiteGraph {
+            graph: UnGraph::with_capacity(nodes, edges),
+            sides: Vec::with_capacity(nodes),
+        }
+    }
+
+    /// Create a `BipartiteGraph` from an undirected graph and the side of
+    /// each of its nodes, indexed by node index.
+    ///
+    /// Return [`BipartiteError::SameSide`] if an edge joins two nodes of the
+    /// same side.
+    ///
+    /// **Panics** if the number of sides is not the number of nodes.
+    #[track_caller]
+    pub fn from_graph_with_sides(
+        graph: UnGraph<N, E, Ix>,
+        sides: Vec<Side>,
+    ) -> Result<Self, BipartiteError> {
+        assert_eq!(
+            sides.len(),
+            graph.node_count(),
+            "BipartiteGraph::from_graph_with_sides: one side per node"
+        );
+        for edge in graph.raw_edges() {
+            let side = sides[edge.source().index()];
+            if side == sides[edge.target().index()] {
+                return Err(BipartiteError::SameSide(side));
+            }
+        }
+        Ok(BipartiteGraph { graph, sides })
+    }
+
+    /// Create a `BipartiteGraph` from an undirected graph, finding the sides
+    /// of its nodes: in each connected component, the node of the smallest
+    /// index is on the left side.
+    ///
+    /// Return [`BipartiteError::NotBipartite`] if the graph has an odd cycle,
+    /// including a self loop.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn from_graph(graph: UnGraph<N, E, Ix>) -> Result<Self, BipartiteError> {
+        let mut sides: Vec<Option<Side>> = vec![None; graph.node_count()];
+        let mut queue = VecDeque::new();
+        for start in graph.node_indices() {
+            if sides[start.index()].is_some() {
+                continue;
+            }
+            sides[start.index()] = Some(Side::Left);
+            queue.push_back(start);
+            while let Some(a) = queue.pop_front() {
+                let side = sides[a.index()].map(Side::opposite);
+                for b in graph.neighbors(a) {
+                    match sides[b.index()] {
+                        None => {
+                            sides[b.index()] = side;
+                            queue.push_back(b);
+                        }
+                        Some(other) if Some(other) != side => {
+                            return Err(BipartiteError::NotBipartite)
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        let sides = sides.into_iter().map(|side| side.unwrap()).collect();
+        Ok(BipartiteGraph { graph, sides })
+    }
+
+    /// Return the underlying undirected graph.
+    pub fn graph(&self) -> &UnGraph<N, E, Ix> {
+        &self.graph
+    }
+
+    /// Return the underlying undirected graph, and the side of each node,
+    /// indexed by node index.
+    pub fn into_parts(self) -> (UnGraph<N, E, Ix>, Vec<Side>) {
+        (self.graph, self.sides)
+    }
+
+    /// Add a node on the given side, and return its index.
+    ///
+    /// **Panics** if the graph is at the maximum number of nodes for its
+    /// index type.
+    #[track_caller]
+    pub fn add_node(&mut self, side: Side, weight: N) -> NodeIndex<Ix> {
+        let a = self.graph.add_node(weight);
+        self.sides.push(side);
+        a
+    }
+
+    /// Add a node on the left side, and return its index.
+    #[track_caller]
+    pub fn add_left(&mut self, weight: N) -> NodeIndex<Ix> {
+        self.add_node(Side::Left, weight)
+    }
+
+    /// Add a node on the right side, and return its index.
+    #[track_caller]
+    pub fn add_right(&mut self, weight: N) -> NodeIndex<Ix> {
+        self.add_node(Side::Right, weight)
+    }
+
+    /// Add an edge between `a` and `b`, which must be on different sides,
+    /// and return its index.
+    ///
+    /// Possible errors:
+    /// - [`BipartiteError::NodeOutBounds`] if any of the nodes don't exist.
+    /// - [`BipartiteError::SameSide`] if the nodes are on the same side.
+    ///
+    /// **Panics** if the graph is at the maximum number of edges for its
+    /// index type.
+    #[track_caller]
+    pub fn add_edge(
+        &mut self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+        weight: E,
+    ) -> Result<EdgeIndex<Ix>, BipartiteError> {
+        match (self.side(a), self.side(b)) {
+            (Some(x), Some(y)) if x == y => Err(BipartiteError::SameSide(x)),
+            (Some(_), Some(_)) => Ok(self.graph.add_edge(a, b, weight)),
+            _ => Err(BipartiteError::NodeOutBounds),
+        }
+    }
+
+    /// Remove `a` from the graph if it exists, and return its weight.
+    ///
+    /// As with [`Graph::remove_node`](crate::graph::Graph::remove_node), the
+    /// last node takes the index of the removed node, with its side.
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> Option<N> {
+        let weight = self.graph.remove_node(a)?;
+        self.sides.swap_remove(a.index());
+        Some(weight)
+    }
+
+    /// Remove an edge and return its edge weight, or `None` if it didn't
+    /// exist.
+    pub fn remove_edge(&mut self, e: EdgeIndex<Ix>) -> Option<E> {
+        self.graph.remove_edge(e)
+    }
+
+    /// Access the weight for node `a`, mutably.
+    pub fn node_weight_mut(&mut self, a: NodeIndex<Ix>) -> Option<&mut N> {
+        self.graph.node_weight_mut(a)
+    }
+
+    /// Access the weight for edge `e`, mutably.
+    pub fn edge_weight_mut(&mut self, e: EdgeIndex<Ix>) -> Option<&mut E> {
+        self.graph.edge_weight_mut(e)
+    }
+
+    /// Return the side of `a`, or `None` if it doesn't exist.
+    pub fn side(&self, a: NodeIndex<Ix>) -> Option<Side> {
+        self.sides.get(a.index()).copied()
+    }
+
+    /// Return an iterator over the nodes of a side, in the order of their
+    /// indices.
+    pub fn nodes_on(&self, side: Side) -> SideNodes<'_, Ix> {
+        SideNodes {
+            side,
+            iter: self.sides.iter().enumerate(),
+            ix: core::marker::PhantomData,
+        }
+    }
+
+    /// Return an iterator over the nodes of the left side.
+    pub fn left_nodes(&self) -> SideNodes<'_, Ix> {
+        self.nodes_on(Side::Left)
+    }
+
+    /// Return an iterator over the nodes of the right side.
+    pub fn right_nodes(&self) -> SideNodes<'_, Ix> {
+        self.nodes_on(Side::Right)
+    }
+
+    /// Return the number of nodes of a side.
+    pub fn count_on(&self, side: Side) -> usize {
+        self.sides.iter().filter(|&&s| s == side).count()
+    }
+
+    /// Project the graph onto a side: return the graph of the nodes of
+    /// `side`, with an edge between each pair of them that share a neighbor
+    /// on the other side, weighted by `weight`.
+    ///
+    /// The nodes of the projection are in the order of their indices in
+    /// this graph, which are their weights. The parallel edges of this graph
+    /// count once.
+    ///
+    /// Computes in **O(|V| + Σ d(w)²)** time, where the sum is over the
+    /// nodes **w** of the other side, of degree **d(w)**.
+    pub fn projection(
+        &self,
+        side: Side,
+        weight: ProjectionWeight,
+    ) -> UnGraph<NodeIndex<Ix>, f64, Ix> {
+        // The distinct neighbors of every node.
+        let neighbors: Vec<Vec<usize>> = self
+            .graph
+            .node_indices()
+            .map(|a| {
+                let mut list: Vec<_> = self.graph.neighbors(a).map(|b| b.index()).collect();
+                list.sort_unstable();
+                list.dedup();
+                list
+            })
+            .collect();
+
+        let mut projection = UnGraph::with_capacity(self.count_on(side), 0);
+        let mut index = vec![NodeIndex::end(); self.sides.len()];
+        for a in self.nodes_on(side) {
+            index[a.index()] = projection.add_node(a);
+        }
+
+        let mut shared = vec![0.; self.sides.len()];
+        let mut touched = Vec::new();
+        for a in self.nodes_on(side) {
+            for &w in &neighbors[a.index()] {
+                let degree = neighbors[w].len();
+                for &b in &neighbors[w] {
+                    if b <= a.index() {
+                        continue;
+                    }
+                    if shared[b] == 0. {
+                        touched.push(b);
+                    }
+                    shared[b] += match weight {
+                        ProjectionWeight::Newman => 1. / (degree - 1) as f64,
+                        _ => 1.,
+                    };
+                }
+            }
+            touched.sort_unstable();
+            for b in touched.drain(..) {
+                let count = core::mem::replace(&mut shared[b], 0.);
+                let value = match weight {
+                    ProjectionWeight::Jaccard => {
+                        let union = neighbors[a.index()].len() + neighbors[b].len();
+                        count / (union as f64 - count)
+                    }
+                    _ => count,
+                };
+                projection.add_edge(index[a.index()], index[b], value);
+            }
+        }
+        projection
+    }
+}
+
+/// Iterator over the nodes of a side of a [`BipartiteGraph`].
+///
+/// Created with [`BipartiteGraph::left_nodes`],
+/// [`BipartiteGraph::right_nodes`] and [`BipartiteGraph::nodes_on`].
+#[derive(Clone, Debug)]
+pub struct SideNodes<'a, Ix> {
+    side: Side,
+    iter: Enumerate<slice::Iter<'a, Side>>,
+    ix: core::marker::PhantomData<Ix>,
+}
+
+impl<Ix: IndexType> Iterator for SideNodes<'_, Ix> {
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<NodeIndex<Ix>> {
+        let side = self.side;
+        self.iter
+            .find(|&(_, &s)| s == side)
+            .map(|(i, _)| NodeIndex::new(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
@@ -0,0 +1,218 @@
+//! Memoized algorithm results for graphs that are read much more often than
+//! they change.
+//!
+//! A [`Versioned`] graph counts its mutations: every mutable access to the
+//! wrapped graph bumps its [version](Versioned::version). A [`ResultCache`]
+//! remembers the results of algorithms, such as strongly connected
+//! components or a topological order, together with the version of the
+//! graph they were computed on, and recomputes them only once the graph has
+//! changed.
+//!
+//! # Example
+//! ```rust
+//! use petgraph::algo::{kosaraju_scc, toposort};
+//! use petgraph::cache::{ResultCache, Versioned};
+//! use petgraph::graph::{DiGraph, NodeIndex};
+//!
+//! let mut graph = Versioned::new(DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]));
+//! let mut cache = ResultCache::new(8);
+//!
+//! let order = cache.get_or_compute(&graph, "toposort", |g| toposort(g, None));
+//! assert!(order.is_ok());
+//! // The result is reused until the graph changes.
+//! let mut computed = false;
+//! cache.get_or_compute(&graph, "toposort", |g| {
+//!     computed = true;
+//!     toposort(g, None)
+//! });
+//! assert!(!computed);
+//!
+//! graph.add_edge(NodeIndex::new(2), NodeIndex::new(0), ());
+//! let order = cache.get_or_compute(&graph, "toposort", |g| toposort(g, None));
+//! assert!(order.is_err());
+//! let sccs = cache.get_or_compute(&graph, "scc", |g| kosaraju_scc(g));
+//! assert_eq!(sccs.len(), 1);
+//! ```
+
+use alloc::{boxed::Box, vec::Vec};
+use core::any::Any;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+/// A graph, or any other value, together with a counter of its mutations.
+///
+/// The wrapped graph can be read through [`Deref`], and every mutable
+/// access, through [`DerefMut`] or [`get_mut`](Versioned::get_mut), bumps
+/// the version, whether or not the graph is actually changed.
+#[derive(Clone, Debug, Default)]
+pub struct Versioned<G> {
+    graph: G,
+    version: u64,
+}
+
+impl<G> Versioned<G> {
+    /// Wrap `graph`, at version `0`.
+    pub fn new(graph: G) -> Self {
+        Versioned { graph, version: 0 }
+    }
+
+    /// Return the number of mutable accesses to the graph so far.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Return the graph, without changing the version.
+    pub fn get(&self) -> &G {
+        &self.graph
+    }
+
+    /// Return the graph for a mutation, and bump the version.
+    pub fn get_mut(&mut self) -> &mut G {
+        self.version = self.version.wrapping_add(1);
+        &mut self.graph
+    }
+
+    /// Unwrap the graph.
+    pub fn into_inner(self) -> G {
+        self.graph
+    }
+}
+
+impl<G> From<G> for Versioned<G> {
+    fn from(graph: G) -> Self {
+        Versioned::new(graph)
+    }
+}
+
+impl<G> Deref for Versioned<G> {
+    type Target = G;
+
+    fn deref(&self) -> &G {
+        &self.graph
+    }
+}
+
+impl<G> DerefMut for Versioned<G> {
+    fn deref_mut(&mut self) -> &mut G {
+        self.get_mut()
+    }
+}
+
+struct CacheEntry<K> {
+    key: K,
+    version: u64,
+    value: Box<dyn Any>,
+}
+
+/// A small least recently used cache of the results of algorithms on a
+/// [`Versioned`] graph.
+///
+/// Each result is stored under a key chosen by the caller, such as a name
+/// or an enum of the algorithms, with the version of the graph it was
+/// computed on. A result is only returned for the same key and version,
+/// and for the same result type; otherwise it is computed again. When the
+/// cache is full, the least recently used result is dropped.
+///
+/// A cache should be used with a single graph, since the versions of
+/// different graphs can coincide.
+pub struct ResultCache<K> {
+    capacity: usize,
+    // From the least recently used to the most recently used.
+    entries: Vec<CacheEntry<K>>,
+}
+
+impl<K: Eq> ResultCache<K> {
+    /// Create an empty cache that holds at most `capacity` results.
+    ///
+    /// **Panics** if `capacity` is `0`.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "ResultCache: the capacity must be positive");
+        ResultCache {
+            capacity,
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Return the result stored under `key` for the current version of
+    /// `graph`, or compute it with `compute` and store it.
+    ///
+    /// Besides `compute`, this takes **O(capacity)** time.
+    pub fn get_or_compute<G, T, F>(&mut self, graph: &Versioned<G>, key: K, compute: F) -> &T
+    where
+        T: Any,
+        F: FnOnce(&G) -> T,
+    {
+        let version = graph.version();
+        let position = self.entries.iter().position(|entry| entry.key == key);
+        let entry = match position.map(|i| self.entries.remove(i)) {
+            Some(entry) if entry.version == version && entry.value.is::<T>() => entry,
+            _ => {
+                if self.entries.len() == self.capacity {
+                    self.entries.remove(0);
+                }
+                CacheEntry {
+                    key,
+                    version,
+                    value: Box::new(compute(graph.get())),
+                }
+            }
+        };
+        self.entries.push(entry);
+        self.entries
+            .last()
+            .and_then(|entry| entry.value.downcast_ref())
+            .unwrap()
+    }
+
+    /// Return the result stored under `key` if it was computed on the
+    /// current version of `graph` and has type `T`, without marking it as
+    /// used.
+    pub fn get<G, T: Any>(&self, graph: &Versioned<G>, key: &K) -> Option<&T> {
+        self.entries
+            .iter()
+            .find(|entry| entry.key == *key && entry.version == graph.version())
+            .and_then(|entry| entry.value.downcast_ref())
+    }
+
+    /// Drop the results that were computed on an older version of `graph`.
+    pub fn remove_stale<G>(&mut self, graph: &Versioned<G>) {
+        self.entries
+            .retain(|entry| entry.version == graph.version());
+    }
+
+    /// Drop all results.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Return the number of stored results.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Return `true` if no result is stored.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the maximum number of stored results.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl<K: fmt::Debug> fmt::Debug for ResultCache<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResultCache")
+            .field("capacity", &self.capacity)
+            .field(
+                "entries",
+                &self
+                    .entries
+                    .iter()
+                    .map(|entry| (&entry.key, entry.version))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,984 @@
+//! `ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>` is a graph datastructure with a
+//! capacity fixed at compile time, whose storage never allocates.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut, Range};
+
+use crate::graph::{DefaultIx, EdgeIndex, GraphError, IndexType, NodeIndex};
+use crate::visit::{
+    Data, EdgeCount, EdgeIndexable, EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+    IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeIndexable, Visitable,
+};
+use crate::{Directed, Direction, EdgeType, Incoming, Outgoing, Undirected};
+
+#[derive(Clone)]
+struct Node<N, Ix> {
+    weight: N,
+    /// Next edge in outgoing and incoming edge lists.
+    next: [EdgeIndex<Ix>; 2],
+}
+
+#[derive(Clone)]
+struct Edge<E, Ix> {
+    weight: E,
+    /// Next edge in outgoing and incoming edge lists.
+    next: [EdgeIndex<Ix>; 2],
+    /// Start and end node index.
+    node: [NodeIndex<Ix>; 2],
+}
+
+/// `ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>` is a graph datastructure with
+/// room for at most `MAX_N` nodes and `MAX_E` edges, stored inline.
+///
+/// It has the same adjacency list representation as [`Graph`](crate::Graph),
+/// but its nodes and edges are kept in arrays sized at compile time, so it
+/// never allocates and can be placed in a `static` or on the stack of a
+/// target without a heap. Nodes and edges can be added, but not removed;
+/// [`clear`](ConstGraph::clear) empties the graph.
+///
+/// `ConstGraph` implements the core visit traits, so the traversals and
+/// algorithms of this crate that only need those traits work on it. Its visit
+/// map is a `[bool; MAX_N]` array, but the traversals and algorithms
+/// themselves still allocate their queues, stacks and results, like
+/// [`Bfs`](crate::visit::Bfs), [`Dfs`](crate::visit::Dfs) and
+/// [`dijkstra`](crate::algo::dijkstra) do; they need a heap.
+///
+/// - `N`, `E`: the node and edge weights.
+/// - `MAX_N`, `MAX_E`: the node and edge capacities.
+/// - `Ty`: [`Directed`] or [`Undirected`].
+/// - `Ix`: the index type, which must be able to represent `MAX_N` and
+///   `MAX_E`. A small one such as `u8` or `u16` saves memory.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::dijkstra;
+/// use petgraph::const_graph::ConstGraph;
+/// use petgraph::visit::{Bfs, EdgeRef};
+///
+/// let mut graph = ConstGraph::<&str, u32, 4, 8>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// graph.add_edge(a, b, 1);
+/// graph.add_edge(b, c, 2);
+/// graph.add_edge(a, c, 5);
+///
+/// let mut bfs = Bfs::new(&graph, a);
+/// let mut order = Vec::new();
+/// while let Some(node) = bfs.next(&graph) {
+///     order.push(graph[node]);
+/// }
+/// assert_eq!(order, ["a", "c", "b"]);
+///
+/// let distances = dijkstra(&graph, a, None, |e| *e.weight());
+/// assert_eq!(distances[&c], 3);
+///
+/// // The capacity is fixed.
+/// graph.add_node("d");
+/// assert!(graph.try_add_node("e").is_err());
+/// ```
+pub struct ConstGraph<N, E, const MAX_N: usize, const MAX_E: usize, Ty = Directed, Ix = DefaultIx> {
+    nodes: [Option<Node<N, Ix>>; MAX_N],
+    edges: [Option<Edge<E, Ix>>; MAX_E],
+    node_count: usize,
+    edge_count: usize,
+    ty: PhantomData<Ty>,
+}
+
+/// A `ConstGraph` with directed edges.
+pub type DiConstGraph<N, E, const MAX_N: usize, const MAX_E: usize, Ix = DefaultIx> =
+    ConstGraph<N, E, MAX_N, MAX_E, Directed, Ix>;
+
+/// A `ConstGraph` with undirected edges.
+pub type UnConstGraph<N, E, const MAX_N: usize, const MAX_E: usize, Ix = DefaultIx> =
+    ConstGraph<N, E, MAX_N, MAX_E, Undirected, Ix>;
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> Clone
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    N: Clone,
+    E: Clone,
+    Ix: IndexType,
+{
+    fn clone(&self) -> Self {
+        ConstGraph {
+            nodes: self.nodes.clone(),
+            edges: self.edges.clone(),
+            node_count: self.node_count,
+            edge_count: self.edge_count,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> fmt::Debug
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    N: fmt::Debug,
+    E: fmt::Debug,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arrow = if Ty::is_directed() { "->" } else { "-" };
+        let mut s = f.debug_struct("ConstGraph");
+        s.field(
+            "Ty",
+            &if Ty::is_directed() {
+                "Directed"
+            } else {
+                "Undirected"
+            },
+        );
+        s.field("node_count", &self.node_count);
+        s.field("edge_count", &self.edge_count);
+        s.field(
+            "nodes",
+            &DebugList(|f: &mut fmt::Formatter<'_>| {
+                f.debug_list().entries(self.node_weights()).finish()
+            }),
+        );
+        s.field(
+            "edges",
+            &DebugList(|f: &mut fmt::Formatter<'_>| {
+                f.debug_list()
+                    .entries(
+                        self.edge_references().map(|e| {
+                            DebugEdge(e.node[0].index(), arrow, e.node[1].index(), e.weight)
+                        }),
+                    )
+                    .finish()
+            }),
+        );
+        s.finish()
+    }
+}
+
+struct DebugList<F>(F);
+
+impl<F> fmt::Debug for DebugList<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+struct DebugEdge<'a, E>(usize, &'static str, usize, &'a E);
+
+impl<E: fmt::Debug> fmt::Debug for DebugEdge<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}: {:?}", self.0, self.1, self.2, self.3)
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ix>
+    ConstGraph<N, E, MAX_N, MAX_E, Directed, Ix>
+{
+    /// Create a new, empty `ConstGraph` with directed edges.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ix>
+    ConstGraph<N, E, MAX_N, MAX_E, Undirected, Ix>
+{
+    /// Create a new, empty `ConstGraph` with undirected edges.
+    pub fn new_undirected() -> Self {
+        Self::default()
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> Default
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+{
+    fn default() -> Self {
+        ConstGraph {
+            nodes: core::array::from_fn(|_| None),
+            edges: core::array::from_fn(|_| None),
+            node_count: 0,
+            edge_count: 0,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Return the maximum number of nodes, `MAX_N`.
+    pub fn node_capacity(&self) -> usize {
+        MAX_N
+    }
+
+    /// Return the maximum number of edges, `MAX_E`.
+    pub fn edge_capacity(&self) -> usize {
+        MAX_E
+    }
+
+    /// Whether the graph has directed edges or not.
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Add a node with weight `weight` and return its index.
+    ///
+    /// **Panics** if the graph already has `MAX_N` nodes, or if the index
+    /// type can't represent the new node.
+    #[track_caller]
+    pub fn add_node(&mut self, weight: N) -> NodeIndex<Ix> {
+        self.try_add_node(weight)
+            .expect("ConstGraph::add_node: node capacity exceeded")
+    }
+
+    /// Try to add a node with weight `weight` and return its index.
+    ///
+    /// Return [`GraphError::NodeIxLimit`] if the graph already has `MAX_N`
+    /// nodes, or if the index type can't represent the new node.
+    pub fn try_add_node(&mut self, weight: N) -> Result<NodeIndex<Ix>, GraphError> {
+        let i = self.node_count;
+        if i == MAX_N || <Ix as IndexType>::max().index() <= i {
+            return Err(GraphError::NodeIxLimit);
+        }
+        self.nodes[i] = Some(Node {
+            weight,
+            next: [EdgeIndex::end(); 2],
+        });
+        self.node_count += 1;
+        Ok(NodeIndex::new(i))
+    }
+
+    /// Add an edge from `a` to `b` with weight `weight` and return its index.
+    /// Parallel edges and loops are allowed.
+    ///
+    /// **Panics** if the graph already has `MAX_E` edges, if the index type
+    /// can't represent the new edge, or if `a` or `b` is not in the graph.
+    #[track_caller]
+    pub fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> EdgeIndex<Ix> {
+        match self.try_add_edge(a, b, weight) {
+            Ok(edge) => edge,
+            Err(GraphError::EdgeIxLimit) => {
+                panic!("ConstGraph::add_edge: edge capacity exceeded")
+            }
+            Err(_) => panic!("ConstGraph::add_edge: node indices out of bounds"),
+        }
+    }
+
+    /// Try to add an edge from `a` to `b` with weight `weight` and return its
+    /// index.
+    ///
+    /// Return [`GraphError::EdgeIxLimit`] if the graph already has `MAX_E`
+    /// edges or if the index type can't represent the new edge, and
+    /// [`GraphError::NodeOutBounds`] if `a` or `b` is not in the graph.
+    pub fn try_add_edge(
+        &mut self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+        weight: E,
+    ) -> Result<EdgeIndex<Ix>, GraphError> {
+        let i = self.edge_count;
+        if i == MAX_E || <Ix as IndexType>::max().index() <= i {
+            return Err(GraphError::EdgeIxLimit);
+        }
+        if a.index() >= self.node_count || b.index() >= self.node_count {
+            return Err(GraphError::NodeOutBounds);
+        }
+        let index = EdgeIndex::new(i);
+        let next_a = self.node_mut(a).next[0];
+        let next_b = {
+            let node = self.node_mut(b);
+            let next = node.next[1];
+            node.next[1] = index;
+            next
+        };
+        self.node_mut(a).next[0] = index;
+        self.edges[i] = Some(Edge {
+            weight,
+            next: [next_a, next_b],
+            node: [a, b],
+        });
+        self.edge_count += 1;
+        Ok(index)
+    }
+
+    /// Remove all nodes and edges.
+    pub fn clear(&mut self) {
+        for node in &mut self.nodes[..self.node_count] {
+            *node = None;
+        }
+        for edge in &mut self.edges[..self.edge_count] {
+            *edge = None;
+        }
+        self.node_count = 0;
+        self.edge_count = 0;
+    }
+
+    fn node(&self, a: NodeIndex<Ix>) -> Option<&Node<N, Ix>> {
+        self.nodes.get(a.index()).and_then(Option::as_ref)
+    }
+
+    fn node_mut(&mut self, a: NodeIndex<Ix>) -> &mut Node<N, Ix> {
+        self.nodes[a.index()].as_mut().unwrap()
+    }
+
+    /// Access the weight for node `a`.
+    ///
+    /// Return `None` if the node doesn't exist.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.node(a).map(|node| &node.weight)
+    }
+
+    /// Access the weight for node `a`, mutably.
+    ///
+    /// Return `None` if the node doesn't exist.
+    pub fn node_weight_mut(&mut self, a: NodeIndex<Ix>) -> Option<&mut N> {
+        self.nodes
+            .get_mut(a.index())
+            .and_then(Option::as_mut)
+            .map(|node| &mut node.weight)
+    }
+
+    /// Access the weight for edge `e`.
+    ///
+    /// Return `None` if the edge doesn't exist.
+    pub fn edge_weight(&self, e: EdgeIndex<Ix>) -> Option<&E> {
+        self.edges
+            .get(e.index())
+            .and_then(Option::as_ref)
+            .map(|edge| &edge.weight)
+    }
+
+    /// Access the weight for edge `e`, mutably.
+    ///
+    /// Return `None` if the edge doesn't exist.
+    pub fn edge_weight_mut(&mut self, e: EdgeIndex<Ix>) -> Option<&mut E> {
+        self.edges
+            .get_mut(e.index())
+            .and_then(Option::as_mut)
+            .map(|edge| &mut edge.weight)
+    }
+
+    /// Return the source and target of edge `e`, or `None` if it doesn't
+    /// exist.
+    pub fn edge_endpoints(&self, e: EdgeIndex<Ix>) -> Option<(NodeIndex<Ix>, NodeIndex<Ix>)> {
+        self.edges
+            .get(e.index())
+            .and_then(Option::as_ref)
+            .map(|edge| (edge.node[0], edge.node[1]))
+    }
+
+    /// Return an iterator over the node weights, in index order.
+    pub fn node_weights(&self) -> impl Iterator<Item = &N> {
+        self.nodes[..self.node_count]
+            .iter()
+            .flatten()
+            .map(|node| &node.weight)
+    }
+
+    /// Return an iterator over the node indices.
+    pub fn node_indices(&self) -> Indices<NodeIndex<Ix>> {
+        Indices::new(self.node_count)
+    }
+
+    /// Return an iterator over the edge indices.
+    pub fn edge_indices(&self) -> Indices<EdgeIndex<Ix>> {
+        Indices::new(self.edge_count)
+    }
+
+    /// Return an iterator over the neighbors of `a`: the targets of its
+    /// outgoing edges if the graph is directed, and all its neighbors
+    /// otherwise. A neighbor is produced once per edge.
+    pub fn neighbors(&self, a: NodeIndex<Ix>) -> Neighbors<'_, E, Ix> {
+        self.neighbors_directed(a, Outgoing)
+    }
+
+    /// Return an iterator over the neighbors of `a` in direction `dir`, or
+    /// all its neighbors if the graph is undirected.
+    pub fn neighbors_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Neighbors<'_, E, Ix> {
+        let mut next = self.node(a).map_or([EdgeIndex::end(); 2], |node| node.next);
+        let mut skip_start = a;
+        if Ty::is_directed() {
+            next[1 - dir.index()] = EdgeIndex::end();
+            skip_start = NodeIndex::end();
+        }
+        Neighbors {
+            skip_start,
+            edges: &self.edges[..self.edge_count],
+            next,
+        }
+    }
+
+    /// Return an iterator over the outgoing edges of `a`, or all its edges
+    /// if the graph is undirected.
+    pub fn edges(&self, a: NodeIndex<Ix>) -> Edges<'_, E, Ty, Ix> {
+        self.edges_directed(a, Outgoing)
+    }
+
+    /// Return an iterator over the edges of `a` in direction `dir`, or all
+    /// its edges if the graph is undirected. The edges are oriented so that
+    /// `a` is their source for `Outgoing` and their target for `Incoming`.
+    pub fn edges_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Edges<'_, E, Ty, Ix> {
+        Edges {
+            skip_start: a,
+            edges: &self.edges[..self.edge_count],
+            next: self.node(a).map_or([EdgeIndex::end(); 2], |node| node.next),
+            direction: dir,
+            ty: PhantomData,
+        }
+    }
+
+    /// Return an edge from `a` to `b` (or between them, if the graph is
+    /// undirected), or `None` if there is none.
+    pub fn find_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        self.edges(a).find(|e| e.target() == b).map(|e| e.id())
+    }
+
+    /// Return an iterator over all edges of the graph, in index order.
+    pub fn edge_references(&self) -> EdgeReferences<'_, E, Ix> {
+        EdgeReferences {
+            iter: self.edges[..self.edge_count].iter().enumerate(),
+        }
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> Index<NodeIndex<Ix>>
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = N;
+
+    /// **Panics** if the node doesn't exist.
+    fn index(&self, index: NodeIndex<Ix>) -> &N {
+        self.node_weight(index).unwrap()
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> IndexMut<NodeIndex<Ix>>
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// **Panics** if the node doesn't exist.
+    fn index_mut(&mut self, index: NodeIndex<Ix>) -> &mut N {
+        self.node_weight_mut(index).unwrap()
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> Index<EdgeIndex<Ix>>
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+
+    /// **Panics** if the edge doesn't exist.
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        self.edge_weight(index).unwrap()
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> IndexMut<EdgeIndex<Ix>>
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// **Panics** if the edge doesn't exist.
+    fn index_mut(&mut self, index: EdgeIndex<Ix>) -> &mut E {
+        self.edge_weight_mut(index).unwrap()
+    }
+}
+
+/// Iterator over the node or edge indices of a [`ConstGraph`].
+#[derive(Debug, Clone)]
+pub struct Indices<I> {
+    range: Range<usize>,
+    ty: PhantomData<fn() -> I>,
+}
+
+impl<I> Indices<I> {
+    fn new(n: usize) -> Self {
+        Indices {
+            range: 0..n,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<Ix: IndexType> Iterator for Indices<NodeIndex<Ix>> {
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(NodeIndex::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<Ix: IndexType> Iterator for Indices<EdgeIndex<Ix>> {
+    type Item = EdgeIndex<Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(EdgeIndex::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<Ix: IndexType> ExactSizeIterator for Indices<NodeIndex<Ix>> {}
+impl<Ix: IndexType> ExactSizeIterator for Indices<EdgeIndex<Ix>> {}
+impl<Ix: IndexType> FusedIterator for Indices<NodeIndex<Ix>> {}
+impl<Ix: IndexType> FusedIterator for Indices<EdgeIndex<Ix>> {}
+
+/// Iterator over the neighbors of a node in a [`ConstGraph`].
+#[derive(Debug)]
+pub struct Neighbors<'a, E, Ix> {
+    /// Starting node, to skip loops the second time they are seen.
+    skip_start: NodeIndex<Ix>,
+    edges: &'a [Option<Edge<E, Ix>>],
+    next: [EdgeIndex<Ix>; 2],
+}
+
+impl<E, Ix: IndexType> Clone for Neighbors<'_, E, Ix> {
+    fn clone(&self) -> Self {
+        Neighbors {
+            skip_start: self.skip_start,
+            edges: self.edges,
+            next: self.next,
+        }
+    }
+}
+
+fn get<E, Ix: IndexType>(edges: &[Option<Edge<E, Ix>>], e: EdgeIndex<Ix>) -> Option<&Edge<E, Ix>> {
+    edges.get(e.index()).and_then(Option::as_ref)
+}
+
+impl<E, Ix: IndexType> Iterator for Neighbors<'_, E, Ix> {
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<NodeIndex<Ix>> {
+        if let Some(edge) = get(self.edges, self.next[0]) {
+            self.next[0] = edge.next[0];
+            return Some(edge.node[1]);
+        }
+        while let Some(edge) = get(self.edges, self.next[1]) {
+            self.next[1] = edge.next[1];
+            if edge.node[0] != self.skip_start {
+                return Some(edge.node[0]);
+            }
+        }
+        None
+    }
+}
+
+impl<E, Ix: IndexType> FusedIterator for Neighbors<'_, E, Ix> {}
+
+/// Reference to a [`ConstGraph`] edge.
+#[derive(Debug)]
+pub struct EdgeReference<'a, E, Ix> {
+    index: EdgeIndex<Ix>,
+    node: [NodeIndex<Ix>; 2],
+    weight: &'a E,
+}
+
+impl<E, Ix: IndexType> Clone for EdgeReference<'_, E, Ix> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E, Ix: IndexType> Copy for EdgeReference<'_, E, Ix> {}
+
+impl<E, Ix: IndexType> PartialEq for EdgeReference<'_, E, Ix>
+where
+    E: PartialEq,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.index == rhs.index && self.weight == rhs.weight
+    }
+}
+
+impl<'a, E, Ix: IndexType> EdgeRef for EdgeReference<'a, E, Ix> {
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = EdgeIndex<Ix>;
+    type Weight = E;
+
+    fn source(&self) -> Self::NodeId {
+        self.node[0]
+    }
+    fn target(&self) -> Self::NodeId {
+        self.node[1]
+    }
+    fn weight(&self) -> &E {
+        self.weight
+    }
+    fn id(&self) -> Self::EdgeId {
+        self.index
+    }
+}
+
+/// Iterator over the edges of a node in a [`ConstGraph`].
+#[derive(Debug)]
+pub struct Edges<'a, E, Ty, Ix> {
+    /// Starting node, to skip loops the second time they are seen.
+    skip_start: NodeIndex<Ix>,
+    edges: &'a [Option<Edge<E, Ix>>],
+    next: [EdgeIndex<Ix>; 2],
+    direction: Direction,
+    ty: PhantomData<Ty>,
+}
+
+impl<E, Ty, Ix: IndexType> Clone for Edges<'_, E, Ty, Ix> {
+    fn clone(&self) -> Self {
+        Edges {
+            skip_start: self.skip_start,
+            edges: self.edges,
+            next: self.next,
+            direction: self.direction,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<'a, E, Ty, Ix> Iterator for Edges<'a, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Item = EdgeReference<'a, E, Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // A directed graph iterates over the list of `direction`, an
+        // undirected one over both lists, orienting edges away from the
+        // start for `Outgoing` and towards it for `Incoming`.
+        let both = !Ty::is_directed();
+        let flip = |node: [NodeIndex<Ix>; 2], list: Direction| {
+            if both && list != self.direction {
+                [node[1], node[0]]
+            } else {
+                node
+            }
+        };
+        if both || self.direction == Outgoing {
+            let index = self.next[0];
+            if let Some(edge) = get(self.edges, index) {
+                self.next[0] = edge.next[0];
+                return Some(EdgeReference {
+                    index,
+                    node: flip(edge.node, Outgoing),
+                    weight: &edge.weight,
+                });
+            }
+        }
+        if both || self.direction == Incoming {
+            while let Some(edge) = get(self.edges, self.next[1]) {
+                let index = self.next[1];
+                self.next[1] = edge.next[1];
+                if both && edge.node[0] == self.skip_start {
+                    continue;
+                }
+                return Some(EdgeReference {
+                    index,
+                    node: flip(edge.node, Incoming),
+                    weight: &edge.weight,
+                });
+            }
+        }
+        None
+    }
+}
+
+impl<E, Ty: EdgeType, Ix: IndexType> FusedIterator for Edges<'_, E, Ty, Ix> {}
+
+/// Iterator over all edges of a [`ConstGraph`].
+#[derive(Debug, Clone)]
+pub struct EdgeReferences<'a, E, Ix> {
+    iter: core::iter::Enumerate<core::slice::Iter<'a, Option<Edge<E, Ix>>>>,
+}
+
+impl<'a, E, Ix: IndexType> Iterator for EdgeReferences<'a, E, Ix> {
+    type Item = EdgeReference<'a, E, Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find_map(|(i, edge)| {
+            edge.as_ref().map(|edge| EdgeReference {
+                index: EdgeIndex::new(i),
+                node: edge.node,
+                weight: &edge.weight,
+            })
+        })
+    }
+}
+
+impl<E, Ix: IndexType> FusedIterator for EdgeReferences<'_, E, Ix> {}
+
+/// Iterator over the nodes of a [`ConstGraph`], with their weights.
+#[derive(Debug, Clone)]
+pub struct NodeReferences<'a, N, Ix> {
+    iter: core::iter::Enumerate<core::slice::Iter<'a, Option<Node<N, Ix>>>>,
+}
+
+impl<'a, N, Ix: IndexType> Iterator for NodeReferences<'a, N, Ix> {
+    type Item = (NodeIndex<Ix>, &'a N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .find_map(|(i, node)| node.as_ref().map(|node| (NodeIndex::new(i), &node.weight)))
+    }
+}
+
+impl<N, Ix: IndexType> FusedIterator for NodeReferences<'_, N, Ix> {}
+
+impl<N, Ix: fmt::Debug> fmt::Debug for Node<N, Ix>
+where
+    N: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.weight.fmt(f)
+    }
+}
+
+impl<E, Ix: fmt::Debug> fmt::Debug for Edge<E, Ix>
+where
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Edge")
+            .field(&self.node)
+            .field(&self.weight)
+            .finish()
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> GraphBase
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = EdgeIndex<Ix>;
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> GraphProp
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeType = Ty;
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> Data
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> crate::data::DataMap
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_weight(&self, id: Self::NodeId) -> Option<&N> {
+        self.node_weight(id)
+    }
+    fn edge_weight(&self, id: Self::EdgeId) -> Option<&E> {
+        self.edge_weight(id)
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> NodeCount
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> EdgeCount
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> NodeIndexable
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_bound(&self) -> usize {
+        self.node_count
+    }
+    fn to_index(&self, ix: NodeIndex<Ix>) -> usize {
+        ix.index()
+    }
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        NodeIndex::new(ix)
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> NodeCompactIndexable
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> EdgeIndexable
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn edge_bound(&self) -> usize {
+        self.edge_count
+    }
+    fn to_index(&self, ix: EdgeIndex<Ix>) -> usize {
+        ix.index()
+    }
+    fn from_index(&self, ix: usize) -> Self::EdgeId {
+        EdgeIndex::new(ix)
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> Visitable
+    for ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Map = [bool; MAX_N];
+    fn visit_map(&self) -> [bool; MAX_N] {
+        [false; MAX_N]
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        *map = [false; MAX_N];
+    }
+}
+
+impl<'a, N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> IntoNeighbors
+    for &'a ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Neighbors = Neighbors<'a, E, Ix>;
+    fn neighbors(self, a: NodeIndex<Ix>) -> Self::Neighbors {
+        ConstGraph::neighbors(self, a)
+    }
+}
+
+impl<'a, N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> IntoNeighborsDirected
+    for &'a ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NeighborsDirected = Neighbors<'a, E, Ix>;
+    fn neighbors_directed(self, a: NodeIndex<Ix>, dir: Direction) -> Self::NeighborsDirected {
+        ConstGraph::neighbors_directed(self, a, dir)
+    }
+}
+
+impl<'a, N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> IntoEdgeReferences
+    for &'a ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeRef = EdgeReference<'a, E, Ix>;
+    type EdgeReferences = EdgeReferences<'a, E, Ix>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        ConstGraph::edge_references(self)
+    }
+}
+
+impl<'a, N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> IntoEdges
+    for &'a ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Edges = Edges<'a, E, Ty, Ix>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        ConstGraph::edges(self, a)
+    }
+}
+
+impl<'a, N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> IntoEdgesDirected
+    for &'a ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgesDirected = Edges<'a, E, Ty, Ix>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        ConstGraph::edges_directed(self, a, dir)
+    }
+}
+
+impl<N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> IntoNodeIdentifiers
+    for &ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeIdentifiers = Indices<NodeIndex<Ix>>;
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.node_indices()
+    }
+}
+
+impl<'a, N, E, const MAX_N: usize, const MAX_E: usize, Ty, Ix> IntoNodeReferences
+    for &'a ConstGraph<N, E, MAX_N, MAX_E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeRef = (NodeIndex<Ix>, &'a N);
+    type NodeReferences = NodeReferences<'a, N, Ix>;
+    fn node_references(self) -> Self::NodeReferences {
+        NodeReferences {
+            iter: self.nodes[..self.node_count].iter().enumerate(),
+        }
+    }
+}
@@ -11,9 +11,9 @@ use core::{
 };
 
 use crate::visit::{
-    Data, EdgeCount, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences,
-    IntoEdges, IntoNeighbors, IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable,
-    NodeCount, NodeIndexable, Visitable,
+    Data, Degree, EdgeCount, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp, GraphRef,
+    IntoEdgeReferences, IntoEdges, IntoNeighbors, IntoNodeIdentifiers, IntoNodeReferences,
+    NodeCompactIndexable, NodeCount, NodeIndexable, NodeRef, Visitable,
 };
 
 use crate::util::zip;
@@ -21,7 +21,7 @@ use crate::util::zip;
 #[doc(no_inline)]
 pub use crate::graph::{DefaultIx, IndexType};
 
-use crate::{Directed, EdgeType, IntoWeightedEdge};
+use crate::{Directed, EdgeType, Graph, IntoWeightedEdge};
 
 /// Csr node index type, a plain integer.
 pub type NodeIndex<Ix = DefaultIx> = Ix;
@@ -67,7 +67,10 @@ impl fmt::Display for CsrError {
 ///
 /// Self loops are allowed, no parallel edges.
 ///
-/// Fast iteration of the outgoing edges of a node.
+/// Fast iteration of the outgoing edges of a node. The neighbors of each node
+/// are kept sorted, so that [`contains_edge`](Csr::contains_edge) and
+/// [`find_edge`](Csr::find_edge) use a binary search, and the common
+/// neighbors of two nodes are found by merging their neighbor lists.
 ///
 /// [`CSR`]: https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)
 #[derive(Debug)]
@@ -264,6 +267,51 @@ where
     Ty: EdgeType,
     Ix: IndexType,
 {
+    /// Create a `Csr` from its node weights and its entries
+    /// `(source, target, weight)`, sorted and unique, with both directions of
+    /// the undirected edges, and its number of edges.
+    pub(crate) fn from_sorted_entries(
+        node_weights: Vec<N>,
+        entries: Vec<(usize, NodeIndex<Ix>, E)>,
+        edge_count: usize,
+    ) -> Self {
+        let mut row = vec![0; node_weights.len() + 1];
+        let mut column = Vec::with_capacity(entries.len());
+        let mut edges = Vec::with_capacity(entries.len());
+        for (source, target, weight) in entries {
+            row[source + 1] += 1;
+            column.push(target);
+            edges.push(weight);
+        }
+        for i in 1..row.len() {
+            row[i] += row[i - 1];
+        }
+        Csr {
+            column,
+            edges,
+            row,
+            node_weights,
+            edge_count,
+            ty: PhantomData,
+        }
+    }
+
+    /// Create a `Csr` from its node weights and its entries
+    /// `(source, target, weight)`, with both directions of the undirected
+    /// edges, keeping the first of duplicate entries.
+    fn from_unsorted_entries(
+        node_weights: Vec<N>,
+        mut entries: Vec<(usize, NodeIndex<Ix>, E)>,
+    ) -> Self {
+        entries.sort_by_key(|entry| (entry.0, entry.1));
+        entries.dedup_by_key(|entry| (entry.0, entry.1));
+        let edge_count = entries
+            .iter()
+            .filter(|entry| entry.0 <= entry.1.index())
+            .count();
+        Csr::from_sorted_entries(node_weights, entries, edge_count)
+    }
+
     pub fn node_count(&self) -> usize {
         self.row.len() - 1
     }
@@ -400,6 +448,16 @@ where
         self.find_edge_pos(a, b).is_ok()
     }
 
+    /// Return the index of the edge from `a` to `b`, if any.
+    ///
+    /// Computes in **O(log d)** time, where **d** is the out degree of `a`.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn find_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex> {
+        self.find_edge_pos(a, b).ok()
+    }
+
     fn neighbors_range(&self, a: NodeIndex<Ix>) -> Range<usize> {
         let index = self.row[a.index()];
         let end = self
@@ -424,6 +482,48 @@ where
         r.end - r.start
     }
 
+    /// Return the number of edges ending at `a`.
+    ///
+    /// - `Directed`: Incoming edges to `a`. A self loop counts once.
+    /// - `Undirected`: Same as [`.degree(a)`](#method.degree).
+    ///
+    /// Computes in **O(|E|)** time for `Directed` graphs, which don't store
+    /// the incoming edges, and in **O(log |V|)** time otherwise.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        if self.is_directed() {
+            assert!(
+                a.index() < self.node_count(),
+                "Csr::in_degree: node out of bounds"
+            );
+            self.column.iter().filter(|&&b| b == a).count()
+        } else {
+            self.degree(a)
+        }
+    }
+
+    /// Return the number of edge endpoints at `a`: a self loop counts twice,
+    /// and for `Directed` graphs this is the sum of the in- and out-degree.
+    ///
+    /// Unlike [`.out_degree(a)`](#method.out_degree), which counts the
+    /// stored entries of `a`, it counts a self loop twice in an `Undirected`
+    /// graph too.
+    ///
+    /// Computes in **O(|E|)** time for `Directed` graphs, and in
+    /// **O(log |V|)** time otherwise.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        if self.is_directed() {
+            self.out_degree(a) + self.in_degree(a)
+        } else {
+            self.out_degree(a) + self.contains_edge(a, a) as usize
+        }
+    }
+
     /// Computes in **O(1)** time.
     ///
     /// **Panics** if the node `a` does not exist.
@@ -457,6 +557,357 @@ where
             ty: self.ty,
         }
     }
+
+    /// Return an iterator over the nodes that are neighbors of both `a` and
+    /// `b`, in increasing order.
+    ///
+    /// The sorted neighbor lists are merged, with a loop that compilers can
+    /// turn into branch free code.
+    ///
+    /// Computes in **O(d(a) + d(b))** time.
+    ///
+    /// **Panics** if the node `a` or `b` does not exist.
+    #[track_caller]
+    pub fn common_neighbors(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> CommonNeighbors<'_, Ix> {
+        CommonNeighbors {
+            a: self.neighbors_slice(a),
+            b: self.neighbors_slice(b),
+        }
+    }
+
+    /// Return the number of triangles of the graph.
+    ///
+    /// For a directed graph, only the triangles of edges from lower to
+    /// higher node indices are counted: `a → b`, `a → c` and `b → c` with
+    /// `a < b < c`. Self loops are ignored.
+    ///
+    /// Computes in **O(|E|·d)** time, where **d** is the maximum degree.
+    ///
+    /// ```rust
+    /// use petgraph::csr::Csr;
+    /// use petgraph::Undirected;
+    ///
+    /// let mut graph = Csr::<(), (), Undirected>::with_nodes(4);
+    /// for (a, b) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 1)] {
+    ///     graph.add_edge(a, b, ());
+    /// }
+    /// assert_eq!(graph.triangle_count(), 2);
+    /// ```
+    pub fn triangle_count(&self) -> usize {
+        let mut count = 0;
+        for a in 0..self.node_count() {
+            let a = Ix::new(a);
+            let higher = |n: NodeIndex<Ix>| {
+                let neighbors = self.neighbors_slice(n);
+                &neighbors[neighbors.partition_point(|&c| c <= n)..]
+            };
+            for &b in higher(a) {
+                count += CommonNeighbors {
+                    a: higher(a),
+                    b: higher(b),
+                }
+                .count();
+            }
+        }
+        count
+    }
+
+    /// Keep all edges for which `visit` returns `true`, remove the others.
+    ///
+    /// For an undirected graph, `visit` is called once per edge, with its
+    /// source no greater than its target, and both directions of the edge
+    /// are kept or removed together.
+    ///
+    /// Edge indices are invalidated.
+    ///
+    /// Computes in **O(|V| + |E| log d)** time, where **d** is the maximum
+    /// degree.
+    ///
+    /// ```rust
+    /// use petgraph::csr::Csr;
+    ///
+    /// let mut graph = Csr::<(), u32>::from_sorted_edges(&[(0, 1, 5), (0, 2, 1), (1, 2, 7)]).unwrap();
+    /// graph.retain_edges(|edge| *edge.weight() > 2);
+    /// assert_eq!(graph.edge_count(), 2);
+    /// assert!(!graph.contains_edge(0, 2));
+    /// ```
+    pub fn retain_edges<F>(&mut self, mut visit: F)
+    where
+        F: FnMut(EdgeReference<E, Ty, Ix>) -> bool,
+    {
+        let mut keep = Vec::with_capacity(self.column.len());
+        for source in 0..self.node_count() {
+            let a = Ix::new(source);
+            for edge in self.edges(a) {
+                let b = edge.target;
+                let kept = if self.is_directed() || a <= b {
+                    visit(edge)
+                } else {
+                    // the other direction is in an earlier row
+                    let pos = self.find_edge_pos(b, a).unwrap();
+                    keep[pos]
+                };
+                keep.push(kept);
+            }
+        }
+
+        let mut keep = keep.into_iter();
+        let n = self.node_count();
+        self.rebuild_entries(n, |source, target| {
+            if keep.next().unwrap() {
+                Some((source, target))
+            } else {
+                None
+            }
+        });
+    }
+
+    /// Keep all nodes for which `visit` returns `true`, remove the others
+    /// with their edges.
+    ///
+    /// The remaining nodes keep their relative order, and are renumbered to
+    /// have compact indices. Edge indices are invalidated.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    ///
+    /// ```rust
+    /// use petgraph::csr::Csr;
+    ///
+    /// let mut graph = Csr::<char, ()>::new();
+    /// for name in ['a', 'b', 'c'] {
+    ///     graph.add_node(name);
+    /// }
+    /// graph.add_edge(0, 2, ());
+    /// graph.add_edge(1, 2, ());
+    /// graph.retain_nodes(|_, &name| name != 'a');
+    /// assert_eq!(graph.node_count(), 2);
+    /// assert_eq!(graph[0], 'b');
+    /// assert_eq!(graph.neighbors_slice(0), &[1]);
+    /// ```
+    pub fn retain_nodes<F>(&mut self, mut visit: F)
+    where
+        F: FnMut(NodeIndex<Ix>, &N) -> bool,
+    {
+        let mut new_index = Vec::with_capacity(self.node_count());
+        let mut count = 0;
+        for (i, weight) in self.node_weights.iter().enumerate() {
+            if visit(Ix::new(i), weight) {
+                new_index.push(Some(Ix::new(count)));
+                count += 1;
+            } else {
+                new_index.push(None);
+            }
+        }
+
+        let mut index = new_index.iter();
+        self.node_weights
+            .retain(|_| index.next().unwrap().is_some());
+        self.rebuild_entries(count, |source, target| {
+            Some((new_index[source]?.index(), new_index[target.index()]?))
+        });
+    }
+
+    /// Create a new `Csr` with the structure of `self`, with weights mapped
+    /// by `node_map` and `edge_map`.
+    ///
+    /// For an undirected graph, `edge_map` is called for both directions of
+    /// each edge, except self loops.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn map<'a, F, G, N2, E2>(&'a self, mut node_map: F, mut edge_map: G) -> Csr<N2, E2, Ty, Ix>
+    where
+        F: FnMut(NodeIndex<Ix>, &'a N) -> N2,
+        G: FnMut(EdgeIndex, &'a E) -> E2,
+    {
+        Csr {
+            column: self.column.clone(),
+            edges: self
+                .edges
+                .iter()
+                .enumerate()
+                .map(|(i, weight)| edge_map(i, weight))
+                .collect(),
+            row: self.row.clone(),
+            node_weights: self
+                .node_weights
+                .iter()
+                .enumerate()
+                .map(|(i, weight)| node_map(Ix::new(i), weight))
+                .collect(),
+            edge_count: self.edge_count,
+            ty: PhantomData,
+        }
+    }
+
+    /// Reverse the direction of all edges, transposing the adjacency
+    /// matrix. An undirected graph is left unchanged.
+    ///
+    /// Edge indices are invalidated.
+    ///
+    /// Computes in **O(|V| + |E| log |E|)** time.
+    ///
+    /// ```rust
+    /// use petgraph::csr::Csr;
+    ///
+    /// let mut graph = Csr::<(), ()>::from_sorted_edges(&[(0, 1), (0, 2), (2, 1)]).unwrap();
+    /// graph.reverse();
+    /// assert_eq!(graph.neighbors_slice(1), &[0, 2]);
+    /// assert_eq!(graph.neighbors_slice(2), &[0]);
+    /// ```
+    pub fn reverse(&mut self) {
+        if !self.is_directed() {
+            return;
+        }
+        let graph = core::mem::take(self);
+        let mut entries = Vec::with_capacity(graph.column.len());
+        let mut iter = zip(graph.column, graph.edges);
+        for (source, range) in graph.row.windows(2).enumerate() {
+            for (target, weight) in (&mut iter).take(range[1] - range[0]) {
+                entries.push((target.index(), Ix::new(source), weight));
+            }
+        }
+        // a stable sort keeps the sources of each row sorted
+        entries.sort_by_key(|entry| entry.0);
+        *self = Csr::from_sorted_entries(graph.node_weights, entries, 0);
+    }
+
+    /// Create a new `Csr` with the nodes and edges of `graph`, with cloned
+    /// weights.
+    ///
+    /// The nodes are renumbered in the order of [`IntoNodeReferences`]. Of
+    /// parallel edges, only the first one is kept. If either `graph` or the
+    /// `Csr` is undirected, each edge is stored in both directions.
+    ///
+    /// Computes in **O(|V| + |E| log |E|)** time.
+    ///
+    /// ```rust
+    /// use petgraph::csr::Csr;
+    /// use petgraph::graph::DiGraph;
+    ///
+    /// let mut graph = DiGraph::<&str, f64>::new();
+    /// let a = graph.add_node("a");
+    /// let b = graph.add_node("b");
+    /// graph.add_edge(b, a, 1.5);
+    ///
+    /// let csr = Csr::<_, _>::from_graph(&graph);
+    /// assert_eq!(csr[1], "b");
+    /// assert_eq!(csr.edges_slice(1), &[1.5]);
+    /// ```
+    pub fn from_graph<G>(graph: G) -> Self
+    where
+        G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+        G: Data<NodeWeight = N, EdgeWeight = E>,
+        N: Clone,
+        E: Clone,
+    {
+        let mut position = vec![0; graph.node_bound()];
+        let mut node_weights = Vec::new();
+        for node in graph.node_references() {
+            position[graph.to_index(node.id())] = node_weights.len();
+            node_weights.push(node.weight().clone());
+        }
+        let mut entries = Vec::new();
+        for edge in graph.edge_references() {
+            let a = position[graph.to_index(edge.source())];
+            let b = position[graph.to_index(edge.target())];
+            if (!Ty::is_directed() || !graph.is_directed()) && a != b {
+                entries.push((b, Ix::new(a), edge.weight().clone()));
+            }
+            entries.push((a, Ix::new(b), edge.weight().clone()));
+        }
+        Csr::from_unsorted_entries(node_weights, entries)
+    }
+
+    /// Rebuild the rows with `node_count` nodes, keeping each entry for
+    /// which `f` returns its new source and target.
+    ///
+    /// `f` must keep the entries sorted and unique.
+    fn rebuild_entries<F>(&mut self, node_count: usize, mut f: F)
+    where
+        F: FnMut(usize, NodeIndex<Ix>) -> Option<(usize, NodeIndex<Ix>)>,
+    {
+        let old_row = core::mem::replace(&mut self.row, vec![0; node_count + 1]);
+        let mut entries = zip(
+            core::mem::take(&mut self.column),
+            core::mem::take(&mut self.edges),
+        );
+        let mut edge_count = 0;
+        for (source, range) in old_row.windows(2).enumerate() {
+            for (target, weight) in (&mut entries).take(range[1] - range[0]) {
+                if let Some((a, b)) = f(source, target) {
+                    self.row[a + 1] += 1;
+                    self.column.push(b);
+                    self.edges.push(weight);
+                    if a <= b.index() {
+                        edge_count += 1;
+                    }
+                }
+            }
+        }
+        for i in 1..self.row.len() {
+            self.row[i] += self.row[i - 1];
+        }
+        if !self.is_directed() {
+            self.edge_count = edge_count;
+        }
+    }
+}
+
+/// Convert a `Graph` into a `Csr`, with the same node indices.
+///
+/// Of parallel edges, only the first one is kept.
+///
+/// Computes in **O(|V| + |E| log |E|)** time.
+impl<N, E, Ty, Ix> From<Graph<N, E, Ty, Ix>> for Csr<N, E, Ty, Ix>
+where
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: Graph<N, E, Ty, Ix>) -> Self {
+        let (nodes, edges) = graph.into_nodes_edges();
+        let mut entries = Vec::with_capacity(edges.len());
+        for edge in edges {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            if !Ty::is_directed() && a != b {
+                entries.push((b, Ix::new(a), edge.weight.clone()));
+            }
+            entries.push((a, Ix::new(b), edge.weight));
+        }
+        let node_weights = nodes.into_iter().map(|node| node.weight).collect();
+        Csr::from_unsorted_entries(node_weights, entries)
+    }
+}
+
+/// Iterator over the common neighbors of two nodes of a [`Csr`].
+///
+/// Created with [`Csr::common_neighbors`].
+#[derive(Clone, Debug)]
+pub struct CommonNeighbors<'a, Ix: 'a = DefaultIx> {
+    a: &'a [NodeIndex<Ix>],
+    b: &'a [NodeIndex<Ix>],
+}
+
+impl<Ix> Iterator for CommonNeighbors<'_, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let (Some(&x), Some(&y)) = (self.a.first(), self.b.first()) {
+            self.a = &self.a[(x <= y) as usize..];
+            self.b = &self.b[(y <= x) as usize..];
+            if x == y {
+                return Some(x);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.a.len().min(self.b.len())))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -797,6 +1248,26 @@ where
     }
 }
 
+impl<N, E, Ty, Ix> Degree for Csr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        if self.is_directed() {
+            Csr::out_degree(self, a)
+        } else {
+            Csr::degree(self, a)
+        }
+    }
+    fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        Csr::in_degree(self, a)
+    }
+    fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        Csr::degree(self, a)
+    }
+}
+
 impl<N, E, Ty, Ix> GraphProp for Csr<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -886,6 +1357,446 @@ where
     }
 }
 
+/// The error type for [`CsrSlice::new`] and its builder methods, for
+/// slices that do not describe a graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsrSliceError {
+    /// The offsets are empty: there must be one more offset than nodes.
+    EmptyOffsets,
+    /// The offset of the node at this index is greater than the next one.
+    OffsetsNotSorted(usize),
+    /// The last offset is greater than the number of targets.
+    OffsetOutOfBounds(usize),
+    /// The target of the edge at this index is not a node.
+    TargetOutOfBounds(usize),
+    /// The number of weights, node or edge, does not match the number of
+    /// nodes or targets.
+    WeightCount(usize),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CsrSliceError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for CsrSliceError {}
+
+impl fmt::Display for CsrSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsrSliceError::EmptyOffsets => write!(f, "The offsets are empty"),
+            CsrSliceError::OffsetsNotSorted(i) => {
+                write!(f, "The offsets of nodes {i} and {} are not sorted", i + 1)
+            }
+            CsrSliceError::OffsetOutOfBounds(offset) => {
+                write!(f, "The last offset {offset} is out of the targets bounds")
+            }
+            CsrSliceError::TargetOutOfBounds(i) => {
+                write!(f, "The target of edge {i} is not a node")
+            }
+            CsrSliceError::WeightCount(count) => {
+                write!(f, "The number of weights {count} does not match the graph")
+            }
+        }
+    }
+}
+
+/// A directed graph in compressed sparse row form over borrowed slices.
+///
+/// `CsrSlice` implements the read-only graph traits directly over arrays
+/// owned by the caller, so that adjacency data that lives in a memory map,
+/// a columnar buffer or memory shared through FFI can be given to the
+/// algorithms without copying it. The outgoing edges of node `i` are the
+/// entries `offsets[i]..offsets[i + 1]` of `targets`, which are also the
+/// edge indices, and of the optional edge weights.
+///
+/// The slices are validated once when the graph is created. The type is
+/// `Copy`, so it can be passed by value to the algorithms, which see the
+/// node weights as `N` and the edge weights as `E`, both `()` when not
+/// given.
+///
+/// An owned [`Csr`] with directed edges can be viewed as a `CsrSlice` with
+/// [`Csr::as_slice`].
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{dijkstra, is_cyclic_directed};
+/// use petgraph::csr::CsrSlice;
+///
+/// // 0 -> 1 -> 3, 0 -> 2 -> 3
+/// let offsets = [0, 2, 3, 4, 4];
+/// let targets = [1u32, 2, 3, 3];
+/// let weights = [1.0, 4.0, 1.0, 1.0];
+/// let graph = CsrSlice::new(&offsets, &targets)
+///     .unwrap()
+///     .with_edge_weights(&weights)
+///     .unwrap();
+///
+/// assert!(!is_cyclic_directed(graph));
+/// let distances = dijkstra(graph, 0, None, |e| *e.weight());
+/// assert_eq!(distances[&3], 2.0);
+///
+/// // Invalid slices are rejected.
+/// assert!(CsrSlice::new(&[0, 2, 1], &targets[..2]).is_err());
+/// ```
+pub struct CsrSlice<'a, N = (), E = (), Ix = DefaultIx> {
+    offsets: &'a [usize],
+    targets: &'a [Ix],
+    node_weights: &'a [N],
+    edge_weights: &'a [E],
+}
+
+impl<N, E, Ix> Clone for CsrSlice<'_, N, E, Ix> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N, E, Ix> Copy for CsrSlice<'_, N, E, Ix> {}
+
+impl<N, E, Ix: fmt::Debug> fmt::Debug for CsrSlice<'_, N, E, Ix> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CsrSlice")
+            .field("offsets", &self.offsets)
+            .field("targets", &self.targets)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Return a slice of `len` units. A `Vec` of zero-sized values does not
+/// allocate, so leaking it is free.
+fn units(len: usize) -> &'static [()] {
+    Vec::leak(vec![(); len])
+}
+
+impl<'a, Ix> CsrSlice<'a, (), (), Ix>
+where
+    Ix: IndexType,
+{
+    /// Create a graph from the `offsets` of the outgoing edges of every node
+    /// in `targets`, and one more offset for the end of the last node.
+    ///
+    /// Computes in **O(|V| + |E|)** time, to check that the offsets are
+    /// sorted and within `targets`, and that the targets are nodes.
+    pub fn new(offsets: &'a [usize], targets: &'a [Ix]) -> Result<Self, CsrSliceError> {
+        let (last, rest) = match offsets.split_last() {
+            Some(split) => split,
+            None => return Err(CsrSliceError::EmptyOffsets),
+        };
+        if let Some(i) = offsets.windows(2).position(|w| w[0] > w[1]) {
+            return Err(CsrSliceError::OffsetsNotSorted(i));
+        }
+        if *last > targets.len() {
+            return Err(CsrSliceError::OffsetOutOfBounds(*last));
+        }
+        let node_count = rest.len();
+        if let Some(i) = targets[offsets[0]..*last]
+            .iter()
+            .position(|t| t.index() >= node_count)
+        {
+            return Err(CsrSliceError::TargetOutOfBounds(offsets[0] + i));
+        }
+        Ok(CsrSlice {
+            offsets,
+            targets,
+            node_weights: units(node_count),
+            edge_weights: units(targets.len()),
+        })
+    }
+}
+
+impl<'a, E, Ix> CsrSlice<'a, (), E, Ix>
+where
+    Ix: IndexType,
+{
+    /// Create a graph from slices that were already validated by
+    /// [`CsrSlice::new`], with `edge_weights` in lock step with the targets.
+    #[cfg(feature = "mmap")]
+    pub(crate) fn from_parts_unchecked(
+        offsets: &'a [usize],
+        targets: &'a [Ix],
+        edge_weights: &'a [E],
+    ) -> Self {
+        debug_assert_eq!(targets.len(), edge_weights.len());
+        CsrSlice {
+            offsets,
+            targets,
+            node_weights: units(offsets.len() - 1),
+            edge_weights,
+        }
+    }
+}
+
+impl<'a, N, E, Ix> CsrSlice<'a, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    /// Use `weights` as the node weights, one for each node.
+    pub fn with_node_weights<N2>(
+        self,
+        weights: &'a [N2],
+    ) -> Result<CsrSlice<'a, N2, E, Ix>, CsrSliceError> {
+        if weights.len() != self.node_count() {
+            return Err(CsrSliceError::WeightCount(weights.len()));
+        }
+        Ok(CsrSlice {
+            offsets: self.offsets,
+            targets: self.targets,
+            node_weights: weights,
+            edge_weights: self.edge_weights,
+        })
+    }
+
+    /// Use `weights` as the edge weights, in lock step with the targets.
+    pub fn with_edge_weights<E2>(
+        self,
+        weights: &'a [E2],
+    ) -> Result<CsrSlice<'a, N, E2, Ix>, CsrSliceError> {
+        if weights.len() != self.targets.len() {
+            return Err(CsrSliceError::WeightCount(weights.len()));
+        }
+        Ok(CsrSlice {
+            offsets: self.offsets,
+            targets: self.targets,
+            node_weights: self.node_weights,
+            edge_weights: weights,
+        })
+    }
+
+    /// Return the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Return the number of edges.
+    pub fn edge_count(&self) -> usize {
+        self.offsets[self.node_count()] - self.offsets[0]
+    }
+
+    fn neighbors_range(&self, a: NodeIndex<Ix>) -> Range<usize> {
+        self.offsets[a.index()]..self.offsets[a.index() + 1]
+    }
+
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        self.neighbors_range(a).len()
+    }
+
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn neighbors_slice(&self, a: NodeIndex<Ix>) -> &'a [NodeIndex<Ix>] {
+        &self.targets[self.neighbors_range(a)]
+    }
+
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn edges_slice(&self, a: NodeIndex<Ix>) -> &'a [E] {
+        &self.edge_weights[self.neighbors_range(a)]
+    }
+
+    /// Computes in **O(d)** time, where **d** is the out-degree of `a`.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.neighbors_slice(a).contains(&b)
+    }
+
+    /// Return an iterator of the outgoing edges of `a`.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `EdgeReference<E, Directed, Ix>`.
+    #[track_caller]
+    pub fn edges(&self, a: NodeIndex<Ix>) -> Edges<'a, E, Directed, Ix> {
+        let r = self.neighbors_range(a);
+        Edges {
+            index: r.start,
+            source: a,
+            iter: zip(&self.targets[r.clone()], &self.edge_weights[r]),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<N, E, Ix> Csr<N, E, Directed, Ix>
+where
+    Ix: IndexType,
+{
+    /// Return a [`CsrSlice`] view of the graph.
+    pub fn as_slice(&self) -> CsrSlice<'_, N, E, Ix> {
+        CsrSlice {
+            offsets: &self.row,
+            targets: &self.column,
+            node_weights: &self.node_weights,
+            edge_weights: &self.edges,
+        }
+    }
+}
+
+impl<N, E, Ix> GraphBase for CsrSlice<'_, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = EdgeIndex;
+}
+
+impl<N, E, Ix> GraphRef for CsrSlice<'_, N, E, Ix> where Ix: IndexType {}
+
+impl<N, E, Ix> Data for CsrSlice<'_, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<N, E, Ix> GraphProp for CsrSlice<'_, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type EdgeType = Directed;
+}
+
+impl<N, E, Ix> NodeCount for CsrSlice<'_, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn node_count(&self) -> usize {
+        CsrSlice::node_count(self)
+    }
+}
+
+impl<N, E, Ix> EdgeCount for CsrSlice<'_, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn edge_count(&self) -> usize {
+        CsrSlice::edge_count(self)
+    }
+}
+
+impl<N, E, Ix> NodeIndexable for CsrSlice<'_, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn node_bound(&self) -> usize {
+        self.node_count()
+    }
+    fn to_index(&self, a: Self::NodeId) -> usize {
+        a.index()
+    }
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        Ix::new(ix)
+    }
+}
+
+impl<N, E, Ix> NodeCompactIndexable for CsrSlice<'_, N, E, Ix> where Ix: IndexType {}
+
+impl<N, E, Ix> Visitable for CsrSlice<'_, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Map = FixedBitSet;
+    fn visit_map(&self) -> FixedBitSet {
+        FixedBitSet::with_capacity(self.node_count())
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+        map.grow(self.node_count());
+    }
+}
+
+impl<'a, N, E, Ix> IntoNeighbors for CsrSlice<'a, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Neighbors = Neighbors<'a, Ix>;
+
+    /// Return an iterator of the targets of the outgoing edges of `a`.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    fn neighbors(self, a: Self::NodeId) -> Self::Neighbors {
+        Neighbors {
+            iter: self.neighbors_slice(a).iter(),
+        }
+    }
+}
+
+impl<'a, N, E, Ix> IntoEdges for CsrSlice<'a, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Edges = Edges<'a, E, Directed, Ix>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        CsrSlice::edges(&self, a)
+    }
+}
+
+impl<'a, N, E, Ix> IntoEdgeReferences for CsrSlice<'a, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type EdgeRef = EdgeReference<'a, E, Directed, Ix>;
+    type EdgeReferences = EdgeReferences<'a, E, Directed, Ix>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        EdgeReferences {
+            index: self.offsets[0],
+            source_index: Ix::new(0),
+            edge_ranges: self.offsets.windows(2).enumerate(),
+            column: self.targets,
+            edges: self.edge_weights,
+            iter: zip(&[], &[]),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<N, E, Ix> IntoNodeIdentifiers for CsrSlice<'_, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeIdentifiers = NodeIdentifiers<Ix>;
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        NodeIdentifiers {
+            r: 0..self.node_count(),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<'a, N, E, Ix> IntoNodeReferences for CsrSlice<'a, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeRef = (NodeIndex<Ix>, &'a N);
+    type NodeReferences = NodeReferences<'a, N, Ix>;
+    fn node_references(self) -> Self::NodeReferences {
+        NodeReferences {
+            iter: self.node_weights.iter().enumerate(),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<N, E, Ix> Index<NodeIndex<Ix>> for CsrSlice<'_, N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Output = N;
+
+    fn index(&self, ix: NodeIndex<Ix>) -> &N {
+        &self.node_weights[ix.index()]
+    }
+}
+
 /*
  *
 Example
@@ -956,6 +1867,76 @@ mod tests {
         assert_eq!(m.edge_count(), 4);
     }
 
+    #[test]
+    fn csr_retain() {
+        let mut m: Csr<(), u32, Undirected> = Csr::with_nodes(4);
+        m.add_edge(0, 1, 1);
+        m.add_edge(1, 1, 2);
+        m.add_edge(1, 2, 3);
+        m.add_edge(3, 2, 4);
+        m.retain_edges(|edge| edge.weight() % 2 == 0);
+        assert_eq!(m.edge_count(), 2);
+        assert_eq!(&m.column, &[1, 3, 2]);
+        assert_eq!(&m.row, &[0, 0, 1, 2, 3]);
+
+        let mut m: Csr<u8, ()> = Csr::from_sorted_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]).unwrap();
+        m.retain_nodes(|a, _| a != 1);
+        assert_eq!(m.node_count(), 3);
+        assert_eq!(m.edge_count(), 2);
+        assert_eq!(&m.column, &[0, 2]);
+        assert_eq!(&m.row, &[0, 0, 2, 2]);
+    }
+
+    #[test]
+    fn csr_map_reverse() {
+        let mut m: Csr<(), u32> =
+            Csr::from_sorted_edges(&[(0, 1, 1), (0, 2, 2), (1, 2, 3), (2, 0, 4)]).unwrap();
+        let mapped = m.map(|a, _| a * 10, |_, w| w + 1);
+        assert_eq!(mapped[2], 20);
+        assert_eq!(mapped.edges_slice(1), &[4]);
+
+        m.reverse();
+        assert_eq!(&m.column, &[2, 0, 0, 1]);
+        assert_eq!(&m.row, &[0, 1, 2, 4]);
+        assert_eq!(m.edges_slice(2), &[2, 3]);
+        m.reverse();
+        assert_eq!(m.neighbors_slice(0), &[1, 2]);
+    }
+
+    #[test]
+    fn csr_from_graph() {
+        let mut g = crate::graph::UnGraph::<u8, u8>::new_undirected();
+        let a = g.add_node(0);
+        let b = g.add_node(1);
+        g.add_edge(a, b, 5);
+        g.add_edge(b, b, 6);
+        g.add_edge(b, a, 7);
+        let m: Csr<u8, u8> = Csr::from_graph(&g);
+        assert_eq!(&m.column, &[1, 0, 1]);
+        assert_eq!(&m.edges, &[5, 5, 6]);
+        let m: Csr<u8, u8, Undirected> = Csr::from_graph(&g);
+        assert_eq!(m.edge_count(), 2);
+    }
+
+    #[test]
+    fn csr_sorted_neighbors() {
+        let mut g = crate::graph::UnGraph::<(), u8>::new_undirected();
+        g.extend_with_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 1), (3, 0), (4, 4)]);
+        let m: Csr<(), u8, Undirected> = Csr::from(g);
+        assert_eq!(m.node_count(), 5);
+        assert_eq!(m.edge_count(), 7);
+        assert_eq!(m.neighbors_slice(3), &[0, 1, 2]);
+        assert_eq!(m.find_edge(3, 1), Some(10));
+        assert_eq!(m.find_edge(4, 1), None);
+        let common: Vec<_> = m.common_neighbors(0, 1).collect();
+        assert_eq!(common, [2, 3]);
+        assert_eq!(m.common_neighbors(4, 4).collect::<Vec<_>>(), [4]);
+        assert_eq!(m.triangle_count(), 4);
+
+        let d: Csr = Csr::from_sorted_edges(&[(0, 1), (0, 2), (1, 2), (2, 0)]).unwrap();
+        assert_eq!(d.triangle_count(), 1);
+    }
+
     #[should_panic]
     #[test]
     fn csr_from_error_1() {
@@ -1217,4 +2198,88 @@ mod tests {
         assert_eq!(refs.next(), Some((2, &44)));
         assert_eq!(refs.next(), None);
     }
+
+    #[test]
+    fn csr_slice() {
+        use super::{CsrSlice, CsrSliceError};
+        use crate::algo::{dijkstra, is_cyclic_directed};
+        use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences};
+
+        let edges = [
+            (0, 1, 2.0),
+            (0, 2, 1.0),
+            (1, 3, 1.0),
+            (2, 1, 0.5),
+            (3, 0, 4.0),
+        ];
+        let csr: Csr<u8, f64> = Csr::from_sorted_edges(&edges).unwrap();
+        let view = csr.as_slice();
+        assert_eq!(view.node_count(), 4);
+        assert_eq!(view.edge_count(), 5);
+        assert_eq!(dijkstra(view, 0, None, |e| *e.weight()), {
+            dijkstra(&csr, 0, None, |e| *e.weight())
+        });
+        let triples = |iter: &mut dyn Iterator<Item = (u32, u32, f64)>| iter.collect::<Vec<_>>();
+        assert_eq!(
+            triples(
+                &mut view
+                    .edge_references()
+                    .map(|e| (e.source(), e.target(), *e.weight()))
+            ),
+            triples(
+                &mut csr
+                    .edge_references()
+                    .map(|e| (e.source(), e.target(), *e.weight()))
+            )
+        );
+        assert_eq!(
+            view.node_references().collect::<Vec<_>>(),
+            csr.node_references().collect::<Vec<_>>()
+        );
+
+        // Offsets that don't start at zero, over a larger buffer.
+        let offsets = [2, 3, 3, 5];
+        let targets = [7u32, 7, 1, 2, 0, 7];
+        let names = ["a", "b", "c"];
+        let graph = CsrSlice::new(&offsets, &targets)
+            .unwrap()
+            .with_node_weights(&names)
+            .unwrap();
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.neighbors_slice(2), &[2, 0]);
+        assert!(graph.contains_edge(0, 1));
+        assert!(!graph.contains_edge(1, 0));
+        assert_eq!(graph[2], "c");
+        let ids: Vec<_> = graph.edge_references().map(|e| e.id()).collect();
+        assert_eq!(ids, [2, 3, 4]);
+        assert!(is_cyclic_directed(graph));
+        let mut dfs = Dfs::new(graph, 0);
+        let mut order = Vec::new();
+        while let Some(v) = dfs.next(graph) {
+            order.push(v);
+        }
+        assert_eq!(order, [0, 1]);
+
+        assert_eq!(
+            CsrSlice::<(), (), u32>::new(&[], &[]).unwrap_err(),
+            CsrSliceError::EmptyOffsets
+        );
+        assert_eq!(
+            CsrSlice::new(&[0, 2, 1], &[0u32, 1]).unwrap_err(),
+            CsrSliceError::OffsetsNotSorted(1)
+        );
+        assert_eq!(
+            CsrSlice::new(&[0, 3], &[0u32, 0]).unwrap_err(),
+            CsrSliceError::OffsetOutOfBounds(3)
+        );
+        assert_eq!(
+            CsrSlice::new(&offsets, &[7u32, 7, 1, 3, 0]).unwrap_err(),
+            CsrSliceError::TargetOutOfBounds(3)
+        );
+        assert_eq!(
+            graph.with_edge_weights(&[1, 2]).unwrap_err(),
+            CsrSliceError::WeightCount(2)
+        );
+        assert!(CsrSlice::new(&[0], &[0u32; 0]).unwrap().node_count() == 0);
+    }
 }
@@ -0,0 +1,248 @@
+//! Edge weight decay and pruning for long-running dynamic graphs.
+//!
+//! Graphs that accumulate interactions over time, such as communication or
+//! co-occurrence networks, usually let old interactions fade: every so often
+//! the edge weights are multiplied by a decay factor, and the edges (and
+//! nodes) whose weight fell below a threshold are removed.
+//!
+//! Removing them one by one from a [`Graph`] is slow, and each removal
+//! moves the last node or edge into the freed index. A [`DecaySchedule`]
+//! instead decays and prunes the graph in a single pass that keeps the
+//! remaining nodes and edges in their order, and returns a [`Remapping`]
+//! from the old indices to the new ones.
+
+use alloc::{vec, vec::Vec};
+use core::ops::Mul;
+
+use crate::algo::Measure;
+use crate::graph::{EdgeIndex, Graph, IndexType, NodeIndex};
+use crate::EdgeType;
+
+/// The new indices of the nodes and edges of a graph after pruning, as
+/// returned by [`DecaySchedule::apply`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Remapping<Ix> {
+    nodes: Vec<Option<NodeIndex<Ix>>>,
+    edges: Vec<Option<EdgeIndex<Ix>>>,
+}
+
+impl<Ix: IndexType> Remapping<Ix> {
+    /// Return the new index of the node at index `old`, or `None` if it was
+    /// removed.
+    ///
+    /// **Panics** if `old` was not a node index of the graph.
+    pub fn node(&self, old: NodeIndex<Ix>) -> Option<NodeIndex<Ix>> {
+        self.nodes[old.index()]
+    }
+
+    /// Return the new index of the edge at index `old`, or `None` if it was
+    /// removed.
+    ///
+    /// **Panics** if `old` was not an edge index of the graph.
+    pub fn edge(&self, old: EdgeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        self.edges[old.index()]
+    }
+
+    /// Return the number of nodes that were removed.
+    pub fn removed_node_count(&self) -> usize {
+        self.nodes.iter().filter(|index| index.is_none()).count()
+    }
+
+    /// Return the number of edges that were removed.
+    pub fn removed_edge_count(&self) -> usize {
+        self.edges.iter().filter(|index| index.is_none()).count()
+    }
+
+    /// Return `true` if nothing was removed, so that all indices are
+    /// unchanged.
+    pub fn is_identity(&self) -> bool {
+        self.nodes.iter().all(Option::is_some) && self.edges.iter().all(Option::is_some)
+    }
+}
+
+/// A periodic decay of the edge weights of a [`Graph`], followed by the
+/// removal of the edges and nodes whose weight fell below a threshold.
+///
+/// Each application multiplies every edge weight by the decay `factor`,
+/// then removes:
+/// * the edges whose new weight is less than the edge threshold;
+/// * if a node threshold is set, the nodes whose *strength* (the sum of the
+///   weights of their remaining edges, counting a loop once) is less than
+///   it, with their edges.
+///
+/// With a period of `p`, [`tick`](Self::tick) applies the schedule once
+/// every `p` calls, so that it can be called on every step of a simulation
+/// or every batch of updates.
+///
+/// # Example
+/// ```rust
+/// use petgraph::decay::DecaySchedule;
+/// use petgraph::graph::UnGraph;
+///
+/// let mut graph = UnGraph::<&str, f64>::new_undirected();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// let d = graph.add_node("d");
+/// graph.add_edge(a, b, 4.0);
+/// graph.add_edge(b, c, 1.0);
+/// graph.add_edge(c, d, 0.5);
+///
+/// // Halve the weights every 10 ticks, remove the edges lighter than 0.4,
+/// // and the nodes left without edges.
+/// let mut schedule = DecaySchedule::new(0.5, 0.4)
+///     .with_node_threshold(f64::MIN_POSITIVE)
+///     .with_period(10);
+///
+/// for _ in 0..9 {
+///     assert!(schedule.tick(&mut graph).is_none());
+/// }
+/// let remapping = schedule.tick(&mut graph).unwrap();
+///
+/// // The edge from c to d was removed, and then d.
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.edge_count(), 2);
+/// assert_eq!(remapping.node(d), None);
+/// assert_eq!(remapping.node(c), Some(c));
+/// assert_eq!(graph[graph.find_edge(a, b).unwrap()], 2.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DecaySchedule<E> {
+    factor: E,
+    edge_threshold: E,
+    node_threshold: Option<E>,
+    period: usize,
+    ticks: usize,
+}
+
+impl<E> DecaySchedule<E>
+where
+    E: Measure + Copy + Mul<Output = E>,
+{
+    /// Create a schedule that multiplies the edge weights by `factor` and
+    /// removes the edges whose weight is then less than `edge_threshold`,
+    /// on every tick.
+    pub fn new(factor: E, edge_threshold: E) -> Self {
+        DecaySchedule {
+            factor,
+            edge_threshold,
+            node_threshold: None,
+            period: 1,
+            ticks: 0,
+        }
+    }
+
+    /// Also remove the nodes whose strength is less than `threshold` after
+    /// the edges are pruned.
+    ///
+    /// A threshold just above zero removes the nodes left without edges.
+    pub fn with_node_threshold(mut self, threshold: E) -> Self {
+        self.node_threshold = Some(threshold);
+        self
+    }
+
+    /// Apply the schedule once every `period` ticks.
+    ///
+    /// **Panics** if `period` is zero.
+    pub fn with_period(mut self, period: usize) -> Self {
+        assert!(period > 0, "DecaySchedule: the period must be positive");
+        self.period = period;
+        self
+    }
+
+    /// Return the number of ticks until the next application.
+    pub fn ticks_remaining(&self) -> usize {
+        self.period - self.ticks
+    }
+
+    /// Advance the schedule by one tick, and apply it to `graph` if its
+    /// period has elapsed.
+    ///
+    /// Return the remapping of the indices if the schedule was applied.
+    pub fn tick<N, Ty, Ix>(&mut self, graph: &mut Graph<N, E, Ty, Ix>) -> Option<Remapping<Ix>>
+    where
+        Ty: EdgeType,
+        Ix: IndexType,
+    {
+        self.ticks += 1;
+        if self.ticks < self.period {
+            return None;
+        }
+        self.ticks = 0;
+        Some(self.apply(graph))
+    }
+
+    /// Decay the edge weights of `graph` and prune it now, regardless of the
+    /// period.
+    ///
+    /// The remaining nodes and edges keep their relative order, and the
+    /// returned [`Remapping`] gives their new indices.
+    ///
+    /// # Complexity
+    /// * Time complexity: **O(|V| + |E|)**.
+    /// * Auxiliary space: **O(|V| + |E|)**.
+    ///
+    /// where **|V|** is the number of nodes and **|E|** is the number of edges.
+    pub fn apply<N, Ty, Ix>(&self, graph: &mut Graph<N, E, Ty, Ix>) -> Remapping<Ix>
+    where
+        Ty: EdgeType,
+        Ix: IndexType,
+    {
+        for weight in graph.edge_weights_mut() {
+            *weight = *weight * self.factor;
+        }
+
+        let keep_edge: Vec<bool> = graph
+            .edge_weights()
+            .map(|&weight| weight >= self.edge_threshold)
+            .collect();
+        let keep_node = match self.node_threshold {
+            None => vec![true; graph.node_count()],
+            Some(threshold) => {
+                let mut strength = vec![E::default(); graph.node_count()];
+                for (edge, _) in graph.raw_edges().iter().zip(&keep_edge).filter(|e| *e.1) {
+                    let (source, target) = (edge.source().index(), edge.target().index());
+                    strength[source] = strength[source] + edge.weight;
+                    if source != target {
+                        strength[target] = strength[target] + edge.weight;
+                    }
+                }
+                strength.iter().map(|s| *s >= threshold).collect()
+            }
+        };
+
+        let unchanged = keep_node.iter().all(|&keep| keep) && keep_edge.iter().all(|&keep| keep);
+        if unchanged {
+            return Remapping {
+                nodes: graph.node_indices().map(Some).collect(),
+                edges: graph.edge_indices().map(Some).collect(),
+            };
+        }
+
+        let old = core::mem::replace(graph, Graph::with_capacity(0, 0));
+        let (nodes, edges) = old.into_nodes_edges();
+        let mut node_map = Vec::with_capacity(nodes.len());
+        let kept_nodes = keep_node.iter().filter(|&&keep| keep).count();
+        let kept_edges = keep_edge.iter().filter(|&&keep| keep).count();
+        let mut pruned = Graph::with_capacity(kept_nodes, kept_edges);
+        for (node, keep) in nodes.into_iter().zip(keep_node) {
+            node_map.push(keep.then(|| pruned.add_node(node.weight)));
+        }
+        let mut edge_map = Vec::with_capacity(edges.len());
+        for (edge, keep) in edges.into_iter().zip(keep_edge) {
+            let source = node_map[edge.source().index()];
+            let target = node_map[edge.target().index()];
+            edge_map.push(match (source, target) {
+                (Some(source), Some(target)) if keep => {
+                    Some(pruned.add_edge(source, target, edge.weight))
+                }
+                _ => None,
+            });
+        }
+        *graph = pruned;
+        Remapping {
+            nodes: node_map,
+            edges: edge_map,
+        }
+    }
+}
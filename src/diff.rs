@@ -0,0 +1,448 @@
+//! Compare graphs and report their differences, for use in tests.
+//!
+//! Comparing graphs with `assert_eq!` on their `Debug` output is hard to
+//! read, and fails when two equal graphs store their nodes or edges in a
+//! different order. [`graph_diff`] instead lists the missing and extra nodes
+//! and edges and the mismatched weights, and [`graph_diff_isomorphic`] does
+//! the same after matching the nodes of the graphs by isomorphism.
+//! [`assert_graph_eq`] and [`assert_graph_isomorphic`] panic with that list.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::algo::subgraph_isomorphisms_iter;
+use crate::data::DataMap;
+use crate::visit::{
+    Data, EdgeCount, EdgeRef, GetAdjacencyMatrix, GraphProp, IntoEdgeReferences, IntoEdgesDirected,
+    IntoNodeReferences, NodeCompactIndexable, NodeIndexable, NodeRef,
+};
+
+/// How the nodes of the graphs were matched by a [`GraphDiff`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NodeMatching {
+    /// Nodes are matched by their index.
+    Index,
+    /// Nodes are matched by an isomorphism between the graphs, and indices
+    /// are those of the left graph.
+    Isomorphism,
+    /// The graphs are not isomorphic, so nodes are matched by their index.
+    NotIsomorphic,
+}
+
+/// The differences between a left and a right graph.
+///
+/// Nodes are identified by their index, and edges by the indices of their
+/// endpoints. Edges are compared as multisets: when the graphs have several
+/// edges between the same nodes, only the edges whose weights have no equal
+/// counterpart are reported.
+///
+/// The `Display` implementation lists the differences, one per line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphDiff<N, E> {
+    /// How the nodes were matched.
+    pub matching: NodeMatching,
+    /// Whether edges are directed.
+    pub directed: bool,
+    /// Nodes of the left graph that are not in the right one.
+    pub missing_nodes: Vec<(usize, N)>,
+    /// Nodes of the right graph that are not in the left one.
+    pub extra_nodes: Vec<(usize, N)>,
+    /// Nodes whose weights differ, with the left and right weights.
+    pub changed_nodes: Vec<(usize, N, N)>,
+    /// Edges of the left graph that are not in the right one.
+    pub missing_edges: Vec<(usize, usize, E)>,
+    /// Edges of the right graph that are not in the left one.
+    pub extra_edges: Vec<(usize, usize, E)>,
+    /// Edges whose weights differ, with the left and right weights.
+    pub changed_edges: Vec<(usize, usize, E, E)>,
+}
+
+impl<N, E> GraphDiff<N, E> {
+    /// Return `true` if the graphs have no differences.
+    pub fn is_empty(&self) -> bool {
+        self.missing_nodes.is_empty()
+            && self.extra_nodes.is_empty()
+            && self.changed_nodes.is_empty()
+            && self.missing_edges.is_empty()
+            && self.extra_edges.is_empty()
+            && self.changed_edges.is_empty()
+    }
+}
+
+impl<N: fmt::Debug, E: fmt::Debug> fmt::Display for GraphDiff<N, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return match self.matching {
+                NodeMatching::Index => f.write_str("graphs are equal"),
+                _ => f.write_str("graphs are isomorphic"),
+            };
+        }
+        f.write_str(match self.matching {
+            NodeMatching::Index => "graphs differ (nodes matched by index):",
+            NodeMatching::Isomorphism => "graphs differ (nodes matched by isomorphism):",
+            NodeMatching::NotIsomorphic => "graphs are not isomorphic (nodes matched by index):",
+        })?;
+        let arrow = if self.directed { "->" } else { "--" };
+        for (i, w) in &self.missing_nodes {
+            write!(f, "\n  - node {}: {:?}", i, w)?;
+        }
+        for (i, w) in &self.extra_nodes {
+            write!(f, "\n  + node {}: {:?}", i, w)?;
+        }
+        for (i, left, right) in &self.changed_nodes {
+            write!(f, "\n  ~ node {}: {:?} => {:?}", i, left, right)?;
+        }
+        for (a, b, w) in &self.missing_edges {
+            write!(f, "\n  - edge {} {} {}: {:?}", a, arrow, b, w)?;
+        }
+        for (a, b, w) in &self.extra_edges {
+            write!(f, "\n  + edge {} {} {}: {:?}", a, arrow, b, w)?;
+        }
+        for (a, b, left, right) in &self.changed_edges {
+            write!(
+                f,
+                "\n  ~ edge {} {} {}: {:?} => {:?}",
+                a, arrow, b, left, right
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// \[Generic\] Compare two graphs, matching their nodes by index.
+///
+/// Return the nodes and edges that are only in one of the graphs, and the
+/// ones whose weights differ. Undirected edges are compared regardless of
+/// the order of their endpoints.
+///
+/// # Arguments
+/// * `left`: the first graph.
+/// * `right`: the second graph, with the same node and edge weight types.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| log |E| + k²)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **k** is the largest number of parallel edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::diff::graph_diff;
+/// use petgraph::Graph;
+///
+/// let mut left = Graph::<&str, u32>::new();
+/// let a = left.add_node("a");
+/// let b = left.add_node("b");
+/// left.add_edge(a, b, 1);
+///
+/// let mut right = left.clone();
+/// right[b] = "c";
+/// right.update_edge(a, b, 2);
+///
+/// let diff = graph_diff(&left, &right);
+/// assert_eq!(diff.changed_nodes, vec![(1, "b", "c")]);
+/// assert_eq!(diff.changed_edges, vec![(0, 1, 1, 2)]);
+/// assert_eq!(
+///     diff.to_string(),
+///     "graphs differ (nodes matched by index):\n  ~ node 1: \"b\" => \"c\"\n  ~ edge 0 -> 1: 1 => 2"
+/// );
+/// ```
+pub fn graph_diff<G0, G1>(left: G0, right: G1) -> GraphDiff<G0::NodeWeight, G0::EdgeWeight>
+where
+    G0: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+    G1: IntoNodeReferences
+        + IntoEdgeReferences
+        + NodeIndexable
+        + GraphProp<EdgeType = G0::EdgeType>
+        + Data<NodeWeight = G0::NodeWeight, EdgeWeight = G0::EdgeWeight>,
+    G0::NodeWeight: PartialEq + Clone,
+    G0::EdgeWeight: PartialEq + Clone,
+{
+    compare(left, right, |i| i, NodeMatching::Index)
+}
+
+/// \[Generic\] Compare two graphs, matching their nodes by isomorphism.
+///
+/// If the graphs are isomorphic with equal weights, the returned diff is
+/// empty. If they are isomorphic when ignoring weights, the diff lists the
+/// weights that differ under one such isomorphism, with the node indices of
+/// `left`. Otherwise, the graphs are compared by index like [`graph_diff`]
+/// and the diff is marked [`NodeMatching::NotIsomorphic`].
+///
+/// The graphs should not be [multigraphs].
+///
+/// # Arguments
+/// * `left`: the first graph.
+/// * `right`: the second graph, with the same node and edge weight types.
+///
+/// # Complexity
+/// Exponential in the worst case, like [`is_isomorphic`](crate::algo::is_isomorphic).
+///
+/// [multigraphs]: https://en.wikipedia.org/wiki/Multigraph
+///
+/// # Example
+/// ```rust
+/// use petgraph::diff::{graph_diff_isomorphic, NodeMatching};
+/// use petgraph::Graph;
+///
+/// let left = Graph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// let right = Graph::<(), ()>::from_edges([(2, 0), (1, 2)]);
+/// assert!(graph_diff_isomorphic(&left, &right).is_empty());
+///
+/// let right = Graph::<(), ()>::from_edges([(0, 1), (2, 1)]);
+/// let diff = graph_diff_isomorphic(&left, &right);
+/// assert_eq!(diff.matching, NodeMatching::NotIsomorphic);
+/// assert_eq!(diff.missing_edges, vec![(1, 2, ())]);
+/// assert_eq!(diff.extra_edges, vec![(2, 1, ())]);
+/// ```
+pub fn graph_diff_isomorphic<G0, G1>(
+    left: G0,
+    right: G1,
+) -> GraphDiff<G0::NodeWeight, G0::EdgeWeight>
+where
+    G0: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp
+        + IntoEdgesDirected
+        + IntoNodeReferences,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoEdgesDirected
+        + IntoNodeReferences
+        + Data<NodeWeight = G0::NodeWeight, EdgeWeight = G0::EdgeWeight>,
+    G0::NodeWeight: PartialEq + Clone,
+    G0::EdgeWeight: PartialEq + Clone,
+{
+    let mapping =
+        if left.node_count() == right.node_count() && left.edge_count() == right.edge_count() {
+            // With as many nodes and edges, a subgraph isomorphism is an
+            // isomorphism.
+            let mut weights_match = |a: &G0::NodeWeight, b: &G0::NodeWeight| a == b;
+            let mut edge_weights_match = |a: &G0::EdgeWeight, b: &G0::EdgeWeight| a == b;
+            let mut any_node = |_: &G0::NodeWeight, _: &G0::NodeWeight| true;
+            let mut any_edge = |_: &G0::EdgeWeight, _: &G0::EdgeWeight| true;
+            subgraph_isomorphisms_iter(&left, &right, &mut weights_match, &mut edge_weights_match)
+                .and_then(|mut mappings| mappings.next())
+                .or_else(|| {
+                    subgraph_isomorphisms_iter(&left, &right, &mut any_node, &mut any_edge)
+                        .and_then(|mut mappings| mappings.next())
+                })
+        } else {
+            None
+        };
+
+    match mapping {
+        Some(mapping) => {
+            let mut inverse = alloc::vec![0; mapping.len()];
+            for (i, &j) in mapping.iter().enumerate() {
+                inverse[j] = i;
+            }
+            compare(left, right, |j| inverse[j], NodeMatching::Isomorphism)
+        }
+        None => compare(left, right, |i| i, NodeMatching::NotIsomorphic),
+    }
+}
+
+/// \[Generic\] Assert that two graphs are equal, matching their nodes by
+/// index.
+///
+/// **Panics** with the list of differences computed by [`graph_diff`] if the
+/// graphs differ.
+///
+/// # Example
+/// ```rust
+/// use petgraph::diff::assert_graph_eq;
+/// use petgraph::Graph;
+///
+/// let mut left = Graph::<u32, ()>::new();
+/// let mut right = Graph::<u32, ()>::new();
+/// let (a0, b0) = (left.add_node(0), left.add_node(1));
+/// let (a1, b1) = (right.add_node(0), right.add_node(1));
+/// left.add_edge(a0, b0, ());
+/// left.add_edge(b0, a0, ());
+/// // The order of the edges doesn't matter.
+/// right.add_edge(b1, a1, ());
+/// right.add_edge(a1, b1, ());
+/// assert_graph_eq(&left, &right);
+/// ```
+#[track_caller]
+pub fn assert_graph_eq<G0, G1>(left: G0, right: G1)
+where
+    G0: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+    G1: IntoNodeReferences
+        + IntoEdgeReferences
+        + NodeIndexable
+        + GraphProp<EdgeType = G0::EdgeType>
+        + Data<NodeWeight = G0::NodeWeight, EdgeWeight = G0::EdgeWeight>,
+    G0::NodeWeight: PartialEq + Clone + fmt::Debug,
+    G0::EdgeWeight: PartialEq + Clone + fmt::Debug,
+{
+    let diff = graph_diff(left, right);
+    if !diff.is_empty() {
+        panic!("assertion failed: {}", diff);
+    }
+}
+
+/// \[Generic\] Assert that two graphs are isomorphic, with equal weights.
+///
+/// **Panics** with the list of differences computed by
+/// [`graph_diff_isomorphic`] if the graphs are not isomorphic.
+#[track_caller]
+pub fn assert_graph_isomorphic<G0, G1>(left: G0, right: G1)
+where
+    G0: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp
+        + IntoEdgesDirected
+        + IntoNodeReferences,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoEdgesDirected
+        + IntoNodeReferences
+        + Data<NodeWeight = G0::NodeWeight, EdgeWeight = G0::EdgeWeight>,
+    G0::NodeWeight: PartialEq + Clone + fmt::Debug,
+    G0::EdgeWeight: PartialEq + Clone + fmt::Debug,
+{
+    let diff = graph_diff_isomorphic(left, right);
+    if !diff.is_empty() {
+        panic!("assertion failed: {}", diff);
+    }
+}
+
+/// Compare `left` and `right`, translating the node indices of `right` to
+/// those of `left` with `to_left`.
+fn compare<G0, G1, F>(
+    left: G0,
+    right: G1,
+    to_left: F,
+    matching: NodeMatching,
+) -> GraphDiff<G0::NodeWeight, G0::EdgeWeight>
+where
+    G0: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+    G1: IntoNodeReferences
+        + IntoEdgeReferences
+        + NodeIndexable
+        + Data<NodeWeight = G0::NodeWeight, EdgeWeight = G0::EdgeWeight>,
+    G0::NodeWeight: PartialEq + Clone,
+    G0::EdgeWeight: PartialEq + Clone,
+    F: Fn(usize) -> usize,
+{
+    let directed = left.is_directed();
+    let mut diff = GraphDiff {
+        matching,
+        directed,
+        missing_nodes: Vec::new(),
+        extra_nodes: Vec::new(),
+        changed_nodes: Vec::new(),
+        missing_edges: Vec::new(),
+        extra_edges: Vec::new(),
+        changed_edges: Vec::new(),
+    };
+
+    let mut left_nodes: Vec<_> = left
+        .node_references()
+        .map(|node| (left.to_index(node.id()), node.weight().clone()))
+        .collect();
+    let mut right_nodes: Vec<_> = right
+        .node_references()
+        .map(|node| (to_left(right.to_index(node.id())), node.weight().clone()))
+        .collect();
+    left_nodes.sort_by_key(|&(i, _)| i);
+    right_nodes.sort_by_key(|&(i, _)| i);
+    let mut right_nodes = right_nodes.into_iter().peekable();
+    for (i, weight) in left_nodes {
+        while let Some((j, extra)) = right_nodes.next_if(|&(j, _)| j < i) {
+            diff.extra_nodes.push((j, extra));
+        }
+        match right_nodes.next_if(|&(j, _)| j == i) {
+            Some((_, other)) if other == weight => {}
+            Some((_, other)) => diff.changed_nodes.push((i, weight, other)),
+            None => diff.missing_nodes.push((i, weight)),
+        }
+    }
+    diff.extra_nodes.extend(right_nodes);
+
+    let endpoints = |a: usize, b: usize| {
+        if directed || a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+    let mut left_edges: Vec<_> = left
+        .edge_references()
+        .map(|edge| {
+            let (a, b) = endpoints(left.to_index(edge.source()), left.to_index(edge.target()));
+            (a, b, edge.weight().clone())
+        })
+        .collect();
+    let mut right_edges: Vec<_> = right
+        .edge_references()
+        .map(|edge| {
+            let (a, b) = endpoints(
+                to_left(right.to_index(edge.source())),
+                to_left(right.to_index(edge.target())),
+            );
+            (a, b, edge.weight().clone())
+        })
+        .collect();
+    left_edges.sort_by_key(|&(a, b, _)| (a, b));
+    right_edges.sort_by_key(|&(a, b, _)| (a, b));
+
+    let (mut l, mut r) = (0, 0);
+    while l < left_edges.len() || r < right_edges.len() {
+        let key = match (left_edges.get(l), right_edges.get(r)) {
+            (Some(x), Some(y)) => (x.0, x.1).min((y.0, y.1)),
+            (Some(x), None) => (x.0, x.1),
+            (None, Some(y)) => (y.0, y.1),
+            (None, None) => unreachable!(),
+        };
+        let l_end = l + left_edges[l..]
+            .iter()
+            .take_while(|e| (e.0, e.1) == key)
+            .count();
+        let r_end = r + right_edges[r..]
+            .iter()
+            .take_while(|e| (e.0, e.1) == key)
+            .count();
+
+        // Drop the pairs of equal weights, then pair up the remaining ones.
+        let mut unmatched: Vec<_> = right_edges[r..r_end].iter().map(|e| &e.2).collect();
+        let mut missing = Vec::new();
+        for (_, _, weight) in &left_edges[l..l_end] {
+            match unmatched.iter().position(|&w| w == weight) {
+                Some(k) => {
+                    unmatched.remove(k);
+                }
+                None => missing.push(weight),
+            }
+        }
+        let mut unmatched = unmatched.into_iter();
+        for weight in missing {
+            match unmatched.next() {
+                Some(other) => {
+                    diff.changed_edges
+                        .push((key.0, key.1, weight.clone(), other.clone()))
+                }
+                None => diff.missing_edges.push((key.0, key.1, weight.clone())),
+            }
+        }
+        for other in unmatched {
+            diff.extra_edges.push((key.0, key.1, other.clone()));
+        }
+        l = l_end;
+        r = r_end;
+    }
+    diff
+}
@@ -0,0 +1,310 @@
+//! Graphviz output of a graph together with a partition of its nodes into
+//! communities.
+
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt::{self, Display};
+
+use super::{Config, Configs, Escaped, FnFmt, RankDir, EDGE, INDENT, TYPE};
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+
+/// The label of a node that is in no community.
+const NONE: usize = usize::MAX;
+
+/// The default palette of [`CommunityDot`], the twelve colors of the
+/// ColorBrewer "Set3" qualitative scheme.
+pub const DEFAULT_PALETTE: [&str; 12] = [
+    "#8dd3c7", "#ffffb3", "#bebada", "#fb8072", "#80b1d3", "#fdb462", "#b3de69", "#fccde5",
+    "#d9d9d9", "#bc80bd", "#ccebc5", "#ffed6f",
+];
+
+/// `CommunityDot` implements output to graphviz .dot format for a graph
+/// whose nodes are split into communities, such as the result of a
+/// community detection or of a [partition](crate::algo::partition).
+///
+/// Each community is drawn as a cluster subgraph, and its nodes are filled
+/// with the color of the community, taken in turn from a palette. The edges
+/// inside a community are drawn in its color, and the edges between
+/// communities are dashed, gray, and do not constrain the layout, so that
+/// `dot` ranks the nodes by the edges inside the communities. The largest
+/// communities are written first.
+///
+/// The communities are given as a label for each node, indexed by the
+/// graph's node indices (see [`NodeIndexable`]). Nodes with the label
+/// `usize::MAX` are in no community and are drawn outside of the clusters.
+///
+/// The [`Config`] options apply as for [`Dot`](super::Dot).
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::dot::CommunityDot;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph = UnGraph::<&str, u32>::from_edges([(0, 1, 1), (1, 2, 2), (2, 3, 3)]);
+/// let graph = graph.map(|i, _| ["a", "b", "c", "d"][i.index()], |_, &w| w);
+/// let labels = [0, 0, 1, 1];
+///
+/// println!("{}", CommunityDot::new(&graph, &labels));
+///
+/// // In this case the output looks like this:
+/// //
+/// // graph {
+/// //     subgraph cluster_0 {
+/// //         label = "0"
+/// //         color = "#8dd3c7"
+/// //         0 [ label = "a" style = "filled" fillcolor = "#8dd3c7" ]
+/// //         1 [ label = "b" style = "filled" fillcolor = "#8dd3c7" ]
+/// //     }
+/// //     subgraph cluster_1 {
+/// //         label = "1"
+/// //         color = "#ffffb3"
+/// //         2 [ label = "c" style = "filled" fillcolor = "#ffffb3" ]
+/// //         3 [ label = "d" style = "filled" fillcolor = "#ffffb3" ]
+/// //     }
+/// //     0 -- 1 [ label = "1" color = "#8dd3c7" ]
+/// //     1 -- 2 [ label = "2" style = "dashed" color = "gray50" constraint = "false" ]
+/// //     2 -- 3 [ label = "3" color = "#ffffb3" ]
+/// // }
+/// ```
+pub struct CommunityDot<'a, G> {
+    graph: G,
+    labels: &'a [usize],
+    palette: &'a [&'a str],
+    config: Configs,
+}
+
+impl<'a, G> CommunityDot<'a, G>
+where
+    G: NodeIndexable,
+{
+    /// Create a `CommunityDot` formatting wrapper with default
+    /// configuration.
+    ///
+    /// **Panics** if `labels` is shorter than the graph's node bound.
+    #[inline]
+    pub fn new(graph: G, labels: &'a [usize]) -> Self {
+        Self::with_config(graph, labels, &[])
+    }
+
+    /// Create a `CommunityDot` formatting wrapper with custom configuration.
+    ///
+    /// **Panics** if `labels` is shorter than the graph's node bound.
+    pub fn with_config(graph: G, labels: &'a [usize], config: &'a [Config]) -> Self {
+        assert!(
+            labels.len() >= graph.node_bound(),
+            "CommunityDot: every node index needs a label"
+        );
+        CommunityDot {
+            graph,
+            labels,
+            palette: &DEFAULT_PALETTE,
+            config: Configs::extract(config),
+        }
+    }
+
+    /// Use the colors of `palette`, in turn, for the communities instead of
+    /// [`DEFAULT_PALETTE`].
+    ///
+    /// The colors are written as they are, so any Graphviz color works, such
+    /// as `"#4477aa"` or `"lightblue"`.
+    ///
+    /// **Panics** if `palette` is empty.
+    pub fn with_palette(mut self, palette: &'a [&'a str]) -> Self {
+        assert!(!palette.is_empty(), "CommunityDot: the palette is empty");
+        self.palette = palette;
+        self
+    }
+}
+
+impl<G> CommunityDot<'_, G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    fn color(&self, label: usize) -> &str {
+        self.palette[label % self.palette.len()]
+    }
+
+    fn node_fmt<NF>(&self, f: &mut fmt::Formatter, node: G::NodeRef, node_fmt: &NF) -> fmt::Result
+    where
+        NF: Fn(&G::NodeWeight, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let index = self.graph.to_index(node.id());
+        write!(f, "{index} [ ")?;
+        if !self.config.NodeNoLabel {
+            write!(f, "label = \"")?;
+            if self.config.NodeIndexLabel {
+                write!(f, "{index}")?;
+            } else {
+                Escaped(FnFmt(node.weight(), node_fmt)).fmt(f)?;
+            }
+            write!(f, "\" ")?;
+        }
+        let label = self.labels[index];
+        if label != NONE {
+            write!(
+                f,
+                "style = \"filled\" fillcolor = \"{}\" ",
+                self.color(label)
+            )?;
+        }
+        writeln!(f, "]")
+    }
+
+    fn graph_fmt<NF, EF>(&self, f: &mut fmt::Formatter, node_fmt: NF, edge_fmt: EF) -> fmt::Result
+    where
+        NF: Fn(&G::NodeWeight, &mut fmt::Formatter) -> fmt::Result,
+        EF: Fn(&G::EdgeWeight, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let g = self.graph;
+        if !self.config.GraphContentOnly {
+            writeln!(f, "{} {{", TYPE[g.is_directed() as usize])?;
+        }
+
+        if let Some(rank_dir) = &self.config.RankDir {
+            let value = match rank_dir {
+                RankDir::TB => "TB",
+                RankDir::BT => "BT",
+                RankDir::LR => "LR",
+                RankDir::RL => "RL",
+            };
+            writeln!(f, "{INDENT}rankdir=\"{value}\"")?;
+        }
+
+        // group the nodes by community, the largest communities first
+        let mut members: Vec<Vec<G::NodeRef>> = Vec::new();
+        let mut outside = Vec::new();
+        for node in g.node_references() {
+            let label = self.labels[g.to_index(node.id())];
+            if label == NONE {
+                outside.push(node);
+                continue;
+            }
+            if label >= members.len() {
+                members.resize_with(label + 1, Vec::new);
+            }
+            members[label].push(node);
+        }
+        let mut order: Vec<usize> = (0..members.len())
+            .filter(|&label| !members[label].is_empty())
+            .collect();
+        order.sort_by_key(|&label| Reverse(members[label].len()));
+
+        for label in order {
+            writeln!(f, "{INDENT}subgraph cluster_{label} {{")?;
+            writeln!(f, "{INDENT}{INDENT}label = \"{label}\"")?;
+            writeln!(f, "{INDENT}{INDENT}color = \"{}\"", self.color(label))?;
+            for &node in &members[label] {
+                write!(f, "{INDENT}{INDENT}")?;
+                self.node_fmt(f, node, &node_fmt)?;
+            }
+            writeln!(f, "{INDENT}}}")?;
+        }
+        for node in outside {
+            write!(f, "{INDENT}")?;
+            self.node_fmt(f, node, &node_fmt)?;
+        }
+
+        for (i, edge) in g.edge_references().enumerate() {
+            let (source, target) = (g.to_index(edge.source()), g.to_index(edge.target()));
+            write!(
+                f,
+                "{}{} {} {} [ ",
+                INDENT,
+                source,
+                EDGE[g.is_directed() as usize],
+                target,
+            )?;
+            if !self.config.EdgeNoLabel {
+                write!(f, "label = \"")?;
+                if self.config.EdgeIndexLabel {
+                    write!(f, "{i}")?;
+                } else {
+                    Escaped(FnFmt(edge.weight(), &edge_fmt)).fmt(f)?;
+                }
+                write!(f, "\" ")?;
+            }
+            let label = self.labels[source];
+            if label != NONE && label == self.labels[target] {
+                writeln!(f, "color = \"{}\" ]", self.color(label))?;
+            } else {
+                writeln!(
+                    f,
+                    "style = \"dashed\" color = \"gray50\" constraint = \"false\" ]"
+                )?;
+            }
+        }
+
+        if !self.config.GraphContentOnly {
+            writeln!(f, "}}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<G> fmt::Display for CommunityDot<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    G::EdgeWeight: fmt::Display,
+    G::NodeWeight: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph_fmt(f, fmt::Display::fmt, fmt::Display::fmt)
+    }
+}
+
+impl<G> fmt::Debug for CommunityDot<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    G::EdgeWeight: fmt::Debug,
+    G::NodeWeight: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph_fmt(f, fmt::Debug::fmt, fmt::Debug::fmt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::format;
+
+    use super::CommunityDot;
+    use crate::dot::Config;
+    use crate::prelude::Graph;
+
+    #[test]
+    fn test_communities() {
+        let mut graph = Graph::<&str, &str>::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+        let c = graph.add_node("C");
+        let d = graph.add_node("D");
+        graph.add_edge(a, b, "ab");
+        graph.add_edge(c, d, "cd");
+        graph.add_edge(b, d, "bd");
+        graph.add_edge(a, c, "ac");
+        // the largest community comes first, and `A` is in none
+        let labels = [usize::MAX, 1, 0, 0];
+        let dot = format!(
+            "{}",
+            CommunityDot::with_config(&graph, &labels, &[Config::EdgeNoLabel])
+                .with_palette(&["red", "blue"])
+        );
+        assert_eq!(
+            dot,
+            "digraph {\n    \
+            subgraph cluster_0 {\n        label = \"0\"\n        color = \"red\"\n        \
+            2 [ label = \"C\" style = \"filled\" fillcolor = \"red\" ]\n        \
+            3 [ label = \"D\" style = \"filled\" fillcolor = \"red\" ]\n    }\n    \
+            subgraph cluster_1 {\n        label = \"1\"\n        color = \"blue\"\n        \
+            1 [ label = \"B\" style = \"filled\" fillcolor = \"blue\" ]\n    }\n    \
+            0 [ label = \"A\" ]\n    \
+            0 -> 1 [ style = \"dashed\" color = \"gray50\" constraint = \"false\" ]\n    \
+            2 -> 3 [ color = \"red\" ]\n    \
+            1 -> 3 [ style = \"dashed\" color = \"gray50\" constraint = \"false\" ]\n    \
+            0 -> 2 [ style = \"dashed\" color = \"gray50\" constraint = \"false\" ]\n}\n"
+        );
+    }
+}
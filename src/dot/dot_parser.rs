@@ -34,6 +34,11 @@ impl From<ParsingError> for DotParsingError {
 impl Error for DotParsingError {}
 
 /// This trait extends [Create] with a method to parse a graph from a dot string.
+///
+/// The weights of the graph are those of the `dot-parser` crate. To map the
+/// nodes and edges to weights of your own, or without this feature, use
+/// [`from_dot`](crate::dot::from_dot) instead; see the
+/// [module documentation](crate::dot) for how the two importers compare.
 pub trait ParseFromDot<'a>:
     Create<EdgeWeight = DotAttrList<'a>, NodeWeight = DotNodeWeight<'a>>
 {
@@ -1,6 +1,27 @@
-//! Simple graphviz dot file format output.
-
-use alloc::string::String;
+//! Simple graphviz dot file format output, and a parser for it.
+//!
+//! There are two ways to read DOT:
+//!
+//! - [`parse_dot`], [`from_dot`] and [`from_dot_labels`] are always
+//!   available, also without `std`. They read a document at runtime into a
+//!   [`DotGraph`] of owned strings, or into any graph type with weights
+//!   mapped by closures, and report errors with their line.
+//! - `dot_parser::ParseFromDot`, behind the `dot_parser` feature, builds on
+//!   the [`dot-parser`](https://docs.rs/dot-parser) crate. Its node and edge
+//!   weights borrow the attributes of the document, and its
+//!   `graph_from_str!` and `graph_from_file!` macros read a document at
+//!   compile time.
+//!
+//! Prefer the former to map the attributes to weights of your own, or
+//! without `std`, and the latter to embed a document in the program.
+//!
+//! The former is not built on the latter because the `dot-parser` crate
+//! needs `std` and a parser generator, which the default build of petgraph
+//! does not depend on, and because `ParseFromDot` fixes the weights of the
+//! graph to the types of that crate. The latter is kept for its
+//! compile-time macros, which the former has no equivalent of.
+
+use alloc::{string::String, vec, vec::Vec};
 use core::fmt::{self, Display, Write};
 
 use crate::visit::{
@@ -56,6 +77,11 @@ where
     get_edge_attributes: &'a dyn Fn(G, G::EdgeRef) -> String,
     get_node_attributes: &'a dyn Fn(G, G::NodeRef) -> String,
     config: Configs,
+    graph_attributes: &'a [(&'a str, &'a str)],
+    node_attribute_map: &'a dyn Fn(G, G::NodeRef) -> Vec<(String, String)>,
+    edge_attribute_map: &'a dyn Fn(G, G::EdgeRef) -> Vec<(String, String)>,
+    get_cluster: &'a dyn Fn(G, G::NodeRef) -> Option<String>,
+    get_rank: &'a dyn Fn(G, G::NodeRef) -> Option<usize>,
 }
 
 static TYPE: [&str; 2] = ["graph", "digraph"];
@@ -91,8 +117,85 @@ where
             get_edge_attributes,
             get_node_attributes,
             config,
+            graph_attributes: &[],
+            node_attribute_map: &|_, _| Vec::new(),
+            edge_attribute_map: &|_, _| Vec::new(),
+            get_cluster: &|_, _| None,
+            get_rank: &|_, _| None,
         }
     }
+
+    /// Set attributes of the graph itself, such as `splines` or `bgcolor`.
+    ///
+    /// The values are quoted and escaped, except HTML strings, which start
+    /// with `<` and end with `>`.
+    pub fn with_graph_attributes(mut self, attributes: &'a [(&'a str, &'a str)]) -> Self {
+        self.graph_attributes = attributes;
+        self
+    }
+
+    /// Set the attributes of each node, such as `shape`, `color` or
+    /// `label`, from the names and values returned by `attributes`.
+    ///
+    /// They come after the label and the attributes of
+    /// [`with_attr_getters`](Dot::with_attr_getters), so a `label` replaces
+    /// the label of the node's weight. The values are quoted and escaped,
+    /// except HTML strings, which start with `<` and end with `>`.
+    pub fn with_node_attributes(
+        mut self,
+        attributes: &'a dyn Fn(G, G::NodeRef) -> Vec<(String, String)>,
+    ) -> Self {
+        self.node_attribute_map = attributes;
+        self
+    }
+
+    /// Set the attributes of each edge, such as `color`, `style` or
+    /// `label`, from the names and values returned by `attributes`.
+    ///
+    /// They come after the label and the attributes of
+    /// [`with_attr_getters`](Dot::with_attr_getters), so a `label` replaces
+    /// the label of the edge's weight. The values are quoted and escaped,
+    /// except HTML strings, which start with `<` and end with `>`.
+    pub fn with_edge_attributes(
+        mut self,
+        attributes: &'a dyn Fn(G, G::EdgeRef) -> Vec<(String, String)>,
+    ) -> Self {
+        self.edge_attribute_map = attributes;
+        self
+    }
+
+    /// Group the nodes into clusters: the nodes for which `cluster` returns
+    /// the same name are drawn together, in a box labeled with the name.
+    ///
+    /// The clusters are written in the order of their first node, and the
+    /// nodes for which `cluster` returns `None` outside of any cluster.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::dot::{Config, Dot};
+    /// use petgraph::graph::DiGraph;
+    ///
+    /// let graph = DiGraph::<&str, ()>::from_edges(&[(0, 1), (1, 2)]);
+    /// let dot = Dot::with_config(&graph, &[Config::NodeIndexLabel, Config::EdgeNoLabel])
+    ///     .with_node_attributes(&|_, _| vec![("shape".into(), "box".into())])
+    ///     .with_clusters(&|_, (i, _)| (i.index() > 0).then(|| "tail".into()));
+    /// assert!(format!("{:?}", dot).contains("subgraph cluster_0 {"));
+    /// ```
+    pub fn with_clusters(mut self, cluster: &'a dyn Fn(G, G::NodeRef) -> Option<String>) -> Self {
+        self.get_cluster = cluster;
+        self
+    }
+
+    /// Constrain the nodes for which `rank` returns the same number to the
+    /// same rank of the layout, such as the same row for a top to bottom
+    /// layout.
+    ///
+    /// The numbers only group the nodes: the order of the ranks is given by
+    /// the edges.
+    pub fn with_ranks(mut self, rank: &'a dyn Fn(G, G::NodeRef) -> Option<usize>) -> Self {
+        self.get_rank = rank;
+        self
+    }
 }
 
 /// Direction of graph layout.
@@ -184,19 +287,47 @@ where
             writeln!(f, "{INDENT}rankdir=\"{value}\"")?;
         }
 
-        // output all labels
+        for &(name, value) in self.graph_attributes {
+            write!(f, "{INDENT}")?;
+            write_attribute(f, name, value)?;
+            writeln!(f)?;
+        }
+
+        // output all labels, with the nodes of each cluster together
+        let mut clusters: Vec<(String, Vec<G::NodeRef>)> = Vec::new();
+        let mut ranks: Vec<(usize, Vec<usize>)> = Vec::new();
         for node in g.node_references() {
-            write!(f, "{}{} [ ", INDENT, g.to_index(node.id()),)?;
-            if !self.config.NodeNoLabel {
-                write!(f, "label = \"")?;
-                if self.config.NodeIndexLabel {
-                    write!(f, "{}", g.to_index(node.id()))?;
-                } else {
-                    Escaped(FnFmt(node.weight(), &node_fmt)).fmt(f)?;
+            if let Some(rank) = (self.get_rank)(g, node) {
+                let index = g.to_index(node.id());
+                match ranks.iter_mut().find(|(r, _)| *r == rank) {
+                    Some((_, nodes)) => nodes.push(index),
+                    None => ranks.push((rank, vec![index])),
                 }
-                write!(f, "\" ")?;
             }
-            writeln!(f, "{}]", (self.get_node_attributes)(g, node))?;
+            match (self.get_cluster)(g, node) {
+                Some(name) => match clusters.iter_mut().find(|(n, _)| *n == name) {
+                    Some((_, nodes)) => nodes.push(node),
+                    None => clusters.push((name, vec![node])),
+                },
+                None => self.node_fmt(f, INDENT, node, &node_fmt)?,
+            }
+        }
+        for (i, (name, nodes)) in clusters.into_iter().enumerate() {
+            writeln!(f, "{INDENT}subgraph cluster_{i} {{")?;
+            write!(f, "{INDENT}{INDENT}")?;
+            write_attribute(f, "label", &name)?;
+            writeln!(f)?;
+            for node in nodes {
+                self.node_fmt(f, "        ", node, &node_fmt)?;
+            }
+            writeln!(f, "{INDENT}}}")?;
+        }
+        for (_, nodes) in ranks {
+            write!(f, "{INDENT}{{ rank = same;")?;
+            for index in nodes {
+                write!(f, " {index};")?;
+            }
+            writeln!(f, " }}")?;
         }
         // output all edges
         for (i, edge) in g.edge_references().enumerate() {
@@ -217,7 +348,9 @@ where
                 }
                 write!(f, "\" ")?;
             }
-            writeln!(f, "{}]", (self.get_edge_attributes)(g, edge))?;
+            let getter = (self.get_edge_attributes)(g, edge);
+            write_attributes(f, &getter, &(self.edge_attribute_map)(g, edge))?;
+            writeln!(f, "]")?;
         }
 
         if !self.config.GraphContentOnly {
@@ -225,6 +358,66 @@ where
         }
         Ok(())
     }
+
+    /// Write the statement of a node, with its label and attributes.
+    fn node_fmt<NF>(
+        &self,
+        f: &mut fmt::Formatter,
+        indent: &str,
+        node: G::NodeRef,
+        node_fmt: &NF,
+    ) -> fmt::Result
+    where
+        NF: Fn(&G::NodeWeight, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let g = self.graph;
+        write!(f, "{}{} [ ", indent, g.to_index(node.id()),)?;
+        if !self.config.NodeNoLabel {
+            write!(f, "label = \"")?;
+            if self.config.NodeIndexLabel {
+                write!(f, "{}", g.to_index(node.id()))?;
+            } else {
+                Escaped(FnFmt(node.weight(), node_fmt)).fmt(f)?;
+            }
+            write!(f, "\" ")?;
+        }
+        let getter = (self.get_node_attributes)(g, node);
+        write_attributes(f, &getter, &(self.node_attribute_map)(g, node))?;
+        writeln!(f, "]")
+    }
+}
+
+/// Write an attribute as `name = "value"`, or `name = <value>` for an HTML
+/// string.
+fn write_attribute(f: &mut fmt::Formatter, name: &str, value: &str) -> fmt::Result {
+    if name.chars().all(|c| c.is_alphanumeric() || c == '_') && !name.is_empty() {
+        write!(f, "{name} = ")?;
+    } else {
+        write!(f, "\"{}\" = ", Escaped(name))?;
+    }
+    if value.starts_with('<') && value.ends_with('>') {
+        write!(f, "{value}")
+    } else {
+        write!(f, "\"{}\"", Escaped(value))
+    }
+}
+
+/// Write the attributes of an attribute getter, then the attributes of an
+/// attribute map, each followed by a space.
+fn write_attributes(
+    f: &mut fmt::Formatter,
+    getter: &str,
+    attributes: &[(String, String)],
+) -> fmt::Result {
+    f.write_str(getter)?;
+    if !getter.is_empty() && !getter.ends_with(' ') && !attributes.is_empty() {
+        f.write_str(" ")?;
+    }
+    for (name, value) in attributes {
+        write_attribute(f, name, value)?;
+        write!(f, " ")?;
+    }
+    Ok(())
 }
 
 impl<G> fmt::Display for Dot<'_, G>
@@ -324,13 +517,18 @@ where
     }
 }
 
+pub mod community;
 #[cfg(feature = "dot_parser")]
 #[macro_use]
 pub mod dot_parser;
+mod parse;
+
+pub use community::CommunityDot;
+pub use parse::{from_dot, from_dot_labels, parse_dot, DotEdge, DotError, DotGraph, DotNode};
 
 #[cfg(test)]
 mod test {
-    use alloc::{format, string::String};
+    use alloc::{format, string::String, vec};
     use core::fmt::Write;
 
     use super::{Config, Dot, Escaper, RankDir};
@@ -456,4 +654,41 @@ mod test {
         );
         assert_eq!(dot, "digraph {\n    0 [ label = \"a\"]\n    1 [ label = \"b\"]\n    0 -> 1 [ label = \"EDGE_LABEL\"]\n}\n");
     }
+
+    #[test]
+    fn test_attribute_maps() {
+        let graph = simple_graph();
+        let dot = format!(
+            "{:?}",
+            Dot::with_attr_getters(&graph, &[], &|_, _| String::new(), &|_, _| "color = red"
+                .into())
+            .with_graph_attributes(&[("bgcolor", "gray"), ("label", "<<b>G</b>>")])
+            .with_node_attributes(&|_, nr| vec![("shape".into(), format!("{}\"box", nr.weight()))])
+            .with_edge_attributes(&|_, _| vec![("arrow head".into(), "none".into())]),
+        );
+        assert_eq!(
+            dot,
+            "digraph {\n    bgcolor = \"gray\"\n    label = <<b>G</b>>\n    0 [ label = \"\\\"A\\\"\" color = red shape = \"A\\\"box\" ]\n    1 [ label = \"\\\"B\\\"\" color = red shape = \"B\\\"box\" ]\n    0 -> 1 [ label = \"\\\"edge_label\\\"\" \"arrow head\" = \"none\" ]\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_clusters_and_ranks() {
+        let mut graph = Graph::<&str, &str>::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.extend_with_edges([(a, b, ""), (b, c, ""), (c, d, "")]);
+        let dot = format!(
+            "{}",
+            Dot::with_config(&graph, &[Config::EdgeNoLabel])
+                .with_clusters(&|_, (i, _)| (i.index() % 2 == 1).then(|| "odd".into()))
+                .with_ranks(&|_, (i, _)| (i != a).then(|| i.index() / 2)),
+        );
+        assert_eq!(
+            dot,
+            "digraph {\n    0 [ label = \"a\" ]\n    2 [ label = \"c\" ]\n    subgraph cluster_0 {\n        label = \"odd\"\n        1 [ label = \"b\" ]\n        3 [ label = \"d\" ]\n    }\n    { rank = same; 1; }\n    { rank = same; 2; 3; }\n    0 -> 1 [ ]\n    1 -> 2 [ ]\n    2 -> 3 [ ]\n}\n"
+        );
+    }
 }
@@ -0,0 +1,744 @@
+//! A parser for the [DOT language](https://graphviz.org/doc/info/lang.html)
+//! of Graphviz.
+//!
+//! Unlike `dot_parser::ParseFromDot`, it has no dependency, so it is always
+//! compiled; see the [module documentation](super) for which to pick.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::data::Create;
+use crate::visit::GraphProp;
+
+/// A graph read from DOT by [`parse_dot`].
+///
+/// Subgraphs are flattened: their nodes and edges are those of the graph,
+/// and the default attributes they set apply to the nodes and edges
+/// created in them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DotGraph {
+    /// `true` for a `digraph`, `false` for a `graph`.
+    pub directed: bool,
+    /// `true` for a `strict` graph, whose parallel edges are merged.
+    pub strict: bool,
+    /// The name of the graph, if it has one.
+    pub name: Option<String>,
+    /// The attributes of the graph itself.
+    pub attributes: Vec<(String, String)>,
+    /// The nodes, in the order of their first appearance.
+    pub nodes: Vec<DotNode>,
+    /// The edges, in the order of the document.
+    pub edges: Vec<DotEdge>,
+}
+
+/// A node of a [`DotGraph`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DotNode {
+    /// The id of the node.
+    pub id: String,
+    /// The attributes of the node, including the defaults that applied when
+    /// it was created.
+    pub attributes: Vec<(String, String)>,
+}
+
+/// An edge of a [`DotGraph`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DotEdge {
+    /// The position of the source node in [`DotGraph::nodes`].
+    pub source: usize,
+    /// The position of the target node in [`DotGraph::nodes`].
+    pub target: usize,
+    /// The attributes of the edge, including the defaults that applied.
+    pub attributes: Vec<(String, String)>,
+}
+
+impl DotNode {
+    /// Return the value of the attribute `name`, if it is set.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        get(&self.attributes, name)
+    }
+}
+
+impl DotEdge {
+    /// Return the value of the attribute `name`, if it is set.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        get(&self.attributes, name)
+    }
+}
+
+fn get<'a>(attributes: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Set the attributes `new` in `attributes`, replacing the values of those
+/// already set.
+fn merge(attributes: &mut Vec<(String, String)>, new: &[(String, String)]) {
+    for (key, value) in new {
+        match attributes.iter_mut().find(|(k, _)| k == key) {
+            Some((_, old)) => old.clone_from(value),
+            None => attributes.push((key.clone(), value.clone())),
+        }
+    }
+}
+
+/// An error while reading a DOT document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DotError {
+    /// The document is not valid DOT, at the line.
+    Syntax {
+        /// The line of the error, from `1`.
+        line: usize,
+        /// A description of the error.
+        message: &'static str,
+    },
+    /// The document describes a directed graph and the graph type is
+    /// undirected, or the reverse.
+    Directedness {
+        /// Whether the document describes a directed graph.
+        directed: bool,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DotError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for DotError {}
+
+impl fmt::Display for DotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DotError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            DotError::Directedness { directed: true } => {
+                write!(f, "a directed DOT graph for an undirected graph type")
+            }
+            DotError::Directedness { directed: false } => {
+                write!(f, "an undirected DOT graph for a directed graph type")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// An id, and whether it was quoted, so that it is not a keyword.
+    Id(String, bool),
+    Open,
+    Close,
+    OpenList,
+    CloseList,
+    Semicolon,
+    Comma,
+    Colon,
+    Equals,
+    /// An edge operator, `->` if `true` and `--` if `false`.
+    EdgeOp(bool),
+}
+
+struct Lexer<'a> {
+    input: &'a str,
+    position: usize,
+    peeked: Option<(Token, usize)>,
+}
+
+impl<'a> Lexer<'a> {
+    fn line(&self, position: usize) -> usize {
+        1 + self.input.as_bytes()[..position]
+            .iter()
+            .filter(|&&b| b == b'\n')
+            .count()
+    }
+
+    fn error(&self, message: &'static str) -> DotError {
+        let position = self.peeked.as_ref().map_or(self.position, |&(_, p)| p);
+        DotError::Syntax {
+            line: self.line(position),
+            message,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    /// Skip whitespace, comments and preprocessor lines.
+    fn skip(&mut self) -> Result<(), DotError> {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.position += rest.len() - trimmed.len();
+            let at_line_start = || {
+                self.input[..self.position]
+                    .chars()
+                    .rev()
+                    .take_while(|&c| c != '\n')
+                    .all(char::is_whitespace)
+            };
+            let end = if trimmed.starts_with("//") || (trimmed.starts_with('#') && at_line_start())
+            {
+                trimmed.find('\n').unwrap_or(trimmed.len())
+            } else if let Some(comment) = trimmed.strip_prefix("/*") {
+                let end = comment
+                    .find("*/")
+                    .ok_or_else(|| self.error("unterminated comment"))?;
+                end + 4
+            } else {
+                return Ok(());
+            };
+            self.position += end;
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<&Token>, DotError> {
+        if self.peeked.is_none() {
+            self.skip()?;
+            let start = self.position;
+            if let Some(token) = self.token()? {
+                self.peeked = Some((token, start));
+            }
+        }
+        Ok(self.peeked.as_ref().map(|(token, _)| token))
+    }
+
+    fn next(&mut self) -> Result<Option<Token>, DotError> {
+        self.peek()?;
+        Ok(self.peeked.take().map(|(token, _)| token))
+    }
+
+    /// Consume the next token if it is `token`.
+    fn eat(&mut self, token: &Token) -> Result<bool, DotError> {
+        if self.peek()? == Some(token) {
+            self.peeked = None;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn expect(&mut self, token: &Token, message: &'static str) -> Result<(), DotError> {
+        if self.eat(token)? {
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    /// Consume the next token if it is the unquoted keyword `keyword`.
+    fn eat_keyword(&mut self, keyword: &str) -> Result<bool, DotError> {
+        if let Some(Token::Id(id, false)) = self.peek()? {
+            if id.eq_ignore_ascii_case(keyword) {
+                self.peeked = None;
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn id(&mut self, message: &'static str) -> Result<String, DotError> {
+        match self.peek()? {
+            Some(Token::Id(..)) => match self.next()? {
+                Some(Token::Id(id, _)) => Ok(id),
+                _ => unreachable!(),
+            },
+            _ => Err(self.error(message)),
+        }
+    }
+
+    /// Read the token at the current position.
+    fn token(&mut self) -> Result<Option<Token>, DotError> {
+        let rest = self.rest();
+        let c = match rest.chars().next() {
+            Some(c) => c,
+            None => return Ok(None),
+        };
+        let single = match c {
+            '{' => Some(Token::Open),
+            '}' => Some(Token::Close),
+            '[' => Some(Token::OpenList),
+            ']' => Some(Token::CloseList),
+            ';' => Some(Token::Semicolon),
+            ',' => Some(Token::Comma),
+            ':' => Some(Token::Colon),
+            '=' => Some(Token::Equals),
+            _ => None,
+        };
+        if let Some(token) = single {
+            self.position += 1;
+            return Ok(Some(token));
+        }
+        if rest.starts_with("->") || rest.starts_with("--") {
+            self.position += 2;
+            return Ok(Some(Token::EdgeOp(rest.starts_with("->"))));
+        }
+        if c == '"' {
+            let mut id = self.quoted()?;
+            // Quoted strings can be concatenated with `+`.
+            loop {
+                let saved = self.position;
+                self.skip()?;
+                if self.rest().starts_with('+') {
+                    self.position += 1;
+                    self.skip()?;
+                    if self.rest().starts_with('"') {
+                        id.push_str(&self.quoted()?);
+                        continue;
+                    }
+                    return Err(self.error("expected a quoted string after '+'"));
+                }
+                self.position = saved;
+                return Ok(Some(Token::Id(id, true)));
+            }
+        }
+        if c == '<' {
+            let mut depth = 0;
+            for (i, c) in rest.char_indices() {
+                match c {
+                    '<' => depth += 1,
+                    '>' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            self.position += i + 1;
+                            return Ok(Some(Token::Id(rest[..=i].into(), true)));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            return Err(self.error("unterminated HTML string"));
+        }
+        let is_id_char = |c: char| c.is_alphanumeric() || c == '_' || c == '.' || !c.is_ascii();
+        let start = usize::from(c == '-');
+        let end = rest[start..]
+            .find(|c: char| !is_id_char(c))
+            .map_or(rest.len(), |end| start + end);
+        if end == start {
+            return Err(self.error("unexpected character"));
+        }
+        self.position += end;
+        Ok(Some(Token::Id(rest[..end].into(), false)))
+    }
+
+    /// Read a double quoted string, with its escaped quotes, backslashes
+    /// and newlines decoded.
+    fn quoted(&mut self) -> Result<String, DotError> {
+        let rest = &self.rest()[1..];
+        let mut id = String::new();
+        let mut chars = rest.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.position += 1 + i + 1;
+                    return Ok(id);
+                }
+                '\\' => match chars.next() {
+                    Some((_, '"')) => id.push('"'),
+                    Some((_, '\\')) => id.push('\\'),
+                    Some((_, '\n')) => {}
+                    Some((_, '\r')) => {
+                        if rest[i + 2..].starts_with('\n') {
+                            chars.next();
+                        }
+                    }
+                    Some((_, other)) => {
+                        id.push('\\');
+                        id.push(other);
+                    }
+                    None => break,
+                },
+                _ => id.push(c),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+}
+
+/// The deepest nesting of subgraphs that is read, so that a document does
+/// not overflow the stack.
+const MAX_DEPTH: usize = 256;
+
+/// The default attributes of a scope.
+#[derive(Clone, Default)]
+struct Defaults {
+    node: Vec<(String, String)>,
+    edge: Vec<(String, String)>,
+}
+
+struct Parser<'a> {
+    lexer: Lexer<'a>,
+    graph: DotGraph,
+    indices: HashMap<String, usize>,
+    /// The edges of a strict graph, by their ends.
+    edge_indices: HashMap<(usize, usize), usize>,
+    /// The number of subgraphs being read.
+    depth: usize,
+}
+
+/// The nodes mentioned by the statements of a subgraph, in order and
+/// without repetition.
+#[derive(Default)]
+struct Mentioned {
+    nodes: Vec<usize>,
+    seen: HashSet<usize>,
+}
+
+impl Mentioned {
+    fn extend(&mut self, nodes: &[usize]) {
+        for &node in nodes {
+            if self.seen.insert(node) {
+                self.nodes.push(node);
+            }
+        }
+    }
+}
+
+impl Parser<'_> {
+    fn graph(&mut self) -> Result<(), DotError> {
+        self.graph.strict = self.lexer.eat_keyword("strict")?;
+        self.graph.directed = if self.lexer.eat_keyword("digraph")? {
+            true
+        } else if self.lexer.eat_keyword("graph")? {
+            false
+        } else {
+            return Err(self.lexer.error("expected 'graph' or 'digraph'"));
+        };
+        if let Some(Token::Id(..)) = self.lexer.peek()? {
+            self.graph.name = Some(self.lexer.id("")?);
+        }
+        self.lexer.expect(&Token::Open, "expected '{'")?;
+        self.statements(Defaults::default(), true)?;
+        if self.lexer.next()?.is_some() {
+            return Err(self.lexer.error("unexpected content after the graph"));
+        }
+        Ok(())
+    }
+
+    /// Parse the statements of the graph or a subgraph until its closing
+    /// brace, and return the nodes they mention.
+    fn statements(&mut self, mut defaults: Defaults, top: bool) -> Result<Vec<usize>, DotError> {
+        let mut mentioned = Mentioned::default();
+        loop {
+            if self.lexer.eat(&Token::Close)? {
+                return Ok(mentioned.nodes);
+            }
+            if self.lexer.peek()?.is_none() {
+                return Err(self.lexer.error("expected '}'"));
+            }
+            self.statement(&mut defaults, top, &mut mentioned)?;
+            self.lexer.eat(&Token::Semicolon)?;
+        }
+    }
+
+    fn statement(
+        &mut self,
+        defaults: &mut Defaults,
+        top: bool,
+        mentioned: &mut Mentioned,
+    ) -> Result<(), DotError> {
+        for (keyword, is_graph) in [("graph", true), ("node", false), ("edge", false)] {
+            if self.lexer.eat_keyword(keyword)? {
+                let attributes = self.attributes()?;
+                if is_graph {
+                    if top {
+                        merge(&mut self.graph.attributes, &attributes);
+                    }
+                } else if keyword == "node" {
+                    merge(&mut defaults.node, &attributes);
+                } else {
+                    merge(&mut defaults.edge, &attributes);
+                }
+                return Ok(());
+            }
+        }
+
+        let first = match self.lexer.peek()? {
+            Some(Token::Id(id, quoted)) if *quoted || !id.eq_ignore_ascii_case("subgraph") => {
+                let id = self.lexer.id("")?;
+                if self.lexer.eat(&Token::Equals)? {
+                    let value = self.lexer.id("expected a value after '='")?;
+                    if top {
+                        merge(&mut self.graph.attributes, &[(id, value)]);
+                    }
+                    return Ok(());
+                }
+                self.port()?;
+                let node = self.node(id, defaults);
+                if !matches!(self.lexer.peek()?, Some(Token::EdgeOp(_))) {
+                    let attributes = self.attributes()?;
+                    merge(&mut self.graph.nodes[node].attributes, &attributes);
+                }
+                alloc::vec![node]
+            }
+            _ => self.subgraph(defaults)?,
+        };
+        mentioned.extend(&first);
+
+        let mut ends = alloc::vec![first];
+        while let Some(Token::EdgeOp(directed)) = self.lexer.peek()? {
+            if *directed != self.graph.directed {
+                return Err(self.lexer.error(if self.graph.directed {
+                    "'--' in a directed graph"
+                } else {
+                    "'->' in an undirected graph"
+                }));
+            }
+            self.lexer.next()?;
+            let next = match self.lexer.peek()? {
+                Some(Token::Id(id, quoted)) if *quoted || !id.eq_ignore_ascii_case("subgraph") => {
+                    let id = self.lexer.id("")?;
+                    self.port()?;
+                    alloc::vec![self.node(id, defaults)]
+                }
+                Some(Token::Id(..)) | Some(Token::Open) => self.subgraph(defaults)?,
+                _ => return Err(self.lexer.error("expected a node or a subgraph")),
+            };
+            mentioned.extend(&next);
+            ends.push(next);
+        }
+        if ends.len() > 1 {
+            let mut attributes = defaults.edge.clone();
+            merge(&mut attributes, &self.attributes()?);
+            for pair in ends.windows(2) {
+                for &source in &pair[0] {
+                    for &target in &pair[1] {
+                        self.edge(source, target, &attributes);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse a subgraph, with or without the `subgraph` keyword and name,
+    /// and return the nodes it mentions.
+    fn subgraph(&mut self, defaults: &Defaults) -> Result<Vec<usize>, DotError> {
+        if self.lexer.eat_keyword("subgraph")? {
+            if let Some(Token::Id(..)) = self.lexer.peek()? {
+                self.lexer.id("")?;
+            }
+        }
+        self.lexer.expect(&Token::Open, "expected a statement")?;
+        if self.depth == MAX_DEPTH {
+            return Err(self.lexer.error("subgraphs nested too deeply"));
+        }
+        self.depth += 1;
+        let mentioned = self.statements(defaults.clone(), false);
+        self.depth -= 1;
+        mentioned
+    }
+
+    /// Skip the port of a node, which is ignored.
+    fn port(&mut self) -> Result<(), DotError> {
+        for _ in 0..2 {
+            if !self.lexer.eat(&Token::Colon)? {
+                break;
+            }
+            self.lexer.id("expected a port")?;
+        }
+        Ok(())
+    }
+
+    /// Parse any number of attribute lists.
+    fn attributes(&mut self) -> Result<Vec<(String, String)>, DotError> {
+        let mut attributes = Vec::new();
+        while self.lexer.eat(&Token::OpenList)? {
+            while !self.lexer.eat(&Token::CloseList)? {
+                let key = self.lexer.id("expected an attribute name")?;
+                self.lexer.expect(&Token::Equals, "expected '='")?;
+                let value = self.lexer.id("expected an attribute value")?;
+                merge(&mut attributes, &[(key, value)]);
+                if !self.lexer.eat(&Token::Comma)? {
+                    self.lexer.eat(&Token::Semicolon)?;
+                }
+            }
+        }
+        Ok(attributes)
+    }
+
+    /// Return the node `id`, created with the default attributes if it is
+    /// new.
+    fn node(&mut self, id: String, defaults: &Defaults) -> usize {
+        let nodes = &mut self.graph.nodes;
+        *self.indices.entry(id).or_insert_with_key(|id| {
+            nodes.push(DotNode {
+                id: id.clone(),
+                attributes: defaults.node.clone(),
+            });
+            nodes.len() - 1
+        })
+    }
+
+    fn edge(&mut self, source: usize, target: usize, attributes: &[(String, String)]) {
+        if self.graph.strict {
+            let key = if self.graph.directed || source <= target {
+                (source, target)
+            } else {
+                (target, source)
+            };
+            if let Some(&index) = self.edge_indices.get(&key) {
+                merge(&mut self.graph.edges[index].attributes, attributes);
+                return;
+            }
+            self.edge_indices.insert(key, self.graph.edges.len());
+        }
+        self.graph.edges.push(DotEdge {
+            source,
+            target,
+            attributes: attributes.to_vec(),
+        });
+    }
+}
+
+/// Read a graph in the [DOT language][1] of Graphviz.
+///
+/// The whole language is supported, with these simplifications:
+///
+/// - Subgraphs, including clusters, are flattened. An edge to or from a
+///   subgraph links every node of the subgraph.
+/// - Ports are ignored, and the edges attach to their nodes.
+/// - The attributes of subgraphs are ignored, except the default node and
+///   edge attributes they set for their own statements.
+/// - Quoted strings have their escaped quotes, backslashes and line
+///   continuations decoded, but other escape sequences, such as `\n` in a
+///   label, are kept as they are. HTML strings keep their angle brackets.
+///
+/// The `dot_parser` feature provides another importer, `ParseFromDot`; see
+/// the [module documentation](crate::dot) for how the two compare.
+///
+/// [1]: https://graphviz.org/doc/info/lang.html
+///
+/// # Example
+/// ```rust
+/// use petgraph::dot::parse_dot;
+///
+/// let dot = parse_dot(r#"
+///     digraph G {
+///         node [shape = box];
+///         a -> { b c } [color = red];
+///         b [label = "B\nnode"];
+///     }
+/// "#).unwrap();
+///
+/// assert!(dot.directed);
+/// assert_eq!(dot.nodes.len(), 3);
+/// assert_eq!(dot.nodes[1].attribute("label"), Some(r"B\nnode"));
+/// assert_eq!(dot.nodes[2].attribute("shape"), Some("box"));
+/// assert_eq!(dot.edges.len(), 2);
+/// assert_eq!(dot.edges[1].attribute("color"), Some("red"));
+/// ```
+pub fn parse_dot(input: &str) -> Result<DotGraph, DotError> {
+    let mut parser = Parser {
+        lexer: Lexer {
+            input,
+            position: 0,
+            peeked: None,
+        },
+        graph: DotGraph::default(),
+        indices: HashMap::new(),
+        edge_indices: HashMap::new(),
+        depth: 0,
+    };
+    parser.graph()?;
+    Ok(parser.graph)
+}
+
+/// \[Generic\] Read a graph in the DOT language of Graphviz, with the node
+/// and edge weights given by `node_weight` and `edge_weight`.
+///
+/// The document is read by [`parse_dot`], and the graph gets its nodes in
+/// order, so that with [`Graph`](crate::graph::Graph) node `i` of the
+/// [`DotGraph`] has index `i`, and then its edges.
+///
+/// # Arguments
+/// * `input`: the DOT document.
+/// * `node_weight`: the weight of a node, from its id and attributes.
+/// * `edge_weight`: the weight of an edge, from its attributes.
+///
+/// # Returns
+/// * The graph, or an error if the document is not valid DOT, or if it is
+///   directed and the graph is not, or the reverse.
+///
+/// # Example
+/// ```rust
+/// use petgraph::dot::from_dot;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<String, f64> = from_dot(
+///     "graph { a -- b [weight = 2.5]; b -- c }",
+///     |node| node.id,
+///     |edge| edge.attribute("weight").and_then(|w| w.parse().ok()).unwrap_or(1.0),
+/// )
+/// .unwrap();
+/// assert_eq!(graph.node_weights().collect::<Vec<_>>(), ["a", "b", "c"]);
+/// assert_eq!(graph.edge_weights().collect::<Vec<_>>(), [&2.5, &1.0]);
+/// ```
+pub fn from_dot<G, NF, EF>(
+    input: &str,
+    mut node_weight: NF,
+    mut edge_weight: EF,
+) -> Result<G, DotError>
+where
+    G: Create + GraphProp,
+    NF: FnMut(DotNode) -> G::NodeWeight,
+    EF: FnMut(DotEdge) -> G::EdgeWeight,
+{
+    let dot = parse_dot(input)?;
+    let mut graph = G::with_capacity(dot.nodes.len(), dot.edges.len());
+    if graph.is_directed() != dot.directed {
+        return Err(DotError::Directedness {
+            directed: dot.directed,
+        });
+    }
+    let nodes: Vec<G::NodeId> = dot
+        .nodes
+        .into_iter()
+        .map(|node| graph.add_node(node_weight(node)))
+        .collect();
+    for edge in dot.edges {
+        let (a, b) = (nodes[edge.source], nodes[edge.target]);
+        graph.add_edge(a, b, edge_weight(edge));
+    }
+    Ok(graph)
+}
+
+/// \[Generic\] Read a graph in the DOT language of Graphviz, with the
+/// labels of the nodes and edges as weights.
+///
+/// The weight of a node is its `label` attribute, or its id if it has none,
+/// and the weight of an edge is its `label` attribute, or an empty string.
+/// This reads back the output of [`Dot`](super::Dot) for a graph whose
+/// labels do not contain newlines.
+///
+/// # Example
+/// ```rust
+/// use petgraph::dot::{from_dot_labels, Dot};
+/// use petgraph::graph::DiGraph;
+///
+/// let mut graph = DiGraph::<String, String>::new();
+/// let a = graph.add_node("a \"quoted\" \\ label".into());
+/// let b = graph.add_node("b".into());
+/// graph.add_edge(a, b, "a to b".into());
+///
+/// let parsed: DiGraph<String, String> = from_dot_labels(&Dot::new(&graph).to_string()).unwrap();
+/// assert_eq!(parsed.node_weights().collect::<Vec<_>>(), graph.node_weights().collect::<Vec<_>>());
+/// assert_eq!(parsed.edge_weights().collect::<Vec<_>>(), ["a to b"]);
+/// ```
+pub fn from_dot_labels<G>(input: &str) -> Result<G, DotError>
+where
+    G: Create<NodeWeight = String, EdgeWeight = String> + GraphProp,
+{
+    from_dot(
+        input,
+        |node| {
+            node.attribute("label")
+                .map_or(node.id.clone(), ToString::to_string)
+        },
+        |edge| edge.attribute("label").unwrap_or_default().into(),
+    )
+}
@@ -0,0 +1,272 @@
+//! Deterministic families of graphs.
+//!
+//! For a directed graph, every edge goes from the lower to the higher node,
+//! except where noted.
+
+use alloc::vec::Vec;
+
+use super::from_pairs;
+use crate::data::Create;
+use crate::visit::GraphProp;
+use crate::EdgeType;
+
+/// \[Generic\] Generate the complete graph on `n` nodes: every pair of
+/// distinct nodes is adjacent.
+///
+/// For a directed graph, both orientations of every pair are edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::complete_graph;
+/// use petgraph::graph::{DiGraph, UnGraph};
+///
+/// let graph: UnGraph<(), ()> = complete_graph(5);
+/// assert_eq!(graph.edge_count(), 10);
+/// let graph: DiGraph<(), ()> = complete_graph(5);
+/// assert_eq!(graph.edge_count(), 20);
+/// ```
+pub fn complete_graph<G>(n: usize) -> G
+where
+    G: Create + GraphProp,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let directed = G::EdgeType::is_directed();
+    let edges = (0..n).flat_map(move |a| {
+        let start = if directed { 0 } else { a + 1 };
+        (start..n).filter(move |&b| b != a).map(move |b| (a, b))
+    });
+    from_pairs(n, edges)
+}
+
+/// \[Generic\] Generate the complete bipartite graph **K(m, n)**: the nodes
+/// `0..m` and `m..m + n` form the two parts, and every node of the first
+/// part is adjacent to every node of the second.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::is_bipartite_undirected;
+/// use petgraph::generators::complete_bipartite_graph;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let graph: UnGraph<(), ()> = complete_bipartite_graph(2, 3);
+/// assert_eq!((graph.node_count(), graph.edge_count()), (5, 6));
+/// assert!(is_bipartite_undirected(&graph, NodeIndex::new(0)));
+/// ```
+pub fn complete_bipartite_graph<G>(m: usize, n: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let edges = (0..m).flat_map(move |a| (m..m + n).map(move |b| (a, b)));
+    from_pairs(m + n, edges)
+}
+
+/// \[Generic\] Generate the path graph on `n` nodes, with an edge from each
+/// node `i` to `i + 1`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::path_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> = path_graph(4);
+/// assert_eq!(graph.edge_count(), 3);
+/// ```
+pub fn path_graph<G>(n: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    from_pairs(n, (1..n).map(|i| (i - 1, i)))
+}
+
+/// \[Generic\] Generate the cycle graph on `n` nodes: the path graph with an
+/// edge from the last node back to node `0`.
+///
+/// With fewer than three nodes, the closing edge would be a self loop or
+/// duplicate the single edge of the path, and is left out.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::is_cyclic_directed;
+/// use petgraph::generators::cycle_graph;
+/// use petgraph::graph::DiGraph;
+///
+/// let graph: DiGraph<(), ()> = cycle_graph(6);
+/// assert_eq!(graph.edge_count(), 6);
+/// assert!(is_cyclic_directed(&graph));
+/// ```
+pub fn cycle_graph<G>(n: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let closing = (n >= 3).then(|| (n - 1, 0));
+    from_pairs(n, (1..n).map(|i| (i - 1, i)).chain(closing))
+}
+
+/// \[Generic\] Generate the star graph with `leaves` leaves: node `0` is
+/// the center, adjacent to each of the nodes `1..=leaves`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::star_graph;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let graph: UnGraph<(), ()> = star_graph(5);
+/// assert_eq!(graph.node_count(), 6);
+/// assert_eq!(graph.neighbors(NodeIndex::new(0)).count(), 5);
+/// ```
+pub fn star_graph<G>(leaves: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    from_pairs(leaves + 1, (1..=leaves).map(|i| (0, i)))
+}
+
+/// \[Generic\] Generate the wheel graph with `rim` nodes on its rim: node
+/// `0` is the hub, adjacent to each of the nodes `1..=rim`, which form a
+/// cycle.
+///
+/// As in [`cycle_graph`], the rim has no closing edge with fewer than three
+/// nodes. For a directed graph, the rim is oriented from `i` to `i + 1` and
+/// from `rim` back to `1`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::wheel_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> = wheel_graph(5);
+/// assert_eq!((graph.node_count(), graph.edge_count()), (6, 10));
+/// ```
+pub fn wheel_graph<G>(rim: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let spokes = (1..=rim).map(|i| (0, i));
+    let path = (2..=rim).map(|i| (i - 1, i));
+    let closing = (rim >= 3).then_some((rim, 1));
+    from_pairs(rim + 1, spokes.chain(path).chain(closing))
+}
+
+/// \[Generic\] Generate a grid graph with the given size along each
+/// dimension, such as `&[rows, columns]` for a 2D grid or `&[x, y, z]` for
+/// a 3D one.
+///
+/// The nodes are numbered in row-major order: the node at coordinates
+/// `[i, j]` of a 2D grid has index `i * columns + j`. Each node is adjacent
+/// to the nodes that differ by one in a single coordinate. With `periodic`,
+/// the grid wraps around like a torus: along each dimension of size at
+/// least three, the last node is also adjacent to the first.
+///
+/// For a directed graph, every edge goes towards the higher coordinate, and
+/// the wrapping edges from the last node to the first.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::grid_graph;
+/// use petgraph::graph::{NodeIndex, UnGraph};
+///
+/// let grid: UnGraph<(), ()> = grid_graph(&[3, 4], false);
+/// assert_eq!((grid.node_count(), grid.edge_count()), (12, 17));
+/// // The node in row 1, column 2.
+/// assert_eq!(grid.neighbors(NodeIndex::new(1 * 4 + 2)).count(), 4);
+///
+/// let torus: UnGraph<(), ()> = grid_graph(&[3, 4, 5], true);
+/// assert!(torus.node_indices().all(|v| torus.neighbors(v).count() == 6));
+/// ```
+pub fn grid_graph<G>(dimensions: &[usize], periodic: bool) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let n: usize = dimensions.iter().product();
+    let mut edges = Vec::new();
+    // The distance between the indices of neighbors along each dimension.
+    let mut stride = n;
+    for &size in dimensions {
+        stride /= size.max(1);
+        for v in 0..n {
+            let coordinate = v / stride % size;
+            if coordinate + 1 < size {
+                edges.push((v, v + stride));
+            } else if periodic && size >= 3 {
+                edges.push((v, v - coordinate * stride));
+            }
+        }
+    }
+    from_pairs(n, edges)
+}
+
+/// \[Generic\] Generate the hypercube graph of dimension `d`: its `2^d`
+/// nodes are adjacent when the binary representations of their indices
+/// differ in a single bit.
+///
+/// **Panics** if `2^d` overflows `usize`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::hypercube_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let cube: UnGraph<(), ()> = hypercube_graph(3);
+/// assert_eq!((cube.node_count(), cube.edge_count()), (8, 12));
+/// ```
+pub fn hypercube_graph<G>(d: u32) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let n = 1usize
+        .checked_shl(d)
+        .expect("hypercube_graph: too many dimensions");
+    let edges = (0..d).flat_map(move |bit| {
+        (0..n)
+            .filter(move |v| v & (1 << bit) == 0)
+            .map(move |v| (v, v | (1 << bit)))
+    });
+    from_pairs(n, edges)
+}
+
+/// \[Generic\] Generate the perfectly balanced tree of height `height`, in
+/// which every node that is not a leaf has `children` children.
+///
+/// The nodes are numbered in breadth-first order from the root `0`, so the
+/// children of node `i` are `i * children + 1..=i * children + children`.
+/// For a directed graph, the edges go from each node to its children.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::balanced_tree;
+/// use petgraph::graph::UnGraph;
+///
+/// // A binary tree with 1 + 2 + 4 + 8 nodes.
+/// let tree: UnGraph<(), ()> = balanced_tree(2, 3);
+/// assert_eq!((tree.node_count(), tree.edge_count()), (15, 14));
+/// ```
+pub fn balanced_tree<G>(children: usize, height: u32) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let mut n = 1;
+    let mut level = 1;
+    for _ in 0..height {
+        level *= children;
+        n += level;
+    }
+    let edges = (1..n).map(|v| ((v - 1) / children, v));
+    from_pairs(n, edges)
+}
@@ -0,0 +1,367 @@
+//! Random graphs with a planted community structure, to test and benchmark
+//! community detection against the known communities.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use hashbrown::HashSet;
+#[cfg(feature = "std")]
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::from_pairs;
+#[cfg(feature = "std")]
+use super::pair_stubs;
+use crate::data::Create;
+use crate::visit::GraphProp;
+use crate::EdgeType;
+
+/// \[Generic\] Generate a random graph from the [stochastic block model][1]:
+/// the nodes are split into blocks, and two nodes of the blocks `i` and `j`
+/// are adjacent independently with probability `probabilities[i][j]`.
+///
+/// The nodes are numbered block by block: the first `sizes[0]` nodes form
+/// block `0`, and so on. For a directed graph, both orientations of each
+/// pair of nodes are drawn separately, and `probabilities[i][j]` is the
+/// probability of an edge from block `i` to block `j`. There are no self
+/// loops.
+///
+/// **Panics** if `probabilities` is not a square matrix with a row for each
+/// block, if one of its entries is not between `0.0` and `1.0`, or if it is
+/// not symmetric for an undirected graph.
+///
+/// # Arguments
+/// * `sizes`: the number of nodes of each block.
+/// * `probabilities`: the probability of an edge between each pair of blocks.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * The graph, and the block of each node.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Stochastic_block_model
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::stochastic_block_model;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::visit::EdgeRef;
+/// use petgraph::SeededRng;
+///
+/// // Two dense communities, with no edges between them.
+/// let probabilities = [[0.8, 0.0], [0.0, 0.8]];
+/// let (graph, blocks): (UnGraph<(), ()>, _) =
+///     stochastic_block_model(&[10, 20], &probabilities, &mut SeededRng::new(1));
+/// assert_eq!(graph.node_count(), 30);
+/// assert_eq!(blocks[9..11], [0, 1]);
+/// for edge in graph.edge_references() {
+///     assert_eq!(blocks[edge.source().index()], blocks[edge.target().index()]);
+/// }
+/// ```
+pub fn stochastic_block_model<G, R, P>(
+    sizes: &[usize],
+    probabilities: &[P],
+    rng: &mut R,
+) -> (G, Vec<usize>)
+where
+    G: Create + GraphProp,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng + ?Sized,
+    P: AsRef<[f64]>,
+{
+    let k = sizes.len();
+    assert!(
+        probabilities.len() == k && probabilities.iter().all(|row| row.as_ref().len() == k),
+        "probabilities must have a row and a column for each block"
+    );
+    let directed = G::EdgeType::is_directed();
+    for (i, row) in probabilities.iter().enumerate() {
+        for (j, &p) in row.as_ref().iter().enumerate() {
+            assert!(
+                (0.0..=1.0).contains(&p),
+                "probabilities must be probabilities"
+            );
+            assert!(
+                directed || p == probabilities[j].as_ref()[i],
+                "probabilities must be symmetric for an undirected graph"
+            );
+        }
+    }
+
+    let blocks: Vec<usize> = sizes
+        .iter()
+        .enumerate()
+        .flat_map(|(block, &size)| core::iter::repeat(block).take(size))
+        .collect();
+    let n = blocks.len();
+    let mut edges = Vec::new();
+    for a in 0..n {
+        let row = probabilities[blocks[a]].as_ref();
+        let start = if directed { 0 } else { a + 1 };
+        for b in start..n {
+            if a != b && rng.gen_bool(row[blocks[b]]) {
+                edges.push((a, b));
+            }
+        }
+    }
+    (from_pairs(n, edges), blocks)
+}
+
+/// The parameters of [`lfr_benchmark_graph`].
+///
+/// The degrees of the nodes follow a power law of exponent
+/// `degree_exponent` between `min_degree` and `max_degree`, and the sizes
+/// of the communities a power law of exponent `community_exponent` between
+/// `min_community` and `max_community`. Each node has a fraction `mixing`
+/// of its edges outside of its community.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LfrConfig {
+    /// The exponent of the degree distribution, typically between `2` and `3`.
+    pub degree_exponent: f64,
+    /// The exponent of the community size distribution, typically between
+    /// `1` and `2`.
+    pub community_exponent: f64,
+    /// The fraction of the edges of each node that leave its community,
+    /// between `0.0` and `1.0`.
+    pub mixing: f64,
+    /// The smallest degree, at least `1`.
+    pub min_degree: usize,
+    /// The largest degree.
+    pub max_degree: usize,
+    /// The size of the smallest community, at least `1`.
+    pub min_community: usize,
+    /// The size of the largest community.
+    pub max_community: usize,
+    /// The number of times to draw the community sizes and assign the nodes
+    /// to them, before giving up.
+    pub max_attempts: usize,
+}
+
+#[cfg(feature = "std")]
+impl Default for LfrConfig {
+    /// The parameters of the benchmarks of the original paper, with
+    /// communities of `20` to `100` nodes and a mixing of `0.1`.
+    fn default() -> Self {
+        LfrConfig {
+            degree_exponent: 2.5,
+            community_exponent: 1.5,
+            mixing: 0.1,
+            min_degree: 5,
+            max_degree: 50,
+            min_community: 20,
+            max_community: 100,
+            max_attempts: 100,
+        }
+    }
+}
+
+/// \[Generic\] Generate a random graph from the [LFR benchmark][1] of
+/// Lancichinetti, Fortunato and Radicchi: a graph with power law
+/// distributions of the degrees and of the community sizes, where each node
+/// has a given fraction of its edges outside of its community.
+///
+/// The degrees and community sizes are drawn from their distributions, and
+/// each node is assigned to a random community large enough for its edges
+/// inside of it. The edges inside each community, and then the edges
+/// between communities, are formed by pairing the endpoints of the nodes at
+/// random. The pairs that would form a self loop, a parallel edge, or an
+/// edge on the wrong side of a community are paired again while possible,
+/// and otherwise dropped, so the degrees and the mixing of the graph are
+/// close to, but may be slightly lower than, the ones drawn.
+///
+/// For a directed graph, each edge is added once, in an arbitrary
+/// orientation. Requires the `std` feature.
+///
+/// **Panics** if `mixing` is not between `0.0` and `1.0`, or if a minimum
+/// of `config` is `0` or larger than the matching maximum.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `config`: the parameters of the distributions.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * `Some((graph, communities))`: the graph, and the community of each
+///   node.
+/// * `None`: if no community sizes adding up to `n` nodes, and with room
+///   for the edges of every node, were found in `config.max_attempts`
+///   attempts.
+///
+/// # Complexity
+/// * Time complexity: **O(max_attempts · |V| · c + |E|)** expected.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **c** is the number of communities.
+///
+/// [1]: https://doi.org/10.1103/PhysRevE.78.046110
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::{lfr_benchmark_graph, LfrConfig};
+/// use petgraph::graph::UnGraph;
+/// use petgraph::visit::EdgeRef;
+/// use petgraph::SeededRng;
+///
+/// let config = LfrConfig { mixing: 0.2, ..LfrConfig::default() };
+/// let (graph, communities): (UnGraph<(), ()>, _) =
+///     lfr_benchmark_graph(1000, &config, &mut SeededRng::new(1)).unwrap();
+/// assert_eq!(communities.len(), 1000);
+///
+/// let crossing = graph
+///     .edge_references()
+///     .filter(|e| communities[e.source().index()] != communities[e.target().index()])
+///     .count();
+/// let mixing = crossing as f64 / graph.edge_count() as f64;
+/// assert!((0.15..0.25).contains(&mixing));
+/// ```
+#[cfg(feature = "std")]
+pub fn lfr_benchmark_graph<G, R>(
+    n: usize,
+    config: &LfrConfig,
+    rng: &mut R,
+) -> Option<(G, Vec<usize>)>
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng + ?Sized,
+{
+    assert!(
+        (0.0..=1.0).contains(&config.mixing),
+        "mixing must be between 0.0 and 1.0"
+    );
+    assert!(
+        0 < config.min_degree && config.min_degree <= config.max_degree,
+        "the degree range must not be empty or contain 0"
+    );
+    assert!(
+        0 < config.min_community && config.min_community <= config.max_community,
+        "the community size range must not be empty or contain 0"
+    );
+
+    let degrees: Vec<usize> = (0..n)
+        .map(|_| {
+            power_law(
+                config.min_degree,
+                config.max_degree,
+                config.degree_exponent,
+                rng,
+            )
+        })
+        .collect();
+    let internal: Vec<usize> = degrees
+        .iter()
+        .map(|&d| ((1.0 - config.mixing) * d as f64).round() as usize)
+        .collect();
+    let communities = (0..config.max_attempts).find_map(|_| {
+        let sizes = community_sizes(n, config, rng)?;
+        assign_communities(&internal, &sizes, rng)
+    })?;
+
+    let mut members = Vec::new();
+    for (v, &c) in communities.iter().enumerate() {
+        if c >= members.len() {
+            members.resize_with(c + 1, Vec::new);
+        }
+        members[c].push(v);
+    }
+    let mut present = HashSet::new();
+    let mut edges = Vec::new();
+    for nodes in &members {
+        let stubs = nodes
+            .iter()
+            .flat_map(|&v| core::iter::repeat(v).take(internal[v]))
+            .collect();
+        pair_stubs(
+            stubs,
+            |a, b| a != b && present.insert((a, b)),
+            &mut edges,
+            rng,
+        );
+    }
+    let stubs = (0..n)
+        .flat_map(|v| core::iter::repeat(v).take(degrees[v] - internal[v]))
+        .collect();
+    let crossing =
+        |a: usize, b: usize| a != b && communities[a] != communities[b] && present.insert((a, b));
+    pair_stubs(stubs, crossing, &mut edges, rng);
+    Some((from_pairs(n, edges), communities))
+}
+
+/// Draw an integer between `min` and `max` from a power law of exponent
+/// `exponent`, by discretizing the continuous law on `[min, max + 1)`.
+#[cfg(feature = "std")]
+fn power_law<R: Rng + ?Sized>(min: usize, max: usize, exponent: f64, rng: &mut R) -> usize {
+    let (a, b) = (min as f64, (max + 1) as f64);
+    let u: f64 = rng.gen();
+    let e = 1.0 - exponent;
+    let x = if e.abs() < 1e-9 {
+        a * (b / a).powf(u)
+    } else {
+        (a.powf(e) + u * (b.powf(e) - a.powf(e))).powf(1.0 / e)
+    };
+    (x as usize).clamp(min, max)
+}
+
+/// Draw community sizes that add up to `n`, or `None` if the last one would
+/// be too small.
+#[cfg(feature = "std")]
+fn community_sizes<R: Rng + ?Sized>(
+    n: usize,
+    config: &LfrConfig,
+    rng: &mut R,
+) -> Option<Vec<usize>> {
+    let mut sizes = Vec::new();
+    let mut total = 0;
+    while total < n {
+        let size = power_law(
+            config.min_community,
+            config.max_community,
+            config.community_exponent,
+            rng,
+        );
+        if total + size > n {
+            let rest = n - total;
+            if rest < config.min_community {
+                return None;
+            }
+            sizes.push(rest);
+            break;
+        }
+        sizes.push(size);
+        total += size;
+    }
+    Some(sizes)
+}
+
+/// Assign each node to a random community with room for its internal
+/// edges, starting from the nodes with the most internal edges, or return
+/// `None` if one of them does not fit.
+#[cfg(feature = "std")]
+fn assign_communities<R: Rng + ?Sized>(
+    internal: &[usize],
+    sizes: &[usize],
+    rng: &mut R,
+) -> Option<Vec<usize>> {
+    let mut order: Vec<usize> = (0..internal.len()).collect();
+    order.sort_by_key(|&v| core::cmp::Reverse(internal[v]));
+    let mut room = sizes.to_vec();
+    let mut communities = alloc::vec![0; internal.len()];
+    let mut eligible = Vec::new();
+    for v in order {
+        eligible.clear();
+        eligible.extend((0..sizes.len()).filter(|&c| room[c] > 0 && sizes[c] > internal[v]));
+        let &c = eligible.choose(rng)?;
+        room[c] -= 1;
+        communities[v] = c;
+    }
+    Some(communities)
+}
@@ -0,0 +1,302 @@
+//! Graphs with a given degree sequence.
+//!
+//! A degree sequence lists the degree of each node: node `i` of a graph
+//! realizing `degrees` has `degrees[i]` neighbors. The sequence is
+//! *graphical* when some simple graph, without self loops or parallel edges,
+//! realizes it.
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "rand")]
+use hashbrown::HashSet;
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use super::from_pairs;
+#[cfg(feature = "rand")]
+use super::{double_edge_swaps, pair_stubs};
+use crate::data::Create;
+#[cfg(feature = "rand")]
+use crate::graph::{Graph, IndexType, NodeIndex};
+#[cfg(feature = "rand")]
+use crate::EdgeType;
+
+/// Return `true` if `degrees` is the degree sequence of a simple graph.
+///
+/// Uses the [Erdős–Gallai theorem][1]: the sum of the degrees must be even,
+/// and for every `k`, the `k` largest degrees must not add up to more than
+/// the edges between those `k` nodes and to the other nodes can hold.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| log |V|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the length of `degrees`.
+///
+/// [1]: https://en.wikipedia.org/wiki/Erd%C5%91s%E2%80%93Gallai_theorem
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::is_graphical;
+///
+/// assert!(is_graphical(&[3, 3, 2, 2, 2]));
+/// // The sum of the degrees is odd.
+/// assert!(!is_graphical(&[3, 3, 2, 2, 1]));
+/// // Two nodes cannot both be adjacent to all of the three others.
+/// assert!(!is_graphical(&[3, 3, 1, 1]));
+/// ```
+pub fn is_graphical(degrees: &[usize]) -> bool {
+    let n = degrees.len();
+    let mut sorted = degrees.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    if sorted.iter().sum::<usize>() % 2 == 1 || sorted.first().map_or(false, |&d| d >= n) {
+        return false;
+    }
+    // suffix[i] is the sum of the degrees from i on.
+    let mut suffix = vec![0; n + 1];
+    for i in (0..n).rev() {
+        suffix[i] = suffix[i + 1] + sorted[i];
+    }
+    let mut largest = 0;
+    // The number of degrees that are at least k.
+    let mut at_least = n;
+    for k in 1..=n {
+        largest += sorted[k - 1];
+        while at_least > 0 && sorted[at_least - 1] < k {
+            at_least -= 1;
+        }
+        // Beyond the first k, the degrees that are at least k count k, and
+        // the others count themselves.
+        let capped = at_least.saturating_sub(k) * k + suffix[at_least.max(k)];
+        if largest > k * (k - 1) + capped {
+            return false;
+        }
+    }
+    true
+}
+
+/// \[Generic\] Build a simple graph with the degree sequence `degrees` with
+/// the [Havel–Hakimi algorithm][1], or return `None` if the sequence is not
+/// graphical.
+///
+/// The node with the largest remaining degree is repeatedly connected to the
+/// nodes with the next largest remaining degrees. The result is
+/// deterministic. For a directed graph, each edge is added once, in an
+/// arbitrary orientation.
+///
+/// # Arguments
+/// * `degrees`: the degree of each node.
+///
+/// # Returns
+/// * `Some(graph)`: a graph where node `i` has `degrees[i]` neighbors.
+/// * `None`: if the sequence is not graphical.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| · Δ + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **Δ** is the largest degree.
+///
+/// [1]: https://en.wikipedia.org/wiki/Havel%E2%80%93Hakimi_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::havel_hakimi_graph;
+/// use petgraph::graph::UnGraph;
+///
+/// let graph: UnGraph<(), ()> = havel_hakimi_graph(&[3, 3, 2, 2, 2]).unwrap();
+/// let degrees: Vec<_> = graph.node_indices().map(|v| graph.neighbors(v).count()).collect();
+/// assert_eq!(degrees, [3, 3, 2, 2, 2]);
+///
+/// assert!(havel_hakimi_graph::<UnGraph<(), ()>>(&[3, 3, 1, 1]).is_none());
+/// ```
+pub fn havel_hakimi_graph<G>(degrees: &[usize]) -> Option<G>
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let max = degrees.iter().copied().max().unwrap_or(0);
+    // The nodes, by remaining degree.
+    let mut buckets = vec![Vec::new(); max + 1];
+    for (v, &d) in degrees.iter().enumerate().rev() {
+        buckets[d].push(v);
+    }
+    let mut edges = Vec::with_capacity(degrees.iter().sum::<usize>() / 2);
+    let mut lowered = Vec::new();
+    let mut top = max;
+    loop {
+        while top > 0 && buckets[top].is_empty() {
+            top -= 1;
+        }
+        if top == 0 {
+            break;
+        }
+        let v = buckets[top].pop().unwrap();
+        // Connect v to the top nodes with the largest remaining degrees,
+        // which move down a bucket once they have all been chosen.
+        let mut level = top;
+        while lowered.len() < top {
+            if level == 0 {
+                return None;
+            }
+            match buckets[level].pop() {
+                Some(w) => {
+                    edges.push((v, w));
+                    lowered.push((w, level - 1));
+                }
+                None => level -= 1,
+            }
+        }
+        for (w, d) in lowered.drain(..) {
+            buckets[d].push(w);
+        }
+    }
+    Some(from_pairs(degrees.len(), edges))
+}
+
+/// \[Generic\] Generate a random graph with the degree sequence `degrees`
+/// from the [configuration model][1]: each node gets as many endpoints as
+/// its degree, and the endpoints are paired uniformly at random.
+///
+/// Without rejection, the result is a multigraph that realizes the degree
+/// sequence exactly, where a self loop counts twice in the degree of its
+/// node. With `reject_loops` or `reject_parallel_edges`, the pairs that
+/// would form a self loop or a parallel edge are paired again while
+/// possible, and otherwise dropped, so some degrees may end up lower than
+/// requested.
+///
+/// For a directed graph, each edge is added once, in an arbitrary
+/// orientation.
+///
+/// **Panics** if the sum of the degrees is odd.
+///
+/// # Arguments
+/// * `degrees`: the degree of each node.
+/// * `reject_loops`: whether to leave out self loops.
+/// * `reject_parallel_edges`: whether to leave out parallel edges.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * A graph with `degrees.len()` nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** expected for a sparse graph.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Configuration_model
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::configuration_model;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::SeededRng;
+///
+/// let degrees = [4, 3, 3, 2, 2, 1, 1];
+/// let graph: UnGraph<(), ()> = configuration_model(&degrees, false, false, &mut SeededRng::new(1));
+/// assert_eq!(graph.edge_count(), 8);
+///
+/// let simple: UnGraph<(), ()> = configuration_model(&degrees, true, true, &mut SeededRng::new(1));
+/// assert!(simple.edge_count() <= 8);
+/// ```
+#[cfg(feature = "rand")]
+pub fn configuration_model<G, R>(
+    degrees: &[usize],
+    reject_loops: bool,
+    reject_parallel_edges: bool,
+    rng: &mut R,
+) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng + ?Sized,
+{
+    let total: usize = degrees.iter().sum();
+    assert!(total % 2 == 0, "the sum of the degrees must be even");
+    let stubs = degrees
+        .iter()
+        .enumerate()
+        .flat_map(|(v, &d)| core::iter::repeat(v).take(d))
+        .collect();
+    let mut present = HashSet::new();
+    let accept = |a: usize, b: usize| {
+        !(reject_loops && a == b) && (!reject_parallel_edges || present.insert((a, b)))
+    };
+    let mut edges = Vec::with_capacity(total / 2);
+    pair_stubs(stubs, accept, &mut edges, rng);
+    from_pairs(degrees.len(), edges)
+}
+
+/// Shuffle the edges of `graph` in place with `swaps` random double edge
+/// swaps, which keep the degree of every node.
+///
+/// A swap replaces two edges `a → b` and `c → d` by `a → d` and `c → b`, so
+/// a directed graph keeps the in- and out-degree of every node. The swaps
+/// never create a self loop or a parallel edge: those that would are
+/// skipped and drawn again, up to `100 · swaps` attempts in total, so that
+/// a graph with too few possible swaps is left with fewer. After enough
+/// swaps, typically ten per edge, the graph is a uniform sample of the
+/// simple graphs with its degrees, the usual null model to tell which of
+/// its properties the degrees alone explain.
+///
+/// The nodes, the edge indices and the edge weights are kept: each edge
+/// index keeps its weight and source, but may get a new target.
+///
+/// # Arguments
+/// * `graph`: the graph to rewire.
+/// * `swaps`: the number of swaps to make.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * The number of swaps made.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| + swaps)** expected, unless most
+///   attempts are skipped.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::{grid_graph, rewire};
+/// use petgraph::graph::UnGraph;
+/// use petgraph::SeededRng;
+///
+/// let mut graph: UnGraph<(), ()> = grid_graph(&[4, 4], false);
+/// let degrees: Vec<_> = graph.node_indices().map(|v| graph.neighbors(v).count()).collect();
+///
+/// let swaps = 10 * graph.edge_count();
+/// assert_eq!(rewire(&mut graph, swaps, &mut SeededRng::new(1)), swaps);
+/// assert!(graph.node_indices().all(|v| graph.neighbors(v).count() == degrees[v.index()]));
+/// ```
+#[cfg(feature = "rand")]
+pub fn rewire<N, E, Ty, Ix, R>(graph: &mut Graph<N, E, Ty, Ix>, swaps: usize, rng: &mut R) -> usize
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    R: Rng + ?Sized,
+{
+    let mut pairs: Vec<(usize, usize)> = graph
+        .raw_edges()
+        .iter()
+        .map(|edge| (edge.source().index(), edge.target().index()))
+        .collect();
+    let attempts = swaps.saturating_mul(100);
+    let done = double_edge_swaps(&mut pairs, graph.is_directed(), swaps, attempts, rng);
+    if done > 0 {
+        let (nodes, edges) = core::mem::take(graph).into_nodes_edges();
+        *graph = Graph::with_capacity(nodes.len(), edges.len());
+        for node in nodes {
+            graph.add_node(node.weight);
+        }
+        for (edge, (a, b)) in edges.into_iter().zip(pairs) {
+            graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), edge.weight);
+        }
+    }
+    done
+}
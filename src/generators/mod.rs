@@ -0,0 +1,167 @@
+//! Graph generators.
+//!
+//! Every generator is generic over the graph type it builds, through the
+//! [`Create`] trait, and gives the nodes and edges default weights. Nodes are
+//! added in order, so with [`Graph`](crate::graph::Graph) node `i` of the
+//! description has index `i`. Since the nodes of a
+//! [`GraphMap`](crate::graphmap::GraphMap) are their weights, generate a
+//! `Graph` and convert it instead.
+//!
+//! Random generators take their random number generator as an argument,
+//! see the [`rng`](crate::rng) module, and require the `rand` feature.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "rand")]
+use hashbrown::HashMap;
+#[cfg(feature = "rand")]
+use rand::{seq::SliceRandom, Rng};
+
+use crate::data::Create;
+
+pub mod classic;
+#[cfg(feature = "rand")]
+pub mod community;
+pub mod degree_sequence;
+#[cfg(feature = "rand")]
+pub mod random;
+
+pub use classic::{
+    balanced_tree, complete_bipartite_graph, complete_graph, cycle_graph, grid_graph,
+    hypercube_graph, path_graph, star_graph, wheel_graph,
+};
+#[cfg(feature = "rand")]
+pub use community::stochastic_block_model;
+#[cfg(all(feature = "rand", feature = "std"))]
+pub use community::{lfr_benchmark_graph, LfrConfig};
+#[cfg(feature = "rand")]
+pub use degree_sequence::{configuration_model, rewire};
+pub use degree_sequence::{havel_hakimi_graph, is_graphical};
+#[cfg(feature = "rand")]
+pub use random::{
+    barabasi_albert_graph, gnm_random_graph, gnp_random_graph, random_geometric_graph,
+    random_regular_graph, watts_strogatz_graph,
+};
+
+/// Build a graph with `n` nodes and the edges between the nodes at the given
+/// positions, with default weights.
+fn from_pairs<G, I>(n: usize, edges: I) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let edges = edges.into_iter();
+    let mut graph = G::with_capacity(n, edges.size_hint().0);
+    let nodes: Vec<G::NodeId> = (0..n).map(|_| graph.add_node(Default::default())).collect();
+    for (a, b) in edges {
+        graph.add_edge(nodes[a], nodes[b], Default::default());
+    }
+    graph
+}
+
+/// Pair the endpoints in `stubs` at random into edges `(a, b)` with
+/// `a <= b`, keeping the pairs for which `accept` returns `true` and pairing
+/// the rejected endpoints again as long as some pairs are accepted.
+///
+/// An endpoint left over from an odd number of them is dropped.
+#[cfg(feature = "rand")]
+fn pair_stubs<F, R>(
+    mut stubs: Vec<usize>,
+    mut accept: F,
+    edges: &mut Vec<(usize, usize)>,
+    rng: &mut R,
+) where
+    F: FnMut(usize, usize) -> bool,
+    R: Rng + ?Sized,
+{
+    let mut rejected = Vec::new();
+    loop {
+        stubs.shuffle(rng);
+        for pair in stubs.chunks_exact(2) {
+            let (a, b) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+            if accept(a, b) {
+                edges.push((a, b));
+            } else {
+                rejected.extend_from_slice(pair);
+            }
+        }
+        if rejected.len() + 1 >= stubs.len() {
+            return;
+        }
+        core::mem::swap(&mut stubs, &mut rejected);
+        rejected.clear();
+    }
+}
+
+/// Make up to `swaps` double edge swaps on the graph with the edges
+/// `edges`, in at most `attempts` attempts, and return the number of swaps
+/// made.
+///
+/// A swap replaces two random edges `(a, b)` and `(c, d)` by `(a, d)` and
+/// `(c, b)`, which keeps the degree of every node, or the in- and
+/// out-degrees for a `directed` graph. Undirected edges are also swapped
+/// as `(a, c)` and `(b, d)`, with probability one half. A swap that would
+/// create a self loop or a parallel edge is skipped, so the swaps never add
+/// any, but keep those already in `edges`.
+#[cfg(feature = "rand")]
+pub(crate) fn double_edge_swaps<R>(
+    edges: &mut [(usize, usize)],
+    directed: bool,
+    swaps: usize,
+    attempts: usize,
+    rng: &mut R,
+) -> usize
+where
+    R: Rng + ?Sized,
+{
+    let key = |a: usize, b: usize| {
+        if directed || a < b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+    // The number of edges between each pair of nodes.
+    let mut present: HashMap<(usize, usize), usize> = HashMap::new();
+    for &(a, b) in edges.iter() {
+        *present.entry(key(a, b)).or_insert(0) += 1;
+    }
+    let mut done = 0;
+    if edges.len() < 2 {
+        return done;
+    }
+    for _ in 0..attempts {
+        if done == swaps {
+            break;
+        }
+        let i = rng.gen_range(0..edges.len());
+        let j = rng.gen_range(0..edges.len());
+        let ((a, b), (mut c, mut d)) = (edges[i], edges[j]);
+        if !directed && rng.gen_bool(0.5) {
+            core::mem::swap(&mut c, &mut d);
+        }
+        if i == j
+            || a == d
+            || c == b
+            || present.contains_key(&key(a, d))
+            || present.contains_key(&key(c, b))
+        {
+            continue;
+        }
+        for old in [key(a, b), key(c, d)] {
+            let count = present.get_mut(&old).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                present.remove(&old);
+            }
+        }
+        present.insert(key(a, d), 1);
+        present.insert(key(c, b), 1);
+        edges[i] = (a, d);
+        edges[j] = (c, b);
+        done += 1;
+    }
+    done
+}
@@ -0,0 +1,470 @@
+//! Random graph models.
+
+use alloc::{vec, vec::Vec};
+
+use hashbrown::{HashMap, HashSet};
+use rand::{seq::SliceRandom, Rng};
+
+use super::from_pairs;
+use crate::data::Create;
+use crate::visit::GraphProp;
+use crate::EdgeType;
+
+/// Return `(a, b)` for a directed graph, and the pair in increasing order
+/// for an undirected one.
+fn key(directed: bool, a: usize, b: usize) -> (usize, usize) {
+    if directed || a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// \[Generic\] Generate an [Erdős–Rényi][1] random graph **G(n, p)**: each
+/// possible edge is present independently with probability `p`.
+///
+/// For a directed graph, both orientations of each pair of nodes are drawn
+/// separately. There are no self loops.
+///
+/// **Panics** if `p` is not between `0.0` and `1.0`.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `p`: the probability of each edge.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * A graph with `n` nodes.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Erd%C5%91s%E2%80%93R%C3%A9nyi_model
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::gnp_random_graph;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::SeededRng;
+///
+/// let graph: UnGraph<(), ()> = gnp_random_graph(100, 0.1, &mut SeededRng::new(1));
+/// assert_eq!(graph.node_count(), 100);
+/// // About 0.1 · 4950 edges.
+/// assert!((400..600).contains(&graph.edge_count()));
+/// ```
+pub fn gnp_random_graph<G, R>(n: usize, p: f64, rng: &mut R) -> G
+where
+    G: Create + GraphProp,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng + ?Sized,
+{
+    assert!((0.0..=1.0).contains(&p), "p must be a probability");
+    let directed = G::EdgeType::is_directed();
+    let mut edges = Vec::new();
+    for a in 0..n {
+        let start = if directed { 0 } else { a + 1 };
+        for b in start..n {
+            if a != b && rng.gen_bool(p) {
+                edges.push((a, b));
+            }
+        }
+    }
+    from_pairs(n, edges)
+}
+
+/// \[Generic\] Generate an [Erdős–Rényi][1] random graph **G(n, m)**: a
+/// graph chosen uniformly among the graphs with `n` nodes and `m` edges.
+///
+/// For a directed graph, the two orientations of a pair of nodes are
+/// distinct edges. There are no self loops or parallel edges.
+///
+/// **Panics** if `m` is larger than the number of possible edges.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `m`: the number of edges.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * A graph with `n` nodes and `m` edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** expected when **|E|** is at most
+///   half of the possible edges, **O(|V|²)** otherwise.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Erd%C5%91s%E2%80%93R%C3%A9nyi_model
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::gnm_random_graph;
+/// use petgraph::graph::DiGraph;
+/// use petgraph::SeededRng;
+///
+/// let graph: DiGraph<(), ()> = gnm_random_graph(10, 85, &mut SeededRng::new(1));
+/// assert_eq!((graph.node_count(), graph.edge_count()), (10, 85));
+/// ```
+pub fn gnm_random_graph<G, R>(n: usize, m: usize, rng: &mut R) -> G
+where
+    G: Create + GraphProp,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng + ?Sized,
+{
+    let directed = G::EdgeType::is_directed();
+    let pairs = n * n.saturating_sub(1);
+    let possible = if directed { pairs } else { pairs / 2 };
+    assert!(m <= possible, "too many edges for the number of nodes");
+
+    // Draw the edges, or the non-edges if they are fewer.
+    let complement = m > possible / 2;
+    let draws = if complement { possible - m } else { m };
+    let mut drawn = HashSet::with_capacity(draws);
+    let mut order = Vec::with_capacity(draws);
+    while order.len() < draws {
+        let a = rng.gen_range(0..n);
+        let b = rng.gen_range(0..n);
+        if a != b && drawn.insert(key(directed, a, b)) {
+            order.push(key(directed, a, b));
+        }
+    }
+    if !complement {
+        return from_pairs(n, order);
+    }
+    let edges = (0..n).flat_map(|a| {
+        let start = if directed { 0 } else { a + 1 };
+        (start..n).map(move |b| (a, b))
+    });
+    from_pairs(
+        n,
+        edges.filter(|&(a, b)| a != b && !drawn.contains(&(a, b))),
+    )
+}
+
+/// \[Generic\] Generate a [Barabási–Albert][1] preferential attachment
+/// graph.
+///
+/// The graph starts with `m` nodes and no edges. Each of the other nodes is
+/// then added with edges to `m` distinct existing nodes, chosen with
+/// probabilities proportional to their degrees (the first added node
+/// connects to all initial nodes). The degrees follow a power law.
+///
+/// For a directed graph, the edges go from each new node to the older ones.
+///
+/// **Panics** if `m` is `0` or not smaller than `n`.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `m`: the number of edges of each new node.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * A graph with `n` nodes and `m · (n - m)` edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** expected.
+/// * Auxiliary space: **O(|E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Barab%C3%A1si%E2%80%93Albert_model
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::barabasi_albert_graph;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::SeededRng;
+///
+/// let graph: UnGraph<(), ()> = barabasi_albert_graph(1000, 2, &mut SeededRng::new(1));
+/// assert_eq!(graph.edge_count(), 2 * 998);
+/// // A few hubs have a much larger degree than the average of 4.
+/// let max_degree = graph.node_indices().map(|v| graph.neighbors(v).count()).max();
+/// assert!(max_degree.unwrap() > 20);
+/// ```
+pub fn barabasi_albert_graph<G, R>(n: usize, m: usize, rng: &mut R) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng + ?Sized,
+{
+    assert!(0 < m && m < n, "m must be between 1 and n - 1");
+    let mut edges = Vec::with_capacity(m * (n - m));
+    // Each node appears once for each of its edges.
+    let mut repeated = Vec::with_capacity(2 * m * (n - m));
+    let mut targets: Vec<usize> = (0..m).collect();
+    for source in m..n {
+        for &target in &targets {
+            edges.push((source, target));
+        }
+        repeated.extend_from_slice(&targets);
+        repeated.extend(core::iter::repeat(source).take(m));
+        targets.clear();
+        while targets.len() < m && source + 1 < n {
+            let target = *repeated.choose(rng).unwrap();
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+    }
+    from_pairs(n, edges)
+}
+
+/// \[Generic\] Generate a [Watts–Strogatz][1] small-world graph.
+///
+/// The graph starts as a ring of `n` nodes, each connected to its `k / 2`
+/// nearest neighbors on each side. Then each edge `(u, v)` of the ring is
+/// rewired with probability `beta` to `(u, w)`, where `w` is chosen uniformly
+/// among the nodes that are not `u` or already adjacent to it. A small
+/// `beta` keeps the high clustering of the ring and gives short paths.
+///
+/// For a directed graph, the edges of the ring go from `u` to `u + j`.
+///
+/// **Panics** if `k` is not smaller than `n`, or if `beta` is not between
+/// `0.0` and `1.0`.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `k`: the degree of each node in the ring; an odd `k` is rounded down.
+/// * `beta`: the probability of rewiring each edge.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * A graph with `n` nodes and `n · (k / 2)` edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** expected.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Watts%E2%80%93Strogatz_model
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::watts_strogatz_graph;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::SeededRng;
+///
+/// let ring: UnGraph<(), ()> = watts_strogatz_graph(20, 4, 0.0, &mut SeededRng::new(1));
+/// assert!(ring.node_indices().all(|v| ring.neighbors(v).count() == 4));
+///
+/// let graph: UnGraph<(), ()> = watts_strogatz_graph(20, 4, 0.3, &mut SeededRng::new(1));
+/// assert_eq!(graph.edge_count(), 40);
+/// ```
+pub fn watts_strogatz_graph<G, R>(n: usize, k: usize, beta: f64, rng: &mut R) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng + ?Sized,
+{
+    assert!(k < n || n == 0, "k must be smaller than n");
+    assert!((0.0..=1.0).contains(&beta), "beta must be a probability");
+    let half = k / 2;
+    let mut edges = Vec::with_capacity(n * half);
+    let mut present = HashSet::with_capacity(n * half);
+    let mut degree = vec![2 * half; n];
+    for j in 1..=half {
+        for u in 0..n {
+            let v = (u + j) % n;
+            edges.push((u, v));
+            present.insert(key(false, u, v));
+        }
+    }
+    // Rewire the edges of each distance in turn, as in the original model.
+    for edge in &mut edges {
+        let (u, v) = *edge;
+        if degree[u] + 1 >= n || !rng.gen_bool(beta) {
+            continue;
+        }
+        let w = loop {
+            let w = rng.gen_range(0..n);
+            if w != u && !present.contains(&key(false, u, w)) {
+                break w;
+            }
+        };
+        present.remove(&key(false, u, v));
+        present.insert(key(false, u, w));
+        degree[v] -= 1;
+        degree[w] += 1;
+        *edge = (u, w);
+    }
+    from_pairs(n, edges)
+}
+
+/// \[Generic\] Generate a random `d`-regular graph: a simple graph where
+/// every node has exactly `d` neighbors, chosen uniformly at random (in
+/// the limit of large `n`).
+///
+/// Uses the pairing algorithm of [Steger and Wormald][1]: the `d` endpoints
+/// of every node are paired at random, keeping only the pairs that form
+/// new edges, and starting over in the rare case where no valid pairing is
+/// left.
+///
+/// For a directed graph, each edge is added once, in an arbitrary
+/// orientation.
+///
+/// **Panics** if `d` is not smaller than `n`, or if `n · d` is odd.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `d`: the degree of each node.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * A graph with `n` nodes and `n · d / 2` edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| · d²)** expected for a small `d`.
+/// * Auxiliary space: **O(|V| · d)**.
+///
+/// where **|V|** is the number of nodes.
+///
+/// [1]: https://doi.org/10.1017/S0963548399003867
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::random_regular_graph;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::SeededRng;
+///
+/// let graph: UnGraph<(), ()> = random_regular_graph(50, 3, &mut SeededRng::new(1));
+/// assert!(graph.node_indices().all(|v| graph.neighbors(v).count() == 3));
+/// ```
+pub fn random_regular_graph<G, R>(n: usize, d: usize, rng: &mut R) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng + ?Sized,
+{
+    assert!(d < n || (n == 0 && d == 0), "d must be smaller than n");
+    assert!(n * d % 2 == 0, "n · d must be even");
+    loop {
+        if let Some(edges) = try_regular_pairing(n, d, rng) {
+            return from_pairs(n, edges);
+        }
+    }
+}
+
+fn try_regular_pairing<R>(n: usize, d: usize, rng: &mut R) -> Option<Vec<(usize, usize)>>
+where
+    R: Rng + ?Sized,
+{
+    let mut edges = Vec::with_capacity(n * d / 2);
+    let mut present = HashSet::with_capacity(n * d / 2);
+    let mut stubs: Vec<usize> = (0..n).flat_map(|v| core::iter::repeat(v).take(d)).collect();
+    let mut leftover = vec![0; n];
+    while !stubs.is_empty() {
+        stubs.shuffle(rng);
+        for pair in stubs.chunks_exact(2) {
+            let (a, b) = key(false, pair[0], pair[1]);
+            if a != b && present.insert((a, b)) {
+                edges.push((a, b));
+            } else {
+                leftover[a] += 1;
+                leftover[b] += 1;
+            }
+        }
+        // Continue with the unpaired endpoints, if they can still form an
+        // edge.
+        let open: Vec<usize> = (0..n).filter(|&v| leftover[v] > 0).collect();
+        let suitable = open.iter().enumerate().any(|(i, &a)| {
+            open[i + 1..]
+                .iter()
+                .any(|&b| !present.contains(&key(false, a, b)))
+        });
+        if !open.is_empty() && !suitable {
+            return None;
+        }
+        stubs.clear();
+        for v in open {
+            stubs.extend(core::iter::repeat(v).take(leftover[v]));
+            leftover[v] = 0;
+        }
+    }
+    Some(edges)
+}
+
+/// \[Generic\] Generate a [random geometric graph][1] in the unit square:
+/// `n` points are placed uniformly at random, and two nodes are adjacent
+/// when their points are at distance at most `radius`.
+///
+/// The points are bucketed in a grid of cells of side `radius`, so that
+/// only nearby pairs are compared. For a directed graph, the edges go from
+/// the lower to the higher node.
+///
+/// # Arguments
+/// * `n`: the number of nodes.
+/// * `radius`: the largest distance between adjacent nodes.
+/// * `rng`: the random number generator.
+///
+/// # Returns
+/// * The graph, and the position of each node.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** expected.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Random_geometric_graph
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::random_geometric_graph;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::visit::EdgeRef;
+/// use petgraph::SeededRng;
+///
+/// let (graph, points): (UnGraph<(), ()>, _) =
+///     random_geometric_graph(200, 0.1, &mut SeededRng::new(1));
+/// for edge in graph.edge_references() {
+///     let [x1, y1] = points[edge.source().index()];
+///     let [x2, y2] = points[edge.target().index()];
+///     assert!((x1 - x2).powi(2) + (y1 - y2).powi(2) <= 0.1 * 0.1);
+/// }
+/// ```
+pub fn random_geometric_graph<G, R>(n: usize, radius: f64, rng: &mut R) -> (G, Vec<[f64; 2]>)
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng + ?Sized,
+{
+    let points: Vec<[f64; 2]> = (0..n).map(|_| [rng.gen(), rng.gen()]).collect();
+    if radius <= 0.0 {
+        return (from_pairs(n, core::iter::empty()), points);
+    }
+    let cell = |x: f64| (x / radius) as usize;
+    let mut grid: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (i, &[x, y]) in points.iter().enumerate() {
+        grid.entry((cell(x), cell(y))).or_default().push(i);
+    }
+    let mut edges = Vec::new();
+    let mut close = Vec::new();
+    for (i, &[x, y]) in points.iter().enumerate() {
+        let (cx, cy) = (cell(x), cell(y));
+        for gx in cx.saturating_sub(1)..=cx.saturating_add(1) {
+            for gy in cy.saturating_sub(1)..=cy.saturating_add(1) {
+                for &j in grid.get(&(gx, gy)).map_or(&[][..], Vec::as_slice) {
+                    let [u, v] = points[j];
+                    let (dx, dy) = (x - u, y - v);
+                    if j > i && dx * dx + dy * dy <= radius * radius {
+                        close.push(j);
+                    }
+                }
+            }
+        }
+        close.sort_unstable();
+        edges.extend(close.drain(..).map(|j| (i, j)));
+    }
+    (from_pairs(n, edges), points)
+}
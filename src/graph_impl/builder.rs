@@ -0,0 +1,204 @@
+use core::borrow::Borrow;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use super::{DefaultIx, EdgeIndex, Graph, IndexType, NodeIndex};
+use crate::{Directed, EdgeType, Undirected};
+
+/// A builder of a [`Graph`] whose nodes are identified by keys, such as the
+/// string ids of a dataset, instead of node indices.
+///
+/// Each distinct key gets a node the first time it is used, and the builder
+/// keeps the map from the keys to their node indices, which
+/// [`build`](GraphBuilder::build) returns along with the graph.
+///
+/// ```
+/// use petgraph::graph::GraphBuilder;
+///
+/// let mut builder = GraphBuilder::<&str, &str, u32>::new();
+/// builder.add_node("paris", "Paris");
+/// builder.add_edge("paris", "lyon", 465);
+/// builder.extend_with_edges([("lyon", "marseille", 315), ("marseille", "paris", 775)]);
+///
+/// let (graph, indices) = builder.build();
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph[indices["paris"]], "Paris");
+/// // The nodes created by edges get the default weight.
+/// assert_eq!(graph[indices["lyon"]], "");
+/// ```
+#[derive(Clone, Debug)]
+pub struct GraphBuilder<K, N, E, Ty = Directed, Ix = DefaultIx>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    graph: Graph<N, E, Ty, Ix>,
+    indices: HashMap<K, NodeIndex<Ix>>,
+}
+
+impl<K, N, E> GraphBuilder<K, N, E, Directed>
+where
+    K: Hash + Eq,
+{
+    /// Create a builder of a directed graph.
+    pub fn new() -> Self {
+        GraphBuilder::with_capacity(0, 0)
+    }
+}
+
+impl<K, N, E> GraphBuilder<K, N, E, Undirected>
+where
+    K: Hash + Eq,
+{
+    /// Create a builder of an undirected graph.
+    pub fn new_undirected() -> Self {
+        GraphBuilder::with_capacity(0, 0)
+    }
+}
+
+impl<K, N, E, Ty, Ix> Default for GraphBuilder<K, N, E, Ty, Ix>
+where
+    K: Hash + Eq,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        GraphBuilder::with_capacity(0, 0)
+    }
+}
+
+impl<K, N, E, Ty, Ix> GraphBuilder<K, N, E, Ty, Ix>
+where
+    K: Hash + Eq,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a builder with estimated capacity.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        GraphBuilder {
+            graph: Graph::with_capacity(nodes, edges),
+            indices: HashMap::with_capacity(nodes),
+        }
+    }
+
+    /// Return the index of the node of `key`, adding a node with the
+    /// default weight if the key is new.
+    ///
+    /// Computes in **O(1)** expected time.
+    pub fn node(&mut self, key: K) -> NodeIndex<Ix>
+    where
+        N: Default,
+    {
+        let graph = &mut self.graph;
+        *self
+            .indices
+            .entry(key)
+            .or_insert_with(|| graph.add_node(N::default()))
+    }
+
+    /// Add a node with the given weight for `key`, or set the weight of the
+    /// node of `key` if it already has one, and return its index.
+    ///
+    /// Computes in **O(1)** expected time.
+    pub fn add_node(&mut self, key: K, weight: N) -> NodeIndex<Ix> {
+        match self.indices.get(&key) {
+            Some(&index) => {
+                self.graph[index] = weight;
+                index
+            }
+            None => {
+                let index = self.graph.add_node(weight);
+                self.indices.insert(key, index);
+                index
+            }
+        }
+    }
+
+    /// Add an edge from the node of `a` to the node of `b`, adding the nodes
+    /// of new keys with the default weight, and return its index.
+    ///
+    /// Computes in **O(1)** expected time.
+    ///
+    /// **Panics** if the graph is at the maximum number of nodes or edges
+    /// for its index type.
+    pub fn add_edge(&mut self, a: K, b: K, weight: E) -> EdgeIndex<Ix>
+    where
+        N: Default,
+    {
+        let a = self.node(a);
+        let b = self.node(b);
+        self.graph.add_edge(a, b, weight)
+    }
+
+    /// Add the edges `(a, b, weight)` of an iterable, as with
+    /// [`add_edge`](GraphBuilder::add_edge).
+    pub fn extend_with_edges<I>(&mut self, iterable: I)
+    where
+        I: IntoIterator<Item = (K, K, E)>,
+        N: Default,
+    {
+        let iter = iterable.into_iter();
+        self.graph.reserve_edges(iter.size_hint().0);
+        for (a, b, weight) in iter {
+            self.add_edge(a, b, weight);
+        }
+    }
+
+    /// Return the index of the node of `key`, if any.
+    pub fn node_index<Q>(&self, key: &Q) -> Option<NodeIndex<Ix>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indices.get(key).copied()
+    }
+
+    /// Return the graph built so far.
+    pub fn graph(&self) -> &Graph<N, E, Ty, Ix> {
+        &self.graph
+    }
+
+    /// Return the graph, and the map from the keys to their node indices.
+    #[allow(clippy::type_complexity)]
+    pub fn build(self) -> (Graph<N, E, Ty, Ix>, HashMap<K, NodeIndex<Ix>>) {
+        (self.graph, self.indices)
+    }
+}
+
+impl<N, E, Ty, Ix> Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a new `Graph` from an iterable of edges `(a, b, weight)`
+    /// between nodes identified by keys, such as string ids.
+    ///
+    /// Each distinct key gets a node with the default weight, in the order
+    /// of their first appearance. Return the graph, and the map from the
+    /// keys to their node indices.
+    ///
+    /// Computes in **O(|E|)** expected time.
+    ///
+    /// See [`GraphBuilder`] to also add nodes with weights.
+    ///
+    /// ```
+    /// use petgraph::graph::UnGraph;
+    ///
+    /// let (graph, indices) =
+    ///     UnGraph::<(), f64>::from_edges_with_keys([("alice", "bob", 1.5), ("bob", "carol", 2.)]);
+    /// assert_eq!(graph.node_count(), 3);
+    /// assert!(graph.contains_edge(indices["carol"], indices["bob"]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn from_edges_with_keys<I, K>(iterable: I) -> (Self, HashMap<K, NodeIndex<Ix>>)
+    where
+        I: IntoIterator<Item = (K, K, E)>,
+        K: Hash + Eq,
+        N: Default,
+    {
+        let mut builder = GraphBuilder::with_capacity(0, 0);
+        builder.extend_with_edges(iterable);
+        builder.build()
+    }
+}
@@ -0,0 +1,291 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use super::{DefaultIx, EdgeIndex, Graph, GraphError, IndexType, NodeIndex};
+use crate::csr::Csr;
+use crate::{Directed, EdgeType, Undirected};
+
+/// A graph that many threads can build at once, through a shared reference.
+///
+/// `ConcurrentGraph` hands out the node and edge indices from atomic
+/// counters, and appends the weights to one of several mutex-protected
+/// shards, picked by index, so that threads adding nodes and edges at the
+/// same time rarely wait for each other. It can only grow: once it is
+/// built, [`into_graph`](ConcurrentGraph::into_graph) or
+/// [`into_csr`](ConcurrentGraph::into_csr) converts it into a regular
+/// graph, with the same node and edge indices.
+///
+/// ```
+/// use petgraph::graph::ConcurrentGraph;
+///
+/// let graph = ConcurrentGraph::<usize, ()>::new();
+/// let hub = graph.add_node(0);
+/// std::thread::scope(|scope| {
+///     for i in 1..=4 {
+///         let graph = &graph;
+///         scope.spawn(move || {
+///             let node = graph.add_node(i);
+///             graph.add_edge(hub, node, ());
+///         });
+///     }
+/// });
+///
+/// let graph = graph.into_graph();
+/// assert_eq!(graph.node_count(), 5);
+/// assert_eq!(graph.neighbors(hub).count(), 4);
+/// ```
+#[derive(Debug)]
+pub struct ConcurrentGraph<N, E, Ty = Directed, Ix = DefaultIx> {
+    node_count: AtomicUsize,
+    edge_count: AtomicUsize,
+    /// The nodes with their indices, in the shard of index `i % shards`.
+    nodes: Vec<Mutex<Vec<(usize, N)>>>,
+    /// The edges with their indices, in the shard of index `i % shards`
+    /// for the first edge of a batch.
+    #[allow(clippy::type_complexity)]
+    edges: Vec<Mutex<Vec<(usize, [NodeIndex<Ix>; 2], E)>>>,
+    ty: PhantomData<Ty>,
+}
+
+impl<N, E> ConcurrentGraph<N, E, Directed> {
+    /// Create a new, empty directed `ConcurrentGraph`, with a number of
+    /// shards suited to the parallelism of the machine.
+    pub fn new() -> Self {
+        ConcurrentGraph::default()
+    }
+}
+
+impl<N, E> ConcurrentGraph<N, E, Undirected> {
+    /// Create a new, empty undirected `ConcurrentGraph`, with a number of
+    /// shards suited to the parallelism of the machine.
+    pub fn new_undirected() -> Self {
+        ConcurrentGraph::default()
+    }
+}
+
+impl<N, E, Ty, Ix> Default for ConcurrentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        let parallelism = std::thread::available_parallelism().map_or(1, |n| n.get());
+        ConcurrentGraph::with_shards(4 * parallelism)
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // The shards are only appended to, which leaves them valid if a thread
+    // panics while it holds the lock.
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn into_inner<T>(mutex: Mutex<T>) -> T {
+    mutex.into_inner().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<N, E, Ty, Ix> ConcurrentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a new, empty `ConcurrentGraph` with `shards` shards for the
+    /// nodes and for the edges, at least one.
+    ///
+    /// More shards make the threads wait less for each other.
+    pub fn with_shards(shards: usize) -> Self {
+        let shards = shards.max(1);
+        ConcurrentGraph {
+            node_count: AtomicUsize::new(0),
+            edge_count: AtomicUsize::new(0),
+            nodes: (0..shards).map(|_| Mutex::new(Vec::new())).collect(),
+            edges: (0..shards).map(|_| Mutex::new(Vec::new())).collect(),
+            ty: PhantomData,
+        }
+    }
+
+    /// Return the number of nodes added so far.
+    pub fn node_count(&self) -> usize {
+        self.node_count.load(Ordering::Acquire)
+    }
+
+    /// Return the number of edges added so far.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count.load(Ordering::Acquire)
+    }
+
+    /// Whether the graph has directed edges or not.
+    #[inline]
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Reserve `count` consecutive indices from `counter`, and return the
+    /// first one, or `None` if they do not all fit in `Ix`.
+    fn reserve(counter: &AtomicUsize, count: usize) -> Option<usize> {
+        // `end()` is not a valid index, except for `usize`.
+        let limit = if <Ix as IndexType>::max().index() == !0 {
+            usize::MAX
+        } else {
+            <Ix as IndexType>::max().index()
+        };
+        counter
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                n.checked_add(count).filter(|&end| end <= limit)
+            })
+            .ok()
+    }
+
+    /// Add a node with associated data `weight` to the graph, and return its
+    /// index.
+    ///
+    /// Computes in **O(1)** amortized time, after waiting for the other
+    /// threads that add a node to the same shard.
+    ///
+    /// **Panics** if the graph is at the maximum number of nodes for its
+    /// index type.
+    #[track_caller]
+    pub fn add_node(&self, weight: N) -> NodeIndex<Ix> {
+        self.try_add_node(weight).unwrap()
+    }
+
+    /// Try to add a node with associated data `weight` to the graph, and
+    /// return its index.
+    ///
+    /// Return [`GraphError::NodeIxLimit`] if the graph is at the maximum
+    /// number of nodes for its index type.
+    pub fn try_add_node(&self, weight: N) -> Result<NodeIndex<Ix>, GraphError> {
+        let id = Self::reserve(&self.node_count, 1).ok_or(GraphError::NodeIxLimit)?;
+        lock(&self.nodes[id % self.nodes.len()]).push((id, weight));
+        Ok(NodeIndex::new(id))
+    }
+
+    /// Add an edge from `a` to `b` to the graph, with its associated data
+    /// `weight`, and return its index.
+    ///
+    /// Computes in **O(1)** amortized time, after waiting for the other
+    /// threads that add an edge to the same shard.
+    ///
+    /// **Panics** if any of the nodes don't exist.<br>
+    /// **Panics** if the graph is at the maximum number of edges for its
+    /// index type.
+    #[track_caller]
+    pub fn add_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> EdgeIndex<Ix> {
+        let res = self.try_add_edge(a, b, weight);
+        if res == Err(GraphError::NodeOutBounds) {
+            panic!("ConcurrentGraph::add_edge: node indices out of bounds");
+        }
+        res.unwrap()
+    }
+
+    /// Try to add an edge from `a` to `b` to the graph, with its associated
+    /// data `weight`, and return its index.
+    ///
+    /// Possible errors:
+    /// - [`GraphError::NodeOutBounds`] if any of the nodes don't exist.
+    /// - [`GraphError::EdgeIxLimit`] if the graph is at the maximum number of
+    ///   edges for its index type.
+    pub fn try_add_edge(
+        &self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+        weight: E,
+    ) -> Result<EdgeIndex<Ix>, GraphError> {
+        let node_count = self.node_count();
+        if a.index() >= node_count || b.index() >= node_count {
+            return Err(GraphError::NodeOutBounds);
+        }
+        let id = Self::reserve(&self.edge_count, 1).ok_or(GraphError::EdgeIxLimit)?;
+        lock(&self.edges[id % self.edges.len()]).push((id, [a, b], weight));
+        Ok(EdgeIndex::new(id))
+    }
+
+    /// Add the edges `(a, b, weight)` of an iterable to the graph, with
+    /// consecutive indices, under a single lock.
+    ///
+    /// Adding edges in batches makes the threads wait less for each other
+    /// than adding them one by one.
+    ///
+    /// **Panics** if any of the nodes don't exist.<br>
+    /// **Panics** if the graph is at the maximum number of edges for its
+    /// index type.
+    #[track_caller]
+    pub fn extend_with_edges<I>(&self, iterable: I)
+    where
+        I: IntoIterator<Item = (NodeIndex<Ix>, NodeIndex<Ix>, E)>,
+    {
+        let node_count = self.node_count();
+        let batch: Vec<_> = iterable.into_iter().collect();
+        if batch
+            .iter()
+            .any(|(a, b, _)| a.index() >= node_count || b.index() >= node_count)
+        {
+            panic!("ConcurrentGraph::extend_with_edges: node indices out of bounds");
+        }
+        let first = Self::reserve(&self.edge_count, batch.len())
+            .ok_or(GraphError::EdgeIxLimit)
+            .unwrap();
+        let mut shard = lock(&self.edges[first % self.edges.len()]);
+        shard.extend(
+            batch
+                .into_iter()
+                .enumerate()
+                .map(|(i, (a, b, weight))| (first + i, [a, b], weight)),
+        );
+    }
+
+    /// Convert into a [`Graph`], with the same node and edge indices.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn into_graph(self) -> Graph<N, E, Ty, Ix> {
+        let node_count = self.node_count();
+        let edge_count = self.edge_count();
+        let mut nodes: Vec<Option<N>> = (0..node_count).map(|_| None).collect();
+        for shard in self.nodes {
+            for (id, weight) in into_inner(shard) {
+                nodes[id] = Some(weight);
+            }
+        }
+        let mut edges: Vec<Option<([NodeIndex<Ix>; 2], E)>> =
+            (0..edge_count).map(|_| None).collect();
+        for shard in self.edges {
+            for (id, node, weight) in into_inner(shard) {
+                edges[id] = Some((node, weight));
+            }
+        }
+
+        let mut graph = Graph::with_capacity(node_count, edge_count);
+        for weight in nodes.into_iter().flatten() {
+            graph.add_node(weight);
+        }
+        for ([a, b], weight) in edges.into_iter().flatten() {
+            graph.add_edge(a, b, weight);
+        }
+        graph
+    }
+
+    /// Convert into a [`Csr`], with the same node indices.
+    ///
+    /// As with the conversion of a `Graph` into a `Csr`, the neighbors are
+    /// sorted and the parallel edges are merged.
+    ///
+    /// Computes in **O(|V| + |E| log |E|)** time.
+    pub fn into_csr(self) -> Csr<N, E, Ty, Ix>
+    where
+        E: Clone,
+    {
+        Csr::from(self.into_graph())
+    }
+}
+
+impl<N, E, Ty, Ix> From<ConcurrentGraph<N, E, Ty, Ix>> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: ConcurrentGraph<N, E, Ty, Ix>) -> Self {
+        graph.into_graph()
+    }
+}
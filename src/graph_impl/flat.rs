@@ -0,0 +1,229 @@
+use alloc::vec::Vec;
+
+use super::{DefaultIx, Graph, GraphError, IndexType, NodeIndex};
+use crate::EdgeType;
+
+#[cfg(feature = "stable_graph")]
+use super::stable_graph::StableGraph;
+#[cfg(feature = "stable_graph")]
+use crate::visit::NodeIndexable;
+
+/// A graph stored as flat arrays: the node weights, and the sources,
+/// targets and weights of the edges.
+///
+/// The endpoints of the edges are raw indices into `node_weights`, in the
+/// compact range `0..node_weights.len()`, and edge `i` is made of
+/// `sources[i]`, `targets[i]` and `edge_weights[i]`. This is the layout
+/// expected by GPU kernels and columnar (e.g. Arrow) pipelines, which can
+/// consume each array as a single buffer.
+///
+/// Created by [`Graph::to_flat_arrays`] and [`Graph::into_flat_arrays`],
+/// and turned back into a graph with [`Graph::from_flat_arrays`]. The
+/// `StableGraph` methods of the same names renumber the nodes to close the
+/// holes left by removals.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FlatArrays<N, E, Ix = DefaultIx> {
+    /// The weight of each node.
+    pub node_weights: Vec<N>,
+    /// The index of the source node of each edge.
+    pub sources: Vec<Ix>,
+    /// The index of the target node of each edge.
+    pub targets: Vec<Ix>,
+    /// The weight of each edge.
+    pub edge_weights: Vec<E>,
+}
+
+impl<N, E, Ix> FlatArrays<N, E, Ix> {
+    /// Return the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.node_weights.len()
+    }
+
+    /// Return the number of edges.
+    pub fn edge_count(&self) -> usize {
+        self.edge_weights.len()
+    }
+}
+
+impl<N, E, Ix: IndexType> FlatArrays<N, E, Ix> {
+    fn with_capacity(nodes: usize, edges: usize) -> Self {
+        FlatArrays {
+            node_weights: Vec::with_capacity(nodes),
+            sources: Vec::with_capacity(edges),
+            targets: Vec::with_capacity(edges),
+            edge_weights: Vec::with_capacity(edges),
+        }
+    }
+
+    fn push_edge(&mut self, a: Ix, b: Ix, weight: E) {
+        self.sources.push(a);
+        self.targets.push(b);
+        self.edge_weights.push(weight);
+    }
+
+    /// Check that the edge arrays have the same length, and return the
+    /// edges.
+    fn into_parts(self) -> (Vec<N>, impl Iterator<Item = (Ix, Ix, E)>) {
+        assert!(
+            self.sources.len() == self.edge_weights.len()
+                && self.targets.len() == self.edge_weights.len(),
+            "FlatArrays: the edge arrays have different lengths"
+        );
+        let edges = self
+            .sources
+            .into_iter()
+            .zip(self.targets)
+            .zip(self.edge_weights)
+            .map(|((a, b), weight)| (a, b, weight));
+        (self.node_weights, edges)
+    }
+}
+
+impl<N, E, Ty, Ix> Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Return a copy of the graph as [`FlatArrays`]: the node weights, and
+    /// the sources, targets and weights of the edges, in index order.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    ///
+    /// ```
+    /// use petgraph::graph::{DiGraph, FlatArrays};
+    ///
+    /// let mut graph = DiGraph::<&str, f32>::new();
+    /// let a = graph.add_node("a");
+    /// let b = graph.add_node("b");
+    /// let c = graph.add_node("c");
+    /// graph.extend_with_edges([(a, b, 1.), (b, c, 2.), (a, c, 4.)]);
+    ///
+    /// let flat = graph.to_flat_arrays();
+    /// assert_eq!(flat.node_weights, ["a", "b", "c"]);
+    /// assert_eq!(flat.sources, [0, 1, 0]);
+    /// assert_eq!(flat.targets, [1, 2, 2]);
+    /// assert_eq!(flat.edge_weights, [1., 2., 4.]);
+    ///
+    /// let copy = DiGraph::from_flat_arrays(flat).unwrap();
+    /// assert_eq!(copy.edge_count(), 3);
+    /// ```
+    pub fn to_flat_arrays(&self) -> FlatArrays<N, E, Ix>
+    where
+        N: Clone,
+        E: Clone,
+    {
+        let mut flat = FlatArrays::with_capacity(self.node_count(), self.edge_count());
+        flat.node_weights
+            .extend(self.nodes.iter().map(|node| node.weight.clone()));
+        for edge in &self.edges {
+            flat.push_edge(
+                Ix::new(edge.source().index()),
+                Ix::new(edge.target().index()),
+                edge.weight.clone(),
+            );
+        }
+        flat
+    }
+
+    /// Convert the graph into [`FlatArrays`], moving the weights.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn into_flat_arrays(self) -> FlatArrays<N, E, Ix> {
+        let mut flat = FlatArrays::with_capacity(self.node_count(), self.edge_count());
+        flat.node_weights
+            .extend(self.nodes.into_iter().map(|node| node.weight));
+        for edge in self.edges {
+            flat.push_edge(
+                Ix::new(edge.source().index()),
+                Ix::new(edge.target().index()),
+                edge.weight,
+            );
+        }
+        flat
+    }
+
+    /// Create a graph from [`FlatArrays`]. Node `i` of the graph has the
+    /// weight `node_weights[i]`, and the edges are added in order.
+    ///
+    /// Return [`GraphError::NodeOutBounds`] if an edge has an endpoint that
+    /// is not a node, and [`GraphError::NodeIxLimit`] or
+    /// [`GraphError::EdgeIxLimit`] if the index type is too small.
+    ///
+    /// **Panics** if `sources`, `targets` and `edge_weights` have different
+    /// lengths.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn from_flat_arrays(flat: FlatArrays<N, E, Ix>) -> Result<Self, GraphError> {
+        let mut graph = Graph::with_capacity(flat.node_count(), flat.edge_count());
+        let (node_weights, edges) = flat.into_parts();
+        for weight in node_weights {
+            graph.try_add_node(weight)?;
+        }
+        for (a, b, weight) in edges {
+            graph.try_add_edge(NodeIndex::new(a.index()), NodeIndex::new(b.index()), weight)?;
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(feature = "stable_graph")]
+impl<N, E, Ty, Ix> StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Return a copy of the graph as [`FlatArrays`].
+    ///
+    /// The nodes are renumbered in the compact range `0..node_count()`, in
+    /// index order, and the edges are listed in index order, skipping the
+    /// holes left by removals.
+    ///
+    /// Computes in **O(|V| + |E|)** time, where **|V|** and **|E|** include
+    /// the vacant indices.
+    pub fn to_flat_arrays(&self) -> FlatArrays<N, E, Ix>
+    where
+        N: Clone,
+        E: Clone,
+    {
+        let mut flat = FlatArrays::with_capacity(self.node_count(), self.edge_count());
+        let mut compact = Vec::with_capacity(self.node_bound());
+        compact.resize(self.node_bound(), <Ix as IndexType>::max());
+        for (i, v) in self.node_indices().enumerate() {
+            compact[v.index()] = Ix::new(i);
+            flat.node_weights.push(self[v].clone());
+        }
+        for e in self.edge_indices() {
+            let (a, b) = self.edge_endpoints(e).unwrap();
+            flat.push_edge(compact[a.index()], compact[b.index()], self[e].clone());
+        }
+        flat
+    }
+
+    /// Create a graph from [`FlatArrays`], without holes. Node `i` of the
+    /// graph has the weight `node_weights[i]`, and the edges are added in
+    /// order.
+    ///
+    /// Return [`GraphError::NodeOutBounds`] if an edge has an endpoint that
+    /// is not a node, and [`GraphError::NodeIxLimit`] or
+    /// [`GraphError::EdgeIxLimit`] if the index type is too small.
+    ///
+    /// **Panics** if `sources`, `targets` and `edge_weights` have different
+    /// lengths.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn from_flat_arrays(flat: FlatArrays<N, E, Ix>) -> Result<Self, GraphError> {
+        let n = flat.node_count();
+        let mut graph = StableGraph::with_capacity(n, flat.edge_count());
+        let (node_weights, edges) = flat.into_parts();
+        for weight in node_weights {
+            graph.try_add_node(weight)?;
+        }
+        for (a, b, weight) in edges {
+            if a.index() >= n || b.index() >= n {
+                return Err(GraphError::NodeOutBounds);
+            }
+            graph.try_add_edge(NodeIndex::new(a.index()), NodeIndex::new(b.index()), weight)?;
+        }
+        Ok(graph)
+    }
+}
@@ -5,7 +5,7 @@ use crate::data::{DataMap, DataMapMut};
 use crate::graph::Graph;
 use crate::graph::{GraphIndex, IndexType};
 use crate::visit::{
-    Data, EdgeCount, EdgeIndexable, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdges,
+    Data, Degree, EdgeCount, EdgeIndexable, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdges,
     IntoEdgesDirected, IntoNeighborsDirected, IntoNodeIdentifiers, NodeCompactIndexable, NodeCount,
     NodeIndexable,
 };
@@ -104,6 +104,7 @@ NodeCompactIndexable! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
 NodeCount! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
 NodeIndexable! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
 EdgeCount! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
+Degree! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
 EdgeIndexable! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
 GraphProp! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
 Visitable! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
@@ -0,0 +1,768 @@
+use alloc::{vec, vec::Vec};
+use core::{iter, marker::PhantomData, ops::Index, slice};
+
+use fixedbitset::FixedBitSet;
+
+use super::{
+    edge_index, node_index, DefaultIx, EdgeIndex, EdgeIndices, EdgeReference, Graph, IndexType,
+    NodeIndex, NodeIndices,
+};
+use crate::data::DataMap;
+use crate::visit::{
+    AdjacencyBitMatrix, Data, Degree, EdgeCount, EdgeIndexable, GetAdjacencyMatrix, GraphBase,
+    GraphProp, IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors,
+    IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable,
+    NodeCount, NodeIndexable, Visitable,
+};
+use crate::{Directed, Direction, EdgeType, Incoming, Outgoing};
+
+/// An immutable graph, packed for fast reads.
+///
+/// `FrozenCsrGraph` is created from a [`Graph`] that is done being built,
+/// with [`Graph::freeze`], and keeps its node and edge indices. The edges of
+/// each node are stored contiguously, in compressed sparse row form, in both
+/// directions: iterating the neighbors or edges of a node is a linear scan
+/// of one slice, and the in- and out-degrees are read from prefix sums in
+/// **O(1)** time.
+///
+/// The graph has no method to add or remove nodes and edges; use
+/// [`into_graph`](FrozenCsrGraph::into_graph) to get a mutable `Graph` back.
+/// The neighbors of a node are listed in the order of the edge indices.
+///
+/// ```
+/// use petgraph::graph::DiGraph;
+/// use petgraph::algo::dijkstra;
+///
+/// let mut graph = DiGraph::<&str, u32>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// graph.extend_with_edges([(a, b, 1), (b, c, 2), (a, c, 4)]);
+///
+/// let frozen = graph.freeze();
+/// assert_eq!(frozen.out_degree(a), 2);
+/// assert_eq!(frozen.neighbors(a).collect::<Vec<_>>(), [b, c]);
+/// assert_eq!(dijkstra(&frozen, a, None, |e| *e.weight())[&c], 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FrozenCsrGraph<N, E, Ty = Directed, Ix = DefaultIx> {
+    node_weights: Vec<N>,
+    edge_weights: Vec<E>,
+    /// The source and target of each edge.
+    endpoints: Vec<[NodeIndex<Ix>; 2]>,
+    /// The edges of node `a` in direction `k` are
+    /// `adjacent[k][offsets[k][a]..offsets[k][a + 1]]`, with `k` being 0
+    /// for outgoing and 1 for incoming edges.
+    offsets: [Vec<usize>; 2],
+    /// The other endpoint and the index of each edge, grouped by node.
+    adjacent: [Vec<(NodeIndex<Ix>, EdgeIndex<Ix>)>; 2],
+    ty: PhantomData<Ty>,
+}
+
+impl<N, E, Ty, Ix> Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Convert the graph into a [`FrozenCsrGraph`], an immutable graph
+    /// packed for fast reads, with the same node and edge indices.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn freeze(self) -> FrozenCsrGraph<N, E, Ty, Ix> {
+        let (nodes, edges) = self.into_nodes_edges();
+        let node_count = nodes.len();
+        let mut endpoints = Vec::with_capacity(edges.len());
+        let mut edge_weights = Vec::with_capacity(edges.len());
+        for edge in edges {
+            endpoints.push(edge.node);
+            edge_weights.push(edge.weight);
+        }
+
+        let mut offsets = [vec![0; node_count + 1], vec![0; node_count + 1]];
+        for node in &endpoints {
+            for (k, offsets) in offsets.iter_mut().enumerate() {
+                offsets[node[k].index() + 1] += 1;
+            }
+        }
+        for offsets in &mut offsets {
+            for a in 0..node_count {
+                offsets[a + 1] += offsets[a];
+            }
+        }
+
+        let empty = (NodeIndex::end(), EdgeIndex::end());
+        let mut adjacent = [vec![empty; endpoints.len()], vec![empty; endpoints.len()]];
+        let mut next = offsets.clone();
+        for (i, node) in endpoints.iter().enumerate() {
+            for k in 0..2 {
+                let position = &mut next[k][node[k].index()];
+                adjacent[k][*position] = (node[1 - k], edge_index(i));
+                *position += 1;
+            }
+        }
+
+        FrozenCsrGraph {
+            node_weights: nodes.into_iter().map(|node| node.weight).collect(),
+            edge_weights,
+            endpoints,
+            offsets,
+            adjacent,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<N, E, Ty, Ix> From<Graph<N, E, Ty, Ix>> for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: Graph<N, E, Ty, Ix>) -> Self {
+        graph.freeze()
+    }
+}
+
+impl<N, E, Ty, Ix> From<FrozenCsrGraph<N, E, Ty, Ix>> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: FrozenCsrGraph<N, E, Ty, Ix>) -> Self {
+        graph.into_graph()
+    }
+}
+
+impl<N, E, Ty, Ix> FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Convert back into a mutable [`Graph`], with the same node and edge
+    /// indices.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn into_graph(self) -> Graph<N, E, Ty, Ix> {
+        let mut graph = Graph::with_capacity(self.node_count(), self.edge_count());
+        for weight in self.node_weights {
+            graph.add_node(weight);
+        }
+        for ([a, b], weight) in self.endpoints.into_iter().zip(self.edge_weights) {
+            graph.add_edge(a, b, weight);
+        }
+        graph
+    }
+
+    /// Return the number of nodes (vertices) in the graph.
+    pub fn node_count(&self) -> usize {
+        self.node_weights.len()
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edge_weights.len()
+    }
+
+    /// Whether the graph has directed edges or not.
+    #[inline]
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Access the weight for node `a`.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.node_weights.get(a.index())
+    }
+
+    /// Access the weight for edge `e`.
+    pub fn edge_weight(&self, e: EdgeIndex<Ix>) -> Option<&E> {
+        self.edge_weights.get(e.index())
+    }
+
+    /// Access the source and target nodes for `e`.
+    pub fn edge_endpoints(&self, e: EdgeIndex<Ix>) -> Option<(NodeIndex<Ix>, NodeIndex<Ix>)> {
+        self.endpoints.get(e.index()).map(|&[a, b]| (a, b))
+    }
+
+    /// Return the node weights, in the order of the node indices.
+    pub fn node_weights(&self) -> &[N] {
+        &self.node_weights
+    }
+
+    /// Return the edge weights, in the order of the edge indices.
+    pub fn edge_weights(&self) -> &[E] {
+        &self.edge_weights
+    }
+
+    /// Return an iterator over the node indices of the graph.
+    pub fn node_indices(&self) -> NodeIndices<Ix> {
+        NodeIndices {
+            r: 0..self.node_count(),
+            ty: PhantomData,
+        }
+    }
+
+    /// Return an iterator over the edge indices of the graph.
+    pub fn edge_indices(&self) -> EdgeIndices<Ix> {
+        EdgeIndices {
+            r: 0..self.edge_count(),
+            ty: PhantomData,
+        }
+    }
+
+    /// Return the edges of `a` in direction `k`, or an empty slice if the
+    /// node doesn't exist.
+    fn adjacent(&self, a: NodeIndex<Ix>, k: usize) -> &[(NodeIndex<Ix>, EdgeIndex<Ix>)] {
+        match self.offsets[k].get(a.index()..a.index() + 2) {
+            Some(&[start, end]) => &self.adjacent[k][start..end],
+            _ => &[],
+        }
+    }
+
+    fn adjacent_iter(&self, a: NodeIndex<Ix>, dir: Option<Direction>) -> Adjacent<'_, Ix> {
+        let (outgoing, incoming) = match dir {
+            Some(Outgoing) => (self.adjacent(a, 0), &[][..]),
+            Some(Incoming) => (&[][..], self.adjacent(a, 1)),
+            None => (self.adjacent(a, 0), self.adjacent(a, 1)),
+        };
+        Adjacent {
+            outgoing: outgoing.iter(),
+            incoming: incoming.iter(),
+            // For an undirected iterator, skip the self loops in the
+            // incoming edges, which are also outgoing edges.
+            skip_start: if dir.is_none() { a } else { NodeIndex::end() },
+        }
+    }
+
+    fn undirected_or(dir: Direction) -> Option<Direction> {
+        if Ty::is_directed() {
+            Some(dir)
+        } else {
+            None
+        }
+    }
+
+    /// Return the number of edges of `a` in direction `dir`, or of all the
+    /// edges of `a` for an undirected graph, where a self loop counts twice.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn degree_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> usize {
+        if Ty::is_directed() {
+            self.adjacent(a, dir.index()).len()
+        } else {
+            self.degree(a)
+        }
+    }
+
+    /// Return the number of edges from `a`; see [`degree_directed`](Self::degree_directed).
+    pub fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        self.degree_directed(a, Outgoing)
+    }
+
+    /// Return the number of edges to `a`; see [`degree_directed`](Self::degree_directed).
+    pub fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        self.degree_directed(a, Incoming)
+    }
+
+    /// Return the number of edge endpoints at `a`: the sum of its in- and
+    /// out-degrees for a directed graph.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        self.adjacent(a, 0).len() + self.adjacent(a, 1).len()
+    }
+
+    /// Return an iterator of all nodes with an edge starting from `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges connected to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors(&self, a: NodeIndex<Ix>) -> FrozenNeighbors<'_, Ix> {
+        self.neighbors_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of all neighbors that have an edge between them and
+    /// `a`, in the specified direction.
+    /// If the graph's edges are undirected, this is equivalent to *.neighbors(a)*.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> FrozenNeighbors<'_, Ix> {
+        FrozenNeighbors {
+            iter: self.adjacent_iter(a, Self::undirected_or(dir)),
+        }
+    }
+
+    /// Return an iterator of all edges of `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges connected to `a`, with `a` being the source of each edge.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `EdgeReference<E, Ix>`.
+    pub fn edges(&self, a: NodeIndex<Ix>) -> FrozenEdges<'_, E, Ix> {
+        self.edges_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of all edges of `a`, in the specified direction.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`, `Outgoing`: All edges connected to `a`, with `a` being the source of each edge.
+    /// - `Undirected`, `Incoming`: All edges connected to `a`, with `a` being the target of each edge.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `EdgeReference<E, Ix>`.
+    pub fn edges_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> FrozenEdges<'_, E, Ix> {
+        FrozenEdges {
+            node: a,
+            dir,
+            weights: &self.edge_weights,
+            iter: self.adjacent_iter(a, Self::undirected_or(dir)),
+        }
+    }
+
+    /// Lookup an edge from `a` to `b`.
+    ///
+    /// Computes in **O(min(e'))** time, where **e'** is the number of edges
+    /// scanned: the outgoing edges of `a` or the incoming edges of `b`,
+    /// whichever are fewer.
+    pub fn find_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        self.find_edge_directed(a, b).or_else(|| {
+            if Ty::is_directed() {
+                None
+            } else {
+                self.find_edge_directed(b, a)
+            }
+        })
+    }
+
+    fn find_edge_directed(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        let (outgoing, incoming) = (self.adjacent(a, 0), self.adjacent(b, 1));
+        let (edges, other) = if outgoing.len() <= incoming.len() {
+            (outgoing, b)
+        } else {
+            (incoming, a)
+        };
+        edges
+            .iter()
+            .find(|&&(node, _)| node == other)
+            .map(|&(_, edge)| edge)
+    }
+
+    /// Return `true` if there is an edge from `a` to `b`, or between them
+    /// for an undirected graph.
+    pub fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.find_edge(a, b).is_some()
+    }
+}
+
+/// Index the `FrozenCsrGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, E, Ty, Ix> Index<NodeIndex<Ix>> for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = N;
+    fn index(&self, index: NodeIndex<Ix>) -> &N {
+        &self.node_weights[index.index()]
+    }
+}
+
+/// Index the `FrozenCsrGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, Ty, Ix> Index<EdgeIndex<Ix>> for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        &self.edge_weights[index.index()]
+    }
+}
+
+/// The other endpoints and indices of the edges of a node.
+#[derive(Debug, Clone)]
+struct Adjacent<'a, Ix> {
+    outgoing: slice::Iter<'a, (NodeIndex<Ix>, EdgeIndex<Ix>)>,
+    incoming: slice::Iter<'a, (NodeIndex<Ix>, EdgeIndex<Ix>)>,
+    skip_start: NodeIndex<Ix>,
+}
+
+impl<Ix: IndexType> Iterator for Adjacent<'_, Ix> {
+    /// The other endpoint, the edge, and whether it is an outgoing edge.
+    type Item = (NodeIndex<Ix>, EdgeIndex<Ix>, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(&(node, edge)) = self.outgoing.next() {
+            return Some((node, edge, true));
+        }
+        let skip_start = self.skip_start;
+        self.incoming
+            .find(|&&(node, _)| node != skip_start)
+            .map(|&(node, edge)| (node, edge, false))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.outgoing.len();
+        (len, Some(len + self.incoming.len()))
+    }
+}
+
+/// Iterator over the neighbors of a node of a [`FrozenCsrGraph`].
+///
+/// Iterator element type is `NodeIndex<Ix>`.
+///
+/// Created with [`.neighbors()`][1], [`.neighbors_directed()`][2].
+///
+/// [1]: FrozenCsrGraph::neighbors
+/// [2]: FrozenCsrGraph::neighbors_directed
+#[derive(Debug, Clone)]
+pub struct FrozenNeighbors<'a, Ix = DefaultIx> {
+    iter: Adjacent<'a, Ix>,
+}
+
+impl<Ix: IndexType> Iterator for FrozenNeighbors<'_, Ix> {
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<NodeIndex<Ix>> {
+        self.iter.next().map(|(node, _, _)| node)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator over the edges of a node of a [`FrozenCsrGraph`].
+///
+/// Iterator element type is `EdgeReference<E, Ix>`.
+///
+/// Created with [`.edges()`][1], [`.edges_directed()`][2].
+///
+/// [1]: FrozenCsrGraph::edges
+/// [2]: FrozenCsrGraph::edges_directed
+#[derive(Debug, Clone)]
+pub struct FrozenEdges<'a, E, Ix = DefaultIx> {
+    node: NodeIndex<Ix>,
+    dir: Direction,
+    weights: &'a [E],
+    iter: Adjacent<'a, Ix>,
+}
+
+impl<'a, E, Ix: IndexType> Iterator for FrozenEdges<'a, E, Ix> {
+    type Item = EdgeReference<'a, E, Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (other, index, _) = self.iter.next()?;
+        // The edges of a directed graph only come from the list of `dir`, and
+        // the edges of an undirected graph are oriented away from the node
+        // for `Outgoing`.
+        let node = if self.dir == Outgoing {
+            [self.node, other]
+        } else {
+            [other, self.node]
+        };
+        Some(EdgeReference {
+            index,
+            node,
+            weight: &self.weights[index.index()],
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator over all edges of a [`FrozenCsrGraph`], in the order of their
+/// indices.
+///
+/// Iterator element type is `EdgeReference<E, Ix>`.
+#[derive(Debug, Clone)]
+pub struct FrozenEdgeReferences<'a, E, Ix = DefaultIx> {
+    #[allow(clippy::type_complexity)]
+    iter: iter::Enumerate<iter::Zip<slice::Iter<'a, [NodeIndex<Ix>; 2]>, slice::Iter<'a, E>>>,
+}
+
+impl<'a, E, Ix: IndexType> Iterator for FrozenEdgeReferences<'a, E, Ix> {
+    type Item = EdgeReference<'a, E, Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, (&node, weight))| EdgeReference {
+            index: edge_index(i),
+            node,
+            weight,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<E, Ix: IndexType> ExactSizeIterator for FrozenEdgeReferences<'_, E, Ix> {}
+
+/// Iterator over all nodes of a [`FrozenCsrGraph`].
+///
+/// Iterator element type is `(NodeIndex<Ix>, &N)`.
+#[derive(Debug, Clone)]
+pub struct FrozenNodeReferences<'a, N, Ix = DefaultIx> {
+    iter: iter::Enumerate<slice::Iter<'a, N>>,
+    ix: PhantomData<Ix>,
+}
+
+impl<'a, N, Ix: IndexType> Iterator for FrozenNodeReferences<'a, N, Ix> {
+    type Item = (NodeIndex<Ix>, &'a N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, weight)| (node_index(i), weight))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<N, Ix: IndexType> ExactSizeIterator for FrozenNodeReferences<'_, N, Ix> {}
+
+impl<N, E, Ty, Ix> GraphBase for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = EdgeIndex<Ix>;
+}
+
+impl<N, E, Ty, Ix> Data for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<N, E, Ty, Ix> GraphProp for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeType = Ty;
+}
+
+impl<N, E, Ty, Ix> DataMap for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_weight(&self, id: Self::NodeId) -> Option<&Self::NodeWeight> {
+        self.node_weight(id)
+    }
+    fn edge_weight(&self, id: Self::EdgeId) -> Option<&Self::EdgeWeight> {
+        self.edge_weight(id)
+    }
+}
+
+impl<N, E, Ty, Ix> NodeCount for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_count(&self) -> usize {
+        self.node_count()
+    }
+}
+
+impl<N, E, Ty, Ix> EdgeCount for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_count()
+    }
+}
+
+impl<N, E, Ty, Ix> Degree for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        FrozenCsrGraph::out_degree(self, a)
+    }
+    fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        FrozenCsrGraph::in_degree(self, a)
+    }
+    fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        FrozenCsrGraph::degree(self, a)
+    }
+}
+
+impl<N, E, Ty, Ix> NodeIndexable for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn node_bound(&self) -> usize {
+        self.node_count()
+    }
+    #[inline]
+    fn to_index(&self, ix: NodeIndex<Ix>) -> usize {
+        ix.index()
+    }
+    #[inline]
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        NodeIndex::new(ix)
+    }
+}
+
+impl<N, E, Ty, Ix> NodeCompactIndexable for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
+
+impl<N, E, Ty, Ix> EdgeIndexable for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn edge_bound(&self) -> usize {
+        self.edge_count()
+    }
+
+    fn to_index(&self, ix: EdgeIndex<Ix>) -> usize {
+        ix.index()
+    }
+
+    fn from_index(&self, ix: usize) -> Self::EdgeId {
+        EdgeIndex::new(ix)
+    }
+}
+
+impl<N, E, Ty, Ix> Visitable for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Map = FixedBitSet;
+    fn visit_map(&self) -> FixedBitSet {
+        FixedBitSet::with_capacity(self.node_count())
+    }
+
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+        map.grow(self.node_count());
+    }
+}
+
+/// The adjacency matrix for **FrozenCsrGraph** is an [`AdjacencyBitMatrix`]
+/// computed by `.adjacency_matrix()`.
+impl<N, E, Ty, Ix> GetAdjacencyMatrix for FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type AdjMatrix = AdjacencyBitMatrix;
+
+    fn adjacency_matrix(&self) -> AdjacencyBitMatrix {
+        AdjacencyBitMatrix::from_graph(self)
+    }
+
+    fn is_adjacent(&self, matrix: &AdjacencyBitMatrix, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        matrix.contains(a.index(), b.index())
+    }
+}
+
+impl<N, E, Ty, Ix> IntoNodeIdentifiers for &FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeIdentifiers = NodeIndices<Ix>;
+    fn node_identifiers(self) -> NodeIndices<Ix> {
+        self.node_indices()
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNodeReferences for &'a FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeRef = (NodeIndex<Ix>, &'a N);
+    type NodeReferences = FrozenNodeReferences<'a, N, Ix>;
+    fn node_references(self) -> Self::NodeReferences {
+        FrozenNodeReferences {
+            iter: self.node_weights.iter().enumerate(),
+            ix: PhantomData,
+        }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNeighbors for &'a FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Neighbors = FrozenNeighbors<'a, Ix>;
+    fn neighbors(self, a: NodeIndex<Ix>) -> Self::Neighbors {
+        FrozenCsrGraph::neighbors(self, a)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNeighborsDirected for &'a FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NeighborsDirected = FrozenNeighbors<'a, Ix>;
+    fn neighbors_directed(self, a: NodeIndex<Ix>, dir: Direction) -> Self::NeighborsDirected {
+        FrozenCsrGraph::neighbors_directed(self, a, dir)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdgeReferences for &'a FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeRef = EdgeReference<'a, E, Ix>;
+    type EdgeReferences = FrozenEdgeReferences<'a, E, Ix>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        FrozenEdgeReferences {
+            iter: self.endpoints.iter().zip(&self.edge_weights).enumerate(),
+        }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdges for &'a FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Edges = FrozenEdges<'a, E, Ix>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        FrozenCsrGraph::edges(self, a)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdgesDirected for &'a FrozenCsrGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgesDirected = FrozenEdges<'a, E, Ix>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        FrozenCsrGraph::edges_directed(self, a, dir)
+    }
+}
@@ -479,6 +479,21 @@ enum Pair<T> {
 
 use core::cmp::max;
 
+/// Count the edges of the linked list for direction `dir` that starts at
+/// `next`.
+fn count_edge_list<E, Ix: IndexType>(
+    edges: &[Edge<E, Ix>],
+    mut next: EdgeIndex<Ix>,
+    dir: Direction,
+) -> usize {
+    let mut count = 0;
+    while let Some(edge) = edges.get(next.index()) {
+        count += 1;
+        next = edge.next[dir.index()];
+    }
+    count
+}
+
 /// Get mutable references at index `a` and `b`.
 fn index_twice<T>(slc: &mut [T], a: usize, b: usize) -> Pair<&mut T> {
     if max(a, b) >= slc.len() {
@@ -984,6 +999,63 @@ where
         }
     }
 
+    /// Return the number of edges starting from `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`. A self loop counts once.
+    /// - `Undirected`: Same as [`.degree(a)`](#method.degree).
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    pub fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        if self.is_directed() {
+            self.degree_directed(a, Outgoing)
+        } else {
+            self.degree(a)
+        }
+    }
+
+    /// Return the number of edges ending at `a`.
+    ///
+    /// - `Directed`: Incoming edges to `a`. A self loop counts once.
+    /// - `Undirected`: Same as [`.degree(a)`](#method.degree).
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    pub fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        if self.is_directed() {
+            self.degree_directed(a, Incoming)
+        } else {
+            self.degree(a)
+        }
+    }
+
+    /// Return the number of edge endpoints at `a`: a self loop counts twice,
+    /// and for `Directed` graphs this is the sum of the in- and out-degree.
+    ///
+    /// Unlike `.neighbors(a).count()`, which lists each self loop once in an
+    /// `Undirected` graph, this follows the usual convention that the degrees
+    /// sum to twice the number of edges.
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    pub fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        self.degree_directed(a, Outgoing) + self.degree_directed(a, Incoming)
+    }
+
+    /// Count the edges in the list of `a` for direction `dir`.
+    fn degree_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> usize {
+        match self.nodes.get(a.index()) {
+            None => 0,
+            Some(node) => count_edge_list(&self.edges, node.next[dir.index()], dir),
+        }
+    }
+
     /// Return an iterator over all the edges connecting `a` and `b`.
     ///
     /// - `Directed`: Outgoing edges from `a`.
@@ -2368,6 +2440,22 @@ where
     }
 }
 
+impl<N, E, Ty, Ix> visit::Degree for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        Graph::out_degree(self, a)
+    }
+    fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        Graph::in_degree(self, a)
+    }
+    fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        Graph::degree(self, a)
+    }
+}
+
 impl<'a, N, E, Ty, Ix> visit::IntoNodeReferences for &'a Graph<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -2511,10 +2599,23 @@ where
     }
 }
 
+mod builder;
+#[cfg(feature = "std")]
+mod concurrent;
+mod flat;
 mod frozen;
+mod frozen_csr;
 #[cfg(feature = "stable_graph")]
 pub mod stable_graph;
 
+pub use self::builder::GraphBuilder;
+#[cfg(feature = "std")]
+pub use self::concurrent::ConcurrentGraph;
+pub use self::flat::FlatArrays;
+pub use self::frozen_csr::{
+    FrozenCsrGraph, FrozenEdgeReferences, FrozenEdges, FrozenNeighbors, FrozenNodeReferences,
+};
+
 /// `Frozen` is a graph wrapper.
 ///
 /// The `Frozen` only allows shared access (read-only) to the
@@ -0,0 +1,373 @@
+//! An append-only log of `StableGraph` changes.
+//!
+//! A [`LoggedGraph`] wraps a [`StableGraph`] and appends a record to a
+//! writer for every node or edge that is added or removed, so an evolving
+//! graph can be persisted incrementally, without serializing the whole graph
+//! after every change. [`read_graph_log`] replays a log to reconstruct the
+//! graph, with the same node and edge indices.
+//!
+//! The log is a text format with one record per line:
+//!
+//! ```text
+//! petgraph-log 1 directed
+//! n 0 first node weight
+//! n 1 second node weight
+//! e 0 0 1 edge weight
+//! -e 0
+//! -n 1
+//! ```
+//!
+//! The header gives the format version and whether the graph is directed.
+//! `n` records add the node of the given index, and `e` records add the edge
+//! of the given index, between the given source and target nodes. `-n` and
+//! `-e` records remove a node (with its edges) or an edge. Weights are
+//! written with their [`Display`] implementation and read with their
+//! [`FromStr`] implementation, with backslashes, line feeds and carriage
+//! returns escaped.
+//!
+//! Depends on `feature = "std"`.
+//!
+//! # Example
+//! ```rust
+//! use petgraph::stable_graph::log::{read_graph_log, LoggedGraph};
+//! use petgraph::stable_graph::StableGraph;
+//! use petgraph::Directed;
+//!
+//! let mut log = Vec::new();
+//! let mut graph = LoggedGraph::<String, u32, Directed, u32, _>::new(&mut log).unwrap();
+//! let a = graph.add_node("a".to_string()).unwrap();
+//! let b = graph.add_node("b".to_string()).unwrap();
+//! let c = graph.add_node("c".to_string()).unwrap();
+//! graph.add_edge(a, b, 1).unwrap();
+//! graph.add_edge(b, c, 2).unwrap();
+//! graph.remove_node(b).unwrap();
+//! let (graph, _) = graph.into_inner();
+//!
+//! let replayed: StableGraph<String, u32> = read_graph_log(&log[..]).unwrap();
+//! assert_eq!(replayed.node_count(), 2);
+//! assert_eq!(replayed.edge_count(), 0);
+//! assert_eq!(replayed[c], graph[c]);
+//! ```
+
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display};
+use core::str::FromStr;
+use std::io::{self, BufRead, Write};
+
+use super::{EdgeIndex, IndexType, NodeIndex, StableGraph};
+use crate::EdgeType;
+
+const HEADER: &str = "petgraph-log 1";
+
+/// A [`StableGraph`] that appends every change to a log.
+///
+/// Reading methods are available through [`graph`](Self::graph). Every
+/// mutating method applies the change to the graph first, and then writes
+/// the record. If writing fails, the error is returned and the change is
+/// kept in the graph, but the log is missing it (or has a partial record at
+/// its end, which [`read_graph_log`] ignores).
+pub struct LoggedGraph<N, E, Ty, Ix, W> {
+    graph: StableGraph<N, E, Ty, Ix>,
+    writer: W,
+}
+
+impl<N, E, Ty, Ix, W> fmt::Debug for LoggedGraph<N, E, Ty, Ix, W>
+where
+    N: fmt::Debug,
+    E: fmt::Debug,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoggedGraph")
+            .field("graph", &self.graph)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<N, E, Ty, Ix, W> LoggedGraph<N, E, Ty, Ix, W>
+where
+    N: Display,
+    E: Display,
+    Ty: EdgeType,
+    Ix: IndexType,
+    W: Write,
+{
+    /// Create an empty graph logging to `writer`, and write the log header.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        let kind = if Ty::is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        };
+        writeln!(writer, "{HEADER} {kind}")?;
+        Ok(LoggedGraph {
+            graph: StableGraph::with_capacity(0, 0),
+            writer,
+        })
+    }
+
+    /// Continue logging the changes of `graph` to `writer`.
+    ///
+    /// `graph` should be the result of [`read_graph_log`] on the log that
+    /// `writer` appends to, so that replaying the complete log gives the
+    /// same graph.
+    pub fn resume(graph: StableGraph<N, E, Ty, Ix>, writer: W) -> Self {
+        LoggedGraph { graph, writer }
+    }
+
+    /// Return the graph.
+    pub fn graph(&self) -> &StableGraph<N, E, Ty, Ix> {
+        &self.graph
+    }
+
+    /// Return the graph and the writer.
+    pub fn into_inner(self) -> (StableGraph<N, E, Ty, Ix>, W) {
+        (self.graph, self.writer)
+    }
+
+    /// Flush the writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Add a node to the graph and log it.
+    ///
+    /// **Panics** if the `StableGraph` is at the maximum number of nodes for
+    /// its index type.
+    pub fn add_node(&mut self, weight: N) -> io::Result<NodeIndex<Ix>> {
+        let line = escape(&weight.to_string());
+        let a = self.graph.add_node(weight);
+        writeln!(self.writer, "n {} {}", a.index(), line)?;
+        Ok(a)
+    }
+
+    /// Add an edge from `a` to `b` to the graph and log it.
+    ///
+    /// **Panics** if any of the nodes don't exist, or if the `StableGraph`
+    /// is at the maximum number of edges for its index type.
+    pub fn add_edge(
+        &mut self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+        weight: E,
+    ) -> io::Result<EdgeIndex<Ix>> {
+        let line = escape(&weight.to_string());
+        let e = self.graph.add_edge(a, b, weight);
+        writeln!(
+            self.writer,
+            "e {} {} {} {}",
+            e.index(),
+            a.index(),
+            b.index(),
+            line
+        )?;
+        Ok(e)
+    }
+
+    /// Remove node `a` and its edges from the graph, and log it.
+    ///
+    /// Return the node weight, or `None` if it didn't exist (in which case
+    /// nothing is logged).
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> io::Result<Option<N>> {
+        let weight = self.graph.remove_node(a);
+        if weight.is_some() {
+            writeln!(self.writer, "-n {}", a.index())?;
+        }
+        Ok(weight)
+    }
+
+    /// Remove edge `e` from the graph, and log it.
+    ///
+    /// Return the edge weight, or `None` if it didn't exist (in which case
+    /// nothing is logged).
+    pub fn remove_edge(&mut self, e: EdgeIndex<Ix>) -> io::Result<Option<E>> {
+        let weight = self.graph.remove_edge(e);
+        if weight.is_some() {
+            writeln!(self.writer, "-e {}", e.index())?;
+        }
+        Ok(weight)
+    }
+}
+
+/// The error type for [`read_graph_log`].
+#[derive(Debug)]
+pub enum GraphLogError {
+    /// Reading the log failed.
+    Io(io::Error),
+    /// The log does not start with a valid header.
+    InvalidHeader,
+    /// The log is for a directed graph and an undirected one was requested,
+    /// or the opposite.
+    EdgeTypeMismatch,
+    /// The record on the given line (starting from 1) is malformed.
+    InvalidRecord(usize),
+    /// The weight on the given line could not be parsed.
+    InvalidWeight(usize),
+    /// The record on the given line does not apply to the graph: it adds an
+    /// item with an unexpected index, refers to a missing node, or removes
+    /// a missing item.
+    Inconsistent(usize),
+}
+
+impl std::error::Error for GraphLogError {}
+
+impl fmt::Display for GraphLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphLogError::Io(err) => write!(f, "Failed to read the graph log: {err}"),
+            GraphLogError::InvalidHeader => write!(f, "Invalid graph log header."),
+            GraphLogError::EdgeTypeMismatch => {
+                write!(f, "The graph log has a different edge type.")
+            }
+            GraphLogError::InvalidRecord(line) => write!(f, "Invalid record on line {line}."),
+            GraphLogError::InvalidWeight(line) => write!(f, "Invalid weight on line {line}."),
+            GraphLogError::Inconsistent(line) => {
+                write!(f, "The record on line {line} does not apply to the graph.")
+            }
+        }
+    }
+}
+
+impl From<io::Error> for GraphLogError {
+    fn from(err: io::Error) -> Self {
+        GraphLogError::Io(err)
+    }
+}
+
+/// Reconstruct a [`StableGraph`] by replaying a log written by
+/// [`LoggedGraph`].
+///
+/// The node and edge indices of the result are the same as in the logged
+/// graph. A partial record at the end of the log (without its final line
+/// feed), as left by an interrupted write, is ignored.
+pub fn read_graph_log<N, E, Ty, Ix, R>(
+    mut reader: R,
+) -> Result<StableGraph<N, E, Ty, Ix>, GraphLogError>
+where
+    N: FromStr,
+    E: FromStr,
+    Ty: EdgeType,
+    Ix: IndexType,
+    R: BufRead,
+{
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 || !line.ends_with('\n') {
+        return Err(GraphLogError::InvalidHeader);
+    }
+    let directed = match line.trim_end().strip_prefix(HEADER) {
+        Some(" directed") => true,
+        Some(" undirected") => false,
+        _ => return Err(GraphLogError::InvalidHeader),
+    };
+    if directed != Ty::is_directed() {
+        return Err(GraphLogError::EdgeTypeMismatch);
+    }
+
+    let mut graph = StableGraph::with_capacity(0, 0);
+    let mut line_number = 1;
+    loop {
+        line.clear();
+        line_number += 1;
+        if reader.read_line(&mut line)? == 0 || !line.ends_with('\n') {
+            break;
+        }
+        let record = line.strip_suffix('\n').unwrap();
+        let record = record.strip_suffix('\r').unwrap_or(record);
+        replay(&mut graph, record, line_number)?;
+    }
+    Ok(graph)
+}
+
+fn replay<N, E, Ty, Ix>(
+    graph: &mut StableGraph<N, E, Ty, Ix>,
+    record: &str,
+    line: usize,
+) -> Result<(), GraphLogError>
+where
+    N: FromStr,
+    E: FromStr,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let (kind, rest) = record.split_once(' ').unwrap_or((record, ""));
+    let mut fields = rest.splitn(if kind == "e" { 4 } else { 2 }, ' ');
+    let mut index = || {
+        fields
+            .next()
+            .and_then(|field| field.parse::<usize>().ok())
+            .ok_or(GraphLogError::InvalidRecord(line))
+    };
+    match kind {
+        "n" => {
+            let a = index()?;
+            let weight = parse_weight(fields.next(), line)?;
+            if graph.add_node(weight).index() != a {
+                return Err(GraphLogError::Inconsistent(line));
+            }
+        }
+        "e" => {
+            let e = index()?;
+            let a = NodeIndex::new(index()?);
+            let b = NodeIndex::new(index()?);
+            let weight = parse_weight(fields.next(), line)?;
+            if !graph.contains_node(a) || !graph.contains_node(b) {
+                return Err(GraphLogError::Inconsistent(line));
+            }
+            if graph.add_edge(a, b, weight).index() != e {
+                return Err(GraphLogError::Inconsistent(line));
+            }
+        }
+        "-n" => {
+            let a = index()?;
+            if graph.remove_node(NodeIndex::new(a)).is_none() {
+                return Err(GraphLogError::Inconsistent(line));
+            }
+        }
+        "-e" => {
+            let e = index()?;
+            if graph.remove_edge(EdgeIndex::new(e)).is_none() {
+                return Err(GraphLogError::Inconsistent(line));
+            }
+        }
+        _ => return Err(GraphLogError::InvalidRecord(line)),
+    }
+    Ok(())
+}
+
+fn parse_weight<T: FromStr>(field: Option<&str>, line: usize) -> Result<T, GraphLogError> {
+    let field = field.ok_or(GraphLogError::InvalidRecord(line))?;
+    let text = unescape(field).ok_or(GraphLogError::InvalidRecord(line))?;
+    text.parse().map_err(|_| GraphLogError::InvalidWeight(line))
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(text: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next()? {
+                '\\' => unescaped.push('\\'),
+                'n' => unescaped.push('\n'),
+                'r' => unescaped.push('\r'),
+                _ => return None,
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Some(unescaped)
+}
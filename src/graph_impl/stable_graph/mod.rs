@@ -3,7 +3,7 @@
 //! Depends on `feature = "stable_graph"`.
 //!
 
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 use core::{
     cmp, fmt, iter,
     marker::PhantomData,
@@ -14,7 +14,7 @@ use core::{
 
 use fixedbitset::FixedBitSet;
 
-use super::{index_twice, Edge, Frozen, GraphError, Node, Pair, DIRECTIONS};
+use super::{count_edge_list, index_twice, Edge, Frozen, GraphError, Node, Pair, DIRECTIONS};
 use crate::iter_format::{DebugMap, IterFormatExt, NoPretty};
 use crate::iter_utils::IterUtilsExt;
 use crate::visit::{self, EdgeIndexable, EdgeRef, IntoEdgeReferences, NodeIndexable};
@@ -30,8 +30,11 @@ pub use crate::graph::{
 
 use crate::util::enumerate;
 
+#[cfg(feature = "std")]
+pub mod log;
 #[cfg(feature = "serde-1")]
 mod serialization;
+pub mod transaction;
 
 /// `StableGraph<N, E, Ty, Ix>` is a graph datastructure using an adjacency
 /// list representation.
@@ -282,6 +285,22 @@ where
         }
     }
 
+    /// Add a node slot after the last one, vacant if `weight` is `None`,
+    /// without reusing the vacant slots.
+    pub(crate) fn push_node_slot(&mut self, weight: Option<N>) {
+        match weight {
+            Some(weight) => {
+                self.node_count += 1;
+                self.g.add_node(Some(weight));
+            }
+            None => {
+                let mut free_node = self.free_node;
+                self.add_vacant_node(&mut free_node);
+                self.free_node = free_node;
+            }
+        }
+    }
+
     /// free_node: Which free list to update for the vacancy
     fn add_vacant_node(&mut self, free_node: &mut NodeIndex<Ix>) {
         let node_idx = self.g.add_node(None);
@@ -798,6 +817,59 @@ where
         }
     }
 
+    /// Return the number of edges starting from `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`. A self loop counts once.
+    /// - `Undirected`: Same as [`.degree(a)`](#method.degree).
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    pub fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        if self.is_directed() {
+            self.degree_directed(a, Outgoing)
+        } else {
+            self.degree(a)
+        }
+    }
+
+    /// Return the number of edges ending at `a`.
+    ///
+    /// - `Directed`: Incoming edges to `a`. A self loop counts once.
+    /// - `Undirected`: Same as [`.degree(a)`](#method.degree).
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    pub fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        if self.is_directed() {
+            self.degree_directed(a, Incoming)
+        } else {
+            self.degree(a)
+        }
+    }
+
+    /// Return the number of edge endpoints at `a`: a self loop counts twice,
+    /// and for `Directed` graphs this is the sum of the in- and out-degree.
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    pub fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        self.degree_directed(a, Outgoing) + self.degree_directed(a, Incoming)
+    }
+
+    /// Count the edges in the list of `a` for direction `dir`.
+    fn degree_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> usize {
+        match self.get_node(a) {
+            None => 0,
+            Some(node) => count_edge_list(&self.g.edges, node.next[dir.index()], dir),
+        }
+    }
+
     /// Return an iterator over either the nodes without edges to them
     /// (`Incoming`) or from them (`Outgoing`).
     ///
@@ -900,6 +972,73 @@ where
         self.check_free_lists();
     }
 
+    /// Remove the vacancies left by removed nodes and edges, so that the
+    /// node and edge indices form a compact interval again, and shrink the
+    /// storage to fit.
+    ///
+    /// Nodes and edges keep their relative order. Return the mapping from
+    /// the old indices to the new ones; all other indices are invalidated.
+    ///
+    /// Computes in **O(|V| + |E|)** time, where **|V|** and **|E|** are the
+    /// node and edge bounds.
+    ///
+    /// ```
+    /// use petgraph::stable_graph::StableGraph;
+    /// use petgraph::visit::NodeIndexable;
+    ///
+    /// let mut gr = StableGraph::<_, ()>::new();
+    /// let a = gr.add_node("a");
+    /// let b = gr.add_node("b");
+    /// let c = gr.add_node("c");
+    /// gr.add_edge(a, c, ());
+    /// gr.remove_node(b);
+    ///
+    /// let compaction = gr.compact();
+    /// let c2 = compaction.node(c).unwrap();
+    /// assert_eq!(c2.index(), 1);
+    /// assert_eq!(gr[c2], "c");
+    /// assert_eq!(compaction.node(b), None);
+    /// assert_eq!(gr.node_bound(), 2);
+    /// ```
+    pub fn compact(&mut self) -> Compaction<Ix> {
+        let (graph, compaction) = core::mem::take(self).into_compact_graph();
+        *self = StableGraph::from(graph);
+        compaction
+    }
+
+    /// Convert the graph into a `Graph` without vacancies, and return it
+    /// with the mapping from the old indices to the indices in the `Graph`.
+    ///
+    /// The result is the same as with `Graph::from`, which only returns the
+    /// graph.
+    ///
+    /// Computes in **O(|V| + |E|)** time, where **|V|** and **|E|** are the
+    /// node and edge bounds.
+    pub fn into_compact_graph(self) -> (Graph<N, E, Ty, Ix>, Compaction<Ix>) {
+        let mut result_g = Graph::with_capacity(self.node_count(), self.edge_count());
+        // mapping from old index to new index
+        let mut nodes = vec![NodeIndex::end(); self.g.nodes.len()];
+        let mut edges = vec![EdgeIndex::end(); self.g.edges.len()];
+
+        for (i, node) in enumerate(self.g.nodes) {
+            if let Some(nw) = node.weight {
+                nodes[i] = result_g.add_node(nw);
+            }
+        }
+        for (i, edge) in enumerate(self.g.edges) {
+            let source_index = edge.source().index();
+            let target_index = edge.target().index();
+            if let Some(ew) = edge.weight {
+                let source = nodes[source_index];
+                let target = nodes[target_index];
+                debug_assert!(source != NodeIndex::end());
+                debug_assert!(target != NodeIndex::end());
+                edges[i] = result_g.add_edge(source, target, ew);
+            }
+        }
+        (result_g, Compaction { nodes, edges })
+    }
+
     /// Create a new `StableGraph` from an iterable of edges.
     ///
     /// Node weights `N` are set to default values.
@@ -1337,27 +1476,36 @@ where
     Ix: IndexType,
 {
     fn from(graph: StableGraph<N, E, Ty, Ix>) -> Self {
-        let mut result_g = Graph::with_capacity(graph.node_count(), graph.edge_count());
-        // mapping from old node index to new node index
-        let mut node_index_map = vec![NodeIndex::end(); graph.node_bound()];
+        graph.into_compact_graph().0
+    }
+}
 
-        for (i, node) in enumerate(graph.g.nodes) {
-            if let Some(nw) = node.weight {
-                node_index_map[i] = result_g.add_node(nw);
-            }
-        }
-        for edge in graph.g.edges {
-            let source_index = edge.source().index();
-            let target_index = edge.target().index();
-            if let Some(ew) = edge.weight {
-                let source = node_index_map[source_index];
-                let target = node_index_map[target_index];
-                debug_assert!(source != NodeIndex::end());
-                debug_assert!(target != NodeIndex::end());
-                result_g.add_edge(source, target, ew);
-            }
-        }
-        result_g
+/// The mapping from the old node and edge indices of a `StableGraph` to the
+/// new ones, returned by [`StableGraph::compact`] and
+/// [`StableGraph::into_compact_graph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compaction<Ix = DefaultIx> {
+    nodes: Vec<NodeIndex<Ix>>,
+    edges: Vec<EdgeIndex<Ix>>,
+}
+
+impl<Ix: IndexType> Compaction<Ix> {
+    /// Return the new index of the node that had index `a`, or `None` if
+    /// there was no such node.
+    pub fn node(&self, a: NodeIndex<Ix>) -> Option<NodeIndex<Ix>> {
+        self.nodes
+            .get(a.index())
+            .copied()
+            .filter(|&ix| ix != NodeIndex::end())
+    }
+
+    /// Return the new index of the edge that had index `e`, or `None` if
+    /// there was no such edge.
+    pub fn edge(&self, e: EdgeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        self.edges
+            .get(e.index())
+            .copied()
+            .filter(|&ix| ix != EdgeIndex::end())
     }
 }
 
@@ -2050,6 +2198,22 @@ where
     }
 }
 
+impl<N, E, Ty, Ix> visit::Degree for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        StableGraph::out_degree(self, a)
+    }
+    fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        StableGraph::in_degree(self, a)
+    }
+    fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        StableGraph::degree(self, a)
+    }
+}
+
 #[test]
 fn stable_graph() {
     use std::println;
@@ -0,0 +1,368 @@
+//! Speculative changes to a `StableGraph`, that can be rolled back.
+//!
+//! A [`TransactionalGraph`] wraps a [`StableGraph`] and records every node or
+//! edge that is added or removed, and every weight that is replaced, since
+//! the last [`commit`](TransactionalGraph::commit).
+//! [`rollback`](TransactionalGraph::rollback) undoes them, in reverse order,
+//! and leaves the graph exactly as it was: the same nodes and edges, with the
+//! same indices, in the same order, and the same indices for the next nodes
+//! and edges to be added. A search can try a change and undo it in time
+//! proportional to the change, without cloning the graph, and
+//! [`savepoint`](TransactionalGraph::savepoint) and
+//! [`rollback_to`](TransactionalGraph::rollback_to) nest such trials.
+//!
+//! # Example
+//! ```rust
+//! use petgraph::algo::has_path_connecting;
+//! use petgraph::stable_graph::transaction::TransactionalGraph;
+//! use petgraph::stable_graph::StableUnGraph;
+//!
+//! let graph = StableUnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+//! let mut graph = TransactionalGraph::new(graph);
+//!
+//! // The edges whose removal disconnects their endpoints.
+//! let mut bridges = Vec::new();
+//! for e in graph.edge_indices().collect::<Vec<_>>() {
+//!     let (a, b) = graph.edge_endpoints(e).unwrap();
+//!     let savepoint = graph.savepoint();
+//!     graph.remove_edge(e);
+//!     if !has_path_connecting(&*graph, a, b, None) {
+//!         bridges.push(e);
+//!     }
+//!     graph.rollback_to(savepoint);
+//! }
+//! assert_eq!(bridges, [3.into()]);
+//! assert_eq!(graph.edge_count(), 4);
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+use super::{EdgeIndex, IndexType, NodeIndex, StableGraph, DIRECTIONS};
+use crate::graph::DefaultIx;
+use crate::{Directed, EdgeType};
+
+/// A change to the graph, with what it takes to undo it.
+enum Change<N, E, Ix> {
+    /// A node was added, after the last one if `pushed`, or else in the
+    /// first vacant slot.
+    AddNode { node: NodeIndex<Ix>, pushed: bool },
+    /// An edge was added, after the last one if `pushed`, or else in the
+    /// first vacant slot.
+    AddEdge { edge: EdgeIndex<Ix>, pushed: bool },
+    /// A node without edges was removed.
+    RemoveNode { node: NodeIndex<Ix>, weight: N },
+    /// An edge was removed, with the edges that followed it in the lists of
+    /// its source and target.
+    RemoveEdge {
+        edge: EdgeIndex<Ix>,
+        nodes: [NodeIndex<Ix>; 2],
+        next: [EdgeIndex<Ix>; 2],
+        weight: E,
+    },
+    /// The weight of a node was replaced.
+    NodeWeight { node: NodeIndex<Ix>, weight: N },
+    /// The weight of an edge was replaced.
+    EdgeWeight { edge: EdgeIndex<Ix>, weight: E },
+}
+
+/// A [`StableGraph`] whose changes can be rolled back.
+///
+/// Reading methods are available through `Deref`. Every mutating method
+/// records how to undo the change, until the next
+/// [`commit`](Self::commit).
+pub struct TransactionalGraph<N, E, Ty = Directed, Ix = DefaultIx> {
+    graph: StableGraph<N, E, Ty, Ix>,
+    changes: Vec<Change<N, E, Ix>>,
+    commits: usize,
+}
+
+/// A point in the changes of a [`TransactionalGraph`] to roll back to, made
+/// by [`savepoint`](TransactionalGraph::savepoint).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Savepoint {
+    commits: usize,
+    changes: usize,
+}
+
+impl<N, E, Ty, Ix> fmt::Debug for TransactionalGraph<N, E, Ty, Ix>
+where
+    N: fmt::Debug,
+    E: fmt::Debug,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransactionalGraph")
+            .field("graph", &self.graph)
+            .field("pending_changes", &self.changes.len())
+            .finish()
+    }
+}
+
+impl<N, E, Ty, Ix> Default for TransactionalGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        TransactionalGraph::new(StableGraph::default())
+    }
+}
+
+impl<N, E, Ty, Ix> Deref for TransactionalGraph<N, E, Ty, Ix> {
+    type Target = StableGraph<N, E, Ty, Ix>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.graph
+    }
+}
+
+impl<N, E, Ty, Ix> TransactionalGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Wrap `graph`, without pending changes.
+    pub fn new(graph: StableGraph<N, E, Ty, Ix>) -> Self {
+        TransactionalGraph {
+            graph,
+            changes: Vec::new(),
+            commits: 0,
+        }
+    }
+
+    /// Return the graph.
+    pub fn graph(&self) -> &StableGraph<N, E, Ty, Ix> {
+        &self.graph
+    }
+
+    /// Return the graph, with its pending changes.
+    pub fn into_graph(self) -> StableGraph<N, E, Ty, Ix> {
+        self.graph
+    }
+
+    /// Return the number of changes since the last commit.
+    pub fn pending_changes(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Keep the changes since the last commit, which can't be rolled back
+    /// anymore.
+    pub fn commit(&mut self) {
+        self.changes.clear();
+        self.commits += 1;
+    }
+
+    /// Undo the changes since the last commit.
+    ///
+    /// Computes in **O(c + e')** time, where **c** is the number of changes
+    /// and **e'** the number of edges next to the removed edges.
+    pub fn rollback(&mut self) {
+        while let Some(change) = self.changes.pop() {
+            self.undo(change);
+        }
+    }
+
+    /// Return a savepoint, to undo the next changes with
+    /// [`rollback_to`](Self::rollback_to).
+    pub fn savepoint(&self) -> Savepoint {
+        Savepoint {
+            commits: self.commits,
+            changes: self.changes.len(),
+        }
+    }
+
+    /// Undo the changes since `savepoint`.
+    ///
+    /// Rolling back to a savepoint also discards the savepoints made after
+    /// it.
+    ///
+    /// **Panics** if the changes since `savepoint` have been committed, or
+    /// already rolled back.
+    #[track_caller]
+    pub fn rollback_to(&mut self, savepoint: Savepoint) {
+        assert!(
+            savepoint.commits == self.commits && savepoint.changes <= self.changes.len(),
+            "TransactionalGraph::rollback_to: the savepoint is no longer valid"
+        );
+        while self.changes.len() > savepoint.changes {
+            let change = self.changes.pop().unwrap();
+            self.undo(change);
+        }
+    }
+
+    /// Add a node with associated data `weight` to the graph, and return its
+    /// index.
+    ///
+    /// **Panics** if the `StableGraph` is at the maximum number of nodes for
+    /// its index type.
+    #[track_caller]
+    pub fn add_node(&mut self, weight: N) -> NodeIndex<Ix> {
+        let pushed = self.graph.free_node == NodeIndex::end();
+        let node = self.graph.add_node(weight);
+        self.changes.push(Change::AddNode { node, pushed });
+        node
+    }
+
+    /// Add an edge from `a` to `b` to the graph, with its associated data
+    /// `weight`, and return its index.
+    ///
+    /// **Panics** if any of the nodes don't exist.<br>
+    /// **Panics** if the `StableGraph` is at the maximum number of edges for
+    /// its index type.
+    #[track_caller]
+    pub fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> EdgeIndex<Ix> {
+        let pushed = self.graph.free_edge == EdgeIndex::end();
+        let edge = self.graph.add_edge(a, b, weight);
+        self.changes.push(Change::AddEdge { edge, pushed });
+        edge
+    }
+
+    /// Remove `a` and its edges from the graph, and return its weight, or
+    /// `None` if it didn't exist.
+    ///
+    /// The weight is kept to restore the node on rollback, until the next
+    /// commit.
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> Option<&N> {
+        if !self.graph.contains_node(a) {
+            return None;
+        }
+        // Remove the edges one by one, as `StableGraph::remove_node` does.
+        for d in &DIRECTIONS {
+            loop {
+                let next = self.graph.g.nodes[a.index()].next[d.index()];
+                if next == EdgeIndex::end() {
+                    break;
+                }
+                self.remove_edge(next);
+            }
+        }
+        let weight = self.graph.remove_node(a)?;
+        self.changes.push(Change::RemoveNode { node: a, weight });
+        match self.changes.last() {
+            Some(Change::RemoveNode { weight, .. }) => Some(weight),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Remove the edge `e` from the graph, and return its weight, or `None`
+    /// if it didn't exist.
+    ///
+    /// The weight is kept to restore the edge on rollback, until the next
+    /// commit.
+    pub fn remove_edge(&mut self, e: EdgeIndex<Ix>) -> Option<&E> {
+        let (nodes, next) = match self.graph.g.edges.get(e.index()) {
+            Some(edge) if edge.weight.is_some() => (edge.node, edge.next),
+            _ => return None,
+        };
+        let weight = self.graph.remove_edge(e)?;
+        self.changes.push(Change::RemoveEdge {
+            edge: e,
+            nodes,
+            next,
+            weight,
+        });
+        match self.changes.last() {
+            Some(Change::RemoveEdge { weight, .. }) => Some(weight),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Replace the weight of the node `a`, and return the previous one, or
+    /// `None` if the node doesn't exist.
+    ///
+    /// The previous weight is kept to restore it on rollback, until the next
+    /// commit.
+    pub fn set_node_weight(&mut self, a: NodeIndex<Ix>, weight: N) -> Option<&N> {
+        let slot = self.graph.node_weight_mut(a)?;
+        let weight = core::mem::replace(slot, weight);
+        self.changes.push(Change::NodeWeight { node: a, weight });
+        match self.changes.last() {
+            Some(Change::NodeWeight { weight, .. }) => Some(weight),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Replace the weight of the edge `e`, and return the previous one, or
+    /// `None` if the edge doesn't exist.
+    ///
+    /// The previous weight is kept to restore it on rollback, until the next
+    /// commit.
+    pub fn set_edge_weight(&mut self, e: EdgeIndex<Ix>, weight: E) -> Option<&E> {
+        let slot = self.graph.edge_weight_mut(e)?;
+        let weight = core::mem::replace(slot, weight);
+        self.changes.push(Change::EdgeWeight { edge: e, weight });
+        match self.changes.last() {
+            Some(Change::EdgeWeight { weight, .. }) => Some(weight),
+            _ => unreachable!(),
+        }
+    }
+
+    fn undo(&mut self, change: Change<N, E, Ix>) {
+        let graph = &mut self.graph;
+        match change {
+            Change::AddNode { node, pushed } => {
+                let _weight = graph.remove_node(node);
+                debug_assert!(_weight.is_some());
+                if pushed {
+                    // Unlink the node from the head of the free list, and
+                    // drop its slot.
+                    let next = graph.g.nodes[node.index()].next[0]._into_node();
+                    if next != NodeIndex::end() {
+                        graph.g.nodes[next.index()].next[1] = EdgeIndex::end();
+                    }
+                    graph.free_node = next;
+                    graph.g.nodes.pop();
+                }
+            }
+            Change::AddEdge { edge, pushed } => {
+                let _weight = graph.remove_edge(edge);
+                debug_assert!(_weight.is_some());
+                if pushed {
+                    graph.free_edge = graph.g.edges[edge.index()].next[0];
+                    graph.g.edges.pop();
+                }
+            }
+            Change::RemoveNode { node, weight } => {
+                debug_assert!(graph.free_node == node);
+                graph.occupy_vacant_node(node, weight);
+            }
+            Change::RemoveEdge {
+                edge,
+                nodes,
+                next,
+                weight,
+            } => {
+                debug_assert!(graph.free_edge == edge);
+                let slot = &mut graph.g.edges[edge.index()];
+                graph.free_edge = slot.next[0];
+                slot.weight = Some(weight);
+                slot.node = nodes;
+                slot.next = next;
+                // Put the edge back before `next` in the lists of its source
+                // and target, where it was.
+                for (k, node) in nodes.iter().enumerate() {
+                    let head = &mut graph.g.nodes[node.index()].next[k];
+                    if *head == next[k] {
+                        *head = edge;
+                        continue;
+                    }
+                    let mut e = *head;
+                    while graph.g.edges[e.index()].next[k] != next[k] {
+                        e = graph.g.edges[e.index()].next[k];
+                    }
+                    graph.g.edges[e.index()].next[k] = edge;
+                }
+                graph.edge_count += 1;
+            }
+            Change::NodeWeight { node, weight } => {
+                graph[node] = weight;
+            }
+            Change::EdgeWeight { edge, weight } => {
+                graph[edge] = weight;
+            }
+        }
+    }
+}
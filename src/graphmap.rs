@@ -524,6 +524,57 @@ where
         }
     }
 
+    /// Return the number of edges starting from `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`. A self loop counts once.
+    /// - `Undirected`: Same as [`.degree(a)`](#method.degree).
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    pub fn out_degree(&self, a: N) -> usize {
+        self.degree_directed(a, CompactDirection::Outgoing)
+    }
+
+    /// Return the number of edges ending at `a`.
+    ///
+    /// - `Directed`: Incoming edges to `a`. A self loop counts once.
+    /// - `Undirected`: Same as [`.degree(a)`](#method.degree).
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    pub fn in_degree(&self, a: N) -> usize {
+        self.degree_directed(a, CompactDirection::Incoming)
+    }
+
+    /// Return the number of edge endpoints at `a`: a self loop counts twice,
+    /// and for `Directed` graphs this is the sum of the in- and out-degree.
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges
+    /// connected to `a`.
+    pub fn degree(&self, a: N) -> usize {
+        match self.nodes.get(&a) {
+            None => 0,
+            // A self loop has a single entry in the adjacency list.
+            Some(neigh) => neigh.len() + neigh.iter().filter(|&&(b, _)| b == a).count(),
+        }
+    }
+
+    fn degree_directed(&self, a: N, dir: CompactDirection) -> usize {
+        if !Ty::is_directed() {
+            return self.degree(a);
+        }
+        match self.nodes.get(&a) {
+            None => 0,
+            Some(neigh) => neigh.iter().filter(|&&(b, d)| d == dir || b == a).count(),
+        }
+    }
+
     /// Return a reference to the edge weight connecting `a` with `b`, or
     /// `None` if the edge does not exist in the graph.
     pub fn edge_weight(&self, a: N, b: N) -> Option<&E> {
@@ -1346,6 +1397,23 @@ where
     }
 }
 
+impl<N, E, Ty, S> visit::Degree for GraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn out_degree(&self, a: N) -> usize {
+        GraphMap::out_degree(self, a)
+    }
+    fn in_degree(&self, a: N) -> usize {
+        GraphMap::in_degree(self, a)
+    }
+    fn degree(&self, a: N) -> usize {
+        GraphMap::degree(self, a)
+    }
+}
+
 /// The `GraphMap` keeps an adjacency matrix internally.
 impl<N, E, Ty, S> visit::GetAdjacencyMatrix for GraphMap<N, E, Ty, S>
 where
@@ -0,0 +1,588 @@
+//! [GraphML](http://graphml.graphdrawing.org/) import and export, with typed
+//! node and edge attributes.
+//!
+//! GraphML is the XML format of yEd, Gephi, Cytoscape and networkx, among
+//! others. [`GraphMl`] writes any graph, with the attributes given by two
+//! functions, and [`from_graphml`] reads a document into a [`Graph`] or a
+//! [`StableGraph`] whose weights hold the ids and attributes of the nodes
+//! and edges.
+//!
+//! Only the parts of GraphML that describe a plain graph are supported:
+//! nested graphs are flattened, and hyperedges, ports and the attributes of
+//! the graph itself are ignored, as well as the contents of the attributes
+//! that are not character data, like the graphics of yEd.
+//!
+//! [`Graph`]: crate::graph::Graph
+//! [`StableGraph`]: crate::stable_graph::StableGraph
+//!
+//! # Example
+//!
+//! ```
+//! use petgraph::graph::{DiGraph, EdgeReference};
+//! use petgraph::graphml::{from_graphml, AttrValue, Attributes, GraphMl, GraphMlEdge, GraphMlNode};
+//! use petgraph::visit::EdgeRef;
+//!
+//! let mut graph = DiGraph::<&str, f64>::new();
+//! let a = graph.add_node("a");
+//! let b = graph.add_node("b");
+//! graph.add_edge(a, b, 2.5);
+//!
+//! let node_attributes = |(_, name): (_, &&str)| {
+//!     Attributes::from([("name".into(), AttrValue::from(*name))])
+//! };
+//! let edge_attributes = |edge: EdgeReference<f64>| {
+//!     Attributes::from([("weight".into(), AttrValue::from(*edge.weight()))])
+//! };
+//! let xml = GraphMl::with_attr_getters(&graph, &node_attributes, &edge_attributes).to_string();
+//!
+//! let parsed: DiGraph<GraphMlNode, GraphMlEdge> = from_graphml(&xml).unwrap();
+//! assert_eq!(parsed[a].attributes["name"], AttrValue::String("a".into()));
+//! assert_eq!(parsed.raw_edges()[0].weight.attributes["weight"], AttrValue::Float(2.5));
+//! ```
+
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+use core::fmt::{self, Display, Write};
+
+use hashbrown::HashMap;
+
+use crate::data::Create;
+pub use crate::io::{AttrValue, Attributes};
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+use crate::xml::{Escaped, Event, Reader};
+
+/// Parse a value of the GraphML type `type_name`, or return `None` if the
+/// type is unknown or the value invalid.
+fn parse_value(type_name: &str, value: &str) -> Option<AttrValue> {
+    let trimmed = value.trim();
+    match type_name {
+        "boolean" => match trimmed {
+            "true" | "1" => Some(AttrValue::Boolean(true)),
+            "false" | "0" => Some(AttrValue::Boolean(false)),
+            _ => None,
+        },
+        "int" | "long" => trimmed.parse().ok().map(AttrValue::Int),
+        "float" | "double" => trimmed.parse().ok().map(AttrValue::Float),
+        "string" => Some(AttrValue::String(value.into())),
+        _ => None,
+    }
+}
+
+/// The weight of a node read by [`from_graphml`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GraphMlNode {
+    /// The id of the node in the document.
+    pub id: String,
+    /// The attributes of the node, with the defaults of the document for
+    /// those it does not set.
+    pub attributes: Attributes,
+}
+
+/// The weight of an edge read by [`from_graphml`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GraphMlEdge {
+    /// The id of the edge in the document, if it has one.
+    pub id: Option<String>,
+    /// The attributes of the edge, with the defaults of the document for
+    /// those it does not set.
+    pub attributes: Attributes,
+}
+
+/// `GraphMl` implements output to GraphML for a graph.
+///
+/// The nodes get the ids `n0`, `n1`, ..., from their indices, and the edges
+/// `e0`, `e1`, ..., in the order of [`IntoEdgeReferences`]. Each attribute
+/// name of the nodes, and of the edges, is declared once, with the type of
+/// its values, or the type `string` if its values have different types.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::UnGraph;
+/// use petgraph::graphml::GraphMl;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let xml = GraphMl::new(&graph).to_string();
+/// assert!(xml.contains(r#"<graph id="G" edgedefault="undirected">"#));
+/// assert!(xml.contains(r#"<edge id="e0" source="n0" target="n1"/>"#));
+/// ```
+pub struct GraphMl<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    graph: G,
+    get_node_attributes: &'a dyn Fn(G::NodeRef) -> Attributes,
+    get_edge_attributes: &'a dyn Fn(G::EdgeRef) -> Attributes,
+}
+
+impl<'a, G> GraphMl<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    /// Create a `GraphMl` formatting wrapper, without attributes.
+    pub fn new(graph: G) -> Self {
+        Self::with_attr_getters(graph, &|_| Attributes::new(), &|_| Attributes::new())
+    }
+
+    /// Create a `GraphMl` formatting wrapper, with the attributes of each
+    /// node and edge given by `get_node_attributes` and
+    /// `get_edge_attributes`.
+    pub fn with_attr_getters(
+        graph: G,
+        get_node_attributes: &'a dyn Fn(G::NodeRef) -> Attributes,
+        get_edge_attributes: &'a dyn Fn(G::EdgeRef) -> Attributes,
+    ) -> Self {
+        GraphMl {
+            graph,
+            get_node_attributes,
+            get_edge_attributes,
+        }
+    }
+}
+
+/// The ids and types of the declared attributes, by name.
+type Keys<'k> = BTreeMap<&'k str, (String, &'static str)>;
+
+/// Declare the attribute names of `attributes`, with ids from `d{first_id}`.
+fn declare<'k, I>(attributes: I, first_id: usize) -> Keys<'k>
+where
+    I: IntoIterator<Item = &'k Attributes>,
+{
+    let mut keys = Keys::new();
+    for (name, value) in attributes.into_iter().flatten() {
+        let next_id = first_id + keys.len();
+        let (_, type_name) = keys
+            .entry(name)
+            .or_insert_with(|| (format!("d{next_id}"), value.type_name()));
+        if *type_name != value.type_name() {
+            *type_name = "string";
+        }
+    }
+    keys
+}
+
+fn write_keys(f: &mut fmt::Formatter<'_>, keys: &Keys<'_>, domain: &str) -> fmt::Result {
+    for (name, (id, type_name)) in keys {
+        writeln!(
+            f,
+            r#"  <key id="{id}" for="{domain}" attr.name="{}" attr.type="{type_name}"/>"#,
+            Escaped(name)
+        )?;
+    }
+    Ok(())
+}
+
+/// Write the start tag `start` of an element, its attributes as data
+/// elements, and its end tag.
+fn write_element(
+    f: &mut fmt::Formatter<'_>,
+    start: fmt::Arguments<'_>,
+    element: &str,
+    attributes: &Attributes,
+    keys: &Keys<'_>,
+) -> fmt::Result {
+    if attributes.is_empty() {
+        return writeln!(f, "    <{start}/>");
+    }
+    writeln!(f, "    <{start}>")?;
+    for (name, value) in attributes {
+        let mut text = String::new();
+        write!(text, "{value}")?;
+        writeln!(
+            f,
+            r#"      <data key="{}">{}</data>"#,
+            keys[name.as_str()].0,
+            Escaped(&text)
+        )?;
+    }
+    writeln!(f, "    </{element}>")
+}
+
+impl<G> Display for GraphMl<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let g = self.graph;
+        let node_attributes: Vec<_> = g
+            .node_references()
+            .map(|node| (g.to_index(node.id()), (self.get_node_attributes)(node)))
+            .collect();
+        let edge_attributes: Vec<_> = g
+            .edge_references()
+            .map(|edge| {
+                let ends = (g.to_index(edge.source()), g.to_index(edge.target()));
+                (ends, (self.get_edge_attributes)(edge))
+            })
+            .collect();
+        let node_keys = declare(node_attributes.iter().map(|(_, a)| a), 0);
+        let edge_keys = declare(edge_attributes.iter().map(|(_, a)| a), node_keys.len());
+
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            f,
+            concat!(
+                r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns""#,
+                r#" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance""#,
+                r#" xsi:schemaLocation="http://graphml.graphdrawing.org/xmlns"#,
+                r#" http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd">"#
+            )
+        )?;
+        write_keys(f, &node_keys, "node")?;
+        write_keys(f, &edge_keys, "edge")?;
+        let edgedefault = if g.is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        };
+        writeln!(f, r#"  <graph id="G" edgedefault="{edgedefault}">"#)?;
+        for (index, attributes) in &node_attributes {
+            let start = format_args!(r#"node id="n{index}""#);
+            write_element(f, start, "node", attributes, &node_keys)?;
+        }
+        for (i, ((a, b), attributes)) in edge_attributes.iter().enumerate() {
+            let start = format_args!(r#"edge id="e{i}" source="n{a}" target="n{b}""#);
+            write_element(f, start, "edge", attributes, &edge_keys)?;
+        }
+        writeln!(f, "  </graph>")?;
+        writeln!(f, "</graphml>")
+    }
+}
+
+impl<G> fmt::Debug for GraphMl<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// An error while reading a GraphML document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GraphMlError {
+    /// The document is not well-formed XML, or not GraphML, at the line.
+    Syntax {
+        /// The line of the error, from `1`.
+        line: usize,
+        /// A description of the error.
+        message: &'static str,
+    },
+    /// Two nodes have the same id.
+    DuplicateNode(String),
+    /// An edge refers to a node id that is not declared.
+    UnknownNode(String),
+    /// A data element refers to a key id that is not declared.
+    UnknownKey(String),
+    /// A value, or a default value, is invalid for the type of its key.
+    InvalidValue {
+        /// The id of the key.
+        key: String,
+        /// The invalid value.
+        value: String,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GraphMlError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for GraphMlError {}
+
+impl Display for GraphMlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphMlError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            GraphMlError::DuplicateNode(id) => write!(f, "duplicate node id {id:?}"),
+            GraphMlError::UnknownNode(id) => write!(f, "edge to undeclared node id {id:?}"),
+            GraphMlError::UnknownKey(id) => write!(f, "data for undeclared key id {id:?}"),
+            GraphMlError::InvalidValue { key, value } => {
+                write!(f, "invalid value {value:?} for key id {key:?}")
+            }
+        }
+    }
+}
+
+/// A key declaration.
+#[derive(Default)]
+struct Key {
+    name: String,
+    type_name: String,
+    domain: String,
+    default: Option<String>,
+}
+
+/// A node or an edge, with the data of its attributes, by key id.
+#[derive(Default)]
+struct Element {
+    id: Option<String>,
+    ends: Option<(String, String)>,
+    data: Vec<(String, String)>,
+}
+
+/// Where the character data goes.
+enum Text {
+    None,
+    /// The data of the attribute with this key id of the current element.
+    Data(String),
+    /// The default value of the current key.
+    Default,
+}
+
+/// \[Generic\] Read a GraphML document into a graph.
+///
+/// The graph gets the nodes in the order of the document, weighted by
+/// their id and attributes, and then the edges, weighted by their id, if
+/// any, and attributes. Each attribute value is converted to the type of
+/// its key; a key without a type is a string. Elements without a value for
+/// a key get its default, if it has one. The edges are added in the
+/// direction of the document, whether or not the graph is directed.
+///
+/// # Arguments
+/// * `input`: the GraphML document.
+///
+/// # Returns
+/// * The graph, or an error if the document is not valid GraphML, if an
+///   edge refers to an undeclared node, or if a value does not have the
+///   type of its key.
+///
+/// # Complexity
+/// * Time complexity: **O(n)**.
+/// * Auxiliary space: **O(n)**.
+///
+/// where **n** is the length of the document.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::graphml::{from_graphml, AttrValue, GraphMlEdge, GraphMlNode};
+///
+/// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+///   <key id="d0" for="node" attr.name="color" attr.type="string">
+///     <default>yellow</default>
+///   </key>
+///   <key id="d1" for="edge" attr.name="weight" attr.type="double"/>
+///   <graph id="G" edgedefault="undirected">
+///     <node id="a"><data key="d0">green</data></node>
+///     <node id="b"/>
+///     <edge source="a" target="b"><data key="d1">1.5</data></edge>
+///   </graph>
+/// </graphml>"#;
+///
+/// let graph: UnGraph<GraphMlNode, GraphMlEdge> = from_graphml(xml).unwrap();
+/// assert_eq!(graph.node_count(), 2);
+/// assert_eq!(graph.raw_nodes()[1].weight.id, "b");
+/// assert_eq!(graph.raw_nodes()[1].weight.attributes["color"], AttrValue::from("yellow"));
+/// assert_eq!(graph.raw_edges()[0].weight.attributes["weight"], AttrValue::Float(1.5));
+/// ```
+pub fn from_graphml<G>(input: &str) -> Result<G, GraphMlError>
+where
+    G: Create<NodeWeight = GraphMlNode, EdgeWeight = GraphMlEdge>,
+{
+    let mut reader = Reader::new(input);
+    let syntax = |reader: &Reader, message| GraphMlError::Syntax {
+        line: reader.line(),
+        message,
+    };
+    let attribute = |attributes: &mut Vec<(&str, String)>, name: &str| {
+        attributes
+            .iter()
+            .position(|&(key, _)| key == name)
+            .map(|i| attributes.swap_remove(i).1)
+    };
+
+    let mut keys: HashMap<String, Key> = HashMap::new();
+    let mut key: Option<(String, Key)> = None;
+    let mut nodes: Vec<Element> = Vec::new();
+    let mut edges: Vec<Element> = Vec::new();
+    // The open nodes and edges, which nested graphs make more than one,
+    // and whether each is an edge.
+    let mut open: Vec<(Element, bool)> = Vec::new();
+    let mut text = Text::None;
+    let mut buffer = String::new();
+    // The depth of the elements inside a data element, whose content is
+    // ignored.
+    let mut ignored = 0;
+    let mut root = true;
+
+    while let Some(event) = reader.next_event().map_err(|m| syntax(&reader, m))? {
+        match event {
+            Event::Start {
+                name,
+                mut attributes,
+                empty,
+            } => {
+                if root {
+                    if name != "graphml" {
+                        return Err(syntax(&reader, "the root element is not graphml"));
+                    }
+                    root = false;
+                    continue;
+                }
+                if !matches!(text, Text::None) || ignored > 0 {
+                    if !empty {
+                        ignored += 1;
+                    }
+                    continue;
+                }
+                match name {
+                    "key" => {
+                        let id = attribute(&mut attributes, "id")
+                            .ok_or_else(|| syntax(&reader, "key without id"))?;
+                        let declared = Key {
+                            name: attribute(&mut attributes, "attr.name")
+                                .unwrap_or_else(|| id.clone()),
+                            type_name: attribute(&mut attributes, "attr.type")
+                                .unwrap_or_else(|| "string".into()),
+                            domain: attribute(&mut attributes, "for")
+                                .unwrap_or_else(|| "all".into()),
+                            default: None,
+                        };
+                        if empty {
+                            keys.insert(id, declared);
+                        } else {
+                            key = Some((id, declared));
+                        }
+                    }
+                    "default" if key.is_some() && !empty => {
+                        text = Text::Default;
+                        buffer.clear();
+                    }
+                    "node" | "edge" => {
+                        let is_edge = name == "edge";
+                        let id = attribute(&mut attributes, "id");
+                        let ends = if is_edge {
+                            let source = attribute(&mut attributes, "source");
+                            let target = attribute(&mut attributes, "target");
+                            match (source, target) {
+                                (Some(source), Some(target)) => Some((source, target)),
+                                _ => return Err(syntax(&reader, "edge without source or target")),
+                            }
+                        } else if id.is_none() {
+                            return Err(syntax(&reader, "node without id"));
+                        } else {
+                            None
+                        };
+                        let current = Element {
+                            id,
+                            ends,
+                            data: Vec::new(),
+                        };
+                        if empty {
+                            if is_edge { &mut edges } else { &mut nodes }.push(current);
+                        } else {
+                            open.push((current, is_edge));
+                        }
+                    }
+                    "data" if !open.is_empty() => {
+                        let id = attribute(&mut attributes, "key")
+                            .ok_or_else(|| syntax(&reader, "data without key"))?;
+                        if empty {
+                            open.last_mut().unwrap().0.data.push((id, String::new()));
+                        } else {
+                            text = Text::Data(id);
+                            buffer.clear();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(content) => {
+                if ignored == 0 && !matches!(text, Text::None) {
+                    buffer.push_str(&content);
+                }
+            }
+            Event::End(name) => {
+                if ignored > 0 {
+                    ignored -= 1;
+                    continue;
+                }
+                match (name, &text) {
+                    ("default", Text::Default) => {
+                        if let Some((_, declared)) = key.as_mut() {
+                            declared.default = Some(core::mem::take(&mut buffer));
+                        }
+                        text = Text::None;
+                    }
+                    ("data", Text::Data(_)) => {
+                        if let Text::Data(id) = core::mem::replace(&mut text, Text::None) {
+                            let value = core::mem::take(&mut buffer);
+                            open.last_mut().unwrap().0.data.push((id, value));
+                        }
+                    }
+                    ("key", _) => {
+                        if let Some((id, declared)) = key.take() {
+                            keys.insert(id, declared);
+                        }
+                    }
+                    ("node", _) | ("edge", _) => {
+                        if let Some((current, is_edge)) = open.pop() {
+                            if is_edge { &mut edges } else { &mut nodes }.push(current);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let typed = |id: &str, value: &str| -> Result<(String, AttrValue), GraphMlError> {
+        let declared = keys
+            .get(id)
+            .ok_or_else(|| GraphMlError::UnknownKey(id.into()))?;
+        parse_value(&declared.type_name, value)
+            .map(|value| (declared.name.clone(), value))
+            .ok_or_else(|| GraphMlError::InvalidValue {
+                key: id.into(),
+                value: value.into(),
+            })
+    };
+    let attributes = |data: &[(String, String)], domain: &str| {
+        let mut attributes = Attributes::new();
+        for (id, declared) in &keys {
+            if let Some(default) = &declared.default {
+                if declared.domain == domain || declared.domain == "all" {
+                    let (name, value) = typed(id, default)?;
+                    attributes.insert(name, value);
+                }
+            }
+        }
+        for (id, value) in data {
+            let (name, value) = typed(id, value)?;
+            attributes.insert(name, value);
+        }
+        Ok(attributes)
+    };
+
+    let mut graph = G::with_capacity(nodes.len(), edges.len());
+    let mut indices = HashMap::with_capacity(nodes.len());
+    for node in nodes {
+        let id = node.id.unwrap_or_default();
+        if indices.contains_key(&id) {
+            return Err(GraphMlError::DuplicateNode(id));
+        }
+        let weight = GraphMlNode {
+            id: id.clone(),
+            attributes: attributes(&node.data, "node")?,
+        };
+        indices.insert(id, graph.add_node(weight));
+    }
+    for edge in edges {
+        let (source, target) = edge.ends.unwrap_or_default();
+        let index = |id: String| {
+            indices
+                .get(&id)
+                .copied()
+                .ok_or(GraphMlError::UnknownNode(id))
+        };
+        let (a, b) = (index(source)?, index(target)?);
+        let weight = GraphMlEdge {
+            id: edge.id,
+            attributes: attributes(&edge.data, "edge")?,
+        };
+        graph.add_edge(a, b, weight);
+    }
+    Ok(graph)
+}
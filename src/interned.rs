@@ -0,0 +1,411 @@
+//! Graphs that store each distinct node and edge weight once.
+//!
+//! When many nodes or edges share the same weight — labels that are repeated
+//! strings, or large shared structs — storing the weight in every node and
+//! edge wastes a lot of memory. An [`InternedGraph`] keeps every distinct
+//! weight once in an [`Interner`], and stores a small [`Symbol`] in the
+//! topology instead.
+//!
+//! The topology is a regular [`Graph`] with [`Symbol`] weights, available
+//! through [`InternedGraph::graph`], so all algorithms and visitors can run
+//! on it directly.
+
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::ops::Index;
+
+use hashbrown::{DefaultHashBuilder, HashTable};
+
+use crate::graph::{DefaultIx, EdgeIndex, Graph, IndexType, NodeIndex};
+use crate::{Directed, EdgeType, Undirected};
+
+/// The id of a value stored in an [`Interner`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Return the position of the value in its interner, starting from `0`
+    /// in insertion order.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A set of distinct values, each identified by a [`Symbol`].
+///
+/// Every value is stored once: interning a value equal to one that is
+/// already stored returns the existing symbol.
+#[derive(Clone)]
+pub struct Interner<T> {
+    values: Vec<T>,
+    /// The symbols of `values`, hashed by their value.
+    table: HashTable<Symbol>,
+    hasher: DefaultHashBuilder,
+}
+
+impl<T> Interner<T>
+where
+    T: Eq + Hash,
+{
+    /// Create an empty interner.
+    pub fn new() -> Self {
+        Interner {
+            values: Vec::new(),
+            table: HashTable::new(),
+            hasher: DefaultHashBuilder::default(),
+        }
+    }
+
+    /// Return the symbol of `value`, storing it if it is not stored yet.
+    ///
+    /// **Panics** if more than `u32::MAX` distinct values are stored.
+    pub fn intern(&mut self, value: T) -> Symbol {
+        let hash = hash_one(&self.hasher, &value);
+        let values = &self.values;
+        if let Some(&symbol) = self.table.find(hash, |s| values[s.index()] == value) {
+            return symbol;
+        }
+        let symbol =
+            Symbol(u32::try_from(self.values.len()).expect("Interner: too many distinct values"));
+        self.values.push(value);
+        let (values, hasher) = (&self.values, &self.hasher);
+        self.table
+            .insert_unique(hash, symbol, |s| hash_one(hasher, &values[s.index()]));
+        symbol
+    }
+
+    /// Return the symbol of `value`, or `None` if it is not stored.
+    pub fn get<Q>(&self, value: &Q) -> Option<Symbol>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let hash = hash_one(&self.hasher, value);
+        self.table
+            .find(hash, |s| self.values[s.index()].borrow() == value)
+            .copied()
+    }
+}
+
+impl<T> Interner<T> {
+    /// Return the value of `symbol`.
+    ///
+    /// **Panics** if `symbol` does not belong to this interner.
+    pub fn resolve(&self, symbol: Symbol) -> &T {
+        &self.values[symbol.index()]
+    }
+
+    /// Return the number of distinct values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Return whether the interner is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Return an iterator over the symbols and values, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &T)> {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (Symbol(i as u32), value))
+    }
+}
+
+impl<T: Eq + Hash> Default for Interner<T> {
+    fn default() -> Self {
+        Interner::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Interner<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.values).finish()
+    }
+}
+
+impl<T> Index<Symbol> for Interner<T> {
+    type Output = T;
+    fn index(&self, symbol: Symbol) -> &T {
+        self.resolve(symbol)
+    }
+}
+
+fn hash_one<S: BuildHasher, Q: ?Sized + Hash>(hasher: &S, value: &Q) -> u64 {
+    let mut state = hasher.build_hasher();
+    value.hash(&mut state);
+    state.finish()
+}
+
+/// A [`Graph`] whose node and edge weights are interned.
+///
+/// Each distinct node weight and each distinct edge weight is stored once,
+/// and the topology only stores their [`Symbol`]s. Nodes and edges are
+/// identified by the indices of the underlying [`Graph`], which follow the
+/// same rules: removing a node or an edge shifts the last one into its
+/// place. Removing items does not remove their weights from the interners.
+///
+/// # Example
+/// ```rust
+/// use petgraph::interned::InternedGraph;
+/// use petgraph::algo::dijkstra;
+///
+/// let mut graph = InternedGraph::<String, String>::new();
+/// let a = graph.add_node("city".to_string());
+/// let b = graph.add_node("city".to_string());
+/// let c = graph.add_node("village".to_string());
+/// graph.add_edge(a, b, "road".to_string());
+/// graph.add_edge(b, c, "road".to_string());
+///
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.node_interner().len(), 2);
+/// assert_eq!(graph.edge_interner().len(), 1);
+/// assert_eq!(graph[c], "village");
+///
+/// // Algorithms run on the topology.
+/// let distances = dijkstra(graph.graph(), a, None, |_| 1);
+/// assert_eq!(distances[&c], 2);
+/// ```
+pub struct InternedGraph<N, E, Ty = Directed, Ix = DefaultIx> {
+    graph: Graph<Symbol, Symbol, Ty, Ix>,
+    nodes: Interner<N>,
+    edges: Interner<E>,
+}
+
+impl<N, E, Ty, Ix> Clone for InternedGraph<N, E, Ty, Ix>
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn clone(&self) -> Self {
+        InternedGraph {
+            graph: self.graph.clone(),
+            nodes: self.nodes.clone(),
+            edges: self.edges.clone(),
+        }
+    }
+}
+
+impl<N, E> InternedGraph<N, E, Directed>
+where
+    N: Eq + Hash,
+    E: Eq + Hash,
+{
+    /// Create a new, empty directed `InternedGraph`.
+    pub fn new() -> Self {
+        InternedGraph::default()
+    }
+}
+
+impl<N, E> InternedGraph<N, E, Undirected>
+where
+    N: Eq + Hash,
+    E: Eq + Hash,
+{
+    /// Create a new, empty undirected `InternedGraph`.
+    pub fn new_undirected() -> Self {
+        InternedGraph::default()
+    }
+}
+
+impl<N, E, Ty, Ix> InternedGraph<N, E, Ty, Ix>
+where
+    N: Eq + Hash,
+    E: Eq + Hash,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a new, empty `InternedGraph` with room for the given number of
+    /// nodes and edges.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        InternedGraph {
+            graph: Graph::with_capacity(nodes, edges),
+            nodes: Interner::new(),
+            edges: Interner::new(),
+        }
+    }
+
+    /// Return the underlying graph, whose weights are the symbols of the
+    /// node and edge weights.
+    pub fn graph(&self) -> &Graph<Symbol, Symbol, Ty, Ix> {
+        &self.graph
+    }
+
+    /// Return the interner of the node weights.
+    pub fn node_interner(&self) -> &Interner<N> {
+        &self.nodes
+    }
+
+    /// Return the interner of the edge weights.
+    pub fn edge_interner(&self) -> &Interner<E> {
+        &self.edges
+    }
+
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Add a node with weight `weight` to the graph.
+    ///
+    /// Return the index of the new node.
+    ///
+    /// **Panics** if the graph is at the maximum number of nodes for its
+    /// index type, or if there are more than `u32::MAX` distinct node
+    /// weights.
+    pub fn add_node(&mut self, weight: N) -> NodeIndex<Ix> {
+        let symbol = self.nodes.intern(weight);
+        self.graph.add_node(symbol)
+    }
+
+    /// Add an edge from `a` to `b` with weight `weight` to the graph.
+    ///
+    /// Return the index of the new edge.
+    ///
+    /// **Panics** if any of the nodes don't exist, if the graph is at the
+    /// maximum number of edges for its index type, or if there are more than
+    /// `u32::MAX` distinct edge weights.
+    pub fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> EdgeIndex<Ix> {
+        let symbol = self.edges.intern(weight);
+        self.graph.add_edge(a, b, symbol)
+    }
+
+    /// Remove node `a` and its edges from the graph, and return the symbol
+    /// of its weight, or `None` if it didn't exist.
+    ///
+    /// As in [`Graph::remove_node`], the last node takes the index of the
+    /// removed node.
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> Option<Symbol> {
+        self.graph.remove_node(a)
+    }
+
+    /// Remove edge `e` from the graph, and return the symbol of its weight,
+    /// or `None` if it didn't exist.
+    ///
+    /// As in [`Graph::remove_edge`], the last edge takes the index of the
+    /// removed edge.
+    pub fn remove_edge(&mut self, e: EdgeIndex<Ix>) -> Option<Symbol> {
+        self.graph.remove_edge(e)
+    }
+
+    /// Return the weight of node `a`, or `None` if it doesn't exist.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.graph.node_weight(a).map(|&s| self.nodes.resolve(s))
+    }
+
+    /// Return the weight of edge `e`, or `None` if it doesn't exist.
+    pub fn edge_weight(&self, e: EdgeIndex<Ix>) -> Option<&E> {
+        self.graph.edge_weight(e).map(|&s| self.edges.resolve(s))
+    }
+
+    /// Return an iterator over the nodes whose weight is `weight`.
+    pub fn nodes_with_weight<Q>(&self, weight: &Q) -> impl Iterator<Item = NodeIndex<Ix>> + '_
+    where
+        N: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let symbol = self.nodes.get(weight);
+        self.graph
+            .node_indices()
+            .filter(move |&a| symbol == Some(self.graph[a]))
+    }
+
+    /// Convert a graph, interning its weights. Node and edge indices are
+    /// kept.
+    pub fn from_graph(graph: Graph<N, E, Ty, Ix>) -> Self {
+        let (nodes, edges) = graph.into_nodes_edges();
+        let mut interned = InternedGraph::with_capacity(nodes.len(), edges.len());
+        for node in nodes {
+            interned.add_node(node.weight);
+        }
+        for edge in edges {
+            interned.add_edge(edge.source(), edge.target(), edge.weight);
+        }
+        interned
+    }
+
+    /// Convert to a graph with a copy of its weight in every node and edge.
+    /// Node and edge indices are kept.
+    pub fn into_graph(self) -> Graph<N, E, Ty, Ix>
+    where
+        N: Clone,
+        E: Clone,
+    {
+        let InternedGraph {
+            graph,
+            nodes,
+            edges,
+        } = self;
+        graph.map(
+            |_, &s| nodes.resolve(s).clone(),
+            |_, &s| edges.resolve(s).clone(),
+        )
+    }
+}
+
+impl<N, E, Ty, Ix> Default for InternedGraph<N, E, Ty, Ix>
+where
+    N: Eq + Hash,
+    E: Eq + Hash,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        InternedGraph::with_capacity(0, 0)
+    }
+}
+
+impl<N, E, Ty, Ix> fmt::Debug for InternedGraph<N, E, Ty, Ix>
+where
+    N: fmt::Debug,
+    E: fmt::Debug,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InternedGraph")
+            .field("graph", &self.graph)
+            .field("nodes", &self.nodes)
+            .field("edges", &self.edges)
+            .finish()
+    }
+}
+
+/// Index the `InternedGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, E, Ty, Ix> Index<NodeIndex<Ix>> for InternedGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = N;
+    fn index(&self, index: NodeIndex<Ix>) -> &N {
+        self.nodes.resolve(self.graph[index])
+    }
+}
+
+/// Index the `InternedGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, Ty, Ix> Index<EdgeIndex<Ix>> for InternedGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        self.edges.resolve(self.graph[index])
+    }
+}
@@ -0,0 +1,222 @@
+//! Conversion of graphs to and from dense adjacency matrices.
+//!
+//! The entry of row `i` and column `j` of the adjacency matrix of a graph
+//! is the weight of the edges from its `i`-th node to its `j`-th node, or
+//! zero, the default weight, if there are none. The matrices are vectors of
+//! rows, or with the `ndarray` feature, two dimensional
+//! [`ndarray`](https://docs.rs/ndarray/0.16/ndarray/) arrays.
+//!
+//! See the [`matrix_market`](super::matrix_market) module for sparse
+//! matrices.
+
+use alloc::{vec, vec::Vec};
+use core::ops::AddAssign;
+
+use crate::data::Create;
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Return the dense adjacency matrix of a graph, with the
+/// weights given by `edge_weight`.
+///
+/// The rows and columns are the nodes, in the order of
+/// [`IntoNodeIdentifiers`]. The weights of parallel edges are added up, so
+/// that with a weight of `1` the entries count the edges. The matrix of an
+/// undirected graph is symmetric.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `edge_weight`: the weight of each edge.
+///
+/// # Returns
+/// * The adjacency matrix, as a vector of rows.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E|)**.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::DiGraph;
+/// use petgraph::io::adjacency_matrix::to_adjacency_matrix;
+///
+/// let graph = DiGraph::<(), f64>::from_edges(&[(0, 1, 0.5), (1, 2, 2.0), (0, 1, 1.0)]);
+/// let matrix = to_adjacency_matrix(&graph, |edge| *edge.weight());
+/// assert_eq!(
+///     matrix,
+///     [[0.0, 1.5, 0.0], [0.0, 0.0, 2.0], [0.0, 0.0, 0.0]]
+/// );
+/// ```
+pub fn to_adjacency_matrix<G, W, F>(graph: G, mut edge_weight: F) -> Vec<Vec<W>>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> W,
+    W: Default + Clone + AddAssign,
+{
+    let (positions, n) = positions(graph);
+    let mut matrix = vec![vec![W::default(); n]; n];
+    for edge in graph.edge_references() {
+        let a = positions[graph.to_index(edge.source())];
+        let b = positions[graph.to_index(edge.target())];
+        let weight = edge_weight(edge);
+        if !graph.is_directed() && a != b {
+            matrix[b][a] += weight.clone();
+        }
+        matrix[a][b] += weight;
+    }
+    matrix
+}
+
+/// The position of each node, by node index, in the order of
+/// [`IntoNodeIdentifiers`], and the number of nodes.
+fn positions<G>(graph: G) -> (Vec<usize>, usize)
+where
+    G: IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut positions = vec![usize::MAX; graph.node_bound()];
+    let mut n = 0;
+    for node in graph.node_identifiers() {
+        positions[graph.to_index(node)] = n;
+        n += 1;
+    }
+    (positions, n)
+}
+
+/// \[Generic\] Create a graph from a dense adjacency matrix.
+///
+/// The graph gets a node per row, and an edge per entry that is not zero,
+/// the default weight, weighted by the entry, in row major order. For an
+/// undirected graph, the matrix is taken as symmetric: only the entries on
+/// and above the diagonal are read.
+///
+/// # Arguments
+/// * `matrix`: the adjacency matrix, as a slice of rows.
+///
+/// # Returns
+/// * The graph.
+///
+/// **Panics** if the matrix is not square.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|²)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of rows.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::io::adjacency_matrix::from_adjacency_matrix;
+///
+/// let matrix = [[0, 3, 1], [3, 0, 0], [1, 0, 2]];
+/// let graph: UnGraph<(), i32> = from_adjacency_matrix(&matrix);
+/// assert_eq!(graph.edge_count(), 3);
+/// assert_eq!(graph.edge_weights().copied().collect::<Vec<_>>(), [3, 1, 2]);
+/// ```
+pub fn from_adjacency_matrix<G, R>(matrix: &[R]) -> G
+where
+    G: Create<NodeWeight = ()> + GraphProp,
+    G::EdgeWeight: Default + Clone + PartialEq,
+    R: AsRef<[G::EdgeWeight]>,
+{
+    let n = matrix.len();
+    let zero = G::EdgeWeight::default();
+    let mut graph = G::with_capacity(n, 0);
+    let nodes: Vec<_> = (0..n).map(|_| graph.add_node(())).collect();
+    let symmetric = !graph.is_directed();
+    for (i, row) in matrix.iter().enumerate() {
+        let row = row.as_ref();
+        assert_eq!(row.len(), n, "the adjacency matrix is not square");
+        let start = if symmetric { i } else { 0 };
+        for (j, weight) in row.iter().enumerate().skip(start) {
+            if *weight != zero {
+                graph.add_edge(nodes[i], nodes[j], weight.clone());
+            }
+        }
+    }
+    graph
+}
+
+/// \[Generic\] Return the dense adjacency matrix of a graph as an
+/// [`ndarray::Array2`], with the weights given by `edge_weight`.
+///
+/// The matrix is the same as for [`to_adjacency_matrix`].
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E|)**.
+/// * Auxiliary space: **O(|V|²)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use ndarray::array;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::io::adjacency_matrix::to_ndarray;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 1)]);
+/// assert_eq!(to_ndarray(&graph, |_| 1), array![[0, 1], [1, 1]]);
+/// ```
+#[cfg(feature = "ndarray")]
+pub fn to_ndarray<G, W, F>(graph: G, mut edge_weight: F) -> ndarray::Array2<W>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> W,
+    W: Default + Clone + AddAssign,
+{
+    let (positions, n) = positions(graph);
+    let mut matrix = ndarray::Array2::default((n, n));
+    for edge in graph.edge_references() {
+        let a = positions[graph.to_index(edge.source())];
+        let b = positions[graph.to_index(edge.target())];
+        let weight = edge_weight(edge);
+        if !graph.is_directed() && a != b {
+            matrix[[b, a]] += weight.clone();
+        }
+        matrix[[a, b]] += weight;
+    }
+    matrix
+}
+
+/// \[Generic\] Create a graph from a dense adjacency matrix given as an
+/// [`ndarray::ArrayView2`].
+///
+/// The graph is the same as for [`from_adjacency_matrix`].
+///
+/// **Panics** if the matrix is not square.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|²)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of rows.
+///
+/// # Example
+/// ```rust
+/// use ndarray::array;
+/// use petgraph::graph::DiGraph;
+/// use petgraph::io::adjacency_matrix::from_ndarray;
+///
+/// let graph: DiGraph<(), f32> = from_ndarray(array![[0.0, 0.5], [0.0, 0.0]].view());
+/// assert_eq!(graph.edge_count(), 1);
+/// ```
+#[cfg(feature = "ndarray")]
+pub fn from_ndarray<G>(matrix: ndarray::ArrayView2<'_, G::EdgeWeight>) -> G
+where
+    G: Create<NodeWeight = ()> + GraphProp,
+    G::EdgeWeight: Default + Clone + PartialEq,
+{
+    let n = matrix.nrows();
+    assert_eq!(matrix.ncols(), n, "the adjacency matrix is not square");
+    let zero = G::EdgeWeight::default();
+    let mut graph = G::with_capacity(n, 0);
+    let nodes: Vec<_> = (0..n).map(|_| graph.add_node(())).collect();
+    let symmetric = !graph.is_directed();
+    for ((i, j), weight) in matrix.indexed_iter() {
+        if (!symmetric || j >= i) && *weight != zero {
+            graph.add_edge(nodes[i], nodes[j], weight.clone());
+        }
+    }
+    graph
+}
@@ -0,0 +1,212 @@
+//! CSV import and export of graphs with typed node and edge records.
+//!
+//! A graph is stored as two CSV tables with headers: one record per node,
+//! with its id, and one record per edge, with the ids of its source and
+//! target. The records are the node and edge weights, serialized and
+//! deserialized with serde: the node weights implement [`NodeRecord`],
+//! which gives their id, and the edge weights [`EdgeRecord`], which gives
+//! the ids of their ends.
+//!
+//! See the [`edgelist`](super::edgelist) module for plain edge lists of
+//! labels and weights.
+//!
+//! # Example
+//!
+//! ```
+//! use petgraph::graph::DiGraph;
+//! use petgraph::io::csv::{from_csv, to_csv, EdgeRecord, NodeRecord};
+//! use serde_derive::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct City {
+//!     id: String,
+//!     population: u32,
+//! }
+//!
+//! impl NodeRecord for City {
+//!     fn id(&self) -> &str {
+//!         &self.id
+//!     }
+//! }
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Road {
+//!     from: String,
+//!     to: String,
+//!     km: f64,
+//! }
+//!
+//! impl EdgeRecord for Road {
+//!     fn source(&self) -> &str {
+//!         &self.from
+//!     }
+//!     fn target(&self) -> &str {
+//!         &self.to
+//!     }
+//! }
+//!
+//! let nodes = "id,population\nlyon,520000\nparis,2100000\n";
+//! let edges = "from,to,km\nlyon,paris,465.5\n";
+//! let graph: DiGraph<City, Road> = from_csv(nodes.as_bytes(), edges.as_bytes()).unwrap();
+//! assert_eq!(graph.raw_nodes()[1].weight.population, 2100000);
+//! assert_eq!(graph.raw_edges()[0].target().index(), 1);
+//!
+//! let (mut nodes_out, mut edges_out) = (Vec::new(), Vec::new());
+//! to_csv(&graph, &mut nodes_out, &mut edges_out).unwrap();
+//! assert_eq!(nodes_out, nodes.as_bytes());
+//! assert_eq!(edges_out, edges.as_bytes());
+//! ```
+
+use alloc::string::String;
+use core::fmt;
+use std::io;
+
+use hashbrown::HashMap;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::data::Create;
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences, NodeRef};
+
+/// A node record, with the id that edge records refer to.
+pub trait NodeRecord {
+    /// Return the id of the node.
+    fn id(&self) -> &str;
+}
+
+/// An edge record, with the ids of its ends.
+pub trait EdgeRecord {
+    /// Return the id of the source of the edge.
+    fn source(&self) -> &str;
+    /// Return the id of the target of the edge.
+    fn target(&self) -> &str;
+}
+
+/// An error while reading the CSV tables of a graph.
+#[derive(Debug)]
+pub enum CsvError {
+    /// A table is not valid CSV, or a record does not deserialize.
+    Csv(::csv::Error),
+    /// Two nodes have the same id.
+    DuplicateNode(String),
+    /// An edge refers to a node id that is not in the node table.
+    UnknownNode(String),
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Csv(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Csv(error) => error.fmt(f),
+            CsvError::DuplicateNode(id) => write!(f, "duplicate node id {id:?}"),
+            CsvError::UnknownNode(id) => write!(f, "edge to unknown node id {id:?}"),
+        }
+    }
+}
+
+impl From<::csv::Error> for CsvError {
+    fn from(error: ::csv::Error) -> Self {
+        CsvError::Csv(error)
+    }
+}
+
+/// \[Generic\] Read a graph from a CSV table of node records and a CSV
+/// table of edge records, both with headers.
+///
+/// The graph gets the nodes, and then the edges, in the order of their
+/// tables. The edges are added in the direction of their records, whether
+/// or not the graph is directed.
+///
+/// # Arguments
+/// * `nodes`: the table of the node records.
+/// * `edges`: the table of the edge records.
+///
+/// # Returns
+/// * The graph, or an error if a table is not valid, if two nodes have the
+///   same id, or if an edge refers to an unknown node.
+///
+/// # Complexity
+/// * Time complexity: **O(n)** expected.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **n** is the length of the tables and **|V|** the number of nodes.
+pub fn from_csv<G, RN, RE>(nodes: RN, edges: RE) -> Result<G, CsvError>
+where
+    G: Create,
+    G::NodeWeight: NodeRecord + DeserializeOwned,
+    G::EdgeWeight: EdgeRecord + DeserializeOwned,
+    RN: io::Read,
+    RE: io::Read,
+{
+    let mut graph = G::with_capacity(0, 0);
+    let mut indices = HashMap::new();
+    for node in ::csv::Reader::from_reader(nodes).into_deserialize() {
+        let node: G::NodeWeight = node?;
+        let id = String::from(node.id());
+        if indices.contains_key(&id) {
+            return Err(CsvError::DuplicateNode(id));
+        }
+        indices.insert(id, graph.add_node(node));
+    }
+    for edge in ::csv::Reader::from_reader(edges).into_deserialize() {
+        let edge: G::EdgeWeight = edge?;
+        let index = |id: &str| {
+            indices
+                .get(id)
+                .copied()
+                .ok_or_else(|| CsvError::UnknownNode(id.into()))
+        };
+        let (a, b) = (index(edge.source())?, index(edge.target())?);
+        graph.add_edge(a, b, edge);
+    }
+    Ok(graph)
+}
+
+/// \[Generic\] Write the node weights of a graph as a CSV table of node
+/// records, and its edge weights as a CSV table of edge records, both with
+/// headers.
+///
+/// The records are written in the order of [`IntoNodeReferences`] and
+/// [`IntoEdgeReferences`]. The edge records should refer to the ids of the
+/// node records, for [`from_csv`] to read the graph back.
+///
+/// # Arguments
+/// * `graph`: the graph.
+/// * `nodes`: the writer of the node table.
+/// * `edges`: the writer of the edge table.
+///
+/// # Returns
+/// * An error if a record does not serialize, or if writing fails.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(1)**.
+///
+/// where **|V|** is the number of nodes and **|E|** the number of edges.
+pub fn to_csv<G, WN, WE>(graph: G, nodes: WN, edges: WE) -> Result<(), ::csv::Error>
+where
+    G: IntoNodeReferences + IntoEdgeReferences,
+    G::NodeWeight: Serialize,
+    G::EdgeWeight: Serialize,
+    WN: io::Write,
+    WE: io::Write,
+{
+    let mut writer = ::csv::Writer::from_writer(nodes);
+    for node in graph.node_references() {
+        writer.serialize(node.weight())?;
+    }
+    writer.flush()?;
+    let mut writer = ::csv::Writer::from_writer(edges);
+    for edge in graph.edge_references() {
+        writer.serialize(edge.weight())?;
+    }
+    writer.flush()?;
+    Ok(())
+}
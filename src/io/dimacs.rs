@@ -0,0 +1,338 @@
+//! Import and export of the formats of the
+//! [DIMACS implementation challenges](http://dimacs.rutgers.edu/programs/challenge/).
+//!
+//! The graphs of the shortest path (`sp`), maximum flow (`max`) and graph
+//! coloring (`edge`) challenges, and many benchmark graphs since, are
+//! given as a problem line `p <problem> <nodes> <edges>`, followed by one
+//! line per arc, `a <source> <target> <weight>`, or per edge,
+//! `e <a> <b>`, with the nodes numbered from `1`, and for a maximum flow
+//! problem the source and sink as `n <node> s` and `n <node> t`. The lines
+//! starting with `c` are comments.
+//!
+//! [`Dimacs`] writes any graph as one of these problems, and
+//! [`from_dimacs`] reads a problem into a [`Graph`] or a [`StableGraph`].
+//!
+//! [`Graph`]: crate::graph::Graph
+//! [`StableGraph`]: crate::stable_graph::StableGraph
+
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+use crate::data::Create;
+use crate::visit::{
+    EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeCount,
+    NodeIndexable,
+};
+
+/// The problem written by [`Dimacs`].
+#[derive(Clone, Copy)]
+enum Problem<N> {
+    ShortestPath,
+    MaxFlow(N, N),
+    Edge,
+}
+
+/// `Dimacs` implements output to a DIMACS challenge format for a graph.
+///
+/// The nodes are numbered from `1`, in the order of
+/// [`IntoNodeIdentifiers`]. For the shortest path and maximum flow
+/// problems, each edge of an undirected graph is written as two arcs, one
+/// in each direction, except self loops.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::{DiGraph, EdgeReference, NodeIndex};
+/// use petgraph::io::dimacs::Dimacs;
+/// use petgraph::visit::EdgeRef;
+///
+/// let graph = DiGraph::<(), u32>::from_edges(&[(0, 1, 5), (1, 2, 3)]);
+/// let capacity = |edge: EdgeReference<u32>| f64::from(*edge.weight());
+/// let max = Dimacs::max_flow(&graph, NodeIndex::new(0), NodeIndex::new(2), &capacity);
+/// assert_eq!(
+///     max.to_string(),
+///     "p max 3 2\nn 1 s\nn 3 t\na 1 2 5\na 2 3 3\n"
+/// );
+/// ```
+pub struct Dimacs<'a, G>
+where
+    G: IntoEdgeReferences,
+{
+    graph: G,
+    problem: Problem<G::NodeId>,
+    get_edge_weight: &'a dyn Fn(G::EdgeRef) -> f64,
+}
+
+impl<'a, G> Dimacs<'a, G>
+where
+    G: IntoEdgeReferences,
+{
+    /// Create a `Dimacs` formatting wrapper for a shortest path problem,
+    /// with the length of each arc given by `get_edge_weight`.
+    pub fn shortest_path(graph: G, get_edge_weight: &'a dyn Fn(G::EdgeRef) -> f64) -> Self {
+        Dimacs {
+            graph,
+            problem: Problem::ShortestPath,
+            get_edge_weight,
+        }
+    }
+
+    /// Create a `Dimacs` formatting wrapper for a maximum flow problem from
+    /// `source` to `sink`, with the capacity of each arc given by
+    /// `get_edge_weight`.
+    pub fn max_flow(
+        graph: G,
+        source: G::NodeId,
+        sink: G::NodeId,
+        get_edge_weight: &'a dyn Fn(G::EdgeRef) -> f64,
+    ) -> Self {
+        Dimacs {
+            graph,
+            problem: Problem::MaxFlow(source, sink),
+            get_edge_weight,
+        }
+    }
+
+    /// Create a `Dimacs` formatting wrapper for a graph coloring problem,
+    /// whose edges have no weight.
+    pub fn edge(graph: G) -> Self {
+        Dimacs {
+            graph,
+            problem: Problem::Edge,
+            get_edge_weight: &|_| 0.,
+        }
+    }
+}
+
+impl<G> Display for Dimacs<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let g = self.graph;
+        // The number of each node, by node index.
+        let mut numbers = vec![0; g.node_bound()];
+        for (i, node) in g.node_identifiers().enumerate() {
+            numbers[g.to_index(node)] = i + 1;
+        }
+        let number = |node| numbers[g.to_index(node)];
+        let both_ways = !g.is_directed() && !matches!(self.problem, Problem::Edge);
+        let edge_count = g
+            .edge_references()
+            .map(|edge| {
+                if both_ways && edge.source() != edge.target() {
+                    2
+                } else {
+                    1
+                }
+            })
+            .sum::<usize>();
+        let name = match self.problem {
+            Problem::ShortestPath => "sp",
+            Problem::MaxFlow(..) => "max",
+            Problem::Edge => "edge",
+        };
+        writeln!(f, "p {name} {} {edge_count}", g.node_count())?;
+        if let Problem::MaxFlow(source, sink) = self.problem {
+            writeln!(f, "n {} s", number(source))?;
+            writeln!(f, "n {} t", number(sink))?;
+        }
+        for edge in g.edge_references() {
+            let (a, b) = (number(edge.source()), number(edge.target()));
+            if let Problem::Edge = self.problem {
+                writeln!(f, "e {a} {b}")?;
+                continue;
+            }
+            let weight = (self.get_edge_weight)(edge);
+            writeln!(f, "a {a} {b} {weight}")?;
+            if both_ways && a != b {
+                writeln!(f, "a {b} {a} {weight}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<G> fmt::Debug for Dimacs<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// A graph read by [`from_dimacs`], with its problem.
+#[derive(Clone, Debug)]
+pub struct DimacsGraph<G: GraphBase> {
+    /// The problem of the problem line, such as `sp`, `max` or `edge`.
+    pub problem: String,
+    /// The graph.
+    pub graph: G,
+    /// The source of a maximum flow problem.
+    pub source: Option<G::NodeId>,
+    /// The sink of a maximum flow problem.
+    pub sink: Option<G::NodeId>,
+}
+
+/// An error while reading a DIMACS problem.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DimacsError {
+    /// The problem is not valid, at the line.
+    Syntax {
+        /// The line of the error, from `1`.
+        line: usize,
+        /// A description of the error.
+        message: &'static str,
+    },
+    /// An arc, an edge or a node line refers to a node number greater than
+    /// the number of nodes.
+    UnknownNode(usize),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DimacsError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for DimacsError {}
+
+impl Display for DimacsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DimacsError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            DimacsError::UnknownNode(number) => write!(f, "undeclared node number {number}"),
+        }
+    }
+}
+
+/// \[Generic\] Read a DIMACS problem into a graph.
+///
+/// The graph gets the nodes declared by the problem line, in the order of
+/// their numbers, and the arcs and edges in the order of the problem,
+/// weighted by their weight, or the default weight for those without one,
+/// such as the edges of a graph coloring problem. They are added in the
+/// direction of the problem, whether or not the graph is directed.
+///
+/// # Arguments
+/// * `input`: the problem.
+///
+/// # Returns
+/// * The graph, with its problem and, for a maximum flow problem, its
+///   source and sink, or an error if the problem is not valid, refers to
+///   an undeclared node, or declares more than a thousand nodes per byte of
+///   its length.
+///
+/// # Complexity
+/// * Time complexity: **O(n)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **n** is the length of the problem and **|V|** the number of
+/// nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::dijkstra;
+/// use petgraph::graph::DiGraph;
+/// use petgraph::io::dimacs::from_dimacs;
+///
+/// let gr = "\
+/// c a small road network
+/// p sp 3 3
+/// a 1 2 4
+/// a 2 3 1
+/// a 1 3 7
+/// ";
+///
+/// let problem = from_dimacs::<DiGraph<(), u32>>(gr).unwrap();
+/// assert_eq!(problem.problem, "sp");
+/// let start = problem.graph.node_indices().next().unwrap();
+/// let distances = dijkstra(&problem.graph, start, None, |edge| *edge.weight());
+/// assert_eq!(distances.values().max(), Some(&5));
+/// ```
+pub fn from_dimacs<G>(input: &str) -> Result<DimacsGraph<G>, DimacsError>
+where
+    G: Create<NodeWeight = ()>,
+    G::EdgeWeight: FromStr + Default,
+{
+    let mut result: Option<DimacsGraph<G>> = None;
+    let mut nodes: Vec<G::NodeId> = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let syntax = |message| DimacsError::Syntax {
+            line: i + 1,
+            message,
+        };
+        let mut fields = line.split_whitespace();
+        let kind = match fields.next() {
+            None => continue,
+            Some(kind) => kind,
+        };
+        if kind.starts_with('c') {
+            continue;
+        }
+        if kind == "p" {
+            if result.is_some() {
+                return Err(syntax("more than one problem line"));
+            }
+            let problem = fields.next().ok_or_else(|| syntax("expected a problem"))?;
+            let mut count = || -> Result<usize, DimacsError> {
+                fields
+                    .next()
+                    .and_then(|count| count.parse().ok())
+                    .ok_or_else(|| syntax("expected the numbers of nodes and edges"))
+            };
+            let (node_count, edge_count) = (count()?, count()?);
+            if !super::plausible_node_count(node_count, input.len()) {
+                return Err(syntax("too many nodes for the length of the document"));
+            }
+            // Each edge takes a line, so the input bounds their number.
+            let mut graph = G::with_capacity(node_count, edge_count.min(input.len()));
+            nodes = (0..node_count).map(|_| graph.add_node(())).collect();
+            result = Some(DimacsGraph {
+                problem: problem.into(),
+                graph,
+                source: None,
+                sink: None,
+            });
+            continue;
+        }
+        let result = result
+            .as_mut()
+            .ok_or_else(|| syntax("expected the problem line"))?;
+        let mut node = || -> Result<G::NodeId, DimacsError> {
+            let number: usize = fields
+                .next()
+                .and_then(|number| number.parse().ok())
+                .ok_or_else(|| syntax("expected a node number"))?;
+            number
+                .checked_sub(1)
+                .and_then(|i| nodes.get(i))
+                .copied()
+                .ok_or(DimacsError::UnknownNode(number))
+        };
+        match kind {
+            "a" | "e" => {
+                let (a, b) = (node()?, node()?);
+                let weight = match fields.next() {
+                    Some(weight) => weight.parse().map_err(|_| syntax("invalid weight"))?,
+                    None => G::EdgeWeight::default(),
+                };
+                result.graph.add_edge(a, b, weight);
+            }
+            "n" => {
+                let node = node()?;
+                match fields.next() {
+                    Some("s") => result.source = Some(node),
+                    Some("t") => result.sink = Some(node),
+                    _ => return Err(syntax("expected s or t")),
+                }
+            }
+            _ => return Err(syntax("unknown line type")),
+        }
+    }
+    result.ok_or(DimacsError::Syntax {
+        line: input.lines().count(),
+        message: "no problem line",
+    })
+}
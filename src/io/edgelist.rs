@@ -0,0 +1,307 @@
+//! Edge list import and export, including CSV and TSV edge tables.
+//!
+//! An edge list has one edge per line: the labels of its source and
+//! target, and optionally its weight, separated by whitespace or by a
+//! delimiter, such as a comma or a tab. [`EdgeList`] writes any graph, with
+//! the labels and weights given by two functions, and [`from_edgelist`]
+//! reads an edge list into a [`Graph`] or a [`StableGraph`], along with the
+//! [`Interner`] of the node labels.
+//!
+//! The lines are split at each delimiter, without quoting; see the `csv`
+//! module behind the `csv` feature for CSV files of typed records.
+//!
+//! [`Graph`]: crate::graph::Graph
+//! [`StableGraph`]: crate::stable_graph::StableGraph
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+use crate::data::Create;
+use crate::interned::Interner;
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef};
+
+/// The delimiter and comment character of an edge list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EdgeListFormat {
+    /// The delimiter of the fields of a line, or `None` for whitespace.
+    pub delimiter: Option<char>,
+    /// The character that starts a comment, which lasts to the end of the
+    /// line, if any.
+    pub comment: Option<char>,
+}
+
+impl EdgeListFormat {
+    /// Fields separated by whitespace, and comments starting with `#`.
+    pub const WHITESPACE: EdgeListFormat = EdgeListFormat {
+        delimiter: None,
+        comment: Some('#'),
+    };
+    /// Fields separated by commas, and comments starting with `#`.
+    pub const CSV: EdgeListFormat = EdgeListFormat {
+        delimiter: Some(','),
+        comment: Some('#'),
+    };
+    /// Fields separated by tabs, and comments starting with `#`.
+    pub const TSV: EdgeListFormat = EdgeListFormat {
+        delimiter: Some('\t'),
+        comment: Some('#'),
+    };
+}
+
+impl Default for EdgeListFormat {
+    fn default() -> Self {
+        EdgeListFormat::WHITESPACE
+    }
+}
+
+/// `EdgeList` implements output to an edge list for a graph.
+///
+/// Each edge is written on its own line, in the order of
+/// [`IntoEdgeReferences`], and the nodes without edges are not written.
+/// The labels should not contain the delimiter, or whitespace if the fields
+/// are separated by whitespace.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::{DiGraph, EdgeReference};
+/// use petgraph::io::edgelist::{EdgeList, EdgeListFormat};
+/// use petgraph::visit::EdgeRef;
+///
+/// let mut graph = DiGraph::<&str, u32>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// graph.add_edge(a, b, 7);
+///
+/// let labels = |(_, label): (_, &&str)| label.to_string();
+/// let weights = |edge: EdgeReference<u32>| Some(edge.weight().to_string());
+/// let csv = EdgeList::with_attr_getters(&graph, &labels, &weights).with_format(EdgeListFormat::CSV);
+/// assert_eq!(csv.to_string(), "a,b,7\n");
+/// assert_eq!(EdgeList::new(&graph).to_string(), "0 1\n");
+/// ```
+pub struct EdgeList<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    graph: G,
+    format: EdgeListFormat,
+    get_node_label: Option<&'a dyn Fn(G::NodeRef) -> String>,
+    get_edge_weight: &'a dyn Fn(G::EdgeRef) -> Option<String>,
+}
+
+impl<'a, G> EdgeList<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    /// Create an `EdgeList` formatting wrapper, with the node indices as
+    /// labels and without weights.
+    pub fn new(graph: G) -> Self {
+        EdgeList {
+            graph,
+            format: EdgeListFormat::WHITESPACE,
+            get_node_label: None,
+            get_edge_weight: &|_| None,
+        }
+    }
+
+    /// Create an `EdgeList` formatting wrapper, with the label of each node
+    /// given by `get_node_label`, and the weight of each edge, if any, by
+    /// `get_edge_weight`.
+    pub fn with_attr_getters(
+        graph: G,
+        get_node_label: &'a dyn Fn(G::NodeRef) -> String,
+        get_edge_weight: &'a dyn Fn(G::EdgeRef) -> Option<String>,
+    ) -> Self {
+        EdgeList {
+            graph,
+            format: EdgeListFormat::WHITESPACE,
+            get_node_label: Some(get_node_label),
+            get_edge_weight,
+        }
+    }
+
+    /// Set the delimiter of the fields, a space if it is whitespace. The
+    /// comment character is not used.
+    pub fn with_format(mut self, format: EdgeListFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+impl<G> Display for EdgeList<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let g = self.graph;
+        let delimiter = self.format.delimiter.unwrap_or(' ');
+        let labels: Option<Vec<_>> = self.get_node_label.map(|get_node_label| {
+            let mut labels = alloc::vec![String::new(); g.node_bound()];
+            for node in g.node_references() {
+                labels[g.to_index(node.id())] = get_node_label(node);
+            }
+            labels
+        });
+        let write_label = |f: &mut fmt::Formatter<'_>, node| match &labels {
+            Some(labels) => f.write_str(&labels[g.to_index(node)]),
+            None => write!(f, "{}", g.to_index(node)),
+        };
+        for edge in g.edge_references() {
+            write_label(f, edge.source())?;
+            write!(f, "{delimiter}")?;
+            write_label(f, edge.target())?;
+            if let Some(weight) = (self.get_edge_weight)(edge) {
+                write!(f, "{delimiter}{weight}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<G> fmt::Debug for EdgeList<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// An error while reading an edge list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EdgeListError {
+    /// A line has fewer than two fields.
+    MissingTarget {
+        /// The line of the error, from `1`.
+        line: usize,
+    },
+    /// The weight of a line is invalid.
+    InvalidWeight {
+        /// The line of the error, from `1`.
+        line: usize,
+        /// The invalid weight.
+        weight: String,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EdgeListError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for EdgeListError {}
+
+impl Display for EdgeListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EdgeListError::MissingTarget { line } => {
+                write!(f, "line {line}: expected a source and a target")
+            }
+            EdgeListError::InvalidWeight { line, weight } => {
+                write!(f, "line {line}: invalid weight {weight:?}")
+            }
+        }
+    }
+}
+
+/// \[Generic\] Read an edge list into a graph.
+///
+/// Each line that is not empty once its comment is removed is an edge,
+/// from the node of its first field to the node of its second field,
+/// weighted by its third field, or by the default weight if it has only two
+/// fields. The fields are trimmed, and those after the third are ignored,
+/// as well as a header line that `has_header` says the list starts with.
+/// The nodes are added in the order of their first appearance, weighted by
+/// their label, and interned in the same order: the node of the label with
+/// the symbol of index `i` is the `i`-th node of the graph.
+///
+/// # Arguments
+/// * `input`: the edge list.
+/// * `format`: the delimiter and the comment character of the edge list.
+/// * `has_header`: whether the first line that is not a comment is a header
+///   to skip.
+///
+/// # Returns
+/// * The graph and the interner of the node labels, or an error if a line
+///   has a single field or an invalid weight.
+///
+/// # Complexity
+/// * Time complexity: **O(n)** expected.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **n** is the length of the edge list and **|V|** the number of
+/// nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::io::edgelist::{from_edgelist, EdgeListFormat};
+///
+/// let tsv = "source\ttarget\tweight\n# the first edge\nalice\tbob\t2.5\nbob\tcarol\n";
+/// let (graph, labels): (UnGraph<String, f64>, _) =
+///     from_edgelist(tsv, EdgeListFormat::TSV, true).unwrap();
+/// assert_eq!(graph.node_count(), 3);
+/// let bob = NodeIndex::new(labels.get("bob").unwrap().index());
+/// assert_eq!(graph[bob], "bob");
+/// let weights: Vec<_> = graph.edge_weights().copied().collect();
+/// assert_eq!(weights, [2.5, 0.0]);
+/// ```
+pub fn from_edgelist<G>(
+    input: &str,
+    format: EdgeListFormat,
+    has_header: bool,
+) -> Result<(G, Interner<String>), EdgeListError>
+where
+    G: Create<NodeWeight = String>,
+    G::EdgeWeight: FromStr + Default,
+{
+    let mut graph = G::with_capacity(0, 0);
+    let mut labels = Interner::new();
+    let mut nodes = Vec::new();
+    let mut skip_header = has_header;
+    for (i, line) in input.lines().enumerate() {
+        let line = match format.comment.and_then(|comment| line.find(comment)) {
+            Some(end) => &line[..end],
+            None => line,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        if skip_header {
+            skip_header = false;
+            continue;
+        }
+        let mut fields = line
+            .split(|c: char| match format.delimiter {
+                Some(delimiter) => c == delimiter,
+                None => c.is_whitespace(),
+            })
+            .map(str::trim)
+            .filter(|field| format.delimiter.is_some() || !field.is_empty());
+        let (source, target) = match (fields.next(), fields.next()) {
+            (Some(source), Some(target)) => (source, target),
+            _ => return Err(EdgeListError::MissingTarget { line: i + 1 }),
+        };
+        let weight = match fields.next() {
+            Some(weight) => weight.parse().map_err(|_| EdgeListError::InvalidWeight {
+                line: i + 1,
+                weight: weight.into(),
+            })?,
+            None => G::EdgeWeight::default(),
+        };
+        let mut node = |label: &str| {
+            let symbol = match labels.get(label) {
+                Some(symbol) => symbol,
+                None => labels.intern(String::from(label)),
+            };
+            if symbol.index() == nodes.len() {
+                nodes.push(graph.add_node(label.into()));
+            }
+            nodes[symbol.index()]
+        };
+        let (a, b) = (node(source), node(target));
+        graph.add_edge(a, b, weight);
+    }
+    Ok((graph, labels))
+}
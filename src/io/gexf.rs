@@ -0,0 +1,634 @@
+//! [GEXF](https://gexf.net/) import and export, with typed node and edge
+//! attributes.
+//!
+//! GEXF is the XML format of [Gephi](https://gephi.org/). [`Gexf`] writes
+//! any graph, with the attributes given by two functions, and
+//! [`from_gexf`] reads a document into a [`Graph`] or a [`StableGraph`]
+//! whose weights hold the ids, labels and attributes of the nodes and
+//! edges.
+//!
+//! Only the parts of GEXF that describe a static graph are supported: the
+//! hierarchy of nodes is flattened, and the visualization data, the spells
+//! of dynamic graphs and the metadata are ignored.
+//!
+//! [`Graph`]: crate::graph::Graph
+//! [`StableGraph`]: crate::stable_graph::StableGraph
+//!
+//! # Example
+//!
+//! ```
+//! use petgraph::graph::{DiGraph, EdgeReference};
+//! use petgraph::io::gexf::{from_gexf, Gexf, GexfEdge, GexfNode};
+//! use petgraph::io::{AttrValue, Attributes};
+//! use petgraph::visit::EdgeRef;
+//!
+//! let mut graph = DiGraph::<&str, f64>::new();
+//! let a = graph.add_node("a");
+//! let b = graph.add_node("b");
+//! graph.add_edge(a, b, 2.5);
+//!
+//! let node_attributes = |(_, name): (_, &&str)| {
+//!     Attributes::from([("label".into(), AttrValue::from(*name))])
+//! };
+//! let edge_attributes = |edge: EdgeReference<f64>| {
+//!     Attributes::from([
+//!         ("weight".into(), AttrValue::from(*edge.weight())),
+//!         ("kind".into(), AttrValue::from("road")),
+//!     ])
+//! };
+//! let xml = Gexf::with_attr_getters(&graph, &node_attributes, &edge_attributes).to_string();
+//!
+//! let parsed: DiGraph<GexfNode, GexfEdge> = from_gexf(&xml).unwrap();
+//! assert_eq!(parsed[a].label.as_deref(), Some("a"));
+//! let edge = &parsed.raw_edges()[0].weight;
+//! assert_eq!(edge.weight, Some(2.5));
+//! assert_eq!(edge.attributes["kind"], AttrValue::from("road"));
+//! ```
+
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+use core::fmt::{self, Display, Write};
+
+use hashbrown::HashMap;
+
+use super::{AttrValue, Attributes};
+use crate::data::Create;
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+use crate::xml::{Escaped, Event, Reader};
+
+/// The weight of a node read by [`from_gexf`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GexfNode {
+    /// The id of the node in the document.
+    pub id: String,
+    /// The label of the node, if it has one.
+    pub label: Option<String>,
+    /// The attributes of the node, with the defaults of the document for
+    /// those it does not set.
+    pub attributes: Attributes,
+}
+
+/// The weight of an edge read by [`from_gexf`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GexfEdge {
+    /// The id of the edge in the document, if it has one.
+    pub id: Option<String>,
+    /// The label of the edge, if it has one.
+    pub label: Option<String>,
+    /// The weight of the edge, if it has one.
+    pub weight: Option<f64>,
+    /// The attributes of the edge, with the defaults of the document for
+    /// those it does not set.
+    pub attributes: Attributes,
+}
+
+/// `Gexf` implements output to GEXF 1.3 for a graph.
+///
+/// The nodes get the ids `0`, `1`, ..., from their indices, and the edges
+/// the ids `0`, `1`, ..., in the order of [`IntoEdgeReferences`]. The
+/// attribute `label` of a node or an edge is written as its label, and the
+/// numeric attribute `weight` of an edge as its weight. Each other
+/// attribute name of the nodes, and of the edges, is declared once, with
+/// the type of its values, or the type `string` if its values have
+/// different types.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::UnGraph;
+/// use petgraph::io::gexf::Gexf;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let xml = Gexf::new(&graph).to_string();
+/// assert!(xml.contains(r#"<graph defaultedgetype="undirected" mode="static">"#));
+/// assert!(xml.contains(r#"<edge id="0" source="0" target="1"/>"#));
+/// ```
+pub struct Gexf<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    graph: G,
+    get_node_attributes: &'a dyn Fn(G::NodeRef) -> Attributes,
+    get_edge_attributes: &'a dyn Fn(G::EdgeRef) -> Attributes,
+}
+
+impl<'a, G> Gexf<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    /// Create a `Gexf` formatting wrapper, without attributes.
+    pub fn new(graph: G) -> Self {
+        Self::with_attr_getters(graph, &|_| Attributes::new(), &|_| Attributes::new())
+    }
+
+    /// Create a `Gexf` formatting wrapper, with the attributes of each node
+    /// and edge given by `get_node_attributes` and `get_edge_attributes`.
+    pub fn with_attr_getters(
+        graph: G,
+        get_node_attributes: &'a dyn Fn(G::NodeRef) -> Attributes,
+        get_edge_attributes: &'a dyn Fn(G::EdgeRef) -> Attributes,
+    ) -> Self {
+        Gexf {
+            graph,
+            get_node_attributes,
+            get_edge_attributes,
+        }
+    }
+}
+
+/// The ids and types of the declared attributes, by name.
+type Declared<'k> = BTreeMap<&'k str, (usize, &'static str)>;
+
+/// Declare the attribute names of `attributes`, with ids from `0`.
+fn declare<'k, I>(attributes: I) -> Declared<'k>
+where
+    I: IntoIterator<Item = &'k Attributes>,
+{
+    let mut declared = Declared::new();
+    for (name, value) in attributes.into_iter().flatten() {
+        let next_id = declared.len();
+        let (_, type_name) = declared.entry(name).or_insert((next_id, value.type_name()));
+        if *type_name != value.type_name() {
+            *type_name = "string";
+        }
+    }
+    declared
+}
+
+fn write_declarations(
+    f: &mut fmt::Formatter<'_>,
+    declared: &Declared<'_>,
+    class: &str,
+) -> fmt::Result {
+    if declared.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, r#"    <attributes class="{class}">"#)?;
+    for (name, (id, type_name)) in declared {
+        writeln!(
+            f,
+            r#"      <attribute id="{id}" title="{}" type="{type_name}"/>"#,
+            Escaped(name)
+        )?;
+    }
+    writeln!(f, "    </attributes>")
+}
+
+/// Write the start tag `start` of an element, its attributes as attribute
+/// values, and its end tag.
+fn write_element(
+    f: &mut fmt::Formatter<'_>,
+    start: fmt::Arguments<'_>,
+    element: &str,
+    attributes: &Attributes,
+    declared: &Declared<'_>,
+) -> fmt::Result {
+    if attributes.is_empty() {
+        return writeln!(f, "      <{start}/>");
+    }
+    writeln!(f, "      <{start}>")?;
+    writeln!(f, "        <attvalues>")?;
+    for (name, value) in attributes {
+        let mut text = String::new();
+        write!(text, "{value}")?;
+        writeln!(
+            f,
+            r#"          <attvalue for="{}" value="{}"/>"#,
+            declared[name.as_str()].0,
+            Escaped(&text)
+        )?;
+    }
+    writeln!(f, "        </attvalues>")?;
+    writeln!(f, "      </{element}>")
+}
+
+/// Remove the attribute `name` from `attributes`, and return it formatted
+/// as an XML attribute.
+fn take_xml_attribute(attributes: &mut Attributes, name: &str) -> String {
+    match attributes.remove(name) {
+        Some(value) => {
+            let mut text = String::new();
+            let _ = write!(text, "{value}");
+            format!(r#" {name}="{}""#, Escaped(&text))
+        }
+        None => String::new(),
+    }
+}
+
+impl<G> Display for Gexf<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let g = self.graph;
+        let nodes: Vec<_> = g
+            .node_references()
+            .map(|node| {
+                let mut attributes = (self.get_node_attributes)(node);
+                let label = take_xml_attribute(&mut attributes, "label");
+                (g.to_index(node.id()), label, attributes)
+            })
+            .collect();
+        let edges: Vec<_> = g
+            .edge_references()
+            .map(|edge| {
+                let ends = (g.to_index(edge.source()), g.to_index(edge.target()));
+                let mut attributes = (self.get_edge_attributes)(edge);
+                let mut extra = take_xml_attribute(&mut attributes, "label");
+                if matches!(
+                    attributes.get("weight"),
+                    Some(AttrValue::Int(_) | AttrValue::Float(_))
+                ) {
+                    extra += &take_xml_attribute(&mut attributes, "weight");
+                }
+                (ends, extra, attributes)
+            })
+            .collect();
+        let node_declared = declare(nodes.iter().map(|(_, _, a)| a));
+        let edge_declared = declare(edges.iter().map(|(_, _, a)| a));
+
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(f, r#"<gexf xmlns="http://gexf.net/1.3" version="1.3">"#)?;
+        let edge_type = if g.is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        };
+        writeln!(
+            f,
+            r#"  <graph defaultedgetype="{edge_type}" mode="static">"#
+        )?;
+        write_declarations(f, &node_declared, "node")?;
+        write_declarations(f, &edge_declared, "edge")?;
+        writeln!(f, "    <nodes>")?;
+        for (index, label, attributes) in &nodes {
+            let start = format_args!(r#"node id="{index}"{label}"#);
+            write_element(f, start, "node", attributes, &node_declared)?;
+        }
+        writeln!(f, "    </nodes>")?;
+        writeln!(f, "    <edges>")?;
+        for (i, ((a, b), extra, attributes)) in edges.iter().enumerate() {
+            let start = format_args!(r#"edge id="{i}" source="{a}" target="{b}"{extra}"#);
+            write_element(f, start, "edge", attributes, &edge_declared)?;
+        }
+        writeln!(f, "    </edges>")?;
+        writeln!(f, "  </graph>")?;
+        writeln!(f, "</gexf>")
+    }
+}
+
+impl<G> fmt::Debug for Gexf<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// An error while reading a GEXF document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GexfError {
+    /// The document is not well-formed XML, or not GEXF, at the line.
+    Syntax {
+        /// The line of the error, from `1`.
+        line: usize,
+        /// A description of the error.
+        message: &'static str,
+    },
+    /// Two nodes have the same id.
+    DuplicateNode(String),
+    /// An edge refers to a node id that is not declared.
+    UnknownNode(String),
+    /// An attribute value refers to an attribute id that is not declared.
+    UnknownAttribute(String),
+    /// A value is invalid for its type, such as the weight of an edge, or
+    /// an attribute value, or a default value, for the type of its
+    /// attribute.
+    InvalidValue {
+        /// The id of the attribute, or `weight`.
+        attribute: String,
+        /// The invalid value.
+        value: String,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GexfError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for GexfError {}
+
+impl Display for GexfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GexfError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            GexfError::DuplicateNode(id) => write!(f, "duplicate node id {id:?}"),
+            GexfError::UnknownNode(id) => write!(f, "edge to undeclared node id {id:?}"),
+            GexfError::UnknownAttribute(id) => {
+                write!(f, "value for undeclared attribute id {id:?}")
+            }
+            GexfError::InvalidValue { attribute, value } => {
+                write!(f, "invalid value {value:?} for attribute {attribute:?}")
+            }
+        }
+    }
+}
+
+/// Parse a value of the GEXF type `type_name`, or return `None` if the
+/// value is invalid. The types without a variant of [`AttrValue`], such as
+/// dates and lists, are strings.
+fn parse_value(type_name: &str, value: &str) -> Option<AttrValue> {
+    let trimmed = value.trim();
+    match type_name {
+        "boolean" => match trimmed {
+            "true" | "1" => Some(AttrValue::Boolean(true)),
+            "false" | "0" => Some(AttrValue::Boolean(false)),
+            _ => None,
+        },
+        "byte" | "short" | "integer" | "long" => trimmed.parse().ok().map(AttrValue::Int),
+        "float" | "double" => trimmed.parse().ok().map(AttrValue::Float),
+        _ => Some(AttrValue::String(value.into())),
+    }
+}
+
+/// An attribute declaration.
+struct Declaration {
+    title: String,
+    type_name: String,
+    default: Option<String>,
+}
+
+/// A node or an edge, with its attribute values, by attribute id.
+#[derive(Default)]
+struct Element {
+    id: Option<String>,
+    label: Option<String>,
+    ends: Option<(String, String)>,
+    weight: Option<String>,
+    values: Vec<(String, String)>,
+}
+
+/// \[Generic\] Read a GEXF document into a graph.
+///
+/// The graph gets the nodes in the order of the document, weighted by
+/// their id, label and attributes, and then the edges, weighted by their
+/// id, label, weight and attributes. Each attribute value is converted to
+/// the type of its attribute. Elements without a value for an attribute get
+/// its default, if it has one. The edges are added in the direction of the
+/// document, whether or not the graph, or the edge type, is directed.
+///
+/// # Arguments
+/// * `input`: the GEXF document.
+///
+/// # Returns
+/// * The graph, or an error if the document is not valid GEXF, if an edge
+///   refers to an undeclared node, or if a value does not have the type of
+///   its attribute.
+///
+/// # Complexity
+/// * Time complexity: **O(n)**.
+/// * Auxiliary space: **O(n)**.
+///
+/// where **n** is the length of the document.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::io::gexf::{from_gexf, GexfEdge, GexfNode};
+/// use petgraph::io::AttrValue;
+///
+/// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <gexf xmlns="http://gexf.net/1.3" version="1.3">
+///   <graph defaultedgetype="undirected">
+///     <attributes class="node">
+///       <attribute id="0" title="age" type="integer">
+///         <default>0</default>
+///       </attribute>
+///     </attributes>
+///     <nodes>
+///       <node id="a" label="Alice">
+///         <attvalues><attvalue for="0" value="42"/></attvalues>
+///       </node>
+///       <node id="b" label="Bob"/>
+///     </nodes>
+///     <edges>
+///       <edge source="a" target="b" weight="1.5"/>
+///     </edges>
+///   </graph>
+/// </gexf>"#;
+///
+/// let graph: UnGraph<GexfNode, GexfEdge> = from_gexf(xml).unwrap();
+/// assert_eq!(graph.node_count(), 2);
+/// assert_eq!(graph.raw_nodes()[0].weight.attributes["age"], AttrValue::Int(42));
+/// assert_eq!(graph.raw_nodes()[1].weight.attributes["age"], AttrValue::Int(0));
+/// assert_eq!(graph.raw_edges()[0].weight.weight, Some(1.5));
+/// ```
+pub fn from_gexf<G>(input: &str) -> Result<G, GexfError>
+where
+    G: Create<NodeWeight = GexfNode, EdgeWeight = GexfEdge>,
+{
+    let mut reader = Reader::new(input);
+    let syntax = |reader: &Reader, message| GexfError::Syntax {
+        line: reader.line(),
+        message,
+    };
+    let attribute = |attributes: &mut Vec<(&str, String)>, name: &str| {
+        attributes
+            .iter()
+            .position(|&(key, _)| key == name)
+            .map(|i| attributes.swap_remove(i).1)
+    };
+
+    // The declarations of the node and of the edge attributes, by id.
+    let mut node_declarations: HashMap<String, Declaration> = HashMap::new();
+    let mut edge_declarations: HashMap<String, Declaration> = HashMap::new();
+    let mut class_is_edge = false;
+    let mut declaration: Option<(String, Declaration)> = None;
+    let mut in_default = false;
+    let mut buffer = String::new();
+    let mut nodes: Vec<Element> = Vec::new();
+    let mut edges: Vec<Element> = Vec::new();
+    // The open nodes and edges, which the hierarchy of nodes makes more
+    // than one, and whether each is an edge.
+    let mut open: Vec<(Element, bool)> = Vec::new();
+    let mut root = true;
+
+    while let Some(event) = reader.next_event().map_err(|m| syntax(&reader, m))? {
+        match event {
+            Event::Start {
+                name,
+                mut attributes,
+                empty,
+            } => {
+                if root {
+                    if name != "gexf" {
+                        return Err(syntax(&reader, "the root element is not gexf"));
+                    }
+                    root = false;
+                    continue;
+                }
+                match name {
+                    "attributes" => {
+                        class_is_edge =
+                            attribute(&mut attributes, "class").as_deref() == Some("edge");
+                    }
+                    "attribute" => {
+                        let id = attribute(&mut attributes, "id")
+                            .ok_or_else(|| syntax(&reader, "attribute without id"))?;
+                        let declared = Declaration {
+                            title: attribute(&mut attributes, "title")
+                                .unwrap_or_else(|| id.clone()),
+                            type_name: attribute(&mut attributes, "type")
+                                .unwrap_or_else(|| "string".into()),
+                            default: None,
+                        };
+                        declaration = Some((id, declared));
+                        if empty {
+                            let (id, declared) = declaration.take().unwrap();
+                            if class_is_edge {
+                                &mut edge_declarations
+                            } else {
+                                &mut node_declarations
+                            }
+                            .insert(id, declared);
+                        }
+                    }
+                    "default" if declaration.is_some() && !empty => {
+                        in_default = true;
+                        buffer.clear();
+                    }
+                    "node" | "edge" => {
+                        let is_edge = name == "edge";
+                        let id = attribute(&mut attributes, "id");
+                        let ends = if is_edge {
+                            let source = attribute(&mut attributes, "source");
+                            let target = attribute(&mut attributes, "target");
+                            match (source, target) {
+                                (Some(source), Some(target)) => Some((source, target)),
+                                _ => return Err(syntax(&reader, "edge without source or target")),
+                            }
+                        } else if id.is_none() {
+                            return Err(syntax(&reader, "node without id"));
+                        } else {
+                            None
+                        };
+                        let current = Element {
+                            id,
+                            label: attribute(&mut attributes, "label"),
+                            ends,
+                            weight: attribute(&mut attributes, "weight"),
+                            values: Vec::new(),
+                        };
+                        if empty {
+                            if is_edge { &mut edges } else { &mut nodes }.push(current);
+                        } else {
+                            open.push((current, is_edge));
+                        }
+                    }
+                    "attvalue" if !open.is_empty() => {
+                        // GEXF 1.1 and earlier name the attribute id `id`.
+                        let id = attribute(&mut attributes, "for")
+                            .or_else(|| attribute(&mut attributes, "id"))
+                            .ok_or_else(|| syntax(&reader, "attvalue without attribute id"))?;
+                        let value = attribute(&mut attributes, "value")
+                            .ok_or_else(|| syntax(&reader, "attvalue without value"))?;
+                        open.last_mut().unwrap().0.values.push((id, value));
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(content) => {
+                if in_default {
+                    buffer.push_str(&content);
+                }
+            }
+            Event::End(name) => match name {
+                "default" if in_default => {
+                    if let Some((_, declared)) = declaration.as_mut() {
+                        declared.default = Some(core::mem::take(&mut buffer));
+                    }
+                    in_default = false;
+                }
+                "attribute" => {
+                    if let Some((id, declared)) = declaration.take() {
+                        if class_is_edge {
+                            &mut edge_declarations
+                        } else {
+                            &mut node_declarations
+                        }
+                        .insert(id, declared);
+                    }
+                }
+                "node" | "edge" => {
+                    if let Some((current, is_edge)) = open.pop() {
+                        if is_edge { &mut edges } else { &mut nodes }.push(current);
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+
+    let typed = |declarations: &HashMap<String, Declaration>, id: &str, value: &str| {
+        let declared = declarations
+            .get(id)
+            .ok_or_else(|| GexfError::UnknownAttribute(id.into()))?;
+        parse_value(&declared.type_name, value)
+            .map(|value| (declared.title.clone(), value))
+            .ok_or_else(|| GexfError::InvalidValue {
+                attribute: id.into(),
+                value: value.into(),
+            })
+    };
+    let attributes = |declarations: &HashMap<String, Declaration>, values: &[(String, String)]| {
+        let mut attributes = Attributes::new();
+        for (id, declared) in declarations {
+            if let Some(default) = &declared.default {
+                let (title, value) = typed(declarations, id, default)?;
+                attributes.insert(title, value);
+            }
+        }
+        for (id, value) in values {
+            let (title, value) = typed(declarations, id, value)?;
+            attributes.insert(title, value);
+        }
+        Ok(attributes)
+    };
+
+    let mut graph = G::with_capacity(nodes.len(), edges.len());
+    let mut indices = HashMap::with_capacity(nodes.len());
+    for node in nodes {
+        let id = node.id.unwrap_or_default();
+        if indices.contains_key(&id) {
+            return Err(GexfError::DuplicateNode(id));
+        }
+        let weight = GexfNode {
+            id: id.clone(),
+            label: node.label,
+            attributes: attributes(&node_declarations, &node.values)?,
+        };
+        indices.insert(id, graph.add_node(weight));
+    }
+    for edge in edges {
+        let (source, target) = edge.ends.unwrap_or_default();
+        let index = |id: String| indices.get(&id).copied().ok_or(GexfError::UnknownNode(id));
+        let (a, b) = (index(source)?, index(target)?);
+        let weight = match edge.weight {
+            Some(weight) => Some(weight.trim().parse().map_err(|_| GexfError::InvalidValue {
+                attribute: "weight".into(),
+                value: weight,
+            })?),
+            None => None,
+        };
+        let weight = GexfEdge {
+            id: edge.id,
+            label: edge.label,
+            weight,
+            attributes: attributes(&edge_declarations, &edge.values)?,
+        };
+        graph.add_edge(a, b, weight);
+    }
+    Ok(graph)
+}
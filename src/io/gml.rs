@@ -0,0 +1,480 @@
+//! [GML](https://en.wikipedia.org/wiki/Graph_Modelling_Language) import and
+//! export, with node and edge attributes.
+//!
+//! GML, the Graph Modelling Language, is a plain text format of nested
+//! lists of keys and values, read and written by networkx, igraph, Gephi,
+//! Cytoscape and yEd, among others. [`Gml`] writes any graph, with the
+//! attributes given by two functions, and [`from_gml`] reads a document
+//! into a [`Graph`] or a [`StableGraph`] whose weights hold the ids and
+//! attributes of the nodes and edges.
+//!
+//! The attributes are the keys of a node or an edge with an integer, a
+//! real or a string value; the keys with a list value, like the
+//! `graphics` of yEd, are ignored, as well as the attributes of the graph
+//! itself.
+//!
+//! [`Graph`]: crate::graph::Graph
+//! [`StableGraph`]: crate::stable_graph::StableGraph
+//!
+//! # Example
+//!
+//! ```
+//! use petgraph::graph::{DiGraph, EdgeReference};
+//! use petgraph::io::gml::{from_gml, Gml, GmlEdge, GmlNode};
+//! use petgraph::io::{AttrValue, Attributes};
+//! use petgraph::visit::EdgeRef;
+//!
+//! let mut graph = DiGraph::<&str, f64>::new();
+//! let a = graph.add_node("a");
+//! let b = graph.add_node("b");
+//! graph.add_edge(a, b, 2.0);
+//!
+//! let node_attributes = |(_, name): (_, &&str)| {
+//!     Attributes::from([("label".into(), AttrValue::from(*name))])
+//! };
+//! let edge_attributes = |edge: EdgeReference<f64>| {
+//!     Attributes::from([("weight".into(), AttrValue::from(*edge.weight()))])
+//! };
+//! let gml = Gml::with_attr_getters(&graph, &node_attributes, &edge_attributes).to_string();
+//!
+//! let parsed: DiGraph<GmlNode, GmlEdge> = from_gml(&gml).unwrap();
+//! assert_eq!(parsed[a].attributes["label"], AttrValue::from("a"));
+//! assert_eq!(parsed.raw_edges()[0].weight.attributes["weight"], AttrValue::Float(2.0));
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+use hashbrown::HashMap;
+
+use super::{AttrValue, Attributes};
+use crate::data::Create;
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+
+/// The weight of a node read by [`from_gml`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GmlNode {
+    /// The id of the node in the document.
+    pub id: i64,
+    /// The attributes of the node.
+    pub attributes: Attributes,
+}
+
+/// The weight of an edge read by [`from_gml`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GmlEdge {
+    /// The attributes of the edge, other than its source and target.
+    pub attributes: Attributes,
+}
+
+/// `Gml` implements output to GML for a graph.
+///
+/// The nodes get the ids `0`, `1`, ..., from their indices. The keys of the
+/// attributes should be letters and digits, starting with a letter, and the
+/// attributes `id` of the nodes, and `source` and `target` of the edges,
+/// are not written. Booleans are written as the integers `1` and `0`.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::UnGraph;
+/// use petgraph::io::gml::Gml;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let gml = Gml::new(&graph).to_string();
+/// assert!(gml.contains("edge [\n    source 0\n    target 1\n  ]"));
+/// ```
+pub struct Gml<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    graph: G,
+    get_node_attributes: &'a dyn Fn(G::NodeRef) -> Attributes,
+    get_edge_attributes: &'a dyn Fn(G::EdgeRef) -> Attributes,
+}
+
+impl<'a, G> Gml<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    /// Create a `Gml` formatting wrapper, without attributes.
+    pub fn new(graph: G) -> Self {
+        Self::with_attr_getters(graph, &|_| Attributes::new(), &|_| Attributes::new())
+    }
+
+    /// Create a `Gml` formatting wrapper, with the attributes of each node
+    /// and edge given by `get_node_attributes` and `get_edge_attributes`.
+    pub fn with_attr_getters(
+        graph: G,
+        get_node_attributes: &'a dyn Fn(G::NodeRef) -> Attributes,
+        get_edge_attributes: &'a dyn Fn(G::EdgeRef) -> Attributes,
+    ) -> Self {
+        Gml {
+            graph,
+            get_node_attributes,
+            get_edge_attributes,
+        }
+    }
+}
+
+/// Write the attributes, other than those named in `skip`.
+fn write_attributes(
+    f: &mut fmt::Formatter<'_>,
+    attributes: &Attributes,
+    skip: &[&str],
+) -> fmt::Result {
+    for (key, value) in attributes {
+        if skip.contains(&key.as_str()) {
+            continue;
+        }
+        write!(f, "    {key} ")?;
+        match value {
+            AttrValue::Boolean(value) => writeln!(f, "{}", u8::from(*value))?,
+            AttrValue::Int(value) => writeln!(f, "{value}")?,
+            // The debug format keeps a fraction or an exponent, so that the
+            // value is read back as a real.
+            AttrValue::Float(value) => writeln!(f, "{value:?}")?,
+            AttrValue::String(value) => {
+                f.write_str("\"")?;
+                let mut rest = value.as_str();
+                while let Some(i) = rest.find(['"', '&']) {
+                    f.write_str(&rest[..i])?;
+                    f.write_str(if rest.as_bytes()[i] == b'"' {
+                        "&quot;"
+                    } else {
+                        "&amp;"
+                    })?;
+                    rest = &rest[i + 1..];
+                }
+                writeln!(f, "{rest}\"")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<G> Display for Gml<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let g = self.graph;
+        writeln!(f, "graph [")?;
+        writeln!(f, "  directed {}", u8::from(g.is_directed()))?;
+        for node in g.node_references() {
+            writeln!(f, "  node [")?;
+            writeln!(f, "    id {}", g.to_index(node.id()))?;
+            write_attributes(f, &(self.get_node_attributes)(node), &["id"])?;
+            writeln!(f, "  ]")?;
+        }
+        for edge in g.edge_references() {
+            writeln!(f, "  edge [")?;
+            writeln!(f, "    source {}", g.to_index(edge.source()))?;
+            writeln!(f, "    target {}", g.to_index(edge.target()))?;
+            let attributes = (self.get_edge_attributes)(edge);
+            write_attributes(f, &attributes, &["source", "target"])?;
+            writeln!(f, "  ]")?;
+        }
+        writeln!(f, "]")
+    }
+}
+
+impl<G> fmt::Debug for Gml<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// An error while reading a GML document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GmlError {
+    /// The document is not valid GML, at the line.
+    Syntax {
+        /// The line of the error, from `1`.
+        line: usize,
+        /// A description of the error.
+        message: &'static str,
+    },
+    /// Two nodes have the same id.
+    DuplicateNode(i64),
+    /// An edge refers to a node id that is not declared.
+    UnknownNode(i64),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GmlError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for GmlError {}
+
+impl Display for GmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GmlError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            GmlError::DuplicateNode(id) => write!(f, "duplicate node id {id}"),
+            GmlError::UnknownNode(id) => write!(f, "edge to undeclared node id {id}"),
+        }
+    }
+}
+
+/// A token of a GML document.
+enum Token<'a> {
+    Key(&'a str),
+    Value(AttrValue),
+    Open,
+    Close,
+}
+
+/// A GML lexer, which skips whitespace and comment lines.
+struct Lexer<'a> {
+    input: &'a str,
+    position: usize,
+    line: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn syntax(&self, message: &'static str) -> GmlError {
+        GmlError::Syntax {
+            line: self.line,
+            message,
+        }
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token<'a>>, GmlError> {
+        let input = self.input;
+        let bytes = input.as_bytes();
+        loop {
+            match bytes.get(self.position) {
+                None => return Ok(None),
+                Some(b'\n') => {
+                    self.line += 1;
+                    self.position += 1;
+                }
+                Some(b) if b.is_ascii_whitespace() => self.position += 1,
+                Some(b'#') => {
+                    let end = input[self.position..].find('\n');
+                    self.position = end.map_or(input.len(), |end| self.position + end);
+                }
+                Some(_) => break,
+            }
+        }
+        let start = self.position;
+        let rest = &input[start..];
+        let token = match bytes[start] {
+            b'[' => {
+                self.position += 1;
+                Token::Open
+            }
+            b']' => {
+                self.position += 1;
+                Token::Close
+            }
+            b'"' => {
+                let end = rest[1..]
+                    .find('"')
+                    .ok_or_else(|| self.syntax("unterminated string"))?;
+                let raw = &rest[1..1 + end];
+                self.line += raw.matches('\n').count();
+                self.position += end + 2;
+                // Strings escape `"` and `&` as XML entities.
+                let value = crate::xml::decode(raw).unwrap_or_else(|_| raw.into());
+                Token::Value(AttrValue::String(value))
+            }
+            b if b.is_ascii_alphabetic() || b == b'_' => {
+                let end = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                self.position += end;
+                Token::Key(&rest[..end])
+            }
+            _ => {
+                let end = rest.find(|c: char| c.is_whitespace() || c == '[' || c == ']');
+                let end = end.unwrap_or(rest.len());
+                let number = &rest[..end];
+                self.position += end;
+                let value = if let Ok(value) = number.parse() {
+                    AttrValue::Int(value)
+                } else if let Ok(value) = number.parse() {
+                    AttrValue::Float(value)
+                } else {
+                    return Err(self.syntax("invalid number"));
+                };
+                Token::Value(value)
+            }
+        };
+        Ok(Some(token))
+    }
+
+    /// Read a value after a key: a scalar, or a list whose keys and values
+    /// are returned by `entries` as `(key, Some(value))`, or `(key, None)`
+    /// for a list value, which is skipped.
+    fn list(&mut self, entries: &mut Vec<(&'a str, Option<AttrValue>)>) -> Result<(), GmlError> {
+        loop {
+            let key = match self.next_token()? {
+                Some(Token::Key(key)) => key,
+                Some(Token::Close) => return Ok(()),
+                None => return Err(self.syntax("unterminated list")),
+                Some(_) => return Err(self.syntax("expected a key")),
+            };
+            match self.next_token()? {
+                Some(Token::Value(value)) => entries.push((key, Some(value))),
+                Some(Token::Open) => {
+                    self.skip_list()?;
+                    entries.push((key, None));
+                }
+                _ => return Err(self.syntax("expected a value")),
+            }
+        }
+    }
+
+    /// Skip the rest of a list, after its `[`.
+    fn skip_list(&mut self) -> Result<(), GmlError> {
+        let mut depth = 1;
+        while depth > 0 {
+            match self.next_token()? {
+                Some(Token::Open) => depth += 1,
+                Some(Token::Close) => depth -= 1,
+                Some(_) => {}
+                None => return Err(self.syntax("unterminated list")),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// \[Generic\] Read a GML document into a graph.
+///
+/// The graph gets the nodes, and the edges, of the first `graph` list of
+/// the document, in its order, weighted by their attributes. Each node
+/// must have an integer `id`, and each edge an integer `source` and
+/// `target`. When a key is repeated, the last value is kept. The edges are
+/// added in the direction of the document, whether or not the graph is
+/// directed.
+///
+/// # Arguments
+/// * `input`: the GML document.
+///
+/// # Returns
+/// * The graph, or an error if the document is not valid GML, if a node
+///   does not have an id, or if an edge refers to an undeclared node.
+///
+/// # Complexity
+/// * Time complexity: **O(n)**.
+/// * Auxiliary space: **O(n)**.
+///
+/// where **n** is the length of the document.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::io::gml::{from_gml, GmlEdge, GmlNode};
+/// use petgraph::io::AttrValue;
+///
+/// let gml = r#"
+/// Creator "example"
+/// graph [
+///   node [ id 1 label "a" ]
+///   node [ id 2 label "b" graphics [ x 1.0 y 2.0 ] ]
+///   edge [ source 1 target 2 weight 0.5 ]
+/// ]"#;
+///
+/// let graph: UnGraph<GmlNode, GmlEdge> = from_gml(gml).unwrap();
+/// assert_eq!(graph.node_count(), 2);
+/// assert_eq!(graph.raw_nodes()[1].weight.id, 2);
+/// assert_eq!(graph.raw_nodes()[1].weight.attributes["label"], AttrValue::from("b"));
+/// assert_eq!(graph.raw_edges()[0].weight.attributes["weight"], AttrValue::Float(0.5));
+/// ```
+pub fn from_gml<G>(input: &str) -> Result<G, GmlError>
+where
+    G: Create<NodeWeight = GmlNode, EdgeWeight = GmlEdge>,
+{
+    let mut lexer = Lexer {
+        input,
+        position: 0,
+        line: 1,
+    };
+    // Find the graph list.
+    loop {
+        match lexer.next_token()? {
+            Some(Token::Key("graph")) => match lexer.next_token()? {
+                Some(Token::Open) => break,
+                _ => return Err(lexer.syntax("expected a list after graph")),
+            },
+            Some(Token::Key(_)) => match lexer.next_token()? {
+                Some(Token::Value(_)) => {}
+                Some(Token::Open) => lexer.skip_list()?,
+                _ => return Err(lexer.syntax("expected a value")),
+            },
+            None => return Err(lexer.syntax("no graph list")),
+            Some(_) => return Err(lexer.syntax("expected a key")),
+        }
+    }
+
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut entries = Vec::new();
+    loop {
+        let key = match lexer.next_token()? {
+            Some(Token::Key(key)) => key,
+            Some(Token::Close) => break,
+            None => return Err(lexer.syntax("unterminated list")),
+            Some(_) => return Err(lexer.syntax("expected a key")),
+        };
+        match lexer.next_token()? {
+            Some(Token::Value(_)) => {}
+            Some(Token::Open) if key == "node" || key == "edge" => {
+                let line = lexer.line;
+                entries.clear();
+                lexer.list(&mut entries)?;
+                let mut attributes = Attributes::new();
+                let (mut first, mut second) = (None, None);
+                let names = if key == "node" {
+                    ["id", "id"]
+                } else {
+                    ["source", "target"]
+                };
+                for (name, value) in entries.drain(..) {
+                    match (name, value) {
+                        (name, Some(AttrValue::Int(id))) if name == names[0] => first = Some(id),
+                        (name, Some(AttrValue::Int(id))) if name == names[1] => second = Some(id),
+                        (name, Some(value)) => {
+                            attributes.insert(name.into(), value);
+                        }
+                        (_, None) => {}
+                    }
+                }
+                let syntax = |message| GmlError::Syntax { line, message };
+                if key == "node" {
+                    let id = first.ok_or_else(|| syntax("node without an integer id"))?;
+                    nodes.push(GmlNode { id, attributes });
+                } else {
+                    let ends = first.zip(second);
+                    let ends = ends.ok_or_else(|| syntax("edge without source or target"))?;
+                    edges.push((ends, GmlEdge { attributes }));
+                }
+            }
+            Some(Token::Open) => lexer.skip_list()?,
+            _ => return Err(lexer.syntax("expected a value")),
+        }
+    }
+
+    let mut graph = G::with_capacity(nodes.len(), edges.len());
+    let mut indices = HashMap::with_capacity(nodes.len());
+    for node in nodes {
+        let id = node.id;
+        if indices.contains_key(&id) {
+            return Err(GmlError::DuplicateNode(id));
+        }
+        indices.insert(id, graph.add_node(node));
+    }
+    for ((source, target), weight) in edges {
+        let index = |id| indices.get(&id).copied().ok_or(GmlError::UnknownNode(id));
+        graph.add_edge(index(source)?, index(target)?, weight);
+    }
+    Ok(graph)
+}
@@ -0,0 +1,437 @@
+//! The [networkx](https://networkx.org/) node-link and the
+//! [Cytoscape.js](https://js.cytoscape.org/) elements JSON schemas.
+//!
+//! A [`NodeLink`] is the document of `networkx.node_link_data`:
+//!
+//! ```json
+//! {"directed": true, "multigraph": false, "graph": {},
+//!  "nodes": [{"id": 0, "name": "a"}, {"id": 1, "name": "b"}],
+//!  "links": [{"source": 0, "target": 1, "weight": 2.5}]}
+//! ```
+//!
+//! and a [`Cytoscape`] the elements of a Cytoscape.js graph:
+//!
+//! ```json
+//! {"elements": {
+//!   "nodes": [{"data": {"id": "0", "name": "a"}}, {"data": {"id": "1", "name": "b"}}],
+//!   "edges": [{"data": {"id": "e0", "source": "0", "target": "1", "weight": 2.5}}]}}
+//! ```
+//!
+//! The fields of the node and edge weights are flattened into the objects of
+//! the nodes and edges, so the weights should serialize as maps, such as
+//! structs, maps or `()`. The documents are serialized and deserialized
+//! with serde, for example with `serde_json`, and converted from and into
+//! any graph, such as a [`Graph`] or a [`StableGraph`].
+//!
+//! [`Graph`]: crate::graph::Graph
+//! [`StableGraph`]: crate::stable_graph::StableGraph
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use core::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+use serde::de::{Deserialize, Deserializer, IgnoredAny};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::data::Create;
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+
+/// A graph in the networkx node-link schema.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::DiGraph;
+/// use petgraph::io::json::NodeLink;
+/// use serde_derive::{Deserialize, Serialize};
+///
+/// #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// struct Person {
+///     name: String,
+/// }
+///
+/// #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// struct Knows {
+///     since: u32,
+/// }
+///
+/// let mut graph = DiGraph::new();
+/// let a = graph.add_node(Person { name: "alice".into() });
+/// let b = graph.add_node(Person { name: "bob".into() });
+/// graph.add_edge(a, b, Knows { since: 2019 });
+///
+/// let json = serde_json::to_string(&NodeLink::from_graph(&graph)).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"directed":true,"multigraph":false,"graph":{},"nodes":[{"id":0,"name":"alice"},{"id":1,"name":"bob"}],"links":[{"source":0,"target":1,"since":2019}]}"#
+/// );
+///
+/// let node_link: NodeLink<usize, Person, Knows> = serde_json::from_str(&json).unwrap();
+/// let parsed: DiGraph<Person, Knows> = node_link.into_graph().unwrap();
+/// assert_eq!(parsed[b], Person { name: "bob".into() });
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "K: Serialize, N: Serialize, E: Serialize",
+    deserialize = "K: Deserialize<'de>, N: Deserialize<'de>, E: Deserialize<'de>"
+))]
+pub struct NodeLink<K, N, E> {
+    /// Whether the graph is directed.
+    pub directed: bool,
+    /// Whether the graph has parallel edges.
+    pub multigraph: bool,
+    /// The attributes of the graph, written as an empty object and ignored
+    /// when read.
+    #[serde(default)]
+    graph: NoAttributes,
+    /// The nodes.
+    pub nodes: Vec<NodeLinkNode<K, N>>,
+    /// The edges.
+    pub links: Vec<NodeLinkEdge<K, E>>,
+}
+
+/// A node of a [`NodeLink`] graph.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "K: Serialize, N: Serialize",
+    deserialize = "K: Deserialize<'de>, N: Deserialize<'de>"
+))]
+pub struct NodeLinkNode<K, N> {
+    /// The id of the node.
+    pub id: K,
+    /// The weight of the node, whose fields are those of the node object.
+    #[serde(flatten)]
+    pub weight: N,
+}
+
+/// An edge of a [`NodeLink`] graph.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "K: Serialize, E: Serialize",
+    deserialize = "K: Deserialize<'de>, E: Deserialize<'de>"
+))]
+pub struct NodeLinkEdge<K, E> {
+    /// The id of the source of the edge.
+    pub source: K,
+    /// The id of the target of the edge.
+    pub target: K,
+    /// The weight of the edge, whose fields are those of the edge object.
+    #[serde(flatten)]
+    pub weight: E,
+}
+
+impl<N, E> NodeLink<usize, N, E> {
+    /// Create the node-link document of a graph, with the node indices as
+    /// ids and clones of the node and edge weights.
+    ///
+    /// The graph is a `multigraph` if it has parallel edges.
+    pub fn from_graph<G>(graph: G) -> Self
+    where
+        G: IntoNodeReferences<NodeWeight = N> + IntoEdgeReferences<EdgeWeight = E>,
+        G: NodeIndexable + GraphProp,
+        N: Clone,
+        E: Clone,
+    {
+        let nodes = graph
+            .node_references()
+            .map(|node| NodeLinkNode {
+                id: graph.to_index(node.id()),
+                weight: node.weight().clone(),
+            })
+            .collect();
+        let links: Vec<_> = graph
+            .edge_references()
+            .map(|edge| NodeLinkEdge {
+                source: graph.to_index(edge.source()),
+                target: graph.to_index(edge.target()),
+                weight: edge.weight().clone(),
+            })
+            .collect();
+        let multigraph = has_parallel_edges(
+            links.iter().map(|edge| (edge.source, edge.target)),
+            graph.is_directed(),
+        );
+        NodeLink {
+            directed: graph.is_directed(),
+            multigraph,
+            graph: NoAttributes,
+            nodes,
+            links,
+        }
+    }
+}
+
+impl<K, N, E> NodeLink<K, N, E>
+where
+    K: Eq + Hash + fmt::Debug,
+{
+    /// \[Generic\] Create a graph from the node-link document.
+    ///
+    /// The graph gets the nodes, and then the edges, in the order of the
+    /// document, weighted by their weights. The edges are added in the
+    /// direction of the document, whether or not the graph is directed.
+    ///
+    /// # Returns
+    /// * The graph, or an error if two nodes have the same id, or if an edge
+    ///   refers to an unknown node.
+    ///
+    /// # Complexity
+    /// * Time complexity: **O(|V| + |E|)** expected.
+    /// * Auxiliary space: **O(|V|)**.
+    ///
+    /// where **|V|** is the number of nodes and **|E|** the number of edges.
+    pub fn into_graph<G>(self) -> Result<G, JsonGraphError>
+    where
+        G: Create<NodeWeight = N, EdgeWeight = E>,
+    {
+        build_graph(
+            self.nodes.into_iter().map(|node| (node.id, node.weight)),
+            self.links
+                .into_iter()
+                .map(|edge| (edge.source, edge.target, edge.weight)),
+        )
+    }
+}
+
+/// A graph in the Cytoscape.js elements schema.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use petgraph::graph::UnGraph;
+/// use petgraph::io::json::Cytoscape;
+///
+/// let json = r#"{"elements": {
+///     "nodes": [{"data": {"id": "a", "label": "A"}}, {"data": {"id": "b", "label": "B"}}],
+///     "edges": [{"data": {"id": "ab", "source": "a", "target": "b", "label": "AB"}}]
+/// }}"#;
+///
+/// type Data = BTreeMap<String, String>;
+/// let cytoscape: Cytoscape<Data, Data> = serde_json::from_str(json).unwrap();
+/// let graph: UnGraph<Data, Data> = cytoscape.into_graph().unwrap();
+/// assert_eq!(graph.edge_weights().next().unwrap()["label"], "AB");
+///
+/// let json = serde_json::to_string(&Cytoscape::from_graph(&graph)).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"elements":{"nodes":[{"data":{"id":"0","label":"A"}},{"data":{"id":"1","label":"B"}}],"edges":[{"data":{"id":"e0","source":"0","target":"1","label":"AB"}}]}}"#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "N: Serialize, E: Serialize",
+    deserialize = "N: Deserialize<'de>, E: Deserialize<'de>"
+))]
+pub struct Cytoscape<N, E> {
+    /// The nodes and edges.
+    pub elements: CytoscapeElements<N, E>,
+}
+
+/// The elements of a [`Cytoscape`] graph.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "N: Serialize, E: Serialize",
+    deserialize = "N: Deserialize<'de>, E: Deserialize<'de>"
+))]
+pub struct CytoscapeElements<N, E> {
+    /// The nodes.
+    pub nodes: Vec<CytoscapeElement<CytoscapeNode<N>>>,
+    /// The edges.
+    #[serde(default)]
+    pub edges: Vec<CytoscapeElement<CytoscapeEdge<E>>>,
+}
+
+/// An element of a [`Cytoscape`] graph, with its data.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CytoscapeElement<D> {
+    /// The data of the element.
+    pub data: D,
+}
+
+/// The data of a node of a [`Cytoscape`] graph.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound(serialize = "N: Serialize", deserialize = "N: Deserialize<'de>"))]
+pub struct CytoscapeNode<N> {
+    /// The id of the node.
+    pub id: String,
+    /// The weight of the node, whose fields are those of the data object.
+    #[serde(flatten)]
+    pub weight: N,
+}
+
+/// The data of an edge of a [`Cytoscape`] graph.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound(serialize = "E: Serialize", deserialize = "E: Deserialize<'de>"))]
+pub struct CytoscapeEdge<E> {
+    /// The id of the edge, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The id of the source of the edge.
+    pub source: String,
+    /// The id of the target of the edge.
+    pub target: String,
+    /// The weight of the edge, whose fields are those of the data object.
+    #[serde(flatten)]
+    pub weight: E,
+}
+
+impl<N, E> Cytoscape<N, E> {
+    /// Create the Cytoscape.js elements of a graph, with the node indices
+    /// as node ids, `e` followed by the edge numbers, in the order of
+    /// [`IntoEdgeReferences`], as edge ids, and clones of the node and edge
+    /// weights.
+    pub fn from_graph<G>(graph: G) -> Self
+    where
+        G: IntoNodeReferences<NodeWeight = N> + IntoEdgeReferences<EdgeWeight = E>,
+        G: NodeIndexable,
+        N: Clone,
+        E: Clone,
+    {
+        let nodes = graph
+            .node_references()
+            .map(|node| CytoscapeElement {
+                data: CytoscapeNode {
+                    id: graph.to_index(node.id()).to_string(),
+                    weight: node.weight().clone(),
+                },
+            })
+            .collect();
+        let edges = graph
+            .edge_references()
+            .enumerate()
+            .map(|(i, edge)| CytoscapeElement {
+                data: CytoscapeEdge {
+                    id: Some(format!("e{i}")),
+                    source: graph.to_index(edge.source()).to_string(),
+                    target: graph.to_index(edge.target()).to_string(),
+                    weight: edge.weight().clone(),
+                },
+            })
+            .collect();
+        Cytoscape {
+            elements: CytoscapeElements { nodes, edges },
+        }
+    }
+
+    /// \[Generic\] Create a graph from the Cytoscape.js elements.
+    ///
+    /// The graph gets the nodes, and then the edges, in the order of the
+    /// elements, weighted by their weights; the edge ids are dropped. The
+    /// edges are added in the direction of the elements, whether or not the
+    /// graph is directed.
+    ///
+    /// # Returns
+    /// * The graph, or an error if two nodes have the same id, or if an edge
+    ///   refers to an unknown node.
+    ///
+    /// # Complexity
+    /// * Time complexity: **O(|V| + |E|)** expected.
+    /// * Auxiliary space: **O(|V|)**.
+    ///
+    /// where **|V|** is the number of nodes and **|E|** the number of edges.
+    pub fn into_graph<G>(self) -> Result<G, JsonGraphError>
+    where
+        G: Create<NodeWeight = N, EdgeWeight = E>,
+    {
+        let CytoscapeElements { nodes, edges } = self.elements;
+        build_graph(
+            nodes
+                .into_iter()
+                .map(|node| (node.data.id, node.data.weight)),
+            edges
+                .into_iter()
+                .map(|edge| (edge.data.source, edge.data.target, edge.data.weight)),
+        )
+    }
+}
+
+/// An error while converting a JSON document into a graph.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonGraphError {
+    /// Two nodes have the same id, given in debug format.
+    DuplicateNode(String),
+    /// An edge refers to a node id, given in debug format, that is not a
+    /// node of the document.
+    UnknownNode(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JsonGraphError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for JsonGraphError {}
+
+impl fmt::Display for JsonGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonGraphError::DuplicateNode(id) => write!(f, "duplicate node id {id}"),
+            JsonGraphError::UnknownNode(id) => write!(f, "edge to unknown node id {id}"),
+        }
+    }
+}
+
+fn build_graph<G, K>(
+    nodes: impl Iterator<Item = (K, G::NodeWeight)>,
+    edges: impl Iterator<Item = (K, K, G::EdgeWeight)>,
+) -> Result<G, JsonGraphError>
+where
+    G: Create,
+    K: Eq + Hash + fmt::Debug,
+{
+    let mut graph = G::with_capacity(nodes.size_hint().0, edges.size_hint().0);
+    let mut indices = HashMap::new();
+    for (id, weight) in nodes {
+        if indices.contains_key(&id) {
+            return Err(JsonGraphError::DuplicateNode(format!("{id:?}")));
+        }
+        indices.insert(id, graph.add_node(weight));
+    }
+    for (source, target, weight) in edges {
+        let index = |id: &K| {
+            indices
+                .get(id)
+                .copied()
+                .ok_or_else(|| JsonGraphError::UnknownNode(format!("{id:?}")))
+        };
+        let (a, b) = (index(&source)?, index(&target)?);
+        graph.add_edge(a, b, weight);
+    }
+    Ok(graph)
+}
+
+fn has_parallel_edges(edges: impl Iterator<Item = (usize, usize)>, directed: bool) -> bool {
+    let mut seen = HashSet::new();
+    for (a, b) in edges {
+        let key = if directed || a <= b { (a, b) } else { (b, a) };
+        if !seen.insert(key) {
+            return true;
+        }
+    }
+    false
+}
+
+/// The graph attributes of a node-link document: an empty object, and
+/// anything when read.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct NoAttributes;
+
+impl Serialize for NoAttributes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for NoAttributes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        IgnoredAny::deserialize(deserializer).map(|_| NoAttributes)
+    }
+}
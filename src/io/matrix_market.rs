@@ -0,0 +1,323 @@
+//! [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html) import
+//! and export of graphs as sparse adjacency matrices.
+//!
+//! The Matrix Market coordinate format, used by the SuiteSparse Matrix
+//! Collection among others, stores a sparse matrix as its size followed by
+//! one line per nonzero entry, `row column [value]`, numbered from `1`. A
+//! graph is stored as its adjacency matrix: the entry of row `i` and column
+//! `j` is the edge from the `i`-th node to the `j`-th node, and its value
+//! the weight of the edge, or no value for a `pattern` matrix. A
+//! `symmetric` matrix stores only its lower triangle, and describes an
+//! undirected graph.
+//!
+//! [`MatrixMarket`] writes any graph, and [`from_matrix_market`] reads a
+//! matrix into a [`Graph`] or a [`StableGraph`]. The dense `array` format,
+//! and the `complex`, `skew-symmetric` and `hermitian` matrices, are not
+//! supported.
+//!
+//! [`Graph`]: crate::graph::Graph
+//! [`StableGraph`]: crate::stable_graph::StableGraph
+
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+use crate::data::Create;
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+};
+
+/// `MatrixMarket` implements output to the Matrix Market coordinate format
+/// for a graph.
+///
+/// The nodes are numbered from `1`, in the order of
+/// [`IntoNodeIdentifiers`], and each edge is an entry of the matrix, so
+/// that parallel edges are repeated entries, which readers usually sum.
+/// The matrix of an undirected graph is `symmetric`, unless the symmetric
+/// storage is disabled, in which case each edge other than a self loop is
+/// written as two entries. The entries have no values, for a `pattern`
+/// matrix, unless the weights of the edges are given.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::{EdgeReference, UnGraph};
+/// use petgraph::io::matrix_market::MatrixMarket;
+/// use petgraph::visit::EdgeRef;
+///
+/// let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 0.5), (2, 1, 2.0)]);
+/// let weight = |edge: EdgeReference<f64>| *edge.weight();
+/// assert_eq!(
+///     MatrixMarket::with_weights(&graph, &weight).to_string(),
+///     "%%MatrixMarket matrix coordinate real symmetric\n3 3 2\n2 1 0.5\n3 2 2\n"
+/// );
+/// assert_eq!(
+///     MatrixMarket::new(&graph).with_symmetric_storage(false).to_string(),
+///     "%%MatrixMarket matrix coordinate pattern general\n3 3 4\n1 2\n2 1\n3 2\n2 3\n"
+/// );
+/// ```
+pub struct MatrixMarket<'a, G>
+where
+    G: IntoEdgeReferences,
+{
+    graph: G,
+    get_edge_weight: Option<&'a dyn Fn(G::EdgeRef) -> f64>,
+    symmetric_storage: bool,
+}
+
+impl<'a, G> MatrixMarket<'a, G>
+where
+    G: IntoEdgeReferences,
+{
+    /// Create a `MatrixMarket` formatting wrapper for a `pattern` matrix,
+    /// without values.
+    pub fn new(graph: G) -> Self {
+        MatrixMarket {
+            graph,
+            get_edge_weight: None,
+            symmetric_storage: true,
+        }
+    }
+
+    /// Create a `MatrixMarket` formatting wrapper for a `real` matrix, with
+    /// the value of each entry given by `get_edge_weight`.
+    pub fn with_weights(graph: G, get_edge_weight: &'a dyn Fn(G::EdgeRef) -> f64) -> Self {
+        MatrixMarket {
+            graph,
+            get_edge_weight: Some(get_edge_weight),
+            symmetric_storage: true,
+        }
+    }
+
+    /// Set whether the matrix of an undirected graph is `symmetric`, which
+    /// is the default, or `general`, with each edge in both directions.
+    pub fn with_symmetric_storage(mut self, symmetric_storage: bool) -> Self {
+        self.symmetric_storage = symmetric_storage;
+        self
+    }
+}
+
+impl<G> Display for MatrixMarket<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let g = self.graph;
+        let mut numbers = vec![0; g.node_bound()];
+        for (i, node) in g.node_identifiers().enumerate() {
+            numbers[g.to_index(node)] = i + 1;
+        }
+        let symmetric = !g.is_directed() && self.symmetric_storage;
+        let both_ways = !g.is_directed() && !self.symmetric_storage;
+        let entries: usize = g
+            .edge_references()
+            .map(|edge| {
+                if both_ways && edge.source() != edge.target() {
+                    2
+                } else {
+                    1
+                }
+            })
+            .sum();
+        writeln!(
+            f,
+            "%%MatrixMarket matrix coordinate {} {}",
+            if self.get_edge_weight.is_some() {
+                "real"
+            } else {
+                "pattern"
+            },
+            if symmetric { "symmetric" } else { "general" }
+        )?;
+        let n = g.node_count();
+        writeln!(f, "{n} {n} {entries}")?;
+        for edge in g.edge_references() {
+            let mut row = numbers[g.to_index(edge.source())];
+            let mut column = numbers[g.to_index(edge.target())];
+            if symmetric && row < column {
+                core::mem::swap(&mut row, &mut column);
+            }
+            let weight = self.get_edge_weight.map(|get| get(edge));
+            let mut entry = |row, column| match weight {
+                Some(weight) => writeln!(f, "{row} {column} {weight}"),
+                None => writeln!(f, "{row} {column}"),
+            };
+            entry(row, column)?;
+            if both_ways && row != column {
+                entry(column, row)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<G> fmt::Debug for MatrixMarket<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// An error while reading a Matrix Market matrix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MatrixMarketError {
+    /// The matrix is not valid, at the line.
+    Syntax {
+        /// The line of the error, from `1`.
+        line: usize,
+        /// A description of the error.
+        message: &'static str,
+    },
+    /// The matrix is of a format, field or symmetry that is not supported,
+    /// or it is not square.
+    Unsupported(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MatrixMarketError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for MatrixMarketError {}
+
+impl Display for MatrixMarketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixMarketError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            MatrixMarketError::Unsupported(what) => write!(f, "unsupported matrix: {what}"),
+        }
+    }
+}
+
+/// \[Generic\] Read a Matrix Market coordinate matrix into a graph.
+///
+/// The matrix must be square: the graph gets a node per row, and an edge
+/// per entry, in the order of the matrix, weighted by its value, or by the
+/// default weight for a `pattern` matrix. An entry of a `symmetric` matrix
+/// outside of the diagonal is also added in the other direction if the
+/// graph is directed.
+///
+/// # Arguments
+/// * `input`: the matrix.
+///
+/// # Returns
+/// * The graph, or an error if the matrix is not valid, or not supported,
+///   or if it declares more than a thousand rows per byte of its length.
+///
+/// # Complexity
+/// * Time complexity: **O(n)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **n** is the length of the matrix and **|V|** the number of rows.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::DiGraph;
+/// use petgraph::io::matrix_market::from_matrix_market;
+///
+/// let mtx = "\
+/// %%MatrixMarket matrix coordinate integer symmetric
+/// % a path of three nodes
+/// 3 3 2
+/// 2 1 5
+/// 3 2 7
+/// ";
+///
+/// let graph: DiGraph<(), i32> = from_matrix_market(mtx).unwrap();
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.edge_weights().copied().collect::<Vec<_>>(), [5, 5, 7, 7]);
+/// ```
+pub fn from_matrix_market<G>(input: &str) -> Result<G, MatrixMarketError>
+where
+    G: Create<NodeWeight = ()> + GraphProp,
+    G::EdgeWeight: FromStr + Clone + Default,
+{
+    let mut lines = input.lines().enumerate();
+    let syntax = |i: usize, message| MatrixMarketError::Syntax {
+        line: i + 1,
+        message,
+    };
+    let header = lines.next().map_or("", |(_, line)| line);
+    let mut words = header.split_whitespace().map(str::to_ascii_lowercase);
+    if words.next().as_deref() != Some("%%matrixmarket")
+        || words.next().as_deref() != Some("matrix")
+    {
+        return Err(syntax(0, "expected the %%MatrixMarket matrix header"));
+    }
+    let (format, field, symmetry) = match (words.next(), words.next(), words.next()) {
+        (Some(format), Some(field), Some(symmetry)) => (format, field, symmetry),
+        _ => return Err(syntax(0, "expected the format, field and symmetry")),
+    };
+    if format != "coordinate" {
+        return Err(MatrixMarketError::Unsupported(format));
+    }
+    let pattern = match field.as_str() {
+        "pattern" => true,
+        "real" | "double" | "integer" => false,
+        _ => return Err(MatrixMarketError::Unsupported(field)),
+    };
+    let symmetric = match symmetry.as_str() {
+        "general" => false,
+        "symmetric" => true,
+        _ => return Err(MatrixMarketError::Unsupported(symmetry)),
+    };
+
+    let mut lines = lines.filter(|(_, line)| {
+        let line = line.trim();
+        !line.is_empty() && !line.starts_with('%')
+    });
+    let (i, size) = lines
+        .next()
+        .ok_or_else(|| syntax(0, "expected the size of the matrix"))?;
+    let size: Vec<usize> = size
+        .split_whitespace()
+        .map(|number| number.parse().map_err(|_| syntax(i, "invalid size")))
+        .collect::<Result<_, _>>()?;
+    let (rows, entries) = match size[..] {
+        [rows, columns, entries] if rows == columns => (rows, entries),
+        [_, _, _] => return Err(MatrixMarketError::Unsupported("not square".into())),
+        _ => return Err(syntax(i, "expected the rows, columns and entries")),
+    };
+
+    if !super::plausible_node_count(rows, input.len()) {
+        return Err(syntax(i, "too many rows for the length of the matrix"));
+    }
+
+    // Each entry takes a line, so the input bounds their number.
+    let mut graph = G::with_capacity(rows, entries.min(input.len()));
+    let mirror = symmetric && graph.is_directed();
+    let nodes: Vec<_> = (0..rows).map(|_| graph.add_node(())).collect();
+    let mut count = 0;
+    for (i, line) in lines {
+        let mut fields = line.split_whitespace();
+        let mut index = || -> Result<usize, MatrixMarketError> {
+            fields
+                .next()
+                .and_then(|index| index.parse::<usize>().ok())
+                .filter(|&index| 1 <= index && index <= rows)
+                .ok_or_else(|| syntax(i, "expected a row and a column in the matrix"))
+        };
+        let (row, column) = (index()? - 1, index()? - 1);
+        let weight = if pattern {
+            G::EdgeWeight::default()
+        } else {
+            fields
+                .next()
+                .and_then(|value| value.parse().ok())
+                .ok_or_else(|| syntax(i, "expected a valid value"))?
+        };
+        if mirror && row != column {
+            graph.add_edge(nodes[column], nodes[row], weight.clone());
+        }
+        graph.add_edge(nodes[row], nodes[column], weight);
+        count += 1;
+    }
+    if count != entries {
+        return Err(syntax(
+            input.lines().count().saturating_sub(1),
+            "the number of entries does not match the size line",
+        ));
+    }
+    Ok(graph)
+}
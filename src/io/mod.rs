@@ -0,0 +1,135 @@
+//! Readers and writers of graph interchange formats.
+//!
+//! Each format has its own module, with a formatting wrapper that writes
+//! any graph and a function that reads a document into a graph, such as a
+//! [`Graph`] or a [`StableGraph`], whose weights hold the ids and
+//! attributes of the nodes and edges.
+//!
+//! * [`adjacency_matrix`]: dense adjacency matrices.
+//! * `csv`: CSV tables of typed node and edge records, behind the `csv`
+//!   feature.
+//! * [`dimacs`]: the formats of the DIMACS shortest path, maximum flow and
+//!   graph coloring challenges.
+//! * [`edgelist`]: edge lists, including CSV and TSV edge tables.
+//! * [`gexf`]: GEXF, the XML format of [Gephi](https://gephi.org/).
+//! * [`gml`]: GML, the Graph Modelling Language.
+//! * `json`: the node-link JSON of networkx and the elements JSON of
+//!   Cytoscape.js, behind the `serde-1` feature.
+//! * [`matrix_market`]: sparse adjacency matrices in the Matrix Market
+//!   format.
+//! * [`pajek`]: the `.net` format of Pajek.
+//! * [`snapshot`]: a compact binary format, to cache graphs between runs.
+//!
+//! See also the [`dot`](crate::dot) and [`graph6`](crate::graph6) modules,
+//! and the `graphml` module behind the `graphml` feature.
+//!
+//! [`Graph`]: crate::graph::Graph
+//! [`StableGraph`]: crate::stable_graph::StableGraph
+
+use alloc::{collections::BTreeMap, string::String};
+use core::fmt;
+
+pub mod adjacency_matrix;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod dimacs;
+pub mod edgelist;
+pub mod gexf;
+pub mod gml;
+#[cfg(feature = "serde-1")]
+pub mod json;
+pub mod matrix_market;
+pub mod pajek;
+pub mod snapshot;
+
+/// Return whether a document of `len` bytes may declare `count` nodes.
+///
+/// Some formats declare the number of nodes ahead, and the nodes need not
+/// appear again, but a count far beyond the length of the document is rather
+/// corrupt, and creating its nodes would exhaust the memory. A thousand nodes
+/// per byte is more than any real document declares.
+pub(crate) fn plausible_node_count(count: usize, len: usize) -> bool {
+    count <= len.saturating_mul(1000)
+}
+
+/// The value of an attribute of a node or an edge.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttrValue {
+    /// A boolean value.
+    Boolean(bool),
+    /// An integer value.
+    Int(i64),
+    /// A floating point value.
+    Float(f64),
+    /// A string value.
+    String(String),
+}
+
+impl AttrValue {
+    /// Return the GraphML and GEXF type of the value.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            AttrValue::Boolean(_) => "boolean",
+            AttrValue::Int(_) => "long",
+            AttrValue::Float(_) => "double",
+            AttrValue::String(_) => "string",
+        }
+    }
+}
+
+/// Format the value as it is written in the XML formats, without escaping.
+impl fmt::Display for AttrValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttrValue::Boolean(value) => value.fmt(f),
+            AttrValue::Int(value) => value.fmt(f),
+            AttrValue::Float(value) => value.fmt(f),
+            AttrValue::String(value) => value.fmt(f),
+        }
+    }
+}
+
+impl From<bool> for AttrValue {
+    fn from(value: bool) -> Self {
+        AttrValue::Boolean(value)
+    }
+}
+
+impl From<i32> for AttrValue {
+    fn from(value: i32) -> Self {
+        AttrValue::Int(value.into())
+    }
+}
+
+impl From<i64> for AttrValue {
+    fn from(value: i64) -> Self {
+        AttrValue::Int(value)
+    }
+}
+
+impl From<f32> for AttrValue {
+    fn from(value: f32) -> Self {
+        AttrValue::Float(value.into())
+    }
+}
+
+impl From<f64> for AttrValue {
+    fn from(value: f64) -> Self {
+        AttrValue::Float(value)
+    }
+}
+
+impl From<&str> for AttrValue {
+    fn from(value: &str) -> Self {
+        AttrValue::String(value.into())
+    }
+}
+
+impl From<String> for AttrValue {
+    fn from(value: String) -> Self {
+        AttrValue::String(value)
+    }
+}
+
+/// The attributes of a node or an edge, by name.
+pub type Attributes = BTreeMap<String, AttrValue>;
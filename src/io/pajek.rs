@@ -0,0 +1,391 @@
+//! [Pajek](http://mrvar.fdv.uni-lj.si/pajek/) `.net` import and export.
+//!
+//! A Pajek network lists its vertices, numbered from `1`, with their labels
+//! and coordinates, then its arcs, which are directed, and its edges, which
+//! are not, with their weights. [`Pajek`] writes any graph, with the labels
+//! and weights given by two functions, and [`from_pajek`] reads a network
+//! into a [`Graph`] or a [`StableGraph`].
+//!
+//! The vertex and edge shape parameters, such as colors, are ignored, as
+//! well as the partitions, vectors and permutations of a project file.
+//!
+//! [`Graph`]: crate::graph::Graph
+//! [`StableGraph`]: crate::stable_graph::StableGraph
+
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt::{self, Display};
+
+use crate::data::Create;
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+
+/// The weight of a vertex read by [`from_pajek`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PajekNode {
+    /// The label of the vertex, or its number if it has no label.
+    pub label: String,
+    /// The coordinates of the vertex, from none up to `x`, `y` and `z`.
+    pub coordinates: Vec<f64>,
+}
+
+/// The weight of an arc or an edge read by [`from_pajek`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PajekEdge {
+    /// Whether it is an arc, rather than an edge.
+    pub directed: bool,
+    /// The weight, if it has one.
+    pub weight: Option<f64>,
+}
+
+/// `Pajek` implements output to the Pajek `.net` format for a graph.
+///
+/// The vertices are numbered from `1`, in the order of
+/// [`IntoNodeReferences`], and only those with a label are listed. The
+/// edges are arcs for a directed graph.
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::{DiGraph, EdgeReference};
+/// use petgraph::io::pajek::Pajek;
+/// use petgraph::visit::EdgeRef;
+///
+/// let graph = DiGraph::<&str, f64>::from_edges(&[(0, 1, 2.5), (1, 0, 1.0)]);
+/// let net = Pajek::with_attr_getters(
+///     &graph,
+///     &|(i, _)| Some(format!("v{}", i.index())),
+///     &|edge: EdgeReference<f64>| Some(*edge.weight()),
+/// );
+/// assert_eq!(
+///     net.to_string(),
+///     "*Vertices 2\n1 \"v0\"\n2 \"v1\"\n*Arcs\n1 2 2.5\n2 1 1\n"
+/// );
+/// ```
+pub struct Pajek<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    graph: G,
+    get_node_label: &'a dyn Fn(G::NodeRef) -> Option<String>,
+    get_edge_weight: &'a dyn Fn(G::EdgeRef) -> Option<f64>,
+}
+
+impl<'a, G> Pajek<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    /// Create a `Pajek` formatting wrapper, without labels and weights.
+    pub fn new(graph: G) -> Self {
+        Self::with_attr_getters(graph, &|_| None, &|_| None)
+    }
+
+    /// Create a `Pajek` formatting wrapper, with the label of each vertex,
+    /// if any, given by `get_node_label`, and the weight of each edge, if
+    /// any, by `get_edge_weight`.
+    pub fn with_attr_getters(
+        graph: G,
+        get_node_label: &'a dyn Fn(G::NodeRef) -> Option<String>,
+        get_edge_weight: &'a dyn Fn(G::EdgeRef) -> Option<f64>,
+    ) -> Self {
+        Pajek {
+            graph,
+            get_node_label,
+            get_edge_weight,
+        }
+    }
+}
+
+impl<G> Display for Pajek<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let g = self.graph;
+        // The number of each vertex, by node index.
+        let mut numbers = vec![0; g.node_bound()];
+        let mut count = 0;
+        for node in g.node_references() {
+            count += 1;
+            numbers[g.to_index(node.id())] = count;
+        }
+        writeln!(f, "*Vertices {count}")?;
+        for node in g.node_references() {
+            if let Some(label) = (self.get_node_label)(node) {
+                // Labels cannot contain quotes.
+                let label = label.replace('"', "'");
+                writeln!(f, "{} \"{}\"", numbers[g.to_index(node.id())], label)?;
+            }
+        }
+        writeln!(f, "{}", if g.is_directed() { "*Arcs" } else { "*Edges" })?;
+        for edge in g.edge_references() {
+            let a = numbers[g.to_index(edge.source())];
+            let b = numbers[g.to_index(edge.target())];
+            match (self.get_edge_weight)(edge) {
+                Some(weight) => writeln!(f, "{a} {b} {weight}")?,
+                None => writeln!(f, "{a} {b}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<G> fmt::Debug for Pajek<'_, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// An error while reading a Pajek network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PajekError {
+    /// The network is not valid, at the line.
+    Syntax {
+        /// The line of the error, from `1`.
+        line: usize,
+        /// A description of the error.
+        message: &'static str,
+    },
+    /// An arc or an edge refers to a vertex number that is not declared.
+    UnknownNode(usize),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PajekError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for PajekError {}
+
+impl Display for PajekError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PajekError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            PajekError::UnknownNode(number) => {
+                write!(f, "edge to undeclared vertex number {number}")
+            }
+        }
+    }
+}
+
+/// Split a line into tokens, at whitespace, except inside quotes.
+fn tokens(line: &str) -> impl Iterator<Item = &str> {
+    let mut rest = line.trim_start();
+    core::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let token;
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted.find('"').unwrap_or(quoted.len());
+            token = &quoted[..end];
+            rest = quoted.get(end + 1..).unwrap_or("");
+        } else {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            token = &rest[..end];
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+        Some(token)
+    })
+}
+
+/// The section of the network being read.
+enum Section {
+    None,
+    Vertices,
+    Edges { directed: bool, list: bool },
+    Matrix { row: usize },
+    Ignored,
+}
+
+/// \[Generic\] Read a Pajek `.net` network into a graph.
+///
+/// The graph gets the vertices in the order of their numbers, weighted by
+/// their label and coordinates, and then the arcs and edges in the order of
+/// the network, weighted by whether they are arcs and by their weight. The
+/// arcs and edges may be given as lines, as lists or as a matrix, whose
+/// nonzero entries are arcs. They are added in the direction of the
+/// network, whether or not the graph is directed.
+///
+/// # Arguments
+/// * `input`: the network.
+///
+/// # Returns
+/// * The graph, or an error if the network is not valid, if an edge
+///   refers to an undeclared vertex, or if it declares more than a thousand
+///   vertices per byte of its length.
+///
+/// # Complexity
+/// * Time complexity: **O(n)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **n** is the length of the network, **|V|** the number of
+/// vertices and **|E|** the number of arcs and edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::DiGraph;
+/// use petgraph::io::pajek::{from_pajek, PajekEdge, PajekNode};
+///
+/// let net = r#"
+/// *Vertices 3
+/// 1 "a" 0.1 0.2 0.5
+/// 2 "b"
+/// *Arcs
+/// 1 2 1.5
+/// *Edges
+/// % an unweighted edge
+/// 2 3
+/// "#;
+///
+/// let graph: DiGraph<PajekNode, PajekEdge> = from_pajek(net).unwrap();
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.raw_nodes()[0].weight.coordinates, [0.1, 0.2, 0.5]);
+/// assert_eq!(graph.raw_nodes()[2].weight.label, "3");
+/// assert_eq!(graph.raw_edges()[0].weight, PajekEdge { directed: true, weight: Some(1.5) });
+/// assert_eq!(graph.raw_edges()[1].weight, PajekEdge { directed: false, weight: None });
+/// ```
+pub fn from_pajek<G>(input: &str) -> Result<G, PajekError>
+where
+    G: Create<NodeWeight = PajekNode, EdgeWeight = PajekEdge>,
+{
+    let mut nodes: Option<Vec<PajekNode>> = None;
+    let mut edges: Vec<(usize, usize, PajekEdge)> = Vec::new();
+    let mut section = Section::None;
+
+    for (i, line) in input.lines().enumerate() {
+        let syntax = |message| PajekError::Syntax {
+            line: i + 1,
+            message,
+        };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+        let mut tokens = tokens(line);
+        if let Some(keyword) = line.strip_prefix('*') {
+            let keyword = keyword.split_whitespace().next().unwrap_or("");
+            let keyword = keyword.to_ascii_lowercase();
+            if keyword == "vertices" {
+                if nodes.is_some() {
+                    return Err(syntax("more than one vertices section"));
+                }
+                let count: usize = tokens
+                    .nth(1)
+                    .and_then(|count| count.parse().ok())
+                    .ok_or_else(|| syntax("expected the number of vertices"))?;
+                if !super::plausible_node_count(count, input.len()) {
+                    return Err(syntax("too many vertices for the length of the network"));
+                }
+                nodes = Some(
+                    (1..=count)
+                        .map(|number| PajekNode {
+                            label: alloc::format!("{number}"),
+                            coordinates: Vec::new(),
+                        })
+                        .collect(),
+                );
+                section = Section::Vertices;
+                continue;
+            }
+            section = match keyword.as_str() {
+                "arcs" | "edges" | "arcslist" | "edgeslist" | "matrix" if nodes.is_none() => {
+                    return Err(syntax("edges before the vertices section"))
+                }
+                "arcs" => Section::Edges {
+                    directed: true,
+                    list: false,
+                },
+                "edges" => Section::Edges {
+                    directed: false,
+                    list: false,
+                },
+                "arcslist" => Section::Edges {
+                    directed: true,
+                    list: true,
+                },
+                "edgeslist" => Section::Edges {
+                    directed: false,
+                    list: true,
+                },
+                "matrix" => Section::Matrix { row: 1 },
+                _ => Section::Ignored,
+            };
+            continue;
+        }
+        let count = nodes.as_ref().map_or(0, Vec::len);
+        let number = |token: Option<&str>| -> Result<usize, PajekError> {
+            let number = token
+                .and_then(|token| token.parse().ok())
+                .ok_or_else(|| syntax("expected a vertex number"))?;
+            if number == 0 || number > count {
+                return Err(PajekError::UnknownNode(number));
+            }
+            Ok(number)
+        };
+        match section {
+            Section::None => return Err(syntax("expected a section")),
+            Section::Ignored => {}
+            Section::Vertices => {
+                let number = number(tokens.next())?;
+                let node = &mut nodes.as_mut().unwrap()[number - 1];
+                if let Some(label) = tokens.next() {
+                    node.label = label.into();
+                }
+                node.coordinates = tokens
+                    .map_while(|token| token.parse().ok())
+                    .take(3)
+                    .collect();
+            }
+            Section::Edges { directed, list } => {
+                let a = number(tokens.next())?;
+                if list {
+                    for token in tokens {
+                        let b = number(Some(token))?;
+                        let weight = PajekEdge {
+                            directed,
+                            weight: None,
+                        };
+                        edges.push((a, b, weight));
+                    }
+                } else {
+                    let b = number(tokens.next())?;
+                    let weight = tokens.next().and_then(|token| token.parse().ok());
+                    edges.push((a, b, PajekEdge { directed, weight }));
+                }
+            }
+            Section::Matrix { ref mut row } => {
+                if *row > count {
+                    return Err(syntax("more matrix rows than vertices"));
+                }
+                for (column, token) in tokens.enumerate() {
+                    let weight: f64 = token
+                        .parse()
+                        .map_err(|_| syntax("expected a matrix entry"))?;
+                    if column >= count {
+                        return Err(syntax("more matrix columns than vertices"));
+                    }
+                    if weight != 0.0 {
+                        let weight = PajekEdge {
+                            directed: true,
+                            weight: Some(weight),
+                        };
+                        edges.push((*row, column + 1, weight));
+                    }
+                }
+                *row += 1;
+            }
+        }
+    }
+
+    let nodes = nodes.unwrap_or_default();
+    let mut graph = G::with_capacity(nodes.len(), edges.len());
+    let indices: Vec<_> = nodes.into_iter().map(|node| graph.add_node(node)).collect();
+    for (a, b, weight) in edges {
+        graph.add_edge(indices[a - 1], indices[b - 1], weight);
+    }
+    Ok(graph)
+}
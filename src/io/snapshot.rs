@@ -0,0 +1,791 @@
+//! A compact binary snapshot format for [`Graph`], [`StableGraph`] and
+//! [`Csr`], to cache large graphs between runs.
+//!
+//! A snapshot starts with a header: the magic bytes `PGSNAP`, the version
+//! of the format, whether the graph is directed, the width in bytes of the
+//! node indices, 4 or 8, and the node bound, node count and edge count. It
+//! continues with fixed width arrays of the node indices and of the edge
+//! endpoints, and ends with the node weights and the edge weights, each as a
+//! length-prefixed array of bytes. All numbers are little endian.
+//!
+//! The weights are written with [`Encode`] and read with [`Decode`], which
+//! are implemented for the primitive types, strings, vectors, options and
+//! pairs. A [`SnapshotView`] reads the header and the indices without
+//! copying, and decodes the weights lazily: the `&str` and `&[u8]` weights
+//! are borrowed from the snapshot.
+//!
+//! # Example
+//!
+//! ```
+//! use petgraph::graph::DiGraph;
+//! use petgraph::io::snapshot::SnapshotView;
+//!
+//! let mut graph = DiGraph::<String, f64>::new();
+//! let a = graph.add_node("a".into());
+//! let b = graph.add_node("b".into());
+//! graph.add_edge(a, b, 2.5);
+//!
+//! let bytes = graph.to_snapshot();
+//! let copy = DiGraph::<String, f64>::from_snapshot(&bytes).unwrap();
+//! assert_eq!(copy[b], "b");
+//!
+//! // Borrow the labels instead of copying them.
+//! let view = SnapshotView::new(&bytes).unwrap();
+//! let labels: Vec<&str> = view.node_weights().collect::<Result<_, _>>().unwrap();
+//! assert_eq!(labels, ["a", "b"]);
+//! ```
+//!
+//! [`Graph`]: crate::graph::Graph
+//! [`StableGraph`]: crate::stable_graph::StableGraph
+//! [`Csr`]: crate::csr::Csr
+
+use alloc::{string::String, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::csr::Csr;
+use crate::graph::{Graph, IndexType, NodeIndex};
+#[cfg(feature = "stable_graph")]
+use crate::stable_graph::StableGraph;
+#[cfg(feature = "stable_graph")]
+use crate::visit::NodeIndexable;
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences};
+use crate::EdgeType;
+
+/// The magic bytes at the start of a snapshot.
+const MAGIC: &[u8; 6] = b"PGSNAP";
+/// The length of the header of a snapshot.
+const HEADER_LEN: usize = 34;
+
+/// The version of the snapshot format written by this version of the crate.
+///
+/// Snapshots of other versions are rejected with
+/// [`SnapshotError::UnsupportedVersion`].
+pub const SNAPSHOT_VERSION: u16 = 1;
+
+/// An error while reading a snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The bytes do not start with the magic bytes of a snapshot.
+    InvalidMagic,
+    /// The snapshot is of an unsupported version of the format.
+    UnsupportedVersion(u16),
+    /// The snapshot ends before its end.
+    UnexpectedEnd,
+    /// The snapshot is directed, and the graph undirected, or conversely.
+    EdgeTypeMismatch,
+    /// The snapshot does not fit the index type of the graph.
+    IndexOverflow,
+    /// The snapshot is not valid.
+    Invalid(&'static str),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnapshotError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for SnapshotError {}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::InvalidMagic => f.write_str("not a graph snapshot"),
+            SnapshotError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {version}")
+            }
+            SnapshotError::UnexpectedEnd => f.write_str("unexpected end of snapshot"),
+            SnapshotError::EdgeTypeMismatch => {
+                f.write_str("the snapshot and the graph differ in directedness")
+            }
+            SnapshotError::IndexOverflow => f.write_str("the snapshot does not fit the index type"),
+            SnapshotError::Invalid(message) => write!(f, "invalid snapshot: {message}"),
+        }
+    }
+}
+
+/// A weight that can be written to a snapshot.
+pub trait Encode {
+    /// Append the bytes of `self` to `out`.
+    fn encode(&self, out: &mut Vec<u8>);
+}
+
+/// A weight that can be read from a snapshot, possibly borrowing from it.
+pub trait Decode<'a>: Sized {
+    /// Read a value from the start of `input`, and advance `input` past it.
+    fn decode(input: &mut &'a [u8]) -> Result<Self, SnapshotError>;
+}
+
+/// Split the first `n` bytes off `input`.
+fn take<'a>(input: &mut &'a [u8], n: usize) -> Result<&'a [u8], SnapshotError> {
+    if input.len() < n {
+        return Err(SnapshotError::UnexpectedEnd);
+    }
+    let (head, tail) = input.split_at(n);
+    *input = tail;
+    Ok(head)
+}
+
+/// Read a length, checking that it fits in `usize`.
+fn decode_len(input: &mut &[u8]) -> Result<usize, SnapshotError> {
+    let len = u64::decode(input)?;
+    usize::try_from(len).map_err(|_| SnapshotError::Invalid("length overflows usize"))
+}
+
+macro_rules! impl_number {
+    ($($ty:ty),*) => {
+        $(
+            impl Encode for $ty {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+
+            impl Decode<'_> for $ty {
+                fn decode(input: &mut &[u8]) -> Result<Self, SnapshotError> {
+                    let bytes = take(input, core::mem::size_of::<$ty>())?;
+                    Ok(<$ty>::from_le_bytes(bytes.try_into().unwrap()))
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl Encode for usize {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (*self as u64).encode(out);
+    }
+}
+
+impl Decode<'_> for usize {
+    fn decode(input: &mut &[u8]) -> Result<Self, SnapshotError> {
+        usize::try_from(u64::decode(input)?)
+            .map_err(|_| SnapshotError::Invalid("integer overflows usize"))
+    }
+}
+
+impl Encode for isize {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (*self as i64).encode(out);
+    }
+}
+
+impl Decode<'_> for isize {
+    fn decode(input: &mut &[u8]) -> Result<Self, SnapshotError> {
+        isize::try_from(i64::decode(input)?)
+            .map_err(|_| SnapshotError::Invalid("integer overflows isize"))
+    }
+}
+
+impl Encode for () {
+    fn encode(&self, _: &mut Vec<u8>) {}
+}
+
+impl Decode<'_> for () {
+    fn decode(_: &mut &[u8]) -> Result<Self, SnapshotError> {
+        Ok(())
+    }
+}
+
+impl Encode for bool {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(u8::from(*self));
+    }
+}
+
+impl Decode<'_> for bool {
+    fn decode(input: &mut &[u8]) -> Result<Self, SnapshotError> {
+        match u8::decode(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(SnapshotError::Invalid("invalid boolean")),
+        }
+    }
+}
+
+impl Encode for char {
+    fn encode(&self, out: &mut Vec<u8>) {
+        u32::from(*self).encode(out);
+    }
+}
+
+impl Decode<'_> for char {
+    fn decode(input: &mut &[u8]) -> Result<Self, SnapshotError> {
+        char::from_u32(u32::decode(input)?).ok_or(SnapshotError::Invalid("invalid char"))
+    }
+}
+
+impl Encode for str {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.as_bytes().encode(out);
+    }
+}
+
+impl<'a> Decode<'a> for &'a str {
+    fn decode(input: &mut &'a [u8]) -> Result<Self, SnapshotError> {
+        let bytes = <&[u8]>::decode(input)?;
+        core::str::from_utf8(bytes).map_err(|_| SnapshotError::Invalid("invalid UTF-8"))
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.as_str().encode(out);
+    }
+}
+
+impl Decode<'_> for String {
+    fn decode(input: &mut &[u8]) -> Result<Self, SnapshotError> {
+        <&str>::decode(input).map(String::from)
+    }
+}
+
+impl<'a> Decode<'a> for &'a [u8] {
+    fn decode(input: &mut &'a [u8]) -> Result<Self, SnapshotError> {
+        let len = decode_len(input)?;
+        take(input, len)
+    }
+}
+
+impl<T: Encode> Encode for [T] {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.len().encode(out);
+        for item in self {
+            item.encode(out);
+        }
+    }
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.as_slice().encode(out);
+    }
+}
+
+impl<'a, T: Decode<'a>> Decode<'a> for Vec<T> {
+    fn decode(input: &mut &'a [u8]) -> Result<Self, SnapshotError> {
+        let len = decode_len(input)?;
+        // Every item takes at least a byte, except zero sized ones.
+        let mut items = Vec::with_capacity(len.min(input.len()));
+        for _ in 0..len {
+            items.push(T::decode(input)?);
+        }
+        Ok(items)
+    }
+}
+
+impl<T: Encode + ?Sized> Encode for &T {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (**self).encode(out);
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(value) => {
+                out.push(1);
+                value.encode(out);
+            }
+        }
+    }
+}
+
+impl<'a, T: Decode<'a>> Decode<'a> for Option<T> {
+    fn decode(input: &mut &'a [u8]) -> Result<Self, SnapshotError> {
+        match u8::decode(input)? {
+            0 => Ok(None),
+            1 => T::decode(input).map(Some),
+            _ => Err(SnapshotError::Invalid("invalid option")),
+        }
+    }
+}
+
+impl<A: Encode, B: Encode> Encode for (A, B) {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.0.encode(out);
+        self.1.encode(out);
+    }
+}
+
+impl<'a, A: Decode<'a>, B: Decode<'a>> Decode<'a> for (A, B) {
+    fn decode(input: &mut &'a [u8]) -> Result<Self, SnapshotError> {
+        Ok((A::decode(input)?, B::decode(input)?))
+    }
+}
+
+/// Write the snapshot of a graph with the given nodes, as node indices and
+/// weights, and edges, as endpoints and weights.
+fn write_snapshot<N: Encode, E: Encode>(
+    directed: bool,
+    node_bound: usize,
+    nodes: &[(usize, &N)],
+    edges: &[(usize, usize, &E)],
+) -> Vec<u8> {
+    let width = if node_bound <= u32::MAX as usize {
+        4
+    } else {
+        8
+    };
+    let write_index = |out: &mut Vec<u8>, index: usize| {
+        if width == 4 {
+            (index as u32).encode(out);
+        } else {
+            (index as u64).encode(out);
+        }
+    };
+
+    let mut out = Vec::with_capacity(HEADER_LEN + width * (nodes.len() + 2 * edges.len()));
+    out.extend_from_slice(MAGIC);
+    SNAPSHOT_VERSION.encode(&mut out);
+    directed.encode(&mut out);
+    (width as u8).encode(&mut out);
+    node_bound.encode(&mut out);
+    nodes.len().encode(&mut out);
+    edges.len().encode(&mut out);
+    for &(index, _) in nodes {
+        write_index(&mut out, index);
+    }
+    for &(a, b, _) in edges {
+        write_index(&mut out, a);
+        write_index(&mut out, b);
+    }
+
+    // The weights, each prefixed by its length, filled in afterwards.
+    let write_weights = |out: &mut Vec<u8>, encode: &dyn Fn(&mut Vec<u8>)| {
+        let start = out.len();
+        0u64.encode(out);
+        encode(out);
+        let len = (out.len() - start - 8) as u64;
+        out[start..start + 8].copy_from_slice(&len.to_le_bytes());
+    };
+    write_weights(&mut out, &|out| {
+        for (_, weight) in nodes {
+            weight.encode(out);
+        }
+    });
+    write_weights(&mut out, &|out| {
+        for (_, _, weight) in edges {
+            weight.encode(out);
+        }
+    });
+    out
+}
+
+/// A snapshot, read without copying.
+///
+/// The header and the index arrays are checked by [`SnapshotView::new`], and
+/// the weights are decoded on demand.
+#[derive(Clone, Copy, Debug)]
+pub struct SnapshotView<'a> {
+    directed: bool,
+    width: usize,
+    node_bound: usize,
+    node_count: usize,
+    edge_count: usize,
+    node_indices: &'a [u8],
+    edge_endpoints: &'a [u8],
+    node_weights: &'a [u8],
+    edge_weights: &'a [u8],
+}
+
+impl<'a> SnapshotView<'a> {
+    /// Read the header and the index arrays of a snapshot.
+    ///
+    /// Return an error if the bytes are not a snapshot of the supported
+    /// version, if they are truncated, or if an index is out of bounds.
+    ///
+    /// Computes in **O(|V| + |E| log |V|)** time, and in **O(|V| + |E|)**
+    /// time for a snapshot without vacant nodes.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, SnapshotError> {
+        let mut input = bytes;
+        if take(&mut input, MAGIC.len()).ok() != Some(&MAGIC[..]) {
+            return Err(SnapshotError::InvalidMagic);
+        }
+        let version = u16::decode(&mut input)?;
+        if version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+        let directed = bool::decode(&mut input)?;
+        let width = match u8::decode(&mut input)? {
+            4 => 4,
+            8 => 8,
+            _ => return Err(SnapshotError::Invalid("invalid index width")),
+        };
+        let node_bound = decode_len(&mut input)?;
+        let node_count = decode_len(&mut input)?;
+        let edge_count = decode_len(&mut input)?;
+        let array_len = |count: usize| count.checked_mul(width).ok_or(SnapshotError::UnexpectedEnd);
+        let node_indices = take(&mut input, array_len(node_count)?)?;
+        let edge_endpoints = take(&mut input, array_len(edge_count)?.saturating_mul(2))?;
+        let node_weights = <&[u8]>::decode(&mut input)?;
+        let edge_weights = <&[u8]>::decode(&mut input)?;
+        if !input.is_empty() {
+            return Err(SnapshotError::Invalid("trailing bytes"));
+        }
+        let view = SnapshotView {
+            directed,
+            width,
+            node_bound,
+            node_count,
+            edge_count,
+            node_indices,
+            edge_endpoints,
+            node_weights,
+            edge_weights,
+        };
+        // The node indices are checked without a buffer of `node_bound`
+        // entries, as the header is not trusted.
+        let mut previous = None;
+        for index in view.node_indices() {
+            if previous >= Some(index) {
+                return Err(SnapshotError::Invalid("node indices out of order"));
+            }
+            previous = Some(index);
+        }
+        if Some(node_bound) != previous.map_or(Some(0), |last: usize| last.checked_add(1)) {
+            return Err(SnapshotError::Invalid(
+                "node bound does not match the node indices",
+            ));
+        }
+        for (a, b) in view.edge_endpoints() {
+            if view.position(a).is_none() || view.position(b).is_none() {
+                return Err(SnapshotError::Invalid("edge to a missing node"));
+            }
+        }
+        Ok(view)
+    }
+
+    /// Return whether the graph of the snapshot is directed.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Return the upper bound of the node indices of the snapshot.
+    pub fn node_bound(&self) -> usize {
+        self.node_bound
+    }
+
+    /// Return the number of nodes of the snapshot.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Return the number of edges of the snapshot.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    fn index(&self, bytes: &[u8]) -> usize {
+        match self.width {
+            4 => u32::from_le_bytes(bytes.try_into().unwrap()) as usize,
+            _ => u64::from_le_bytes(bytes.try_into().unwrap()) as usize,
+        }
+    }
+
+    /// Return an iterator over the node indices, in increasing order.
+    pub fn node_indices(&self) -> impl Iterator<Item = usize> + 'a {
+        let view = *self;
+        self.node_indices
+            .chunks_exact(self.width)
+            .map(move |bytes| view.index(bytes))
+    }
+
+    /// Return an iterator over the source and target indices of the edges.
+    pub fn edge_endpoints(&self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let view = *self;
+        self.edge_endpoints
+            .chunks_exact(2 * self.width)
+            .map(move |bytes| {
+                let (a, b) = bytes.split_at(view.width);
+                (view.index(a), view.index(b))
+            })
+    }
+
+    /// Return an iterator decoding the node weights, in the order of the
+    /// node indices.
+    pub fn node_weights<N: Decode<'a>>(&self) -> Weights<'a, N> {
+        Weights {
+            input: self.node_weights,
+            remaining: self.node_count,
+            ty: PhantomData,
+        }
+    }
+
+    /// Return an iterator decoding the edge weights, in the order of the
+    /// edge endpoints.
+    pub fn edge_weights<E: Decode<'a>>(&self) -> Weights<'a, E> {
+        Weights {
+            input: self.edge_weights,
+            remaining: self.edge_count,
+            ty: PhantomData,
+        }
+    }
+
+    /// Return the position of the node `index` among the nodes of the
+    /// snapshot, if it is one of them.
+    fn position(&self, index: usize) -> Option<usize> {
+        if self.node_bound == self.node_count {
+            return if index < self.node_count {
+                Some(index)
+            } else {
+                None
+            };
+        }
+        let node = |i: usize| self.index(&self.node_indices[i * self.width..(i + 1) * self.width]);
+        let (mut low, mut high) = (0, self.node_count);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match node(mid).cmp(&index) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Some(mid),
+            }
+        }
+        None
+    }
+
+    fn check_edge_type<Ty: EdgeType>(&self) -> Result<(), SnapshotError> {
+        if self.directed != Ty::is_directed() {
+            return Err(SnapshotError::EdgeTypeMismatch);
+        }
+        Ok(())
+    }
+
+    fn check_index<Ix: IndexType>(&self) -> Result<(), SnapshotError> {
+        if self.node_bound > <Ix as IndexType>::max().index()
+            || self.edge_count > <Ix as IndexType>::max().index()
+        {
+            return Err(SnapshotError::IndexOverflow);
+        }
+        Ok(())
+    }
+}
+
+/// An iterator decoding the weights of a [`SnapshotView`].
+#[derive(Clone, Debug)]
+pub struct Weights<'a, T> {
+    input: &'a [u8],
+    remaining: usize,
+    ty: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: Decode<'a>> Iterator for Weights<'a, T> {
+    type Item = Result<T, SnapshotError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let item = T::decode(&mut self.input);
+        if item.is_err() {
+            self.remaining = 0;
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<N, E, Ty, Ix> Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Write the graph to a snapshot.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn to_snapshot(&self) -> Vec<u8>
+    where
+        N: Encode,
+        E: Encode,
+    {
+        let nodes: Vec<_> = self
+            .raw_nodes()
+            .iter()
+            .map(|node| &node.weight)
+            .enumerate()
+            .collect();
+        let edges: Vec<_> = self
+            .raw_edges()
+            .iter()
+            .map(|edge| (edge.source().index(), edge.target().index(), &edge.weight))
+            .collect();
+        write_snapshot(self.is_directed(), self.node_count(), &nodes, &edges)
+    }
+
+    /// Read a graph from a snapshot.
+    ///
+    /// The nodes and edges keep their order; the node indices of a snapshot
+    /// of a [`StableGraph`](crate::stable_graph::StableGraph) with vacant
+    /// nodes are compacted.
+    ///
+    /// Return an error if the snapshot is not valid, or of a graph of the
+    /// other edge type.
+    ///
+    /// Computes in **O(|V| + |E| log |V|)** time, and in **O(|V| + |E|)**
+    /// time for a snapshot without vacant nodes.
+    pub fn from_snapshot<'a>(bytes: &'a [u8]) -> Result<Self, SnapshotError>
+    where
+        N: Decode<'a>,
+        E: Decode<'a>,
+    {
+        let view = SnapshotView::new(bytes)?;
+        view.check_edge_type::<Ty>()?;
+        view.check_index::<Ix>()?;
+        let mut graph = Graph::with_capacity(view.node_count, view.edge_count);
+        for weight in view.node_weights() {
+            graph.add_node(weight?);
+        }
+        for ((a, b), weight) in view.edge_endpoints().zip(view.edge_weights()) {
+            graph.add_edge(
+                NodeIndex::new(view.position(a).unwrap()),
+                NodeIndex::new(view.position(b).unwrap()),
+                weight?,
+            );
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(feature = "stable_graph")]
+impl<N, E, Ty, Ix> StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Write the graph to a snapshot.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn to_snapshot(&self) -> Vec<u8>
+    where
+        N: Encode,
+        E: Encode,
+    {
+        let nodes: Vec<_> = self
+            .node_references()
+            .map(|(node, weight)| (node.index(), weight))
+            .collect();
+        let edges: Vec<_> = self
+            .edge_references()
+            .map(|edge| (edge.source().index(), edge.target().index(), edge.weight()))
+            .collect();
+        write_snapshot(self.is_directed(), self.node_bound(), &nodes, &edges)
+    }
+
+    /// Read a graph from a snapshot.
+    ///
+    /// The nodes keep their indices, including the vacant ones, and the
+    /// edges their order, but the edge indices are compacted.
+    ///
+    /// Return an error if the snapshot is not valid, or of a graph of the
+    /// other edge type. As the vacant nodes take memory but no space in the
+    /// snapshot, a snapshot whose node bound is more than a thousand times
+    /// its length in bytes is not valid either.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn from_snapshot<'a>(bytes: &'a [u8]) -> Result<Self, SnapshotError>
+    where
+        N: Decode<'a>,
+        E: Decode<'a>,
+    {
+        let view = SnapshotView::new(bytes)?;
+        view.check_edge_type::<Ty>()?;
+        view.check_index::<Ix>()?;
+        if !super::plausible_node_count(view.node_bound, bytes.len()) {
+            return Err(SnapshotError::Invalid(
+                "too many vacant nodes for the length of the snapshot",
+            ));
+        }
+        let mut graph = StableGraph::with_capacity(view.node_count, view.edge_count);
+        let mut slots = 0;
+        for (index, weight) in view.node_indices().zip(view.node_weights()) {
+            for _ in slots..index {
+                graph.push_node_slot(None);
+            }
+            graph.push_node_slot(Some(weight?));
+            slots = index + 1;
+        }
+        for ((a, b), weight) in view.edge_endpoints().zip(view.edge_weights()) {
+            graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), weight?);
+        }
+        Ok(graph)
+    }
+}
+
+impl<N, E, Ty, Ix> Csr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Write the graph to a snapshot.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn to_snapshot(&self) -> Vec<u8>
+    where
+        N: Encode,
+        E: Encode,
+    {
+        let nodes: Vec<_> = self
+            .node_references()
+            .map(|(node, weight)| (node.index(), weight))
+            .collect();
+        // An undirected `Csr` holds each edge in both directions.
+        let edges: Vec<_> = self
+            .edge_references()
+            .filter(|edge| self.is_directed() || edge.source() <= edge.target())
+            .map(|edge| (edge.source().index(), edge.target().index(), edge.weight()))
+            .collect();
+        write_snapshot(self.is_directed(), self.node_count(), &nodes, &edges)
+    }
+
+    /// Read a graph from a snapshot.
+    ///
+    /// The parallel edges of the snapshot are skipped, as in
+    /// [`add_edge`](Csr::add_edge).
+    ///
+    /// Return an error if the snapshot is not valid, or of a graph of the
+    /// other edge type.
+    ///
+    /// Computes in **O(|V| + |E| log |E|)** time, and in **O(|V| + |E|)**
+    /// time for the snapshot of a `Csr`.
+    pub fn from_snapshot<'a>(bytes: &'a [u8]) -> Result<Self, SnapshotError>
+    where
+        N: Decode<'a>,
+        E: Decode<'a> + Clone,
+    {
+        let view = SnapshotView::new(bytes)?;
+        view.check_edge_type::<Ty>()?;
+        view.check_index::<Ix>()?;
+        let node_weights = view.node_weights().collect::<Result<Vec<_>, _>>()?;
+        let mut entries = Vec::with_capacity(view.edge_count);
+        for ((a, b), weight) in view.edge_endpoints().zip(view.edge_weights::<E>()) {
+            let (a, b) = (view.position(a).unwrap(), view.position(b).unwrap());
+            let weight = weight?;
+            if !Ty::is_directed() && a != b {
+                entries.push((b, Ix::new(a), weight.clone()));
+            }
+            entries.push((a, Ix::new(b), weight));
+        }
+        // A stable sort keeps the first of the parallel edges.
+        if !entries
+            .windows(2)
+            .all(|pair| (pair[0].0, pair[0].1) <= (pair[1].0, pair[1].1))
+        {
+            entries.sort_by_key(|&(a, b, _)| (a, b));
+        }
+        entries.dedup_by_key(|&mut (a, b, _)| (a, b));
+        let edge_count = if Ty::is_directed() {
+            entries.len()
+        } else {
+            entries.iter().filter(|&&(a, b, _)| a <= b.index()).count()
+        };
+        Ok(Csr::from_sorted_entries(node_weights, entries, edge_count))
+    }
+}
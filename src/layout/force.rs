@@ -0,0 +1,536 @@
+//! Force-directed layouts.
+
+use alloc::{vec, vec::Vec};
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use super::{index_graph, Point};
+use crate::visit::{IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// How the temperature of [`fruchterman_reingold`] decreases.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Cooling {
+    /// Decrease linearly to zero over the iterations.
+    Linear,
+    /// Multiply by the given factor, between `0` and `1`, after every
+    /// iteration.
+    Exponential(f64),
+}
+
+/// The parameters of [`fruchterman_reingold`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FruchtermanReingoldConfig {
+    /// The number of iterations.
+    pub iterations: usize,
+    /// The side of the square the nodes are laid out in. It sets the ideal
+    /// edge length to `size / sqrt(|V|)`.
+    pub size: f64,
+    /// The initial temperature, the largest distance a node can move in one
+    /// iteration.
+    pub temperature: f64,
+    /// How the temperature decreases.
+    pub cooling: Cooling,
+    /// The Barnes–Hut opening angle, or `None` to compute the repulsive
+    /// forces exactly. Smaller values are more accurate; `1.0` is a common
+    /// choice.
+    pub theta: Option<f64>,
+}
+
+impl Default for FruchtermanReingoldConfig {
+    /// 100 iterations in a square of side 100, with linear cooling from a
+    /// temperature of 10, without Barnes–Hut approximation.
+    fn default() -> Self {
+        FruchtermanReingoldConfig {
+            iterations: 100,
+            size: 100.0,
+            temperature: 10.0,
+            cooling: Cooling::Linear,
+            theta: None,
+        }
+    }
+}
+
+/// The parameters of [`force_atlas2`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ForceAtlas2Config {
+    /// The number of iterations.
+    pub iterations: usize,
+    /// The strength of the repulsion. Larger values give a sparser layout.
+    pub scaling: f64,
+    /// The strength of the attraction towards the origin, which keeps the
+    /// connected components together.
+    pub gravity: f64,
+    /// Make gravity grow with the distance to the origin.
+    pub strong_gravity: bool,
+    /// Use an attraction that grows with the logarithm of the length of the
+    /// edges, which makes clusters tighter.
+    pub lin_log: bool,
+    /// The exponent applied to the edge weights: `0.0` ignores them.
+    pub edge_weight_influence: f64,
+    /// How much the nodes may oscillate. Larger values are faster but less
+    /// precise.
+    pub jitter_tolerance: f64,
+    /// The Barnes–Hut opening angle, or `None` to compute the repulsive
+    /// forces exactly. Smaller values are more accurate; `1.2` is a common
+    /// choice.
+    pub theta: Option<f64>,
+}
+
+impl Default for ForceAtlas2Config {
+    /// 100 iterations with the default parameters of the reference
+    /// implementation, without Barnes–Hut approximation.
+    fn default() -> Self {
+        ForceAtlas2Config {
+            iterations: 100,
+            scaling: 2.0,
+            gravity: 1.0,
+            strong_gravity: false,
+            lin_log: false,
+            edge_weight_influence: 1.0,
+            jitter_tolerance: 1.0,
+            theta: None,
+        }
+    }
+}
+
+/// \[Generic\] Compute a layout of the graph with the [Fruchterman–Reingold][1]
+/// algorithm.
+///
+/// Edges attract their endpoints with a force of **d² / k** and every pair
+/// of nodes repels with a force of **k² / d**, where **d** is the distance
+/// between the nodes and **k** the ideal edge length. Every iteration moves
+/// the nodes along the resulting forces, by at most the current temperature.
+///
+/// Edge directions and weights are ignored. Parallel edges attract more.
+///
+/// # Arguments
+/// * `graph`: the graph.
+/// * `config`: the parameters of the layout.
+///
+/// # Returns
+/// * [`struct@hashbrown::HashMap`] that associates to each `NodeId` its
+///   position.
+///
+/// # Complexity
+/// * Time complexity: **O(i (|V|² + |E|))**, or **O(i (|V| log |V| + |E|))**
+///   with the Barnes–Hut approximation.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **i** is the number of iterations, **|V|** is the number of nodes and
+/// **|E|** is the number of edges.
+///
+/// [1]: https://en.wikipedia.org/wiki/Force-directed_graph_drawing
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::layout::{fruchterman_reingold, FruchtermanReingoldConfig};
+///
+/// // A cycle of 8 nodes.
+/// let graph = UnGraph::<(), ()>::from_edges((0..8).map(|i| (i, (i + 1) % 8)));
+/// let positions = fruchterman_reingold(&graph, &FruchtermanReingoldConfig::default());
+///
+/// // Adjacent nodes are closer than opposite ones.
+/// let distance = |a, b| positions[&NodeIndex::new(a)].distance(positions[&NodeIndex::new(b)]);
+/// assert!(distance(0, 1) < distance(0, 4));
+/// ```
+pub fn fruchterman_reingold<G>(
+    graph: G,
+    config: &FruchtermanReingoldConfig,
+) -> HashMap<G::NodeId, Point>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let (nodes, edges) = index_graph(graph, |_| 1.0);
+    let n = nodes.len();
+    let mut positions = initial_positions(n, config.size / 2.0);
+    if n == 0 {
+        return HashMap::new();
+    }
+    let k = config.size / (n as f64).sqrt();
+    let masses = vec![1.0; n];
+    let mut temperature = config.temperature;
+    let mut forces = vec![Point::default(); n];
+
+    for iteration in 0..config.iterations {
+        repulsion(&positions, &masses, config.theta, &mut forces);
+        for force in &mut forces {
+            force.x *= k * k;
+            force.y *= k * k;
+        }
+        for &(a, b, _) in &edges {
+            let (dx, dy) = offset(positions[a], positions[b], a, b);
+            let d = dx.hypot(dy);
+            let f = d / k;
+            forces[a].x += dx * f;
+            forces[a].y += dy * f;
+            forces[b].x -= dx * f;
+            forces[b].y -= dy * f;
+        }
+        for (position, force) in positions.iter_mut().zip(&forces) {
+            let length = force.x.hypot(force.y);
+            if length > 0.0 {
+                let step = length.min(temperature) / length;
+                position.x += force.x * step;
+                position.y += force.y * step;
+            }
+        }
+        temperature = match config.cooling {
+            Cooling::Linear => {
+                config.temperature * (1.0 - (iteration + 1) as f64 / config.iterations as f64)
+            }
+            Cooling::Exponential(factor) => temperature * factor,
+        };
+    }
+    nodes.into_iter().zip(positions).collect()
+}
+
+/// \[Generic\] Compute a layout of the graph with the [ForceAtlas2][1]
+/// algorithm.
+///
+/// Every pair of nodes repels with a force proportional to the product of
+/// their degrees plus one, divided by their distance, so that hubs push each
+/// other away. Edges attract their endpoints proportionally to their length
+/// (or its logarithm with `lin_log`) and to their weight, and gravity pulls
+/// every node towards the origin. The speed of every node adapts to how much
+/// it oscillates.
+///
+/// Edge directions are ignored.
+///
+/// # Arguments
+/// * `graph`: the graph.
+/// * `config`: the parameters of the layout.
+/// * `edge_weight`: closure that returns the weight of an edge, which must be
+///   positive.
+///
+/// # Returns
+/// * [`struct@hashbrown::HashMap`] that associates to each `NodeId` its
+///   position.
+///
+/// # Complexity
+/// * Time complexity: **O(i (|V|² + |E|))**, or **O(i (|V| log |V| + |E|))**
+///   with the Barnes–Hut approximation.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **i** is the number of iterations, **|V|** is the number of nodes and
+/// **|E|** is the number of edges.
+///
+/// [1]: https://doi.org/10.1371/journal.pone.0098679
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::layout::{force_atlas2, ForceAtlas2Config};
+///
+/// // Two triangles joined by a single edge.
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)]);
+/// let config = ForceAtlas2Config {
+///     theta: Some(1.2),
+///     ..ForceAtlas2Config::default()
+/// };
+/// let positions = force_atlas2(&graph, &config, |_| 1.0);
+///
+/// let distance = |a, b| positions[&NodeIndex::new(a)].distance(positions[&NodeIndex::new(b)]);
+/// assert!(distance(0, 1) < distance(0, 5));
+/// ```
+pub fn force_atlas2<G, F>(
+    graph: G,
+    config: &ForceAtlas2Config,
+    edge_weight: F,
+) -> HashMap<G::NodeId, Point>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let (nodes, mut edges) = index_graph(graph, edge_weight);
+    let n = nodes.len();
+    let mut positions = initial_positions(n, (n as f64).sqrt() * 10.0);
+    if n == 0 {
+        return HashMap::new();
+    }
+    for edge in &mut edges {
+        edge.2 = edge.2.powf(config.edge_weight_influence);
+    }
+    let mut masses = vec![1.0; n];
+    for &(a, b, _) in &edges {
+        if a != b {
+            masses[a] += 1.0;
+            masses[b] += 1.0;
+        }
+    }
+
+    let mut forces = vec![Point::default(); n];
+    let mut previous = vec![Point::default(); n];
+    let mut speed = 1.0;
+    let mut speed_efficiency = 1.0;
+
+    for _ in 0..config.iterations {
+        core::mem::swap(&mut forces, &mut previous);
+        // Repulsion, proportional to the masses.
+        repulsion(&positions, &masses, config.theta, &mut forces);
+        for (force, &mass) in forces.iter_mut().zip(&masses) {
+            force.x *= config.scaling * mass;
+            force.y *= config.scaling * mass;
+        }
+        // Gravity.
+        for ((force, position), &mass) in forces.iter_mut().zip(&positions).zip(&masses) {
+            let d = position.x.hypot(position.y);
+            if d > 0.0 {
+                let f = if config.strong_gravity {
+                    config.gravity * mass
+                } else {
+                    config.gravity * mass / d
+                };
+                force.x -= position.x * f;
+                force.y -= position.y * f;
+            }
+        }
+        // Attraction.
+        for &(a, b, weight) in &edges {
+            if a == b {
+                continue;
+            }
+            let (dx, dy) = offset(positions[a], positions[b], a, b);
+            let f = if config.lin_log {
+                let d = dx.hypot(dy);
+                weight * (1.0 + d).ln() / d
+            } else {
+                weight
+            };
+            forces[a].x += dx * f;
+            forces[a].y += dy * f;
+            forces[b].x -= dx * f;
+            forces[b].y -= dy * f;
+        }
+
+        // Adapt the global speed to the oscillations of the nodes.
+        let mut swinging = 0.0;
+        let mut traction = 0.0;
+        for ((force, old), &mass) in forces.iter().zip(&previous).zip(&masses) {
+            swinging += mass * (force.x - old.x).hypot(force.y - old.y);
+            traction += mass * (force.x + old.x).hypot(force.y + old.y) / 2.0;
+        }
+        if traction == 0.0 {
+            continue;
+        }
+        let estimated_jitter = 0.05 * (n as f64).sqrt();
+        let mut jitter = config.jitter_tolerance
+            * estimated_jitter
+                .sqrt()
+                .max((estimated_jitter * traction / (n * n) as f64).min(10.0));
+        if swinging / traction > 2.0 {
+            if speed_efficiency > 0.05 {
+                speed_efficiency *= 0.5;
+            }
+            jitter = jitter.max(config.jitter_tolerance);
+        }
+        let target_speed = jitter * speed_efficiency * traction / swinging;
+        if swinging > jitter * traction {
+            if speed_efficiency > 0.05 {
+                speed_efficiency *= 0.7;
+            }
+        } else if speed < 1000.0 {
+            speed_efficiency *= 1.3;
+        }
+        speed += (target_speed - speed).min(0.5 * speed);
+
+        // Move the nodes.
+        for (((position, force), old), &mass) in positions
+            .iter_mut()
+            .zip(&forces)
+            .zip(&previous)
+            .zip(&masses)
+        {
+            let swinging = mass * (force.x - old.x).hypot(force.y - old.y);
+            let factor = speed / (1.0 + (speed * swinging).sqrt());
+            position.x += force.x * factor;
+            position.y += force.y * factor;
+        }
+    }
+    nodes.into_iter().zip(positions).collect()
+}
+
+/// Place `n` nodes on a spiral of the given radius, evenly spread.
+fn initial_positions(n: usize, radius: f64) -> Vec<Point> {
+    let golden_angle = core::f64::consts::PI * (3.0 - 5f64.sqrt());
+    (0..n)
+        .map(|i| {
+            let r = radius * ((i as f64 + 0.5) / n as f64).sqrt();
+            let angle = i as f64 * golden_angle;
+            Point::new(r * angle.cos(), r * angle.sin())
+        })
+        .collect()
+}
+
+/// Return the offset from `a` to `b`, or a small one that depends on the
+/// indices if they are at the same position.
+fn offset(a: Point, b: Point, i: usize, j: usize) -> (f64, f64) {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    if dx == 0.0 && dy == 0.0 {
+        let angle = (i * 31 + j * 17) as f64;
+        (1e-6 * angle.cos(), 1e-6 * angle.sin())
+    } else {
+        (dx, dy)
+    }
+}
+
+/// Set `forces[i]` to the sum over the other nodes `j` of
+/// `masses[j] * (positions[i] - positions[j]) / d²`, a repulsion of
+/// `masses[j] / d`, exactly or with a Barnes–Hut quadtree.
+fn repulsion(positions: &[Point], masses: &[f64], theta: Option<f64>, forces: &mut [Point]) {
+    match theta {
+        None => {
+            for force in forces.iter_mut() {
+                *force = Point::default();
+            }
+            for i in 0..positions.len() {
+                for j in i + 1..positions.len() {
+                    let (dx, dy) = offset(positions[j], positions[i], j, i);
+                    let d2 = dx * dx + dy * dy;
+                    forces[i].x += dx * masses[j] / d2;
+                    forces[i].y += dy * masses[j] / d2;
+                    forces[j].x -= dx * masses[i] / d2;
+                    forces[j].y -= dy * masses[i] / d2;
+                }
+            }
+        }
+        Some(theta) => {
+            let tree = QuadTree::new(positions, masses);
+            for (i, force) in forces.iter_mut().enumerate() {
+                *force = tree.repulsion(i, positions[i], theta);
+            }
+        }
+    }
+}
+
+const NONE: usize = usize::MAX;
+/// Cells below this depth are not split, so that nodes at the same position
+/// don't recurse forever.
+const MAX_DEPTH: usize = 48;
+
+/// A cell of a [`QuadTree`].
+struct Cell {
+    center: Point,
+    half: f64,
+    mass: f64,
+    /// The sum of the positions weighted by the masses.
+    moment: Point,
+    /// The first of four consecutive children, or `NONE` for a leaf.
+    children: usize,
+    /// The node in a leaf with a single node, or `NONE`.
+    node: usize,
+}
+
+/// A Barnes–Hut quadtree of masses.
+struct QuadTree {
+    cells: Vec<Cell>,
+}
+
+impl QuadTree {
+    fn new(positions: &[Point], masses: &[f64]) -> Self {
+        let (mut min, mut max) = (
+            Point::new(f64::INFINITY, f64::INFINITY),
+            Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY),
+        );
+        for p in positions {
+            min = Point::new(min.x.min(p.x), min.y.min(p.y));
+            max = Point::new(max.x.max(p.x), max.y.max(p.y));
+        }
+        let half = ((max.x - min.x).max(max.y - min.y) / 2.0).max(1e-9);
+        let center = Point::new((min.x + max.x) / 2.0, (min.y + max.y) / 2.0);
+        let mut tree = QuadTree {
+            cells: vec![Cell::new(center, half)],
+        };
+        for (i, (&p, &m)) in positions.iter().zip(masses).enumerate() {
+            tree.insert(i, p, m, positions);
+        }
+        tree
+    }
+
+    fn insert(&mut self, i: usize, p: Point, mass: f64, positions: &[Point]) {
+        let mut cell = 0;
+        let mut depth = 0;
+        loop {
+            let c = &mut self.cells[cell];
+            let empty = c.mass == 0.0 && c.node == NONE;
+            c.mass += mass;
+            c.moment.x += p.x * mass;
+            c.moment.y += p.y * mass;
+            if c.children == NONE {
+                if empty {
+                    c.node = i;
+                    return;
+                }
+                if depth >= MAX_DEPTH {
+                    // Keep the nodes together as a single mass.
+                    c.node = NONE;
+                    return;
+                }
+                // Split the leaf and move its node down.
+                let other = core::mem::replace(&mut c.node, NONE);
+                let (center, half) = (c.center, c.half / 2.0);
+                let first = self.cells.len();
+                self.cells[cell].children = first;
+                for q in 0..4 {
+                    let x = if q & 1 == 0 { -half } else { half };
+                    let y = if q & 2 == 0 { -half } else { half };
+                    self.cells
+                        .push(Cell::new(Point::new(center.x + x, center.y + y), half));
+                }
+                let o = positions[other];
+                let child = first + self.cells[cell].quadrant(o);
+                let other_mass = self.cells[cell].mass - mass;
+                let c = &mut self.cells[child];
+                c.mass = other_mass;
+                c.moment = Point::new(o.x * other_mass, o.y * other_mass);
+                c.node = other;
+            }
+            let c = &self.cells[cell];
+            cell = c.children + c.quadrant(p);
+            depth += 1;
+        }
+    }
+
+    fn repulsion(&self, i: usize, p: Point, theta: f64) -> Point {
+        let mut force = Point::default();
+        let mut stack = vec![0];
+        while let Some(cell) = stack.pop() {
+            let c = &self.cells[cell];
+            if c.mass == 0.0 || c.node == i {
+                continue;
+            }
+            let center = Point::new(c.moment.x / c.mass, c.moment.y / c.mass);
+            let (dx, dy) = (p.x - center.x, p.y - center.y);
+            let d2 = dx * dx + dy * dy;
+            if c.children == NONE || 4.0 * c.half * c.half < theta * theta * d2 {
+                if d2 > 0.0 {
+                    force.x += dx * c.mass / d2;
+                    force.y += dy * c.mass / d2;
+                }
+            } else {
+                stack.extend(c.children..c.children + 4);
+            }
+        }
+        force
+    }
+}
+
+impl Cell {
+    fn new(center: Point, half: f64) -> Self {
+        Cell {
+            center,
+            half,
+            mass: 0.0,
+            moment: Point::default(),
+            children: NONE,
+            node: NONE,
+        }
+    }
+
+    fn quadrant(&self, p: Point) -> usize {
+        usize::from(p.x >= self.center.x) | (usize::from(p.y >= self.center.y) << 1)
+    }
+}
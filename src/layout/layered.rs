@@ -0,0 +1,507 @@
+//! Layered (Sugiyama) layouts.
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::cmp::Ordering;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use super::{index_graph, Point};
+use crate::algo::greedy_feedback_arc_set;
+use crate::graph::{DiGraph, NodeIndex};
+use crate::visit::{IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// How [`layered_layout`] orders the nodes of a layer from the positions of
+/// their neighbors in the adjacent layer, to reduce edge crossings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CrossingHeuristic {
+    /// Order by the mean position of the neighbors.
+    Barycenter,
+    /// Order by the median position of the neighbors, which is less
+    /// sensitive to a few distant neighbors.
+    Median,
+}
+
+/// The parameters of [`layered_layout`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LayeredConfig {
+    /// The vertical distance between consecutive layers.
+    pub layer_spacing: f64,
+    /// The minimal horizontal distance between nodes of the same layer.
+    pub node_spacing: f64,
+    /// The heuristic used to reduce edge crossings.
+    pub crossing_heuristic: CrossingHeuristic,
+    /// The maximal number of passes over the layers, in both directions, to
+    /// reduce edge crossings.
+    pub sweeps: usize,
+}
+
+impl Default for LayeredConfig {
+    fn default() -> Self {
+        LayeredConfig {
+            layer_spacing: 50.0,
+            node_spacing: 50.0,
+            crossing_heuristic: CrossingHeuristic::Barycenter,
+            sweeps: 24,
+        }
+    }
+}
+
+/// An edge of a [`LayeredLayout`], with the points of its drawing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayeredEdge<N> {
+    /// The source of the edge in the graph.
+    pub source: N,
+    /// The target of the edge in the graph.
+    pub target: N,
+    /// Whether the edge was reversed to break a cycle, and so points upwards.
+    pub reversed: bool,
+    /// The points of the polyline from the source to the target: the
+    /// positions of the source, of the bends where the edge crosses a layer,
+    /// and of the target. A loop has the position of its node twice.
+    pub points: Vec<Point>,
+}
+
+/// A layered drawing of a graph, computed by [`layered_layout`].
+#[derive(Clone, Debug)]
+pub struct LayeredLayout<N> {
+    layers: Vec<Vec<N>>,
+    layer_of: HashMap<N, usize>,
+    positions: HashMap<N, Point>,
+    edges: Vec<LayeredEdge<N>>,
+    crossings: usize,
+}
+
+impl<N> LayeredLayout<N>
+where
+    N: Copy + Eq + Hash,
+{
+    /// Return the nodes of every layer, from the top, each from left to
+    /// right.
+    pub fn layers(&self) -> &[Vec<N>] {
+        &self.layers
+    }
+
+    /// Return the layer of `node`, counted from the top, or `None` if it is
+    /// not in the graph.
+    pub fn layer(&self, node: N) -> Option<usize> {
+        self.layer_of.get(&node).copied()
+    }
+
+    /// Return the position of `node`, or `None` if it is not in the graph.
+    pub fn position(&self, node: N) -> Option<Point> {
+        self.positions.get(&node).copied()
+    }
+
+    /// Return the positions of the nodes.
+    pub fn positions(&self) -> &HashMap<N, Point> {
+        &self.positions
+    }
+
+    /// Return the edges of the graph, in the order of
+    /// [`IntoEdgeReferences`], with the points of their drawing.
+    pub fn edges(&self) -> &[LayeredEdge<N>] {
+        &self.edges
+    }
+
+    /// Return the number of edge crossings in the drawing.
+    pub fn crossings(&self) -> usize {
+        self.crossings
+    }
+}
+
+/// \[Generic\] Compute a layered drawing of a directed graph, with the
+/// method of Sugiyama, Tagawa and Toda.
+///
+/// The nodes are placed on horizontal layers so that the edges point
+/// downwards, and edges that span several layers bend where they cross a
+/// layer. The layout runs four phases:
+///
+/// 1. Cycle removal: a small set of edges, found by
+///    [`greedy_feedback_arc_set`], is reversed to make the graph acyclic.
+/// 2. Layer assignment: every node is placed one layer below its lowest
+///    predecessor, and nodes without predecessors just above their highest
+///    successor.
+/// 3. Crossing minimization: the nodes of every layer are sorted by the
+///    barycenter or the median of the positions of their neighbors in the
+///    previous layer, sweeping down and up the layers, and the order with
+///    the fewest crossings is kept.
+/// 4. Coordinate assignment: nodes are moved horizontally as close as
+///    possible to the mean of their neighbors, in their order and at least
+///    `node_spacing` apart.
+///
+/// The edges of an undirected graph are taken from their source to their
+/// target. Loops are ignored, and the layout is deterministic.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `config`: the parameters of the layout.
+///
+/// # Returns
+/// * A [`LayeredLayout`] with the layers and positions of the nodes, and the
+///   polylines of the edges.
+///
+/// # Complexity
+/// * Time complexity: **O(s (|V| + |E|) L log(|V| + |E|))**.
+/// * Auxiliary space: **O((|V| + |E|) L)**.
+///
+/// where **|V|** is the number of nodes, **|E|** the number of edges,
+/// **L** the number of layers and **s** the number of sweeps.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::DiGraph;
+/// use petgraph::layout::{layered_layout, LayeredConfig};
+///
+/// // A dependency graph, with a cycle between `c` and `d`.
+/// let mut graph = DiGraph::<&str, ()>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// let d = graph.add_node("d");
+/// graph.extend_with_edges([(a, b), (a, c), (b, d), (c, d), (d, c)]);
+///
+/// let layout = layered_layout(&graph, &LayeredConfig::default());
+/// assert_eq!(layout.layer(a), Some(0));
+/// // One edge of the cycle points upwards.
+/// assert_eq!(layout.edges().iter().filter(|e| e.reversed).count(), 1);
+/// assert_eq!(layout.crossings(), 0);
+/// assert!(layout.position(a).unwrap().y < layout.position(d).unwrap().y);
+/// ```
+pub fn layered_layout<G>(graph: G, config: &LayeredConfig) -> LayeredLayout<G::NodeId>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let (nodes, edges) = index_graph(graph, |_| 0.0);
+    let n = nodes.len();
+
+    // Reverse a feedback arc set to make the graph acyclic.
+    let mut acyclic = DiGraph::<(), usize>::with_capacity(n, edges.len());
+    for _ in 0..n {
+        acyclic.add_node(());
+    }
+    for (i, &(a, b, _)) in edges.iter().enumerate() {
+        if a != b {
+            acyclic.add_edge(NodeIndex::new(a), NodeIndex::new(b), i);
+        }
+    }
+    let mut reversed = vec![false; edges.len()];
+    for edge in greedy_feedback_arc_set(&acyclic) {
+        reversed[*edge.weight()] = true;
+    }
+    let directed: Vec<(usize, usize)> = edges
+        .iter()
+        .zip(&reversed)
+        .map(|(&(a, b, _), &reversed)| if reversed { (b, a) } else { (a, b) })
+        .collect();
+
+    let layer_of = assign_layers(n, &directed);
+    let mut layering = Layering::new(&layer_of, &directed);
+    let crossings = layering.minimize_crossings(config);
+    let x = layering.assign_coordinates(config.node_spacing);
+
+    let point = |v: usize| Point::new(x[v], layering.layer[v] as f64 * config.layer_spacing);
+    let layered_edges = edges
+        .iter()
+        .enumerate()
+        .map(|(i, &(a, b, _))| {
+            let mut points = vec![point(a)];
+            points.extend(layering.chains[i].iter().map(|&v| point(v)));
+            points.push(point(b));
+            if reversed[i] {
+                // The chain goes from the target to the source.
+                let inner = points.len() - 1;
+                points[1..inner].reverse();
+            }
+            LayeredEdge {
+                source: nodes[a],
+                target: nodes[b],
+                reversed: reversed[i],
+                points,
+            }
+        })
+        .collect();
+    LayeredLayout {
+        layers: layering
+            .order
+            .iter()
+            .map(|layer| {
+                layer
+                    .iter()
+                    .filter(|&&v| v < n)
+                    .map(|&v| nodes[v])
+                    .collect()
+            })
+            .collect(),
+        layer_of: (0..n).map(|v| (nodes[v], layer_of[v])).collect(),
+        positions: (0..n).map(|v| (nodes[v], point(v))).collect(),
+        edges: layered_edges,
+        crossings,
+    }
+}
+
+/// Return the layer of every node of an acyclic graph: one below its lowest
+/// predecessor, or just above its highest successor if it has no
+/// predecessors.
+fn assign_layers(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut successors = vec![Vec::new(); n];
+    let mut in_degree = vec![0; n];
+    for &(a, b) in edges {
+        if a != b {
+            successors[a].push(b);
+            in_degree[b] += 1;
+        }
+    }
+    let sources: Vec<_> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+    let mut queue: VecDeque<_> = sources.iter().copied().collect();
+    let mut layer = vec![0; n];
+    while let Some(v) = queue.pop_front() {
+        for &w in &successors[v] {
+            layer[w] = layer[w].max(layer[v] + 1);
+            in_degree[w] -= 1;
+            if in_degree[w] == 0 {
+                queue.push_back(w);
+            }
+        }
+    }
+    for v in sources {
+        if let Some(highest) = successors[v].iter().map(|&w| layer[w]).min() {
+            layer[v] = highest - 1;
+        }
+    }
+    layer
+}
+
+/// The nodes of a layered graph, with dummy nodes where edges cross layers,
+/// so that every edge joins consecutive layers.
+struct Layering {
+    /// The layer of every node, real nodes first, then dummy nodes.
+    layer: Vec<usize>,
+    /// The neighbors of every node in the layer above and below.
+    up: Vec<Vec<usize>>,
+    down: Vec<Vec<usize>>,
+    /// The dummy nodes of every edge, from top to bottom.
+    chains: Vec<Vec<usize>>,
+    /// The nodes of every layer, from left to right.
+    order: Vec<Vec<usize>>,
+    /// The position of every node in its layer.
+    position: Vec<usize>,
+}
+
+impl Layering {
+    fn new(layer_of: &[usize], edges: &[(usize, usize)]) -> Self {
+        let n = layer_of.len();
+        let mut layer = layer_of.to_vec();
+        let mut up = vec![Vec::new(); n];
+        let mut down = vec![Vec::new(); n];
+        let mut chains = Vec::with_capacity(edges.len());
+        for &(a, b) in edges {
+            let mut chain = Vec::new();
+            if a != b {
+                let mut prev = a;
+                for l in layer[a] + 1..layer[b] {
+                    let dummy = layer.len();
+                    layer.push(l);
+                    up.push(vec![prev]);
+                    down.push(Vec::new());
+                    down[prev].push(dummy);
+                    chain.push(dummy);
+                    prev = dummy;
+                }
+                down[prev].push(b);
+                up[b].push(prev);
+            }
+            chains.push(chain);
+        }
+        let layers = layer.iter().map(|&l| l + 1).max().unwrap_or(0);
+        let mut order = vec![Vec::new(); layers];
+        let mut position = vec![0; layer.len()];
+        for (v, &l) in layer.iter().enumerate() {
+            position[v] = order[l].len();
+            order[l].push(v);
+        }
+        Layering {
+            layer,
+            up,
+            down,
+            chains,
+            order,
+            position,
+        }
+    }
+
+    /// Reorder the layers to reduce the number of crossings, and return it.
+    fn minimize_crossings(&mut self, config: &LayeredConfig) -> usize {
+        let mut best = self.order.clone();
+        let mut best_crossings = self.crossings();
+        for _ in 0..config.sweeps {
+            if best_crossings == 0 {
+                break;
+            }
+            for l in 1..self.order.len() {
+                self.sort_layer(l, true, config.crossing_heuristic);
+            }
+            for l in (0..self.order.len().saturating_sub(1)).rev() {
+                self.sort_layer(l, false, config.crossing_heuristic);
+            }
+            let crossings = self.crossings();
+            if crossings < best_crossings {
+                best_crossings = crossings;
+                best.clone_from(&self.order);
+            }
+        }
+        self.order = best;
+        self.update_positions();
+        best_crossings
+    }
+
+    /// Sort the nodes of layer `l` by the positions of their neighbors in the
+    /// layer above, or below. Nodes without such neighbors keep their
+    /// position.
+    fn sort_layer(&mut self, l: usize, from_above: bool, heuristic: CrossingHeuristic) {
+        let neighbors = if from_above { &self.up } else { &self.down };
+        let mut keyed: Vec<(f64, usize)> = self.order[l]
+            .iter()
+            .map(|&v| {
+                let mut around: Vec<_> = neighbors[v].iter().map(|&w| self.position[w]).collect();
+                let key = if around.is_empty() {
+                    self.position[v] as f64
+                } else {
+                    match heuristic {
+                        CrossingHeuristic::Barycenter => {
+                            around.iter().sum::<usize>() as f64 / around.len() as f64
+                        }
+                        CrossingHeuristic::Median => {
+                            around.sort_unstable();
+                            let mid = around.len() / 2;
+                            if around.len() % 2 == 1 {
+                                around[mid] as f64
+                            } else {
+                                (around[mid - 1] + around[mid]) as f64 / 2.0
+                            }
+                        }
+                    }
+                };
+                (key, v)
+            })
+            .collect();
+        keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        for (i, &(_, v)) in keyed.iter().enumerate() {
+            self.order[l][i] = v;
+            self.position[v] = i;
+        }
+    }
+
+    fn update_positions(&mut self) {
+        for layer in &self.order {
+            for (i, &v) in layer.iter().enumerate() {
+                self.position[v] = i;
+            }
+        }
+    }
+
+    /// Count the crossings between all pairs of consecutive layers, as the
+    /// inversions of the positions of the edge targets when the edges are
+    /// sorted by source.
+    fn crossings(&self) -> usize {
+        let mut total = 0;
+        for l in 1..self.order.len() {
+            let mut edges: Vec<(usize, usize)> = self.order[l - 1]
+                .iter()
+                .flat_map(|&v| self.down[v].iter().map(move |&w| (v, w)))
+                .map(|(v, w)| (self.position[v], self.position[w]))
+                .collect();
+            edges.sort_unstable();
+            // A Fenwick tree of the counts of the targets seen so far.
+            let width = self.order[l].len();
+            let mut tree = vec![0; width + 1];
+            for (seen, &(_, target)) in edges.iter().enumerate() {
+                let mut at_most = 0;
+                let mut i = target + 1;
+                while i > 0 {
+                    at_most += tree[i];
+                    i &= i - 1;
+                }
+                total += seen - at_most;
+                let mut i = target + 1;
+                while i <= width {
+                    tree[i] += 1;
+                    i += i & i.wrapping_neg();
+                }
+            }
+        }
+        total
+    }
+
+    /// Return the horizontal coordinate of every node.
+    ///
+    /// Starting from evenly spaced layers, every layer is placed in turn as
+    /// close as possible to the mean coordinates of the neighbors of its
+    /// nodes, in the least squares sense, keeping them in order and at least
+    /// `spacing` apart.
+    fn assign_coordinates(&self, spacing: f64) -> Vec<f64> {
+        let mut x = vec![0.0; self.layer.len()];
+        for layer in &self.order {
+            let offset = (layer.len() as f64 - 1.0) * spacing / 2.0;
+            for (i, &v) in layer.iter().enumerate() {
+                x[v] = i as f64 * spacing - offset;
+            }
+        }
+        let layers = self.order.len();
+        for pass in 0..8 {
+            let (neighbors, sweep): (_, Vec<usize>) = if pass % 2 == 0 {
+                (&self.up, (1..layers).collect())
+            } else {
+                (&self.down, (0..layers.saturating_sub(1)).rev().collect())
+            };
+            for l in sweep {
+                let desired: Vec<f64> = self.order[l]
+                    .iter()
+                    .map(|&v| {
+                        let around = &neighbors[v];
+                        if around.is_empty() {
+                            x[v]
+                        } else {
+                            around.iter().map(|&w| x[w]).sum::<f64>() / around.len() as f64
+                        }
+                    })
+                    .collect();
+                for (&v, placed) in self.order[l].iter().zip(place(&desired, spacing)) {
+                    x[v] = placed;
+                }
+            }
+        }
+        x
+    }
+}
+
+/// Return the coordinates `x` that minimize the sum of `(x[i] - desired[i])²`
+/// with `x[i + 1] - x[i] >= spacing`.
+///
+/// This is an isotonic regression of `desired[i] - i * spacing`, solved by
+/// pooling adjacent violators.
+fn place(desired: &[f64], spacing: f64) -> Vec<f64> {
+    // Blocks of consecutive values, with their sum and length.
+    let mut blocks: Vec<(f64, usize)> = Vec::new();
+    for (i, &d) in desired.iter().enumerate() {
+        blocks.push((d - i as f64 * spacing, 1));
+        while blocks.len() > 1 {
+            let (sum, len) = blocks[blocks.len() - 1];
+            let (prev_sum, prev_len) = blocks[blocks.len() - 2];
+            if prev_sum / prev_len as f64 <= sum / len as f64 {
+                break;
+            }
+            blocks.pop();
+            *blocks.last_mut().unwrap() = (prev_sum + sum, prev_len + len);
+        }
+    }
+    let mut x = Vec::with_capacity(desired.len());
+    for (sum, len) in blocks {
+        let mean = sum / len as f64;
+        for _ in 0..len {
+            x.push(mean + x.len() as f64 * spacing);
+        }
+    }
+    x
+}
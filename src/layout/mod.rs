@@ -0,0 +1,87 @@
+//! Graph layouts, which compute coordinates in the plane for the nodes of a
+//! graph, for drawing it.
+//!
+//! # Force-directed layouts
+//!
+//! A force-directed layout places the nodes of a graph in the plane by
+//! simulating attractive forces along the edges and repulsive forces between
+//! all nodes, so that adjacent nodes end up close to each other and the
+//! others spread out. Two algorithms are provided:
+//!
+//! * [`fruchterman_reingold`], the classic spring embedder, with a
+//!   temperature that limits the displacements and decreases over time.
+//! * [`force_atlas2`], which repels nodes of high degree more strongly and
+//!   adapts the speed of every node, and is better at showing the structure
+//!   of large, clustered graphs.
+//!
+//! Both return the coordinates of every node, and take their parameters from
+//! a configuration struct with sensible defaults. The repulsion between all
+//! pairs of nodes is computed exactly, or approximated with a Barnes–Hut
+//! quadtree in **O(|V| log |V|)** time per iteration when `theta` is set.
+//!
+//! The initial positions are deterministic: nodes are placed on a spiral in
+//! the order of [`IntoNodeIdentifiers`], so the same graph always gets the
+//! same layout.
+//!
+//! # Layered layouts
+//!
+//! [`layered_layout`] draws a directed graph with its edges pointing
+//! downwards as much as possible, as is usual for dependency graphs and
+//! other hierarchies, with the method of Sugiyama, Tagawa and Toda.
+
+mod force;
+mod layered;
+
+pub use force::{
+    force_atlas2, fruchterman_reingold, Cooling, ForceAtlas2Config, FruchtermanReingoldConfig,
+};
+pub use layered::{layered_layout, CrossingHeuristic, LayeredConfig, LayeredEdge, LayeredLayout};
+
+use alloc::{vec, vec::Vec};
+
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// A position in the plane.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    /// Create a new point.
+    pub fn new(x: f64, y: f64) -> Self {
+        Point { x, y }
+    }
+
+    /// Return the euclidean distance to `other`.
+    pub fn distance(&self, other: Point) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+/// An edge between the nodes at two positions, with its weight.
+type WeightedEdge = (usize, usize, f64);
+
+/// Number the nodes and return them with the edges as pairs of numbers and a
+/// weight.
+fn index_graph<G, F>(graph: G, mut edge_weight: F) -> (Vec<G::NodeId>, Vec<WeightedEdge>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let nodes: Vec<_> = graph.node_identifiers().collect();
+    let mut position = vec![usize::MAX; graph.node_bound()];
+    for (i, &node) in nodes.iter().enumerate() {
+        position[graph.to_index(node)] = i;
+    }
+    let edges = graph
+        .edge_references()
+        .map(|edge| {
+            let a = position[graph.to_index(edge.source())];
+            let b = position[graph.to_index(edge.target())];
+            (a, b, edge_weight(edge))
+        })
+        .collect();
+    (nodes, edges)
+}
@@ -124,11 +124,25 @@ Graph { Ty: "Undirected", node_count: 4, edge_count: 4, edges: (0, 1), (1, 2), (
   An adjacency list graph with arbitrary associated data.
 * [`StableGraph`](./stable_graph/struct.StableGraph.html) -
   Similar to `Graph`, but it keeps indices stable across removals.
+* [`FrozenCsrGraph`](./graph/struct.FrozenCsrGraph.html) -
+  An immutable `Graph`, packed for fast reads.
+* [`ConcurrentGraph`](./graph/struct.ConcurrentGraph.html) -
+  A growable graph that many threads can build at once, converted into a `Graph` when done.
+* [`BipartiteGraph`](./bipartite/struct.BipartiteGraph.html) -
+  An undirected `Graph` whose nodes are on two sides, with edges only between the sides.
+* [`TemporalGraph`](./temporal/struct.TemporalGraph.html) -
+  A `Graph` whose edges are active during a time interval, with snapshots of time windows.
+* [`PropertyGraph`](./property/type.PropertyGraph.html) -
+  A `Graph` whose nodes and edges carry labels and properties, like the records of a graph database.
 * [`GraphMap`](./graphmap/struct.GraphMap.html) -
   An adjacency list graph backed by a hash table. The node identifiers are the keys
   into the table.
+* [`MultiGraphMap`](./multigraphmap/struct.MultiGraphMap.html) -
+  Similar to `GraphMap`, but it allows parallel edges, identified by stable keys.
 * [`MatrixGraph`](./matrix_graph/struct.MatrixGraph.html) -
   An adjacency matrix graph.
+* [`BitMatrixGraph`](./matrix_graph/struct.BitMatrixGraph.html) -
+  An unweighted adjacency matrix graph, with one bit per pair of nodes.
 * [`CSR`](./csr/struct.Csr.html) -
   A sparse adjacency matrix graph with arbitrary associated data.
 
@@ -439,11 +453,13 @@ println!("Enhanced DOT format:\n{:?}", fancy_dot);
 `petgraph` is built with these features enabled by default:
 
 * **graphmap** -
-  Enables [`GraphMap`](./graphmap/struct.GraphMap.html).
+  Enables [`GraphMap`](./graphmap/struct.GraphMap.html) and
+  [`MultiGraphMap`](./multigraphmap/struct.MultiGraphMap.html).
 * **stable_graph** -
   Enables [`StableGraph`](./stable_graph/struct.StableGraph.html).
 * **matrix_graph** -
-  Enables [`MatrixGraph`](./matrix_graph/struct.MatrixGraph.html).
+  Enables [`MatrixGraph`](./matrix_graph/struct.MatrixGraph.html) and
+  [`BitMatrixGraph`](./matrix_graph/struct.BitMatrixGraph.html).
 * **std** -
   Enables the Rust Standard Library. Disabling the `std` feature makes it possible to use `petgraph` in `no_std` contexts.
 
@@ -452,12 +468,41 @@ Optionally, the following features can be enabled:
 * **serde-1** -
   Enables serialization for ``Graph, StableGraph, GraphMap`` using
   [`serde 1.0`](https://crates.io/crates/serde). May require a more recent version
-  of Rust than petgraph alone.
+  of Rust than petgraph alone. Also enables the [`io::json`](./io/json/index.html)
+  module: the networkx node-link and Cytoscape.js JSON schemas.
 * **rayon** -
   Enables parallel versions of iterators and algorithms using
   [`rayon`](https://docs.rs/rayon/latest/rayon/) crate. Requires the `std` feature.
 * **dot_parser** -
   Enables building [`Graph`](./graph/struct.Graph.html) and [`StableGraph`](./stable_graph/struct.StableGraph.html) from [DOT/Graphviz](https://www.graphviz.org/doc/info/lang.html) descriptions. Imports can be made statically or dynamically (i.e. at compile time or at runtime).
+* **graphml** -
+  Enables the [`graphml`](./graphml/index.html) module: export of any graph to
+  [GraphML](http://graphml.graphdrawing.org/), with typed node and edge attributes,
+  and import of GraphML documents into `Graph` and `StableGraph`.
+* **csv** -
+  Enables the [`io::csv`](./io/csv/index.html) module: import and export of graphs as CSV tables of
+  node and edge records, with serde. Implies **std** and **serde-1**.
+* **ndarray** -
+  Enables the conversion of graphs to and from dense adjacency matrices as
+  [`ndarray`](https://docs.rs/ndarray/0.16/ndarray/) arrays, in the
+  [`io::adjacency_matrix`](./io/adjacency_matrix/index.html) module.
+* **rand** -
+  Enables [`SeededRng`](./struct.SeededRng.html), a portable seeded random number
+  generator for the [`rand`](https://docs.rs/rand/0.8/rand/) crate, and the random
+  graph models of [`generators`](./generators/index.html). Randomized
+  algorithms always take their random number generator as an argument.
+* **linalg** -
+  Enables the [`spectral`](./algo/spectral/index.html) module: Laplacian matrices,
+  the Fiedler vector and spectral bisection. Requires the `std` feature.
+* **tracing** -
+  Instruments the main algorithms (Dijkstra, VF2 isomorphism, maximum flow and
+  coloring) with [`tracing`](https://docs.rs/tracing/0.1/tracing/) spans, and
+  `DEBUG` events that report their work: nodes expanded, states explored and
+  pruned, augmenting paths. No subscriber is installed by petgraph.
+* **mmap** -
+  Enables the [`mmap`](./mmap/index.html) module: graphs in compressed sparse row form
+  in memory-mapped files, with [`memmap2`](https://docs.rs/memmap2/0.9/memmap2/).
+  Implies **std**.
 * **unstable** -
   Enables unstable crate features (currently only `generate`).
 * **generate** -
@@ -492,6 +537,8 @@ pub use crate::Direction::{Incoming, Outgoing};
 #[macro_use]
 mod macros;
 mod scored;
+#[macro_use]
+mod trace;
 
 // these modules define trait-implementing macros
 #[macro_use]
@@ -502,36 +549,66 @@ pub mod data;
 pub mod acyclic;
 pub mod adj;
 pub mod algo;
+pub mod bipartite;
+pub mod cache;
+pub mod const_graph;
 pub mod csr;
+pub mod decay;
+pub mod diff;
 pub mod dot;
 #[cfg(feature = "generate")]
 pub mod generate;
+pub mod generators;
 pub mod graph6;
 mod graph_impl;
 #[cfg(feature = "graphmap")]
 pub mod graphmap;
+#[cfg(feature = "graphml")]
+pub mod graphml;
+pub mod interned;
+pub mod io;
 mod iter_format;
 mod iter_utils;
+#[cfg(feature = "std")]
+pub mod layout;
+pub mod link_cut;
 #[cfg(feature = "matrix_graph")]
 pub mod matrix_graph;
+pub mod minor;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(feature = "graphmap")]
+pub mod multigraphmap;
+pub mod partition_refinement;
+pub mod property;
 #[cfg(feature = "quickcheck")]
 mod quickcheck;
+pub mod repair;
 #[cfg(feature = "serde-1")]
 mod serde_utils;
+pub mod temporal;
 mod traits_graph;
 pub mod unionfind;
 mod util;
+mod xml;
 
 pub mod operator;
 pub mod prelude;
+#[cfg(feature = "rand")]
+pub mod rng;
+#[cfg(feature = "rand")]
+pub use crate::rng::SeededRng;
 
 /// `Graph<N, E, Ty, Ix>` is a graph datastructure using an adjacency list representation.
 pub mod graph {
+    #[cfg(feature = "std")]
+    pub use crate::graph_impl::ConcurrentGraph;
     pub use crate::graph_impl::{
         edge_index, node_index, DefaultIx, DiGraph, Edge, EdgeIndex, EdgeIndices, EdgeReference,
-        EdgeReferences, EdgeWeightsMut, Edges, EdgesConnecting, Externals, Frozen, Graph,
-        GraphError, GraphIndex, IndexType, Neighbors, Node, NodeIndex, NodeIndices, NodeReferences,
-        NodeWeightsMut, UnGraph, WalkNeighbors,
+        EdgeReferences, EdgeWeightsMut, Edges, EdgesConnecting, Externals, FlatArrays, Frozen,
+        FrozenCsrGraph, FrozenEdgeReferences, FrozenEdges, FrozenNeighbors, FrozenNodeReferences,
+        Graph, GraphBuilder, GraphError, GraphIndex, IndexType, Neighbors, Node, NodeIndex,
+        NodeIndices, NodeReferences, NodeWeightsMut, UnGraph, WalkNeighbors,
     };
 }
 
@@ -0,0 +1,381 @@
+//! `LinkCutTree<K, W>` is a dynamic forest data structure.
+
+use alloc::{vec, vec::Vec};
+use core::ops::Add;
+
+use crate::graph::IndexType;
+
+const NIL: usize = usize::MAX;
+
+/// `LinkCutTree<K, W>` is a forest of *n* nodes indexed from *0* to *n - 1*,
+/// with the scalar type `K`, whose trees are joined and split by adding and
+/// removing edges, with the [link-cut trees][1] of Sleator and Tarjan.
+///
+/// Each node has a value of type `W`, and the sum, the minimum and the
+/// maximum of the values of the nodes on the path between two nodes of a tree
+/// can be queried. The value of an edge can be given to a node of its own,
+/// between its two ends, as the dynamic minimum spanning tree and flow
+/// algorithms do.
+///
+/// The trees are rooted: [`find_root`][Self::find_root] and
+/// [`lca`][Self::lca] depend on the root, set with
+/// [`make_root`][Self::make_root]. [`link`][Self::link],
+/// [`cut`][Self::cut] and the path queries may change the roots of the trees
+/// they use.
+///
+/// Every operation takes **O(log n)** amortized time, and the queries also
+/// take `&mut self`, since they restructure the forest.
+///
+/// [1]: https://en.wikipedia.org/wiki/Link/cut_tree
+///
+/// # Example
+/// ```rust
+/// use petgraph::link_cut::LinkCutTree;
+///
+/// let mut forest = LinkCutTree::<u32, i32>::from_values(vec![4, 2, 7, 1, 5]);
+/// forest.link(0, 1);
+/// forest.link(1, 2);
+/// forest.link(1, 3);
+/// assert!(forest.connected(0, 3));
+/// assert!(!forest.connected(0, 4));
+///
+/// // The path 2 - 1 - 3.
+/// assert_eq!(forest.path_sum(2, 3), Some(10));
+/// assert_eq!(forest.path_min(2, 3), Some(1));
+/// assert_eq!(forest.path_max(2, 3), Some(7));
+///
+/// forest.cut(1, 3);
+/// forest.link(3, 4);
+/// assert_eq!(forest.path_sum(3, 4), Some(6));
+/// assert_eq!(forest.path_sum(0, 4), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinkCutTree<K, W> {
+    // The nodes form splay trees, each of a path of the forest ordered from
+    // the top down. The parent of the root of a splay tree is the parent in
+    // the forest of the top of its path.
+    children: Vec<[usize; 2]>,
+    parent: Vec<usize>,
+    // Whether the children of the subtree are to be swapped, to reverse the
+    // path when a tree is rerooted.
+    reversed: Vec<bool>,
+    value: Vec<W>,
+    // The aggregates of the values of the subtree of the splay tree.
+    sum: Vec<W>,
+    min: Vec<W>,
+    max: Vec<W>,
+    ty: core::marker::PhantomData<K>,
+}
+
+impl<K, W> Default for LinkCutTree<K, W> {
+    fn default() -> Self {
+        LinkCutTree {
+            children: Vec::new(),
+            parent: Vec::new(),
+            reversed: Vec::new(),
+            value: Vec::new(),
+            sum: Vec::new(),
+            min: Vec::new(),
+            max: Vec::new(),
+            ty: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<K, W> LinkCutTree<K, W>
+where
+    K: IndexType,
+    W: Copy + PartialOrd + Add<Output = W>,
+{
+    /// Create a new `LinkCutTree` of `n` nodes without edges, whose values
+    /// are `W::default()`.
+    pub fn new(n: usize) -> Self
+    where
+        W: Default,
+    {
+        Self::from_values(vec![W::default(); n])
+    }
+
+    /// Create a new `LinkCutTree` of nodes without edges, with these values.
+    pub fn from_values<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = W>,
+    {
+        let mut forest = Self::default();
+        for value in values {
+            forest.add_node(value);
+        }
+        forest
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns true if there are no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Add a new node, without edges, and return its index.
+    pub fn add_node(&mut self, value: W) -> K {
+        let retval = K::new(self.parent.len());
+        self.children.push([NIL, NIL]);
+        self.parent.push(NIL);
+        self.reversed.push(false);
+        self.value.push(value);
+        self.sum.push(value);
+        self.min.push(value);
+        self.max.push(value);
+        retval
+    }
+
+    /// Return the value of `a`.
+    ///
+    /// **Panics** if `a` is out of bounds.
+    #[track_caller]
+    pub fn value(&self, a: K) -> W {
+        self.value[a.index()]
+    }
+
+    /// Set the value of `a` to `value`, and return its previous value.
+    ///
+    /// **Panics** if `a` is out of bounds.
+    #[track_caller]
+    pub fn set_value(&mut self, a: K, value: W) -> W {
+        let a = a.index();
+        self.splay(a);
+        let old = core::mem::replace(&mut self.value[a], value);
+        self.update(a);
+        old
+    }
+
+    /// Make `a` the root of its tree.
+    ///
+    /// **Panics** if `a` is out of bounds.
+    #[track_caller]
+    pub fn make_root(&mut self, a: K) {
+        let a = a.index();
+        self.access(a);
+        self.reversed[a] ^= true;
+    }
+
+    /// Return the root of the tree of `a`.
+    ///
+    /// **Panics** if `a` is out of bounds.
+    #[track_caller]
+    pub fn find_root(&mut self, a: K) -> K {
+        let mut x = a.index();
+        self.access(x);
+        loop {
+            self.push(x);
+            match self.children[x][0] {
+                NIL => break,
+                left => x = left,
+            }
+        }
+        self.splay(x);
+        K::new(x)
+    }
+
+    /// Return `true` if `a` and `b` are in the same tree.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn connected(&mut self, a: K, b: K) -> bool {
+        a == b || self.find_root(a) == self.find_root(b)
+    }
+
+    /// Return the lowest common ancestor of `a` and `b`, with the root of
+    /// their tree, or `None` if they are not in the same tree.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn lca(&mut self, a: K, b: K) -> Option<K> {
+        if !self.connected(a, b) {
+            return None;
+        }
+        self.access(a.index());
+        Some(K::new(self.access(b.index())))
+    }
+
+    /// Add an edge between `a` and `b`, joining their trees, and make `a` a
+    /// child of `b`.
+    ///
+    /// Return `false` if they are already in the same tree, in which case
+    /// nothing changes.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn link(&mut self, a: K, b: K) -> bool {
+        if self.connected(a, b) {
+            return false;
+        }
+        self.make_root(a);
+        self.parent[a.index()] = b.index();
+        true
+    }
+
+    /// Remove the edge between `a` and `b`, splitting their tree.
+    ///
+    /// Return `false` if there is no such edge.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn cut(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (a.index(), b.index());
+        if a == b || !self.connected(K::new(a), K::new(b)) {
+            return false;
+        }
+        // With `a` as the root, `b` is its child if the path between them is
+        // only the two of them.
+        self.make_root(K::new(a));
+        self.access(b);
+        self.push(a);
+        if self.children[b][0] != a || self.children[a][1] != NIL {
+            return false;
+        }
+        self.children[b][0] = NIL;
+        self.parent[a] = NIL;
+        self.update(b);
+        true
+    }
+
+    /// Return the sum of the values of the nodes on the path between `a` and
+    /// `b`, both included, or `None` if they are not in the same tree.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn path_sum(&mut self, a: K, b: K) -> Option<W> {
+        self.expose_path(a, b).map(|x| self.sum[x])
+    }
+
+    /// Return the minimum of the values of the nodes on the path between `a`
+    /// and `b`, both included, or `None` if they are not in the same tree.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn path_min(&mut self, a: K, b: K) -> Option<W> {
+        self.expose_path(a, b).map(|x| self.min[x])
+    }
+
+    /// Return the maximum of the values of the nodes on the path between `a`
+    /// and `b`, both included, or `None` if they are not in the same tree.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn path_max(&mut self, a: K, b: K) -> Option<W> {
+        self.expose_path(a, b).map(|x| self.max[x])
+    }
+
+    /// Make the path between `a` and `b` a splay tree, and return its root.
+    fn expose_path(&mut self, a: K, b: K) -> Option<usize> {
+        if !self.connected(a, b) {
+            return None;
+        }
+        self.make_root(a);
+        self.access(b.index());
+        Some(b.index())
+    }
+
+    fn is_splay_root(&self, x: usize) -> bool {
+        let p = self.parent[x];
+        p == NIL || (self.children[p][0] != x && self.children[p][1] != x)
+    }
+
+    fn push(&mut self, x: usize) {
+        if self.reversed[x] {
+            self.reversed[x] = false;
+            self.children[x].swap(0, 1);
+            for c in self.children[x] {
+                if c != NIL {
+                    self.reversed[c] ^= true;
+                }
+            }
+        }
+    }
+
+    fn update(&mut self, x: usize) {
+        let (mut sum, mut min, mut max) = (self.value[x], self.value[x], self.value[x]);
+        let [left, right] = self.children[x];
+        if left != NIL {
+            sum = self.sum[left] + sum;
+        }
+        if right != NIL {
+            sum = sum + self.sum[right];
+        }
+        for c in [left, right] {
+            if c != NIL {
+                if self.min[c] < min {
+                    min = self.min[c];
+                }
+                if self.max[c] > max {
+                    max = self.max[c];
+                }
+            }
+        }
+        self.sum[x] = sum;
+        self.min[x] = min;
+        self.max[x] = max;
+    }
+
+    fn rotate(&mut self, x: usize) {
+        let p = self.parent[x];
+        let g = self.parent[p];
+        let side = (self.children[p][1] == x) as usize;
+        if !self.is_splay_root(p) {
+            let p_side = (self.children[g][1] == p) as usize;
+            self.children[g][p_side] = x;
+        }
+        self.parent[x] = g;
+        let moved = self.children[x][1 - side];
+        self.children[p][side] = moved;
+        if moved != NIL {
+            self.parent[moved] = p;
+        }
+        self.children[x][1 - side] = p;
+        self.parent[p] = x;
+        self.update(p);
+        self.update(x);
+    }
+
+    fn splay(&mut self, x: usize) {
+        // Push the reversals down from the root of the splay tree first.
+        let mut path = vec![x];
+        let mut y = x;
+        while !self.is_splay_root(y) {
+            y = self.parent[y];
+            path.push(y);
+        }
+        for &y in path.iter().rev() {
+            self.push(y);
+        }
+        while !self.is_splay_root(x) {
+            let p = self.parent[x];
+            if !self.is_splay_root(p) {
+                let g = self.parent[p];
+                let zig_zig = (self.children[g][0] == p) == (self.children[p][0] == x);
+                self.rotate(if zig_zig { p } else { x });
+            }
+            self.rotate(x);
+        }
+    }
+
+    /// Make the path from the root to `x` a splay tree rooted at `x`, and
+    /// return the last node where it joined the path that was accessed
+    /// before.
+    fn access(&mut self, x: usize) -> usize {
+        let mut last = NIL;
+        let mut y = x;
+        while y != NIL {
+            self.splay(y);
+            self.children[y][1] = last;
+            self.update(y);
+            last = y;
+            y = self.parent[y];
+        }
+        self.splay(x);
+        last
+    }
+}
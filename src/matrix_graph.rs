@@ -1,4 +1,6 @@
 //! `MatrixGraph<N, E, Ty, NullN, NullE, Ix>` is a graph datastructure backed by an adjacency matrix.
+//!
+//! `BitMatrixGraph<N, Ty, Ix>` is its unweighted counterpart, backed by an adjacency matrix of bits.
 
 use alloc::{fmt, vec, vec::Vec};
 use core::{
@@ -16,9 +18,10 @@ use crate::{
     data::Build,
     graph::NodeIndex as GraphNodeIndex,
     visit::{
-        Data, EdgeCount, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges,
-        IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
-        IntoNodeReferences, NodeCount, NodeIndexable, Visitable,
+        AdjacencyBitMatrix, BitMatrixNeighbors, Data, Degree, EdgeCount, GetAdjacencyMatrix,
+        GraphBase, GraphProp, IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors,
+        IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences, NodeCount, NodeIndexable,
+        Visitable,
     },
     Directed, Direction, EdgeType, IntoWeightedEdge, Outgoing, Undirected,
 };
@@ -646,6 +649,53 @@ impl<N, E, S: BuildHasher, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexT
         Edges::on_columns(a.index(), &self.node_adjacencies, self.node_capacity)
     }
 
+    /// Return the number of edges starting from `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`. A self loop counts once.
+    /// - `Undirected`: Same as [`.degree(a)`](#method.degree).
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(|V|)** time.
+    pub fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        if self.is_directed() {
+            self.edges(a).count()
+        } else {
+            self.degree(a)
+        }
+    }
+
+    /// Return the number of edges ending at `a`.
+    ///
+    /// - `Directed`: Incoming edges to `a`. A self loop counts once.
+    /// - `Undirected`: Same as [`.degree(a)`](#method.degree).
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(|V|)** time.
+    pub fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        if self.is_directed() {
+            Edges::<Ty, Null, Ix>::on_rows(a.index(), &self.node_adjacencies, self.node_capacity)
+                .count()
+        } else {
+            self.degree(a)
+        }
+    }
+
+    /// Return the number of edge endpoints at `a`: a self loop counts twice,
+    /// and for `Directed` graphs this is the sum of the in- and out-degree.
+    ///
+    /// Returns `0` if the node doesn't exist.
+    ///
+    /// Computes in **O(|V|)** time.
+    pub fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        if self.is_directed() {
+            self.out_degree(a) + self.in_degree(a)
+        } else {
+            self.edges(a).count() + self.get_edge_weight(a, a).is_some() as usize
+        }
+    }
+
     /// Create a new `MatrixGraph` from an iterable of edges.
     ///
     /// Node weights `N` are set to default values.
@@ -1155,6 +1205,11 @@ impl<T, S: BuildHasher> IdStorage<T, S> {
         self.upper_bound - self.removed_ids.len()
     }
 
+    #[inline]
+    fn contains(&self, id: usize) -> bool {
+        matches!(self.elements.get(id), Some(Some(_)))
+    }
+
     fn iter_ids(&self) -> IdIterator<S> {
         IdIterator {
             upper_bound: self.upper_bound,
@@ -1283,6 +1338,20 @@ impl<N, E, S: BuildHasher, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexT
     }
 }
 
+impl<N, E, S: BuildHasher, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType> Degree
+    for MatrixGraph<N, E, S, Ty, Null, Ix>
+{
+    fn out_degree(&self, a: NodeIndex<Ix>) -> usize {
+        MatrixGraph::out_degree(self, a)
+    }
+    fn in_degree(&self, a: NodeIndex<Ix>) -> usize {
+        MatrixGraph::in_degree(self, a)
+    }
+    fn degree(&self, a: NodeIndex<Ix>) -> usize {
+        MatrixGraph::degree(self, a)
+    }
+}
+
 /// Index the `MatrixGraph` by `NodeIndex` pair to access edge weights.
 ///
 /// Also available with indexing syntax: `&graph[e]`.
@@ -1475,6 +1544,650 @@ impl<N, E, S: BuildHasher, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexT
     }
 }
 
+/// `BitMatrixGraph<N, Ty>` is an unweighted graph datastructure using an
+/// adjacency matrix of bits.
+///
+/// It works like a [`MatrixGraph`] whose edges have no weight, but stores the
+/// matrix as an [`AdjacencyBitMatrix`], with one bit for each pair of nodes
+/// instead of one `Option<()>` byte, which uses eight times less memory.
+/// The edge weights, seen through the graph traits, are `()`.
+///
+/// `BitMatrixGraph` is parameterized over:
+///
+/// - Associated data `N` for nodes.
+/// - Edge type `Ty` that determines whether the graph edges are directed or undirected.
+/// - Index type `Ix` that sets the maximum size for the graph (defaults to `DefaultIx`).
+///
+/// The graph uses **O(|V^2|)** bits of space, with **O(1)** edge insertion
+/// and lookup. Self loops are allowed, no parallel edges.
+///
+/// ```
+/// use petgraph::matrix_graph::BitMatrixGraph;
+///
+/// let mut graph = BitMatrixGraph::<&str>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// assert!(graph.add_edge(a, b));
+/// assert!(!graph.add_edge(a, b));
+/// assert!(graph.has_edge(a, b));
+/// assert!(!graph.has_edge(b, a));
+/// ```
+#[derive(Clone)]
+pub struct BitMatrixGraph<
+    N,
+    #[cfg(feature = "std")] S = RandomState,
+    #[cfg(not(feature = "std"))] S,
+    Ty = Directed,
+    Ix = DefaultIx,
+> {
+    matrix: AdjacencyBitMatrix,
+    nodes: IdStorage<N, S>,
+    nb_edges: usize,
+    ty: PhantomData<Ty>,
+    ix: PhantomData<Ix>,
+}
+
+impl<N: fmt::Debug, S: BuildHasher, Ty: EdgeType, Ix: IndexType> fmt::Debug
+    for BitMatrixGraph<N, S, Ty, Ix>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BitMatrixGraph")
+            .field("node_count", &self.node_count())
+            .field("edge_count", &self.nb_edges)
+            .field("matrix", &self.matrix)
+            .finish()
+    }
+}
+
+impl<N, S: BuildHasher, Ty: EdgeType, Ix: IndexType> BitMatrixGraph<N, S, Ty, Ix> {
+    /// Create a new `BitMatrixGraph` with estimated capacity for nodes.
+    pub fn with_capacity(node_capacity: usize) -> Self
+    where
+        S: Default,
+    {
+        Self::with_capacity_and_hasher(node_capacity, Default::default())
+    }
+
+    /// Create a new `BitMatrixGraph` with estimated capacity for nodes and a provided hasher.
+    pub fn with_capacity_and_hasher(node_capacity: usize, hasher: S) -> Self {
+        BitMatrixGraph {
+            matrix: AdjacencyBitMatrix::new(node_capacity),
+            nodes: IdStorage::with_capacity_and_hasher(node_capacity, hasher),
+            nb_edges: 0,
+            ty: PhantomData,
+            ix: PhantomData,
+        }
+    }
+
+    /// Remove all nodes and edges.
+    pub fn clear(&mut self) {
+        self.matrix.clear();
+        self.nodes.clear();
+        self.nb_edges = 0;
+    }
+
+    /// Return the number of nodes (also called vertices) in the graph.
+    ///
+    /// Computes in **O(1)** time.
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Return the number of edges in the graph.
+    ///
+    /// Computes in **O(1)** time.
+    #[inline]
+    pub fn edge_count(&self) -> usize {
+        self.nb_edges
+    }
+
+    /// Return whether the graph has directed edges or not.
+    #[inline]
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Add a node (also called vertex) with associated data `weight` to the graph.
+    ///
+    /// Computes in **O(1)** time, best case.
+    /// Computes in **O(|V|^2)** time, worst case (matrix needs to be re-allocated).
+    ///
+    /// Return the index of the new node.
+    pub fn add_node(&mut self, weight: N) -> NodeIndex<Ix> {
+        let id = self.nodes.add(weight);
+        if id >= self.matrix.node_bound() {
+            // grow by exponential steps to avoid repeated allocations
+            self.matrix.grow(cmp::max((id + 1).next_power_of_two(), 4));
+        }
+        NodeIndex::new(id)
+    }
+
+    /// Remove `a` from the graph, with its edges.
+    ///
+    /// Computes in **O(V)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    #[track_caller]
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> N {
+        let weight = self.nodes.remove(a.index());
+        let cleared = self.matrix.clear_node(a.index());
+        self.nb_edges -= if Ty::is_directed() {
+            cleared
+        } else {
+            // the edges other than a self loop were set in both directions
+            let self_loop = cleared % 2;
+            (cleared + self_loop) / 2
+        };
+        weight
+    }
+
+    /// Add an edge from `a` to `b` to the graph.
+    ///
+    /// Return `true` if the edge was added, `false` if it already existed.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    #[track_caller]
+    pub fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        assert!(
+            self.nodes.contains(a.index()) && self.nodes.contains(b.index()),
+            "BitMatrixGraph::add_edge: node indices out of bounds"
+        );
+        if self.matrix.insert(a.index(), b.index()) {
+            return false;
+        }
+        if !Ty::is_directed() {
+            self.matrix.insert(b.index(), a.index());
+        }
+        self.nb_edges += 1;
+        true
+    }
+
+    /// Remove the edge from `a` to `b`.
+    ///
+    /// Return `true` if the edge existed.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn remove_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        if !self.matrix.remove(a.index(), b.index()) {
+            return false;
+        }
+        if !Ty::is_directed() {
+            self.matrix.remove(b.index(), a.index());
+        }
+        self.nb_edges -= 1;
+        true
+    }
+
+    /// Return `true` if there is an edge between `a` and `b`.
+    ///
+    /// If any of the nodes don't exist - returns `false`.
+    ///
+    /// Computes in **O(1)** time.
+    #[inline]
+    pub fn has_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.matrix.contains(a.index(), b.index())
+    }
+
+    /// Access the weight for node `a`.
+    ///
+    /// Also available with indexing syntax: `&graph[a]`.
+    ///
+    /// **Panics** if the node doesn't exist.
+    #[track_caller]
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> &N {
+        &self.nodes[a.index()]
+    }
+
+    /// Access the weight for node `a`, mutably.
+    ///
+    /// Also available with indexing syntax: `&mut graph[a]`.
+    ///
+    /// **Panics** if the node doesn't exist.
+    #[track_caller]
+    pub fn node_weight_mut(&mut self, a: NodeIndex<Ix>) -> &mut N {
+        &mut self.nodes[a.index()]
+    }
+
+    /// Return the adjacency matrix of the graph. The edges of an undirected
+    /// graph are set in both directions.
+    pub fn matrix(&self) -> &AdjacencyBitMatrix {
+        &self.matrix
+    }
+
+    /// Return an iterator of all nodes with an edge starting from `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is [`NodeIndex<Ix>`](../graph/struct.NodeIndex.html).
+    pub fn neighbors(&self, a: NodeIndex<Ix>) -> BitNeighbors<'_, Ix> {
+        BitNeighbors {
+            iter: BitNeighborsIter::Row(self.matrix.neighbors(a.index())),
+            ix: PhantomData,
+        }
+    }
+
+    /// Return an iterator of all edges of `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges connected to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `(NodeIndex<Ix>, NodeIndex<Ix>, &())`.
+    pub fn edges(&self, a: NodeIndex<Ix>) -> BitEdges<'_, Ix> {
+        BitEdges {
+            node: a,
+            dir: Outgoing,
+            iter: self.neighbors(a),
+        }
+    }
+
+    /// Create a new `BitMatrixGraph` from an iterable of edges.
+    ///
+    /// Node weights `N` are set to default values.
+    ///
+    /// Nodes are inserted automatically to match the edges.
+    ///
+    /// ```
+    /// use petgraph::matrix_graph::BitMatrixGraph;
+    ///
+    /// let gr = BitMatrixGraph::<()>::from_edges(&[(0, 1), (0, 2), (1, 2)]);
+    /// assert_eq!(gr.edge_count(), 3);
+    /// ```
+    pub fn from_edges<I>(iterable: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoWeightedEdge<()>,
+        <I::Item as IntoWeightedEdge<()>>::NodeId: Into<NodeIndex<Ix>>,
+        N: Default,
+        S: Default,
+    {
+        let mut g = Self::with_capacity(0);
+        g.extend_with_edges(iterable);
+        g
+    }
+
+    /// Extend the graph from an iterable of edges.
+    ///
+    /// Node weights `N` are set to default values.
+    ///
+    /// Nodes are inserted automatically to match the edges.
+    pub fn extend_with_edges<I>(&mut self, iterable: I)
+    where
+        I: IntoIterator,
+        I::Item: IntoWeightedEdge<()>,
+        <I::Item as IntoWeightedEdge<()>>::NodeId: Into<NodeIndex<Ix>>,
+        N: Default,
+    {
+        for elt in iterable {
+            let (source, target, ()) = elt.into_weighted_edge();
+            let (source, target) = (source.into(), target.into());
+            let nx = cmp::max(source, target);
+            while nx.index() >= self.node_count() {
+                self.add_node(N::default());
+            }
+            self.add_edge(source, target);
+        }
+    }
+}
+
+impl<N, S: BuildHasher, Ix: IndexType> BitMatrixGraph<N, S, Directed, Ix> {
+    /// Return an iterator of all neighbors that have an edge between them and
+    /// `a`, in the specified direction.
+    ///
+    /// - `Outgoing`: All edges from `a`.
+    /// - `Incoming`: All edges to `a`, found by scanning the column of `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is [`NodeIndex<Ix>`](../graph/struct.NodeIndex.html).
+    pub fn neighbors_directed(&self, a: NodeIndex<Ix>, d: Direction) -> BitNeighbors<'_, Ix> {
+        if d == Outgoing {
+            self.neighbors(a)
+        } else {
+            BitNeighbors {
+                iter: BitNeighborsIter::Column {
+                    matrix: &self.matrix,
+                    column: a.index(),
+                    row: 0,
+                },
+                ix: PhantomData,
+            }
+        }
+    }
+
+    /// Return an iterator of all edges of `a`, in the specified direction.
+    ///
+    /// - `Outgoing`: All edges from `a`.
+    /// - `Incoming`: All edges to `a`.
+    ///
+    /// Produces an empty iterator if the node `a` doesn't exist.<br>
+    /// Iterator element type is `(NodeIndex<Ix>, NodeIndex<Ix>, &())`.
+    pub fn edges_directed(&self, a: NodeIndex<Ix>, d: Direction) -> BitEdges<'_, Ix> {
+        BitEdges {
+            node: a,
+            dir: d,
+            iter: self.neighbors_directed(a, d),
+        }
+    }
+}
+
+/// Create a new empty `BitMatrixGraph`.
+impl<N, S: BuildHasher + Default, Ty: EdgeType, Ix: IndexType> Default
+    for BitMatrixGraph<N, S, Ty, Ix>
+{
+    fn default() -> Self {
+        Self::with_capacity(0)
+    }
+}
+
+impl<N, S: BuildHasher + Default> BitMatrixGraph<N, S, Directed> {
+    /// Create a new `BitMatrixGraph` with directed edges.
+    pub fn new() -> Self {
+        BitMatrixGraph::default()
+    }
+}
+
+impl<N, S: BuildHasher + Default> BitMatrixGraph<N, S, Undirected> {
+    /// Create a new `BitMatrixGraph` with undirected edges.
+    pub fn new_undirected() -> Self {
+        BitMatrixGraph::default()
+    }
+}
+
+/// Index the `BitMatrixGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, S: BuildHasher, Ty: EdgeType, Ix: IndexType> Index<NodeIndex<Ix>>
+    for BitMatrixGraph<N, S, Ty, Ix>
+{
+    type Output = N;
+
+    fn index(&self, ax: NodeIndex<Ix>) -> &N {
+        self.node_weight(ax)
+    }
+}
+
+/// Index the `BitMatrixGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, S: BuildHasher, Ty: EdgeType, Ix: IndexType> IndexMut<NodeIndex<Ix>>
+    for BitMatrixGraph<N, S, Ty, Ix>
+{
+    fn index_mut(&mut self, ax: NodeIndex<Ix>) -> &mut N {
+        self.node_weight_mut(ax)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum BitNeighborsIter<'a> {
+    Row(BitMatrixNeighbors<'a>),
+    Column {
+        matrix: &'a AdjacencyBitMatrix,
+        column: usize,
+        row: usize,
+    },
+}
+
+/// Iterator over the neighbors of a node of a [`BitMatrixGraph`].
+///
+/// Created with [`.neighbors()`](BitMatrixGraph::neighbors) and
+/// [`.neighbors_directed()`](BitMatrixGraph::neighbors_directed).
+#[derive(Debug, Clone)]
+pub struct BitNeighbors<'a, Ix> {
+    iter: BitNeighborsIter<'a>,
+    ix: PhantomData<Ix>,
+}
+
+impl<Ix: IndexType> Iterator for BitNeighbors<'_, Ix> {
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = match &mut self.iter {
+            BitNeighborsIter::Row(iter) => iter.next(),
+            BitNeighborsIter::Column {
+                matrix,
+                column,
+                row,
+            } => {
+                while *row < matrix.node_bound() && !matrix.contains(*row, *column) {
+                    *row += 1;
+                }
+                *row += 1;
+                Some(*row - 1).filter(|&r| r < matrix.node_bound())
+            }
+        };
+        next.map(NodeIndex::new)
+    }
+}
+
+/// Iterator over the edges of a node of a [`BitMatrixGraph`].
+///
+/// Created with [`.edges()`](BitMatrixGraph::edges) and
+/// [`.edges_directed()`](BitMatrixGraph::edges_directed).
+#[derive(Debug, Clone)]
+pub struct BitEdges<'a, Ix> {
+    node: NodeIndex<Ix>,
+    dir: Direction,
+    iter: BitNeighbors<'a, Ix>,
+}
+
+impl<'a, Ix: IndexType> Iterator for BitEdges<'a, Ix> {
+    type Item = (NodeIndex<Ix>, NodeIndex<Ix>, &'a ());
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let other = self.iter.next()?;
+        Some(if self.dir == Outgoing {
+            (self.node, other, &())
+        } else {
+            (other, self.node, &())
+        })
+    }
+}
+
+/// Iterator over all edges of a [`BitMatrixGraph`].
+///
+/// Created with [`.edge_references()`](IntoEdgeReferences::edge_references).
+#[derive(Debug, Clone)]
+pub struct BitEdgeReferences<'a, Ty, Ix> {
+    matrix: &'a AdjacencyBitMatrix,
+    row: usize,
+    iter: BitMatrixNeighbors<'a>,
+    ty: PhantomData<(Ty, Ix)>,
+}
+
+impl<'a, Ty: EdgeType, Ix: IndexType> Iterator for BitEdgeReferences<'a, Ty, Ix> {
+    type Item = (NodeIndex<Ix>, NodeIndex<Ix>, &'a ());
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            for column in &mut self.iter {
+                // the edges of an undirected graph are in both triangles
+                if Ty::is_directed() || column >= self.row {
+                    return Some((NodeIndex::new(self.row), NodeIndex::new(column), &()));
+                }
+            }
+            self.row += 1;
+            if self.row >= self.matrix.node_bound() {
+                return None;
+            }
+            self.iter = self.matrix.neighbors(self.row);
+        }
+    }
+}
+
+impl<N, S: BuildHasher, Ty: EdgeType, Ix: IndexType> GetAdjacencyMatrix
+    for BitMatrixGraph<N, S, Ty, Ix>
+{
+    type AdjMatrix = ();
+
+    fn adjacency_matrix(&self) -> Self::AdjMatrix {}
+
+    fn is_adjacent(&self, _: &Self::AdjMatrix, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.has_edge(a, b)
+    }
+}
+
+impl<N, S, Ty: EdgeType, Ix: IndexType> Visitable for BitMatrixGraph<N, S, Ty, Ix> {
+    type Map = FixedBitSet;
+
+    fn visit_map(&self) -> FixedBitSet {
+        FixedBitSet::with_capacity(self.node_bound())
+    }
+
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+        map.grow(self.node_bound());
+    }
+}
+
+impl<N, S, Ty: EdgeType, Ix: IndexType> GraphBase for BitMatrixGraph<N, S, Ty, Ix> {
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = (NodeIndex<Ix>, NodeIndex<Ix>);
+}
+
+impl<N, S, Ty: EdgeType, Ix: IndexType> GraphProp for BitMatrixGraph<N, S, Ty, Ix> {
+    type EdgeType = Ty;
+}
+
+impl<N, S, Ty: EdgeType, Ix: IndexType> Data for BitMatrixGraph<N, S, Ty, Ix> {
+    type NodeWeight = N;
+    type EdgeWeight = ();
+}
+
+impl<N, S: BuildHasher, Ty: EdgeType, Ix: IndexType> NodeCount for BitMatrixGraph<N, S, Ty, Ix> {
+    fn node_count(&self) -> usize {
+        BitMatrixGraph::node_count(self)
+    }
+}
+
+impl<N, S: BuildHasher, Ty: EdgeType, Ix: IndexType> EdgeCount for BitMatrixGraph<N, S, Ty, Ix> {
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_count()
+    }
+}
+
+impl<N, S, Ty: EdgeType, Ix: IndexType> NodeIndexable for BitMatrixGraph<N, S, Ty, Ix> {
+    fn node_bound(&self) -> usize {
+        self.nodes.upper_bound
+    }
+
+    fn to_index(&self, ix: NodeIndex<Ix>) -> usize {
+        ix.index()
+    }
+
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        NodeIndex::new(ix)
+    }
+}
+
+impl<'a, N, S: BuildHasher, Ty: EdgeType, Ix: IndexType> IntoNodeIdentifiers
+    for &'a BitMatrixGraph<N, S, Ty, Ix>
+{
+    type NodeIdentifiers = NodeIdentifiers<'a, Ix, S>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        NodeIdentifiers::new(self.nodes.iter_ids())
+    }
+}
+
+impl<'a, N, S: BuildHasher + 'a, Ty: EdgeType, Ix: IndexType> IntoNodeReferences
+    for &'a BitMatrixGraph<N, S, Ty, Ix>
+{
+    type NodeRef = (NodeIndex<Ix>, &'a N);
+    type NodeReferences = NodeReferences<'a, N, Ix, S>;
+    fn node_references(self) -> Self::NodeReferences {
+        NodeReferences::new(&self.nodes)
+    }
+}
+
+impl<'a, N, S: BuildHasher, Ty: EdgeType, Ix: IndexType> IntoNeighbors
+    for &'a BitMatrixGraph<N, S, Ty, Ix>
+{
+    type Neighbors = BitNeighbors<'a, Ix>;
+
+    fn neighbors(self, a: NodeIndex<Ix>) -> Self::Neighbors {
+        BitMatrixGraph::neighbors(self, a)
+    }
+}
+
+impl<'a, N, S: BuildHasher, Ix: IndexType> IntoNeighborsDirected
+    for &'a BitMatrixGraph<N, S, Directed, Ix>
+{
+    type NeighborsDirected = BitNeighbors<'a, Ix>;
+
+    fn neighbors_directed(self, a: NodeIndex<Ix>, d: Direction) -> Self::NeighborsDirected {
+        BitMatrixGraph::neighbors_directed(self, a, d)
+    }
+}
+
+impl<'a, N, S, Ty: EdgeType, Ix: IndexType> IntoEdgeReferences
+    for &'a BitMatrixGraph<N, S, Ty, Ix>
+{
+    type EdgeRef = (NodeIndex<Ix>, NodeIndex<Ix>, &'a ());
+    type EdgeReferences = BitEdgeReferences<'a, Ty, Ix>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        BitEdgeReferences {
+            matrix: &self.matrix,
+            row: 0,
+            iter: self.matrix.neighbors(0),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<'a, N, S: BuildHasher, Ty: EdgeType, Ix: IndexType> IntoEdges
+    for &'a BitMatrixGraph<N, S, Ty, Ix>
+{
+    type Edges = BitEdges<'a, Ix>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        BitMatrixGraph::edges(self, a)
+    }
+}
+
+impl<'a, N, S: BuildHasher, Ix: IndexType> IntoEdgesDirected
+    for &'a BitMatrixGraph<N, S, Directed, Ix>
+{
+    type EdgesDirected = BitEdges<'a, Ix>;
+
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        BitMatrixGraph::edges_directed(self, a, dir)
+    }
+}
+
+impl<N, S: BuildHasher, Ty: EdgeType, Ix: IndexType> Build for BitMatrixGraph<N, S, Ty, Ix> {
+    fn add_node(&mut self, weight: Self::NodeWeight) -> Self::NodeId {
+        self.add_node(weight)
+    }
+
+    fn add_edge(
+        &mut self,
+        a: Self::NodeId,
+        b: Self::NodeId,
+        (): Self::EdgeWeight,
+    ) -> Option<Self::EdgeId> {
+        if BitMatrixGraph::add_edge(self, a, b) {
+            Some((a, b))
+        } else {
+            None
+        }
+    }
+
+    fn update_edge(
+        &mut self,
+        a: Self::NodeId,
+        b: Self::NodeId,
+        (): Self::EdgeWeight,
+    ) -> Self::EdgeId {
+        BitMatrixGraph::add_edge(self, a, b);
+        (a, b)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2087,4 +2800,53 @@ mod tests {
         }
         assert_eq!(graph.try_add_node(()), Err(MatrixError::NodeIxLimit));
     }
+
+    #[test]
+    fn test_bit_matrix_graph() {
+        let mut g = BitMatrixGraph::<usize>::new();
+        let nodes: Vec<_> = (0..100).map(|i| g.add_node(i)).collect();
+        for w in nodes.windows(2) {
+            assert!(g.add_edge(w[0], w[1]));
+        }
+        assert!(g.add_edge(nodes[99], nodes[0]));
+        assert!(!g.add_edge(nodes[0], nodes[1]));
+        assert_eq!(g.edge_count(), 100);
+        assert!(g.has_edge(nodes[70], nodes[71]));
+        assert!(!g.has_edge(nodes[71], nodes[70]));
+
+        let incoming: Vec<_> = g.neighbors_directed(nodes[0], Incoming).collect();
+        assert_eq!(incoming, [nodes[99]]);
+        let outgoing: Vec<_> = g.edges_directed(nodes[64], Outgoing).collect();
+        assert_eq!(outgoing, [(nodes[64], nodes[65], &())]);
+        assert_eq!(g.edge_references().count(), 100);
+
+        assert_eq!(g.remove_node(nodes[50]), 50);
+        assert_eq!(g.edge_count(), 98);
+        assert!(g.remove_edge(nodes[0], nodes[1]));
+        assert!(!g.remove_edge(nodes[0], nodes[1]));
+        assert_eq!(g.edge_count(), 97);
+        // the removed index is reused, without the edges of the removed node
+        assert_eq!(g.add_node(100), nodes[50]);
+        assert_eq!(g.neighbors(nodes[50]).count(), 0);
+    }
+
+    #[test]
+    fn test_bit_matrix_graph_undirected() {
+        let mut g =
+            BitMatrixGraph::<(), RandomState, Undirected>::from_edges([(0, 1), (1, 2), (2, 2)]);
+        assert_eq!(g.edge_count(), 3);
+        assert!(g.has_edge(1.into(), 0.into()));
+        let edges: Vec<_> = g
+            .edge_references()
+            .map(|(a, b, _)| (a.index(), b.index()))
+            .collect();
+        assert_eq!(edges, [(0, 1), (1, 2), (2, 2)]);
+        let neighbors: Vec<_> = g.neighbors(2.into()).map(|n| n.index()).collect();
+        assert_eq!(neighbors, [1, 2]);
+
+        g.remove_node(2.into());
+        assert_eq!(g.edge_count(), 1);
+        assert!(!g.has_edge(1.into(), 2.into()));
+        assert!(g.is_adjacent(&g.adjacency_matrix(), 0.into(), 1.into()));
+    }
 }
@@ -0,0 +1,458 @@
+//! Edge contraction, node merging and [graph minors][1].
+//!
+//! A minor of a graph is obtained by deleting edges and nodes and by
+//! contracting edges. The [`Contract`] trait adds the contraction of an edge
+//! and the merging of a set of nodes to [`Graph`] and [`StableGraph`], and
+//! [`is_minor_model`] checks a witness that a graph is a minor of another.
+//!
+//! Both operations handle the edges they create in the same way: the edges
+//! between the merged nodes would become self loops and are removed, with
+//! the self loops of the merged nodes, and the edges that would become
+//! parallel are merged into one. The merged node has no self loop and at
+//! most one edge (in each direction, for a directed graph) to each other
+//! node, so contracting the edges of a simple graph keeps it simple.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Graph_minor
+
+use alloc::{vec, vec::Vec};
+
+use hashbrown::hash_map::{Entry, HashMap};
+use hashbrown::HashSet;
+
+use crate::graph::{EdgeIndex, Graph, IndexType, NodeIndex};
+#[cfg(feature = "stable_graph")]
+use crate::stable_graph::StableGraph;
+use crate::unionfind::UnionFind;
+use crate::visit::{
+    Data, EdgeRef, GraphBase, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable,
+};
+use crate::{Direction, EdgeType};
+
+/// The operations of a graph that merging nodes needs.
+trait Storage {
+    type N;
+    type E;
+    type Ix: IndexType;
+
+    fn is_directed(&self) -> bool;
+    fn has_node(&self, v: NodeIndex<Self::Ix>) -> bool;
+    fn incident_edges(&self, v: NodeIndex<Self::Ix>, edges: &mut Vec<EdgeIndex<Self::Ix>>);
+    fn endpoints(&self, e: EdgeIndex<Self::Ix>) -> (NodeIndex<Self::Ix>, NodeIndex<Self::Ix>);
+    fn take_edge(&mut self, e: EdgeIndex<Self::Ix>) -> Self::E;
+    /// Remove a node without edges, and return its weight and the former
+    /// index of the node that was moved into its place, if any.
+    fn take_node(&mut self, v: NodeIndex<Self::Ix>) -> (Self::N, Option<NodeIndex<Self::Ix>>);
+    fn put_edge(&mut self, a: NodeIndex<Self::Ix>, b: NodeIndex<Self::Ix>, weight: Self::E);
+    fn weight_mut(&mut self, v: NodeIndex<Self::Ix>) -> &mut Self::N;
+}
+
+impl<N, E, Ty: EdgeType, Ix: IndexType> Storage for Graph<N, E, Ty, Ix> {
+    type N = N;
+    type E = E;
+    type Ix = Ix;
+
+    fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    fn has_node(&self, v: NodeIndex<Ix>) -> bool {
+        v.index() < self.node_count()
+    }
+
+    fn incident_edges(&self, v: NodeIndex<Ix>, edges: &mut Vec<EdgeIndex<Ix>>) {
+        for dir in [Direction::Outgoing, Direction::Incoming] {
+            edges.extend(self.edges_directed(v, dir).map(|e| e.id()));
+        }
+    }
+
+    fn endpoints(&self, e: EdgeIndex<Ix>) -> (NodeIndex<Ix>, NodeIndex<Ix>) {
+        self.edge_endpoints(e).unwrap()
+    }
+
+    fn take_edge(&mut self, e: EdgeIndex<Ix>) -> E {
+        self.remove_edge(e).unwrap()
+    }
+
+    fn take_node(&mut self, v: NodeIndex<Ix>) -> (N, Option<NodeIndex<Ix>>) {
+        let weight = self.remove_node(v).unwrap();
+        // The last node takes the place of the removed one.
+        let last = NodeIndex::new(self.node_count());
+        (weight, (last != v).then_some(last))
+    }
+
+    fn put_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) {
+        self.add_edge(a, b, weight);
+    }
+
+    fn weight_mut(&mut self, v: NodeIndex<Ix>) -> &mut N {
+        &mut self[v]
+    }
+}
+
+#[cfg(feature = "stable_graph")]
+impl<N, E, Ty: EdgeType, Ix: IndexType> Storage for StableGraph<N, E, Ty, Ix> {
+    type N = N;
+    type E = E;
+    type Ix = Ix;
+
+    fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    fn has_node(&self, v: NodeIndex<Ix>) -> bool {
+        self.contains_node(v)
+    }
+
+    fn incident_edges(&self, v: NodeIndex<Ix>, edges: &mut Vec<EdgeIndex<Ix>>) {
+        for dir in [Direction::Outgoing, Direction::Incoming] {
+            edges.extend(self.edges_directed(v, dir).map(|e| e.id()));
+        }
+    }
+
+    fn endpoints(&self, e: EdgeIndex<Ix>) -> (NodeIndex<Ix>, NodeIndex<Ix>) {
+        self.edge_endpoints(e).unwrap()
+    }
+
+    fn take_edge(&mut self, e: EdgeIndex<Ix>) -> E {
+        self.remove_edge(e).unwrap()
+    }
+
+    fn take_node(&mut self, v: NodeIndex<Ix>) -> (N, Option<NodeIndex<Ix>>) {
+        (self.remove_node(v).unwrap(), None)
+    }
+
+    fn put_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) {
+        self.add_edge(a, b, weight);
+    }
+
+    fn weight_mut(&mut self, v: NodeIndex<Ix>) -> &mut N {
+        &mut self[v]
+    }
+}
+
+fn merge<S, F, G>(
+    graph: &mut S,
+    nodes: &[NodeIndex<S::Ix>],
+    mut merge_node: F,
+    mut merge_edge: G,
+) -> Option<NodeIndex<S::Ix>>
+where
+    S: Storage,
+    F: FnMut(&mut S::N, S::N),
+    G: FnMut(&mut S::E, S::E),
+{
+    let mut set = nodes.to_vec();
+    set.sort_unstable();
+    set.dedup();
+    let keep = *set.first()?;
+    assert!(
+        set.iter().all(|&v| graph.has_node(v)),
+        "merge_nodes: a node is not in the graph"
+    );
+
+    let mut incident = Vec::new();
+    for &v in &set {
+        graph.incident_edges(v, &mut incident);
+    }
+    incident.sort_unstable();
+    incident.dedup();
+    // Removing the edges from the last one keeps the other indices valid in
+    // a `Graph`, which moves the last edge into the place of a removed one.
+    let target = |v| {
+        if set.binary_search(&v).is_ok() {
+            keep
+        } else {
+            v
+        }
+    };
+    let mut edges = Vec::with_capacity(incident.len());
+    for &e in incident.iter().rev() {
+        let (a, b) = graph.endpoints(e);
+        let weight = graph.take_edge(e);
+        edges.push((target(a), target(b), weight));
+    }
+    edges.reverse();
+
+    // `keep` has the smallest index, so it is never moved.
+    let mut weights = Vec::with_capacity(set.len() - 1);
+    for &v in set[1..].iter().rev() {
+        let (weight, moved) = graph.take_node(v);
+        weights.push(weight);
+        if let Some(old) = moved {
+            for (a, b, _) in &mut edges {
+                for end in [a, b] {
+                    if *end == old {
+                        *end = v;
+                    }
+                }
+            }
+        }
+    }
+    for weight in weights.into_iter().rev() {
+        merge_node(graph.weight_mut(keep), weight);
+    }
+
+    let directed = graph.is_directed();
+    let mut position: HashMap<_, usize> = HashMap::new();
+    let mut kept: Vec<(_, _, S::E)> = Vec::new();
+    for (a, b, weight) in edges {
+        if a == b && a == keep {
+            continue;
+        }
+        let key = if directed || a <= b { (a, b) } else { (b, a) };
+        match position.entry(key) {
+            Entry::Occupied(i) => merge_edge(&mut kept[*i.get()].2, weight),
+            Entry::Vacant(entry) => {
+                entry.insert(kept.len());
+                kept.push((a, b, weight));
+            }
+        }
+    }
+    for (a, b, weight) in kept {
+        graph.put_edge(a, b, weight);
+    }
+    Some(keep)
+}
+
+/// Edge contraction and node merging, for [`Graph`] and [`StableGraph`].
+///
+/// See the [module documentation](self) for how the resulting self loops
+/// and parallel edges are handled.
+pub trait Contract: GraphBase + Data {
+    /// Merge `nodes` into one node, and return it, or `None` if `nodes` is
+    /// empty.
+    ///
+    /// The merged node is the node of `nodes` with the smallest index, and
+    /// `merge_node` is called with its weight and the weight of each other
+    /// node, in increasing order of index, as they are removed. The edges of
+    /// the removed nodes are moved to the merged node, and `merge_edge` is
+    /// called with the weight of the first edge (by index) and the weight of
+    /// each edge that would be parallel to it. Repeated nodes are ignored.
+    ///
+    /// The edges of the merged nodes are removed and added again, so their
+    /// indices may change. A [`Graph`] also moves its last nodes into the
+    /// places of the removed nodes, like [`Graph::remove_node`], while a
+    /// [`StableGraph`] keeps the other indices.
+    ///
+    /// **Panics** if a node of `nodes` is not in the graph.
+    ///
+    /// Computes in **O(|V| + Σ deg(v) · k)** time, where **deg(v)** is the
+    /// degree of each node of `nodes` and **k** is the number of nodes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::graph::{NodeIndex, UnGraph};
+    /// use petgraph::minor::Contract;
+    ///
+    /// // The populations of cities and the lengths of roads between them.
+    /// let mut graph = UnGraph::<u32, f32>::new_undirected();
+    /// let a = graph.add_node(10);
+    /// let b = graph.add_node(20);
+    /// let c = graph.add_node(30);
+    /// let d = graph.add_node(40);
+    /// graph.extend_with_edges([(a, b, 1.0), (b, c, 2.0), (a, c, 5.0), (c, d, 3.0)]);
+    ///
+    /// // Merge a and b into a metropolitan area, keeping the shortest roads.
+    /// let metro = graph
+    ///     .merge_nodes(&[b, a], |pop, other| *pop += other, |len, other| *len = len.min(other))
+    ///     .unwrap();
+    /// assert_eq!(metro, a);
+    /// assert_eq!(graph[metro], 30);
+    /// assert_eq!(graph.node_count(), 3);
+    /// assert_eq!(graph.edge_count(), 2);
+    /// // d took the index of the removed node b.
+    /// let edge = graph.find_edge(metro, c).unwrap();
+    /// assert_eq!(graph[edge], 2.0);
+    /// assert!(graph.contains_edge(c, NodeIndex::new(1)));
+    /// ```
+    fn merge_nodes<F, G>(
+        &mut self,
+        nodes: &[Self::NodeId],
+        merge_node: F,
+        merge_edge: G,
+    ) -> Option<Self::NodeId>
+    where
+        F: FnMut(&mut Self::NodeWeight, Self::NodeWeight),
+        G: FnMut(&mut Self::EdgeWeight, Self::EdgeWeight);
+
+    /// Contract the edge `edge`: remove it and merge its endpoints with
+    /// [`merge_nodes`](Contract::merge_nodes), and return the merged node,
+    /// or `None` if the edge does not exist.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::graph::{EdgeIndex, UnGraph};
+    /// use petgraph::minor::Contract;
+    ///
+    /// // Contracting an edge of a 4-cycle gives a triangle.
+    /// let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    /// graph.contract_edge(EdgeIndex::new(0), |_, _| (), |_, _| ());
+    /// assert_eq!(graph.node_count(), 3);
+    /// assert_eq!(graph.edge_count(), 3);
+    /// ```
+    fn contract_edge<F, G>(
+        &mut self,
+        edge: Self::EdgeId,
+        merge_node: F,
+        merge_edge: G,
+    ) -> Option<Self::NodeId>
+    where
+        F: FnMut(&mut Self::NodeWeight, Self::NodeWeight),
+        G: FnMut(&mut Self::EdgeWeight, Self::EdgeWeight);
+}
+
+impl<N, E, Ty: EdgeType, Ix: IndexType> Contract for Graph<N, E, Ty, Ix> {
+    fn merge_nodes<F, G>(
+        &mut self,
+        nodes: &[NodeIndex<Ix>],
+        merge_node: F,
+        merge_edge: G,
+    ) -> Option<NodeIndex<Ix>>
+    where
+        F: FnMut(&mut N, N),
+        G: FnMut(&mut E, E),
+    {
+        merge(self, nodes, merge_node, merge_edge)
+    }
+
+    fn contract_edge<F, G>(
+        &mut self,
+        edge: EdgeIndex<Ix>,
+        merge_node: F,
+        merge_edge: G,
+    ) -> Option<NodeIndex<Ix>>
+    where
+        F: FnMut(&mut N, N),
+        G: FnMut(&mut E, E),
+    {
+        let (a, b) = self.edge_endpoints(edge)?;
+        merge(self, &[a, b], merge_node, merge_edge)
+    }
+}
+
+#[cfg(feature = "stable_graph")]
+impl<N, E, Ty: EdgeType, Ix: IndexType> Contract for StableGraph<N, E, Ty, Ix> {
+    fn merge_nodes<F, G>(
+        &mut self,
+        nodes: &[NodeIndex<Ix>],
+        merge_node: F,
+        merge_edge: G,
+    ) -> Option<NodeIndex<Ix>>
+    where
+        F: FnMut(&mut N, N),
+        G: FnMut(&mut E, E),
+    {
+        merge(self, nodes, merge_node, merge_edge)
+    }
+
+    fn contract_edge<F, G>(
+        &mut self,
+        edge: EdgeIndex<Ix>,
+        merge_node: F,
+        merge_edge: G,
+    ) -> Option<NodeIndex<Ix>>
+    where
+        F: FnMut(&mut N, N),
+        G: FnMut(&mut E, E),
+    {
+        let (a, b) = self.edge_endpoints(edge)?;
+        merge(self, &[a, b], merge_node, merge_edge)
+    }
+}
+
+/// \[Generic\] Return `true` if `branch_sets` is a model of `h` as a minor of
+/// `g`.
+///
+/// `branch_sets[i]` is the set of nodes of `g` that are contracted into the
+/// node of index `i` of `h`. The sets are a model of `h` when they are
+/// disjoint, each node of `h` has a non empty set that is connected in `g`,
+/// and for each edge of `h`, an edge of `g` joins the two sets. `h` is then
+/// a minor of `g`: contracting the edges inside each set, and deleting the
+/// other nodes and edges, gives `h`, or a graph with more edges.
+///
+/// The direction of the edges is ignored, and so are the self loops of
+/// `h`. The sets of the indices that are not nodes of `h` are ignored.
+///
+/// # Arguments
+/// * `g`: the larger graph.
+/// * `h`: the candidate minor.
+/// * `branch_sets`: a set of nodes of `g` for each node index of `h`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| α(|V|) + |E(h)|)** expected.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges
+/// of `g`, **|E(h)|** is the number of edges of `h` and **α** is the inverse
+/// Ackermann function.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::minor::is_minor_model;
+///
+/// // A 6-cycle has a triangle as a minor: contract every other edge.
+/// let cycle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+/// let triangle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let sets = |pairs: [[usize; 2]; 3]| pairs.map(|set| set.map(NodeIndex::new).to_vec());
+/// assert!(is_minor_model(&cycle, &triangle, &sets([[0, 1], [2, 3], [4, 5]])));
+/// // The nodes 0 and 2 are not adjacent.
+/// assert!(!is_minor_model(&cycle, &triangle, &sets([[0, 2], [1, 3], [4, 5]])));
+/// ```
+pub fn is_minor_model<G, H>(g: G, h: H, branch_sets: &[Vec<G::NodeId>]) -> bool
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    H: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    assert_eq!(
+        branch_sets.len(),
+        h.node_bound(),
+        "is_minor_model: there must be a branch set for each node index of the minor"
+    );
+    let mut owner = vec![usize::MAX; g.node_bound()];
+    let mut total = 0;
+    for node in h.node_identifiers() {
+        let i = h.to_index(node);
+        if branch_sets[i].is_empty() {
+            return false;
+        }
+        for &v in &branch_sets[i] {
+            let v = g.to_index(v);
+            if owner[v] != usize::MAX {
+                return false;
+            }
+            owner[v] = i;
+            total += 1;
+        }
+    }
+
+    // Join the nodes of each set along the edges inside it, and record the
+    // pairs of sets joined by an edge.
+    let mut components = UnionFind::new(g.node_bound());
+    let mut joins = total;
+    let mut adjacent = HashSet::new();
+    for edge in g.edge_references() {
+        let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        let (x, y) = (owner[a], owner[b]);
+        if x == usize::MAX || y == usize::MAX {
+            continue;
+        }
+        if x == y {
+            if components.union(a, b) {
+                joins -= 1;
+            }
+        } else {
+            adjacent.insert((x.min(y), x.max(y)));
+        }
+    }
+    // Each set is connected exactly when the unions leave one component per
+    // set.
+    let sets = h.node_identifiers().count();
+    if joins != sets {
+        return false;
+    }
+    h.edge_references().all(|edge| {
+        let (x, y) = (h.to_index(edge.source()), h.to_index(edge.target()));
+        x == y || adjacent.contains(&(x.min(y), x.max(y)))
+    })
+}
@@ -0,0 +1,399 @@
+//! Memory-mapped graphs in compressed sparse row form.
+//!
+//! [`MmapCsr::write`] writes a graph to a file in a binary CSR layout whose
+//! arrays can be used in place, and [`MmapCsr`] maps such a file into memory
+//! and views it as a [`CsrSlice`], which implements the visit traits. The
+//! operating system pages the arrays in as the algorithms read them, so that
+//! graphs larger than the memory can be analyzed without loading them.
+//!
+//! The file starts with a header of 40 bytes: the magic bytes `PGCSRMAP`,
+//! the version of the layout as a `u32`, a byte order mark `0x01020304` as a
+//! `u32`, the width in bytes of a `usize`, the type tags of the node indices
+//! and of the edge weights, 5 bytes of padding, and the node count and
+//! entry count as `u64`. It continues with the `node_count + 1` offsets as
+//! `usize`, the targets, and the edge weights, each array padded to a
+//! multiple of 8 bytes. All numbers are in the native byte order, so a file
+//! can only be mapped on a machine of the same byte order.
+//!
+//! The node indices and the edge weights are [`MmapValue`]s: the primitive
+//! integer and floating point types, and `()` for an unweighted graph.
+//!
+//! # Example
+//!
+//! ```
+//! use petgraph::algo::dijkstra;
+//! use petgraph::graph::DiGraph;
+//! use petgraph::mmap::MmapCsr;
+//! use petgraph::visit::EdgeRef;
+//!
+//! let graph = DiGraph::<(), f64>::from_edges([(0, 1, 1.), (1, 2, 2.), (0, 2, 5.)]);
+//! let path = std::env::temp_dir().join("petgraph-mmap-doc.csr");
+//! let mut file = std::fs::File::create(&path).unwrap();
+//! MmapCsr::<f64>::write(&graph, |e| *e.weight(), &mut file).unwrap();
+//!
+//! // Safety: the file is not modified while it is mapped.
+//! let mapped = unsafe { MmapCsr::<f64>::open(&path) }.unwrap();
+//! let distances = dijkstra(mapped.graph(), 0, None, |e| *e.weight());
+//! assert_eq!(distances[&2], 3.);
+//! std::fs::remove_file(&path).unwrap();
+//! ```
+
+use alloc::{vec, vec::Vec};
+use core::{fmt, marker::PhantomData, mem, slice};
+use std::{fs::File, io, path::Path};
+
+use memmap2::Mmap;
+
+use crate::csr::{CsrSlice, CsrSliceError};
+use crate::graph::{DefaultIx, IndexType};
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+
+/// The magic bytes at the start of a file.
+const MAGIC: &[u8; 8] = b"PGCSRMAP";
+/// The byte order mark, in the native byte order.
+const BYTE_ORDER: u32 = 0x0102_0304;
+/// The length of the header of a file.
+const HEADER_LEN: usize = 40;
+
+/// The version of the file layout written by this version of the crate.
+///
+/// Files of other versions are rejected with
+/// [`MmapCsrError::UnsupportedVersion`].
+pub const MMAP_CSR_VERSION: u32 = 1;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A plain value type that can be read in place from a file: the primitive
+/// integer and floating point types, and `()`.
+///
+/// This trait is sealed: every bit pattern of the right size is a valid
+/// value of these types, which makes reading them in place sound.
+pub trait MmapValue: Copy + private::Sealed + 'static {
+    /// The tag of the type in the header of a file.
+    #[doc(hidden)]
+    const TAG: u8;
+}
+
+macro_rules! mmap_value {
+    ($($ty:ty => $tag:expr),*) => {
+        $(
+            impl private::Sealed for $ty {}
+            impl MmapValue for $ty {
+                const TAG: u8 = $tag;
+            }
+        )*
+    };
+}
+
+mmap_value! {
+    () => 0, u8 => 1, u16 => 2, u32 => 3, u64 => 4, usize => 5,
+    i8 => 6, i16 => 7, i32 => 8, i64 => 9, f32 => 10, f64 => 11
+}
+
+/// An error while mapping a file.
+#[derive(Debug)]
+pub enum MmapCsrError {
+    /// The file could not be opened or mapped.
+    Io(io::Error),
+    /// The file does not start with the magic bytes of the layout.
+    InvalidMagic,
+    /// The file is of an unsupported version of the layout.
+    UnsupportedVersion(u32),
+    /// The file was written on a machine of another byte order or width.
+    PlatformMismatch,
+    /// The node indices or the edge weights of the file are not of the
+    /// requested types.
+    TypeMismatch,
+    /// The length of the file does not match its header.
+    UnexpectedLength,
+    /// The arrays of the file do not describe a graph.
+    Invalid(CsrSliceError),
+}
+
+impl fmt::Display for MmapCsrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MmapCsrError::Io(e) => write!(f, "Could not map the file: {e}"),
+            MmapCsrError::InvalidMagic => write!(f, "The file is not a CSR file"),
+            MmapCsrError::UnsupportedVersion(v) => {
+                write!(f, "The version {v} of the file is not supported")
+            }
+            MmapCsrError::PlatformMismatch => {
+                write!(f, "The file was written on another kind of platform")
+            }
+            MmapCsrError::TypeMismatch => {
+                write!(f, "The file has other index or weight types")
+            }
+            MmapCsrError::UnexpectedLength => {
+                write!(f, "The length of the file does not match its header")
+            }
+            MmapCsrError::Invalid(e) => write!(f, "The file is not valid: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for MmapCsrError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MmapCsrError::Io(e) => Some(e),
+            MmapCsrError::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MmapCsrError {
+    fn from(e: io::Error) -> Self {
+        MmapCsrError::Io(e)
+    }
+}
+
+/// Return the length of an array of `len` values of `T`, padded to a
+/// multiple of 8 bytes, or `None` if it overflows.
+fn padded_len<T>(len: usize) -> Option<usize> {
+    let len = len.checked_mul(mem::size_of::<T>())?.checked_add(7)?;
+    Some(len / 8 * 8)
+}
+
+/// View the values as bytes.
+fn as_bytes<T: MmapValue>(values: &[T]) -> &[u8] {
+    // Safety: the `MmapValue` types have no padding bytes.
+    unsafe { slice::from_raw_parts(values.as_ptr().cast(), mem::size_of_val(values)) }
+}
+
+/// View `len` values of `T` at `start` in the bytes, which must be in
+/// bounds and aligned.
+fn cast<T: MmapValue>(bytes: &[u8], start: usize, len: usize) -> &[T] {
+    let bytes = &bytes[start..start + len * mem::size_of::<T>()];
+    if mem::size_of::<T>() == 0 {
+        // Safety: a dangling pointer is valid for zero-sized values.
+        return unsafe { slice::from_raw_parts(core::ptr::NonNull::dangling().as_ptr(), len) };
+    }
+    assert_eq!(bytes.as_ptr() as usize % mem::align_of::<T>(), 0);
+    // Safety: the bytes are in bounds and aligned, and any bit pattern is a
+    // valid `MmapValue`.
+    unsafe { slice::from_raw_parts(bytes.as_ptr().cast(), len) }
+}
+
+fn read_u32(bytes: &[u8], start: usize) -> u32 {
+    u32::from_ne_bytes(bytes[start..start + 4].try_into().unwrap())
+}
+
+fn read_u64(bytes: &[u8], start: usize) -> u64 {
+    u64::from_ne_bytes(bytes[start..start + 8].try_into().unwrap())
+}
+
+/// A directed graph in compressed sparse row form, in a memory-mapped file.
+///
+/// `MmapCsr` validates the file once when it is mapped, and then views it
+/// with [`.graph()`](MmapCsr::graph) as a [`CsrSlice`] of edge weights `E`
+/// and node indices `Ix`, without copying. The nodes have no weights.
+///
+/// See the [module documentation](self) for the layout of the file.
+pub struct MmapCsr<E = (), Ix = DefaultIx> {
+    mmap: Mmap,
+    node_count: usize,
+    entry_count: usize,
+    targets_start: usize,
+    weights_start: usize,
+    ty: PhantomData<fn() -> (E, Ix)>,
+}
+
+impl<E, Ix> fmt::Debug for MmapCsr<E, Ix> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MmapCsr")
+            .field("node_count", &self.node_count)
+            .field("edge_count", &self.entry_count)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<E, Ix> MmapCsr<E, Ix>
+where
+    E: MmapValue,
+    Ix: IndexType + MmapValue,
+{
+    fn offsets_start() -> usize {
+        HEADER_LEN
+    }
+
+    // The counts come from the header of the file, so the offsets are
+    // `None` rather than overflowing for a corrupt one.
+
+    fn targets_start(node_count: usize) -> Option<usize> {
+        Self::offsets_start().checked_add(padded_len::<usize>(node_count.checked_add(1)?)?)
+    }
+
+    fn weights_start(node_count: usize, entry_count: usize) -> Option<usize> {
+        Self::targets_start(node_count)?.checked_add(padded_len::<Ix>(entry_count)?)
+    }
+
+    fn file_len(node_count: usize, entry_count: usize) -> Option<usize> {
+        Self::weights_start(node_count, entry_count)?.checked_add(padded_len::<E>(entry_count)?)
+    }
+
+    /// Write a graph to `writer`, in the layout read by [`MmapCsr`], with
+    /// the edge weights given by `edge_weight`.
+    ///
+    /// The nodes are identified by their index, as given by
+    /// [`NodeIndexable`], and the edges of an undirected graph are written in
+    /// both directions.
+    ///
+    /// Computes in **O(|V| + |E|)** time, and builds the arrays in memory
+    /// before writing them.
+    ///
+    /// Return an error of kind [`io::ErrorKind::InvalidInput`] if the nodes
+    /// do not fit in `Ix`.
+    pub fn write<G, F, W>(graph: G, mut edge_weight: F, mut writer: W) -> io::Result<()>
+    where
+        G: IntoEdgeReferences + NodeIndexable + GraphProp,
+        F: FnMut(G::EdgeRef) -> E,
+        W: io::Write,
+    {
+        let node_count = graph.node_bound();
+        if node_count > 0 && node_count - 1 > <Ix as IndexType>::max().index() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the nodes do not fit in the index type",
+            ));
+        }
+
+        let mut entries = Vec::new();
+        for edge in graph.edge_references() {
+            let (a, b) = (graph.to_index(edge.source()), graph.to_index(edge.target()));
+            let weight = edge_weight(edge);
+            entries.push((a, b, weight));
+            if !graph.is_directed() && a != b {
+                entries.push((b, a, weight));
+            }
+        }
+
+        let mut offsets = vec![0usize; node_count + 1];
+        for &(a, _, _) in &entries {
+            offsets[a + 1] += 1;
+        }
+        for a in 0..node_count {
+            offsets[a + 1] += offsets[a];
+        }
+        let mut next = offsets.clone();
+        let mut targets = vec![Ix::new(0); entries.len()];
+        let mut weights = Vec::with_capacity(entries.len());
+        // Stable placement keeps the weights in lock step with the targets.
+        let mut order = vec![0; entries.len()];
+        for (i, &(a, b, _)) in entries.iter().enumerate() {
+            let position = next[a];
+            next[a] += 1;
+            targets[position] = Ix::new(b);
+            order[position] = i;
+        }
+        weights.extend(order.iter().map(|&i| entries[i].2));
+
+        let mut header = [0; HEADER_LEN];
+        header[..8].copy_from_slice(MAGIC);
+        header[8..12].copy_from_slice(&MMAP_CSR_VERSION.to_ne_bytes());
+        header[12..16].copy_from_slice(&BYTE_ORDER.to_ne_bytes());
+        header[16] = mem::size_of::<usize>() as u8;
+        header[17] = Ix::TAG;
+        header[18] = E::TAG;
+        header[24..32].copy_from_slice(&(node_count as u64).to_ne_bytes());
+        header[32..40].copy_from_slice(&(entries.len() as u64).to_ne_bytes());
+        writer.write_all(&header)?;
+
+        let padding = [0; 8];
+        for bytes in [as_bytes(&offsets), as_bytes(&targets), as_bytes(&weights)] {
+            writer.write_all(bytes)?;
+            writer.write_all(&padding[..(8 - bytes.len() % 8) % 8])?;
+        }
+        writer.flush()
+    }
+
+    /// Map the file at `path` into memory, and validate it.
+    ///
+    /// Computes in **O(|V| + |E|)** time, to check that the arrays describe a
+    /// graph, reading the file once.
+    ///
+    /// # Safety
+    ///
+    /// As with [`Mmap::map`], the file must not be modified or truncated,
+    /// by this process or another one, while it is mapped: the graph would
+    /// change under the algorithms, or the process would crash.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> Result<Self, MmapCsrError> {
+        let file = File::open(path)?;
+        Self::from_mmap(Mmap::map(&file)?)
+    }
+
+    /// Validate a file that is already mapped into memory.
+    ///
+    /// Computes in **O(|V| + |E|)** time, to check that the arrays describe a
+    /// graph.
+    pub fn from_mmap(mmap: Mmap) -> Result<Self, MmapCsrError> {
+        let bytes = &mmap[..];
+        if bytes.len() < HEADER_LEN || &bytes[..8] != MAGIC {
+            return Err(MmapCsrError::InvalidMagic);
+        }
+        let version = read_u32(bytes, 8);
+        if version != MMAP_CSR_VERSION {
+            return Err(MmapCsrError::UnsupportedVersion(version));
+        }
+        if read_u32(bytes, 12) != BYTE_ORDER || bytes[16] as usize != mem::size_of::<usize>() {
+            return Err(MmapCsrError::PlatformMismatch);
+        }
+        if bytes[17] != Ix::TAG || bytes[18] != E::TAG {
+            return Err(MmapCsrError::TypeMismatch);
+        }
+        let node_count = usize::try_from(read_u64(bytes, 24));
+        let entry_count = usize::try_from(read_u64(bytes, 32));
+        let (node_count, entry_count) = match (node_count, entry_count) {
+            (Ok(n), Ok(m)) => (n, m),
+            _ => return Err(MmapCsrError::UnexpectedLength),
+        };
+        if Self::file_len(node_count, entry_count) != Some(bytes.len()) {
+            return Err(MmapCsrError::UnexpectedLength);
+        }
+        // Both are at most the length of the file.
+        let targets_start = Self::targets_start(node_count).unwrap();
+        let weights_start = Self::weights_start(node_count, entry_count).unwrap();
+        let graph = MmapCsr {
+            mmap,
+            node_count,
+            entry_count,
+            targets_start,
+            weights_start,
+            ty: PhantomData,
+        };
+        CsrSlice::new(graph.offsets(), graph.targets()).map_err(MmapCsrError::Invalid)?;
+        Ok(graph)
+    }
+
+    fn offsets(&self) -> &[usize] {
+        cast(&self.mmap, Self::offsets_start(), self.node_count + 1)
+    }
+
+    fn targets(&self) -> &[Ix] {
+        cast(&self.mmap, self.targets_start, self.entry_count)
+    }
+
+    fn weights(&self) -> &[E] {
+        cast(&self.mmap, self.weights_start, self.entry_count)
+    }
+
+    /// Return the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Return the number of edges, counting both directions of the edges of
+    /// an undirected graph.
+    pub fn edge_count(&self) -> usize {
+        self.entry_count
+    }
+
+    /// View the file as a graph.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn graph(&self) -> CsrSlice<'_, (), E, Ix> {
+        CsrSlice::from_parts_unchecked(self.offsets(), self.targets(), self.weights())
+    }
+}
@@ -0,0 +1,994 @@
+//! `MultiGraphMap<N, E, Ty>` is a graph datastructure where node values are
+//! mapping keys, and any number of edges may connect the same pair of nodes.
+
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    hash::{BuildHasher, Hash},
+    iter::{Copied, FromIterator},
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+    slice::Iter,
+};
+
+use hashbrown::HashSet;
+use indexmap::{
+    map::{Iter as IndexMapIter, Keys},
+    IndexMap,
+};
+
+use crate::{
+    data,
+    graph::{node_index, Graph},
+    graphmap::NodeTrait,
+    visit, Directed, Direction, EdgeType, Incoming, IntoWeightedEdge, Outgoing, Undirected,
+};
+
+#[cfg(feature = "std")]
+use std::collections::hash_map::RandomState;
+
+/// A `MultiGraphMap` with undirected edges.
+pub type UnMultiGraphMap<
+    N,
+    E,
+    #[cfg(not(feature = "std"))] S,
+    #[cfg(feature = "std")] S = RandomState,
+> = MultiGraphMap<N, E, Undirected, S>;
+/// A `MultiGraphMap` with directed edges.
+pub type DiMultiGraphMap<
+    N,
+    E,
+    #[cfg(not(feature = "std"))] S,
+    #[cfg(feature = "std")] S = RandomState,
+> = MultiGraphMap<N, E, Directed, S>;
+
+/// The key of an edge of a [`MultiGraphMap`].
+///
+/// Keys are handed out in increasing order and are never reused, so a key
+/// stays valid, and keeps referring to the same edge, until that edge is
+/// removed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EdgeKey(usize);
+
+impl EdgeKey {
+    /// Return the key as a `usize`.
+    #[inline]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Edge<N, E> {
+    source: N,
+    target: N,
+    weight: E,
+}
+
+/// `MultiGraphMap<N, E, Ty>` is a graph datastructure using an associative
+/// array of its node weights `N`, which allows parallel edges.
+///
+/// Like [`GraphMap`](crate::graphmap::GraphMap), the node weights `N` are
+/// the node identifiers, but adding an edge between two nodes that are
+/// already connected adds another edge instead of replacing the weight of
+/// the existing one. Each edge is identified by an [`EdgeKey`], which stays
+/// stable across the removal of other nodes and edges.
+///
+/// It uses an adjacency list of edge keys per node, and a map from each
+/// pair of nodes to the keys of the edges connecting them, using
+/// **O(|V| + |E|)** space, and allows finding the edges connecting two nodes
+/// in constant time.
+///
+/// `MultiGraphMap` is parameterized over:
+///
+/// - Associated data `N` for nodes and `E` for edges, called *weights*.
+/// - The node weight `N` must implement `Copy`, `Ord` and `Hash`, as for
+///   `GraphMap`, see [`NodeTrait`].
+/// - `E` can be of arbitrary type.
+/// - Edge type `Ty` that determines whether the graph edges are directed or
+///   undirected.
+///
+/// You can use the type aliases `UnMultiGraphMap` and `DiMultiGraphMap` for
+/// convenience.
+///
+/// Depends on crate feature `graphmap` (default).
+///
+/// ```
+/// use petgraph::multigraphmap::UnMultiGraphMap;
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// struct StationId(u32);
+///
+/// let mut network = UnMultiGraphMap::<StationId, &str>::new();
+/// let bus = network.add_edge(StationId(1), StationId(2), "bus");
+/// let tram = network.add_edge(StationId(2), StationId(1), "tram");
+/// assert_ne!(bus, tram);
+/// assert_eq!(network.edge_count(), 2);
+/// assert_eq!(network.edges_connecting(StationId(1), StationId(2)).count(), 2);
+///
+/// network.remove_edge(bus);
+/// assert_eq!(network[tram], "tram");
+/// ```
+#[derive(Clone)]
+pub struct MultiGraphMap<
+    N,
+    E,
+    Ty,
+    #[cfg(not(feature = "std"))] S,
+    #[cfg(feature = "std")] S = RandomState,
+> where
+    S: BuildHasher,
+{
+    nodes: IndexMap<N, Vec<EdgeKey>, S>,
+    edges: IndexMap<EdgeKey, Edge<N, E>, S>,
+    pairs: IndexMap<(N, N), Vec<EdgeKey>, S>,
+    next_key: usize,
+    ty: PhantomData<Ty>,
+}
+
+impl<N, E, Ty, S> fmt::Debug for MultiGraphMap<N, E, Ty, S>
+where
+    N: fmt::Debug,
+    E: fmt::Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MultiGraphMap")
+            .field("nodes", &DebugList(self.nodes.keys()))
+            .field("edges", &DebugList(self.edges.iter()))
+            .finish()
+    }
+}
+
+struct DebugList<I>(I);
+
+impl<I> fmt::Debug for DebugList<I>
+where
+    I: Iterator + Clone,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.0.clone()).finish()
+    }
+}
+
+impl<N, E, Ty, S> MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    /// Create a new `MultiGraphMap`
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self::default()
+    }
+
+    /// Create a new `MultiGraphMap` with estimated capacity.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self
+    where
+        S: Default,
+    {
+        Self {
+            nodes: IndexMap::with_capacity_and_hasher(nodes, S::default()),
+            edges: IndexMap::with_capacity_and_hasher(edges, S::default()),
+            pairs: IndexMap::with_capacity_and_hasher(edges, S::default()),
+            next_key: 0,
+            ty: PhantomData,
+        }
+    }
+
+    /// Create a new `MultiGraphMap` from an iterable of edges.
+    ///
+    /// Node values are taken directly from the list.
+    /// Edge weights `E` may either be specified in the list,
+    /// or they are filled with default values.
+    ///
+    /// Nodes are inserted automatically to match the edges, and every
+    /// element of the list adds an edge, even if it repeats a pair of nodes.
+    ///
+    /// ```
+    /// use petgraph::multigraphmap::DiMultiGraphMap;
+    ///
+    /// let gr = DiMultiGraphMap::<_, ()>::from_edges(&[(0, 1), (0, 1), (1, 2)]);
+    /// assert_eq!(gr.edge_count(), 3);
+    /// ```
+    pub fn from_edges<I>(iterable: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoWeightedEdge<E, NodeId = N>,
+        S: Default,
+    {
+        Self::from_iter(iterable)
+    }
+
+    /// Use their natural order to map the node pair (a, b) to a canonical
+    /// pair.
+    #[inline]
+    fn pair(a: N, b: N) -> (N, N) {
+        if Ty::is_directed() || a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Whether the graph has directed edges.
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Remove all nodes and edges.
+    ///
+    /// The keys of the removed edges are not reused.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+        self.pairs.clear();
+    }
+
+    /// Add node `n` to the graph.
+    pub fn add_node(&mut self, n: N) -> N {
+        self.nodes.entry(n).or_default();
+        n
+    }
+
+    /// Remove node `n` from the graph, with all its edges.
+    ///
+    /// Return `true` if it did exist.
+    ///
+    /// Computes in **O(e)** time, where **e** is the number of edges
+    /// connected to `n` and to its neighbors.
+    pub fn remove_node(&mut self, n: N) -> bool {
+        let keys = match self.nodes.get(&n) {
+            None => return false,
+            Some(keys) => keys.clone(),
+        };
+        for key in keys {
+            self.remove_edge(key);
+        }
+        self.nodes.swap_remove(&n);
+        true
+    }
+
+    /// Return `true` if the node is contained in the graph.
+    pub fn contains_node(&self, n: N) -> bool {
+        self.nodes.contains_key(&n)
+    }
+
+    /// Add an edge connecting `a` and `b` to the graph, with associated
+    /// data `weight`, and return its key. For a directed graph, the edge is
+    /// directed from `a` to `b`.
+    ///
+    /// Inserts nodes `a` and/or `b` if they aren't already part of the graph.
+    /// Existing edges between `a` and `b` are left untouched.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn add_edge(&mut self, a: N, b: N, weight: E) -> EdgeKey {
+        let key = EdgeKey(self.next_key);
+        self.next_key += 1;
+        self.nodes.entry(a).or_default().push(key);
+        if a != b {
+            self.nodes.entry(b).or_default().push(key);
+        }
+        self.pairs.entry(Self::pair(a, b)).or_default().push(key);
+        self.edges.insert(
+            key,
+            Edge {
+                source: a,
+                target: b,
+                weight,
+            },
+        );
+        key
+    }
+
+    /// Remove the edge of key `key` from the graph.
+    ///
+    /// Return the weight of the edge, or `None` if it didn't exist.
+    ///
+    /// Computes in **O(e)** time, where **e** is the number of edges
+    /// connected to its endpoints.
+    pub fn remove_edge(&mut self, key: EdgeKey) -> Option<E> {
+        let edge = self.edges.swap_remove(&key)?;
+        Self::remove_key(self.nodes.get_mut(&edge.source), key);
+        Self::remove_key(self.nodes.get_mut(&edge.target), key);
+        let pair = Self::pair(edge.source, edge.target);
+        if let Some(keys) = self.pairs.get_mut(&pair) {
+            keys.retain(|&k| k != key);
+            if keys.is_empty() {
+                self.pairs.swap_remove(&pair);
+            }
+        }
+        Some(edge.weight)
+    }
+
+    fn remove_key(keys: Option<&mut Vec<EdgeKey>>, key: EdgeKey) {
+        if let Some(keys) = keys {
+            if let Some(position) = keys.iter().position(|&k| k == key) {
+                keys.swap_remove(position);
+            }
+        }
+    }
+
+    /// Return `true` if at least one edge connects `a` with `b`.
+    pub fn contains_edge(&self, a: N, b: N) -> bool {
+        self.pairs.contains_key(&Self::pair(a, b))
+    }
+
+    /// Return `true` if the edge of key `key` is contained in the graph.
+    pub fn contains_edge_key(&self, key: EdgeKey) -> bool {
+        self.edges.contains_key(&key)
+    }
+
+    /// Return the source and target of the edge of key `key`, in the order
+    /// they were given to [`add_edge`](MultiGraphMap::add_edge).
+    pub fn edge_endpoints(&self, key: EdgeKey) -> Option<(N, N)> {
+        self.edges.get(&key).map(|edge| (edge.source, edge.target))
+    }
+
+    /// Return a reference to the weight of the edge of key `key`.
+    pub fn edge_weight(&self, key: EdgeKey) -> Option<&E> {
+        self.edges.get(&key).map(|edge| &edge.weight)
+    }
+
+    /// Return a mutable reference to the weight of the edge of key `key`.
+    pub fn edge_weight_mut(&mut self, key: EdgeKey) -> Option<&mut E> {
+        self.edges.get_mut(&key).map(|edge| &mut edge.weight)
+    }
+
+    /// Return an iterator over the nodes of the graph.
+    ///
+    /// Iterator element type is `N`.
+    pub fn nodes(&self) -> Nodes<'_, N> {
+        Nodes {
+            iter: self.nodes.keys().copied(),
+        }
+    }
+
+    /// Return an iterator over all edges connecting `a` with `b`.
+    ///
+    /// The edges are oriented from `a` to `b`: for a directed graph, these
+    /// are only the edges from `a` to `b`.
+    ///
+    /// Iterator element type is `EdgeReference<N, E>`.
+    pub fn edges_connecting(&self, a: N, b: N) -> EdgesConnecting<'_, N, E, S> {
+        let keys = match self.pairs.get(&Self::pair(a, b)) {
+            Some(keys) => &keys[..],
+            None => &[],
+        };
+        EdgesConnecting {
+            a,
+            b,
+            iter: keys.iter(),
+            edges: &self.edges,
+        }
+    }
+
+    /// Return an iterator of the neighbors of node `a`, once per edge.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.
+    ///
+    /// Iterator element type is `N`.
+    pub fn neighbors(&self, a: N) -> Neighbors<'_, N, E, Ty, S> {
+        self.neighbors_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of the neighbors of node `a` in direction `dir`,
+    /// once per edge.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.
+    ///
+    /// Iterator element type is `N`.
+    pub fn neighbors_directed(&self, a: N, dir: Direction) -> Neighbors<'_, N, E, Ty, S> {
+        Neighbors {
+            iter: self.edges_directed(a, dir),
+        }
+    }
+
+    /// Return an iterator of the edges of node `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges connected to `a`, with `a` as source.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.
+    ///
+    /// Iterator element type is `EdgeReference<N, E>`.
+    pub fn edges(&self, a: N) -> Edges<'_, N, E, Ty, S> {
+        self.edges_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of the edges of node `a` in direction `dir`.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`, `Outgoing`: All edges connected to `a`, with `a` as
+    ///   source.
+    /// - `Undirected`, `Incoming`: All edges connected to `a`, with `a` as
+    ///   target.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.
+    ///
+    /// Iterator element type is `EdgeReference<N, E>`.
+    pub fn edges_directed(&self, a: N, dir: Direction) -> Edges<'_, N, E, Ty, S> {
+        let keys = match self.nodes.get(&a) {
+            Some(keys) => &keys[..],
+            None => &[],
+        };
+        Edges {
+            node: a,
+            dir,
+            iter: keys.iter(),
+            edges: &self.edges,
+            ty: PhantomData,
+        }
+    }
+
+    /// Return an iterator over all edges of the graph, in no particular
+    /// order.
+    ///
+    /// Iterator element type is `EdgeReference<N, E>`.
+    pub fn all_edges(&self) -> AllEdges<'_, N, E> {
+        AllEdges {
+            iter: self.edges.iter(),
+        }
+    }
+
+    /// Return the number of edges from `a`, counting self loops once.
+    ///
+    /// For an undirected graph, this is the number of edges connected to
+    /// `a`.
+    pub fn out_degree(&self, a: N) -> usize {
+        self.edges(a).count()
+    }
+
+    /// Return the number of edges to `a`, counting self loops once.
+    ///
+    /// For an undirected graph, this is the number of edges connected to
+    /// `a`.
+    pub fn in_degree(&self, a: N) -> usize {
+        self.edges_directed(a, Incoming).count()
+    }
+
+    /// Return a `Graph` that corresponds to this `MultiGraphMap`, with all
+    /// its parallel edges.
+    ///
+    /// The node weights `N` are used as node weights in the resulting
+    /// `Graph`, and the edge keys are lost.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// **Panics** if the number of nodes or edges does not fit with
+    /// the resulting graph's index type.
+    #[track_caller]
+    pub fn into_graph<Ix>(self) -> Graph<N, E, Ty, Ix>
+    where
+        Ix: crate::graph::IndexType,
+    {
+        let mut gr = Graph::with_capacity(self.node_count(), self.edge_count());
+        for &node in self.nodes.keys() {
+            gr.add_node(node);
+        }
+        for (_, edge) in self.edges {
+            let ai = self.nodes.get_index_of(&edge.source).unwrap();
+            let bi = self.nodes.get_index_of(&edge.target).unwrap();
+            gr.add_edge(node_index(ai), node_index(bi), edge.weight);
+        }
+        gr
+    }
+}
+
+impl<N, E, Ty, S> Default for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher + Default,
+{
+    fn default() -> Self {
+        MultiGraphMap::with_capacity(0, 0)
+    }
+}
+
+/// Create a new `MultiGraphMap` from an iterable of edges.
+impl<N, E, Ty, Item, S> FromIterator<Item> for MultiGraphMap<N, E, Ty, S>
+where
+    Item: IntoWeightedEdge<E, NodeId = N>,
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I>(iterable: I) -> Self
+    where
+        I: IntoIterator<Item = Item>,
+    {
+        let iter = iterable.into_iter();
+        let (low, _) = iter.size_hint();
+        let mut g = Self::with_capacity(0, low);
+        g.extend(iter);
+        g
+    }
+}
+
+/// Extend the graph from an iterable of edges.
+///
+/// Nodes are inserted automatically to match the edges.
+impl<N, E, Ty, Item, S> Extend<Item> for MultiGraphMap<N, E, Ty, S>
+where
+    Item: IntoWeightedEdge<E, NodeId = N>,
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn extend<I>(&mut self, iterable: I)
+    where
+        I: IntoIterator<Item = Item>,
+    {
+        let iter = iterable.into_iter();
+        let (low, _) = iter.size_hint();
+        self.edges.reserve(low);
+
+        for elt in iter {
+            let (source, target, weight) = elt.into_weighted_edge();
+            self.add_edge(source, target, weight);
+        }
+    }
+}
+
+/// Index the `MultiGraphMap` by `EdgeKey` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, Ty, S> Index<EdgeKey> for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Output = E;
+    fn index(&self, key: EdgeKey) -> &E {
+        self.edge_weight(key)
+            .expect("MultiGraphMap::index: no such edge")
+    }
+}
+
+/// Index the `MultiGraphMap` by `EdgeKey` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, Ty, S> IndexMut<EdgeKey> for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn index_mut(&mut self, key: EdgeKey) -> &mut E {
+        self.edge_weight_mut(key)
+            .expect("MultiGraphMap::index: no such edge")
+    }
+}
+
+/// A reference to an edge of a [`MultiGraphMap`].
+#[derive(Debug)]
+pub struct EdgeReference<'a, N, E> {
+    source: N,
+    target: N,
+    key: EdgeKey,
+    weight: &'a E,
+}
+
+impl<N: Copy, E> Clone for EdgeReference<'_, N, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N: Copy, E> Copy for EdgeReference<'_, N, E> {}
+
+impl<N: Copy, E> visit::EdgeRef for EdgeReference<'_, N, E> {
+    type NodeId = N;
+    type EdgeId = EdgeKey;
+    type Weight = E;
+
+    fn source(&self) -> N {
+        self.source
+    }
+    fn target(&self) -> N {
+        self.target
+    }
+    fn weight(&self) -> &E {
+        self.weight
+    }
+    fn id(&self) -> EdgeKey {
+        self.key
+    }
+}
+
+iterator_wrap! {
+    impl (Iterator DoubleEndedIterator ExactSizeIterator) for
+    #[derive(Debug, Clone)]
+    struct Nodes <'a, N> where { N: 'a + NodeTrait }
+    item: N,
+    iter: Copied<Keys<'a, N, Vec<EdgeKey>>>,
+}
+
+/// Iterator over the edges of a node of a [`MultiGraphMap`].
+///
+/// Created with [`.edges()`](MultiGraphMap::edges) and
+/// [`.edges_directed()`](MultiGraphMap::edges_directed).
+#[derive(Debug, Clone)]
+pub struct Edges<'a, N, E, Ty, S>
+where
+    S: BuildHasher,
+{
+    node: N,
+    dir: Direction,
+    iter: Iter<'a, EdgeKey>,
+    edges: &'a IndexMap<EdgeKey, Edge<N, E>, S>,
+    ty: PhantomData<Ty>,
+}
+
+impl<'a, N, E, Ty, S> Iterator for Edges<'a, N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Item = EdgeReference<'a, N, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let edges = self.edges;
+        for &key in &mut self.iter {
+            let edge = &edges[&key];
+            let (source, target) = if Ty::is_directed() {
+                let endpoint = match self.dir {
+                    Outgoing => edge.source,
+                    Incoming => edge.target,
+                };
+                if endpoint != self.node {
+                    continue;
+                }
+                (edge.source, edge.target)
+            } else {
+                let other = if edge.source == self.node {
+                    edge.target
+                } else {
+                    edge.source
+                };
+                match self.dir {
+                    Outgoing => (self.node, other),
+                    Incoming => (other, self.node),
+                }
+            };
+            return Some(EdgeReference {
+                source,
+                target,
+                key,
+                weight: &edge.weight,
+            });
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if Ty::is_directed() {
+            (0, upper)
+        } else {
+            (lower, upper)
+        }
+    }
+}
+
+/// Iterator over the neighbors of a node of a [`MultiGraphMap`].
+///
+/// Created with [`.neighbors()`](MultiGraphMap::neighbors) and
+/// [`.neighbors_directed()`](MultiGraphMap::neighbors_directed).
+#[derive(Debug, Clone)]
+pub struct Neighbors<'a, N, E, Ty, S>
+where
+    S: BuildHasher,
+{
+    iter: Edges<'a, N, E, Ty, S>,
+}
+
+impl<N, E, Ty, S> Iterator for Neighbors<'_, N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Item = N;
+    fn next(&mut self) -> Option<N> {
+        let dir = self.iter.dir;
+        self.iter.next().map(|edge| match dir {
+            Outgoing => edge.target,
+            Incoming => edge.source,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator over the edges connecting two nodes of a [`MultiGraphMap`].
+///
+/// Created with [`.edges_connecting()`](MultiGraphMap::edges_connecting).
+#[derive(Debug, Clone)]
+pub struct EdgesConnecting<'a, N, E, S>
+where
+    S: BuildHasher,
+{
+    a: N,
+    b: N,
+    iter: Iter<'a, EdgeKey>,
+    edges: &'a IndexMap<EdgeKey, Edge<N, E>, S>,
+}
+
+impl<'a, N, E, S> Iterator for EdgesConnecting<'a, N, E, S>
+where
+    N: NodeTrait,
+    S: BuildHasher,
+{
+    type Item = EdgeReference<'a, N, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let edges = self.edges;
+        self.iter.next().map(|&key| EdgeReference {
+            source: self.a,
+            target: self.b,
+            key,
+            weight: &edges[&key].weight,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<N, E, S> ExactSizeIterator for EdgesConnecting<'_, N, E, S>
+where
+    N: NodeTrait,
+    S: BuildHasher,
+{
+}
+
+/// Iterator over all edges of a [`MultiGraphMap`].
+///
+/// Created with [`.all_edges()`](MultiGraphMap::all_edges).
+#[derive(Debug, Clone)]
+pub struct AllEdges<'a, N, E> {
+    iter: IndexMapIter<'a, EdgeKey, Edge<N, E>>,
+}
+
+impl<'a, N, E> Iterator for AllEdges<'a, N, E>
+where
+    N: NodeTrait,
+{
+    type Item = EdgeReference<'a, N, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(&key, edge)| EdgeReference {
+            source: edge.source,
+            target: edge.target,
+            key,
+            weight: &edge.weight,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<N, E> DoubleEndedIterator for AllEdges<'_, N, E>
+where
+    N: NodeTrait,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(&key, edge)| EdgeReference {
+            source: edge.source,
+            target: edge.target,
+            key,
+            weight: &edge.weight,
+        })
+    }
+}
+
+impl<N, E> ExactSizeIterator for AllEdges<'_, N, E> where N: NodeTrait {}
+
+impl<N, E, Ty, S> visit::GraphBase for MultiGraphMap<N, E, Ty, S>
+where
+    N: Copy + PartialEq,
+    S: BuildHasher,
+{
+    type NodeId = N;
+    type EdgeId = EdgeKey;
+}
+
+impl<N, E, Ty, S> visit::Data for MultiGraphMap<N, E, Ty, S>
+where
+    N: Copy + PartialEq,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<N, E, Ty, S> visit::Visitable for MultiGraphMap<N, E, Ty, S>
+where
+    N: Copy + Ord + Hash,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Map = HashSet<N>;
+    fn visit_map(&self) -> HashSet<N> {
+        HashSet::with_capacity(self.node_count())
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+    }
+}
+
+impl<N, E, Ty, S> visit::GraphProp for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type EdgeType = Ty;
+}
+
+impl<'a, N, E: 'a, Ty, S> visit::IntoNodeIdentifiers for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type NodeIdentifiers = Nodes<'a, N>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.nodes()
+    }
+}
+
+impl<N, E, Ty, S> visit::NodeCount for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn node_count(&self) -> usize {
+        (*self).node_count()
+    }
+}
+
+impl<N, E, Ty, S> visit::NodeIndexable for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn node_bound(&self) -> usize {
+        self.node_count()
+    }
+    fn to_index(&self, ix: Self::NodeId) -> usize {
+        self.nodes.get_index_of(&ix).expect("node not found")
+    }
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        assert!(
+            ix < self.nodes.len(),
+            "The requested index {ix} is out-of-bounds."
+        );
+        let (&key, _) = self.nodes.get_index(ix).unwrap();
+        key
+    }
+}
+
+impl<N, E, Ty, S> visit::NodeCompactIndexable for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+}
+
+impl<'a, N: 'a, E, Ty, S> visit::IntoNeighbors for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Neighbors = Neighbors<'a, N, E, Ty, S>;
+    fn neighbors(self, n: Self::NodeId) -> Self::Neighbors {
+        self.neighbors(n)
+    }
+}
+
+impl<'a, N: 'a, E, Ty, S> visit::IntoNeighborsDirected for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type NeighborsDirected = Neighbors<'a, N, E, Ty, S>;
+    fn neighbors_directed(self, n: N, dir: Direction) -> Self::NeighborsDirected {
+        self.neighbors_directed(n, dir)
+    }
+}
+
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdgeReferences for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type EdgeRef = EdgeReference<'a, N, E>;
+    type EdgeReferences = AllEdges<'a, N, E>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        self.all_edges()
+    }
+}
+
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdges for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type Edges = Edges<'a, N, E, Ty, S>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        self.edges(a)
+    }
+}
+
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdgesDirected for &'a MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    type EdgesDirected = Edges<'a, N, E, Ty, S>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        self.edges_directed(a, dir)
+    }
+}
+
+impl<N, E, Ty, S> visit::EdgeCount for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_count()
+    }
+}
+
+impl<N, E, Ty, S> data::DataMap for MultiGraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn edge_weight(&self, id: Self::EdgeId) -> Option<&Self::EdgeWeight> {
+        self.edge_weight(id)
+    }
+
+    fn node_weight(&self, id: Self::NodeId) -> Option<&Self::NodeWeight> {
+        self.nodes.get_key_value(&id).map(|(k, _)| k)
+    }
+}
@@ -1,16 +1,49 @@
 //! Operators for creating new graphs from existing ones.
-use super::graph::{Graph, IndexType};
-use super::EdgeType;
-use crate::visit::IntoNodeReferences;
+//!
+//! The [graph products][1] combine two graphs `g1` and `g2` into a graph
+//! whose nodes are the pairs `(u, v)` of a node `u` of `g1` and a node `v`
+//! of `g2`. The pair `(u, v)` is the node of index `u * m + v`, where `m` is
+//! the number of nodes of `g2`, and its weight is given by a closure of the
+//! weights of `u` and `v`. The products differ in which pairs are adjacent.
+//!
+//! For directed graphs, the edges of a product follow the direction of the
+//! edges of `g1` and `g2`. Self loops of the inputs count as a node being
+//! adjacent to itself, and parallel edges give parallel edges.
+//!
+//! The set operations, such as [`union`] and [`intersection`], combine two
+//! graphs whose nodes are identified by their weights, or by a key computed
+//! from their weights. The [`disjoint_union`] and the [`join`] instead keep
+//! the nodes of both graphs apart.
+//!
+//! The [`quotient_graph`] collapses the blocks of a partition of the nodes
+//! of a graph into single nodes, and [`subgraph`] and [`edge_subgraph`]
+//! extract the parts of a graph induced by some of its nodes or edges.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Graph_product
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use fixedbitset::FixedBitSet;
+use hashbrown::{HashMap, HashSet};
+
+use super::graph::{EdgeIndex, Graph, IndexType, NodeIndex};
+use super::{EdgeType, Outgoing};
+use crate::data::Create;
+use crate::visit::{
+    Data, EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers,
+    IntoNodeReferences, NodeIndexable, NodeRef,
+};
 
 /// \[Generic\] complement of the graph
 ///
 /// Computes the graph complement of the input Graph and stores it
 /// in the provided empty output Graph.
 ///
-/// The function does not create self-loops.
+/// The function does not create self-loops. For an undirected graph, each
+/// edge of the complement is added twice, once from each of its endpoints;
+/// [`complement_graph`] adds it once.
 ///
-/// Computes in **O(|V|^2*log(|V|))** time (average).
+/// Computes in **O(|V|^2 + |E|)** time.
 ///
 /// Returns the complement.
 ///
@@ -73,11 +106,1298 @@ pub fn complement<N, E, Ty, Ix>(
     for (_node, weight) in input.node_references() {
         output.add_node(weight.clone());
     }
+    add_complement_edges(input, output, weight, false);
+}
+
+/// Add to `output` an edge between every two distinct nodes that are not
+/// adjacent in `input`. With `once`, an undirected edge is only added from
+/// its smaller endpoint.
+fn add_complement_edges<N, E, Ty, Ix>(
+    input: &Graph<N, E, Ty, Ix>,
+    output: &mut Graph<N, E, Ty, Ix>,
+    weight: E,
+    once: bool,
+) where
+    Ty: EdgeType,
+    Ix: IndexType,
+    E: Clone,
+{
+    let mut adjacent = FixedBitSet::with_capacity(input.node_count());
     for x in input.node_indices() {
-        for y in input.node_indices() {
-            if x != y && !input.contains_edge(x, y) {
-                output.add_edge(x, y, weight.clone());
+        adjacent.clear();
+        adjacent.extend(input.neighbors(x).map(|y| y.index()));
+        let first = if once && !Ty::is_directed() {
+            x.index() + 1
+        } else {
+            0
+        };
+        for y in first..input.node_count() {
+            if y != x.index() && !adjacent[y] {
+                output.add_edge(x, NodeIndex::new(y), weight.clone());
             }
         }
     }
 }
+
+/// \[Generic\] Return the complement of a graph: the graph on the same
+/// nodes, where two distinct nodes are adjacent exactly when they are not
+/// adjacent in `input`.
+///
+/// The nodes keep their indices and weights, and every edge has the weight
+/// `weight`. There are no self loops. For a directed graph, the edge
+/// `a -> b` is in the complement when it is not in `input`, whatever the
+/// edge `b -> a`. Unlike [`complement`], this adds each edge of an
+/// undirected complement once.
+///
+/// This turns, for example, a maximum independent set into a maximum
+/// clique of the complement.
+///
+/// # Complexity
+/// * Time complexity: **O(|V|² + |E|)**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::operator::complement_graph;
+///
+/// // The complement of the 5-cycle is another 5-cycle.
+/// let cycle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// let complement = complement_graph(&cycle, ());
+/// assert_eq!(complement.edge_count(), 5);
+/// assert!(complement.node_indices().all(|v| complement.neighbors(v).count() == 2));
+/// assert!(complement.contains_edge(0.into(), 2.into()));
+/// ```
+pub fn complement_graph<N, E, Ty, Ix>(input: &Graph<N, E, Ty, Ix>, weight: E) -> Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    E: Clone,
+    N: Clone,
+{
+    let mut output = Graph::with_capacity(input.node_count(), 0);
+    for (_node, weight) in input.node_references() {
+        output.add_node(weight.clone());
+    }
+    add_complement_edges(input, &mut output, weight, true);
+    output
+}
+
+/// \[Generic\] Return the [line graph][1] of a graph, whose nodes are the
+/// edges of `input`.
+///
+/// The node of index `i` is the edge of index `i` of `input`, with its
+/// weight. In an undirected graph, two edges are adjacent when they share
+/// an endpoint, and they are linked once even if they share both. In a
+/// directed graph, there is an edge from `a -> b` to every edge `b -> c`,
+/// so a self loop is followed by itself. The weight of each edge of the
+/// line graph is the weight of the shared node.
+///
+/// This turns, for example, an edge coloring into a node coloring of the
+/// line graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + Σ deg(v)²)**.
+/// * Auxiliary space: **O(Σ deg(v)²)**.
+///
+/// where **|V|** is the number of nodes and **deg(v)** is the degree of `v`.
+///
+/// [1]: https://en.wikipedia.org/wiki/Line_graph
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::dsatur_coloring;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::operator::line_graph;
+///
+/// // A star with three edges: each pair of edges shares the center.
+/// let star = UnGraph::<char, ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let lines = line_graph(&star);
+/// assert_eq!(lines.node_count(), 3);
+/// assert_eq!(lines.edge_count(), 3);
+///
+/// // A proper edge coloring of the star needs three colors.
+/// let (_, colors) = dsatur_coloring(&lines);
+/// assert_eq!(colors, 3);
+/// ```
+pub fn line_graph<N, E, Ty, Ix>(input: &Graph<N, E, Ty, Ix>) -> Graph<E, N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    E: Clone,
+    N: Clone,
+{
+    let mut output = Graph::with_capacity(input.edge_count(), 0);
+    for weight in input.edge_weights() {
+        output.add_node(weight.clone());
+    }
+    let node = |e: EdgeIndex<Ix>| NodeIndex::new(e.index());
+    if Ty::is_directed() {
+        for e in input.edge_references() {
+            for f in input.edges_directed(e.target(), Outgoing) {
+                output.add_edge(node(e.id()), node(f.id()), input[e.target()].clone());
+            }
+        }
+    } else {
+        let mut linked = HashSet::new();
+        let mut incident = Vec::new();
+        for v in input.node_indices() {
+            incident.clear();
+            incident.extend(input.edges(v).map(|e| e.id()));
+            // A self loop may be listed twice.
+            incident.sort_unstable();
+            incident.dedup();
+            for (i, &e) in incident.iter().enumerate() {
+                for &f in &incident[i + 1..] {
+                    if linked.insert((e, f)) {
+                        output.add_edge(node(e), node(f), input[v].clone());
+                    }
+                }
+            }
+        }
+    }
+    output
+}
+
+/// The edges of a graph as pairs of node indices: both orientations of each
+/// edge of an undirected graph, except for self loops.
+fn arcs<N, E, Ty, Ix>(graph: &Graph<N, E, Ty, Ix>) -> Vec<(usize, usize)>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut arcs = Vec::with_capacity(2 * graph.edge_count());
+    for edge in graph.edge_references() {
+        let (a, b) = (edge.source().index(), edge.target().index());
+        arcs.push((a, b));
+        if !Ty::is_directed() && a != b {
+            arcs.push((b, a));
+        }
+    }
+    arcs
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Product {
+    Cartesian,
+    Tensor,
+    Strong,
+    Lexicographic,
+}
+
+fn product<N1, E1, N2, E2, N, E, Ty, Ix1, Ix2, F>(
+    g1: &Graph<N1, E1, Ty, Ix1>,
+    g2: &Graph<N2, E2, Ty, Ix2>,
+    kind: Product,
+    mut node_weight: F,
+) -> Graph<N, E, Ty>
+where
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    E: Default,
+    F: FnMut(&N1, &N2) -> N,
+{
+    let (arcs1, arcs2) = (arcs(g1), arcs(g2));
+    let n2 = g2.node_count();
+    let mut graph = Graph::with_capacity(g1.node_count() * n2, 0);
+    for u in g1.node_weights() {
+        for v in g2.node_weights() {
+            graph.add_node(node_weight(u, v));
+        }
+    }
+    // Add the arc between two pairs, once for an undirected graph.
+    let mut add = |(a1, a2): (usize, usize), (b1, b2): (usize, usize)| {
+        let (a, b) = (a1 * n2 + a2, b1 * n2 + b2);
+        if Ty::is_directed() || a <= b {
+            graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), E::default());
+        }
+    };
+    if kind != Product::Tensor {
+        // u == u' and v ~ v'.
+        for u in 0..g1.node_count() {
+            for &(a, b) in &arcs2 {
+                add((u, a), (u, b));
+            }
+        }
+    }
+    if kind == Product::Cartesian || kind == Product::Strong {
+        // u ~ u' and v == v'.
+        for &(a, b) in &arcs1 {
+            for v in 0..n2 {
+                add((a, v), (b, v));
+            }
+        }
+    }
+    if kind == Product::Tensor || kind == Product::Strong {
+        // u ~ u' and v ~ v'.
+        for &(a1, b1) in &arcs1 {
+            for &(a2, b2) in &arcs2 {
+                add((a1, a2), (b1, b2));
+            }
+        }
+    }
+    if kind == Product::Lexicographic {
+        // u ~ u', whatever v and v'.
+        for &(a, b) in &arcs1 {
+            for v in 0..n2 {
+                for w in 0..n2 {
+                    add((a, v), (b, w));
+                }
+            }
+        }
+    }
+    graph
+}
+
+/// \[Generic\] Return the [Cartesian product][1] of two graphs: `(u, v)` and
+/// `(u', v')` are adjacent when `u == u'` and `v` is adjacent to `v'`, or
+/// when `u` is adjacent to `u'` and `v == v'`.
+///
+/// See the [module documentation](self) for the numbering of the nodes. The
+/// edges get default weights.
+///
+/// # Arguments
+/// * `g1`, `g2`: the factors of the product.
+/// * `node_weight`: closure that returns the weight of the node `(u, v)`
+///   from the weights of `u` and `v`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V1| · |E2| + |E1| · |V2|)**.
+/// * Auxiliary space: **O(|E1| + |E2|)**.
+///
+/// where **|V1|**, **|V2|** are the numbers of nodes and **|E1|**, **|E2|**
+/// the numbers of edges of the factors.
+///
+/// [1]: https://en.wikipedia.org/wiki/Cartesian_product_of_graphs
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::operator::cartesian_product;
+///
+/// // The product of two paths is a grid.
+/// let path = UnGraph::<u32, ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let grid: UnGraph<(u32, u32), ()> = cartesian_product(&path, &path, |&u, &v| (u, v));
+/// assert_eq!((grid.node_count(), grid.edge_count()), (9, 12));
+/// ```
+pub fn cartesian_product<N1, E1, N2, E2, N, E, Ty, Ix1, Ix2, F>(
+    g1: &Graph<N1, E1, Ty, Ix1>,
+    g2: &Graph<N2, E2, Ty, Ix2>,
+    node_weight: F,
+) -> Graph<N, E, Ty>
+where
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    E: Default,
+    F: FnMut(&N1, &N2) -> N,
+{
+    product(g1, g2, Product::Cartesian, node_weight)
+}
+
+/// \[Generic\] Return the [tensor product][1], or categorical product, of
+/// two graphs: `(u, v)` and `(u', v')` are adjacent when `u` is adjacent to
+/// `u'` and `v` is adjacent to `v'`.
+///
+/// See the [module documentation](self) for the numbering of the nodes. The
+/// edges get default weights.
+///
+/// # Arguments
+/// * `g1`, `g2`: the factors of the product.
+/// * `node_weight`: closure that returns the weight of the node `(u, v)`
+///   from the weights of `u` and `v`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V1| · |V2| + |E1| · |E2|)**.
+/// * Auxiliary space: **O(|E1| + |E2|)**.
+///
+/// where **|V1|**, **|V2|** are the numbers of nodes and **|E1|**, **|E2|**
+/// the numbers of edges of the factors.
+///
+/// [1]: https://en.wikipedia.org/wiki/Tensor_product_of_graphs
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::connected_components;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::operator::tensor_product;
+///
+/// // The tensor product of a bipartite graph with itself is disconnected.
+/// let edge = UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let product: UnGraph<(), ()> = tensor_product(&edge, &edge, |_, _| ());
+/// assert_eq!(product.edge_count(), 2);
+/// assert_eq!(connected_components(&product), 2);
+/// ```
+pub fn tensor_product<N1, E1, N2, E2, N, E, Ty, Ix1, Ix2, F>(
+    g1: &Graph<N1, E1, Ty, Ix1>,
+    g2: &Graph<N2, E2, Ty, Ix2>,
+    node_weight: F,
+) -> Graph<N, E, Ty>
+where
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    E: Default,
+    F: FnMut(&N1, &N2) -> N,
+{
+    product(g1, g2, Product::Tensor, node_weight)
+}
+
+/// \[Generic\] Return the [strong product][1] of two graphs: the union of
+/// their [Cartesian](cartesian_product) and [tensor](tensor_product)
+/// products.
+///
+/// See the [module documentation](self) for the numbering of the nodes. The
+/// edges get default weights.
+///
+/// # Arguments
+/// * `g1`, `g2`: the factors of the product.
+/// * `node_weight`: closure that returns the weight of the node `(u, v)`
+///   from the weights of `u` and `v`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V1| · |E2| + |E1| · |V2| + |E1| · |E2|)**.
+/// * Auxiliary space: **O(|E1| + |E2|)**.
+///
+/// where **|V1|**, **|V2|** are the numbers of nodes and **|E1|**, **|E2|**
+/// the numbers of edges of the factors.
+///
+/// [1]: https://en.wikipedia.org/wiki/Strong_product_of_graphs
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::operator::strong_product;
+///
+/// // The moves of a king on a 3 × 3 board.
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let board: UnGraph<(), ()> = strong_product(&path, &path, |_, _| ());
+/// assert_eq!(board.neighbors(NodeIndex::new(4)).count(), 8);
+/// ```
+pub fn strong_product<N1, E1, N2, E2, N, E, Ty, Ix1, Ix2, F>(
+    g1: &Graph<N1, E1, Ty, Ix1>,
+    g2: &Graph<N2, E2, Ty, Ix2>,
+    node_weight: F,
+) -> Graph<N, E, Ty>
+where
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    E: Default,
+    F: FnMut(&N1, &N2) -> N,
+{
+    product(g1, g2, Product::Strong, node_weight)
+}
+
+/// \[Generic\] Return the [lexicographic product][1], or composition, of two
+/// graphs: `(u, v)` and `(u', v')` are adjacent when `u` is adjacent to
+/// `u'`, or when `u == u'` and `v` is adjacent to `v'`.
+///
+/// Each node of `g1` is replaced by a copy of `g2`, and each edge of `g1` by
+/// the edges between all the nodes of the two copies. See the
+/// [module documentation](self) for the numbering of the nodes. The edges
+/// get default weights.
+///
+/// # Arguments
+/// * `g1`, `g2`: the factors of the product.
+/// * `node_weight`: closure that returns the weight of the node `(u, v)`
+///   from the weights of `u` and `v`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V1| · |E2| + |E1| · |V2|²)**.
+/// * Auxiliary space: **O(|E1| + |E2|)**.
+///
+/// where **|V1|**, **|V2|** are the numbers of nodes and **|E1|**, **|E2|**
+/// the numbers of edges of the factors.
+///
+/// [1]: https://en.wikipedia.org/wiki/Lexicographic_product_of_graphs
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::operator::lexicographic_product;
+///
+/// // Replacing both nodes of an edge by a triangle gives a complete graph.
+/// let edge = UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let triangle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let product: UnGraph<(), ()> = lexicographic_product(&edge, &triangle, |_, _| ());
+/// assert_eq!(product.edge_count(), 15);
+/// ```
+pub fn lexicographic_product<N1, E1, N2, E2, N, E, Ty, Ix1, Ix2, F>(
+    g1: &Graph<N1, E1, Ty, Ix1>,
+    g2: &Graph<N2, E2, Ty, Ix2>,
+    node_weight: F,
+) -> Graph<N, E, Ty>
+where
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    E: Default,
+    F: FnMut(&N1, &N2) -> N,
+{
+    product(g1, g2, Product::Lexicographic, node_weight)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SetOperation {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// The node keys of a graph, and the first edge between each pair of keys.
+struct KeyedGraph<'a, K, E> {
+    keys: Vec<K>,
+    edges: Vec<(usize, usize, &'a E)>,
+}
+
+impl<'a, K: Eq + Hash, E> KeyedGraph<'a, K, E> {
+    fn new<N, Ty, Ix, F>(graph: &'a Graph<N, E, Ty, Ix>, key: &mut F) -> Self
+    where
+        Ty: EdgeType,
+        Ix: IndexType,
+        F: FnMut(&N) -> K,
+    {
+        let keys: Vec<K> = graph.node_weights().map(key).collect();
+        let mut edges = Vec::new();
+        {
+            let mut seen = HashSet::new();
+            for edge in graph.edge_references() {
+                let (a, b) = (edge.source().index(), edge.target().index());
+                if seen.insert((&keys[a], &keys[b])) {
+                    if !Ty::is_directed() {
+                        seen.insert((&keys[b], &keys[a]));
+                    }
+                    edges.push((a, b, edge.weight()));
+                }
+            }
+        }
+        KeyedGraph { keys, edges }
+    }
+
+    /// Return the set of the pairs of keys of the edges, in both
+    /// orientations for an undirected graph.
+    fn edge_keys(&self, directed: bool) -> HashSet<(&K, &K)> {
+        let mut set = HashSet::with_capacity(self.edges.len());
+        for &(a, b, _) in &self.edges {
+            set.insert((&self.keys[a], &self.keys[b]));
+            if !directed {
+                set.insert((&self.keys[b], &self.keys[a]));
+            }
+        }
+        set
+    }
+}
+
+fn set_operation<N, E, Ty, Ix1, Ix2, K, F>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+    mut key: F,
+    operation: SetOperation,
+) -> Graph<N, E, Ty>
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    K: Eq + Hash,
+    F: FnMut(&N) -> K,
+{
+    use SetOperation::*;
+    let k1 = KeyedGraph::new(g1, &mut key);
+    let k2 = KeyedGraph::new(g2, &mut key);
+    let directed = Ty::is_directed();
+    let (edges1, edges2) = (k1.edge_keys(directed), k2.edge_keys(directed));
+    let nodes2: HashSet<&K> = k2.keys.iter().collect();
+
+    let mut graph = Graph::with_capacity(0, 0);
+    let mut index = HashMap::new();
+    for (k, weight) in k1.keys.iter().zip(g1.node_weights()) {
+        if (operation != Intersection || nodes2.contains(k)) && !index.contains_key(k) {
+            index.insert(k, graph.add_node(weight.clone()));
+        }
+    }
+    if operation == Union || operation == SymmetricDifference {
+        for (k, weight) in k2.keys.iter().zip(g2.node_weights()) {
+            if !index.contains_key(k) {
+                index.insert(k, graph.add_node(weight.clone()));
+            }
+        }
+    }
+
+    let mut add_edges = |keyed: &KeyedGraph<'_, K, E>, keep: &dyn Fn(&K, &K) -> bool| {
+        for &(a, b, weight) in &keyed.edges {
+            let (a, b) = (&keyed.keys[a], &keyed.keys[b]);
+            if keep(a, b) {
+                graph.add_edge(index[a], index[b], weight.clone());
+            }
+        }
+    };
+    let in_first = |a: &K, b: &K| edges1.contains(&(a, b));
+    let in_second = |a: &K, b: &K| edges2.contains(&(a, b));
+    match operation {
+        Union => {
+            add_edges(&k1, &|_, _| true);
+            add_edges(&k2, &|a, b| !in_first(a, b));
+        }
+        Intersection => add_edges(&k1, &in_second),
+        Difference => add_edges(&k1, &|a, b| !in_second(a, b)),
+        SymmetricDifference => {
+            add_edges(&k1, &|a, b| !in_second(a, b));
+            add_edges(&k2, &|a, b| !in_first(a, b));
+        }
+    }
+    graph
+}
+
+/// \[Generic\] Return the union of two graphs whose nodes are identified by
+/// their weights: the nodes and edges of either of them.
+///
+/// See [`union_by_key`] for the details.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::operator::union;
+///
+/// let mut roads = UnGraph::<&str, u32>::new_undirected();
+/// let a = roads.add_node("Ashford");
+/// let b = roads.add_node("Barton");
+/// roads.add_edge(a, b, 12);
+///
+/// let mut rails = UnGraph::<&str, u32>::new_undirected();
+/// let b = rails.add_node("Barton");
+/// let c = rails.add_node("Crewe");
+/// rails.add_edge(b, c, 30);
+///
+/// let network = union(&roads, &rails);
+/// assert_eq!(network.node_weights().copied().collect::<Vec<_>>(), ["Ashford", "Barton", "Crewe"]);
+/// assert_eq!(network.edge_count(), 2);
+/// ```
+pub fn union<N, E, Ty, Ix1, Ix2>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+) -> Graph<N, E, Ty>
+where
+    N: Clone + Eq + Hash,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+{
+    union_by_key(g1, g2, N::clone)
+}
+
+/// \[Generic\] Return the union of two graphs whose nodes are identified by
+/// the key `key` returns for their weights: the nodes and edges of either of
+/// them.
+///
+/// The nodes of `g1` come first, in order, followed by the nodes of `g2`
+/// whose keys are not in `g1`. Nodes with the same key are identified, even
+/// within one graph, and take the weight of the first of them. Edges are
+/// identified by the keys of their endpoints: of the parallel edges between
+/// two nodes (in the same direction, for a directed graph), only the first
+/// is kept, preferring the edges of `g1`.
+///
+/// # Complexity
+/// * Time complexity: **O(|V1| + |E1| + |V2| + |E2|)** expected.
+/// * Auxiliary space: **O(|V1| + |E1| + |V2| + |E2|)**.
+///
+/// where **|V1|**, **|V2|** are the numbers of nodes and **|E1|**, **|E2|**
+/// the numbers of edges of the graphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::DiGraph;
+/// use petgraph::operator::union_by_key;
+///
+/// // Accounts, identified by their ids, with a balance.
+/// let mut monday = DiGraph::<(u32, i64), ()>::new();
+/// let a = monday.add_node((1, 100));
+/// let b = monday.add_node((2, 50));
+/// monday.add_edge(a, b, ());
+///
+/// let mut tuesday = DiGraph::<(u32, i64), ()>::new();
+/// let b = tuesday.add_node((2, 20));
+/// let c = tuesday.add_node((3, 0));
+/// tuesday.add_edge(b, c, ());
+///
+/// let transfers = union_by_key(&monday, &tuesday, |&(id, _)| id);
+/// assert_eq!(transfers.node_weights().copied().collect::<Vec<_>>(), [(1, 100), (2, 50), (3, 0)]);
+/// assert_eq!(transfers.edge_count(), 2);
+/// ```
+pub fn union_by_key<N, E, Ty, Ix1, Ix2, K, F>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+    key: F,
+) -> Graph<N, E, Ty>
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    K: Eq + Hash,
+    F: FnMut(&N) -> K,
+{
+    set_operation(g1, g2, key, SetOperation::Union)
+}
+
+/// \[Generic\] Return the intersection of two graphs whose nodes are
+/// identified by their weights: the nodes and edges of both of them.
+///
+/// See [`intersection_by_key`] for the details.
+pub fn intersection<N, E, Ty, Ix1, Ix2>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+) -> Graph<N, E, Ty>
+where
+    N: Clone + Eq + Hash,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+{
+    intersection_by_key(g1, g2, N::clone)
+}
+
+/// \[Generic\] Return the intersection of two graphs whose nodes are
+/// identified by the key `key` returns for their weights: the nodes and
+/// edges of both of them.
+///
+/// The nodes and edges keep the order and the weights of `g1`. Nodes and
+/// edges are identified as in [`union_by_key`].
+///
+/// # Complexity
+/// * Time complexity: **O(|V1| + |E1| + |V2| + |E2|)** expected.
+/// * Auxiliary space: **O(|V1| + |E1| + |V2| + |E2|)**.
+///
+/// where **|V1|**, **|V2|** are the numbers of nodes and **|E1|**, **|E2|**
+/// the numbers of edges of the graphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::operator::intersection_by_key;
+///
+/// // Hosts, with their names and addresses, in two overlay networks.
+/// let mut g1 = UnGraph::<(&str, u32), ()>::new_undirected();
+/// let [a, b, c] = [("a", 1), ("b", 2), ("c", 3)].map(|w| g1.add_node(w));
+/// g1.extend_with_edges([(a, b), (b, c)]);
+///
+/// let mut g2 = UnGraph::<(&str, u32), ()>::new_undirected();
+/// let [c, b, a] = [("c", 3), ("b", 2), ("a", 9)].map(|w| g2.add_node(w));
+/// g2.extend_with_edges([(c, b), (b, a)]);
+///
+/// let by_name = intersection_by_key(&g1, &g2, |&(name, _)| name);
+/// assert_eq!(by_name.node_count(), 3);
+/// assert_eq!(by_name.edge_count(), 2);
+///
+/// let by_host = intersection_by_key(&g1, &g2, |&host| host);
+/// assert_eq!(by_host.node_count(), 2);
+/// assert_eq!(by_host.edge_count(), 1);
+/// ```
+pub fn intersection_by_key<N, E, Ty, Ix1, Ix2, K, F>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+    key: F,
+) -> Graph<N, E, Ty>
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    K: Eq + Hash,
+    F: FnMut(&N) -> K,
+{
+    set_operation(g1, g2, key, SetOperation::Intersection)
+}
+
+/// \[Generic\] Return the difference of two graphs whose nodes are
+/// identified by their weights: the nodes of `g1`, and the edges of `g1`
+/// that are not edges of `g2`.
+///
+/// See [`difference_by_key`] for the details.
+pub fn difference<N, E, Ty, Ix1, Ix2>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+) -> Graph<N, E, Ty>
+where
+    N: Clone + Eq + Hash,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+{
+    difference_by_key(g1, g2, N::clone)
+}
+
+/// \[Generic\] Return the difference of two graphs whose nodes are
+/// identified by the key `key` returns for their weights: the nodes of
+/// `g1`, and the edges of `g1` that are not edges of `g2`.
+///
+/// All the nodes of `g1` are kept, so that the result can be compared with
+/// `g1`. Nodes and edges are identified as in [`union_by_key`].
+///
+/// # Complexity
+/// * Time complexity: **O(|V1| + |E1| + |V2| + |E2|)** expected.
+/// * Auxiliary space: **O(|V1| + |E1| + |V2| + |E2|)**.
+///
+/// where **|V1|**, **|V2|** are the numbers of nodes and **|E1|**, **|E2|**
+/// the numbers of edges of the graphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::DiGraph;
+/// use petgraph::operator::difference;
+///
+/// let mut before = DiGraph::<char, ()>::new();
+/// let a = before.add_node('a');
+/// let b = before.add_node('b');
+/// before.extend_with_edges([(a, b), (b, a)]);
+///
+/// let mut after = DiGraph::<char, ()>::new();
+/// let b = after.add_node('b');
+/// let a = after.add_node('a');
+/// after.add_edge(b, a, ());
+///
+/// let removed = difference(&before, &after);
+/// assert_eq!(removed.node_count(), 2);
+/// assert_eq!(removed.edge_count(), 1);
+/// ```
+pub fn difference_by_key<N, E, Ty, Ix1, Ix2, K, F>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+    key: F,
+) -> Graph<N, E, Ty>
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    K: Eq + Hash,
+    F: FnMut(&N) -> K,
+{
+    set_operation(g1, g2, key, SetOperation::Difference)
+}
+
+/// \[Generic\] Return the symmetric difference of two graphs whose nodes
+/// are identified by their weights: the nodes of either of them, and the
+/// edges of exactly one of them.
+///
+/// See [`symmetric_difference_by_key`] for the details.
+pub fn symmetric_difference<N, E, Ty, Ix1, Ix2>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+) -> Graph<N, E, Ty>
+where
+    N: Clone + Eq + Hash,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+{
+    symmetric_difference_by_key(g1, g2, N::clone)
+}
+
+/// \[Generic\] Return the symmetric difference of two graphs whose nodes
+/// are identified by the key `key` returns for their weights: the nodes of
+/// either of them, and the edges of exactly one of them.
+///
+/// The nodes are those of the [union](union_by_key), so that the result can
+/// be compared with both graphs. Nodes and edges are identified as in
+/// [`union_by_key`].
+///
+/// # Complexity
+/// * Time complexity: **O(|V1| + |E1| + |V2| + |E2|)** expected.
+/// * Auxiliary space: **O(|V1| + |E1| + |V2| + |E2|)**.
+///
+/// where **|V1|**, **|V2|** are the numbers of nodes and **|E1|**, **|E2|**
+/// the numbers of edges of the graphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::operator::symmetric_difference_by_key;
+///
+/// let mut g1 = UnGraph::<u8, ()>::new_undirected();
+/// let [a, b, c] = [1, 2, 3].map(|w| g1.add_node(w));
+/// g1.extend_with_edges([(a, b), (b, c)]);
+///
+/// let mut g2 = UnGraph::<u8, ()>::new_undirected();
+/// let [c, b, d] = [3, 2, 4].map(|w| g2.add_node(w));
+/// g2.extend_with_edges([(c, b), (c, d)]);
+///
+/// let changed = symmetric_difference_by_key(&g1, &g2, |&w| w);
+/// assert_eq!(changed.node_count(), 4);
+/// // The edges 1 - 2 and 3 - 4.
+/// assert_eq!(changed.edge_count(), 2);
+/// ```
+pub fn symmetric_difference_by_key<N, E, Ty, Ix1, Ix2, K, F>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+    key: F,
+) -> Graph<N, E, Ty>
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    K: Eq + Hash,
+    F: FnMut(&N) -> K,
+{
+    set_operation(g1, g2, key, SetOperation::SymmetricDifference)
+}
+
+/// \[Generic\] Return the disjoint union of two graphs, with the new index of
+/// each node of `g1` and of `g2`.
+///
+/// The nodes and edges of `g1` come first, with the same indices, followed
+/// by those of `g2`, in order. The weights are cloned.
+///
+/// # Arguments
+/// * `g1`: the first graph.
+/// * `g2`: the second graph.
+///
+/// # Returns
+/// * The union, the new indices of the nodes of `g1` and the new indices of
+///   the nodes of `g2`, indexed by their old indices.
+///
+/// # Complexity
+/// * Time complexity: **O(|V1| + |E1| + |V2| + |E2|)**.
+/// * Auxiliary space: **O(|V1| + |V2|)**.
+///
+/// where **|V1|**, **|V2|** are the numbers of nodes and **|E1|**, **|E2|**
+/// the numbers of edges of the graphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::operator::disjoint_union;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let edge = UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let (graph, first, second) = disjoint_union(&path, &edge);
+/// assert_eq!(graph.node_count(), 5);
+/// assert_eq!(graph.edge_count(), 3);
+/// assert!(graph.contains_edge(second[0], second[1]));
+/// assert!(!graph.contains_edge(first[2], second[0]));
+/// ```
+pub fn disjoint_union<N, E, Ty, Ix1, Ix2>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+) -> (Graph<N, E, Ty>, Vec<NodeIndex>, Vec<NodeIndex>)
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+{
+    let mut graph = Graph::with_capacity(
+        g1.node_count() + g2.node_count(),
+        g1.edge_count() + g2.edge_count(),
+    );
+    let first: Vec<NodeIndex> = g1
+        .node_weights()
+        .map(|weight| graph.add_node(weight.clone()))
+        .collect();
+    let second: Vec<NodeIndex> = g2
+        .node_weights()
+        .map(|weight| graph.add_node(weight.clone()))
+        .collect();
+    for edge in g1.edge_references() {
+        let (a, b) = (first[edge.source().index()], first[edge.target().index()]);
+        graph.add_edge(a, b, edge.weight().clone());
+    }
+    for edge in g2.edge_references() {
+        let (a, b) = (second[edge.source().index()], second[edge.target().index()]);
+        graph.add_edge(a, b, edge.weight().clone());
+    }
+    (graph, first, second)
+}
+
+/// \[Generic\] Return the join of two graphs: their [disjoint
+/// union](disjoint_union), where every node of `g1` is also connected to
+/// every node of `g2`.
+///
+/// The new edges come after the edges of the disjoint union, and their
+/// weights are given by `edge_weight` from their endpoints in the result.
+/// For a directed graph, they go from the nodes of `g1` to the nodes of
+/// `g2`.
+///
+/// # Arguments
+/// * `g1`: the first graph.
+/// * `g2`: the second graph.
+/// * `edge_weight`: closure that returns the weight of a new edge from its
+///   endpoints.
+///
+/// # Returns
+/// * The join, the new indices of the nodes of `g1` and the new indices of
+///   the nodes of `g2`, indexed by their old indices.
+///
+/// # Complexity
+/// * Time complexity: **O(|V1| · |V2| + |E1| + |E2|)**.
+/// * Auxiliary space: **O(|V1| + |V2|)**.
+///
+/// where **|V1|**, **|V2|** are the numbers of nodes and **|E1|**, **|E2|**
+/// the numbers of edges of the graphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::operator::join;
+///
+/// // The wheel graph: a cycle joined with a hub.
+/// let cycle = UnGraph::<(), u32>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let mut hub = UnGraph::<(), u32>::new_undirected();
+/// hub.add_node(());
+/// let (wheel, _, hub) = join(&cycle, &hub, |_, _| 1);
+/// assert_eq!(wheel.edge_count(), 8);
+/// assert_eq!(wheel.neighbors(hub[0]).count(), 4);
+/// ```
+pub fn join<N, E, Ty, Ix1, Ix2, F>(
+    g1: &Graph<N, E, Ty, Ix1>,
+    g2: &Graph<N, E, Ty, Ix2>,
+    mut edge_weight: F,
+) -> (Graph<N, E, Ty>, Vec<NodeIndex>, Vec<NodeIndex>)
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix1: IndexType,
+    Ix2: IndexType,
+    F: FnMut(NodeIndex, NodeIndex) -> E,
+{
+    let (mut graph, first, second) = disjoint_union(g1, g2);
+    graph.reserve_edges(first.len() * second.len());
+    for &a in &first {
+        for &b in &second {
+            let weight = edge_weight(a, b);
+            graph.add_edge(a, b, weight);
+        }
+    }
+    (graph, first, second)
+}
+
+/// \[Generic\] Return the quotient graph of a graph by a partition of its
+/// nodes: each block of the partition becomes a single node, and the edges
+/// between two blocks become a single edge.
+///
+/// This generalizes the [condensation](crate::algo::condensation) of the
+/// strongly connected components to any partition, such as the communities
+/// of a network or a [`Partition`](crate::algo::partition::Partition).
+///
+/// Node `i` of the quotient is the block of the nodes labelled `i`, and its
+/// weight lists them in order. The edges of the graph between the blocks
+/// `a` and `b` (from `a` to `b`, for a directed graph) are aggregated into
+/// one edge, whose weight starts from `W::default()` and is updated by
+/// `aggregate` with each edge. The edges inside a block are aggregated into
+/// a self loop of its node. Nodes labelled `usize::MAX` are left out, with
+/// their edges.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `labels`: the block of each node, indexed by node index (see
+///   [`NodeIndexable`]).
+/// * `aggregate`: closure that adds an edge of the input graph to the weight
+///   of an edge of the quotient.
+///
+/// # Returns
+/// * The quotient, with as many nodes as the largest label plus one.
+///
+/// **Panics** if `labels` has fewer entries than the node bound of the
+/// graph.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** expected.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::operator::quotient_graph;
+///
+/// // Two triangles joined by two edges.
+/// let graph = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 0, 1),
+///     (3, 4, 1), (4, 5, 1), (5, 3, 1),
+///     (0, 3, 5), (2, 5, 7),
+/// ]);
+/// let communities = [0, 0, 0, 1, 1, 1];
+/// let quotient = quotient_graph(&graph, &communities, |total: &mut u32, edge| {
+///     *total += *edge.weight()
+/// });
+/// assert_eq!(quotient.node_count(), 2);
+/// assert_eq!(quotient[NodeIndex::new(1)], [3, 4, 5].map(NodeIndex::new));
+/// let between = quotient.find_edge(NodeIndex::new(0), NodeIndex::new(1)).unwrap();
+/// assert_eq!(quotient[between], 12);
+/// let inside = quotient.find_edge(NodeIndex::new(0), NodeIndex::new(0)).unwrap();
+/// assert_eq!(quotient[inside], 3);
+/// ```
+pub fn quotient_graph<G, W, F>(
+    graph: G,
+    labels: &[usize],
+    mut aggregate: F,
+) -> Graph<Vec<G::NodeId>, W, G::EdgeType>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    W: Default,
+    F: FnMut(&mut W, G::EdgeRef),
+{
+    assert!(
+        labels.len() >= graph.node_bound(),
+        "quotient_graph: a label is needed for each node index"
+    );
+    let label = |v: G::NodeId| labels[graph.to_index(v)];
+    let blocks = graph
+        .node_identifiers()
+        .map(label)
+        .filter(|&l| l != usize::MAX)
+        .max()
+        .map_or(0, |l| l + 1);
+    let mut quotient = Graph::with_capacity(blocks, 0);
+    for _ in 0..blocks {
+        quotient.add_node(Vec::new());
+    }
+    for v in graph.node_identifiers() {
+        if label(v) != usize::MAX {
+            quotient[NodeIndex::new(label(v))].push(v);
+        }
+    }
+
+    let directed = graph.is_directed();
+    let mut edges = HashMap::new();
+    for edge in graph.edge_references() {
+        let (a, b) = (label(edge.source()), label(edge.target()));
+        if a == usize::MAX || b == usize::MAX {
+            continue;
+        }
+        let key = if directed || a <= b { (a, b) } else { (b, a) };
+        let e = *edges.entry(key).or_insert_with(|| {
+            quotient.add_edge(NodeIndex::new(key.0), NodeIndex::new(key.1), W::default())
+        });
+        aggregate(&mut quotient[e], edge);
+    }
+    quotient
+}
+
+/// A subgraph extracted by [`subgraph`] or [`edge_subgraph`], with the maps
+/// between its nodes and edges and those of the original graph.
+#[derive(Clone, Debug)]
+pub struct Subgraph<G: GraphBase> {
+    graph: G,
+    nodes: HashMap<G::NodeId, G::NodeId>,
+    original_nodes: HashMap<G::NodeId, G::NodeId>,
+    edges: HashMap<G::EdgeId, G::EdgeId>,
+    original_edges: HashMap<G::EdgeId, G::EdgeId>,
+}
+
+impl<G: GraphBase> Subgraph<G>
+where
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+{
+    /// Return the subgraph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Return the subgraph, without the maps.
+    pub fn into_graph(self) -> G {
+        self.graph
+    }
+
+    /// Return the node of the subgraph for the node `a` of the original
+    /// graph, or `None` if the subgraph doesn't have it.
+    pub fn node(&self, a: G::NodeId) -> Option<G::NodeId> {
+        self.nodes.get(&a).copied()
+    }
+
+    /// Return the node of the original graph for the node `a` of the
+    /// subgraph.
+    pub fn original_node(&self, a: G::NodeId) -> Option<G::NodeId> {
+        self.original_nodes.get(&a).copied()
+    }
+
+    /// Return the edge of the subgraph for the edge `e` of the original
+    /// graph, or `None` if the subgraph doesn't have it.
+    pub fn edge(&self, e: G::EdgeId) -> Option<G::EdgeId> {
+        self.edges.get(&e).copied()
+    }
+
+    /// Return the edge of the original graph for the edge `e` of the
+    /// subgraph.
+    pub fn original_edge(&self, e: G::EdgeId) -> Option<G::EdgeId> {
+        self.original_edges.get(&e).copied()
+    }
+}
+
+/// \[Generic\] Return the subgraph induced by a set of nodes: the graph of
+/// the same type with these nodes and the edges between them, with the maps
+/// between its nodes and edges and those of `graph`.
+///
+/// The nodes and edges of the subgraph are in the same order as in `graph`,
+/// with cloned weights. The nodes of `nodes` that are not in `graph`, and
+/// the repeated ones, are ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `nodes`: the nodes to keep.
+///
+/// # Returns
+/// * The [`Subgraph`], with the maps in both directions.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** expected.
+/// * Auxiliary space: **O(|V'| + |E'|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges
+/// of the graph, and **|V'|** and **|E'|** those of the subgraph.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::{DiGraph, NodeIndex};
+/// use petgraph::operator::subgraph;
+///
+/// let mut graph = DiGraph::<char, u32>::new();
+/// let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|w| graph.add_node(w));
+/// graph.extend_with_edges([(a, b, 1), (b, c, 2), (c, d, 3), (d, b, 4)]);
+///
+/// let sub = subgraph(&graph, [d, b, c]);
+/// assert_eq!(sub.graph().node_count(), 3);
+/// assert_eq!(sub.graph().edge_count(), 3);
+/// // The nodes keep their order in the graph.
+/// assert_eq!(sub.node(b), Some(NodeIndex::new(0)));
+/// assert_eq!(sub.node(a), None);
+/// assert_eq!(sub.original_node(NodeIndex::new(2)), Some(d));
+/// assert_eq!(sub.graph()[NodeIndex::new(2)], 'd');
+/// ```
+pub fn subgraph<'a, G, I>(graph: &'a G, nodes: I) -> Subgraph<G>
+where
+    G: Create,
+    &'a G: IntoNodeReferences
+        + IntoEdgeReferences
+        + GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId>
+        + Data<NodeWeight = G::NodeWeight, EdgeWeight = G::EdgeWeight>,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone,
+    I: IntoIterator<Item = G::NodeId>,
+{
+    let nodes: HashSet<G::NodeId> = nodes.into_iter().collect();
+    induced_subgraph(graph, |v| nodes.contains(&v), |_| true)
+}
+
+/// \[Generic\] Return the subgraph induced by a set of edges: the graph of
+/// the same type with these edges and their endpoints, with the maps
+/// between its nodes and edges and those of `graph`.
+///
+/// The nodes and edges of the subgraph are in the same order as in `graph`,
+/// with cloned weights. The edges of `edges` that are not in `graph`, and
+/// the repeated ones, are ignored.
+///
+/// # Arguments
+/// * `graph`: the input graph.
+/// * `edges`: the edges to keep.
+///
+/// # Returns
+/// * The [`Subgraph`], with the maps in both directions.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** expected.
+/// * Auxiliary space: **O(|V'| + |E'|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges
+/// of the graph, and **|V'|** and **|E'|** those of the subgraph.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::{EdgeIndex, UnGraph};
+/// use petgraph::operator::edge_subgraph;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let sub = edge_subgraph(&graph, [EdgeIndex::new(2)]);
+/// assert_eq!(sub.graph().node_count(), 2);
+/// assert_eq!(sub.graph().edge_count(), 1);
+/// assert_eq!(sub.original_edge(EdgeIndex::new(0)), Some(EdgeIndex::new(2)));
+/// ```
+pub fn edge_subgraph<'a, G, I>(graph: &'a G, edges: I) -> Subgraph<G>
+where
+    G: Create,
+    &'a G: IntoNodeReferences
+        + IntoEdgeReferences
+        + GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId>
+        + Data<NodeWeight = G::NodeWeight, EdgeWeight = G::EdgeWeight>,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone,
+    I: IntoIterator<Item = G::EdgeId>,
+{
+    let edges: HashSet<G::EdgeId> = edges.into_iter().collect();
+    let mut nodes = HashSet::new();
+    for edge in graph.edge_references() {
+        if edges.contains(&edge.id()) {
+            nodes.insert(edge.source());
+            nodes.insert(edge.target());
+        }
+    }
+    induced_subgraph(graph, |v| nodes.contains(&v), |e| edges.contains(&e))
+}
+
+/// Return the subgraph of the nodes that `keep_node` keeps, and of the edges
+/// between them that `keep_edge` keeps.
+fn induced_subgraph<'a, G>(
+    graph: &'a G,
+    keep_node: impl Fn(G::NodeId) -> bool,
+    keep_edge: impl Fn(G::EdgeId) -> bool,
+) -> Subgraph<G>
+where
+    G: Create,
+    &'a G: IntoNodeReferences
+        + IntoEdgeReferences
+        + GraphBase<NodeId = G::NodeId, EdgeId = G::EdgeId>
+        + Data<NodeWeight = G::NodeWeight, EdgeWeight = G::EdgeWeight>,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone,
+{
+    let mut sub = Subgraph {
+        graph: G::default(),
+        nodes: HashMap::new(),
+        original_nodes: HashMap::new(),
+        edges: HashMap::new(),
+        original_edges: HashMap::new(),
+    };
+    for node in graph.node_references() {
+        if keep_node(node.id()) {
+            let a = sub.graph.add_node(node.weight().clone());
+            sub.nodes.insert(node.id(), a);
+            sub.original_nodes.insert(a, node.id());
+        }
+    }
+    for edge in graph.edge_references() {
+        let (a, b) = match (sub.nodes.get(&edge.source()), sub.nodes.get(&edge.target())) {
+            (Some(&a), Some(&b)) if keep_edge(edge.id()) => (a, b),
+            _ => continue,
+        };
+        if let Some(e) = sub.graph.add_edge(a, b, edge.weight().clone()) {
+            sub.edges.insert(edge.id(), e);
+            sub.original_edges.insert(e, edge.id());
+        }
+    }
+    sub
+}
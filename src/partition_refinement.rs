@@ -0,0 +1,284 @@
+//! `PartitionRefinement` is an ordered partition of a set of elements that
+//! can be refined efficiently.
+
+use alloc::{vec, vec::Vec};
+use core::iter::FusedIterator;
+
+const NONE: usize = usize::MAX;
+
+/// An ordered partition of the elements `0..n` into sets, supporting
+/// refinement by a pivot set in time linear in the size of the pivot.
+///
+/// Refining by a pivot splits every set that the pivot partially overlaps in
+/// two: the elements in the pivot, which form a new set placed just before
+/// the old one, and the others, which keep the old set. This is the building
+/// block of lexicographic breadth-first search, modular decomposition and
+/// Hopcroft's automaton minimization.
+///
+/// Sets are identified by an id, which stays valid as long as the set is not
+/// empty. Elements can also be removed from the partition.
+///
+/// [Partition refinement](https://en.wikipedia.org/wiki/Partition_refinement)
+///
+/// # Example
+/// ```rust
+/// use petgraph::partition_refinement::PartitionRefinement;
+///
+/// let mut partition = PartitionRefinement::new(6);
+/// partition.refine([1, 3, 5]);
+/// partition.refine([0, 1]);
+///
+/// let sets: Vec<Vec<usize>> = partition
+///     .sets()
+///     .map(|set| {
+///         let mut set = set.to_vec();
+///         set.sort();
+///         set
+///     })
+///     .collect();
+/// assert_eq!(sets, vec![vec![1], vec![3, 5], vec![0], vec![2, 4]]);
+/// assert_eq!(partition.set_of(3), partition.set_of(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PartitionRefinement {
+    /// The elements, with each set in a contiguous range.
+    elements: Vec<usize>,
+    /// The position of each element in `elements`.
+    position: Vec<usize>,
+    /// The set of each element, or `NONE` if it was removed.
+    set_of: Vec<usize>,
+    sets: Vec<Part>,
+    /// The first and last sets, in order.
+    head: usize,
+    tail: usize,
+    set_count: usize,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Part {
+    start: usize,
+    end: usize,
+    prev: usize,
+    next: usize,
+    /// Number of elements moved to the start of the range during the current
+    /// refinement.
+    marked: usize,
+}
+
+impl PartitionRefinement {
+    /// Create a partition of the elements `0..n` with a single set, or no
+    /// set if `n` is `0`.
+    pub fn new(n: usize) -> Self {
+        let mut partition = PartitionRefinement {
+            elements: (0..n).collect(),
+            position: (0..n).collect(),
+            set_of: vec![0; n],
+            sets: Vec::new(),
+            head: NONE,
+            tail: NONE,
+            set_count: 0,
+            len: n,
+        };
+        if n > 0 {
+            partition.sets.push(Part {
+                start: 0,
+                end: n,
+                prev: NONE,
+                next: NONE,
+                marked: 0,
+            });
+            partition.head = 0;
+            partition.tail = 0;
+            partition.set_count = 1;
+        }
+        partition
+    }
+
+    /// Return the number of elements in the partition.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return whether the partition has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the number of sets in the partition.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Return whether `element` is in the partition.
+    pub fn contains(&self, element: usize) -> bool {
+        self.set_of.get(element).map_or(false, |&s| s != NONE)
+    }
+
+    /// Return the id of the set containing `element`, or `None` if it is not
+    /// in the partition.
+    pub fn set_of(&self, element: usize) -> Option<usize> {
+        self.set_of.get(element).copied().filter(|&s| s != NONE)
+    }
+
+    /// Return the elements of set `set`, in no particular order.
+    ///
+    /// **Panics** if `set` is not a set id of the partition.
+    pub fn set(&self, set: usize) -> &[usize] {
+        let part = &self.sets[set];
+        &self.elements[part.start..part.end]
+    }
+
+    /// Return the id of the first set, or `None` if the partition is empty.
+    pub fn first(&self) -> Option<usize> {
+        Some(self.head).filter(|&s| s != NONE)
+    }
+
+    /// Return the id of the last set, or `None` if the partition is empty.
+    pub fn last(&self) -> Option<usize> {
+        Some(self.tail).filter(|&s| s != NONE)
+    }
+
+    /// Return the id of the set after `set`, or `None` if it is the last one.
+    pub fn next(&self, set: usize) -> Option<usize> {
+        Some(self.sets[set].next).filter(|&s| s != NONE)
+    }
+
+    /// Return the id of the set before `set`, or `None` if it is the first
+    /// one.
+    pub fn prev(&self, set: usize) -> Option<usize> {
+        Some(self.sets[set].prev).filter(|&s| s != NONE)
+    }
+
+    /// Return an iterator over the sets, in order.
+    pub fn sets(&self) -> Sets<'_> {
+        Sets {
+            partition: self,
+            next: self.head,
+        }
+    }
+
+    /// Split every set into its elements that are in `pivot`, placed in a new
+    /// set just before it, and its other elements, which keep the set id.
+    ///
+    /// Elements of `pivot` that are not in the partition are ignored, and
+    /// sets that are contained in `pivot` are not split. Return the pairs of
+    /// the id of each split set and the id of the new set.
+    ///
+    /// Computes in **O(|pivot|)** time.
+    pub fn refine<I>(&mut self, pivot: I) -> Vec<(usize, usize)>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let mut touched = Vec::new();
+        for element in pivot {
+            let s = match self.set_of(element) {
+                Some(s) => s,
+                None => continue,
+            };
+            let part = &mut self.sets[s];
+            let pos = self.position[element];
+            if pos < part.start + part.marked {
+                // Already moved.
+                continue;
+            }
+            let target = part.start + part.marked;
+            part.marked += 1;
+            if part.marked == 1 {
+                touched.push(s);
+            }
+            let other = self.elements[target];
+            self.elements.swap(pos, target);
+            self.position[other] = pos;
+            self.position[element] = target;
+        }
+
+        let mut splits = Vec::new();
+        for s in touched {
+            let t = self.sets.len();
+            let part = &mut self.sets[s];
+            let marked = core::mem::replace(&mut part.marked, 0);
+            if marked == part.end - part.start {
+                continue;
+            }
+            let (start, prev) = (part.start, part.prev);
+            part.start += marked;
+            part.prev = t;
+            self.sets.push(Part {
+                start,
+                end: start + marked,
+                prev,
+                next: s,
+                marked: 0,
+            });
+            if prev == NONE {
+                self.head = t;
+            } else {
+                self.sets[prev].next = t;
+            }
+            for &element in &self.elements[start..start + marked] {
+                self.set_of[element] = t;
+            }
+            self.set_count += 1;
+            splits.push((s, t));
+        }
+        splits
+    }
+
+    /// Remove `element` from the partition, and remove its set if it becomes
+    /// empty. Return `false` if `element` was not in the partition.
+    pub fn remove(&mut self, element: usize) -> bool {
+        let s = match self.set_of(element) {
+            Some(s) => s,
+            None => return false,
+        };
+        // Swap the element to the end of its range and shrink the range.
+        let part = &mut self.sets[s];
+        part.end -= 1;
+        let (pos, last) = (self.position[element], part.end);
+        let other = self.elements[last];
+        self.elements.swap(pos, last);
+        self.position[other] = pos;
+        self.position[element] = last;
+        self.set_of[element] = NONE;
+        self.len -= 1;
+
+        if part.start == part.end {
+            let (prev, next) = (part.prev, part.next);
+            if prev == NONE {
+                self.head = next;
+            } else {
+                self.sets[prev].next = next;
+            }
+            if next == NONE {
+                self.tail = prev;
+            } else {
+                self.sets[next].prev = prev;
+            }
+            self.set_count -= 1;
+        }
+        true
+    }
+}
+
+/// An iterator over the sets of a [`PartitionRefinement`], in order.
+#[derive(Debug, Clone)]
+pub struct Sets<'a> {
+    partition: &'a PartitionRefinement,
+    next: usize,
+}
+
+impl<'a> Iterator for Sets<'a> {
+    type Item = &'a [usize];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == NONE {
+            return None;
+        }
+        let set = self.partition.set(self.next);
+        self.next = self.partition.sets[self.next].next;
+        Some(set)
+    }
+}
+
+impl FusedIterator for Sets<'_> {}
@@ -0,0 +1,359 @@
+//! Property graphs, whose nodes and edges carry labels and properties.
+//!
+//! A [`PropertyGraph`] is a [`Graph`] with [`PropertyNode`] and
+//! [`PropertyEdge`] weights, in the model of graph databases like Neo4j or
+//! of Gremlin: a node has any number of labels, an edge has one label, its
+//! type, and both have a map of named [`PropertyValue`]s. The graph keeps all
+//! the methods of `Graph` and its algorithms, with filtered views of the
+//! nodes or edges of a label or a property, such as
+//! [`nodes_labeled`](Graph::nodes_labeled) and
+//! [`edges_labeled`](Graph::edges_labeled).
+//!
+//! ```
+//! use petgraph::algo::has_path_connecting;
+//! use petgraph::property::{PropertyEdge, PropertyGraph, PropertyNode};
+//!
+//! let mut graph = PropertyGraph::new();
+//! let alice = graph.add_node(PropertyNode::new(["Person"]).with("name", "Alice"));
+//! let bob = graph.add_node(PropertyNode::new(["Person"]).with("name", "Bob"));
+//! let acme = graph.add_node(PropertyNode::new(["Company"]).with("name", "Acme"));
+//! graph.add_edge(alice, bob, PropertyEdge::new("KNOWS").with("since", 2019));
+//! graph.add_edge(bob, acme, PropertyEdge::new("WORKS_AT"));
+//!
+//! assert_eq!(graph.find_node("name", &"Bob".into()), Some(bob));
+//! assert_eq!(graph[alice].get("name").and_then(|v| v.as_str()), Some("Alice"));
+//!
+//! // Only the people.
+//! let people = graph.nodes_labeled("Person");
+//! assert!(has_path_connecting(&people, alice, bob, None));
+//! assert!(!has_path_connecting(&people, alice, acme, None));
+//!
+//! // Only the `KNOWS` edges.
+//! let knows = graph.edges_labeled("KNOWS");
+//! assert!(!has_path_connecting(&knows, bob, acme, None));
+//! ```
+//!
+//! [`Graph`]: crate::graph::Graph
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt;
+
+use fixedbitset::FixedBitSet;
+
+use crate::graph::{DefaultIx, EdgeReference, Graph, IndexType, NodeIndex};
+use crate::visit::{EdgeFiltered, FilterEdge, NodeFiltered};
+use crate::{Directed, EdgeType, Undirected};
+
+/// The value of a property.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum PropertyValue {
+    /// No value.
+    #[default]
+    Null,
+    /// A boolean.
+    Bool(bool),
+    /// An integer.
+    Int(i64),
+    /// A floating point number.
+    Float(f64),
+    /// A string.
+    String(String),
+    /// A list of values.
+    List(Vec<PropertyValue>),
+}
+
+impl PropertyValue {
+    /// Return the boolean, if the value is one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            PropertyValue::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// Return the integer, if the value is one.
+    pub fn as_int(&self) -> Option<i64> {
+        match *self {
+            PropertyValue::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Return the number, if the value is a floating point number or an
+    /// integer.
+    pub fn as_float(&self) -> Option<f64> {
+        match *self {
+            PropertyValue::Float(x) => Some(x),
+            PropertyValue::Int(i) => Some(i as f64),
+            _ => None,
+        }
+    }
+
+    /// Return the string, if the value is one.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Return the list, if the value is one.
+    pub fn as_list(&self) -> Option<&[PropertyValue]> {
+        match self {
+            PropertyValue::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// Return `true` if the value is `Null`.
+    pub fn is_null(&self) -> bool {
+        *self == PropertyValue::Null
+    }
+}
+
+impl fmt::Display for PropertyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyValue::Null => f.write_str("null"),
+            PropertyValue::Bool(b) => write!(f, "{b}"),
+            PropertyValue::Int(i) => write!(f, "{i}"),
+            PropertyValue::Float(x) => write!(f, "{x}"),
+            PropertyValue::String(s) => write!(f, "{s:?}"),
+            PropertyValue::List(list) => {
+                f.write_str("[")?;
+                for (i, value) in list.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+macro_rules! property_value_from {
+    ($($t:ty => $variant:ident),*) => {
+        $(
+            impl From<$t> for PropertyValue {
+                fn from(value: $t) -> Self {
+                    PropertyValue::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+property_value_from! {
+    bool => Bool,
+    i8 => Int,
+    i16 => Int,
+    i32 => Int,
+    i64 => Int,
+    u8 => Int,
+    u16 => Int,
+    u32 => Int,
+    f32 => Float,
+    f64 => Float,
+    &str => String,
+    String => String,
+    Vec<PropertyValue> => List
+}
+
+impl<T: Into<PropertyValue>> From<Option<T>> for PropertyValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(PropertyValue::Null, Into::into)
+    }
+}
+
+/// The properties of a node or an edge, by name.
+pub type Properties = BTreeMap<String, PropertyValue>;
+
+/// The weight of a node of a [`PropertyGraph`]: its labels and properties.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PropertyNode {
+    /// The labels of the node.
+    pub labels: Vec<String>,
+    /// The properties of the node.
+    pub properties: Properties,
+}
+
+impl PropertyNode {
+    /// Create a node with these labels, and no properties.
+    pub fn new<I>(labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        PropertyNode {
+            labels: labels.into_iter().map(Into::into).collect(),
+            properties: Properties::new(),
+        }
+    }
+
+    /// Set the property `key` to `value`, and return the node.
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    /// Set the property `key` to `value`, and return its previous value.
+    pub fn set(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<PropertyValue>,
+    ) -> Option<PropertyValue> {
+        self.properties.insert(key.into(), value.into())
+    }
+
+    /// Return the value of the property `key`.
+    pub fn get(&self, key: &str) -> Option<&PropertyValue> {
+        self.properties.get(key)
+    }
+
+    /// Return `true` if the node has the label `label`.
+    pub fn has_label(&self, label: &str) -> bool {
+        self.labels.iter().any(|l| l == label)
+    }
+}
+
+/// The weight of an edge of a [`PropertyGraph`]: its label and properties.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PropertyEdge {
+    /// The label of the edge, the type of the relationship.
+    pub label: String,
+    /// The properties of the edge.
+    pub properties: Properties,
+}
+
+impl PropertyEdge {
+    /// Create an edge with this label, and no properties.
+    pub fn new(label: impl Into<String>) -> Self {
+        PropertyEdge {
+            label: label.into(),
+            properties: Properties::new(),
+        }
+    }
+
+    /// Set the property `key` to `value`, and return the edge.
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    /// Set the property `key` to `value`, and return its previous value.
+    pub fn set(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<PropertyValue>,
+    ) -> Option<PropertyValue> {
+        self.properties.insert(key.into(), value.into())
+    }
+
+    /// Return the value of the property `key`.
+    pub fn get(&self, key: &str) -> Option<&PropertyValue> {
+        self.properties.get(key)
+    }
+}
+
+/// A [`Graph`] whose nodes and edges carry labels and properties.
+///
+/// [`Graph`]: crate::graph::Graph
+pub type PropertyGraph<Ty = Directed, Ix = DefaultIx> = Graph<PropertyNode, PropertyEdge, Ty, Ix>;
+
+/// An undirected [`PropertyGraph`].
+pub type UnPropertyGraph<Ix = DefaultIx> = PropertyGraph<Undirected, Ix>;
+
+/// An edge filter that keeps the edges of a [`PropertyGraph`] whose weight
+/// matches a predicate.
+///
+/// Created with [`Graph::edges_where`] and [`Graph::edges_labeled`].
+#[derive(Clone, Copy, Debug)]
+pub struct EdgeWhere<F>(pub F);
+
+impl<F, Ix> FilterEdge<EdgeReference<'_, PropertyEdge, Ix>> for EdgeWhere<F>
+where
+    F: Fn(&PropertyEdge) -> bool,
+    Ix: IndexType,
+{
+    fn include_edge(&self, edge: EdgeReference<'_, PropertyEdge, Ix>) -> bool {
+        (self.0)(edge.weight())
+    }
+}
+
+/// The view of the nodes of a [`PropertyGraph`] that match a predicate.
+pub type NodeView<'a, Ty = Directed, Ix = DefaultIx> =
+    NodeFiltered<&'a PropertyGraph<Ty, Ix>, FixedBitSet>;
+
+/// The view of the edges of a [`PropertyGraph`] that match a predicate.
+pub type EdgeView<'a, F, Ty = Directed, Ix = DefaultIx> =
+    EdgeFiltered<&'a PropertyGraph<Ty, Ix>, EdgeWhere<F>>;
+
+impl<Ty, Ix> Graph<PropertyNode, PropertyEdge, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Return the view of the graph with only the nodes that match
+    /// `predicate`, and the edges between them.
+    ///
+    /// The view keeps the node indices, and implements the visit traits
+    /// through a reference, like `&view`.
+    ///
+    /// Computes in **O(|V|)** time.
+    pub fn nodes_where<F>(&self, predicate: F) -> NodeView<'_, Ty, Ix>
+    where
+        F: Fn(&PropertyNode) -> bool,
+    {
+        let mut nodes = FixedBitSet::with_capacity(self.node_count());
+        for (i, node) in self.raw_nodes().iter().enumerate() {
+            nodes.set(i, predicate(&node.weight));
+        }
+        NodeFiltered(self, nodes)
+    }
+
+    /// Return the view of the graph with only the nodes that have the label
+    /// `label`, and the edges between them.
+    pub fn nodes_labeled(&self, label: &str) -> NodeView<'_, Ty, Ix> {
+        self.nodes_where(|node| node.has_label(label))
+    }
+
+    /// Return the view of the graph with only the nodes whose property `key`
+    /// is `value`, and the edges between them.
+    pub fn nodes_with_property(&self, key: &str, value: &PropertyValue) -> NodeView<'_, Ty, Ix> {
+        self.nodes_where(|node| node.get(key) == Some(value))
+    }
+
+    /// Return the view of the graph with only the edges that match
+    /// `predicate`, and all the nodes.
+    ///
+    /// The view implements the visit traits through a reference, like
+    /// `&view`. It checks the edges as they are visited.
+    pub fn edges_where<F>(&self, predicate: F) -> EdgeView<'_, F, Ty, Ix>
+    where
+        F: Fn(&PropertyEdge) -> bool,
+    {
+        EdgeFiltered(self, EdgeWhere(predicate))
+    }
+
+    /// Return the view of the graph with only the edges that have the label
+    /// `label`, and all the nodes.
+    pub fn edges_labeled<'a>(
+        &'a self,
+        label: &'a str,
+    ) -> EdgeView<'a, impl Fn(&PropertyEdge) -> bool + 'a, Ty, Ix> {
+        self.edges_where(move |edge| edge.label == label)
+    }
+
+    /// Return the first node whose property `key` is `value`, such as the
+    /// identifier of a record of a database.
+    ///
+    /// Computes in **O(|V|)** time.
+    pub fn find_node(&self, key: &str, value: &PropertyValue) -> Option<NodeIndex<Ix>> {
+        self.raw_nodes()
+            .iter()
+            .position(|node| node.weight.get(key) == Some(value))
+            .map(NodeIndex::new)
+    }
+}
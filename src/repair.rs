@@ -0,0 +1,237 @@
+//! Repairs that make a [`Graph`] connected, acyclic or simple, for cleaning
+//! up data before running algorithms that require it.
+//!
+//! Each repair changes the graph in place and returns a [`RepairReport`]
+//! of exactly which edges were added and removed.
+
+use alloc::vec::Vec;
+
+use hashbrown::hash_map::{Entry, HashMap};
+use hashbrown::HashSet;
+
+use crate::algo::greedy_feedback_arc_set;
+use crate::graph::{DefaultIx, EdgeIndex, Graph, IndexType, NodeIndex};
+use crate::unionfind::UnionFind;
+use crate::visit::EdgeRef;
+use crate::{Directed, EdgeType};
+
+/// The changes made to a graph by a repair.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RepairReport<E, Ix = DefaultIx> {
+    /// The edges that were added, which keep their indices after the repair.
+    pub added: Vec<EdgeIndex<Ix>>,
+    /// The edges that were removed, as their endpoints and weights, in the
+    /// order of their indices before the repair.
+    pub removed: Vec<(NodeIndex<Ix>, NodeIndex<Ix>, E)>,
+}
+
+impl<E, Ix> RepairReport<E, Ix> {
+    /// Return `true` if the graph was left unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Remove the edges in `edges`, in increasing order of index, and return
+/// their endpoints and weights.
+fn remove_edges<N, E, Ty, Ix>(
+    graph: &mut Graph<N, E, Ty, Ix>,
+    edges: &[EdgeIndex<Ix>],
+) -> Vec<(NodeIndex<Ix>, NodeIndex<Ix>, E)>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    // Removing an edge moves the last edge into its place, so the edges are
+    // removed from the last one, which keeps the smaller indices valid.
+    let mut removed = Vec::with_capacity(edges.len());
+    for &e in edges.iter().rev() {
+        let (a, b) = graph.edge_endpoints(e).unwrap();
+        let weight = graph.remove_edge(e).unwrap();
+        removed.push((a, b, weight));
+    }
+    removed.reverse();
+    removed
+}
+
+/// Connect the components of a graph with as few edges as possible.
+///
+/// The first node of each component (by index) is linked to the first node
+/// of the graph, with an edge whose weight is given by `edge_factory`, so
+/// that `c - 1` edges are added to a graph with `c` components. For a
+/// directed graph, the components are the weakly connected components, and
+/// the edges go from the first node of the graph.
+///
+/// # Arguments
+/// * `graph`: the graph to connect.
+/// * `edge_factory`: closure that returns the weight of a new edge from
+///   its endpoints.
+///
+/// # Returns
+/// * The added edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E| α(|V|))**.
+/// * Auxiliary space: **O(|V|)**.
+///
+/// where **|V|** is the number of nodes, **|E|** is the number of edges and
+/// **α** is the inverse Ackermann function.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::connected_components;
+/// use petgraph::graph::UnGraph;
+/// use petgraph::repair::make_connected;
+///
+/// let mut graph = UnGraph::<(), f32>::from_edges(&[(0, 1), (2, 3), (3, 4)]);
+/// graph.add_node(());
+/// let report = make_connected(&mut graph, |_, _| 0.0);
+/// assert_eq!(report.added.len(), 2);
+/// assert_eq!(connected_components(&graph), 1);
+/// ```
+pub fn make_connected<N, E, Ty, Ix, F>(
+    graph: &mut Graph<N, E, Ty, Ix>,
+    mut edge_factory: F,
+) -> RepairReport<E, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    F: FnMut(NodeIndex<Ix>, NodeIndex<Ix>) -> E,
+{
+    let mut components = UnionFind::new(graph.node_count());
+    for edge in graph.edge_references() {
+        components.union(edge.source().index(), edge.target().index());
+    }
+    let mut linked = HashSet::new();
+    let mut added = Vec::new();
+    for v in graph.node_indices() {
+        if linked.insert(components.find_mut(v.index())) && v.index() > 0 {
+            let first = NodeIndex::new(0);
+            let weight = edge_factory(first, v);
+            added.push(graph.add_edge(first, v, weight));
+        }
+    }
+    RepairReport {
+        added,
+        removed: Vec::new(),
+    }
+}
+
+/// Make a directed graph acyclic, by removing the edges of a small
+/// [feedback arc set].
+///
+/// The edges are chosen with [`greedy_feedback_arc_set`], a heuristic that
+/// does not always remove as few edges as possible. Self loops are always
+/// removed. The indices of the remaining edges may change.
+///
+/// # Arguments
+/// * `graph`: the graph to make acyclic.
+///
+/// # Returns
+/// * The removed edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)**.
+/// * Auxiliary space: **O(|V| + |E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// [feedback arc set]: https://en.wikipedia.org/wiki/Feedback_arc_set
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::is_cyclic_directed;
+/// use petgraph::graph::DiGraph;
+/// use petgraph::repair::make_acyclic;
+///
+/// let mut graph = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 2)]);
+/// let report = make_acyclic(&mut graph);
+/// assert_eq!(report.removed.len(), 2);
+/// assert!(!is_cyclic_directed(&graph));
+/// ```
+pub fn make_acyclic<N, E, Ix>(graph: &mut Graph<N, E, Directed, Ix>) -> RepairReport<E, Ix>
+where
+    Ix: IndexType,
+{
+    let mut feedback: Vec<EdgeIndex<Ix>> = greedy_feedback_arc_set(&*graph)
+        .map(|edge| edge.id())
+        .collect();
+    feedback.sort_unstable();
+    RepairReport {
+        added: Vec::new(),
+        removed: remove_edges(graph, &feedback),
+    }
+}
+
+/// Make a graph simple, by removing its self loops and merging its parallel
+/// edges.
+///
+/// Of the edges between the same nodes (in the same direction, for a
+/// directed graph), the one with the smallest index is kept, and `merge` is
+/// called with its weight and the weight of each of the others, in order,
+/// before they are removed. The indices of the remaining edges may change.
+///
+/// # Arguments
+/// * `graph`: the graph to make simple.
+/// * `merge`: closure that merges the weight of a parallel edge into the
+///   weight of the kept edge.
+///
+/// # Returns
+/// * The removed loops and parallel edges.
+///
+/// # Complexity
+/// * Time complexity: **O(|V| + |E|)** expected.
+/// * Auxiliary space: **O(|E|)**.
+///
+/// where **|V|** is the number of nodes and **|E|** is the number of edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::repair::make_simple;
+///
+/// let mut graph = UnGraph::<(), u32>::from_edges(&[(0, 1, 2), (1, 0, 3), (1, 1, 1), (1, 2, 1)]);
+/// let report = make_simple(&mut graph, |total, weight| *total += *weight);
+/// assert_eq!(report.removed.len(), 2);
+/// assert_eq!(graph.edge_count(), 2);
+/// let edge = graph.find_edge(NodeIndex::new(0), NodeIndex::new(1)).unwrap();
+/// assert_eq!(graph[edge], 5);
+/// ```
+pub fn make_simple<N, E, Ty, Ix, F>(
+    graph: &mut Graph<N, E, Ty, Ix>,
+    mut merge: F,
+) -> RepairReport<E, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    F: FnMut(&mut E, &E),
+{
+    let mut kept = HashMap::new();
+    let mut extra = Vec::new();
+    for e in graph.edge_indices() {
+        let (a, b) = graph.edge_endpoints(e).unwrap();
+        if a == b {
+            extra.push(e);
+            continue;
+        }
+        let key = if Ty::is_directed() || a < b {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        match kept.entry(key) {
+            Entry::Occupied(first) => {
+                let (total, weight) = graph.index_twice_mut(*first.get(), e);
+                merge(total, weight);
+                extra.push(e);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(e);
+            }
+        }
+    }
+    RepairReport {
+        added: Vec::new(),
+        removed: remove_edges(graph, &extra),
+    }
+}
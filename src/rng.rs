@@ -0,0 +1,121 @@
+//! Reproducible random number generation.
+//!
+//! Randomized algorithms and generators in `petgraph` never use a global or
+//! thread-local random number generator. They take the generator as an
+//! argument, as `rng: &mut R` where `R: Rng + ?Sized`, so that the caller
+//! controls the seed and the same seed always gives the same result.
+//!
+//! Any [`Rng`](rand::Rng) can be used. [`SeededRng`] is a small generator
+//! whose output is fully specified, so results can be reproduced bit for bit
+//! across platforms and across versions of `rand`.
+
+use rand::{RngCore, SeedableRng};
+
+/// A small, fast and portable random number generator.
+///
+/// `SeededRng` implements the [xoshiro256++] algorithm, seeded with
+/// [SplitMix64] when created from a `u64`. Unlike
+/// [`SmallRng`](https://docs.rs/rand/0.8/rand/rngs/struct.SmallRng.html) or
+/// [`StdRng`](https://docs.rs/rand/0.8/rand/rngs/struct.StdRng.html), its
+/// output for a given seed is part of the public API and does not depend on
+/// the platform, so it is suitable for reproducible simulations and tests.
+///
+/// It is not cryptographically secure.
+///
+/// [xoshiro256++]: https://prng.di.unimi.it/
+/// [SplitMix64]: https://doi.org/10.1145/2714064.2660195
+///
+/// # Example
+/// ```rust
+/// use petgraph::SeededRng;
+/// use rand::Rng;
+///
+/// let mut a = SeededRng::new(42);
+/// let mut b = SeededRng::new(42);
+/// let xs: Vec<u32> = (0..8).map(|_| a.gen_range(0..100)).collect();
+/// let ys: Vec<u32> = (0..8).map(|_| b.gen_range(0..100)).collect();
+/// assert_eq!(xs, ys);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeededRng {
+    state: [u64; 4],
+}
+
+impl SeededRng {
+    /// Create a new generator from a 64-bit seed.
+    ///
+    /// Equivalent to [`SeedableRng::seed_from_u64`].
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut state = [0; 4];
+        for s in &mut state {
+            *s = splitmix64(&mut x);
+        }
+        SeededRng { state }
+    }
+}
+
+fn splitmix64(x: &mut u64) -> u64 {
+    *x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *x;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        // The high bits are of better quality.
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for SeededRng {
+    type Seed = [u8; 32];
+
+    /// Create a new generator from a 256-bit state.
+    ///
+    /// The all-zero state is invalid for xoshiro256++, so it is replaced by
+    /// the state of `SeededRng::new(0)`.
+    fn from_seed(seed: [u8; 32]) -> Self {
+        if seed.iter().all(|&b| b == 0) {
+            return SeededRng::new(0);
+        }
+        let mut state = [0; 4];
+        for (s, chunk) in state.iter_mut().zip(seed.chunks_exact(8)) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(chunk);
+            *s = u64::from_le_bytes(bytes);
+        }
+        SeededRng { state }
+    }
+
+    fn seed_from_u64(seed: u64) -> Self {
+        SeededRng::new(seed)
+    }
+}
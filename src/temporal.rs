@@ -0,0 +1,405 @@
+//! Temporal graphs, whose edges are only active during a time interval.
+//!
+//! In a [`TemporalGraph`], every edge carries a [`TimeInterval`]: it is
+//! active from the `start` to the `end` of the interval, and a path that
+//! follows the edge leaves its source at `start` and reaches its target at
+//! `end`. The [`snapshot`](TemporalGraph::snapshot) of a time window is a
+//! view of the edges active during the window, for the usual graph
+//! algorithms; [`earliest_arrival`](TemporalGraph::earliest_arrival) and
+//! [`latest_departure`](TemporalGraph::latest_departure) follow the
+//! time-respecting paths, whose edges leave one node after reaching it.
+
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::{fmt, ops::Deref};
+
+use hashbrown::HashMap;
+
+use crate::graph::{DefaultIx, EdgeIndex, EdgeReference, Graph, IndexType, NodeIndex};
+use crate::scored::{MaxScored, MinScored};
+use crate::visit::{EdgeFiltered, EdgeRef, FilterEdge};
+use crate::{Directed, Direction, EdgeType, Undirected};
+
+/// A closed interval of time, from `start` to `end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct TimeInterval<T> {
+    /// The start of the interval.
+    pub start: T,
+    /// The end of the interval, not before its start.
+    pub end: T,
+}
+
+impl<T: PartialOrd> TimeInterval<T> {
+    /// Create the interval from `start` to `end`.
+    ///
+    /// **Panics** if `end` is before `start`, or if they are not comparable.
+    #[track_caller]
+    pub fn new(start: T, end: T) -> Self {
+        assert!(
+            start <= end,
+            "TimeInterval::new: the end is before the start"
+        );
+        TimeInterval { start, end }
+    }
+
+    /// Return `true` if `time` is in the interval.
+    pub fn contains(&self, time: &T) -> bool {
+        self.start <= *time && *time <= self.end
+    }
+
+    /// Return `true` if the intervals have a time in common.
+    pub fn overlaps(&self, other: &TimeInterval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+/// The weight of an edge of a [`TemporalGraph`]: its time interval and its
+/// data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TemporalEdge<E, T> {
+    /// The interval during which the edge is active.
+    pub interval: TimeInterval<T>,
+    /// The data of the edge.
+    pub weight: E,
+}
+
+/// An edge filter that keeps the edges of a [`TemporalGraph`] active during
+/// a time window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ActiveDuring<T>(pub TimeInterval<T>);
+
+impl<E, T, Ix> FilterEdge<EdgeReference<'_, TemporalEdge<E, T>, Ix>> for ActiveDuring<T>
+where
+    T: PartialOrd,
+    Ix: IndexType,
+{
+    fn include_edge(&self, edge: EdgeReference<'_, TemporalEdge<E, T>, Ix>) -> bool {
+        edge.weight().interval.overlaps(&self.0)
+    }
+}
+
+/// The view of the edges of a [`TemporalGraph`] active during a time window,
+/// created with [`TemporalGraph::snapshot`].
+pub type Snapshot<'a, N, E, T, Ty = Directed, Ix = DefaultIx> =
+    EdgeFiltered<&'a Graph<N, TemporalEdge<E, T>, Ty, Ix>, ActiveDuring<T>>;
+
+/// A graph whose edges are only active during a time interval.
+///
+/// `TemporalGraph` wraps a [`Graph`] with [`TemporalEdge`] edge weights, and
+/// dereferences to it, so that its read-only methods and the algorithms can
+/// use it directly, through [`.graph()`](TemporalGraph::graph). The edges
+/// are only added through [`add_edge`](TemporalGraph::add_edge), which
+/// checks their interval.
+///
+/// ```
+/// use petgraph::algo::has_path_connecting;
+/// use petgraph::temporal::{TemporalGraph, TimeInterval};
+///
+/// let mut graph = TemporalGraph::<&str, (), u32>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// graph.add_edge(a, b, TimeInterval::new(1, 2), ());
+/// graph.add_edge(b, c, TimeInterval::new(5, 6), ());
+/// graph.add_edge(a, c, TimeInterval::new(8, 9), ());
+///
+/// // Leaving `a` at time 0, `c` is reached through `b` at time 6.
+/// let arrival = graph.earliest_arrival(a, 0);
+/// assert_eq!(arrival[&c], 6);
+/// // Leaving at time 3, the direct edge is the only way.
+/// assert_eq!(graph.earliest_arrival(a, 3)[&c], 9);
+///
+/// // Between times 3 and 7, only the edge from `b` to `c` is active.
+/// let snapshot = graph.snapshot(TimeInterval::new(3, 7));
+/// assert!(!has_path_connecting(&snapshot, a, c, None));
+/// assert!(has_path_connecting(&snapshot, b, c, None));
+/// ```
+pub struct TemporalGraph<N, E, T, Ty = Directed, Ix = DefaultIx> {
+    graph: Graph<N, TemporalEdge<E, T>, Ty, Ix>,
+}
+
+impl<N, E, T, Ty, Ix> Clone for TemporalGraph<N, E, T, Ty, Ix>
+where
+    N: Clone,
+    E: Clone,
+    T: Clone,
+    Ix: IndexType,
+{
+    fn clone(&self) -> Self {
+        TemporalGraph {
+            graph: self.graph.clone(),
+        }
+    }
+}
+
+impl<N, E, T, Ty, Ix> fmt::Debug for TemporalGraph<N, E, T, Ty, Ix>
+where
+    N: fmt::Debug,
+    E: fmt::Debug,
+    T: fmt::Debug,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TemporalGraph")
+            .field("graph", &self.graph)
+            .finish()
+    }
+}
+
+impl<N, E, T> TemporalGraph<N, E, T, Directed> {
+    /// Create a new, empty directed `TemporalGraph`.
+    pub fn new() -> Self {
+        TemporalGraph::default()
+    }
+}
+
+impl<N, E, T> TemporalGraph<N, E, T, Undirected> {
+    /// Create a new, empty undirected `TemporalGraph`.
+    pub fn new_undirected() -> Self {
+        TemporalGraph::default()
+    }
+}
+
+impl<N, E, T, Ty, Ix> Default for TemporalGraph<N, E, T, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        TemporalGraph {
+            graph: Graph::default(),
+        }
+    }
+}
+
+impl<N, E, T, Ty, Ix> Deref for TemporalGraph<N, E, T, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Target = Graph<N, TemporalEdge<E, T>, Ty, Ix>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.graph
+    }
+}
+
+impl<N, E, T, Ty, Ix> TemporalGraph<N, E, T, Ty, Ix>
+where
+    T: PartialOrd + Copy,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a new, empty `TemporalGraph` with estimated capacity.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        TemporalGraph {
+            graph: Graph::with_capacity(nodes, edges),
+        }
+    }
+
+    /// Create a `TemporalGraph` from a graph with [`TemporalEdge`] edge
+    /// weights.
+    ///
+    /// **Panics** if the interval of an edge ends before it starts.
+    #[track_caller]
+    pub fn from_graph(graph: Graph<N, TemporalEdge<E, T>, Ty, Ix>) -> Self {
+        for edge in graph.raw_edges() {
+            let interval = &edge.weight.interval;
+            assert!(
+                interval.start <= interval.end,
+                "TemporalGraph::from_graph: the end of an edge is before its start"
+            );
+        }
+        TemporalGraph { graph }
+    }
+
+    /// Return the underlying graph.
+    pub fn graph(&self) -> &Graph<N, TemporalEdge<E, T>, Ty, Ix> {
+        &self.graph
+    }
+
+    /// Convert into the underlying graph.
+    pub fn into_graph(self) -> Graph<N, TemporalEdge<E, T>, Ty, Ix> {
+        self.graph
+    }
+
+    /// Add a node with associated data `weight` to the graph, and return its
+    /// index.
+    ///
+    /// **Panics** if the graph is at the maximum number of nodes for its
+    /// index type.
+    #[track_caller]
+    pub fn add_node(&mut self, weight: N) -> NodeIndex<Ix> {
+        self.graph.add_node(weight)
+    }
+
+    /// Add an edge from `a` to `b`, active during `interval`, with its
+    /// associated data `weight`, and return its index.
+    ///
+    /// **Panics** if any of the nodes don't exist.<br>
+    /// **Panics** if the interval ends before it starts.<br>
+    /// **Panics** if the graph is at the maximum number of edges for its
+    /// index type.
+    #[track_caller]
+    pub fn add_edge(
+        &mut self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+        interval: TimeInterval<T>,
+        weight: E,
+    ) -> EdgeIndex<Ix> {
+        assert!(
+            interval.start <= interval.end,
+            "TemporalGraph::add_edge: the end of the edge is before its start"
+        );
+        self.graph.add_edge(a, b, TemporalEdge { interval, weight })
+    }
+
+    /// Remove `a` from the graph if it exists, with its edges, and return
+    /// its weight.
+    ///
+    /// See [`Graph::remove_node`] for how the indices change.
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> Option<N> {
+        self.graph.remove_node(a)
+    }
+
+    /// Remove an edge and return its time interval and data, or `None` if it
+    /// didn't exist.
+    pub fn remove_edge(&mut self, e: EdgeIndex<Ix>) -> Option<TemporalEdge<E, T>> {
+        self.graph.remove_edge(e)
+    }
+
+    /// Access the weight for node `a`, mutably.
+    pub fn node_weight_mut(&mut self, a: NodeIndex<Ix>) -> Option<&mut N> {
+        self.graph.node_weight_mut(a)
+    }
+
+    /// Access the data of edge `e`, mutably.
+    pub fn edge_weight_mut(&mut self, e: EdgeIndex<Ix>) -> Option<&mut E> {
+        self.graph.edge_weight_mut(e).map(|edge| &mut edge.weight)
+    }
+
+    /// Return the time interval of edge `e`, or `None` if it doesn't exist.
+    pub fn interval(&self, e: EdgeIndex<Ix>) -> Option<TimeInterval<T>> {
+        self.graph.edge_weight(e).map(|edge| edge.interval)
+    }
+
+    /// Return the view of the graph with only the edges active during
+    /// `window`, at least one time of it.
+    ///
+    /// The snapshot keeps all the nodes and their indices. It implements the
+    /// visit traits through a reference, like `&snapshot`.
+    pub fn snapshot(&self, window: TimeInterval<T>) -> Snapshot<'_, N, E, T, Ty, Ix> {
+        EdgeFiltered(&self.graph, ActiveDuring(window))
+    }
+
+    /// Return the view of the graph with only the edges active at `time`.
+    pub fn snapshot_at(&self, time: T) -> Snapshot<'_, N, E, T, Ty, Ix> {
+        self.snapshot(TimeInterval {
+            start: time,
+            end: time,
+        })
+    }
+
+    /// Return the earliest time each node can be reached at, leaving
+    /// `source` at time `start`, for the nodes that can be reached.
+    ///
+    /// A time-respecting path follows an edge from the start of its interval
+    /// to its end, and leaves a node by an edge that starts no earlier than
+    /// the path reached it. The edges of an undirected graph are followed in
+    /// both directions.
+    ///
+    /// Computes in **O((|V| + |E|) log |V|)** time.
+    pub fn earliest_arrival(&self, source: NodeIndex<Ix>, start: T) -> HashMap<NodeIndex<Ix>, T> {
+        self.best_times(source, start, Direction::Outgoing)
+    }
+
+    /// Return the latest time each node can be left at, to reach `target`
+    /// by time `deadline`, for the nodes that can reach it.
+    ///
+    /// This is the reverse of
+    /// [`earliest_arrival`](TemporalGraph::earliest_arrival), with the same
+    /// time-respecting paths.
+    ///
+    /// Computes in **O((|V| + |E|) log |V|)** time.
+    pub fn latest_departure(
+        &self,
+        target: NodeIndex<Ix>,
+        deadline: T,
+    ) -> HashMap<NodeIndex<Ix>, T> {
+        self.best_times(target, deadline, Direction::Incoming)
+    }
+
+    /// Search the time-respecting paths from `from` forward in time for
+    /// `Outgoing`, and backward in time for `Incoming`, with a Dijkstra-like
+    /// search: the intervals never end before they start, so the times only
+    /// get worse along a path.
+    fn best_times(
+        &self,
+        from: NodeIndex<Ix>,
+        time: T,
+        dir: Direction,
+    ) -> HashMap<NodeIndex<Ix>, T> {
+        let forward = dir == Direction::Outgoing;
+        // Whether `x` is a strictly better time than `y`.
+        let better = |x: T, y: T| if forward { x < y } else { x > y };
+
+        let mut best: Vec<Option<T>> = vec![None; self.graph.node_count()];
+        let mut done = vec![false; self.graph.node_count()];
+        let mut min_heap = BinaryHeap::new();
+        let mut max_heap = BinaryHeap::new();
+        if from.index() < best.len() {
+            best[from.index()] = Some(time);
+            if forward {
+                min_heap.push(MinScored(time, from));
+            } else {
+                max_heap.push(MaxScored(time, from));
+            }
+        }
+
+        loop {
+            let next = if forward {
+                min_heap.pop().map(|MinScored(t, a)| (t, a))
+            } else {
+                max_heap.pop().map(|MaxScored(t, a)| (t, a))
+            };
+            let (t, a) = match next {
+                Some(next) => next,
+                None => break,
+            };
+            if core::mem::replace(&mut done[a.index()], true) {
+                continue;
+            }
+            for edge in self.graph.edges_directed(a, dir) {
+                let interval = edge.weight().interval;
+                let (leave, reach) = if forward {
+                    (interval.start, interval.end)
+                } else {
+                    (interval.end, interval.start)
+                };
+                // The edge must leave after the path reaches `a`.
+                if better(leave, t) {
+                    continue;
+                }
+                let b = if forward {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                if best[b.index()].map_or(true, |old| better(reach, old)) {
+                    best[b.index()] = Some(reach);
+                    if forward {
+                        min_heap.push(MinScored(reach, b));
+                    } else {
+                        max_heap.push(MaxScored(reach, b));
+                    }
+                }
+            }
+        }
+
+        best.into_iter()
+            .enumerate()
+            .filter_map(|(i, t)| Some((NodeIndex::new(i), t?)))
+            .collect()
+    }
+}
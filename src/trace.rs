@@ -0,0 +1,43 @@
+//! Instrumentation of the algorithms with the `tracing` feature.
+//!
+//! The macros expand to nothing, and a [`Counter`] is zero-sized, when the
+//! feature is disabled, so the instrumentation costs nothing by default.
+
+/// Enter a `DEBUG` level span for the rest of the enclosing block.
+macro_rules! trace_span {
+    ($($args:tt)*) => {
+        #[cfg(feature = "tracing")]
+        let _span = ::tracing::debug_span!($($args)*).entered();
+    };
+}
+
+/// Emit a `DEBUG` level event, typically with the counters of an algorithm.
+macro_rules! trace_event {
+    ($($args:tt)*) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!($($args)*);
+    };
+}
+
+/// A count of the steps of an algorithm, such as the nodes expanded or the
+/// states pruned, reported by [`trace_event!`].
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Counter {
+    #[cfg(feature = "tracing")]
+    count: u64,
+}
+
+impl Counter {
+    #[inline]
+    pub(crate) fn incr(&mut self) {
+        #[cfg(feature = "tracing")]
+        {
+            self.count += 1;
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    pub(crate) fn get(self) -> u64 {
+        self.count
+    }
+}
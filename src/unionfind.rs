@@ -1,8 +1,12 @@
 //! `UnionFind<K>` is a disjoint-set data structure.
+//!
+//! [`RollbackUnionFind`] is a variant whose unions can be undone, and
+//! [`WeightedUnionFind`] one that tracks differences between its elements.
 
 use super::graph::IndexType;
 use alloc::{collections::TryReserveError, vec, vec::Vec};
 use core::cmp::Ordering;
+use core::ops::{Add, Sub};
 
 /// `UnionFind<K>` is a disjoint-set data structure. It tracks set membership of *n* elements
 /// indexed from *0* to *n - 1*. The scalar type is `K` which must be an unsigned integer type.
@@ -415,3 +419,346 @@ impl<K> UnionFind<K> {
         self.rank.shrink_to(min_capacity);
     }
 }
+
+/// `RollbackUnionFind<K>` is a disjoint-set data structure whose unions can be
+/// undone, in the reverse order in which they were made.
+///
+/// It tracks set membership of *n* elements indexed from *0* to *n - 1*, like
+/// [`UnionFind`], but it only balances its trees by rank and does not compress
+/// their paths, so that each union changes a bounded part of it. This is the
+/// structure of offline dynamic connectivity and of backtracking searches.
+///
+/// Finding a representative takes **O(log n)** time, and undoing a union
+/// **O(1)**.
+///
+/// # Example
+/// ```rust
+/// use petgraph::unionfind::RollbackUnionFind;
+///
+/// let mut uf = RollbackUnionFind::<u32>::new(4);
+/// uf.union(0, 1);
+/// let snapshot = uf.snapshot();
+/// uf.union(1, 2);
+/// uf.union(2, 3);
+/// assert!(uf.equiv(0, 3));
+///
+/// uf.rollback(snapshot);
+/// assert!(uf.equiv(0, 1));
+/// assert!(!uf.equiv(0, 2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollbackUnionFind<K> {
+    parent: Vec<K>,
+    rank: Vec<u8>,
+    // The representative put below another by each union, and whether the
+    // rank of the other grew.
+    history: Vec<(K, bool)>,
+}
+
+impl<K> Default for RollbackUnionFind<K> {
+    fn default() -> Self {
+        Self {
+            parent: Vec::new(),
+            rank: Vec::new(),
+            history: Vec::new(),
+        }
+    }
+}
+
+impl<K> RollbackUnionFind<K>
+where
+    K: IndexType,
+{
+    /// Create a new `RollbackUnionFind` of `n` disjoint sets.
+    pub fn new(n: usize) -> Self {
+        RollbackUnionFind {
+            parent: (0..n).map(K::new).collect(),
+            rank: vec![0; n],
+            history: Vec::new(),
+        }
+    }
+
+    /// Returns the number of elements in the union-find data-structure.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns true if there are no elements in the union-find data-structure.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Adds a new disjoint set and returns the index of the new set.
+    ///
+    /// Rolling back does not remove the sets that were added.
+    pub fn new_set(&mut self) -> K {
+        let retval = K::new(self.parent.len());
+        self.rank.push(0);
+        self.parent.push(retval);
+        retval
+    }
+
+    /// Return the representative for `x`.
+    ///
+    /// **Panics** if `x` is out of bounds.
+    #[track_caller]
+    pub fn find(&self, mut x: K) -> K {
+        loop {
+            let xparent = self.parent[x.index()];
+            if xparent == x {
+                return x;
+            }
+            x = xparent;
+        }
+    }
+
+    /// Returns `true` if the given elements belong to the same set, and returns
+    /// `false` otherwise.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
+    #[track_caller]
+    pub fn equiv(&self, x: K, y: K) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Unify the two sets containing `x` and `y`.
+    ///
+    /// Return `false` if the sets were already the same, `true` if they were
+    /// unified. Only the unions that return `true` are recorded to be undone.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
+    #[track_caller]
+    pub fn union(&mut self, x: K, y: K) -> bool {
+        let mut xrep = self.find(x);
+        let mut yrep = self.find(y);
+        if xrep == yrep {
+            return false;
+        }
+        if self.rank[xrep.index()] < self.rank[yrep.index()] {
+            core::mem::swap(&mut xrep, &mut yrep);
+        }
+        let grows = self.rank[xrep.index()] == self.rank[yrep.index()];
+        self.parent[yrep.index()] = xrep;
+        if grows {
+            self.rank[xrep.index()] += 1;
+        }
+        self.history.push((yrep, grows));
+        true
+    }
+
+    /// Return the number of unions made, to [`rollback`][Self::rollback] to.
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undo the last union that is not undone yet, and return `false` if
+    /// there is none.
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some((yrep, grows)) => {
+                let xrep = self.parent[yrep.index()];
+                self.parent[yrep.index()] = yrep;
+                if grows {
+                    self.rank[xrep.index()] -= 1;
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Undo the unions made since `snapshot`, as returned by
+    /// [`snapshot`][Self::snapshot].
+    ///
+    /// Does nothing if they are already undone.
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
+
+/// `WeightedUnionFind<K, W>` is a disjoint-set data structure that also
+/// tracks the differences of *potentials* between the elements of each set.
+///
+/// Each union `union(x, y, w)` states that the potential of `y` minus that of
+/// `x` is `w`: the differences then follow between all the elements of a set,
+/// and the unions that contradict them are rejected. This solves systems of
+/// difference constraints *p(y) - p(x) = w* incrementally, such as the
+/// relative positions or offsets of items.
+///
+/// The differences are in `W`, any commutative group under `+` and `-`, such
+/// as an integer type; with floating point numbers, the differences of
+/// the potentials accumulate rounding errors.
+///
+/// # Example
+/// ```rust
+/// use petgraph::unionfind::WeightedUnionFind;
+///
+/// let mut uf = WeightedUnionFind::<u32, i64>::new(4);
+/// assert_eq!(uf.union(0, 1, 5), Ok(true));
+/// assert_eq!(uf.union(1, 2, -2), Ok(true));
+/// assert_eq!(uf.difference(0, 2), Some(3));
+/// assert_eq!(uf.difference(0, 3), None);
+///
+/// // Consistent with the others, and then not.
+/// assert_eq!(uf.union(2, 0, -3), Ok(false));
+/// assert_eq!(uf.union(2, 0, 1), Err(-3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WeightedUnionFind<K, W> {
+    parent: Vec<K>,
+    rank: Vec<u8>,
+    // The potential of each element minus that of its parent.
+    potential: Vec<W>,
+}
+
+impl<K, W> Default for WeightedUnionFind<K, W> {
+    fn default() -> Self {
+        Self {
+            parent: Vec::new(),
+            rank: Vec::new(),
+            potential: Vec::new(),
+        }
+    }
+}
+
+impl<K, W> WeightedUnionFind<K, W>
+where
+    K: IndexType,
+    W: Copy + Default + PartialEq + Add<Output = W> + Sub<Output = W>,
+{
+    /// Create a new `WeightedUnionFind` of `n` disjoint sets.
+    pub fn new(n: usize) -> Self {
+        WeightedUnionFind {
+            parent: (0..n).map(K::new).collect(),
+            rank: vec![0; n],
+            potential: vec![W::default(); n],
+        }
+    }
+
+    /// Returns the number of elements in the union-find data-structure.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns true if there are no elements in the union-find data-structure.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Adds a new disjoint set and returns the index of the new set.
+    pub fn new_set(&mut self) -> K {
+        let retval = K::new(self.parent.len());
+        self.rank.push(0);
+        self.parent.push(retval);
+        self.potential.push(W::default());
+        retval
+    }
+
+    /// Return the representative for `x`, and the potential of `x` minus
+    /// that of the representative.
+    ///
+    /// **Panics** if `x` is out of bounds.
+    #[track_caller]
+    pub fn find(&self, mut x: K) -> (K, W) {
+        let mut potential = W::default();
+        loop {
+            let xparent = self.parent[x.index()];
+            if xparent == x {
+                return (x, potential);
+            }
+            potential = potential + self.potential[x.index()];
+            x = xparent;
+        }
+    }
+
+    /// Return the representative for `x`, and the potential of `x` minus
+    /// that of the representative.
+    ///
+    /// Write back the found representative, flattening the internal
+    /// datastructure in the process and quicken future lookups.
+    ///
+    /// **Panics** if `x` is out of bounds.
+    #[track_caller]
+    pub fn find_mut(&mut self, x: K) -> (K, W) {
+        let (xrep, potential) = self.find(x);
+        // Point the path to the representative, from the top so that the
+        // potentials above are already relative to it.
+        let mut path = Vec::new();
+        let mut y = x;
+        while self.parent[y.index()] != xrep && y != xrep {
+            path.push(y);
+            y = self.parent[y.index()];
+        }
+        for &y in path.iter().rev() {
+            let yparent = self.parent[y.index()];
+            self.potential[y.index()] = self.potential[y.index()] + self.potential[yparent.index()];
+            self.parent[y.index()] = xrep;
+        }
+        (xrep, potential)
+    }
+
+    /// Returns `true` if the given elements belong to the same set, and returns
+    /// `false` otherwise.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
+    #[track_caller]
+    pub fn equiv(&self, x: K, y: K) -> bool {
+        self.find(x).0 == self.find(y).0
+    }
+
+    /// Return the potential of `y` minus that of `x`, or `None` if they are
+    /// not in the same set.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
+    #[track_caller]
+    pub fn difference(&self, x: K, y: K) -> Option<W> {
+        let (xrep, xpotential) = self.find(x);
+        let (yrep, ypotential) = self.find(y);
+        if xrep == yrep {
+            Some(ypotential - xpotential)
+        } else {
+            None
+        }
+    }
+
+    /// Unify the two sets containing `x` and `y`, so that the potential of `y`
+    /// minus that of `x` is `w`.
+    ///
+    /// Return `Ok(false)` if the sets were already the same, with that
+    /// difference, `Ok(true)` if they were unified, and `Err` with the
+    /// difference if the sets were already the same with another one, in which
+    /// case nothing changes.
+    ///
+    /// **Panics** if `x` or `y` is out of bounds.
+    #[track_caller]
+    pub fn union(&mut self, x: K, y: K, w: W) -> Result<bool, W> {
+        let (xrep, xpotential) = self.find_mut(x);
+        let (yrep, ypotential) = self.find_mut(y);
+        if xrep == yrep {
+            let difference = ypotential - xpotential;
+            return if difference == w {
+                Ok(false)
+            } else {
+                Err(difference)
+            };
+        }
+        // The potential of the representative of `y` minus that of the
+        // representative of `x`.
+        let offset = xpotential + w - ypotential;
+        let xrank = self.rank[xrep.index()];
+        let yrank = self.rank[yrep.index()];
+        if xrank < yrank {
+            self.parent[xrep.index()] = yrep;
+            self.potential[xrep.index()] = W::default() - offset;
+        } else {
+            self.parent[yrep.index()] = xrep;
+            self.potential[yrep.index()] = offset;
+            if xrank == yrank {
+                self.rank[xrep.index()] += 1;
+            }
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,387 @@
+use crate::visit::{
+    Data, Degree, EdgeCount, EdgeIndexable, EdgeRef, GraphBase, GraphProp, GraphRef,
+    IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected,
+    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeIndexable,
+    Visitable,
+};
+use crate::{Directed, Direction, Undirected};
+
+/// An adaptor that views a directed graph as an undirected one, without
+/// copying it.
+///
+/// Each edge connects its endpoints both ways: the edges of a node `a` are
+/// its outgoing and incoming edges, oriented with `a` as their source, as
+/// with the edges of an undirected graph. Unlike [`UndirectedAdaptor`], the
+/// graph type is `Undirected` for the algorithms that require it, and a self
+/// loop is a single neighbor and edge of its node.
+///
+/// ```
+/// use petgraph::graph::DiGraph;
+/// use petgraph::visit::{AsUndirected, EdgeRef, IntoEdges, IntoNeighbors};
+///
+/// let graph = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1)]);
+/// let undirected = AsUndirected(&graph);
+/// assert_eq!(undirected.neighbors(1.into()).count(), 2);
+/// for edge in undirected.edges(1.into()) {
+///     assert_eq!(edge.source(), 1.into());
+/// }
+/// ```
+///
+/// [`UndirectedAdaptor`]: crate::visit::UndirectedAdaptor
+#[derive(Copy, Clone, Debug)]
+pub struct AsUndirected<G>(pub G);
+
+/// An adaptor that views an undirected graph as a directed one, without
+/// copying it.
+///
+/// Each edge is exposed in both directions, with the same edge id, except
+/// for self loops which are exposed once. As the two directions of an edge
+/// share its id, `AsDirected` doesn't implement `EdgeCount` or
+/// `EdgeIndexable`.
+///
+/// ```
+/// use petgraph::graph::UnGraph;
+/// use petgraph::visit::{AsDirected, IntoEdgeReferences, IntoNeighborsDirected};
+/// use petgraph::Direction::Incoming;
+///
+/// let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+/// let directed = AsDirected(&graph);
+/// assert_eq!(directed.edge_references().count(), 4);
+/// assert_eq!(directed.neighbors_directed(0.into(), Incoming).count(), 1);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct AsDirected<G>(pub G);
+
+/// An edge reference of [`AsUndirected`] or [`AsDirected`], in its original
+/// direction or reversed.
+#[derive(Copy, Clone, Debug)]
+pub struct OrientedEdgeReference<R> {
+    edge: R,
+    reversed: bool,
+}
+
+impl<R> OrientedEdgeReference<R> {
+    /// Return `true` if the edge is in the opposite direction to the
+    /// original one.
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Return the original edge reference.
+    pub fn as_original(&self) -> &R {
+        &self.edge
+    }
+
+    /// Consume `self` and return the original edge reference.
+    pub fn into_original(self) -> R {
+        self.edge
+    }
+}
+
+impl<R> EdgeRef for OrientedEdgeReference<R>
+where
+    R: EdgeRef,
+{
+    type NodeId = R::NodeId;
+    type EdgeId = R::EdgeId;
+    type Weight = R::Weight;
+    fn source(&self) -> Self::NodeId {
+        if self.reversed {
+            self.edge.target()
+        } else {
+            self.edge.source()
+        }
+    }
+    fn target(&self) -> Self::NodeId {
+        if self.reversed {
+            self.edge.source()
+        } else {
+            self.edge.target()
+        }
+    }
+    fn weight(&self) -> &Self::Weight {
+        self.edge.weight()
+    }
+    fn id(&self) -> Self::EdgeId {
+        self.edge.id()
+    }
+}
+
+/// An iterator of edge references, all in their original direction or all
+/// reversed.
+#[derive(Clone, Debug)]
+pub struct OrientedEdges<I> {
+    iter: I,
+    reversed: bool,
+}
+
+impl<I> Iterator for OrientedEdges<I>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+{
+    type Item = OrientedEdgeReference<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let reversed = self.reversed;
+        self.iter
+            .next()
+            .map(|edge| OrientedEdgeReference { edge, reversed })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator of the edge references of a first iterator, then the reversed
+/// edge references of a second iterator, except for the self loops.
+#[derive(Clone, Debug)]
+pub struct BidirectionalEdges<I> {
+    forward: I,
+    backward: I,
+}
+
+impl<I> Iterator for BidirectionalEdges<I>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    <I::Item as EdgeRef>::NodeId: PartialEq,
+{
+    type Item = OrientedEdgeReference<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(edge) = self.forward.next() {
+            return Some(OrientedEdgeReference {
+                edge,
+                reversed: false,
+            });
+        }
+        self.backward
+            .by_ref()
+            .find(|edge| edge.source() != edge.target())
+            .map(|edge| OrientedEdgeReference {
+                edge,
+                reversed: true,
+            })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, _) = self.forward.size_hint();
+        let high = match (self.forward.size_hint().1, self.backward.size_hint().1) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (low, high)
+    }
+}
+
+/// The neighbors of a node of [`AsUndirected`]: its successors, then its
+/// predecessors other than itself.
+#[derive(Clone, Debug)]
+pub struct BidirectionalNeighbors<I, N> {
+    forward: I,
+    backward: I,
+    node: N,
+}
+
+impl<I, N> Iterator for BidirectionalNeighbors<I, N>
+where
+    I: Iterator<Item = N>,
+    N: PartialEq,
+{
+    type Item = N;
+    fn next(&mut self) -> Option<N> {
+        if let Some(n) = self.forward.next() {
+            return Some(n);
+        }
+        let node = &self.node;
+        self.backward.by_ref().find(|n| n != node)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, _) = self.forward.size_hint();
+        let high = match (self.forward.size_hint().1, self.backward.size_hint().1) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (low, high)
+    }
+}
+
+impl<G: GraphRef> GraphRef for AsUndirected<G> {}
+
+impl<G> GraphProp for AsUndirected<G>
+where
+    G: GraphBase,
+{
+    type EdgeType = Undirected;
+
+    fn is_directed(&self) -> bool {
+        false
+    }
+}
+
+impl<G> IntoNeighbors for AsUndirected<G>
+where
+    G: IntoNeighborsDirected + GraphProp<EdgeType = Directed>,
+{
+    type Neighbors = BidirectionalNeighbors<G::NeighborsDirected, G::NodeId>;
+    fn neighbors(self, a: G::NodeId) -> Self::Neighbors {
+        BidirectionalNeighbors {
+            forward: self.0.neighbors_directed(a, Direction::Outgoing),
+            backward: self.0.neighbors_directed(a, Direction::Incoming),
+            node: a,
+        }
+    }
+}
+
+impl<G> IntoNeighborsDirected for AsUndirected<G>
+where
+    G: IntoNeighborsDirected + GraphProp<EdgeType = Directed>,
+{
+    type NeighborsDirected = BidirectionalNeighbors<G::NeighborsDirected, G::NodeId>;
+    fn neighbors_directed(self, a: G::NodeId, _dir: Direction) -> Self::NeighborsDirected {
+        self.neighbors(a)
+    }
+}
+
+impl<G> IntoEdgeReferences for AsUndirected<G>
+where
+    G: IntoEdgeReferences + GraphProp<EdgeType = Directed>,
+{
+    type EdgeRef = OrientedEdgeReference<G::EdgeRef>;
+    type EdgeReferences = OrientedEdges<G::EdgeReferences>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        OrientedEdges {
+            iter: self.0.edge_references(),
+            reversed: false,
+        }
+    }
+}
+
+impl<G> IntoEdges for AsUndirected<G>
+where
+    G: IntoEdgesDirected + GraphProp<EdgeType = Directed>,
+{
+    type Edges = BidirectionalEdges<G::EdgesDirected>;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        self.edges_directed(a, Direction::Outgoing)
+    }
+}
+
+impl<G> IntoEdgesDirected for AsUndirected<G>
+where
+    G: IntoEdgesDirected + GraphProp<EdgeType = Directed>,
+{
+    type EdgesDirected = BidirectionalEdges<G::EdgesDirected>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        BidirectionalEdges {
+            forward: self.0.edges_directed(a, dir),
+            backward: self.0.edges_directed(a, dir.opposite()),
+        }
+    }
+}
+
+impl<G> Degree for AsUndirected<G>
+where
+    G: Degree + GraphProp<EdgeType = Directed>,
+{
+    fn out_degree(&self, a: G::NodeId) -> usize {
+        self.degree(a)
+    }
+    fn in_degree(&self, a: G::NodeId) -> usize {
+        self.degree(a)
+    }
+    fn degree(&self, a: G::NodeId) -> usize {
+        self.0.out_degree(a) + self.0.in_degree(a)
+    }
+}
+
+impl<G: GraphRef> GraphRef for AsDirected<G> {}
+
+impl<G> GraphProp for AsDirected<G>
+where
+    G: GraphBase,
+{
+    type EdgeType = Directed;
+}
+
+impl<G> IntoNeighbors for AsDirected<G>
+where
+    G: IntoNeighbors + GraphProp<EdgeType = Undirected>,
+{
+    type Neighbors = G::Neighbors;
+    fn neighbors(self, a: G::NodeId) -> G::Neighbors {
+        self.0.neighbors(a)
+    }
+}
+
+impl<G> IntoNeighborsDirected for AsDirected<G>
+where
+    G: IntoNeighbors + GraphProp<EdgeType = Undirected>,
+{
+    type NeighborsDirected = G::Neighbors;
+    fn neighbors_directed(self, a: G::NodeId, _dir: Direction) -> G::Neighbors {
+        self.0.neighbors(a)
+    }
+}
+
+impl<G> IntoEdgeReferences for AsDirected<G>
+where
+    G: IntoEdgeReferences + GraphProp<EdgeType = Undirected>,
+{
+    type EdgeRef = OrientedEdgeReference<G::EdgeRef>;
+    type EdgeReferences = BidirectionalEdges<G::EdgeReferences>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        BidirectionalEdges {
+            forward: self.0.edge_references(),
+            backward: self.0.edge_references(),
+        }
+    }
+}
+
+impl<G> IntoEdges for AsDirected<G>
+where
+    G: IntoEdges + GraphProp<EdgeType = Undirected>,
+{
+    type Edges = OrientedEdges<G::Edges>;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        self.edges_directed(a, Direction::Outgoing)
+    }
+}
+
+impl<G> IntoEdgesDirected for AsDirected<G>
+where
+    G: IntoEdges + GraphProp<EdgeType = Undirected>,
+{
+    type EdgesDirected = OrientedEdges<G::Edges>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        // The edges of an undirected graph start from `a`.
+        OrientedEdges {
+            iter: self.0.edges(a),
+            reversed: dir == Direction::Incoming,
+        }
+    }
+}
+
+macro_rules! access0 {
+    ($e:expr) => {
+        $e.0
+    };
+}
+
+GraphBase! {delegate_impl [[G], G, AsUndirected<G>, access0]}
+Data! {delegate_impl [[G], G, AsUndirected<G>, access0]}
+Visitable! {delegate_impl [[G], G, AsUndirected<G>, access0]}
+NodeIndexable! {delegate_impl [[G], G, AsUndirected<G>, access0]}
+NodeCompactIndexable! {delegate_impl [[G], G, AsUndirected<G>, access0]}
+IntoNodeIdentifiers! {delegate_impl [[G], G, AsUndirected<G>, access0]}
+IntoNodeReferences! {delegate_impl [[G], G, AsUndirected<G>, access0]}
+NodeCount! {delegate_impl [[G], G, AsUndirected<G>, access0]}
+EdgeCount! {delegate_impl [[G], G, AsUndirected<G>, access0]}
+EdgeIndexable! {delegate_impl [[G], G, AsUndirected<G>, access0]}
+
+GraphBase! {delegate_impl [[G], G, AsDirected<G>, access0]}
+Data! {delegate_impl [[G], G, AsDirected<G>, access0]}
+Visitable! {delegate_impl [[G], G, AsDirected<G>, access0]}
+NodeIndexable! {delegate_impl [[G], G, AsDirected<G>, access0]}
+NodeCompactIndexable! {delegate_impl [[G], G, AsDirected<G>, access0]}
+IntoNodeIdentifiers! {delegate_impl [[G], G, AsDirected<G>, access0]}
+IntoNodeReferences! {delegate_impl [[G], G, AsDirected<G>, access0]}
+NodeCount! {delegate_impl [[G], G, AsDirected<G>, access0]}
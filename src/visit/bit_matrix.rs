@@ -0,0 +1,221 @@
+use core::iter::FusedIterator;
+
+use fixedbitset::{Block, FixedBitSet};
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+
+const BITS: usize = Block::BITS as usize;
+
+/// A compact adjacency matrix, with one bit per pair of nodes.
+///
+/// The rows are stored in a [`FixedBitSet`], each one starting on a new block
+/// of bits, so that whole rows can be scanned or combined block by block.
+/// Nodes are identified by their index, as given by [`NodeIndexable`].
+///
+/// It uses **O(|V|²)** bits of space, a byte for every eight pairs of nodes.
+///
+/// ```
+/// use petgraph::graph::UnGraph;
+/// use petgraph::visit::AdjacencyBitMatrix;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let matrix = AdjacencyBitMatrix::from_graph(&graph);
+/// assert!(matrix.contains(1, 0));
+/// assert!(!matrix.contains(3, 1));
+/// assert_eq!(matrix.neighbors(2).collect::<Vec<_>>(), [0, 1, 3]);
+/// assert_eq!(matrix.count_common_neighbors(0, 1), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AdjacencyBitMatrix {
+    bits: FixedBitSet,
+    node_bound: usize,
+    /// The number of blocks of each row.
+    row_blocks: usize,
+}
+
+impl AdjacencyBitMatrix {
+    /// Create an empty matrix for the nodes of index `0..node_bound`.
+    pub fn new(node_bound: usize) -> Self {
+        let row_blocks = (node_bound + BITS - 1) / BITS;
+        AdjacencyBitMatrix {
+            bits: FixedBitSet::with_capacity(node_bound * row_blocks * BITS),
+            node_bound,
+            row_blocks,
+        }
+    }
+
+    /// \[Generic\] Create the adjacency matrix of a graph.
+    ///
+    /// The edges of an undirected graph are set in both directions.
+    ///
+    /// Computes in **O(|V|²/w + |E|)** time, where **w** is the number of bits
+    /// of a block.
+    pub fn from_graph<G>(graph: G) -> Self
+    where
+        G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    {
+        let mut matrix = AdjacencyBitMatrix::new(graph.node_bound());
+        for edge in graph.edge_references() {
+            let a = graph.to_index(edge.source());
+            let b = graph.to_index(edge.target());
+            matrix.insert(a, b);
+            if !graph.is_directed() {
+                matrix.insert(b, a);
+            }
+        }
+        matrix
+    }
+
+    /// Return the number of rows and columns of the matrix.
+    pub fn node_bound(&self) -> usize {
+        self.node_bound
+    }
+
+    #[inline]
+    fn position(&self, a: usize, b: usize) -> usize {
+        a * self.row_blocks * BITS + b
+    }
+
+    /// Grow the matrix to the nodes of index `0..node_bound`, keeping its
+    /// entries.
+    ///
+    /// Computes in **O(|V|²/w)** time when the blocks of a row are too few
+    /// for the new bound, where **w** is the number of bits of a block.
+    pub fn grow(&mut self, node_bound: usize) {
+        if node_bound <= self.node_bound {
+            return;
+        }
+        let row_blocks = (node_bound + BITS - 1) / BITS;
+        if row_blocks == self.row_blocks {
+            self.bits.grow(node_bound * row_blocks * BITS);
+        } else {
+            let mut bits = FixedBitSet::with_capacity(node_bound * row_blocks * BITS);
+            let blocks = bits.as_mut_slice();
+            for a in 0..self.node_bound {
+                let start = a * row_blocks;
+                blocks[start..start + self.row_blocks].copy_from_slice(self.row(a));
+            }
+            self.bits = bits;
+            self.row_blocks = row_blocks;
+        }
+        self.node_bound = node_bound;
+    }
+
+    /// Set the entry from `a` to `b`, and return `true` if it was already
+    /// set.
+    ///
+    /// **Panics** if `a` or `b` is out of bounds.
+    #[track_caller]
+    pub fn insert(&mut self, a: usize, b: usize) -> bool {
+        assert!(a < self.node_bound && b < self.node_bound);
+        self.bits.put(self.position(a, b))
+    }
+
+    /// Clear the entry from `a` to `b`, and return `true` if it was set.
+    pub fn remove(&mut self, a: usize, b: usize) -> bool {
+        if !self.contains(a, b) {
+            return false;
+        }
+        self.bits.remove(self.position(a, b));
+        true
+    }
+
+    /// Return `true` if the entry from `a` to `b` is set.
+    ///
+    /// Computes in **O(1)** time, and returns `false` if `a` or `b` is out of
+    /// bounds.
+    #[inline]
+    pub fn contains(&self, a: usize, b: usize) -> bool {
+        a < self.node_bound && b < self.node_bound && self.bits.contains(self.position(a, b))
+    }
+
+    /// Clear all entries of row `a` and column `a`, and return the number of
+    /// entries that were set.
+    ///
+    /// Computes in **O(|V|)** time.
+    pub fn clear_node(&mut self, a: usize) -> usize {
+        if a >= self.node_bound {
+            return 0;
+        }
+        let mut count = 0;
+        for b in 0..self.node_bound {
+            count += self.remove(b, a) as usize;
+        }
+        let start = self.position(a, 0);
+        count += self.bits.count_ones(start..start + self.node_bound);
+        self.bits.remove_range(start..start + self.node_bound);
+        count
+    }
+
+    /// Clear all entries.
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// Return the blocks of bits of row `a`.
+    fn row(&self, a: usize) -> &[Block] {
+        if a >= self.node_bound {
+            return &[];
+        }
+        let start = a * self.row_blocks;
+        &self.bits.as_slice()[start..start + self.row_blocks]
+    }
+
+    /// Return an iterator over the set entries of row `a`, in increasing
+    /// order: the outgoing neighbors of `a`.
+    ///
+    /// Computes in **O(|V|/w)** time for the whole iteration, where **w** is
+    /// the number of bits of a block.
+    pub fn neighbors(&self, a: usize) -> BitMatrixNeighbors<'_> {
+        let row = self.row(a);
+        BitMatrixNeighbors {
+            block: row.first().copied().unwrap_or(0),
+            rest: row.get(1..).unwrap_or(&[]),
+            offset: 0,
+        }
+    }
+
+    /// Return the number of nodes `c` with both entries from `a` to `c` and
+    /// from `b` to `c` set.
+    ///
+    /// The rows are intersected block by block.
+    ///
+    /// Computes in **O(|V|/w)** time, where **w** is the number of bits of a
+    /// block.
+    pub fn count_common_neighbors(&self, a: usize, b: usize) -> usize {
+        self.row(a)
+            .iter()
+            .zip(self.row(b))
+            .map(|(x, y)| (x & y).count_ones() as usize)
+            .sum()
+    }
+}
+
+/// Iterator over the set entries of a row of an [`AdjacencyBitMatrix`].
+///
+/// Created with [`AdjacencyBitMatrix::neighbors`].
+#[derive(Clone, Debug)]
+pub struct BitMatrixNeighbors<'a> {
+    block: Block,
+    rest: &'a [Block],
+    offset: usize,
+}
+
+impl Iterator for BitMatrixNeighbors<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.block == 0 {
+            let (&block, rest) = self.rest.split_first()?;
+            self.block = block;
+            self.rest = rest;
+            self.offset += BITS;
+        }
+        let bit = self.block.trailing_zeros() as usize;
+        // clear the lowest set bit
+        self.block &= self.block - 1;
+        Some(self.offset + bit)
+    }
+}
+
+impl FusedIterator for BitMatrixNeighbors<'_> {}
@@ -0,0 +1,273 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::size_of;
+
+use super::{IntoNeighbors, VisitMap, Visitable};
+
+/// How the stack of a [`BoundedDfs`] grows when it is full.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StackGrowth {
+    /// Double the capacity, like `Vec`. Fewest reallocations, but a
+    /// reallocation may briefly use three times the memory of the stack.
+    Doubling,
+    /// Add room for the given number of frames (at least one). Memory use
+    /// stays close to the depth of the traversal, at the cost of more
+    /// reallocations.
+    Linear(usize),
+}
+
+/// What a [`BoundedDfs`] does when it reaches its maximum depth.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DepthLimit {
+    /// Still emit the undiscovered neighbors of the nodes at the maximum
+    /// depth, but don't explore further from them. Nodes that are only
+    /// reachable through them are not visited.
+    Prune,
+    /// End the traversal. [`DfsStats::truncated`] tells that it did not
+    /// complete.
+    Stop,
+}
+
+/// The configuration of a [`BoundedDfs`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DfsConfig {
+    /// The number of frames allocated up front. A traversal that never goes
+    /// deeper never reallocates.
+    pub initial_capacity: usize,
+    /// How the stack grows past its capacity.
+    pub growth: StackGrowth,
+    /// The maximum number of nodes on the current path (the start node has
+    /// depth `1`), or `None` for no limit.
+    pub max_depth: Option<usize>,
+    /// What to do when `max_depth` is reached.
+    pub on_limit: DepthLimit,
+}
+
+impl Default for DfsConfig {
+    /// No preallocation, doubling growth, and no depth limit.
+    fn default() -> Self {
+        DfsConfig {
+            initial_capacity: 0,
+            growth: StackGrowth::Doubling,
+            max_depth: None,
+            on_limit: DepthLimit::Prune,
+        }
+    }
+}
+
+/// Diagnostics of the stack of a [`BoundedDfs`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DfsStats {
+    /// The number of nodes on the current path.
+    pub depth: usize,
+    /// The largest depth reached so far.
+    pub max_depth: usize,
+    /// The number of frames the stack can hold without reallocating.
+    pub capacity: usize,
+    /// The size of one stack frame, in bytes.
+    pub frame_size: usize,
+    /// The number of times the stack was reallocated.
+    pub reallocations: usize,
+    /// The number of nodes whose neighbors were not explored because of the
+    /// depth limit.
+    pub pruned: usize,
+    /// Whether the traversal was stopped by the depth limit.
+    pub truncated: bool,
+}
+
+impl DfsStats {
+    /// Return the memory used by the stack, in bytes.
+    pub fn stack_bytes(&self) -> usize {
+        self.capacity * self.frame_size
+    }
+}
+
+/// Visit nodes of a graph in a depth-first-search (DFS) emitting nodes in
+/// preorder, with a stack of one frame per node of the current path.
+///
+/// [`Dfs`](super::Dfs) pushes all the neighbors of every visited node on its
+/// stack, so its stack can grow to the number of edges of the graph.
+/// `BoundedDfs` instead keeps the neighbor iterator of each node of the
+/// current path, so its memory is proportional to the depth of the
+/// traversal. Its growth policy, an optional depth limit, and diagnostics of
+/// its memory use are set with a [`DfsConfig`] and read with
+/// [`stats`](BoundedDfs::stats).
+///
+/// Neighbors are explored in the order of the graph's neighbor iterator, so
+/// nodes are emitted in the same order as a recursive DFS.
+///
+/// `BoundedDfs` borrows the graph, and is an iterator over its nodes.
+///
+/// # Example
+/// ```
+/// use petgraph::graph::DiGraph;
+/// use petgraph::visit::{BoundedDfs, DepthLimit, DfsConfig, StackGrowth};
+///
+/// // A chain of 10000 nodes.
+/// let graph = DiGraph::<(), ()>::from_edges((0..9999u32).map(|i| (i, i + 1)));
+///
+/// let mut dfs = BoundedDfs::new(&graph, 0.into());
+/// assert_eq!(dfs.by_ref().count(), 10000);
+/// assert_eq!(dfs.stats().max_depth, 10000);
+///
+/// // Limit the depth and the growth of the stack.
+/// let config = DfsConfig {
+///     initial_capacity: 64,
+///     growth: StackGrowth::Linear(64),
+///     max_depth: Some(100),
+///     on_limit: DepthLimit::Stop,
+/// };
+/// let mut dfs = BoundedDfs::with_config(&graph, 0.into(), config);
+/// assert_eq!(dfs.by_ref().count(), 100);
+/// let stats = dfs.stats();
+/// assert!(stats.truncated);
+/// assert_eq!(stats.capacity, 128);
+/// ```
+pub struct BoundedDfs<G>
+where
+    G: IntoNeighbors + Visitable,
+{
+    graph: G,
+    stack: Vec<(G::NodeId, G::Neighbors)>,
+    discovered: G::Map,
+    start: Option<G::NodeId>,
+    config: DfsConfig,
+    stats: DfsStats,
+}
+
+impl<G> BoundedDfs<G>
+where
+    G: IntoNeighbors + Visitable,
+{
+    /// Create a new **BoundedDfs** with the default configuration, starting
+    /// at **start**.
+    pub fn new(graph: G, start: G::NodeId) -> Self {
+        Self::with_config(graph, start, DfsConfig::default())
+    }
+
+    /// Create a new **BoundedDfs** with the given configuration, starting at
+    /// **start**.
+    pub fn with_config(graph: G, start: G::NodeId, config: DfsConfig) -> Self {
+        let stack = Vec::with_capacity(config.initial_capacity);
+        let stats = DfsStats {
+            capacity: stack.capacity(),
+            frame_size: size_of::<(G::NodeId, G::Neighbors)>(),
+            ..DfsStats::default()
+        };
+        BoundedDfs {
+            graph,
+            stack,
+            discovered: graph.visit_map(),
+            start: Some(start),
+            config,
+            stats,
+        }
+    }
+
+    /// Keep the discovered map, but clear the stack and restart the
+    /// traversal from **start**, if it is not discovered yet.
+    pub fn move_to(&mut self, start: G::NodeId) {
+        self.stack.clear();
+        self.stats.depth = 0;
+        self.stats.truncated = false;
+        self.start = Some(start);
+    }
+
+    /// Return the map of discovered nodes.
+    pub fn discovered(&self) -> &G::Map {
+        &self.discovered
+    }
+
+    /// Return the diagnostics of the stack.
+    pub fn stats(&self) -> DfsStats {
+        self.stats
+    }
+
+    /// Discover `node` and return it, pushing its frame unless the depth
+    /// limit is reached.
+    fn enter(&mut self, node: G::NodeId) -> Option<G::NodeId> {
+        if self
+            .config
+            .max_depth
+            .map_or(false, |max| self.stack.len() >= max)
+        {
+            match self.config.on_limit {
+                DepthLimit::Prune => {
+                    self.discovered.visit(node);
+                    self.stats.pruned += 1;
+                    return Some(node);
+                }
+                DepthLimit::Stop => {
+                    self.stack.clear();
+                    self.stats.depth = 0;
+                    self.stats.truncated = true;
+                    return None;
+                }
+            }
+        }
+        self.discovered.visit(node);
+        if self.stack.len() == self.stack.capacity() {
+            let additional = match self.config.growth {
+                StackGrowth::Doubling => self.stack.capacity().max(4),
+                StackGrowth::Linear(step) => step.max(1),
+            };
+            self.stack.reserve_exact(additional);
+            self.stats.capacity = self.stack.capacity();
+            self.stats.reallocations += 1;
+        }
+        self.stack.push((node, self.graph.neighbors(node)));
+        self.stats.depth = self.stack.len();
+        self.stats.max_depth = self.stats.max_depth.max(self.stack.len());
+        Some(node)
+    }
+}
+
+impl<G> Iterator for BoundedDfs<G>
+where
+    G: IntoNeighbors + Visitable,
+{
+    type Item = G::NodeId;
+
+    /// Return the next node in the dfs, or **None** if the traversal is done.
+    fn next(&mut self) -> Option<G::NodeId> {
+        if self.stats.truncated {
+            return None;
+        }
+        if let Some(start) = self.start.take() {
+            if !self.discovered.is_visited(&start) {
+                return self.enter(start);
+            }
+        }
+        loop {
+            let (_, neighbors) = self.stack.last_mut()?;
+            match neighbors.next() {
+                Some(succ) => {
+                    if !self.discovered.is_visited(&succ) {
+                        return self.enter(succ);
+                    }
+                }
+                None => {
+                    self.stack.pop();
+                    self.stats.depth = self.stack.len();
+                }
+            }
+        }
+    }
+}
+
+impl<G> fmt::Debug for BoundedDfs<G>
+where
+    G: IntoNeighbors + Visitable,
+    G::NodeId: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundedDfs")
+            .field(
+                "path",
+                &self.stack.iter().map(|(n, _)| n).collect::<Vec<_>>(),
+            )
+            .field("config", &self.config)
+            .field("stats", &self.stats)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,159 @@
+use core::hash::Hash;
+use core::marker::PhantomData;
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::visit::{Bfs, Data, GraphBase, GraphProp, IntoNeighbors, VisitMap, Visitable};
+use crate::Directed;
+
+/// A graph defined implicitly by a successor function.
+///
+/// `ImplicitGraph` wraps a closure that returns the successors of a node, so
+/// that the graph is explored lazily and never materialized. This is useful
+/// for state space search, where nodes are states and edges are moves
+/// between them, and the full graph is too large or infinite.
+///
+/// A reference to an `ImplicitGraph` implements [`IntoNeighbors`] and
+/// [`Visitable`], so it can be traversed with [`Bfs`], [`Dfs`],
+/// [`DfsPostOrder`] and [`depth_first_search`]. The visit map is a hash set
+/// of the visited nodes, so node ids need to implement `Hash` and `Eq`.
+///
+/// The graph is directed: the edges go from a node to its successors. If the
+/// successor function is symmetric, the graph can be considered undirected,
+/// as in [`ImplicitGraph::component_labels`].
+///
+/// [`Dfs`]: crate::visit::Dfs
+/// [`DfsPostOrder`]: crate::visit::DfsPostOrder
+/// [`depth_first_search`]: crate::visit::depth_first_search
+///
+/// # Example
+/// ```rust
+/// use petgraph::visit::{Bfs, ImplicitGraph};
+///
+/// // The states of a counter modulo 10, stepping by 3 or 4.
+/// let graph = ImplicitGraph::new(|n: u32| [(n + 3) % 10, (n + 4) % 10]);
+///
+/// let mut bfs = Bfs::new(&graph, 0);
+/// let mut order = Vec::new();
+/// while let Some(n) = bfs.next(&graph) {
+///     order.push(n);
+/// }
+/// assert_eq!(order.len(), 10);
+/// assert_eq!(&order[..3], &[0, 3, 4]);
+/// ```
+pub struct ImplicitGraph<N, F> {
+    successors: F,
+    node: PhantomData<fn(N)>,
+}
+
+impl<N, F, I> ImplicitGraph<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    /// Create a new `ImplicitGraph` from a function returning the successors
+    /// of a node.
+    pub fn new(successors: F) -> Self {
+        ImplicitGraph {
+            successors,
+            node: PhantomData,
+        }
+    }
+
+    /// Return the successors of `node`.
+    pub fn successors(&self, node: N) -> I::IntoIter {
+        (self.successors)(node).into_iter()
+    }
+
+    /// Label the connected components of the nodes reachable from `roots`.
+    ///
+    /// Every reachable node is mapped to the index of its component, and
+    /// components are numbered from `0` in the order in which they are
+    /// discovered. The successor function is assumed to be symmetric (every
+    /// move can be undone), so that reachability is the same as
+    /// connectivity. Otherwise, a node is labelled with the component of the
+    /// first root that reaches it.
+    ///
+    /// The search only terminates if a finite number of nodes is reachable.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::visit::ImplicitGraph;
+    ///
+    /// // Moving by two steps on a ring of 8 nodes keeps the parity.
+    /// let graph = ImplicitGraph::new(|n: u8| [(n + 2) % 8, (n + 6) % 8]);
+    ///
+    /// let labels = graph.component_labels(0..8);
+    /// assert_eq!(labels.len(), 8);
+    /// assert_eq!(labels[&0], labels[&6]);
+    /// assert_ne!(labels[&0], labels[&1]);
+    /// ```
+    pub fn component_labels<R>(&self, roots: R) -> HashMap<N, usize>
+    where
+        R: IntoIterator<Item = N>,
+    {
+        let mut labels = HashMap::new();
+        let mut component = 0;
+        let mut bfs = Bfs::<N, HashSet<N>>::default();
+        for root in roots {
+            if !bfs.discovered.visit(root) {
+                continue;
+            }
+            bfs.stack.push_back(root);
+            while let Some(node) = bfs.next(self) {
+                labels.insert(node, component);
+            }
+            component += 1;
+        }
+        labels
+    }
+}
+
+impl<N, F> GraphBase for ImplicitGraph<N, F>
+where
+    N: Copy + PartialEq,
+{
+    type NodeId = N;
+    type EdgeId = (N, N);
+}
+
+impl<N, F> Data for ImplicitGraph<N, F>
+where
+    N: Copy + PartialEq,
+{
+    type NodeWeight = ();
+    type EdgeWeight = ();
+}
+
+impl<N, F> GraphProp for ImplicitGraph<N, F>
+where
+    N: Copy + PartialEq,
+{
+    type EdgeType = Directed;
+}
+
+impl<N, F, I> IntoNeighbors for &ImplicitGraph<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Neighbors = I::IntoIter;
+    fn neighbors(self, a: N) -> Self::Neighbors {
+        self.successors(a)
+    }
+}
+
+impl<N, F> Visitable for ImplicitGraph<N, F>
+where
+    N: Copy + Eq + Hash,
+{
+    type Map = HashSet<N>;
+    fn visit_map(&self) -> HashSet<N> {
+        HashSet::new()
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+    }
+}
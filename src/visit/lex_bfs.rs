@@ -0,0 +1,130 @@
+use alloc::vec;
+use core::marker::PhantomData;
+
+use super::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable, Walker};
+use crate::partition_refinement::PartitionRefinement;
+
+/// Visit all nodes of a graph in a lexicographic breadth-first search
+/// (LexBFS) order.
+///
+/// [LexBFS][1] is a breadth-first search that breaks ties between the nodes
+/// at the same distance: the next node is always one whose visited
+/// neighbors, listed in the order they were visited, form the
+/// lexicographically largest sequence. When a connected component is
+/// exhausted, the traversal continues with an unvisited node.
+///
+/// Its order is the basis of the linear time recognition of chordal graphs
+/// (a graph is chordal if and only if the reverse of a LexBFS order is a
+/// perfect elimination ordering), interval and cograph recognition, and of
+/// several ordering heuristics.
+///
+/// The nodes are kept in a [`PartitionRefinement`], refined by the
+/// neighbors of each visited node. The graph is meant to be undirected: for
+/// a directed graph, only the outgoing edges are followed.
+///
+/// `LexBfs` does not itself borrow the graph, like [`Bfs`](super::Bfs).
+///
+/// [1]: https://en.wikipedia.org/wiki/Lexicographic_breadth-first_search
+///
+/// # Example
+/// ```
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::visit::LexBfs;
+///
+/// // Two triangles sharing the edge 1 - 2, and a pendant node.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+///
+/// let mut lex_bfs = LexBfs::with_start(&graph, NodeIndex::new(0));
+/// let mut order = Vec::new();
+/// while let Some(node) = lex_bfs.next(&graph) {
+///     order.push(node.index());
+/// }
+/// // 3 is adjacent to both 1 and 2, so it comes before 4.
+/// assert_eq!(order[0], 0);
+/// assert_eq!(order[3..], [3, 4]);
+///
+/// // The graph is chordal: the neighbors of each node that are visited
+/// // before it form a clique.
+/// let mut position = vec![0; order.len()];
+/// for (i, &v) in order.iter().enumerate() {
+///     position[v] = i;
+/// }
+/// for &v in &order {
+///     let earlier: Vec<_> = graph
+///         .neighbors(NodeIndex::new(v))
+///         .filter(|w| position[w.index()] < position[v])
+///         .collect();
+///     for &a in &earlier {
+///         for &b in &earlier {
+///             assert!(a == b || graph.contains_edge(a, b));
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LexBfs<N> {
+    /// The unvisited nodes, by index, in the order they are to be visited.
+    partition: PartitionRefinement,
+    node: PhantomData<N>,
+}
+
+impl<N> LexBfs<N>
+where
+    N: Copy,
+{
+    /// Create a new **LexBfs** over all the nodes of the graph.
+    pub fn new<G>(graph: G) -> Self
+    where
+        G: IntoNodeIdentifiers<NodeId = N> + NodeIndexable,
+    {
+        let mut partition = PartitionRefinement::new(graph.node_bound());
+        // Leave out the indices without a node.
+        let mut present = vec![false; graph.node_bound()];
+        for node in graph.node_identifiers() {
+            present[graph.to_index(node)] = true;
+        }
+        for (index, _) in present.iter().enumerate().filter(|(_, &p)| !p) {
+            partition.remove(index);
+        }
+        LexBfs {
+            partition,
+            node: PhantomData,
+        }
+    }
+
+    /// Create a new **LexBfs** over all the nodes of the graph, that visits
+    /// **start** first.
+    pub fn with_start<G>(graph: G, start: N) -> Self
+    where
+        G: IntoNodeIdentifiers<NodeId = N> + NodeIndexable,
+    {
+        let mut lex_bfs = Self::new(graph);
+        lex_bfs.partition.refine([graph.to_index(start)]);
+        lex_bfs
+    }
+
+    /// Return the next node in the LexBFS order, or **None** if all nodes
+    /// were visited.
+    pub fn next<G>(&mut self, graph: G) -> Option<N>
+    where
+        G: IntoNeighbors<NodeId = N> + NodeIndexable,
+    {
+        let first = self.partition.first()?;
+        let index = self.partition.set(first)[0];
+        self.partition.remove(index);
+        let node = graph.from_index(index);
+        self.partition
+            .refine(graph.neighbors(node).map(|w| graph.to_index(w)));
+        Some(node)
+    }
+}
+
+impl<G> Walker<G> for LexBfs<G::NodeId>
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    type Item = G::NodeId;
+    fn walk_next(&mut self, context: G) -> Option<Self::Item> {
+        self.next(context)
+    }
+}
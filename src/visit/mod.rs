@@ -23,6 +23,18 @@
 //! [dfs]: fn.depth_first_search.html
 //! [w]: trait.Walker.html
 //!
+//! ### Graph Views
+//!
+//! The adaptors of [`GraphView`][gv], like `filter_nodes`, `filter_edges`,
+//! `reversed`, `map_node_weights` and `map_edge_weights`, are lazy views of
+//! a graph reference that chain in any order, and that the algorithms accept
+//! like the graph itself. [`AsUndirected`][au] and [`AsDirected`][ad] view a
+//! graph with the other edge type.
+//!
+//! [gv]: trait.GraphView.html
+//! [au]: struct.AsUndirected.html
+//! [ad]: struct.AsDirected.html
+//!
 //! ### Other Graph Traits
 //!
 //! The traits are rather loosely coupled at the moment (which is intentional,
@@ -48,6 +60,7 @@
 //! | NodeIndexable         | x     |  x          |    x     | x           | x     |  x    |
 //! | NodeCompactIndexable  | x     |             |    x     |             | x     |  x    |
 //! | EdgeCount             | x     |  x          |    x     | x           | x     |  x    |
+//! | Degree                | x     |  x          |    x     | x           | x     |       |
 //! | EdgeIndexable         | x     |  x          |    x     |             |       |       |
 //! | Data                  | x     |  x          |    x     | x           | x     |  x    |
 //! | IntoNodeIdentifiers   | x     |  x          |    x     | x           | x     |  x    |
@@ -62,9 +75,17 @@
 
 // filter, reversed have their `mod` lines at the end,
 // so that they can use the trait template macros
+pub use self::as_edge_type::*;
+pub use self::bit_matrix::*;
+pub use self::bounded_dfs::*;
 pub use self::filter::*;
+pub use self::implicit::*;
+pub use self::lex_bfs::*;
+#[cfg(feature = "rand")]
+pub use self::random_walk::*;
 pub use self::reversed::*;
 pub use self::undirected_adaptor::*;
+pub use self::view::*;
 
 #[macro_use]
 mod macros;
@@ -431,6 +452,24 @@ where
     }
 }
 
+/// A visit map of fixed size, which does not allocate, for graphs with at
+/// most `N` nodes.
+impl<Ix, const N: usize> VisitMap<Ix> for [bool; N]
+where
+    Ix: IndexType,
+{
+    fn visit(&mut self, x: Ix) -> bool {
+        !core::mem::replace(&mut self[x.index()], true)
+    }
+    fn is_visited(&self, x: &Ix) -> bool {
+        self[x.index()]
+    }
+
+    fn unvisit(&mut self, x: Ix) -> bool {
+        core::mem::replace(&mut self[x.index()], false)
+    }
+}
+
 impl<N, S> VisitMap<N> for HashSet<N, S>
 where
     N: Hash + Eq,
@@ -516,6 +555,43 @@ pub trait EdgeCount : GraphBase {
 
 EdgeCount! {delegate_impl []}
 
+trait_template! {
+/// Access to the degree of each node.
+///
+/// The degrees count edges, not neighbors, so parallel edges count
+/// separately. Depending on the graph’s edge type, they are:
+///
+/// - `Directed`: `out_degree` is the number of edges from `a`, and
+///   `in_degree` the number of edges to `a`. A self loop counts once in
+///   each, and `degree` is their sum.
+/// - `Undirected`: all three are the number of edge endpoints at `a`, so a
+///   self loop counts twice.
+///
+/// With these conventions the degrees of all nodes sum to twice the number
+/// of edges, and the in- and out-degrees each sum to the number of edges of
+/// a directed graph.
+#[allow(clippy::needless_arbitrary_self_type)]
+pub trait Degree : GraphBase {
+    @section self
+    /// Return the number of edges starting from `a`.
+    fn out_degree(self: &Self, a: Self::NodeId) -> usize;
+    /// Return the number of edges ending at `a`.
+    fn in_degree(self: &Self, a: Self::NodeId) -> usize;
+    /// Return the number of edge endpoints at `a`.
+    fn degree(self: &Self, a: Self::NodeId) -> usize;
+}
+}
+
+Degree! {delegate_impl []}
+
+mod as_edge_type;
+mod bit_matrix;
+mod bounded_dfs;
 mod filter;
+mod implicit;
+mod lex_bfs;
+#[cfg(feature = "rand")]
+mod random_walk;
 mod reversed;
 mod undirected_adaptor;
+mod view;
@@ -0,0 +1,332 @@
+use alloc::vec::Vec;
+
+use fixedbitset::FixedBitSet;
+use hashbrown::HashSet;
+use rand::Rng;
+
+use super::{EdgeRef, IntoEdges, NodeIndexable};
+
+/// A random walk on a graph, from a start node.
+///
+/// The walk yields the start node, then moves at each step along a random
+/// outgoing edge of the current node, chosen with a probability
+/// proportional to its weight (every edge has weight `1` for a walk
+/// created with [`new`](RandomWalk::new)). It ends at a node without
+/// outgoing edges of positive weight, so it is usually limited with
+/// [`Iterator::take`]. In an undirected graph, every edge can be followed
+/// in both directions.
+///
+/// `RandomWalk` borrows the random number generator, so that many walks can
+/// be drawn from the same generator, one after another.
+///
+/// # Example
+/// ```
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::visit::RandomWalk;
+/// use petgraph::SeededRng;
+///
+/// let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.0), (1, 2, 0.0), (1, 3, 2.0)]);
+/// let mut rng = SeededRng::new(7);
+///
+/// let walk: Vec<_> = RandomWalk::new(&graph, NodeIndex::new(0), &mut rng)
+///     .take(10)
+///     .collect();
+/// assert_eq!(walk.len(), 10);
+/// assert!(walk.windows(2).all(|w| graph.contains_edge(w[0], w[1])));
+///
+/// // The edge of weight 0 is never followed.
+/// let walk: Vec<_> = RandomWalk::weighted(&graph, NodeIndex::new(0), &mut rng, |e| *e.weight())
+///     .take(100)
+///     .collect();
+/// assert!(!walk.contains(&NodeIndex::new(2)));
+/// ```
+pub struct RandomWalk<'r, G, R, F>
+where
+    G: IntoEdges,
+    R: ?Sized,
+{
+    graph: G,
+    rng: &'r mut R,
+    edge_weight: F,
+    next: Option<G::NodeId>,
+    candidates: Vec<(G::NodeId, f64)>,
+}
+
+impl<'r, G, R> RandomWalk<'r, G, R, fn(G::EdgeRef) -> f64>
+where
+    G: IntoEdges,
+    R: Rng + ?Sized,
+{
+    /// Create a new **RandomWalk** from `start`, that follows each outgoing
+    /// edge with the same probability.
+    pub fn new(graph: G, start: G::NodeId, rng: &'r mut R) -> Self {
+        Self::weighted(graph, start, rng, |_| 1.0)
+    }
+}
+
+impl<'r, G, R, F> RandomWalk<'r, G, R, F>
+where
+    G: IntoEdges,
+    R: Rng + ?Sized,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    /// Create a new **RandomWalk** from `start`, that follows each outgoing
+    /// edge with a probability proportional to `edge_weight`, which should
+    /// be non-negative.
+    pub fn weighted(graph: G, start: G::NodeId, rng: &'r mut R, edge_weight: F) -> Self {
+        RandomWalk {
+            graph,
+            rng,
+            edge_weight,
+            next: Some(start),
+            candidates: Vec::new(),
+        }
+    }
+}
+
+impl<G, R, F> Iterator for RandomWalk<'_, G, R, F>
+where
+    G: IntoEdges,
+    R: Rng + ?Sized,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    type Item = G::NodeId;
+
+    fn next(&mut self) -> Option<G::NodeId> {
+        let node = self.next?;
+        self.candidates.clear();
+        for edge in self.graph.edges(node) {
+            let weight = (self.edge_weight)(edge);
+            self.candidates.push((edge.target(), weight));
+        }
+        self.next = choose(&self.candidates, self.rng);
+        Some(node)
+    }
+}
+
+/// A biased second order random walk on a graph, from a start node, as in
+/// [node2vec][1].
+///
+/// Like a [`RandomWalk`], the walk yields the start node and moves along
+/// random outgoing edges, but the probability of an edge also depends on
+/// the previous node `t` of the walk: the weight of an edge to a node `x`
+/// is multiplied by `1 / p` if `x` is `t`, by `1` if `x` is a neighbor of
+/// `t`, and by `1 / q` otherwise. A large *return parameter* `p` makes the
+/// walk explore rather than backtrack, and a large *in-out parameter* `q`
+/// keeps it close to its start, like a breadth-first search, while a small
+/// `q` moves it away, like a depth-first search. With `p = q = 1`, it is an
+/// ordinary random walk.
+///
+/// The walks are meant to be the "sentences" from which node embeddings are
+/// learned, for example with a skip-gram model.
+///
+/// [1]: https://arxiv.org/abs/1607.00653
+///
+/// # Example
+/// ```
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::visit::Node2VecWalk;
+/// use petgraph::SeededRng;
+///
+/// // A star: the walk alternates between the center and the leaves, and
+/// // with a small return parameter, it mostly goes back to the same leaf.
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let mut rng = SeededRng::new(3);
+///
+/// let walk: Vec<_> = Node2VecWalk::new(&graph, NodeIndex::new(1), 0.25, 4.0, &mut rng)
+///     .take(9)
+///     .collect();
+/// assert!(walk.iter().step_by(2).all(|&v| v != NodeIndex::new(0)));
+/// assert!(walk.iter().skip(1).step_by(2).all(|&v| v == NodeIndex::new(0)));
+/// ```
+pub struct Node2VecWalk<'r, G, R, F>
+where
+    G: IntoEdges,
+    R: ?Sized,
+{
+    graph: G,
+    rng: &'r mut R,
+    edge_weight: F,
+    return_factor: f64,
+    out_factor: f64,
+    previous: Option<G::NodeId>,
+    next: Option<G::NodeId>,
+    candidates: Vec<(G::NodeId, f64)>,
+    /// The neighbors of the previous node.
+    around: FixedBitSet,
+}
+
+impl<'r, G, R> Node2VecWalk<'r, G, R, fn(G::EdgeRef) -> f64>
+where
+    G: IntoEdges + NodeIndexable,
+    R: Rng + ?Sized,
+{
+    /// Create a new **Node2VecWalk** from `start`, with return parameter `p`
+    /// and in-out parameter `q`, where every edge has weight `1`.
+    ///
+    /// **Panics** if `p` or `q` is not positive.
+    pub fn new(graph: G, start: G::NodeId, p: f64, q: f64, rng: &'r mut R) -> Self {
+        Self::weighted(graph, start, p, q, rng, |_| 1.0)
+    }
+}
+
+impl<'r, G, R, F> Node2VecWalk<'r, G, R, F>
+where
+    G: IntoEdges + NodeIndexable,
+    R: Rng + ?Sized,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    /// Create a new **Node2VecWalk** from `start`, with return parameter `p`
+    /// and in-out parameter `q`, where the weight of an edge is given by
+    /// `edge_weight`, which should be non-negative.
+    ///
+    /// **Panics** if `p` or `q` is not positive.
+    pub fn weighted(
+        graph: G,
+        start: G::NodeId,
+        p: f64,
+        q: f64,
+        rng: &'r mut R,
+        edge_weight: F,
+    ) -> Self {
+        assert!(
+            p > 0.0 && q > 0.0,
+            "Node2VecWalk: the parameters must be positive"
+        );
+        Node2VecWalk {
+            graph,
+            rng,
+            edge_weight,
+            return_factor: 1.0 / p,
+            out_factor: 1.0 / q,
+            previous: None,
+            next: Some(start),
+            candidates: Vec::new(),
+            around: FixedBitSet::with_capacity(graph.node_bound()),
+        }
+    }
+}
+
+impl<G, R, F> Iterator for Node2VecWalk<'_, G, R, F>
+where
+    G: IntoEdges + NodeIndexable,
+    R: Rng + ?Sized,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    type Item = G::NodeId;
+
+    fn next(&mut self) -> Option<G::NodeId> {
+        let node = self.next?;
+        let g = self.graph;
+        self.around.clear();
+        if let Some(previous) = self.previous {
+            for w in g.neighbors(previous) {
+                self.around.insert(g.to_index(w));
+            }
+        }
+        self.candidates.clear();
+        for edge in g.edges(node) {
+            let x = edge.target();
+            let bias = match self.previous {
+                None => 1.0,
+                Some(previous) if x == previous => self.return_factor,
+                Some(_) if self.around.contains(g.to_index(x)) => 1.0,
+                Some(_) => self.out_factor,
+            };
+            let weight = (self.edge_weight)(edge);
+            self.candidates.push((x, bias * weight));
+        }
+        self.previous = Some(node);
+        self.next = choose(&self.candidates, self.rng);
+        Some(node)
+    }
+}
+
+/// Return a random node of `candidates`, with a probability proportional
+/// to its weight, or `None` if no weight is positive.
+fn choose<N, R>(candidates: &[(N, f64)], rng: &mut R) -> Option<N>
+where
+    N: Copy,
+    R: Rng + ?Sized,
+{
+    let positive = |&&(_, weight): &&(N, f64)| weight > 0.0;
+    let total: f64 = candidates.iter().filter(positive).map(|&(_, w)| w).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut x = rng.gen::<f64>() * total;
+    let mut chosen = None;
+    for &(node, weight) in candidates.iter().filter(positive) {
+        chosen = Some(node);
+        if x < weight {
+            break;
+        }
+        x -= weight;
+    }
+    chosen
+}
+
+/// Sample the nodes of a graph around `start` with a random walk with
+/// restarts.
+///
+/// A uniform [`RandomWalk`] is run from `start`, which jumps back to
+/// `start` with probability `restart` before each step, and whenever it is
+/// stuck. The distinct nodes it visits, in the order of their first visit,
+/// are the sample. The sample is biased towards the nodes close to `start`
+/// and of high degree, and its induced subgraph keeps much of the local
+/// structure of the graph.
+///
+/// The walk stops when `size` nodes are sampled, or after `100 · size`
+/// steps, so that the sample is smaller if few nodes are reachable from
+/// `start`.
+///
+/// **Panics** if `restart` is not between `0` and `1`.
+///
+/// # Example
+/// ```
+/// use petgraph::graph::{NodeIndex, UnGraph};
+/// use petgraph::visit::random_walk_sample;
+/// use petgraph::SeededRng;
+///
+/// let edges: Vec<(u32, u32)> = (0..100).map(|i| (i, (i + 1) % 100)).collect();
+/// let graph = UnGraph::<(), ()>::from_edges(&edges);
+///
+/// let sample = random_walk_sample(&graph, NodeIndex::new(0), 10, 0.15, &mut SeededRng::new(1));
+/// assert_eq!(sample.len(), 10);
+/// assert_eq!(sample[0], NodeIndex::new(0));
+/// ```
+pub fn random_walk_sample<G, R>(
+    graph: G,
+    start: G::NodeId,
+    size: usize,
+    restart: f64,
+    rng: &mut R,
+) -> Vec<G::NodeId>
+where
+    G: IntoEdges + NodeIndexable,
+    R: Rng + ?Sized,
+{
+    assert!(
+        (0.0..=1.0).contains(&restart),
+        "random_walk_sample: the restart probability must be between 0 and 1"
+    );
+    let mut sample = Vec::new();
+    let mut sampled = HashSet::new();
+    let mut current = start;
+    for _ in 0..size.saturating_mul(100) {
+        if sample.len() == size {
+            break;
+        }
+        if sampled.insert(graph.to_index(current)) {
+            sample.push(current);
+        }
+        current = if rng.gen_bool(restart) {
+            start
+        } else {
+            let mut walk = RandomWalk::new(graph, current, &mut *rng);
+            walk.next();
+            walk.next().unwrap_or(start)
+        };
+    }
+    sample
+}
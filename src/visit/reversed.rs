@@ -1,10 +1,10 @@
 use crate::{Direction, Incoming};
 
 use crate::visit::{
-    Data, EdgeCount, EdgeIndexable, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp, GraphRef,
-    IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected,
-    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeIndexable,
-    Visitable,
+    Data, Degree, EdgeCount, EdgeIndexable, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp,
+    GraphRef, IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors,
+    IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable,
+    NodeCount, NodeIndexable, Visitable,
 };
 
 /// An edge-reversing graph adaptor.
@@ -182,3 +182,18 @@ NodeCount! {delegate_impl [[G], G, Reversed<G>, access0]}
 EdgeCount! {delegate_impl [[G], G, Reversed<G>, access0]}
 EdgeIndexable! {delegate_impl [[G], G, Reversed<G>, access0]}
 GetAdjacencyMatrix! {delegate_impl [[G], G, Reversed<G>, access0]}
+
+impl<G> Degree for Reversed<G>
+where
+    G: Degree,
+{
+    fn out_degree(&self, a: G::NodeId) -> usize {
+        self.0.in_degree(a)
+    }
+    fn in_degree(&self, a: G::NodeId) -> usize {
+        self.0.out_degree(a)
+    }
+    fn degree(&self, a: G::NodeId) -> usize {
+        self.0.degree(a)
+    }
+}
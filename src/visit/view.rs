@@ -0,0 +1,806 @@
+use crate::data::DataMap;
+use crate::visit::{
+    Data, Degree, EdgeCount, EdgeIndexable, EdgeRef, FilterEdge, FilterNode, GetAdjacencyMatrix,
+    GraphBase, GraphProp, GraphRef, IntoEdgeReferences, IntoEdges, IntoEdgesDirected,
+    IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences,
+    NodeCompactIndexable, NodeCount, NodeIndexable, NodeRef, Reversed, Visitable,
+};
+use crate::Direction;
+
+/// Lazy views of a graph, that chain without copying it.
+///
+/// `GraphView` is implemented for every graph reference, like `&Graph`. Each
+/// of its methods wraps the graph in an adaptor that is a graph reference
+/// too, so that they can be chained in any order, and that implements as
+/// many of the graph traits as the graph it wraps, with these exceptions:
+///
+/// - [`filter_nodes`](GraphView::filter_nodes) leaves holes in the node
+///   indices, so the view is not `NodeCompactIndexable`, and its `NodeCount`
+///   and `EdgeCount` count the nodes and edges it keeps, in linear time.
+/// - [`filter_edges`](GraphView::filter_edges) needs the edges of a node to
+///   find its neighbors, and its `EdgeCount` counts the edges it keeps, in
+///   linear time.
+/// - The weights of [`map_node_weights`](GraphView::map_node_weights) and
+///   [`map_edge_weights`](GraphView::map_edge_weights) are computed when the
+///   nodes or edges are visited, so they must be `Copy`, and the views are
+///   not `DataMap`.
+///
+/// The filters and maps are stored in the views, which must be `Copy` to be
+/// graph references: closures are, as long as they only capture references
+/// or `Copy` values.
+///
+/// ```
+/// use petgraph::algo::dijkstra;
+/// use petgraph::graph::{DiGraph, EdgeReference};
+/// use petgraph::visit::{EdgeRef, GraphView, IntoNodeReferences, NodeRef};
+///
+/// let mut graph = DiGraph::<&str, u32>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// let x = graph.add_node("x");
+/// graph.extend_with_edges([(a, b, 1), (b, c, 2), (a, x, 1), (x, c, 1), (a, c, 10)]);
+///
+/// // The paths that end at `c`, that avoid `x` and the edges above 5,
+/// // with costs in tenths.
+/// let view = (&graph)
+///     .filter_nodes(|n| n != x)
+///     .filter_edges(|e: EdgeReference<u32>| *e.weight() <= 5)
+///     .map_edge_weights(|e| e.weight() * 10)
+///     .reversed();
+/// let costs = dijkstra(view, c, None, |e| *e.weight());
+/// assert_eq!(costs[&a], 30);
+/// assert!(!costs.contains_key(&x));
+///
+/// let names = (&graph).map_node_weights(|_, name| name.len());
+/// assert!(names.node_references().all(|n| *n.weight() == 1));
+/// ```
+pub trait GraphView: GraphRef {
+    /// Return the view of the graph with only the nodes that `filter`
+    /// includes, and the edges between them.
+    fn filter_nodes<F>(self, filter: F) -> FilterNodes<Self, F>
+    where
+        F: FilterNode<Self::NodeId> + Copy,
+    {
+        FilterNodes(self, filter)
+    }
+
+    /// Return the view of the graph with only the edges that `filter`
+    /// includes, and all the nodes.
+    fn filter_edges<F>(self, filter: F) -> FilterEdges<Self, F>
+    where
+        Self: IntoEdgeReferences,
+        F: FilterEdge<Self::EdgeRef> + Copy,
+    {
+        FilterEdges(self, filter)
+    }
+
+    /// Return the view of the graph with all edges in the opposite
+    /// direction.
+    ///
+    /// The method is not called `reverse`, so that it doesn't hide the
+    /// methods of the graphs that reverse their edges in place.
+    fn reversed(self) -> Reversed<Self> {
+        Reversed(self)
+    }
+
+    /// Return the view of the graph with the node weights that `map`
+    /// computes from the node identifier and its weight.
+    fn map_node_weights<F, N>(self, map: F) -> MapNodeWeights<Self, F>
+    where
+        Self: Data,
+        F: Fn(Self::NodeId, &Self::NodeWeight) -> N + Copy,
+        N: Copy,
+    {
+        MapNodeWeights(self, map)
+    }
+
+    /// Return the view of the graph with the edge weights that `map`
+    /// computes from the edge reference.
+    fn map_edge_weights<F, E>(self, map: F) -> MapEdgeWeights<Self, F>
+    where
+        Self: IntoEdgeReferences,
+        F: Fn(Self::EdgeRef) -> E + Copy,
+        E: Copy,
+    {
+        MapEdgeWeights(self, map)
+    }
+}
+
+impl<G: GraphRef> GraphView for G {}
+
+macro_rules! access0 {
+    ($e:expr) => {
+        $e.0
+    };
+}
+
+/// A node-filtering graph view, created with [`GraphView::filter_nodes`].
+///
+/// Unlike [`NodeFiltered`](crate::visit::NodeFiltered), it is a graph
+/// reference itself, so that it can be wrapped in other views.
+#[derive(Copy, Clone, Debug)]
+pub struct FilterNodes<G, F>(pub G, pub F);
+
+impl<G: GraphRef, F: Copy> GraphRef for FilterNodes<G, F> {}
+
+GraphBase! {delegate_impl [[G, F], G, FilterNodes<G, F>, access0]}
+Data! {delegate_impl [[G, F], G, FilterNodes<G, F>, access0]}
+GraphProp! {delegate_impl [[G, F], G, FilterNodes<G, F>, access0]}
+NodeIndexable! {delegate_impl [[G, F], G, FilterNodes<G, F>, access0]}
+EdgeIndexable! {delegate_impl [[G, F], G, FilterNodes<G, F>, access0]}
+Visitable! {delegate_impl [[G, F], G, FilterNodes<G, F>, access0]}
+
+impl<G, F> IntoNeighbors for FilterNodes<G, F>
+where
+    G: IntoNeighbors,
+    F: FilterNode<G::NodeId> + Copy,
+{
+    type Neighbors = FilterNodesIter<G::Neighbors, F>;
+    fn neighbors(self, a: G::NodeId) -> Self::Neighbors {
+        FilterNodesIter {
+            include_source: self.1.include_node(a),
+            iter: self.0.neighbors(a),
+            filter: self.1,
+        }
+    }
+}
+
+impl<G, F> IntoNeighborsDirected for FilterNodes<G, F>
+where
+    G: IntoNeighborsDirected,
+    F: FilterNode<G::NodeId> + Copy,
+{
+    type NeighborsDirected = FilterNodesIter<G::NeighborsDirected, F>;
+    fn neighbors_directed(self, a: G::NodeId, dir: Direction) -> Self::NeighborsDirected {
+        FilterNodesIter {
+            include_source: self.1.include_node(a),
+            iter: self.0.neighbors_directed(a, dir),
+            filter: self.1,
+        }
+    }
+}
+
+impl<G, F> IntoNodeIdentifiers for FilterNodes<G, F>
+where
+    G: IntoNodeIdentifiers,
+    F: FilterNode<G::NodeId> + Copy,
+{
+    type NodeIdentifiers = FilterNodesIter<G::NodeIdentifiers, F>;
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        FilterNodesIter {
+            include_source: true,
+            iter: self.0.node_identifiers(),
+            filter: self.1,
+        }
+    }
+}
+
+impl<G, F> IntoNodeReferences for FilterNodes<G, F>
+where
+    G: IntoNodeReferences,
+    F: FilterNode<G::NodeId> + Copy,
+{
+    type NodeRef = G::NodeRef;
+    type NodeReferences = FilterNodesReferences<G::NodeReferences, F>;
+    fn node_references(self) -> Self::NodeReferences {
+        FilterNodesReferences {
+            iter: self.0.node_references(),
+            filter: self.1,
+        }
+    }
+}
+
+impl<G, F> IntoEdgeReferences for FilterNodes<G, F>
+where
+    G: IntoEdgeReferences,
+    F: FilterNode<G::NodeId> + Copy,
+{
+    type EdgeRef = G::EdgeRef;
+    type EdgeReferences = FilterNodesEdges<G::EdgeReferences, F>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        FilterNodesEdges {
+            include_source: true,
+            iter: self.0.edge_references(),
+            filter: self.1,
+        }
+    }
+}
+
+impl<G, F> IntoEdges for FilterNodes<G, F>
+where
+    G: IntoEdges,
+    F: FilterNode<G::NodeId> + Copy,
+{
+    type Edges = FilterNodesEdges<G::Edges, F>;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        FilterNodesEdges {
+            include_source: self.1.include_node(a),
+            iter: self.0.edges(a),
+            filter: self.1,
+        }
+    }
+}
+
+impl<G, F> IntoEdgesDirected for FilterNodes<G, F>
+where
+    G: IntoEdgesDirected,
+    F: FilterNode<G::NodeId> + Copy,
+{
+    type EdgesDirected = FilterNodesEdges<G::EdgesDirected, F>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        FilterNodesEdges {
+            include_source: self.1.include_node(a),
+            iter: self.0.edges_directed(a, dir),
+            filter: self.1,
+        }
+    }
+}
+
+impl<G, F> NodeCount for FilterNodes<G, F>
+where
+    G: IntoNodeIdentifiers,
+    F: FilterNode<G::NodeId> + Copy,
+{
+    /// Count the nodes of the view.
+    ///
+    /// Computes in **O(|V|)** time.
+    fn node_count(&self) -> usize {
+        self.node_identifiers().count()
+    }
+}
+
+impl<G, F> EdgeCount for FilterNodes<G, F>
+where
+    G: IntoEdgeReferences,
+    F: FilterNode<G::NodeId> + Copy,
+{
+    /// Count the edges of the view.
+    ///
+    /// Computes in **O(|E|)** time.
+    fn edge_count(&self) -> usize {
+        self.edge_references().count()
+    }
+}
+
+impl<G, F> DataMap for FilterNodes<G, F>
+where
+    G: DataMap,
+    F: FilterNode<G::NodeId>,
+{
+    fn node_weight(&self, id: Self::NodeId) -> Option<&Self::NodeWeight> {
+        if self.1.include_node(id) {
+            self.0.node_weight(id)
+        } else {
+            None
+        }
+    }
+
+    fn edge_weight(&self, id: Self::EdgeId) -> Option<&Self::EdgeWeight> {
+        self.0.edge_weight(id)
+    }
+}
+
+impl<G, F> GetAdjacencyMatrix for FilterNodes<G, F>
+where
+    G: GetAdjacencyMatrix,
+    F: FilterNode<G::NodeId>,
+{
+    type AdjMatrix = G::AdjMatrix;
+    fn adjacency_matrix(&self) -> Self::AdjMatrix {
+        self.0.adjacency_matrix()
+    }
+    fn is_adjacent(&self, matrix: &Self::AdjMatrix, a: G::NodeId, b: G::NodeId) -> bool {
+        self.1.include_node(a) && self.1.include_node(b) && self.0.is_adjacent(matrix, a, b)
+    }
+}
+
+/// Iterator over the nodes of a [`FilterNodes`] view, as node identifiers.
+#[derive(Clone, Debug)]
+pub struct FilterNodesIter<I, F> {
+    include_source: bool,
+    iter: I,
+    filter: F,
+}
+
+impl<I, F> Iterator for FilterNodesIter<I, F>
+where
+    I: Iterator,
+    I::Item: Copy,
+    F: FilterNode<I::Item>,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.include_source {
+            return None;
+        }
+        let filter = &self.filter;
+        self.iter.find(|&n| filter.include_node(n))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+/// Iterator over the node references of a [`FilterNodes`] view.
+#[derive(Clone, Debug)]
+pub struct FilterNodesReferences<I, F> {
+    iter: I,
+    filter: F,
+}
+
+impl<I, F> Iterator for FilterNodesReferences<I, F>
+where
+    I: Iterator,
+    I::Item: NodeRef,
+    F: FilterNode<<I::Item as NodeRef>::NodeId>,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let filter = &self.filter;
+        self.iter.find(|n| filter.include_node(n.id()))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+/// Iterator over the edges of a [`FilterNodes`] view.
+#[derive(Clone, Debug)]
+pub struct FilterNodesEdges<I, F> {
+    include_source: bool,
+    iter: I,
+    filter: F,
+}
+
+impl<I, F> Iterator for FilterNodesEdges<I, F>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    F: FilterNode<<I::Item as EdgeRef>::NodeId>,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.include_source {
+            return None;
+        }
+        let filter = &self.filter;
+        self.iter
+            .find(|e| filter.include_node(e.source()) && filter.include_node(e.target()))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+/// An edge-filtering graph view, created with [`GraphView::filter_edges`].
+///
+/// Unlike [`EdgeFiltered`](crate::visit::EdgeFiltered), it is a graph
+/// reference itself, so that it can be wrapped in other views.
+#[derive(Copy, Clone, Debug)]
+pub struct FilterEdges<G, F>(pub G, pub F);
+
+impl<G: GraphRef, F: Copy> GraphRef for FilterEdges<G, F> {}
+
+GraphBase! {delegate_impl [[G, F], G, FilterEdges<G, F>, access0]}
+Data! {delegate_impl [[G, F], G, FilterEdges<G, F>, access0]}
+GraphProp! {delegate_impl [[G, F], G, FilterEdges<G, F>, access0]}
+NodeIndexable! {delegate_impl [[G, F], G, FilterEdges<G, F>, access0]}
+NodeCompactIndexable! {delegate_impl [[G, F], G, FilterEdges<G, F>, access0]}
+NodeCount! {delegate_impl [[G, F], G, FilterEdges<G, F>, access0]}
+EdgeIndexable! {delegate_impl [[G, F], G, FilterEdges<G, F>, access0]}
+Visitable! {delegate_impl [[G, F], G, FilterEdges<G, F>, access0]}
+IntoNodeIdentifiers! {delegate_impl [[G, F: Copy], G, FilterEdges<G, F>, access0]}
+IntoNodeReferences! {delegate_impl [[G, F: Copy], G, FilterEdges<G, F>, access0]}
+
+impl<G, F> IntoNeighbors for FilterEdges<G, F>
+where
+    G: IntoEdges,
+    F: FilterEdge<G::EdgeRef> + Copy,
+{
+    type Neighbors = FilterEdgesNeighbors<G::Edges, F>;
+    fn neighbors(self, a: G::NodeId) -> Self::Neighbors {
+        FilterEdgesNeighbors {
+            iter: self.0.edges(a),
+            filter: self.1,
+            dir: Direction::Outgoing,
+        }
+    }
+}
+
+impl<G, F> IntoNeighborsDirected for FilterEdges<G, F>
+where
+    G: IntoEdgesDirected,
+    F: FilterEdge<G::EdgeRef> + Copy,
+{
+    type NeighborsDirected = FilterEdgesNeighbors<G::EdgesDirected, F>;
+    fn neighbors_directed(self, a: G::NodeId, dir: Direction) -> Self::NeighborsDirected {
+        FilterEdgesNeighbors {
+            iter: self.0.edges_directed(a, dir),
+            filter: self.1,
+            dir,
+        }
+    }
+}
+
+impl<G, F> IntoEdgeReferences for FilterEdges<G, F>
+where
+    G: IntoEdgeReferences,
+    F: FilterEdge<G::EdgeRef> + Copy,
+{
+    type EdgeRef = G::EdgeRef;
+    type EdgeReferences = FilterEdgesIter<G::EdgeReferences, F>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        FilterEdgesIter {
+            iter: self.0.edge_references(),
+            filter: self.1,
+        }
+    }
+}
+
+impl<G, F> IntoEdges for FilterEdges<G, F>
+where
+    G: IntoEdges,
+    F: FilterEdge<G::EdgeRef> + Copy,
+{
+    type Edges = FilterEdgesIter<G::Edges, F>;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        FilterEdgesIter {
+            iter: self.0.edges(a),
+            filter: self.1,
+        }
+    }
+}
+
+impl<G, F> IntoEdgesDirected for FilterEdges<G, F>
+where
+    G: IntoEdgesDirected,
+    F: FilterEdge<G::EdgeRef> + Copy,
+{
+    type EdgesDirected = FilterEdgesIter<G::EdgesDirected, F>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        FilterEdgesIter {
+            iter: self.0.edges_directed(a, dir),
+            filter: self.1,
+        }
+    }
+}
+
+impl<G, F> EdgeCount for FilterEdges<G, F>
+where
+    G: IntoEdgeReferences,
+    F: FilterEdge<G::EdgeRef> + Copy,
+{
+    /// Count the edges of the view.
+    ///
+    /// Computes in **O(|E|)** time.
+    fn edge_count(&self) -> usize {
+        self.edge_references().count()
+    }
+}
+
+/// Iterator over the edges of a [`FilterEdges`] view.
+#[derive(Clone, Debug)]
+pub struct FilterEdgesIter<I, F> {
+    iter: I,
+    filter: F,
+}
+
+impl<I, F> Iterator for FilterEdgesIter<I, F>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    F: FilterEdge<I::Item>,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let filter = &self.filter;
+        self.iter.find(|&e| filter.include_edge(e))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+/// Iterator over the neighbors of a node of a [`FilterEdges`] view.
+#[derive(Clone, Debug)]
+pub struct FilterEdgesNeighbors<I, F> {
+    iter: I,
+    filter: F,
+    dir: Direction,
+}
+
+impl<I, F> Iterator for FilterEdgesNeighbors<I, F>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    F: FilterEdge<I::Item>,
+{
+    type Item = <I::Item as EdgeRef>::NodeId;
+    fn next(&mut self) -> Option<Self::Item> {
+        let filter = &self.filter;
+        let edge = self.iter.find(|&e| filter.include_edge(e))?;
+        Some(match self.dir {
+            Direction::Outgoing => edge.target(),
+            Direction::Incoming => edge.source(),
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+/// A graph view with computed node weights, created with
+/// [`GraphView::map_node_weights`].
+#[derive(Copy, Clone, Debug)]
+pub struct MapNodeWeights<G, F>(pub G, pub F);
+
+impl<G: GraphRef, F: Copy> GraphRef for MapNodeWeights<G, F> {}
+
+GraphBase! {delegate_impl [[G, F], G, MapNodeWeights<G, F>, access0]}
+GraphProp! {delegate_impl [[G, F], G, MapNodeWeights<G, F>, access0]}
+NodeIndexable! {delegate_impl [[G, F], G, MapNodeWeights<G, F>, access0]}
+NodeCompactIndexable! {delegate_impl [[G, F], G, MapNodeWeights<G, F>, access0]}
+NodeCount! {delegate_impl [[G, F], G, MapNodeWeights<G, F>, access0]}
+EdgeCount! {delegate_impl [[G, F], G, MapNodeWeights<G, F>, access0]}
+EdgeIndexable! {delegate_impl [[G, F], G, MapNodeWeights<G, F>, access0]}
+Visitable! {delegate_impl [[G, F], G, MapNodeWeights<G, F>, access0]}
+GetAdjacencyMatrix! {delegate_impl [[G, F], G, MapNodeWeights<G, F>, access0]}
+Degree! {delegate_impl [[G, F], G, MapNodeWeights<G, F>, access0]}
+IntoNeighbors! {delegate_impl [[G, F: Copy], G, MapNodeWeights<G, F>, access0]}
+IntoNeighborsDirected! {delegate_impl [[G, F: Copy], G, MapNodeWeights<G, F>, access0]}
+IntoNodeIdentifiers! {delegate_impl [[G, F: Copy], G, MapNodeWeights<G, F>, access0]}
+
+impl<G, F, N> Data for MapNodeWeights<G, F>
+where
+    G: Data,
+    F: Fn(G::NodeId, &G::NodeWeight) -> N,
+{
+    type NodeWeight = N;
+    type EdgeWeight = G::EdgeWeight;
+}
+
+impl<G, F, N> IntoNodeReferences for MapNodeWeights<G, F>
+where
+    G: IntoNodeReferences,
+    F: Fn(G::NodeId, &G::NodeWeight) -> N + Copy,
+    N: Copy,
+{
+    type NodeRef = MappedNodeReference<G::NodeId, N>;
+    type NodeReferences = MapNodeReferences<G::NodeReferences, F>;
+    fn node_references(self) -> Self::NodeReferences {
+        MapNodeReferences {
+            iter: self.0.node_references(),
+            map: self.1,
+        }
+    }
+}
+
+impl<G, F, N> IntoEdgeReferences for MapNodeWeights<G, F>
+where
+    G: IntoEdgeReferences,
+    F: Fn(G::NodeId, &G::NodeWeight) -> N + Copy,
+{
+    type EdgeRef = G::EdgeRef;
+    type EdgeReferences = G::EdgeReferences;
+    fn edge_references(self) -> Self::EdgeReferences {
+        self.0.edge_references()
+    }
+}
+
+impl<G, F, N> IntoEdges for MapNodeWeights<G, F>
+where
+    G: IntoEdges,
+    F: Fn(G::NodeId, &G::NodeWeight) -> N + Copy,
+{
+    type Edges = G::Edges;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        self.0.edges(a)
+    }
+}
+
+impl<G, F, N> IntoEdgesDirected for MapNodeWeights<G, F>
+where
+    G: IntoEdgesDirected,
+    F: Fn(G::NodeId, &G::NodeWeight) -> N + Copy,
+{
+    type EdgesDirected = G::EdgesDirected;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        self.0.edges_directed(a, dir)
+    }
+}
+
+/// A node reference with a computed weight, of a [`MapNodeWeights`] view.
+#[derive(Copy, Clone, Debug)]
+pub struct MappedNodeReference<Id, N> {
+    id: Id,
+    weight: N,
+}
+
+impl<Id: Copy, N: Copy> NodeRef for MappedNodeReference<Id, N> {
+    type NodeId = Id;
+    type Weight = N;
+    fn id(&self) -> Id {
+        self.id
+    }
+    fn weight(&self) -> &N {
+        &self.weight
+    }
+}
+
+/// Iterator over the node references of a [`MapNodeWeights`] view.
+#[derive(Clone, Debug)]
+pub struct MapNodeReferences<I, F> {
+    iter: I,
+    map: F,
+}
+
+impl<I, F, N> Iterator for MapNodeReferences<I, F>
+where
+    I: Iterator,
+    I::Item: NodeRef,
+    F: Fn(<I::Item as NodeRef>::NodeId, &<I::Item as NodeRef>::Weight) -> N,
+{
+    type Item = MappedNodeReference<<I::Item as NodeRef>::NodeId, N>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.iter.next()?;
+        Some(MappedNodeReference {
+            id: node.id(),
+            weight: (self.map)(node.id(), node.weight()),
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A graph view with computed edge weights, created with
+/// [`GraphView::map_edge_weights`].
+#[derive(Copy, Clone, Debug)]
+pub struct MapEdgeWeights<G, F>(pub G, pub F);
+
+impl<G: GraphRef, F: Copy> GraphRef for MapEdgeWeights<G, F> {}
+
+GraphBase! {delegate_impl [[G, F], G, MapEdgeWeights<G, F>, access0]}
+GraphProp! {delegate_impl [[G, F], G, MapEdgeWeights<G, F>, access0]}
+NodeIndexable! {delegate_impl [[G, F], G, MapEdgeWeights<G, F>, access0]}
+NodeCompactIndexable! {delegate_impl [[G, F], G, MapEdgeWeights<G, F>, access0]}
+NodeCount! {delegate_impl [[G, F], G, MapEdgeWeights<G, F>, access0]}
+EdgeCount! {delegate_impl [[G, F], G, MapEdgeWeights<G, F>, access0]}
+EdgeIndexable! {delegate_impl [[G, F], G, MapEdgeWeights<G, F>, access0]}
+Visitable! {delegate_impl [[G, F], G, MapEdgeWeights<G, F>, access0]}
+GetAdjacencyMatrix! {delegate_impl [[G, F], G, MapEdgeWeights<G, F>, access0]}
+Degree! {delegate_impl [[G, F], G, MapEdgeWeights<G, F>, access0]}
+IntoNeighbors! {delegate_impl [[G, F: Copy], G, MapEdgeWeights<G, F>, access0]}
+IntoNeighborsDirected! {delegate_impl [[G, F: Copy], G, MapEdgeWeights<G, F>, access0]}
+IntoNodeIdentifiers! {delegate_impl [[G, F: Copy], G, MapEdgeWeights<G, F>, access0]}
+
+impl<G, F, E> Data for MapEdgeWeights<G, F>
+where
+    G: IntoEdgeReferences,
+    F: Fn(G::EdgeRef) -> E,
+{
+    type NodeWeight = G::NodeWeight;
+    type EdgeWeight = E;
+}
+
+impl<G, F, E> IntoNodeReferences for MapEdgeWeights<G, F>
+where
+    G: IntoNodeReferences + IntoEdgeReferences,
+    F: Fn(G::EdgeRef) -> E + Copy,
+{
+    type NodeRef = G::NodeRef;
+    type NodeReferences = G::NodeReferences;
+    fn node_references(self) -> Self::NodeReferences {
+        self.0.node_references()
+    }
+}
+
+impl<G, F, E> IntoEdgeReferences for MapEdgeWeights<G, F>
+where
+    G: IntoEdgeReferences,
+    F: Fn(G::EdgeRef) -> E + Copy,
+    E: Copy,
+{
+    type EdgeRef = MappedEdgeReference<G::EdgeRef, E>;
+    type EdgeReferences = MapEdgeReferences<G::EdgeReferences, F>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        MapEdgeReferences {
+            iter: self.0.edge_references(),
+            map: self.1,
+        }
+    }
+}
+
+impl<G, F, E> IntoEdges for MapEdgeWeights<G, F>
+where
+    G: IntoEdges,
+    F: Fn(G::EdgeRef) -> E + Copy,
+    E: Copy,
+{
+    type Edges = MapEdgeReferences<G::Edges, F>;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        MapEdgeReferences {
+            iter: self.0.edges(a),
+            map: self.1,
+        }
+    }
+}
+
+impl<G, F, E> IntoEdgesDirected for MapEdgeWeights<G, F>
+where
+    G: IntoEdgesDirected,
+    F: Fn(G::EdgeRef) -> E + Copy,
+    E: Copy,
+{
+    type EdgesDirected = MapEdgeReferences<G::EdgesDirected, F>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        MapEdgeReferences {
+            iter: self.0.edges_directed(a, dir),
+            map: self.1,
+        }
+    }
+}
+
+/// An edge reference with a computed weight, of a [`MapEdgeWeights`] view.
+#[derive(Copy, Clone, Debug)]
+pub struct MappedEdgeReference<R, E> {
+    edge: R,
+    weight: E,
+}
+
+impl<R, E> MappedEdgeReference<R, E> {
+    /// Return the original edge reference, with the original weight.
+    pub fn as_unmapped(&self) -> &R {
+        &self.edge
+    }
+}
+
+impl<R: EdgeRef, E: Copy> EdgeRef for MappedEdgeReference<R, E> {
+    type NodeId = R::NodeId;
+    type EdgeId = R::EdgeId;
+    type Weight = E;
+    fn source(&self) -> R::NodeId {
+        self.edge.source()
+    }
+    fn target(&self) -> R::NodeId {
+        self.edge.target()
+    }
+    fn weight(&self) -> &E {
+        &self.weight
+    }
+    fn id(&self) -> R::EdgeId {
+        self.edge.id()
+    }
+}
+
+/// Iterator over the edges of a [`MapEdgeWeights`] view.
+#[derive(Clone, Debug)]
+pub struct MapEdgeReferences<I, F> {
+    iter: I,
+    map: F,
+}
+
+impl<I, F, E> Iterator for MapEdgeReferences<I, F>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    F: Fn(I::Item) -> E,
+{
+    type Item = MappedEdgeReference<I::Item, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let edge = self.iter.next()?;
+        Some(MappedEdgeReference {
+            edge,
+            weight: (self.map)(edge),
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,273 @@
+//! A minimal XML reader and escaping, for the XML based graph formats.
+//!
+//! The reader checks that the tags are balanced, decodes the predefined and
+//! numeric entities, and skips the prolog, comments, processing
+//! instructions and doctype declarations. It does not validate against a
+//! schema or resolve namespaces: element and attribute names are returned
+//! without their prefix.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+/// An event of the [`Reader`].
+#[derive(Debug)]
+pub(crate) enum Event<'a> {
+    /// A start tag, with its attributes, and whether it is an empty element
+    /// tag, which is not followed by an end tag.
+    Start {
+        name: &'a str,
+        attributes: Vec<(&'a str, String)>,
+        empty: bool,
+    },
+    /// An end tag.
+    End(&'a str),
+    /// Character data, including CDATA sections.
+    Text(String),
+}
+
+/// An XML reader that yields the [`Event`]s of a document.
+pub(crate) struct Reader<'a> {
+    input: &'a str,
+    position: usize,
+    open: Vec<&'a str>,
+    root_done: bool,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Reader {
+            input,
+            position: 0,
+            open: Vec::new(),
+            root_done: false,
+        }
+    }
+
+    /// Return the line, from `1`, of the current position.
+    pub(crate) fn line(&self) -> usize {
+        1 + self.input.as_bytes()[..self.position]
+            .iter()
+            .filter(|&&b| b == b'\n')
+            .count()
+    }
+
+    /// Return the next event, `None` at the end of the document, or a
+    /// description of the syntax error.
+    pub(crate) fn next_event(&mut self) -> Result<Option<Event<'a>>, &'static str> {
+        loop {
+            let rest = &self.input[self.position..];
+            if rest.is_empty() {
+                return if !self.root_done {
+                    Err("unexpected end of input, without a complete root element")
+                } else {
+                    Ok(None)
+                };
+            }
+            if !rest.starts_with('<') {
+                let end = rest.find('<').unwrap_or(rest.len());
+                self.position += end;
+                let text = &rest[..end];
+                if self.open.is_empty() {
+                    if text.trim().is_empty() {
+                        continue;
+                    }
+                    return Err("text outside of the root element");
+                }
+                return decode(text).map(|text| Some(Event::Text(text)));
+            }
+            if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let end = cdata.find("]]>").ok_or("unterminated CDATA section")?;
+                self.position += "<![CDATA[".len() + end + "]]>".len();
+                return Ok(Some(Event::Text(cdata[..end].into())));
+            }
+            if rest.starts_with("<!--") {
+                self.skip_past("-->", "unterminated comment")?;
+                continue;
+            }
+            if rest.starts_with("<?") {
+                self.skip_past("?>", "unterminated processing instruction")?;
+                continue;
+            }
+            if rest.starts_with("<!") {
+                self.skip_declaration()?;
+                continue;
+            }
+            if let Some(tag) = rest.strip_prefix("</") {
+                let end = tag.find('>').ok_or("unterminated end tag")?;
+                let name = tag[..end].trim_end();
+                self.position += 2 + end + 1;
+                return match self.open.pop() {
+                    Some(open) if open == name => {
+                        self.root_done = self.open.is_empty();
+                        Ok(Some(Event::End(local(name))))
+                    }
+                    _ => Err("mismatched end tag"),
+                };
+            }
+            return self.start_tag().map(Some);
+        }
+    }
+
+    fn skip_past(&mut self, terminator: &str, error: &'static str) -> Result<(), &'static str> {
+        let end = self.input[self.position..].find(terminator).ok_or(error)?;
+        self.position += end + terminator.len();
+        Ok(())
+    }
+
+    /// Skip a declaration such as a doctype, with its internal subset.
+    fn skip_declaration(&mut self) -> Result<(), &'static str> {
+        let mut depth = 0;
+        for (i, c) in self.input[self.position..].char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.position += i + 1;
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Err("unterminated declaration")
+    }
+
+    fn start_tag(&mut self) -> Result<Event<'a>, &'static str> {
+        let input = self.input;
+        let mut i = self.position + 1;
+        let end = name_end(input, i);
+        let name = &input[i..end];
+        if name.is_empty() {
+            return Err("invalid tag name");
+        }
+        if self.root_done {
+            return Err("more than one root element");
+        }
+        i = end;
+        let mut attributes = Vec::new();
+        loop {
+            i = skip_whitespace(input, i);
+            let rest = &input[i..];
+            if rest.starts_with("/>") {
+                self.position = i + 2;
+                self.root_done = self.open.is_empty();
+                return Ok(Event::Start {
+                    name: local(name),
+                    attributes,
+                    empty: true,
+                });
+            }
+            if rest.starts_with('>') {
+                self.position = i + 1;
+                self.open.push(name);
+                return Ok(Event::Start {
+                    name: local(name),
+                    attributes,
+                    empty: false,
+                });
+            }
+            if rest.is_empty() {
+                return Err("unterminated start tag");
+            }
+            let key_end = name_end(input, i);
+            let key = &input[i..key_end];
+            if key.is_empty() {
+                return Err("invalid attribute name");
+            }
+            i = skip_whitespace(input, key_end);
+            if !input[i..].starts_with('=') {
+                return Err("expected '=' after an attribute name");
+            }
+            i = skip_whitespace(input, i + 1);
+            let quote = match input[i..].chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err("expected a quoted attribute value"),
+            };
+            let value_end = input[i + 1..]
+                .find(quote)
+                .ok_or("unterminated attribute value")?;
+            let value = &input[i + 1..i + 1 + value_end];
+            if value.contains('<') {
+                return Err("'<' in an attribute value");
+            }
+            attributes.push((local(key), decode(value)?));
+            i += 1 + value_end + 1;
+        }
+    }
+}
+
+fn name_end(input: &str, start: usize) -> usize {
+    input[start..]
+        .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '=' | '<'))
+        .map_or(input.len(), |end| start + end)
+}
+
+fn skip_whitespace(input: &str, start: usize) -> usize {
+    input[start..]
+        .find(|c: char| !c.is_whitespace())
+        .map_or(input.len(), |end| start + end)
+}
+
+/// Return a name without its namespace prefix.
+fn local(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Decode the entity and character references of `text`.
+pub(crate) fn decode(text: &str) -> Result<String, &'static str> {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        let end = rest[start..].find(';').ok_or("unterminated reference")?;
+        let entity = &rest[start + 1..start + end];
+        let c = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16)
+                } else if let Some(decimal) = entity.strip_prefix('#') {
+                    decimal.parse()
+                } else {
+                    return Err("unknown entity");
+                };
+                code.ok()
+                    .and_then(char::from_u32)
+                    .ok_or("invalid character reference")?
+            }
+        };
+        decoded.push(c);
+        rest = &rest[start + end + 1..];
+    }
+    decoded.push_str(rest);
+    Ok(decoded)
+}
+
+/// Format a string escaped for XML character data and attribute values.
+pub(crate) struct Escaped<'a>(pub(crate) &'a str);
+
+impl fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rest = self.0;
+        while let Some(i) = rest.find(['<', '>', '&', '"', '\'', '\n', '\r', '\t']) {
+            f.write_str(&rest[..i])?;
+            f.write_str(match rest.as_bytes()[i] {
+                b'<' => "&lt;",
+                b'>' => "&gt;",
+                b'&' => "&amp;",
+                b'"' => "&quot;",
+                b'\'' => "&apos;",
+                b'\n' => "&#10;",
+                b'\r' => "&#13;",
+                _ => "&#9;",
+            })?;
+            rest = &rest[i + 1..];
+        }
+        f.write_str(rest)
+    }
+}
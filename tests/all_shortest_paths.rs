@@ -0,0 +1,81 @@
+use petgraph::algo::all_shortest_paths;
+use petgraph::{Graph, Undirected};
+
+#[test]
+fn all_shortest_paths_grid() {
+    // 3x3 grid with unit weights: there are 6 shortest paths between
+    // opposite corners.
+    let mut graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+    let nodes: Vec<_> = (0..9).map(|_| graph.add_node(())).collect();
+    for r in 0..3 {
+        for c in 0..3 {
+            if c + 1 < 3 {
+                graph.add_edge(nodes[r * 3 + c], nodes[r * 3 + c + 1], 1);
+            }
+            if r + 1 < 3 {
+                graph.add_edge(nodes[r * 3 + c], nodes[(r + 1) * 3 + c], 1);
+            }
+        }
+    }
+
+    let (cost, paths) =
+        all_shortest_paths(&graph, nodes[0], nodes[8], |e| *e.weight(), None).unwrap();
+    assert_eq!(cost, 4);
+    assert_eq!(paths.len(), 6);
+    for path in &paths {
+        assert_eq!(path.len(), 5);
+        assert_eq!(path[0], nodes[0]);
+        assert_eq!(path[4], nodes[8]);
+    }
+    let mut sorted = paths.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), 6);
+
+    let (_, capped) =
+        all_shortest_paths(&graph, nodes[0], nodes[8], |e| *e.weight(), Some(4)).unwrap();
+    assert_eq!(capped.len(), 4);
+}
+
+#[test]
+fn all_shortest_paths_parallel_edges() {
+    let mut graph = Graph::<(), u32>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.extend_with_edges([(a, b, 1), (a, b, 1), (a, b, 2)]);
+
+    let (cost, paths) = all_shortest_paths(&graph, a, b, |e| *e.weight(), None).unwrap();
+    assert_eq!(cost, 1);
+    assert_eq!(paths, vec![vec![a, b]]);
+}
+
+#[test]
+fn all_shortest_paths_zero_cost_edges() {
+    let mut graph = Graph::<(), u32>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges([(a, b, 1), (a, c, 1), (c, b, 0)]);
+
+    let (cost, mut paths) = all_shortest_paths(&graph, a, b, |e| *e.weight(), None).unwrap();
+    paths.sort();
+    assert_eq!(cost, 1);
+    assert_eq!(paths, vec![vec![a, b], vec![a, c, b]]);
+}
+
+#[test]
+fn all_shortest_paths_same_node_and_unreachable() {
+    let mut graph = Graph::<(), u32>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge(b, a, 1);
+
+    assert_eq!(
+        all_shortest_paths(&graph, a, a, |e| *e.weight(), None),
+        Some((0, vec![vec![a]]))
+    );
+    assert_eq!(
+        all_shortest_paths(&graph, a, b, |e| *e.weight(), None),
+        None
+    );
+}
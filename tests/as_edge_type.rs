@@ -0,0 +1,87 @@
+use petgraph::algo::{connected_components, dijkstra, is_cyclic_undirected, toposort};
+use petgraph::prelude::*;
+use petgraph::visit::{
+    AsDirected, AsUndirected, Degree, EdgeRef, IntoEdgeReferences, IntoEdges, IntoEdgesDirected,
+    IntoNeighbors, IntoNeighborsDirected,
+};
+
+fn sorted<T: Ord>(iter: impl IntoIterator<Item = T>) -> Vec<T> {
+    let mut v: Vec<_> = iter.into_iter().collect();
+    v.sort();
+    v
+}
+
+#[test]
+fn as_undirected() {
+    // Two components, with a self loop on node 3.
+    let graph = DiGraph::<(), u32>::from_edges([(0, 1, 1), (2, 1, 2), (3, 3, 3), (3, 4, 4)]);
+    let undirected = AsUndirected(&graph);
+    assert_eq!(connected_components(undirected), 2);
+    assert!(is_cyclic_undirected(undirected));
+
+    let n = NodeIndex::new;
+    assert_eq!(sorted(undirected.neighbors(n(1))), [n(0), n(2)]);
+    assert_eq!(
+        sorted(undirected.neighbors_directed(n(1), Incoming)),
+        [n(0), n(2)]
+    );
+    assert_eq!(sorted(undirected.neighbors(n(3))), [n(3), n(4)]);
+
+    for a in graph.node_indices() {
+        for edge in undirected.edges(a) {
+            assert_eq!(edge.source(), a);
+        }
+        for edge in undirected.edges_directed(a, Incoming) {
+            assert_eq!(edge.target(), a);
+        }
+        assert_eq!(undirected.edges(a).count(), undirected.neighbors(a).count());
+    }
+    let edge = undirected.edges(n(1)).find(|e| e.target() == n(0)).unwrap();
+    assert!(edge.is_reversed());
+    assert_eq!(*edge.weight(), 1);
+    assert_eq!(undirected.edge_references().count(), 4);
+
+    assert_eq!(undirected.degree(n(1)), 2);
+    assert_eq!(undirected.out_degree(n(3)), 3);
+
+    // Distances follow the edges both ways.
+    let distances = dijkstra(undirected, n(0), None, |e| *e.weight());
+    assert_eq!(distances[&n(2)], 3);
+}
+
+#[test]
+fn as_directed() {
+    let graph = UnGraph::<(), u32>::from_edges([(0, 1, 1), (1, 2, 2), (2, 2, 3)]);
+    let directed = AsDirected(&graph);
+
+    let n = NodeIndex::new;
+    // Both directions of each edge, and the self loop once.
+    let mut edges: Vec<_> = directed
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+        .collect();
+    edges.sort_unstable();
+    assert_eq!(
+        edges,
+        [(0, 1, 1), (1, 0, 1), (1, 2, 2), (2, 1, 2), (2, 2, 3)]
+    );
+
+    for a in graph.node_indices() {
+        for edge in directed.edges_directed(a, Outgoing) {
+            assert_eq!(edge.source(), a);
+        }
+        for edge in directed.edges_directed(a, Incoming) {
+            assert_eq!(edge.target(), a);
+        }
+    }
+    assert_eq!(
+        sorted(directed.neighbors_directed(n(1), Incoming)),
+        [n(0), n(2)]
+    );
+
+    // Every edge makes a cycle of length two.
+    assert!(toposort(directed, None).is_err());
+    let path = UnGraph::<(), ()>::from_edges([(0, 1)]);
+    let distances = dijkstra(AsDirected(&path), n(1), None, |_| 1);
+    assert_eq!(distances[&n(0)], 1);
+}
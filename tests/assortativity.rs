@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+use petgraph::algo::assortativity::{
+    attribute_assortativity, average_degree_connectivity, average_neighbor_degree,
+    degree_assortativity, numeric_assortativity,
+};
+use petgraph::graph::{DiGraph, UnGraph};
+
+fn pearson(pairs: &[(f64, f64)]) -> f64 {
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|p| p.1).sum::<f64>() / n;
+    let covariance = pairs
+        .iter()
+        .map(|p| (p.0 - mean_x) * (p.1 - mean_y))
+        .sum::<f64>();
+    let variance_x = pairs.iter().map(|p| (p.0 - mean_x).powi(2)).sum::<f64>();
+    let variance_y = pairs.iter().map(|p| (p.1 - mean_y).powi(2)).sum::<f64>();
+    covariance / (variance_x * variance_y).sqrt()
+}
+
+#[test]
+fn degree_correlations() {
+    let edges = [(0, 1), (0, 2), (0, 3), (1, 2), (3, 4), (4, 0), (2, 5)];
+    let graph = DiGraph::<(), ()>::from_edges(edges);
+    let out_degree = |v: u32| edges.iter().filter(|e| e.0 == v).count() as f64;
+    let in_degree = |v: u32| edges.iter().filter(|e| e.1 == v).count() as f64;
+    let pairs: Vec<_> = edges
+        .iter()
+        .map(|&(a, b)| (out_degree(a), in_degree(b)))
+        .collect();
+    let r = degree_assortativity(&graph).unwrap();
+    assert!((r - pearson(&pairs)).abs() < 1e-12);
+
+    // The undirected degree assortativity is the numeric assortativity of
+    // the degrees.
+    let graph = UnGraph::<(), ()>::from_edges(edges);
+    let r = degree_assortativity(&graph).unwrap();
+    let numeric = numeric_assortativity(&graph, |v| graph.neighbors(v).count() as f64).unwrap();
+    assert!((r - numeric).abs() < 1e-12);
+
+    // A regular graph has no degree variance.
+    let cycle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(degree_assortativity(&cycle), None);
+    assert_eq!(degree_assortativity(&UnGraph::<(), ()>::default()), None);
+
+    // Out-degrees of the successors, in a directed graph.
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2), (1, 3), (1, 0)]);
+    let average = average_neighbor_degree(&graph);
+    assert_eq!(average[0.into()], 1.5);
+    assert_eq!(average[1.into()], 2.0 / 3.0);
+    assert_eq!(average[2.into()], 0.0);
+    assert_eq!(
+        average_degree_connectivity(&graph),
+        [(2, 1.5), (3, 2.0 / 3.0)]
+    );
+}
+
+#[test]
+fn categorical_assortativity() {
+    // All the edges inside the categories.
+    let graph = UnGraph::<u8, ()>::from_edges([(0, 1), (2, 3), (3, 4)]);
+    let category = |v: petgraph::graph::NodeIndex| v.index() < 2;
+    assert_eq!(attribute_assortativity(&graph, category), Some(1.0));
+
+    // All the edges between two categories of the same size.
+    let graph = UnGraph::<u8, ()>::from_edges([(0, 1), (2, 3), (1, 2)]);
+    let parity = |v: petgraph::graph::NodeIndex| v.index() % 2;
+    assert_eq!(attribute_assortativity(&graph, parity), Some(-1.0));
+
+    // A single category.
+    assert_eq!(attribute_assortativity(&graph, |_| ()), None);
+}
@@ -0,0 +1,109 @@
+use petgraph::bipartite::{BipartiteError, BipartiteGraph, ProjectionWeight, Side};
+use petgraph::graph::{NodeIndex, UnGraph};
+
+fn affiliation() -> BipartiteGraph<&'static str, ()> {
+    // people 0..=3, groups 4..=6
+    let mut g = BipartiteGraph::new();
+    for name in ["a", "b", "c", "d"] {
+        g.add_left(name);
+    }
+    for name in ["x", "y", "z"] {
+        g.add_right(name);
+    }
+    for (a, b) in [(0, 4), (1, 4), (2, 4), (1, 5), (2, 5), (3, 6)] {
+        g.add_edge(NodeIndex::new(a), NodeIndex::new(b), ())
+            .unwrap();
+    }
+    g
+}
+
+fn edges(g: &UnGraph<NodeIndex, f64>) -> Vec<(usize, usize, f64)> {
+    g.raw_edges()
+        .iter()
+        .map(|e| (g[e.source()].index(), g[e.target()].index(), e.weight))
+        .collect()
+}
+
+#[test]
+fn sides() {
+    let mut g = affiliation();
+    assert_eq!(
+        g.left_nodes().map(|n| n.index()).collect::<Vec<_>>(),
+        [0, 1, 2, 3]
+    );
+    assert_eq!(
+        g.right_nodes().map(|n| n.index()).collect::<Vec<_>>(),
+        [4, 5, 6]
+    );
+    assert_eq!(g.count_on(Side::Left), 4);
+    assert_eq!(g.side(NodeIndex::new(5)), Some(Side::Right));
+    assert_eq!(g.side(NodeIndex::new(7)), None);
+
+    assert_eq!(
+        g.add_edge(NodeIndex::new(4), NodeIndex::new(5), ()),
+        Err(BipartiteError::SameSide(Side::Right))
+    );
+    assert_eq!(
+        g.add_edge(NodeIndex::new(0), NodeIndex::new(9), ()),
+        Err(BipartiteError::NodeOutBounds)
+    );
+    assert_eq!(g.edge_count(), 6);
+
+    // The last node, "z" on the right, takes the index of "a".
+    assert_eq!(g.remove_node(NodeIndex::new(0)), Some("a"));
+    assert_eq!(g[NodeIndex::new(0)], "z");
+    assert_eq!(g.side(NodeIndex::new(0)), Some(Side::Right));
+    assert_eq!(g.right_nodes().count(), 3);
+    assert_eq!(g.left_nodes().count(), 3);
+}
+
+#[test]
+fn from_graph() {
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (4, 5)]);
+    let g = BipartiteGraph::from_graph(graph).unwrap();
+    assert_eq!(
+        g.left_nodes().map(|n| n.index()).collect::<Vec<_>>(),
+        [0, 2, 4]
+    );
+    assert_eq!(
+        g.right_nodes().map(|n| n.index()).collect::<Vec<_>>(),
+        [1, 3, 5]
+    );
+
+    let odd = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(
+        BipartiteGraph::from_graph(odd).unwrap_err(),
+        BipartiteError::NotBipartite
+    );
+    let self_loop = UnGraph::<(), ()>::from_edges([(0, 0)]);
+    assert!(BipartiteGraph::from_graph(self_loop).is_err());
+
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+    let sides = vec![Side::Left, Side::Right, Side::Right];
+    assert_eq!(
+        BipartiteGraph::from_graph_with_sides(graph, sides).unwrap_err(),
+        BipartiteError::SameSide(Side::Right)
+    );
+}
+
+#[test]
+fn projections() {
+    let mut g = affiliation();
+    // A parallel edge counts once.
+    g.add_edge(NodeIndex::new(1), NodeIndex::new(4), ())
+        .unwrap();
+
+    let people = g.projection(Side::Left, ProjectionWeight::SharedNeighbors);
+    assert_eq!(people.node_count(), 4);
+    assert_eq!(edges(&people), [(0, 1, 1.), (0, 2, 1.), (1, 2, 2.)]);
+
+    let people = g.projection(Side::Left, ProjectionWeight::Newman);
+    assert_eq!(edges(&people), [(0, 1, 0.5), (0, 2, 0.5), (1, 2, 1.5)]);
+
+    let people = g.projection(Side::Left, ProjectionWeight::Jaccard);
+    assert_eq!(edges(&people), [(0, 1, 0.5), (0, 2, 0.5), (1, 2, 1.)]);
+
+    let groups = g.projection(Side::Right, ProjectionWeight::SharedNeighbors);
+    assert_eq!(groups.node_count(), 3);
+    assert_eq!(edges(&groups), [(4, 5, 2.)]);
+}
@@ -0,0 +1,89 @@
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+use petgraph::visit::{BoundedDfs, DepthLimit, DfsConfig, StackGrowth, VisitMap};
+
+/// Recursive preorder DFS, for comparison.
+fn recursive_dfs(
+    graph: &UnGraph<(), ()>,
+    node: NodeIndex,
+    seen: &mut Vec<bool>,
+    order: &mut Vec<NodeIndex>,
+) {
+    seen[node.index()] = true;
+    order.push(node);
+    for next in graph.neighbors(node) {
+        if !seen[next.index()] {
+            recursive_dfs(graph, next, seen, order);
+        }
+    }
+}
+
+#[test]
+fn bounded_dfs_matches_recursive_order() {
+    let graph = UnGraph::<(), ()>::from_edges([
+        (0, 1),
+        (0, 2),
+        (1, 3),
+        (3, 2),
+        (2, 4),
+        (4, 5),
+        (5, 0),
+        (6, 7),
+    ]);
+    let mut expected = Vec::new();
+    recursive_dfs(&graph, 0.into(), &mut vec![false; 8], &mut expected);
+
+    let mut dfs = BoundedDfs::new(&graph, 0.into());
+    let order: Vec<_> = dfs.by_ref().collect();
+    assert_eq!(order, expected);
+    assert!(!dfs.discovered().is_visited(&NodeIndex::<u32>::new(6)));
+
+    dfs.move_to(6.into());
+    assert_eq!(dfs.by_ref().collect::<Vec<_>>(), vec![6.into(), 7.into()]);
+    dfs.move_to(0.into());
+    assert_eq!(dfs.next(), None);
+}
+
+#[test]
+fn bounded_dfs_memory_on_a_star() {
+    // Dfs pushes every leaf; BoundedDfs only holds the current path.
+    let graph = DiGraph::<(), ()>::from_edges((1..1000u32).map(|i| (0, i)));
+    let mut dfs = BoundedDfs::new(&graph, 0.into());
+    assert_eq!(dfs.by_ref().count(), 1000);
+    let stats = dfs.stats();
+    assert_eq!(stats.max_depth, 2);
+    assert_eq!(stats.depth, 0);
+    assert_eq!(stats.reallocations, 1);
+    assert_eq!(stats.capacity, 4);
+    assert_eq!(stats.stack_bytes(), 4 * stats.frame_size);
+}
+
+#[test]
+fn bounded_dfs_depth_limits() {
+    let graph = DiGraph::<(), ()>::from_edges((0..999u32).map(|i| (i, i + 1)));
+
+    let config = DfsConfig {
+        growth: StackGrowth::Linear(10),
+        max_depth: Some(50),
+        ..DfsConfig::default()
+    };
+    let mut dfs = BoundedDfs::with_config(&graph, 0.into(), config);
+    let order: Vec<_> = dfs.by_ref().collect();
+    // The node at depth 51 is emitted, but not expanded.
+    assert_eq!(order.len(), 51);
+    let stats = dfs.stats();
+    assert_eq!(stats.pruned, 1);
+    assert!(!stats.truncated);
+    assert_eq!(stats.max_depth, 50);
+    assert_eq!(stats.capacity, 50);
+    assert_eq!(stats.reallocations, 5);
+
+    let config = DfsConfig {
+        max_depth: Some(50),
+        on_limit: DepthLimit::Stop,
+        ..DfsConfig::default()
+    };
+    let mut dfs = BoundedDfs::with_config(&graph, 0.into(), config);
+    assert_eq!(dfs.by_ref().count(), 50);
+    assert!(dfs.stats().truncated);
+    assert_eq!(dfs.next(), None);
+}
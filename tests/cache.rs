@@ -0,0 +1,67 @@
+use petgraph::algo::{connected_components, kosaraju_scc};
+use petgraph::cache::{ResultCache, Versioned};
+use petgraph::graph::{DiGraph, NodeIndex};
+
+#[test]
+fn versioned_graph() {
+    let mut graph = Versioned::new(DiGraph::<(), ()>::new());
+    assert_eq!(graph.version(), 0);
+    assert_eq!(graph.node_count(), 0);
+    assert_eq!(graph.version(), 0);
+    let a = graph.add_node(());
+    graph.get_mut().add_edge(a, a, ());
+    assert_eq!(graph.version(), 2);
+    assert_eq!(graph.into_inner().edge_count(), 1);
+}
+
+#[test]
+fn result_cache() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Algo {
+        Scc,
+        Components,
+    }
+
+    let mut graph = Versioned::new(DiGraph::<(), ()>::from_edges([(0, 1), (1, 0), (2, 3)]));
+    let mut cache = ResultCache::new(2);
+    let mut runs = 0;
+    for _ in 0..3 {
+        let sccs = cache.get_or_compute(&graph, Algo::Scc, |g| {
+            runs += 1;
+            kosaraju_scc(g)
+        });
+        assert_eq!(sccs.len(), 3);
+    }
+    assert_eq!(runs, 1);
+    assert_eq!(
+        cache
+            .get::<_, Vec<Vec<NodeIndex>>>(&graph, &Algo::Scc)
+            .unwrap()
+            .len(),
+        3
+    );
+    // A different result type under the same key is a miss.
+    assert!(cache.get::<_, usize>(&graph, &Algo::Scc).is_none());
+
+    let components = *cache.get_or_compute(&graph, Algo::Components, |g| connected_components(g));
+    assert_eq!(components, 2);
+    assert_eq!(cache.len(), 2);
+
+    // A third result evicts the least recently used one.
+    cache.get_or_compute(&graph, Algo::Scc, |g| kosaraju_scc(g));
+    cache.get_or_compute(&graph, Algo::Components, |_| 0u32);
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get::<_, usize>(&graph, &Algo::Components).is_none());
+    assert!(cache.get::<_, u32>(&graph, &Algo::Components).is_some());
+
+    graph.add_edge(NodeIndex::new(1), NodeIndex::new(2), ());
+    assert!(cache
+        .get::<_, Vec<Vec<NodeIndex>>>(&graph, &Algo::Scc)
+        .is_none());
+    let components = *cache.get_or_compute(&graph, Algo::Components, |g| connected_components(g));
+    assert_eq!(components, 1);
+    cache.remove_stale(&graph);
+    assert_eq!(cache.len(), 1);
+    cache.clear();
+    assert!(cache.is_empty());
+}
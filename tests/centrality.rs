@@ -0,0 +1,203 @@
+#[cfg(any(feature = "rand", feature = "stable_graph"))]
+use petgraph::algo::centrality::betweenness_centrality;
+use petgraph::algo::centrality::{
+    closeness_centrality, degree_centrality, Normalization, ScoreMap,
+};
+use petgraph::graph::{Graph, UnGraph};
+#[cfg(feature = "graphmap")]
+use petgraph::graphmap::UnGraphMap;
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableGraph;
+#[cfg(feature = "rand")]
+use {
+    petgraph::generators::gnm_random_graph,
+    petgraph::graph::{EdgeIndex, NodeIndex},
+    petgraph::visit::EdgeRef,
+    petgraph::{Directed, EdgeType, Undirected},
+    rand::{rngs::StdRng, Rng, SeedableRng},
+};
+
+#[cfg(feature = "rand")]
+fn random_graph<Ty: EdgeType>(rng: &mut StdRng) -> Graph<(), u32, Ty> {
+    let n = rng.gen_range(1..10);
+    let pairs = if Ty::is_directed() {
+        n * (n - 1)
+    } else {
+        n * (n - 1) / 2
+    };
+    let m = rng.gen_range(0..=pairs.min(3 * n));
+    let mut graph: Graph<(), u32, Ty> = gnm_random_graph(n, m, rng);
+    for weight in graph.edge_weights_mut() {
+        *weight = rng.gen_range(1..4);
+    }
+    // Add a loop and a parallel edge, which G(n, m) never draws.
+    let a = NodeIndex::new(rng.gen_range(0..n));
+    graph.add_edge(a, a, rng.gen_range(1..4));
+    if m > 0 {
+        let (a, b) = graph
+            .edge_endpoints(EdgeIndex::new(rng.gen_range(0..m)))
+            .unwrap();
+        graph.add_edge(a, b, rng.gen_range(1..4));
+    }
+    graph
+}
+
+#[cfg(feature = "rand")]
+/// Compute the betweenness from the number of shortest paths between all
+/// pairs of nodes.
+fn brute_force_betweenness<Ty: EdgeType>(graph: &Graph<(), u32, Ty>) -> Vec<f64> {
+    let n = graph.node_count();
+    let mut cost = vec![vec![u32::MAX; n]; n];
+    for e in graph.edge_references() {
+        let (a, b) = (e.source().index(), e.target().index());
+        if a != b {
+            cost[a][b] = cost[a][b].min(*e.weight());
+            if !graph.is_directed() {
+                cost[b][a] = cost[a][b];
+            }
+        }
+    }
+    let mut distance = cost.clone();
+    for (v, row) in distance.iter_mut().enumerate() {
+        row[v] = 0;
+    }
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                let through = distance[i][k].saturating_add(distance[k][j]);
+                if through < distance[i][j] {
+                    distance[i][j] = through;
+                }
+            }
+        }
+    }
+    // The number of shortest paths, in order of distance from the source.
+    let mut paths = vec![vec![0.0; n]; n];
+    for s in 0..n {
+        let mut order: Vec<_> = (0..n).filter(|&t| distance[s][t] < u32::MAX).collect();
+        order.sort_by_key(|&t| distance[s][t]);
+        paths[s][s] = 1.0;
+        for &t in &order[1..] {
+            paths[s][t] = (0..n)
+                .filter(|&u| {
+                    cost[u][t] < u32::MAX
+                        && distance[s][u].saturating_add(cost[u][t]) == distance[s][t]
+                })
+                .map(|u| paths[s][u])
+                .sum();
+        }
+    }
+    let mut scores = vec![0.0; n];
+    for (v, score) in scores.iter_mut().enumerate() {
+        for s in 0..n {
+            for t in 0..n {
+                if s == v || t == v || s == t || distance[s][t] == u32::MAX {
+                    continue;
+                }
+                if distance[s][v].saturating_add(distance[v][t]) == distance[s][t] {
+                    *score += paths[s][v] * paths[v][t] / paths[s][t];
+                }
+            }
+        }
+        if !graph.is_directed() {
+            *score /= 2.0;
+        }
+    }
+    scores
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn betweenness_matches_brute_force() {
+    let mut rng = StdRng::seed_from_u64(25);
+    for _ in 0..200 {
+        let graph = random_graph::<Directed>(&mut rng);
+        let scores = betweenness_centrality(&graph, |e| *e.weight());
+        for (v, &expected) in brute_force_betweenness(&graph).iter().enumerate() {
+            assert_close(scores[NodeIndex::new(v)], expected);
+        }
+
+        let graph = random_graph::<Undirected>(&mut rng);
+        let scores = betweenness_centrality(&graph, |e| *e.weight());
+        for (v, &expected) in brute_force_betweenness(&graph).iter().enumerate() {
+            assert_close(scores[NodeIndex::new(v)], expected);
+        }
+    }
+}
+
+#[test]
+fn closeness_and_degree() {
+    // A path of 4 nodes and an isolated node.
+    let mut graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+    graph.add_node(());
+    let closeness = closeness_centrality(&graph);
+    // Node 1 reaches 3 of the 4 other nodes at a total distance of 4.
+    assert_close(closeness[1.into()], 0.75 * 0.75);
+    assert_close(closeness[0.into()], 0.75 * 0.5);
+    assert_eq!(closeness[4.into()], 0.0);
+
+    let degree = degree_centrality(&graph);
+    assert_eq!(
+        degree.iter().map(|(_, &d)| d).collect::<Vec<_>>(),
+        [0.25, 0.5, 0.5, 0.25, 0.0]
+    );
+
+    // Directed graphs use the outgoing edges.
+    let star = Graph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+    let closeness = closeness_centrality(&star);
+    assert_eq!(closeness[0.into()], 1.0);
+    assert_eq!(closeness[1.into()], 0.0);
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn score_map_with_holes() {
+    // A graph with a hole in its node indices uses a hash map.
+    let mut graph = StableGraph::<(), (), Undirected>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let extra = graph.add_node(());
+    graph.add_edge(extra, 0.into(), ());
+    graph.remove_node(2.into());
+    let mut scores = betweenness_centrality(&graph, |_| 1);
+    assert_eq!(scores.len(), 4);
+    assert_eq!(scores.get(2.into()), None);
+    assert_eq!(scores.ranked()[0], (0.into(), &3.0));
+    // The other nodes are leaves of a star.
+    assert_eq!(scores.top_k(2)[1].1, &0.0);
+    *scores.get_mut(3.into()).unwrap() = 6.0;
+    scores.normalize(Normalization::Max);
+    assert_eq!(scores[3.into()], 1.0);
+    assert_eq!(scores[0.into()], 0.5);
+}
+
+#[test]
+#[cfg(feature = "graphmap")]
+fn score_map_of_graphmap() {
+    // A graph map is compact.
+    let map = UnGraphMap::<char, ()>::from_edges([('a', 'b'), ('b', 'c')]);
+    let mut scores = degree_centrality(&map);
+    scores.normalize(Normalization::MinMax);
+    assert_eq!(
+        scores.iter().collect::<Vec<_>>(),
+        [('a', &0.0), ('b', &1.0), ('c', &0.0)]
+    );
+    assert_eq!(format!("{:?}", scores), "{'a': 0.0, 'b': 1.0, 'c': 0.0}");
+}
+
+#[test]
+fn score_map_ranking() {
+    // Ties keep the iteration order and NaN comes last.
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+    let mut scores = ScoreMap::from_indexed(&graph, vec![1.0, f64::NAN, 2.0, 1.0]);
+    let ranked: Vec<_> = scores.ranked().iter().map(|(v, _)| v.index()).collect();
+    assert_eq!(ranked, [2, 0, 3, 1]);
+    let top: Vec<_> = scores.top_k(3).iter().map(|(v, _)| v.index()).collect();
+    assert_eq!(top, [2, 0, 3]);
+    assert!(scores.top_k(0).is_empty());
+    assert_eq!(scores.top_k(10).len(), 4);
+    scores.normalize(Normalization::Sum);
+    assert!(scores[0.into()].is_nan());
+}
@@ -0,0 +1,230 @@
+use petgraph::algo::{chinese_postman, dijkstra};
+use petgraph::graph::NodeIndex;
+use petgraph::{Graph, Undirected};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Check that `walk` is a closed walk that uses every edge of `graph` at least once.
+fn assert_covering_walk<N>(graph: &Graph<N, u32, Undirected>, walk: &[NodeIndex]) {
+    assert_eq!(walk.first(), walk.last());
+    for edge in graph.edge_indices() {
+        let (a, b) = graph.edge_endpoints(edge).unwrap();
+        assert!(
+            walk.windows(2)
+                .any(|w| (w[0] == a && w[1] == b) || (w[0] == b && w[1] == a)),
+            "edge {:?} not covered",
+            edge
+        );
+    }
+    for w in walk.windows(2) {
+        assert!(graph.find_edge(w[0], w[1]).is_some());
+    }
+}
+
+#[test]
+fn chinese_postman_eulerian() {
+    let mut graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.extend_with_edges([(a, b, 1), (b, c, 2), (c, d, 3), (d, a, 4)]);
+
+    let (cost, walk) = chinese_postman(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 10);
+    assert_eq!(walk.len(), 5);
+    assert_covering_walk(&graph, &walk);
+}
+
+#[test]
+fn chinese_postman_path() {
+    let mut graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges([(a, b, 2), (b, c, 5)]);
+
+    let (cost, walk) = chinese_postman(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 14);
+    assert_eq!(walk.len(), 5);
+    assert_covering_walk(&graph, &walk);
+}
+
+#[test]
+fn chinese_postman_four_odd_nodes() {
+    // Complete graph on four nodes: every node has degree 3.
+    let mut graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.extend_with_edges([
+        (a, b, 1),
+        (c, d, 1),
+        (a, c, 5),
+        (b, d, 5),
+        (a, d, 6),
+        (b, c, 6),
+    ]);
+
+    let (cost, walk) = chinese_postman(&graph, |e| *e.weight()).unwrap();
+    // Pairing (a, b) and (c, d) costs 2.
+    assert_eq!(cost, 24 + 2);
+    assert_eq!(walk.len(), 9);
+    assert_covering_walk(&graph, &walk);
+}
+
+#[test]
+fn chinese_postman_self_loop_and_isolated_node() {
+    let mut graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_node(());
+    graph.extend_with_edges([(a, a, 3), (a, b, 1)]);
+
+    let (cost, walk) = chinese_postman(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 5);
+    assert_eq!(walk.len(), 4);
+    assert_covering_walk(&graph, &walk);
+}
+
+#[test]
+fn chinese_postman_disconnected() {
+    let mut graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.extend_with_edges([(a, b, 1), (c, d, 1)]);
+
+    assert_eq!(chinese_postman(&graph, |e| *e.weight()), None);
+}
+
+#[test]
+fn chinese_postman_empty() {
+    let graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+    assert_eq!(chinese_postman(&graph, |e| *e.weight()), Some((0, vec![])));
+}
+
+#[test]
+fn chinese_postman_many_odd_nodes() {
+    // A star with 64 leaves, all of odd degree.
+    let mut graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+    let center = graph.add_node(());
+    for weight in 1..=64 {
+        let leaf = graph.add_node(());
+        graph.add_edge(center, leaf, weight);
+    }
+
+    let (cost, walk) = chinese_postman(&graph, |e| *e.weight()).unwrap();
+    // Every edge is walked twice, whatever the pairing.
+    assert_eq!(cost, 2 * (1..=64).sum::<u32>());
+    assert_eq!(walk.len(), 2 * 64 + 1);
+    assert_covering_walk(&graph, &walk);
+}
+
+#[test]
+fn chinese_postman_beats_closest_pairs() {
+    // Gadgets a -2- b =1= c -2- d, where b and c are joined by two edges, so
+    // that all four nodes have odd degree. Pairing the closest nodes b and c
+    // first would force the pair (a, d) at cost 5, while (a, b) and (c, d)
+    // cost 4. Each gadget hangs from a hub by a double edge, which keeps the
+    // degrees' parity and makes pairs across gadgets cost at least 200.
+    let mut graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+    let hub = graph.add_node(());
+    for _ in 0..25 {
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        let d = graph.add_node(());
+        graph.extend_with_edges([
+            (a, b, 2),
+            (b, c, 1),
+            (b, c, 1),
+            (c, d, 2),
+            (c, hub, 100),
+            (c, hub, 100),
+        ]);
+    }
+
+    let (cost, walk) = chinese_postman(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 25 * (206 + 4));
+    assert_covering_walk(&graph, &walk);
+}
+
+#[test]
+fn chinese_postman_float_weights() {
+    let mut graph: Graph<(), f64, Undirected> = Graph::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.extend_with_edges([(a, b, 0.5), (b, c, 0.25), (c, d, 0.5), (d, b, 1.5)]);
+
+    // `a` and `b` have odd degree, so the edge between them is walked twice.
+    let (cost, walk) = chinese_postman(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(cost, 2.75 + 0.5);
+    assert_eq!(walk.len(), 6);
+    assert_eq!(walk.first(), walk.last());
+}
+
+/// Minimum cost of pairing up `odd` by dynamic programming over subsets.
+fn min_pairing_cost(graph: &Graph<(), u32, Undirected>, odd: &[NodeIndex]) -> u32 {
+    let dist: Vec<Vec<u32>> = odd
+        .iter()
+        .map(|&a| {
+            let from_a = dijkstra(graph, a, None, |e| *e.weight());
+            odd.iter().map(|b| from_a[b]).collect()
+        })
+        .collect();
+    let full = (1usize << odd.len()) - 1;
+    let mut best = vec![u32::MAX; full + 1];
+    best[0] = 0;
+    for mask in 0..full {
+        if best[mask] == u32::MAX {
+            continue;
+        }
+        let i = (!mask).trailing_zeros() as usize;
+        for (j, &d) in dist[i].iter().enumerate().skip(i + 1) {
+            if mask & (1 << j) == 0 {
+                let next = mask | (1 << i) | (1 << j);
+                best[next] = best[next].min(best[mask] + d);
+            }
+        }
+    }
+    best[full]
+}
+
+#[test]
+fn chinese_postman_random_graphs() {
+    let mut rng = StdRng::seed_from_u64(1305);
+    for _ in 0..200 {
+        let n = rng.gen_range(2..16);
+        let mut graph: Graph<(), u32, Undirected> = Graph::new_undirected();
+        let nodes: Vec<_> = (0..n).map(|_| graph.add_node(())).collect();
+        // A random spanning tree keeps the graph connected.
+        for i in 1..n {
+            let j = rng.gen_range(0..i);
+            graph.add_edge(nodes[i], nodes[j], rng.gen_range(0..20));
+        }
+        for _ in 0..rng.gen_range(0..2 * n) {
+            let (i, j) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            graph.add_edge(nodes[i], nodes[j], rng.gen_range(0..20));
+        }
+
+        let mut degree = vec![0; n];
+        for e in graph.edge_indices() {
+            let (a, b) = graph.edge_endpoints(e).unwrap();
+            degree[a.index()] += 1;
+            degree[b.index()] += 1;
+        }
+        let odd: Vec<_> = nodes
+            .iter()
+            .copied()
+            .filter(|v| degree[v.index()] % 2 == 1)
+            .collect();
+        let total: u32 = graph.edge_weights().sum();
+        let (cost, walk) = chinese_postman(&graph, |e| *e.weight()).unwrap();
+        assert_eq!(cost, total + min_pairing_cost(&graph, &odd));
+        assert_covering_walk(&graph, &walk);
+    }
+}
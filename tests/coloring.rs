@@ -1,5 +1,63 @@
-use petgraph::algo::dsatur_coloring;
+use std::collections::HashMap;
+
+use petgraph::algo::{
+    dsatur_coloring, equitable_coloring, list_coloring, minimum_coloring,
+    misra_gries_edge_coloring, rlf_coloring, ColoringError,
+};
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+use petgraph::visit::EdgeRef;
 use petgraph::{Graph, Undirected};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn random_graph(rng: &mut StdRng, n: usize, p: f64) -> UnGraph<(), ()> {
+    let mut graph = UnGraph::new_undirected();
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for a in 0..n {
+        for b in a + 1..n {
+            if rng.gen_bool(p) {
+                graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+            }
+        }
+    }
+    graph
+}
+
+fn assert_proper(
+    graph: &UnGraph<(), ()>,
+    coloring: &hashbrown::HashMap<NodeIndex, usize>,
+    k: usize,
+) {
+    assert_eq!(coloring.len(), graph.node_count());
+    assert!(coloring.values().all(|&c| c < k));
+    for edge in graph.edge_indices() {
+        let (a, b) = graph.edge_endpoints(edge).unwrap();
+        assert_ne!(coloring[&a], coloring[&b]);
+    }
+}
+
+/// Return whether the first `v` nodes can be extended to a `k`-coloring.
+fn brute_force_colorable(graph: &UnGraph<(), ()>, k: usize, colors: &mut Vec<usize>) -> bool {
+    let v = colors.len();
+    if v == graph.node_count() {
+        return true;
+    }
+    for c in 0..k {
+        if graph
+            .neighbors(NodeIndex::new(v))
+            .all(|w| w.index() >= v || colors[w.index()] != c)
+        {
+            colors.push(c);
+            if brute_force_colorable(graph, k, colors) {
+                return true;
+            }
+            colors.pop();
+        }
+    }
+    false
+}
 
 #[test]
 fn dsatur_coloring_cycle6() {
@@ -57,3 +115,185 @@ fn dsatur_coloring_bipartite() {
     let (_, nb_colors) = dsatur_coloring(&graph);
     assert_eq!(nb_colors, 2);
 }
+
+#[test]
+fn minimum_coloring_is_optimal() {
+    let mut rng = StdRng::seed_from_u64(10);
+    for _ in 0..40 {
+        let n = rng.gen_range(0..12);
+        let p = rng.gen_range(0.1..0.9);
+        let graph = random_graph(&mut rng, n, p);
+        let chromatic = (0..=n)
+            .find(|&k| brute_force_colorable(&graph, k, &mut Vec::new()))
+            .unwrap();
+
+        let (coloring, k) = minimum_coloring(&graph);
+        assert_eq!(k, chromatic);
+        assert_proper(&graph, &coloring, k);
+
+        let (coloring, k) = rlf_coloring(&graph);
+        assert!(k >= chromatic);
+        assert_proper(&graph, &coloring, k);
+    }
+}
+
+#[test]
+fn minimum_coloring_of_larger_graphs() {
+    // Odd cycles need 3 colors, complete graphs need one per node.
+    let cycle = UnGraph::<(), ()>::from_edges((0..51).map(|i| (i, (i + 1) % 51)));
+    assert_eq!(minimum_coloring(&cycle).1, 3);
+    let mut complete = UnGraph::<(), ()>::new_undirected();
+    let nodes: Vec<_> = (0..9).map(|_| complete.add_node(())).collect();
+    for (i, &a) in nodes.iter().enumerate() {
+        for &b in &nodes[i + 1..] {
+            complete.add_edge(a, b, ());
+        }
+    }
+    assert_eq!(minimum_coloring(&complete).1, 9);
+    assert_eq!(rlf_coloring(&complete).1, 9);
+
+    let mut rng = StdRng::seed_from_u64(11);
+    let graph = random_graph(&mut rng, 40, 0.3);
+    let (coloring, k) = minimum_coloring(&graph);
+    assert_proper(&graph, &coloring, k);
+    let counts = coloring.values().fold(HashMap::new(), |mut counts, &c| {
+        *counts.entry(c).or_insert(0) += 1;
+        counts
+    });
+    assert_eq!(counts.len(), k);
+    assert!(k <= rlf_coloring(&graph).1);
+    assert!(k <= dsatur_coloring(&graph).1);
+}
+
+#[test]
+fn misra_gries_edge_coloring_is_proper() {
+    let mut rng = StdRng::seed_from_u64(12);
+    for _ in 0..30 {
+        let n = rng.gen_range(0..40);
+        let p = rng.gen_range(0.05..0.8);
+        let graph = random_graph(&mut rng, n, p);
+        let max_degree = graph
+            .node_indices()
+            .map(|v| graph.edges(v).count())
+            .max()
+            .unwrap_or(0);
+
+        let (coloring, k) = misra_gries_edge_coloring(&graph);
+        assert_eq!(coloring.len(), graph.edge_count());
+        assert!(k <= max_degree + 1);
+        assert!(k >= max_degree);
+        for v in graph.node_indices() {
+            let mut colors: Vec<_> = graph.edges(v).map(|e| coloring[&e.id()]).collect();
+            assert!(colors.iter().all(|&c| c < k));
+            colors.sort_unstable();
+            colors.dedup();
+            assert_eq!(colors.len(), graph.edges(v).count());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn misra_gries_edge_coloring_rejects_parallel_edges() {
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 1)]);
+    misra_gries_edge_coloring(&graph);
+}
+
+#[test]
+fn equitable_coloring_sizes() {
+    let mut rng = StdRng::seed_from_u64(14);
+    for _ in 0..30 {
+        let n = rng.gen_range(0..40);
+        let p = rng.gen_range(0.05..0.3);
+        let graph = random_graph(&mut rng, n, p);
+        let max_degree = graph
+            .node_indices()
+            .map(|v| graph.edges(v).count())
+            .max()
+            .unwrap_or(0);
+        // Hajnal–Szemerédi: always possible with more colors than the degree.
+        let k = max_degree + 1 + rng.gen_range(0..3);
+        let (coloring, colors) = equitable_coloring(&graph, k).unwrap();
+        assert_eq!(colors, k);
+        assert_proper(&graph, &coloring, k);
+        let mut sizes = vec![0; k];
+        for &c in coloring.values() {
+            sizes[c] += 1;
+        }
+        let (min, max) = (sizes.iter().min().unwrap(), sizes.iter().max().unwrap());
+        assert!(max - min <= 1);
+    }
+}
+
+#[test]
+fn equitable_coloring_infeasible() {
+    // K(1, 3) has no equitable 2-coloring, but K(3, 3) has one.
+    let star = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3)]);
+    assert_eq!(equitable_coloring(&star, 2), Err(ColoringError::Infeasible));
+    assert!(equitable_coloring(&star, 3).is_ok());
+    let bipartite = UnGraph::<(), ()>::from_edges((0..3).flat_map(|a| (3..6).map(move |b| (a, b))));
+    assert!(equitable_coloring(&bipartite, 2).is_ok());
+    assert_eq!(equitable_coloring(&star, 0), Err(ColoringError::Infeasible));
+    let empty = UnGraph::<(), ()>::new_undirected();
+    assert_eq!(equitable_coloring(&empty, 0).unwrap().1, 0);
+}
+
+#[test]
+fn list_coloring_constraints() {
+    let mut rng = StdRng::seed_from_u64(15);
+    for _ in 0..30 {
+        let n = rng.gen_range(1..12);
+        let graph = random_graph(&mut rng, n, 0.4);
+        let lists: Vec<Vec<usize>> = (0..n)
+            .map(|_| {
+                (0..4)
+                    .filter(|_| rng.gen_bool(0.6))
+                    .map(|c| 10 * c)
+                    .collect()
+            })
+            .collect();
+        let result = list_coloring(&graph, |v: NodeIndex| lists[v.index()].clone());
+
+        // Brute force over all assignments from the lists.
+        let mut exists = false;
+        let mut choice = vec![0; n];
+        if lists.iter().all(|l| !l.is_empty()) {
+            'outer: loop {
+                let ok = graph.edge_indices().all(|e| {
+                    let (a, b) = graph.edge_endpoints(e).unwrap();
+                    lists[a.index()][choice[a.index()]] != lists[b.index()][choice[b.index()]]
+                });
+                if ok {
+                    exists = true;
+                    break;
+                }
+                for i in 0..n {
+                    choice[i] += 1;
+                    if choice[i] < lists[i].len() {
+                        continue 'outer;
+                    }
+                    choice[i] = 0;
+                }
+                break;
+            }
+        }
+
+        match result {
+            Ok(coloring) => {
+                assert!(exists);
+                for v in graph.node_indices() {
+                    assert!(lists[v.index()].contains(&coloring[&v]));
+                }
+                for e in graph.edge_indices() {
+                    let (a, b) = graph.edge_endpoints(e).unwrap();
+                    assert_ne!(coloring[&a], coloring[&b]);
+                }
+            }
+            Err(ColoringError::EmptyList(v)) => assert!(lists[v.index()].is_empty()),
+            Err(ColoringError::Infeasible) => {
+                assert!(!exists);
+                assert!(lists.iter().all(|l| !l.is_empty()));
+            }
+        }
+    }
+}
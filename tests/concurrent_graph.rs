@@ -0,0 +1,82 @@
+#![cfg(feature = "std")]
+
+use petgraph::graph::{ConcurrentGraph, GraphError, NodeIndex};
+use petgraph::visit::EdgeRef;
+use petgraph::{Directed, Undirected};
+
+#[test]
+fn parallel_construction() {
+    let graph = ConcurrentGraph::<(usize, usize), (usize, usize)>::with_shards(3);
+    std::thread::scope(|scope| {
+        for t in 0..8 {
+            let graph = &graph;
+            scope.spawn(move || {
+                let nodes: Vec<_> = (0..100).map(|i| graph.add_node((t, i))).collect();
+                for w in nodes.windows(2) {
+                    graph.add_edge(w[0], w[1], (t, 0));
+                }
+                graph.extend_with_edges(nodes.iter().map(|&n| (n, nodes[0], (t, 1))));
+            });
+        }
+    });
+    assert_eq!(graph.node_count(), 800);
+    assert_eq!(graph.edge_count(), 8 * 199);
+
+    let graph = graph.into_graph();
+    assert_eq!(graph.edge_count(), 8 * 199);
+    for edge in graph.edge_references() {
+        let (t, kind) = *edge.weight();
+        let (source, target) = (graph[edge.source()], graph[edge.target()]);
+        assert_eq!((source.0, target.0), (t, t));
+        if kind == 0 {
+            assert_eq!(source.1 + 1, target.1);
+        } else {
+            assert_eq!(target.1, 0);
+        }
+    }
+}
+
+#[test]
+fn index_limit() {
+    let graph = ConcurrentGraph::<(), (), Directed, u8>::with_shards(2);
+    for _ in 0..255 {
+        graph.add_node(());
+    }
+    assert_eq!(graph.try_add_node(()), Err(GraphError::NodeIxLimit));
+    assert_eq!(
+        graph.try_add_edge(NodeIndex::new(0), NodeIndex::new(255), ()),
+        Err(GraphError::NodeOutBounds)
+    );
+    assert_eq!(graph.into_graph().node_count(), 255);
+}
+
+#[test]
+fn undirected_into_csr() {
+    let graph = ConcurrentGraph::<(), u32, Undirected>::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges([(a, b, 1), (c, b, 2)]);
+    let csr = graph.into_csr();
+    assert_eq!(csr.neighbors_slice(1), [0, 2]);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn rayon_construction() {
+    use rayon::prelude::*;
+
+    let graph = ConcurrentGraph::<usize, ()>::new();
+    let nodes: Vec<_> = (0..1000)
+        .into_par_iter()
+        .map(|i| graph.add_node(i))
+        .collect();
+    nodes.par_windows(2).for_each(|w| {
+        graph.add_edge(w[0], w[1], ());
+    });
+    let graph = graph.into_graph();
+    assert_eq!(graph.edge_count(), 999);
+    for &n in &nodes {
+        assert_eq!(graph[n], n.index());
+    }
+}
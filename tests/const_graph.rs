@@ -0,0 +1,153 @@
+use petgraph::algo::{dijkstra, is_cyclic_directed, toposort};
+use petgraph::const_graph::{ConstGraph, DiConstGraph, UnConstGraph};
+use petgraph::graph::{GraphError, NodeIndex};
+use petgraph::visit::{Dfs, EdgeRef, IntoNodeReferences, VisitMap, Visitable};
+use petgraph::{Directed, EdgeType, Graph, Incoming, Outgoing, Undirected};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn assert_same_as_graph<Ty: EdgeType>(rng: &mut StdRng) {
+    let mut graph = Graph::<usize, usize, Ty>::default();
+    let mut const_graph = ConstGraph::<usize, usize, 20, 60, Ty>::default();
+    let n = rng.gen_range(1..20);
+    for i in 0..n {
+        assert_eq!(graph.add_node(i), const_graph.add_node(i));
+    }
+    for i in 0..rng.gen_range(0..60) {
+        let a = NodeIndex::new(rng.gen_range(0..n));
+        let b = NodeIndex::new(rng.gen_range(0..n));
+        assert_eq!(graph.add_edge(a, b, i), const_graph.add_edge(a, b, i));
+    }
+
+    let edges = |iter: &mut dyn Iterator<Item = (NodeIndex, NodeIndex, usize)>| {
+        let mut list: Vec<_> = iter.collect();
+        list.sort_unstable();
+        list
+    };
+    for v in graph.node_indices() {
+        for dir in [Outgoing, Incoming] {
+            let mut expected: Vec<_> = graph.neighbors_directed(v, dir).collect();
+            let mut actual: Vec<_> = const_graph.neighbors_directed(v, dir).collect();
+            expected.sort_unstable();
+            actual.sort_unstable();
+            assert_eq!(actual, expected);
+
+            let expected = edges(
+                &mut graph
+                    .edges_directed(v, dir)
+                    .map(|e| (e.source(), e.target(), *e.weight())),
+            );
+            let actual = edges(
+                &mut const_graph
+                    .edges_directed(v, dir)
+                    .map(|e| (e.source(), e.target(), *e.weight())),
+            );
+            assert_eq!(actual, expected);
+        }
+    }
+    for e in graph.edge_indices() {
+        assert_eq!(graph.edge_endpoints(e), const_graph.edge_endpoints(e));
+        assert_eq!(graph[e], const_graph[e]);
+    }
+    assert_eq!(
+        graph.node_references().collect::<Vec<_>>(),
+        const_graph.node_references().collect::<Vec<_>>()
+    );
+
+    let mut dfs = Dfs::new(&graph, NodeIndex::new(0));
+    let mut const_dfs = Dfs::new(&const_graph, NodeIndex::new(0));
+    let mut expected = Vec::new();
+    let mut actual = Vec::new();
+    while let Some(v) = dfs.next(&graph) {
+        expected.push(v);
+    }
+    while let Some(v) = const_dfs.next(&const_graph) {
+        actual.push(v);
+    }
+    expected.sort_unstable();
+    actual.sort_unstable();
+    assert_eq!(actual, expected);
+
+    let expected = dijkstra(&graph, NodeIndex::new(0), None, |e| *e.weight());
+    let actual = dijkstra(&const_graph, NodeIndex::new(0), None, |e| *e.weight());
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn const_graph_matches_graph() {
+    let mut rng = StdRng::seed_from_u64(23);
+    for _ in 0..50 {
+        assert_same_as_graph::<Directed>(&mut rng);
+        assert_same_as_graph::<Undirected>(&mut rng);
+    }
+}
+
+#[test]
+fn const_graph_capacity() {
+    let mut graph = DiConstGraph::<(), (), 2, 1>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    assert_eq!(graph.try_add_node(()), Err(GraphError::NodeIxLimit));
+    assert_eq!(
+        graph.try_add_edge(a, NodeIndex::new(2), ()),
+        Err(GraphError::NodeOutBounds)
+    );
+    graph.add_edge(a, b, ());
+    assert_eq!(graph.try_add_edge(b, a, ()), Err(GraphError::EdgeIxLimit));
+    assert_eq!(graph.node_capacity(), 2);
+    assert_eq!(graph.edge_capacity(), 1);
+
+    graph.clear();
+    assert_eq!(graph.node_count(), 0);
+    assert_eq!(graph.edge_count(), 0);
+    assert_eq!(graph.neighbors(a).count(), 0);
+    graph.add_node(());
+
+    // The index type limits the capacity too: `u8::MAX` is reserved.
+    let mut small = ConstGraph::<(), (), 300, 0, Directed, u8>::default();
+    for _ in 0..255 {
+        small.add_node(());
+    }
+    assert_eq!(small.try_add_node(()), Err(GraphError::NodeIxLimit));
+}
+
+#[test]
+fn const_graph_algorithms() {
+    let mut graph = UnConstGraph::<char, u32, 8, 16, u8>::new_undirected();
+    let nodes: Vec<_> = "abcde".chars().map(|c| graph.add_node(c)).collect();
+    graph.add_edge(nodes[0], nodes[1], 1);
+    graph.add_edge(nodes[1], nodes[2], 1);
+    graph.add_edge(nodes[2], nodes[2], 1);
+    graph.add_edge(nodes[3], nodes[2], 1);
+    assert_eq!(graph.neighbors(nodes[2]).count(), 3);
+    assert_eq!(
+        graph.find_edge(nodes[2], nodes[3]).map(|e| e.index()),
+        Some(3)
+    );
+    assert_eq!(graph.find_edge(nodes[0], nodes[4]), None);
+    graph[nodes[4]] = 'z';
+    assert_eq!(
+        format!("{:?}", graph),
+        "ConstGraph { Ty: \"Undirected\", node_count: 5, edge_count: 4, \
+         nodes: ['a', 'b', 'c', 'd', 'z'], \
+         edges: [0 - 1: 1, 1 - 2: 1, 2 - 2: 1, 3 - 2: 1] }"
+    );
+
+    let mut dag = DiConstGraph::<(), (), 4, 4>::new();
+    let (a, b, c) = (dag.add_node(()), dag.add_node(()), dag.add_node(()));
+    dag.add_edge(c, b, ());
+    dag.add_edge(b, a, ());
+    assert!(!is_cyclic_directed(&dag));
+    assert_eq!(toposort(&dag, None).unwrap(), [c, b, a]);
+    dag.add_edge(a, c, ());
+    assert!(is_cyclic_directed(&dag));
+
+    // The visit map is a fixed-size array.
+    let mut map: [bool; 4] = dag.visit_map();
+    assert!(map.visit(b));
+    assert!(!map.visit(b));
+    assert!(map.is_visited(&b) && !map.is_visited(&a));
+    dag.reset_map(&mut map);
+    assert!(!map.is_visited(&b));
+    assert!(map.visit(c) && map.unvisit(c) && !map.unvisit(c));
+}
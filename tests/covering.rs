@@ -0,0 +1,197 @@
+#![cfg(feature = "rand")]
+
+use petgraph::algo::covering::{
+    approximate_maximum_independent_set, approximate_minimum_dominating_set,
+    approximate_minimum_dominating_set_with_bound, approximate_minimum_vertex_cover,
+    approximate_minimum_vertex_cover_with_bound, maximum_independent_set, minimum_dominating_set,
+    minimum_vertex_cover,
+};
+use petgraph::generators::gnp_random_graph;
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A G(n, p) random graph; loops are covered by `covering_ignores_edge_directions`.
+fn random_graph(rng: &mut StdRng, n: usize, p: f64) -> UnGraph<(), ()> {
+    gnp_random_graph(n, p, rng)
+}
+
+fn is_independent(graph: &UnGraph<(), ()>, set: &[NodeIndex]) -> bool {
+    graph
+        .edge_indices()
+        .map(|e| graph.edge_endpoints(e).unwrap())
+        .all(|(a, b)| !(set.contains(&a) && set.contains(&b)))
+}
+
+fn is_cover(graph: &UnGraph<(), ()>, cover: &[NodeIndex]) -> bool {
+    graph
+        .edge_indices()
+        .map(|e| graph.edge_endpoints(e).unwrap())
+        .all(|(a, b)| cover.contains(&a) || cover.contains(&b))
+}
+
+fn is_dominating(graph: &UnGraph<(), ()>, set: &[NodeIndex]) -> bool {
+    graph
+        .node_indices()
+        .all(|v| set.contains(&v) || graph.neighbors(v).any(|w| set.contains(&w)))
+}
+
+fn brute_force_domination_number(graph: &UnGraph<(), ()>) -> usize {
+    let n = graph.node_count();
+    (0..1u32 << n)
+        .filter(|&mask| {
+            let set: Vec<_> = (0..n)
+                .filter(|&i| mask & (1 << i) != 0)
+                .map(NodeIndex::new)
+                .collect();
+            is_dominating(graph, &set)
+        })
+        .map(|mask| mask.count_ones() as usize)
+        .min()
+        .unwrap()
+}
+
+fn brute_force_independence_number(graph: &UnGraph<(), ()>) -> usize {
+    let n = graph.node_count();
+    (0..1u32 << n)
+        .filter(|&mask| {
+            let set: Vec<_> = (0..n)
+                .filter(|&i| mask & (1 << i) != 0)
+                .map(NodeIndex::new)
+                .collect();
+            is_independent(graph, &set)
+        })
+        .map(|mask| mask.count_ones() as usize)
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn exact_independent_set_and_vertex_cover() {
+    let mut rng = StdRng::seed_from_u64(6);
+    for _ in 0..40 {
+        let n = rng.gen_range(0..13);
+        let p = rng.gen_range(0.1..0.7);
+        let graph = random_graph(&mut rng, n, p);
+        let alpha = brute_force_independence_number(&graph);
+
+        let set = maximum_independent_set(&graph);
+        assert!(is_independent(&graph, &set));
+        assert_eq!(set.len(), alpha);
+
+        let cover = minimum_vertex_cover(&graph);
+        assert!(is_cover(&graph, &cover));
+        assert_eq!(cover.len(), n - alpha);
+    }
+}
+
+#[test]
+fn approximate_independent_set_and_vertex_cover() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..20 {
+        let n = rng.gen_range(0..40);
+        let p = rng.gen_range(0.05..0.3);
+        let graph = random_graph(&mut rng, n, p);
+        let alpha = maximum_independent_set(&graph).len();
+
+        let set = approximate_maximum_independent_set(&graph);
+        assert!(is_independent(&graph, &set));
+        assert!(set.len() <= alpha);
+
+        let cover = approximate_minimum_vertex_cover(&graph);
+        assert!(is_cover(&graph, &cover));
+        assert!(cover.len() <= 2 * (n - alpha));
+    }
+}
+
+#[test]
+fn covering_large_sparse_graph() {
+    // A long cycle has independence number n / 2.
+    let n = 2000;
+    let graph = UnGraph::<(), ()>::from_edges((0..n).map(|i| (i, (i + 1) % n)));
+    let set = approximate_maximum_independent_set(&graph);
+    assert!(is_independent(&graph, &set));
+    assert!(set.len() >= n as usize / 3);
+    assert_eq!(maximum_independent_set(&graph).len(), n as usize / 2);
+}
+
+#[test]
+fn covering_ignores_edge_directions() {
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (2, 1), (3, 1), (1, 1)]);
+    assert_eq!(minimum_vertex_cover(&graph), vec![1.into()]);
+    let mut set = maximum_independent_set(&graph);
+    set.sort();
+    assert_eq!(set, vec![0.into(), 2.into(), 3.into()]);
+}
+
+#[test]
+fn exact_and_approximate_dominating_set() {
+    let mut rng = StdRng::seed_from_u64(8);
+    for _ in 0..40 {
+        let n = rng.gen_range(0..13);
+        let p = rng.gen_range(0.05..0.5);
+        let graph = random_graph(&mut rng, n, p);
+        let gamma = brute_force_domination_number(&graph);
+
+        let set = minimum_dominating_set(&graph);
+        assert!(is_dominating(&graph, &set));
+        assert_eq!(set.len(), gamma);
+
+        let approx = approximate_minimum_dominating_set(&graph);
+        assert!(is_dominating(&graph, &approx));
+        assert!(approx.len() >= gamma);
+        // H(13) < 3.2
+        assert!(approx.len() as f64 <= 3.2 * gamma as f64);
+    }
+}
+
+#[test]
+fn dominating_set_of_large_graphs() {
+    // A cycle of n nodes has domination number ceil(n / 3).
+    let n = 60;
+    let graph = UnGraph::<(), ()>::from_edges((0..n).map(|i| (i, (i + 1) % n)));
+    assert_eq!(minimum_dominating_set(&graph).len(), 20);
+
+    let mut rng = StdRng::seed_from_u64(9);
+    let graph = random_graph(&mut rng, 1000, 0.01);
+    let set = approximate_minimum_dominating_set(&graph);
+    assert!(is_dominating(&graph, &set));
+    assert!(set.len() < 300);
+}
+
+#[test]
+fn covers_with_bounds() {
+    let mut rng = StdRng::seed_from_u64(29);
+    for _ in 0..40 {
+        let n = rng.gen_range(0..13);
+        let p = rng.gen_range(0.05..0.5);
+        let graph = random_graph(&mut rng, n, p);
+
+        let tau = minimum_vertex_cover(&graph).len();
+        let cover = approximate_minimum_vertex_cover_with_bound(&graph);
+        assert!(is_cover(&graph, &cover.nodes));
+        assert_eq!(cover.nodes, approximate_minimum_vertex_cover(&graph));
+        assert!(cover.lower_bound <= tau && tau <= cover.nodes.len());
+        assert!(cover.ratio() <= 2.0);
+
+        let gamma = brute_force_domination_number(&graph);
+        let set = approximate_minimum_dominating_set_with_bound(&graph);
+        assert!(is_dominating(&graph, &set.nodes));
+        assert_eq!(set.nodes, approximate_minimum_dominating_set(&graph));
+        assert!(set.lower_bound <= gamma && gamma <= set.nodes.len());
+        assert!(set.ratio() >= 1.0);
+    }
+
+    let mut rng = StdRng::seed_from_u64(30);
+    let graph = random_graph(&mut rng, 2000, 0.002);
+    let cover = approximate_minimum_vertex_cover_with_bound(&graph);
+    assert!(is_cover(&graph, &cover.nodes));
+    assert!(cover.lower_bound > 0 && cover.ratio() <= 2.0);
+    let set = approximate_minimum_dominating_set_with_bound(&graph);
+    assert!(is_dominating(&graph, &set.nodes));
+    assert!(set.lower_bound > 0 && set.lower_bound <= set.nodes.len());
+
+    let empty = UnGraph::<(), ()>::new_undirected();
+    let set = approximate_minimum_dominating_set_with_bound(&empty);
+    assert_eq!((set.nodes.len(), set.lower_bound), (0, 0));
+    assert_eq!(set.ratio(), 1.0);
+}
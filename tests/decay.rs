@@ -0,0 +1,97 @@
+use petgraph::decay::DecaySchedule;
+use petgraph::graph::{DiGraph, EdgeIndex, NodeIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[test]
+fn decay_matches_naive_pruning() {
+    let mut rng = StdRng::seed_from_u64(30);
+    for _ in 0..50 {
+        let n = rng.gen_range(0..30);
+        let mut graph = DiGraph::<usize, f64>::new();
+        for i in 0..n {
+            graph.add_node(i);
+        }
+        if n > 0 {
+            for _ in 0..rng.gen_range(0..4 * n) {
+                let a = NodeIndex::new(rng.gen_range(0..n));
+                let b = NodeIndex::new(rng.gen_range(0..n));
+                graph.add_edge(a, b, rng.gen_range(0.0..10.0));
+            }
+        }
+        let original = graph.clone();
+        let schedule = DecaySchedule::new(0.5, 1.0).with_node_threshold(2.0);
+        let remapping = schedule.apply(&mut graph);
+
+        let mut strength = vec![0.0; n];
+        for edge in original.raw_edges() {
+            if edge.weight * 0.5 >= 1.0 {
+                strength[edge.source().index()] += edge.weight * 0.5;
+                if edge.source() != edge.target() {
+                    strength[edge.target().index()] += edge.weight * 0.5;
+                }
+            }
+        }
+        let mut kept_nodes = 0;
+        for v in original.node_indices() {
+            match remapping.node(v) {
+                Some(w) => {
+                    assert!(strength[v.index()] >= 2.0);
+                    assert_eq!(graph[w], original[v]);
+                    assert_eq!(w.index(), kept_nodes);
+                    kept_nodes += 1;
+                }
+                None => assert!(strength[v.index()] < 2.0),
+            }
+        }
+        assert_eq!(graph.node_count(), kept_nodes);
+        assert_eq!(remapping.removed_node_count(), n - kept_nodes);
+
+        let mut kept_edges = 0;
+        for (i, edge) in original.raw_edges().iter().enumerate() {
+            let source = remapping.node(edge.source());
+            let target = remapping.node(edge.target());
+            match remapping.edge(EdgeIndex::new(i)) {
+                Some(e) => {
+                    assert_eq!(e.index(), kept_edges);
+                    assert_eq!(graph[e], edge.weight * 0.5);
+                    assert_eq!(
+                        graph.edge_endpoints(e),
+                        Some((source.unwrap(), target.unwrap()))
+                    );
+                    kept_edges += 1;
+                }
+                None => assert!(edge.weight * 0.5 < 1.0 || source.is_none() || target.is_none()),
+            }
+        }
+        assert_eq!(graph.edge_count(), kept_edges);
+        assert_eq!(
+            remapping.is_identity(),
+            kept_nodes == n && kept_edges == original.edge_count()
+        );
+    }
+}
+
+#[test]
+fn decay_schedule_period() {
+    let mut graph = DiGraph::<(), u32>::from_edges([(0, 1, 16), (1, 2, 3), (2, 0, 40)]);
+    let mut schedule = DecaySchedule::new(1, 10).with_period(3);
+    assert_eq!(schedule.ticks_remaining(), 3);
+    assert!(schedule.tick(&mut graph).is_none());
+    assert!(schedule.tick(&mut graph).is_none());
+    assert_eq!(schedule.ticks_remaining(), 1);
+    let remapping = schedule.tick(&mut graph).unwrap();
+    assert_eq!(schedule.ticks_remaining(), 3);
+    assert_eq!(remapping.removed_edge_count(), 1);
+    assert_eq!(remapping.edge(EdgeIndex::new(2)), Some(EdgeIndex::new(1)));
+    assert_eq!(graph.edge_count(), 2);
+    assert_eq!(graph.node_count(), 3);
+
+    // Nothing else is pruned, so the indices are unchanged.
+    let remapping = DecaySchedule::new(2, 10).apply(&mut graph);
+    assert!(remapping.is_identity());
+    assert_eq!(
+        graph.edge_weights().copied().collect::<Vec<_>>(),
+        vec![32, 80]
+    );
+}
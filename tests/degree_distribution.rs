@@ -0,0 +1,167 @@
+use petgraph::algo::degree_distribution::{
+    degree_ccdf, degree_histogram, degree_sequence, rich_club_coefficient, weighted_degree,
+    weighted_degrees,
+};
+use petgraph::csr::Csr;
+use petgraph::graph::{DiGraph, UnGraph};
+#[cfg(feature = "graphmap")]
+use petgraph::graphmap::{DiGraphMap, UnGraphMap};
+#[cfg(feature = "matrix_graph")]
+use petgraph::matrix_graph::{DiMatrix, UnMatrix};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableUnGraph;
+use petgraph::visit::{Degree, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::{Directed, Undirected};
+
+#[test]
+fn degree_histograms() {
+    let empty = UnGraph::<(), ()>::new_undirected();
+    assert!(degree_histogram(&empty).is_empty());
+    assert!(degree_ccdf(&empty).is_empty());
+
+    // A path, with a self loop at one end, which counts twice.
+    let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 3)]);
+    assert_eq!(degree_histogram(&path), [0, 1, 2, 1]);
+    assert_eq!(degree_ccdf(&path), [1.0, 1.0, 0.75, 0.25]);
+
+    // The in- and out-degrees add up.
+    let directed = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (1, 2)]);
+    assert_eq!(degree_histogram(&directed), [0, 0, 3]);
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn degree_histogram_with_holes() {
+    // Removed nodes are not counted.
+    let mut stable = StableUnGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+    stable.remove_node(2.into());
+    assert_eq!(degree_histogram(&stable), [0, 2]);
+}
+
+/// The degrees of the nodes of a graph, by index.
+fn degrees<G>(graph: G) -> Vec<(usize, usize, usize)>
+where
+    G: IntoNodeIdentifiers + NodeIndexable + Degree,
+{
+    let mut degrees = vec![(0, 0, 0); graph.node_bound()];
+    for a in graph.node_identifiers() {
+        degrees[graph.to_index(a)] = (graph.out_degree(a), graph.in_degree(a), graph.degree(a));
+    }
+    degrees
+}
+
+/// A self loop on 2, and an edge to it from 0.
+const EDGES: [(u32, u32, u32); 4] = [(0, 1, 1), (1, 2, 2), (2, 2, 4), (0, 2, 8)];
+/// The out-, in- and total degrees of the nodes of `EDGES`, when directed.
+const DIRECTED_DEGREES: [(usize, usize, usize); 3] = [(2, 0, 2), (1, 1, 2), (1, 3, 4)];
+/// The degrees of the nodes of `EDGES`, when undirected.
+const UNDIRECTED_DEGREES: [(usize, usize, usize); 3] = [(2, 2, 2), (2, 2, 2), (4, 4, 4)];
+
+#[test]
+fn degrees_of_all_graph_types() {
+    let edges = EDGES;
+
+    let directed = DiGraph::<(), u32>::from_edges(edges);
+    assert_eq!(degrees(&directed), DIRECTED_DEGREES);
+    let mut csr = Csr::<(), u32, Directed>::with_nodes(3);
+    for (a, b, w) in edges {
+        csr.add_edge(a, b, w);
+    }
+    assert_eq!(degrees(&csr), DIRECTED_DEGREES);
+    assert_eq!(degree_sequence(&csr), [4, 2, 2]);
+
+    let undirected = UnGraph::<(), u32>::from_edges(edges);
+    assert_eq!(degrees(&undirected), UNDIRECTED_DEGREES);
+    let mut csr = Csr::<(), u32, Undirected>::with_nodes(3);
+    for (a, b, w) in edges {
+        csr.add_edge(a, b, w);
+    }
+    assert_eq!(degrees(&csr), UNDIRECTED_DEGREES);
+
+    // The weighted degrees count the edges the same way.
+    let weight = |e: petgraph::graph::EdgeReference<u32>| *e.weight();
+    assert_eq!(weighted_degree(&directed, 2.into(), weight), 18);
+    assert_eq!(weighted_degree(&undirected, 2.into(), weight), 18);
+    assert_eq!(weighted_degrees(&directed, weight), [9, 3, 18]);
+    assert_eq!(weighted_degrees(&undirected, weight), [9, 3, 18]);
+    assert_eq!(weighted_degrees(&csr, |e| *e.weight()), [9, 3, 18]);
+}
+
+#[test]
+#[cfg(feature = "graphmap")]
+fn degrees_of_graph_maps() {
+    use petgraph::visit::EdgeRef;
+
+    assert_eq!(
+        degrees(&DiGraphMap::<u32, u32>::from_edges(EDGES)),
+        DIRECTED_DEGREES
+    );
+    let map = UnGraphMap::<u32, u32>::from_edges(EDGES);
+    assert_eq!(degrees(&map), UNDIRECTED_DEGREES);
+    assert_eq!(weighted_degrees(&map, |e| *e.weight()), [9, 3, 18]);
+}
+
+#[test]
+#[cfg(feature = "matrix_graph")]
+fn degrees_of_matrix_graphs() {
+    let edges = EDGES.map(|(a, b, w)| (a as u16, b as u16, w));
+    assert_eq!(
+        degrees(&DiMatrix::<(), u32>::from_edges(edges)),
+        DIRECTED_DEGREES
+    );
+    assert_eq!(
+        degrees(&UnMatrix::<(), u32>::from_edges(edges)),
+        UNDIRECTED_DEGREES
+    );
+}
+
+#[test]
+fn rich_club_coefficients() {
+    // Directions, loops and parallel edges are ignored.
+    let graph =
+        DiGraph::<(), ()>::from_edges([(0, 1), (1, 0), (1, 2), (2, 0), (0, 0), (0, 3), (1, 4)]);
+    // Degrees 3, 3, 2, 1, 1: 5 edges among 5 nodes, 3 among the 3 nodes of
+    // degree at least 2, and 1 between the 2 nodes of degree 3.
+    assert_eq!(rich_club_coefficient(&graph), [0.5, 1.0, 1.0]);
+
+    let complete =
+        UnGraph::<(), ()>::from_edges((0..6u32).flat_map(|a| (a + 1..6).map(move |b| (a, b))));
+    assert_eq!(rich_club_coefficient(&complete), [1.0; 5]);
+
+    let single = UnGraph::<(), ()>::from_edges([(0, 0)]);
+    assert!(rich_club_coefficient(&single).is_empty());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn normalized_rich_club_coefficients() {
+    use petgraph::algo::degree_distribution::normalized_rich_club_coefficient;
+    use petgraph::SeededRng;
+
+    // Ten hubs forming a clique, each with ten leaves, and a ring through
+    // the leaves: random rewiring spreads the edges of the hubs out.
+    let mut edges = Vec::new();
+    for a in 0..10u32 {
+        for b in a + 1..10 {
+            edges.push((a, b));
+        }
+    }
+    for leaf in 10..110u32 {
+        edges.push((leaf % 10, leaf));
+        edges.push((leaf, if leaf == 109 { 10 } else { leaf + 1 }));
+    }
+    let graph = UnGraph::<(), ()>::from_edges(&edges);
+    let coefficients = rich_club_coefficient(&graph);
+    let normalized = normalized_rich_club_coefficient(&graph, 20, &mut SeededRng::new(5));
+    assert_eq!(normalized.len(), coefficients.len());
+    // The nodes of degree greater than 3 are the hubs.
+    assert_eq!(coefficients[3], 1.0);
+    assert!(normalized[3].unwrap() > 1.0);
+    // Nothing to normalize against for the empty graph.
+    assert!(normalized_rich_club_coefficient(
+        &UnGraph::<(), ()>::new_undirected(),
+        10,
+        &mut SeededRng::new(5)
+    )
+    .is_empty());
+}
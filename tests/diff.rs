@@ -0,0 +1,91 @@
+use petgraph::diff::{
+    assert_graph_eq, assert_graph_isomorphic, graph_diff, graph_diff_isomorphic, NodeMatching,
+};
+use petgraph::graph::{DiGraph, UnGraph};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableGraph;
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn graph_diff_nodes_and_edges() {
+    let mut left = StableGraph::<char, u32>::new();
+    let a = left.add_node('a');
+    let b = left.add_node('b');
+    let c = left.add_node('c');
+    left.add_edge(a, b, 1);
+    left.add_edge(a, b, 2);
+    left.add_edge(b, c, 3);
+
+    let mut right = left.clone();
+    right.remove_node(c);
+    let d = right.add_node('d');
+    right.add_edge(a, d, 4);
+    let ab = right.find_edge(a, b).unwrap();
+    right[ab] = 5;
+
+    let diff = graph_diff(&left, &right);
+    assert!(!diff.is_empty());
+    assert_eq!(diff.matching, NodeMatching::Index);
+    // `d` reuses the index of `c`.
+    assert_eq!(diff.changed_nodes, vec![(2, 'c', 'd')]);
+    assert!(diff.missing_nodes.is_empty());
+    assert!(diff.extra_nodes.is_empty());
+    assert_eq!(diff.missing_edges, vec![(1, 2, 3)]);
+    assert_eq!(diff.extra_edges, vec![(0, 2, 4)]);
+    assert_eq!(diff.changed_edges.len(), 1);
+    assert_eq!(diff.changed_edges[0].3, 5);
+
+    right.remove_node(d);
+    let diff = graph_diff(&left, &right);
+    assert_eq!(diff.missing_nodes, vec![(2, 'c')]);
+    assert_eq!(
+        diff.to_string().lines().collect::<Vec<_>>()[..3],
+        [
+            "graphs differ (nodes matched by index):",
+            "  - node 2: 'c'",
+            "  - edge 1 -> 2: 3",
+        ]
+    );
+    assert!(graph_diff(&left, &left).is_empty());
+    assert_eq!(graph_diff(&left, &left).to_string(), "graphs are equal");
+}
+
+#[test]
+fn graph_diff_undirected_ignores_edge_order() {
+    let left = UnGraph::<(), i32>::from_edges([(0, 1, 1), (1, 2, 2), (1, 2, 3)]);
+    let right = UnGraph::<(), i32>::from_edges([(2, 1, 3), (1, 0, 1), (2, 1, 2)]);
+    assert_graph_eq(&left, &right);
+
+    let right = UnGraph::<(), i32>::from_edges([(2, 1, 3), (1, 0, 1), (2, 1, 7)]);
+    let diff = graph_diff(&left, &right);
+    assert_eq!(diff.changed_edges, vec![(1, 2, 2, 7)]);
+    assert!(diff.to_string().ends_with("~ edge 1 -- 2: 2 => 7"));
+}
+
+#[test]
+fn graph_diff_isomorphic_matches_nodes() {
+    let left = DiGraph::<u8, char>::from_edges([(0, 1, 'x'), (1, 2, 'y')]);
+    let mut right = DiGraph::<u8, char>::from_edges([(2, 0, 'x'), (0, 1, 'y')]);
+    assert_graph_isomorphic(&left, &right);
+    assert!(!graph_diff(&left, &right).is_empty());
+
+    // Same structure, different weights: reported with the left indices.
+    let e = right.find_edge(0.into(), 1.into()).unwrap();
+    right[e] = 'z';
+    let diff = graph_diff_isomorphic(&left, &right);
+    assert_eq!(diff.matching, NodeMatching::Isomorphism);
+    assert_eq!(diff.changed_edges, vec![(1, 2, 'y', 'z')]);
+
+    right.add_node(0);
+    let diff = graph_diff_isomorphic(&left, &right);
+    assert_eq!(diff.matching, NodeMatching::NotIsomorphic);
+    assert_eq!(diff.extra_nodes, vec![(3, 0)]);
+}
+
+#[test]
+#[should_panic(expected = "+ edge 1 -> 0: ()")]
+fn assert_graph_eq_panics_with_diff() {
+    let left = DiGraph::<(), ()>::from_edges([(0, 1)]);
+    let right = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0)]);
+    assert_graph_eq(&left, &right);
+}
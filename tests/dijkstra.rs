@@ -0,0 +1,55 @@
+use petgraph::algo::{dijkstra, k_nearest_nodes};
+use petgraph::graph::{DiGraph, NodeIndex};
+
+/// A directed graph with 40 nodes in a cycle, with shortcuts.
+fn shortcuts() -> DiGraph<(), u32> {
+    let mut edges = Vec::new();
+    for i in 0..40u32 {
+        edges.push((i, (i * 7 + 3) % 40, i % 5 + 1));
+        edges.push((i, (i + 1) % 40, 10));
+    }
+    DiGraph::from_edges(&edges)
+}
+
+#[test]
+fn k_nearest() {
+    let mut graph = shortcuts();
+    // An unreachable node.
+    graph.add_node(());
+    for s in [0, 13, 39] {
+        let source = NodeIndex::new(s);
+        let all = dijkstra(&graph, source, None, |e| *e.weight());
+        for k in [0, 1, 5, 39, 100] {
+            let nearest = k_nearest_nodes(&graph, source, k, |e| *e.weight());
+            assert_eq!(nearest.len(), k.min(39));
+            assert!(nearest.iter().all(|&(v, d)| v != source && all[&v] == d));
+            assert!(nearest.windows(2).all(|w| w[0].1 <= w[1].1));
+            // No node outside of the result is closer than the farthest one.
+            if let Some(&(_, farthest)) = nearest.last() {
+                let closer = all.values().filter(|&&d| d < farthest).count();
+                assert!(closer <= k);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_multi_source() {
+    use petgraph::algo::par_multi_source_dijkstra;
+
+    let mut graph = shortcuts();
+    // An unreachable node.
+    graph.add_node(());
+    let sources: Vec<_> = (0..40).step_by(3).map(NodeIndex::new).collect();
+    let distances = par_multi_source_dijkstra(&graph, &sources, |e| *e.weight());
+    assert_eq!(distances.len(), sources.len());
+    for (&source, row) in sources.iter().zip(&distances) {
+        let expected = dijkstra(&graph, source, None, |e| *e.weight());
+        for v in graph.node_indices() {
+            assert_eq!(row[v.index()], expected.get(&v).copied());
+        }
+        assert_eq!(row[40], None);
+    }
+    assert!(par_multi_source_dijkstra(&graph, &[], |e| *e.weight()).is_empty());
+}
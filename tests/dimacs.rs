@@ -0,0 +1,58 @@
+use petgraph::algo::ford_fulkerson;
+use petgraph::graph::{DiGraph, EdgeReference, NodeIndex, UnGraph};
+use petgraph::io::dimacs::{from_dimacs, Dimacs, DimacsError};
+use petgraph::visit::EdgeRef;
+
+#[test]
+fn max_flow_round_trip() {
+    let graph = DiGraph::<(), u32>::from_edges([(0, 1, 3), (0, 2, 2), (1, 3, 2), (2, 3, 3)]);
+    let (source, sink) = (NodeIndex::new(0), NodeIndex::new(3));
+    let capacity = |edge: EdgeReference<u32>| f64::from(*edge.weight());
+    let max = Dimacs::max_flow(&graph, source, sink, &capacity).to_string();
+
+    let parsed = from_dimacs::<DiGraph<(), u32>>(&max).unwrap();
+    assert_eq!(parsed.problem, "max");
+    assert_eq!((parsed.source, parsed.sink), (Some(source), Some(sink)));
+    let (flow, _) = ford_fulkerson(&parsed.graph, source, sink);
+    assert_eq!(flow, 4);
+}
+
+#[test]
+fn undirected_graphs() {
+    let graph = UnGraph::<(), f64>::from_edges([(0, 1, 1.5), (1, 1, 2.0)]);
+    let length = |edge: EdgeReference<f64>| *edge.weight();
+    assert_eq!(
+        Dimacs::shortest_path(&graph, &length).to_string(),
+        "p sp 2 3\na 1 2 1.5\na 2 1 1.5\na 2 2 2\n"
+    );
+    let coloring = Dimacs::edge(&graph).to_string();
+    assert_eq!(coloring, "p edge 2 2\ne 1 2\ne 2 2\n");
+
+    let parsed = from_dimacs::<UnGraph<(), f64>>(&coloring).unwrap();
+    assert_eq!(parsed.problem, "edge");
+    let edges: Vec<_> = parsed
+        .graph
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+        .collect();
+    assert_eq!(edges, [(0, 1, 0.0), (1, 1, 0.0)]);
+}
+
+#[test]
+fn errors() {
+    let parse = |input: &str| from_dimacs::<DiGraph<(), i32>>(input).unwrap_err();
+    assert_eq!(parse("p sp 2 1\na 1 3 1"), DimacsError::UnknownNode(3));
+    assert_eq!(parse("p sp 2 1\na 0 1 1"), DimacsError::UnknownNode(0));
+    let syntax = |line, message| DimacsError::Syntax { line, message };
+    assert_eq!(parse("a 1 2 3"), syntax(1, "expected the problem line"));
+    assert_eq!(parse("p sp 2 1\na 1 2 x"), syntax(2, "invalid weight"));
+    assert_eq!(parse("p max 2 0\nn 1 x"), syntax(2, "expected s or t"));
+    assert_eq!(parse("c only comments\n"), syntax(1, "no problem line"));
+    assert_eq!(
+        parse("p sp 99999999999999999 0"),
+        syntax(1, "too many nodes for the length of the document")
+    );
+    // The edge count only sizes the allocation.
+    let problem = from_dimacs::<DiGraph<(), i32>>("p sp 2 99999999999999999\na 1 2 1").unwrap();
+    assert_eq!(problem.graph.edge_count(), 1);
+}
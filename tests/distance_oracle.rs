@@ -0,0 +1,68 @@
+#![cfg(feature = "rand")]
+
+use petgraph::algo::{dijkstra, distance_oracle};
+use petgraph::generators::gnm_random_graph;
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::SeededRng;
+use rand::Rng;
+
+/// A G(n, m) random graph with edge weights between 1 and 9.
+fn random_graph(rng: &mut SeededRng, n: usize, m: usize) -> UnGraph<(), u32> {
+    let mut graph: UnGraph<(), u32> = gnm_random_graph(n, m, rng);
+    for weight in graph.edge_weights_mut() {
+        *weight = rng.gen_range(1..10);
+    }
+    graph
+}
+
+#[test]
+fn distance_oracle_stretch_bound() {
+    let mut rng = SeededRng::new(1);
+    let graph = random_graph(&mut rng, 120, 300);
+    for k in 1..=4 {
+        let oracle = distance_oracle(&graph, k, |e| *e.weight(), &mut rng);
+        assert_eq!(oracle.stretch(), 2 * k - 1);
+        for a in graph.node_indices().step_by(7) {
+            let exact = dijkstra(&graph, a, None, |e| *e.weight());
+            for b in graph.node_indices() {
+                match exact.get(&b) {
+                    Some(&d) => {
+                        let estimate = oracle.distance(a, b).unwrap();
+                        assert!(estimate >= d);
+                        assert!(estimate <= d * (2 * k as u32 - 1));
+                        if k == 1 {
+                            assert_eq!(estimate, d);
+                        }
+                    }
+                    None => assert_eq!(oracle.distance(a, b), None),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn distance_oracle_is_reproducible_and_smaller() {
+    let graph = random_graph(&mut SeededRng::new(2), 300, 900);
+    let full = distance_oracle(&graph, 1, |e| *e.weight(), &mut SeededRng::new(5));
+    let a = distance_oracle(&graph, 3, |e| *e.weight(), &mut SeededRng::new(5));
+    let b = distance_oracle(&graph, 3, |e| *e.weight(), &mut SeededRng::new(5));
+    assert_eq!(a.size(), b.size());
+    assert!(a.size() < full.size());
+    for i in 0..300 {
+        let (x, y) = (NodeIndex::new(i), NodeIndex::new(299 - i));
+        assert_eq!(a.distance(x, y), b.distance(x, y));
+    }
+}
+
+#[test]
+fn distance_oracle_disconnected() {
+    let graph = UnGraph::<(), u32>::from_edges([(0, 1, 2), (2, 3, 4)]);
+    let oracle = distance_oracle(&graph, 2, |e| *e.weight(), &mut SeededRng::new(0));
+    assert_eq!(
+        oracle.distance(0.into(), 1.into()).map(|d| d >= 2),
+        Some(true)
+    );
+    assert_eq!(oracle.distance(1.into(), 2.into()), None);
+    assert_eq!(oracle.distance(0.into(), 9.into()), None);
+}
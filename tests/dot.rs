@@ -0,0 +1,204 @@
+use petgraph::dot::{from_dot, from_dot_labels, parse_dot, Config, Dot, DotError};
+use petgraph::graph::{DiGraph, UnGraph};
+use petgraph::visit::EdgeRef;
+
+fn attributes(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|&(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn statements() {
+    let dot = parse_dot(
+        r#"
+        /* A comment */
+        strict digraph "my graph" {
+            # a preprocessor line
+            rankdir = LR; graph [fontsize = 10]
+            node [shape = box, color = red]
+            a [label = "first" color = blue]; // line comment
+            a -> b -> c [weight = 2]
+            edge [style = dashed]
+            subgraph cluster_0 {
+                node [shape = circle]
+                d; e
+                label = "ignored"
+            }
+            c -> { d e } -> f
+            a -> b [weight = 3]
+            "node" -> a:port:ne
+            -1.5 -> .5
+            html [label = <<b>bold</b>>]
+        }
+        "#,
+    )
+    .unwrap();
+    assert!(dot.directed);
+    assert!(dot.strict);
+    assert_eq!(dot.name.as_deref(), Some("my graph"));
+    assert_eq!(
+        dot.attributes,
+        attributes(&[("rankdir", "LR"), ("fontsize", "10")])
+    );
+
+    let ids: Vec<_> = dot.nodes.iter().map(|node| node.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        ["a", "b", "c", "d", "e", "f", "node", "-1.5", ".5", "html"]
+    );
+    assert_eq!(
+        dot.nodes[0].attributes,
+        attributes(&[("shape", "box"), ("color", "blue"), ("label", "first")])
+    );
+    assert_eq!(dot.nodes[3].attribute("shape"), Some("circle"));
+    // The defaults of the subgraph do not leak out of it.
+    assert_eq!(dot.nodes[5].attribute("shape"), Some("box"));
+    assert_eq!(dot.nodes[9].attribute("label"), Some("<<b>bold</b>>"));
+
+    let edges: Vec<_> = dot
+        .edges
+        .iter()
+        .map(|edge| (edge.source, edge.target))
+        .collect();
+    // The strict graph merges the second a -> b.
+    assert_eq!(
+        edges,
+        [
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (2, 4),
+            (3, 5),
+            (4, 5),
+            (6, 0),
+            (7, 8)
+        ]
+    );
+    // The merged edge gets the defaults that apply to the second one.
+    assert_eq!(
+        dot.edges[0].attributes,
+        attributes(&[("weight", "3"), ("style", "dashed")])
+    );
+    assert_eq!(dot.edges[2].attributes, attributes(&[("style", "dashed")]));
+}
+
+#[test]
+fn strings() {
+    let dot = parse_dot(
+        "graph { \"a \\\"quoted\\\" \\\\ \\l\" -- \"split \" + \"string\" ; \"con\\\ntinued\" }",
+    )
+    .unwrap();
+    assert!(!dot.directed);
+    let ids: Vec<_> = dot.nodes.iter().map(|node| node.id.as_str()).collect();
+    assert_eq!(ids, ["a \"quoted\" \\ \\l", "split string", "continued"]);
+    // Keywords are case insensitive, unless quoted.
+    let dot = parse_dot("DiGraph { Node [a = b]; \"node\" }").unwrap();
+    assert_eq!(dot.nodes.len(), 1);
+    assert_eq!(dot.nodes[0].attribute("a"), Some("b"));
+}
+
+#[test]
+fn undirected_strict() {
+    let dot = parse_dot("strict graph { a -- b; b -- a [x = 1]; a -- a; a -- a }").unwrap();
+    assert_eq!(dot.edges.len(), 2);
+    assert_eq!(dot.edges[0].attribute("x"), Some("1"));
+}
+
+#[test]
+fn graphs() {
+    let graph: UnGraph<String, u32> = from_dot(
+        "graph { a -- { b c }; c -- d [w = 7] }",
+        |node| node.id,
+        |edge| edge.attribute("w").map_or(1, |w| w.parse().unwrap()),
+    )
+    .unwrap();
+    assert_eq!(graph.node_count(), 4);
+    let edges: Vec<_> = graph
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+        .collect();
+    assert_eq!(edges, [(0, 1, 1), (0, 2, 1), (2, 3, 7)]);
+
+    assert_eq!(
+        from_dot_labels::<DiGraph<String, String>>("graph { a }").unwrap_err(),
+        DotError::Directedness { directed: false }
+    );
+    assert_eq!(
+        from_dot_labels::<UnGraph<String, String>>("digraph { a }").unwrap_err(),
+        DotError::Directedness { directed: true }
+    );
+
+    // The output of `Dot` reads back.
+    let mut graph = UnGraph::<String, String>::new_undirected();
+    let a = graph.add_node("a".into());
+    let b = graph.add_node("{ b; }".into());
+    graph.add_node("c -> d".into());
+    graph.add_edge(a, b, "\"edge\"".into());
+    graph.add_edge(b, b, String::new());
+    for config in [&[][..], &[Config::EdgeNoLabel][..]] {
+        let parsed: UnGraph<String, String> =
+            from_dot_labels(&Dot::with_config(&graph, config).to_string()).unwrap();
+        assert!(parsed.node_weights().eq(graph.node_weights()));
+        let edges = |g: &UnGraph<String, String>| -> Vec<_> {
+            g.edge_references()
+                .map(|e| (e.source(), e.target()))
+                .collect()
+        };
+        assert_eq!(edges(&parsed), edges(&graph));
+        if config.is_empty() {
+            assert!(parsed.edge_weights().eq(graph.edge_weights()));
+        }
+    }
+}
+
+#[test]
+fn errors() {
+    let line = |input: &str| match parse_dot(input) {
+        Err(DotError::Syntax { line, .. }) => line,
+        other => panic!("expected a syntax error, got {other:?}"),
+    };
+    assert_eq!(line(""), 1);
+    assert_eq!(line("graph {"), 1);
+    assert_eq!(line("graph {\n a -> b\n}"), 2);
+    assert_eq!(line("digraph {\n\n a -- b }"), 3);
+    assert_eq!(line("digraph { a [b] }"), 1);
+    assert_eq!(line("digraph { a -> }"), 1);
+    assert_eq!(line("digraph { \"a }"), 1);
+    assert_eq!(line("digraph { /* a }"), 1);
+    assert_eq!(line("digraph { a } b"), 1);
+    assert_eq!(line("tree { a }"), 1);
+    assert_eq!(line("digraph { \"a\" + b }"), 1);
+}
+
+#[test]
+fn nested_subgraphs() {
+    let nested = |depth: usize| {
+        format!(
+            "digraph {{ {} a {} }}",
+            "{".repeat(depth),
+            "}".repeat(depth)
+        )
+    };
+    let graph = parse_dot(&nested(256)).unwrap();
+    assert_eq!(graph.nodes.len(), 1);
+    assert_eq!(
+        parse_dot(&nested(257)),
+        Err(DotError::Syntax {
+            line: 1,
+            message: "subgraphs nested too deeply"
+        })
+    );
+    // Too deep to read by recursion.
+    assert!(parse_dot(&nested(200_000)).is_err());
+}
+
+#[test]
+fn large_subgraphs() {
+    let ids: Vec<String> = (0..40_000).map(|i| format!("n{i}")).collect();
+    let dot = format!("digraph {{ {{ {} }} -> sink }}", ids.join(" -> "));
+    let graph = parse_dot(&dot).unwrap();
+    assert_eq!(graph.nodes.len(), 40_001);
+    assert_eq!(graph.edges.len(), 2 * 40_000 - 1);
+}
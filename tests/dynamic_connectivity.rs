@@ -0,0 +1,106 @@
+use petgraph::algo::DynamicConnectivity;
+use petgraph::graph::UnGraph;
+use petgraph::unionfind::UnionFind;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The components of the nodes of `edges`, from scratch.
+fn components(n: usize, edges: &[(usize, usize)]) -> UnionFind<usize> {
+    let mut components = UnionFind::new(n);
+    for &(a, b) in edges {
+        components.union(a, b);
+    }
+    components
+}
+
+fn check(connectivity: &DynamicConnectivity<usize>, n: usize, edges: &[(usize, usize)]) {
+    let expected = components(n, edges);
+    assert_eq!(connectivity.edge_count(), edges.len());
+    assert_eq!(
+        connectivity.component_count(),
+        (0..n).filter(|&a| expected.find(a) == a).count()
+    );
+    for a in 0..n {
+        let size = (0..n).filter(|&b| expected.equiv(a, b)).count();
+        assert_eq!(connectivity.component_size(a), size);
+        for b in 0..n {
+            assert_eq!(
+                connectivity.connected(a, b),
+                expected.equiv(a, b),
+                "{a} and {b}, with {edges:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn insert_and_delete() {
+    let mut connectivity = DynamicConnectivity::<usize>::new(5);
+    assert_eq!(connectivity.component_count(), 5);
+    assert!(connectivity.insert_edge(0, 1));
+    assert!(!connectivity.insert_edge(1, 0));
+    assert!(!connectivity.insert_edge(2, 2));
+    assert!(connectivity.insert_edge(1, 2));
+    assert!(connectivity.insert_edge(2, 0));
+    assert!(connectivity.contains_edge(0, 2));
+    check(&connectivity, 5, &[(0, 1), (1, 2), (2, 0)]);
+
+    assert!(connectivity.delete_edge(1, 0));
+    assert!(!connectivity.delete_edge(0, 1));
+    check(&connectivity, 5, &[(1, 2), (2, 0)]);
+    assert!(connectivity.delete_edge(2, 1));
+    check(&connectivity, 5, &[(2, 0)]);
+
+    let a = connectivity.add_node();
+    assert_eq!(a, 5);
+    assert!(connectivity.insert_edge(a, 4));
+    assert!(connectivity.insert_edge(4, 0));
+    check(&connectivity, 6, &[(2, 0), (5, 4), (4, 0)]);
+}
+
+#[test]
+fn from_graph() {
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (3, 4), (4, 4), (0, 1)]);
+    let connectivity = DynamicConnectivity::<usize>::from_graph(&graph);
+    check(&connectivity, 5, &[(0, 1), (1, 2), (3, 4)]);
+}
+
+#[test]
+fn random_updates() {
+    let mut rng = StdRng::seed_from_u64(1370);
+    for n in [2, 5, 12, 30] {
+        let mut connectivity = DynamicConnectivity::<usize>::new(n);
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for step in 0..600 {
+            // Favor insertions early, and deletions late, to go through dense
+            // and sparse graphs.
+            let insert = rng.gen_range(0..600) >= step;
+            if insert || edges.is_empty() {
+                let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+                let new = a != b && !edges.contains(&(a.min(b), a.max(b)));
+                assert_eq!(connectivity.insert_edge(a, b), new);
+                if new {
+                    edges.push((a.min(b), a.max(b)));
+                }
+            } else {
+                let (a, b) = edges.swap_remove(rng.gen_range(0..edges.len()));
+                assert!(connectivity.delete_edge(b, a));
+            }
+            if step % 7 == 0 {
+                check(&connectivity, n, &edges);
+            }
+        }
+        while let Some((a, b)) = edges.pop() {
+            assert!(connectivity.delete_edge(a, b));
+            check(&connectivity, n, &edges);
+        }
+        assert_eq!(connectivity.component_count(), n);
+    }
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let connectivity = DynamicConnectivity::<usize>::new(2);
+    connectivity.connected(0, 2);
+}
@@ -0,0 +1,155 @@
+use petgraph::graph::{DiGraph, UnGraph};
+use petgraph::io::edgelist::{from_edgelist, EdgeList, EdgeListError, EdgeListFormat};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableDiGraph;
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn round_trip() {
+    use petgraph::graph::NodeIndex;
+    use petgraph::visit::EdgeRef;
+
+    let mut graph = StableDiGraph::<String, i32>::new();
+    let a = graph.add_node("a".into());
+    let removed = graph.add_node("removed".into());
+    let b = graph.add_node("b".into());
+    let c = graph.add_node("c".into());
+    graph.add_edge(a, b, -1);
+    graph.add_edge(b, c, 2);
+    graph.add_edge(c, a, 3);
+    graph.remove_node(removed);
+
+    let labels = |(_, label): (_, &String)| label.clone();
+    let weights =
+        |edge: petgraph::stable_graph::EdgeReference<i32>| Some(edge.weight().to_string());
+    for format in [
+        EdgeListFormat::WHITESPACE,
+        EdgeListFormat::CSV,
+        EdgeListFormat::TSV,
+    ] {
+        let list = EdgeList::with_attr_getters(&graph, &labels, &weights)
+            .with_format(format)
+            .to_string();
+        let (parsed, interner): (DiGraph<String, i32>, _) =
+            from_edgelist(&list, format, false).unwrap();
+        assert_eq!(parsed.node_count(), 3);
+        assert_eq!(interner.len(), 3);
+        for (symbol, label) in interner.iter() {
+            assert_eq!(&parsed[NodeIndex::new(symbol.index())], label);
+        }
+        let edges: Vec<_> = parsed
+            .edge_references()
+            .map(|e| {
+                (
+                    &parsed[e.source()][..],
+                    &parsed[e.target()][..],
+                    *e.weight(),
+                )
+            })
+            .collect();
+        assert_eq!(edges, [("a", "b", -1), ("b", "c", 2), ("c", "a", 3)]);
+    }
+}
+
+#[test]
+fn comments_and_delimiters() {
+    let list = "% a list with percent comments\n1;2;0.5;extra\n  2 ; 3 \n\n3;1 % a triangle\n";
+    let format = EdgeListFormat {
+        delimiter: Some(';'),
+        comment: Some('%'),
+    };
+    let (graph, labels): (UnGraph<String, f64>, _) = from_edgelist(list, format, false).unwrap();
+    assert_eq!(
+        labels
+            .iter()
+            .map(|(_, label)| &label[..])
+            .collect::<Vec<_>>(),
+        ["1", "2", "3"]
+    );
+    assert_eq!(
+        graph.edge_weights().copied().collect::<Vec<_>>(),
+        [0.5, 0.0, 0.0]
+    );
+
+    let unweighted = EdgeList::new(&graph).to_string();
+    assert_eq!(unweighted, "0 1\n1 2\n2 0\n");
+}
+
+#[test]
+fn errors() {
+    let parse = |list: &str| {
+        from_edgelist::<DiGraph<String, u8>>(list, EdgeListFormat::default(), false).unwrap_err()
+    };
+    assert_eq!(
+        parse("a b\n\nlonely"),
+        EdgeListError::MissingTarget { line: 3 }
+    );
+    assert_eq!(
+        parse("a b 300"),
+        EdgeListError::InvalidWeight {
+            line: 1,
+            weight: "300".into()
+        }
+    );
+}
+
+#[cfg(feature = "csv")]
+mod csv {
+    use petgraph::graph::UnGraph;
+    use petgraph::io::csv::{from_csv, to_csv, CsvError, EdgeRecord, NodeRecord};
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Person {
+        name: String,
+        age: Option<u8>,
+    }
+
+    impl NodeRecord for Person {
+        fn id(&self) -> &str {
+            &self.name
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Knows {
+        a: String,
+        b: String,
+        since: i32,
+    }
+
+    impl EdgeRecord for Knows {
+        fn source(&self) -> &str {
+            &self.a
+        }
+        fn target(&self) -> &str {
+            &self.b
+        }
+    }
+
+    #[test]
+    fn typed_records() {
+        let nodes = "name,age\n\"Doe, Jane\",41\nBob,\n";
+        let edges = "a,b,since\n\"Doe, Jane\",Bob,1999\n";
+        let graph: UnGraph<Person, Knows> = from_csv(nodes.as_bytes(), edges.as_bytes()).unwrap();
+        assert_eq!(graph.raw_nodes()[0].weight.name, "Doe, Jane");
+        assert_eq!(graph.raw_nodes()[1].weight.age, None);
+        assert_eq!(graph.raw_edges()[0].weight.since, 1999);
+
+        let (mut nodes_out, mut edges_out) = (Vec::new(), Vec::new());
+        to_csv(&graph, &mut nodes_out, &mut edges_out).unwrap();
+        assert_eq!(nodes_out, nodes.as_bytes());
+        assert_eq!(edges_out, edges.as_bytes());
+
+        let unknown = from_csv::<UnGraph<Person, Knows>, _, _>(
+            nodes.as_bytes(),
+            "a,b,since\nBob,Alice,2000\n".as_bytes(),
+        );
+        assert!(matches!(unknown, Err(CsvError::UnknownNode(id)) if id == "Alice"));
+        let invalid = from_csv::<UnGraph<Person, Knows>, _, _>(
+            "name,age\nBob,old\n".as_bytes(),
+            edges.as_bytes(),
+        );
+        assert!(matches!(invalid, Err(CsvError::Csv(_))));
+    }
+}
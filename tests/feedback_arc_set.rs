@@ -0,0 +1,98 @@
+use petgraph::algo::{exact_feedback_arc_set, greedy_feedback_arc_set, is_cyclic_directed};
+use petgraph::graph::{DiGraph, EdgeIndex};
+use petgraph::visit::EdgeRef;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn without_edges(g: &DiGraph<(), u32>, removed: &[EdgeIndex]) -> DiGraph<(), u32> {
+    g.filter_map(
+        |_, _| Some(()),
+        |e, &w| if removed.contains(&e) { None } else { Some(w) },
+    )
+}
+
+/// Minimum cost of a feedback arc set, by trying every subset of edges.
+fn brute_force_cost(g: &DiGraph<(), u32>) -> u32 {
+    let m = g.edge_count();
+    (0..1u32 << m)
+        .filter_map(|mask| {
+            let removed: Vec<_> = (0..m)
+                .filter(|&i| mask & (1 << i) != 0)
+                .map(EdgeIndex::new)
+                .collect();
+            if is_cyclic_directed(&without_edges(g, &removed)) {
+                None
+            } else {
+                Some(removed.iter().map(|&e| g[e]).sum())
+            }
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn exact_fas_random_graphs() {
+    let mut rng = StdRng::seed_from_u64(11);
+    for _ in 0..40 {
+        let n = rng.gen_range(1..7);
+        let m = rng.gen_range(0..13);
+        let mut g = DiGraph::<(), u32>::new();
+        for _ in 0..n {
+            g.add_node(());
+        }
+        for _ in 0..m {
+            let a = rng.gen_range(0..n);
+            let b = rng.gen_range(0..n);
+            g.add_edge(a.into(), b.into(), rng.gen_range(1..5));
+        }
+
+        let fas: Vec<_> = exact_feedback_arc_set(&g, |e| *e.weight())
+            .iter()
+            .map(|e| e.id())
+            .collect();
+        assert!(!is_cyclic_directed(&without_edges(&g, &fas)));
+        let cost: u32 = fas.iter().map(|&e| g[e]).sum();
+        assert_eq!(cost, brute_force_cost(&g));
+
+        let exact = exact_feedback_arc_set(&g, |_| 1).len();
+        assert!(exact <= greedy_feedback_arc_set(&g).count());
+    }
+}
+
+#[test]
+fn exact_fas_loops_and_acyclic() {
+    let g = DiGraph::<(), u32>::from_edges([(0, 0, 1), (0, 1, 1), (1, 2, 1), (0, 2, 1)]);
+    let fas: Vec<_> = exact_feedback_arc_set(&g, |e| *e.weight())
+        .iter()
+        .map(|e| (e.source().index(), e.target().index()))
+        .collect();
+    assert_eq!(fas, vec![(0, 0)]);
+
+    let empty = DiGraph::<(), u32>::new();
+    assert!(exact_feedback_arc_set(&empty, |_| 1).is_empty());
+}
+
+#[test]
+fn exact_fas_tournament() {
+    // The cyclic tournament on 5 nodes: i -> i + 1 and i -> i + 2 (mod 5).
+    let mut edges = Vec::new();
+    for i in 0..5u32 {
+        edges.push((i, (i + 1) % 5, 1));
+        edges.push((i, (i + 2) % 5, 1));
+    }
+    let g = DiGraph::<(), u32>::from_edges(edges);
+    let fas: Vec<_> = exact_feedback_arc_set(&g, |_| 1)
+        .iter()
+        .map(|e| e.id())
+        .collect();
+    assert_eq!(fas.len(), 3);
+    assert!(!is_cyclic_directed(&without_edges(&g, &fas)));
+}
+
+#[test]
+#[should_panic(expected = "too large for an exact feedback arc set")]
+fn exact_fas_component_too_large() {
+    let edges: Vec<(u32, u32, u32)> = (0..26).map(|i| (i, (i + 1) % 26, 1)).collect();
+    let g = DiGraph::<(), u32>::from_edges(edges);
+    exact_feedback_arc_set(&g, |_| 1);
+}
@@ -0,0 +1,98 @@
+use petgraph::algo::{feedback_vertex_set, is_cyclic_directed, is_cyclic_undirected};
+use petgraph::graph::NodeIndex;
+use petgraph::{Directed, EdgeType, Graph, Undirected};
+
+fn remove_nodes<Ty: EdgeType>(graph: &Graph<(), (), Ty>, nodes: &[NodeIndex]) -> Graph<(), (), Ty> {
+    graph.filter_map(
+        |n, _| if nodes.contains(&n) { None } else { Some(()) },
+        |_, _| Some(()),
+    )
+}
+
+#[test]
+fn fvs_undirected_triangles() {
+    // Three triangles sharing node 0.
+    let graph: Graph<(), (), Undirected> = Graph::from_edges([
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (0, 3),
+        (3, 4),
+        (4, 0),
+        (0, 5),
+        (5, 6),
+        (6, 0),
+    ]);
+    let fvs = feedback_vertex_set(&graph);
+    assert_eq!(fvs, vec![NodeIndex::new(0)]);
+}
+
+#[test]
+fn fvs_undirected_complete_graph() {
+    // A complete graph on n nodes needs n - 2 nodes removed.
+    let mut graph: Graph<(), (), Undirected> = Graph::new_undirected();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    for (i, &a) in nodes.iter().enumerate() {
+        for &b in &nodes[i + 1..] {
+            graph.add_edge(a, b, ());
+        }
+    }
+    let fvs = feedback_vertex_set(&graph);
+    assert_eq!(fvs.len(), 4);
+    assert!(!is_cyclic_undirected(&remove_nodes(&graph, &fvs)));
+}
+
+#[test]
+fn fvs_undirected_grid_is_valid_and_minimal() {
+    let mut graph: Graph<(), (), Undirected> = Graph::new_undirected();
+    let nodes: Vec<_> = (0..16).map(|_| graph.add_node(())).collect();
+    for r in 0..4 {
+        for c in 0..4 {
+            if c + 1 < 4 {
+                graph.add_edge(nodes[r * 4 + c], nodes[r * 4 + c + 1], ());
+            }
+            if r + 1 < 4 {
+                graph.add_edge(nodes[r * 4 + c], nodes[(r + 1) * 4 + c], ());
+            }
+        }
+    }
+    let fvs = feedback_vertex_set(&graph);
+    assert!(!is_cyclic_undirected(&remove_nodes(&graph, &fvs)));
+    for i in 0..fvs.len() {
+        let mut smaller = fvs.clone();
+        smaller.remove(i);
+        assert!(is_cyclic_undirected(&remove_nodes(&graph, &smaller)));
+    }
+}
+
+#[test]
+fn fvs_directed() {
+    let graph: Graph<(), (), Directed> = Graph::from_edges([
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 0),
+        (2, 4),
+        (4, 5),
+        (5, 2),
+        (6, 7),
+        (7, 6),
+        (8, 9),
+    ]);
+    let fvs = feedback_vertex_set(&graph);
+    assert_eq!(fvs.len(), 2);
+    assert!(fvs.contains(&NodeIndex::new(2)));
+    assert!(!is_cyclic_directed(&remove_nodes(&graph, &fvs)));
+}
+
+#[test]
+fn fvs_loops_and_parallel_edges() {
+    let graph: Graph<(), (), Undirected> = Graph::from_edges([(0, 0), (1, 2), (1, 2), (3, 4)]);
+    let fvs = feedback_vertex_set(&graph);
+    assert_eq!(fvs.len(), 2);
+    assert!(fvs.contains(&NodeIndex::new(0)));
+    assert!(!is_cyclic_undirected(&remove_nodes(&graph, &fvs)));
+
+    let acyclic: Graph<(), (), Directed> = Graph::from_edges([(0, 1), (1, 2), (0, 2)]);
+    assert!(feedback_vertex_set(&acyclic).is_empty());
+}
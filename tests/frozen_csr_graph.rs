@@ -0,0 +1,84 @@
+use petgraph::algo::{dijkstra, tarjan_scc};
+use petgraph::graph::{node_index as n, DiGraph, FrozenCsrGraph, UnGraph};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::{Incoming, Outgoing};
+
+#[test]
+fn directed() {
+    let mut graph = DiGraph::<u32, u32>::new();
+    for i in 0..5 {
+        graph.add_node(i * 10);
+    }
+    let edges = [
+        (0, 1, 7),
+        (1, 2, 1),
+        (2, 0, 2),
+        (0, 3, 4),
+        (3, 3, 5),
+        (4, 3, 3),
+    ];
+    graph.extend_with_edges(edges);
+    let expected_scc = tarjan_scc(&graph).len();
+    let expected_dist = dijkstra(&graph, n(0), None, |e| *e.weight());
+
+    let frozen = graph.freeze();
+    assert_eq!((frozen.node_count(), frozen.edge_count()), (5, 6));
+    assert_eq!(frozen[n(4)], 40);
+    assert_eq!(frozen.out_degree(n(0)), 2);
+    assert_eq!(frozen.in_degree(n(3)), 3);
+    assert_eq!(frozen.degree(n(3)), 4);
+    assert_eq!(frozen.neighbors(n(0)).collect::<Vec<_>>(), [n(1), n(3)]);
+    assert_eq!(
+        frozen
+            .neighbors_directed(n(3), Incoming)
+            .collect::<Vec<_>>(),
+        [n(0), n(3), n(4)]
+    );
+    let incoming: Vec<_> = frozen
+        .edges_directed(n(0), Incoming)
+        .map(|e| (e.source(), e.target(), *e.weight()))
+        .collect();
+    assert_eq!(incoming, [(n(2), n(0), 2)]);
+
+    let e = frozen.find_edge(n(4), n(3)).unwrap();
+    assert_eq!(frozen[e], 3);
+    assert_eq!(frozen.edge_endpoints(e), Some((n(4), n(3))));
+    assert!(frozen.find_edge(n(3), n(4)).is_none());
+
+    assert_eq!(tarjan_scc(&frozen).len(), expected_scc);
+    assert_eq!(
+        dijkstra(&frozen, n(0), None, |e| *e.weight()),
+        expected_dist
+    );
+    assert_eq!(frozen.edge_references().len(), 6);
+
+    let graph = frozen.into_graph();
+    assert_eq!(graph.edge_count(), 6);
+    assert_eq!(graph[graph.find_edge(n(4), n(3)).unwrap()], 3);
+}
+
+#[test]
+fn undirected() {
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 2), (3, 1)]);
+    let frozen = FrozenCsrGraph::from(graph);
+    assert_eq!(
+        frozen.neighbors(n(1)).collect::<Vec<_>>(),
+        [n(2), n(0), n(3)]
+    );
+    // The self loop is listed once, and counts twice in the degree.
+    assert_eq!(frozen.neighbors(n(2)).collect::<Vec<_>>(), [n(2), n(1)]);
+    assert_eq!(frozen.degree(n(2)), 3);
+    assert_eq!(frozen.out_degree(n(2)), 3);
+    assert!(frozen.contains_edge(n(1), n(3)));
+    assert!(frozen.contains_edge(n(3), n(1)));
+    assert!(!frozen.contains_edge(n(0), n(3)));
+
+    for edge in frozen.edges(n(1)) {
+        assert_eq!(edge.source(), n(1));
+    }
+    for edge in frozen.edges_directed(n(1), Incoming) {
+        assert_eq!(edge.target(), n(1));
+    }
+    assert_eq!(frozen.edges_directed(n(1), Outgoing).count(), 3);
+    assert_eq!(frozen.neighbors(n(7)).count(), 0);
+}
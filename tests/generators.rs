@@ -0,0 +1,358 @@
+use std::collections::HashSet;
+
+use petgraph::algo::{connected_components, is_bipartite_undirected, is_cyclic_directed};
+use petgraph::generators::{
+    balanced_tree, complete_bipartite_graph, complete_graph, cycle_graph, grid_graph,
+    havel_hakimi_graph, hypercube_graph, is_graphical, path_graph, star_graph, wheel_graph,
+};
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+use petgraph::visit::EdgeRef;
+
+/// Check that a graph has no self loops or parallel edges.
+fn assert_simple<Ty: petgraph::EdgeType>(graph: &petgraph::Graph<(), (), Ty>) {
+    let mut seen = HashSet::new();
+    for edge in graph.edge_references() {
+        let (a, b) = (edge.source().index(), edge.target().index());
+        assert_ne!(a, b);
+        let key = if graph.is_directed() || a < b {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        assert!(seen.insert(key), "parallel edge {key:?}");
+    }
+}
+
+fn degrees(graph: &UnGraph<(), ()>) -> Vec<usize> {
+    graph
+        .node_indices()
+        .map(|v| graph.neighbors(v).count())
+        .collect()
+}
+
+#[test]
+fn classic_families() {
+    for n in 0..6 {
+        let graph: UnGraph<(), ()> = complete_graph(n);
+        assert_eq!(graph.edge_count(), n * n.saturating_sub(1) / 2);
+        assert_simple(&graph);
+        let graph: UnGraph<(), ()> = path_graph(n);
+        assert_eq!(graph.edge_count(), n.saturating_sub(1));
+        let graph: DiGraph<(), ()> = cycle_graph(n);
+        assert_simple(&graph);
+        assert_eq!(is_cyclic_directed(&graph), n >= 3);
+        let graph: UnGraph<(), ()> = star_graph(n);
+        assert_eq!(degrees(&graph)[0], n);
+        let graph: UnGraph<(), ()> = wheel_graph(n);
+        assert_simple(&graph);
+        if n >= 3 {
+            assert!(degrees(&graph)[1..].iter().all(|&d| d == 3));
+        }
+    }
+
+    let graph: UnGraph<(), ()> = complete_bipartite_graph(3, 4);
+    assert_eq!(degrees(&graph), [4, 4, 4, 3, 3, 3, 3]);
+    assert!(is_bipartite_undirected(&graph, NodeIndex::new(0)));
+
+    // A 2 × 3 × 4 grid, with and without wrapping.
+    let grid: UnGraph<(), ()> = grid_graph(&[2, 3, 4], false);
+    assert_eq!(grid.edge_count(), 3 * 4 + 2 * 2 * 4 + 2 * 3 * 3);
+    assert_simple(&grid);
+    assert!(grid.contains_edge(NodeIndex::new(0), NodeIndex::new(12)));
+    assert!(grid.contains_edge(NodeIndex::new(5), NodeIndex::new(9)));
+    assert!(!grid.contains_edge(NodeIndex::new(3), NodeIndex::new(4)));
+    let torus: UnGraph<(), ()> = grid_graph(&[2, 3, 4], true);
+    assert_simple(&torus);
+    assert!(degrees(&torus).iter().all(|&d| d == 5));
+    let ring: UnGraph<(), ()> = grid_graph(&[7], true);
+    assert_eq!(ring.edge_count(), 7);
+    let empty: UnGraph<(), ()> = grid_graph(&[3, 0], true);
+    assert_eq!(empty.node_count(), 0);
+
+    for d in 0..6 {
+        let cube: UnGraph<(), ()> = hypercube_graph(d);
+        assert_simple(&cube);
+        assert!(degrees(&cube).iter().all(|&x| x == d as usize));
+        assert_eq!(connected_components(&cube), 1);
+    }
+
+    let tree: DiGraph<(), ()> = balanced_tree(3, 2);
+    assert_eq!(tree.node_count(), 13);
+    assert!(tree
+        .node_indices()
+        .skip(1)
+        .all(|v| tree.neighbors_directed(v, petgraph::Incoming).count() == 1));
+    let path: UnGraph<(), ()> = balanced_tree(1, 4);
+    assert_eq!(degrees(&path), [1, 2, 2, 2, 1]);
+    let root: UnGraph<(), ()> = balanced_tree(0, 3);
+    assert_eq!(root.node_count(), 1);
+}
+
+#[test]
+fn degree_sequences() {
+    // Every sequence of up to 6 degrees below 6: the Erdős–Gallai test and
+    // the Havel–Hakimi construction must agree.
+    let mut graphical = 0;
+    for n in 0..=6u32 {
+        for code in 0..6usize.pow(n) {
+            let sequence: Vec<usize> = (0..n).map(|i| code / 6usize.pow(i) % 6).collect();
+            let graph: Option<UnGraph<(), ()>> = havel_hakimi_graph(&sequence);
+            assert_eq!(is_graphical(&sequence), graph.is_some(), "{sequence:?}");
+            if let Some(graph) = graph {
+                assert_simple(&graph);
+                assert_eq!(degrees(&graph), sequence);
+                graphical += 1;
+            }
+        }
+    }
+    // Many of the sequences were graphical, so both answers were checked.
+    assert!(graphical > 1000);
+    assert!(is_graphical(&[]));
+    assert!(!is_graphical(&[1]));
+    assert!(havel_hakimi_graph::<DiGraph<(), ()>>(&[2, 2, 2]).is_some());
+}
+
+#[cfg(feature = "rand")]
+mod random {
+    use super::*;
+
+    use petgraph::generators::{
+        barabasi_albert_graph, configuration_model, gnm_random_graph, gnp_random_graph,
+        lfr_benchmark_graph, random_geometric_graph, random_regular_graph, rewire,
+        stochastic_block_model, watts_strogatz_graph, LfrConfig,
+    };
+    use petgraph::stable_graph::StableUnGraph;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn erdos_renyi() {
+        let mut rng = StdRng::seed_from_u64(39);
+        let empty: UnGraph<(), ()> = gnp_random_graph(30, 0.0, &mut rng);
+        assert_eq!((empty.node_count(), empty.edge_count()), (30, 0));
+        let full: DiGraph<(), ()> = gnp_random_graph(30, 1.0, &mut rng);
+        assert_eq!(full.edge_count(), 30 * 29);
+        let graph: DiGraph<(), ()> = gnp_random_graph(60, 0.2, &mut rng);
+        assert_simple(&graph);
+        assert!((500..900).contains(&graph.edge_count()));
+
+        for m in [0, 10, 100, 189, 190] {
+            let graph: UnGraph<(), ()> = gnm_random_graph(20, m, &mut rng);
+            assert_eq!((graph.node_count(), graph.edge_count()), (20, m));
+            assert_simple(&graph);
+            let graph: DiGraph<(), ()> = gnm_random_graph(20, 2 * m, &mut rng);
+            assert_eq!(graph.edge_count(), 2 * m);
+            assert_simple(&graph);
+        }
+        let graph: StableUnGraph<(), ()> = gnm_random_graph(5, 7, &mut rng);
+        assert_eq!(graph.edge_count(), 7);
+
+        // The same seed gives the same graph.
+        let a: UnGraph<(), ()> = gnm_random_graph(50, 100, &mut StdRng::seed_from_u64(1));
+        let b: UnGraph<(), ()> = gnm_random_graph(50, 100, &mut StdRng::seed_from_u64(1));
+        let edges = |g: &UnGraph<(), ()>| -> Vec<_> {
+            g.edge_references()
+                .map(|e| (e.source(), e.target()))
+                .collect()
+        };
+        assert_eq!(edges(&a), edges(&b));
+    }
+
+    #[test]
+    fn preferential_attachment_and_small_world() {
+        let mut rng = StdRng::seed_from_u64(1324);
+        for m in [1, 3] {
+            let graph: UnGraph<(), ()> = barabasi_albert_graph(300, m, &mut rng);
+            assert_eq!(graph.edge_count(), m * (300 - m));
+            assert_simple(&graph);
+            // Every node added after the initial ones has at least m edges.
+            assert!(degrees(&graph)[m..].iter().all(|&d| d >= m));
+        }
+        let tree: DiGraph<(), ()> = barabasi_albert_graph(100, 1, &mut rng);
+        assert!(tree.edge_references().all(|e| e.source() > e.target()));
+
+        for beta in [0.0, 0.2, 1.0] {
+            let graph: UnGraph<(), ()> = watts_strogatz_graph(40, 6, beta, &mut rng);
+            assert_eq!(graph.edge_count(), 40 * 3);
+            assert_simple(&graph);
+            let degrees = degrees(&graph);
+            assert_eq!(degrees.iter().sum::<usize>(), 240);
+            if beta == 0.0 {
+                assert!(degrees.iter().all(|&d| d == 6));
+            }
+        }
+    }
+
+    #[test]
+    fn regular_and_geometric() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for (n, d) in [(10, 0), (10, 9), (11, 4), (100, 3), (60, 6)] {
+            let graph: UnGraph<(), ()> = random_regular_graph(n, d, &mut rng);
+            assert_eq!(graph.edge_count(), n * d / 2);
+            assert_simple(&graph);
+            assert!(degrees(&graph).iter().all(|&x| x == d));
+        }
+
+        for radius in [0.0, 0.05, 0.3, 2.0] {
+            let (graph, points): (UnGraph<(), ()>, _) =
+                random_geometric_graph(150, radius, &mut rng);
+            assert_eq!(points.len(), 150);
+            assert_simple(&graph);
+            let mut expected = 0;
+            for i in 0..150 {
+                for j in i + 1..150 {
+                    let [x1, y1] = points[i];
+                    let [x2, y2] = points[j];
+                    let close = (x1 - x2).powi(2) + (y1 - y2).powi(2) <= radius * radius;
+                    expected += usize::from(close);
+                    if close {
+                        assert!(graph.contains_edge(NodeIndex::new(i), NodeIndex::new(j)));
+                    }
+                }
+            }
+            assert_eq!(graph.edge_count(), expected);
+        }
+    }
+
+    #[test]
+    fn community_structure() {
+        let mut rng = StdRng::seed_from_u64(1326);
+        let probabilities = vec![
+            vec![1.0, 0.0, 0.5],
+            vec![0.0, 1.0, 0.0],
+            vec![0.5, 0.0, 0.0],
+        ];
+        let (graph, blocks): (UnGraph<(), ()>, _) =
+            stochastic_block_model(&[4, 3, 5], &probabilities, &mut rng);
+        assert_eq!(blocks, [0, 0, 0, 0, 1, 1, 1, 2, 2, 2, 2, 2]);
+        assert_simple(&graph);
+        // The first two blocks are cliques, and the last one is only linked
+        // to the first.
+        assert!(degrees(&graph)[4..7].iter().all(|&d| d == 2));
+        for edge in graph.edge_references() {
+            let (a, b) = (blocks[edge.source().index()], blocks[edge.target().index()]);
+            assert!(probabilities[a][b] > 0.0);
+        }
+        let (graph, _): (DiGraph<(), ()>, _) =
+            stochastic_block_model(&[3, 3], &[[1.0, 1.0], [0.0, 1.0]], &mut rng);
+        assert_eq!(graph.edge_count(), 6 + 6 + 9);
+
+        let config = LfrConfig {
+            mixing: 0.3,
+            min_degree: 4,
+            max_degree: 30,
+            min_community: 10,
+            max_community: 50,
+            ..LfrConfig::default()
+        };
+        let (graph, communities): (UnGraph<(), ()>, _) =
+            lfr_benchmark_graph(500, &config, &mut rng).unwrap();
+        assert_eq!((graph.node_count(), communities.len()), (500, 500));
+        assert_simple(&graph);
+        let mut sizes = vec![0; communities.iter().max().unwrap() + 1];
+        for &c in &communities {
+            sizes[c] += 1;
+        }
+        assert!(sizes.iter().all(|size| (10..=50).contains(size)));
+        let degrees = degrees(&graph);
+        assert!(degrees.iter().all(|&d| d <= 30));
+        let mean = degrees.iter().sum::<usize>() as f64 / 500.0;
+        assert!((5.0..12.0).contains(&mean), "mean degree {mean}");
+        let crossing = graph
+            .edge_references()
+            .filter(|e| communities[e.source().index()] != communities[e.target().index()])
+            .count();
+        let mixing = crossing as f64 / graph.edge_count() as f64;
+        assert!((0.25..0.35).contains(&mixing), "mixing {mixing}");
+
+        // Communities too large for the graph.
+        let config = LfrConfig {
+            min_community: 600,
+            max_community: 700,
+            max_attempts: 3,
+            ..LfrConfig::default()
+        };
+        assert!(lfr_benchmark_graph::<UnGraph<(), ()>, _>(500, &config, &mut rng).is_none());
+    }
+
+    #[test]
+    fn configuration() {
+        let mut rng = StdRng::seed_from_u64(1327);
+        for _ in 0..20 {
+            let graph: UnGraph<(), ()> = gnp_random_graph(30, 0.2, &mut rng);
+            let sequence = degrees(&graph);
+            assert!(is_graphical(&sequence));
+
+            // Without rejection, the degrees are exact, counting loops twice.
+            let multigraph: UnGraph<(), ()> =
+                configuration_model(&sequence, false, false, &mut rng);
+            assert_eq!(multigraph.edge_count(), graph.edge_count());
+            let mut counted = vec![0; 30];
+            for edge in multigraph.edge_references() {
+                counted[edge.source().index()] += 1;
+                counted[edge.target().index()] += 1;
+            }
+            assert_eq!(counted, sequence);
+
+            let simple: UnGraph<(), ()> = configuration_model(&sequence, true, true, &mut rng);
+            assert_simple(&simple);
+            assert!(degrees(&simple)
+                .iter()
+                .zip(&sequence)
+                .all(|(d, expected)| d <= expected));
+            // Few endpoints are dropped.
+            assert!(simple.edge_count() + 5 >= graph.edge_count());
+        }
+
+        // A single node with two endpoints can only form a loop.
+        let graph: UnGraph<(), ()> = configuration_model(&[2], false, false, &mut rng);
+        assert_eq!(graph.edge_count(), 1);
+        let graph: UnGraph<(), ()> = configuration_model(&[2], true, false, &mut rng);
+        assert_eq!(graph.edge_count(), 0);
+    }
+
+    #[test]
+    fn rewiring() {
+        let mut rng = StdRng::seed_from_u64(1341);
+        let original: UnGraph<(), u32> = gnm_random_graph::<UnGraph<(), ()>, _>(40, 100, &mut rng)
+            .map(|_, _| (), |e, _| e.index() as u32);
+        let mut graph = original.clone();
+        assert_eq!(rewire(&mut graph, 1000, &mut rng), 1000);
+        assert_simple(&graph.map(|_, _| (), |_, _| ()));
+        assert_eq!(graph.node_count(), 40);
+        assert_eq!(graph.edge_count(), 100);
+        for v in graph.node_indices() {
+            assert_eq!(graph.neighbors(v).count(), original.neighbors(v).count());
+        }
+        // Each edge keeps its weight, and the graph changed.
+        assert!(graph.edge_indices().all(|e| graph[e] == e.index() as u32));
+        let edges = |g: &UnGraph<(), u32>| -> HashSet<(usize, usize)> {
+            g.edge_references()
+                .map(|e| {
+                    let (a, b) = (e.source().index(), e.target().index());
+                    (a.min(b), a.max(b))
+                })
+                .collect()
+        };
+        assert!(edges(&graph).intersection(&edges(&original)).count() < 50);
+
+        // Directed swaps keep the in- and out-degrees.
+        let original: DiGraph<(), ()> = gnm_random_graph(30, 90, &mut rng);
+        let mut graph = original.clone();
+        assert_eq!(rewire(&mut graph, 500, &mut rng), 500);
+        assert_simple(&graph);
+        for v in graph.node_indices() {
+            for dir in [petgraph::Outgoing, petgraph::Incoming] {
+                assert_eq!(
+                    graph.neighbors_directed(v, dir).count(),
+                    original.neighbors_directed(v, dir).count()
+                );
+            }
+        }
+
+        // No swap is possible in a star or a triangle.
+        let mut star: UnGraph<(), ()> = star_graph(5);
+        assert_eq!(rewire(&mut star, 10, &mut rng), 0);
+        let mut triangle: UnGraph<(), ()> = complete_graph(3);
+        assert_eq!(rewire(&mut triangle, 10, &mut rng), 0);
+    }
+}
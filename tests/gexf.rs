@@ -0,0 +1,145 @@
+use petgraph::graph::{DiGraph, EdgeReference, UnGraph};
+use petgraph::io::gexf::{from_gexf, Gexf, GexfEdge, GexfError, GexfNode};
+use petgraph::io::{AttrValue, Attributes};
+use petgraph::visit::EdgeRef;
+
+#[test]
+fn round_trip() {
+    let mut graph = DiGraph::<(&str, i64), (f64, bool)>::new();
+    let a = graph.add_node(("<a & b>", 1));
+    let b = graph.add_node(("\"quoted\"", -7));
+    let c = graph.add_node(("", 0));
+    graph.add_edge(a, b, (0.5, true));
+    graph.add_edge(b, c, (-1e300, false));
+    graph.add_edge(c, c, (3.0, true));
+
+    let node_attributes = |(_, &(label, size)): (_, &(&str, i64))| {
+        Attributes::from([
+            ("label".into(), AttrValue::from(label)),
+            ("size".into(), AttrValue::from(size)),
+        ])
+    };
+    let edge_attributes = |edge: EdgeReference<(f64, bool)>| {
+        let (weight, flag) = *edge.weight();
+        Attributes::from([
+            ("weight".into(), AttrValue::from(weight)),
+            ("flag".into(), AttrValue::from(flag)),
+        ])
+    };
+    let xml = Gexf::with_attr_getters(&graph, &node_attributes, &edge_attributes).to_string();
+    assert!(xml.contains(r#"title="size" type="long""#));
+    assert!(xml.contains(r#"defaultedgetype="directed""#));
+
+    let parsed: DiGraph<GexfNode, GexfEdge> = from_gexf(&xml).unwrap();
+    assert_eq!(parsed.node_count(), 3);
+    for node in graph.node_indices() {
+        let weight = &parsed[node];
+        assert_eq!(weight.id, node.index().to_string());
+        assert_eq!(weight.label.as_deref(), Some(graph[node].0));
+        assert_eq!(weight.attributes["size"], AttrValue::Int(graph[node].1));
+    }
+    assert_eq!(parsed.edge_count(), 3);
+    for (edge, original) in parsed.edge_references().zip(graph.edge_references()) {
+        assert_eq!(
+            (edge.source(), edge.target()),
+            (original.source(), original.target())
+        );
+        assert_eq!(edge.weight().weight, Some(original.weight().0));
+        assert_eq!(
+            edge.weight().attributes["flag"],
+            AttrValue::Boolean(original.weight().1)
+        );
+    }
+}
+
+#[test]
+fn older_versions_and_hierarchy() {
+    // GEXF 1.1 names the attribute of a value `id`, and nodes may nest.
+    let xml = r#"<?xml version="1.0"?>
+<gexf xmlns="http://www.gexf.net/1.1draft" xmlns:viz="http://www.gexf.net/1.1draft/viz">
+  <meta><creator>test</creator></meta>
+  <graph defaultedgetype="undirected">
+    <attributes class="node">
+      <attribute id="a0" title="tags" type="liststring"/>
+    </attributes>
+    <nodes>
+      <node id="group" label="Group">
+        <viz:color r="1" g="2" b="3"/>
+        <nodes>
+          <node id="x"><attvalues><attvalue id="a0" value="one|two"/></attvalues></node>
+        </nodes>
+      </node>
+    </nodes>
+    <edges>
+      <edge id="e" source="x" target="group" type="directed"/>
+    </edges>
+  </graph>
+</gexf>"#;
+    let graph: UnGraph<GexfNode, GexfEdge> = from_gexf(xml).unwrap();
+    let ids: Vec<_> = graph
+        .raw_nodes()
+        .iter()
+        .map(|n| n.weight.id.as_str())
+        .collect();
+    assert_eq!(ids, ["x", "group"]);
+    assert_eq!(
+        graph.raw_nodes()[0].weight.attributes["tags"],
+        AttrValue::from("one|two")
+    );
+    let edge = &graph.raw_edges()[0];
+    assert_eq!((edge.source().index(), edge.target().index()), (0, 1));
+    assert_eq!(edge.weight.id.as_deref(), Some("e"));
+    assert_eq!(edge.weight.weight, None);
+}
+
+#[test]
+fn errors() {
+    let document = |nodes: &str, edges: &str| {
+        format!(
+            r#"<gexf><graph><attributes class="edge"><attribute id="0" title="w" type="double"/></attributes><nodes>{nodes}</nodes><edges>{edges}</edges></graph></gexf>"#
+        )
+    };
+    let parse = |xml: &str| from_gexf::<UnGraph<GexfNode, GexfEdge>>(xml).unwrap_err();
+    assert_eq!(
+        parse(&document(r#"<node id="a"/><node id="a"/>"#, "")),
+        GexfError::DuplicateNode("a".into())
+    );
+    assert_eq!(
+        parse(&document(
+            r#"<node id="a"/>"#,
+            r#"<edge source="a" target="b"/>"#
+        )),
+        GexfError::UnknownNode("b".into())
+    );
+    assert_eq!(
+        parse(&document(
+            r#"<node id="a"/>"#,
+            r#"<edge source="a" target="a"><attvalues><attvalue for="1" value="1"/></attvalues></edge>"#
+        )),
+        GexfError::UnknownAttribute("1".into())
+    );
+    assert_eq!(
+        parse(&document(
+            r#"<node id="a"/>"#,
+            r#"<edge source="a" target="a"><attvalues><attvalue for="0" value="x"/></attvalues></edge>"#
+        )),
+        GexfError::InvalidValue {
+            attribute: "0".into(),
+            value: "x".into()
+        }
+    );
+    assert_eq!(
+        parse(&document(
+            r#"<node id="a"/>"#,
+            r#"<edge source="a" target="a" weight="heavy"/>"#
+        )),
+        GexfError::InvalidValue {
+            attribute: "weight".into(),
+            value: "heavy".into()
+        }
+    );
+    assert!(matches!(
+        parse("<graphml></graphml>"),
+        GexfError::Syntax { line: 1, .. }
+    ));
+}
@@ -0,0 +1,108 @@
+use petgraph::graph::{DiGraph, EdgeReference, UnGraph};
+use petgraph::io::gml::{from_gml, Gml, GmlEdge, GmlError, GmlNode};
+use petgraph::io::{AttrValue, Attributes};
+use petgraph::visit::EdgeRef;
+
+#[test]
+fn round_trip() {
+    let mut graph = UnGraph::<(&str, i64), (f64, bool)>::new_undirected();
+    let a = graph.add_node(("\"a\" & b", 1));
+    let b = graph.add_node(("multi\nline", -7));
+    graph.add_edge(a, b, (0.5, true));
+    graph.add_edge(b, b, (-3.0, false));
+    graph.add_edge(a, b, (1e300, true));
+
+    let node_attributes = |(_, &(label, size)): (_, &(&str, i64))| {
+        Attributes::from([
+            ("label".into(), AttrValue::from(label)),
+            ("size".into(), AttrValue::from(size)),
+        ])
+    };
+    let edge_attributes = |edge: EdgeReference<(f64, bool)>| {
+        let (weight, flag) = *edge.weight();
+        Attributes::from([
+            ("weight".into(), AttrValue::from(weight)),
+            ("flag".into(), AttrValue::from(flag)),
+        ])
+    };
+    let gml = Gml::with_attr_getters(&graph, &node_attributes, &edge_attributes).to_string();
+    assert!(gml.starts_with("graph [\n  directed 0\n"));
+
+    let parsed: UnGraph<GmlNode, GmlEdge> = from_gml(&gml).unwrap();
+    assert_eq!(parsed.node_count(), 2);
+    for node in graph.node_indices() {
+        let weight = &parsed[node];
+        assert_eq!(weight.id, node.index() as i64);
+        assert_eq!(weight.attributes, node_attributes((node, &graph[node])));
+    }
+    assert_eq!(parsed.edge_count(), 3);
+    for (edge, original) in parsed.edge_references().zip(graph.edge_references()) {
+        assert_eq!(
+            (edge.source(), edge.target()),
+            (original.source(), original.target())
+        );
+        let (weight, flag) = *original.weight();
+        assert_eq!(edge.weight().attributes["weight"], AttrValue::Float(weight));
+        // Booleans are written as integers.
+        assert_eq!(
+            edge.weight().attributes["flag"],
+            AttrValue::Int(flag.into())
+        );
+    }
+}
+
+#[test]
+fn networkx_document() {
+    let gml = r##"
+# written by hand
+Creator "networkx"
+Version 1.0
+graph [
+  directed 1
+  label "G"
+  node [
+    id 10
+    label "first"
+    graphics [ x 1.5 y -2 fill "#ff0000" ]
+  ]
+  node [ id 20 label "second" value 1E3 ]
+  edge [ source 20 target 10 id 7 ]
+]
+"##;
+    let graph: DiGraph<GmlNode, GmlEdge> = from_gml(gml).unwrap();
+    let nodes: Vec<_> = graph.raw_nodes().iter().map(|n| &n.weight).collect();
+    assert_eq!(nodes[0].id, 10);
+    assert_eq!(
+        nodes[0].attributes,
+        Attributes::from([("label".into(), AttrValue::from("first"))])
+    );
+    assert_eq!(nodes[1].attributes["value"], AttrValue::Float(1000.0));
+    let edge = &graph.raw_edges()[0];
+    assert_eq!((edge.source().index(), edge.target().index()), (1, 0));
+    assert_eq!(edge.weight.attributes["id"], AttrValue::Int(7));
+}
+
+#[test]
+fn errors() {
+    let parse = |gml: &str| from_gml::<UnGraph<GmlNode, GmlEdge>>(gml).unwrap_err();
+    assert_eq!(
+        parse("graph [ node [ id 1 ] node [ id 1 ] ]"),
+        GmlError::DuplicateNode(1)
+    );
+    assert_eq!(
+        parse("graph [ node [ id 1 ] edge [ source 1 target 2 ] ]"),
+        GmlError::UnknownNode(2)
+    );
+    assert_eq!(
+        parse("graph [\n node [ label \"a\" ]\n]"),
+        GmlError::Syntax {
+            line: 2,
+            message: "node without an integer id"
+        }
+    );
+    assert!(matches!(
+        parse("graph [ node [ id 1 ]"),
+        GmlError::Syntax { .. }
+    ));
+    assert!(matches!(parse("Creator \"x\""), GmlError::Syntax { .. }));
+}
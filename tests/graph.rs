@@ -2506,3 +2506,126 @@ fn test_try_add_edge() {
 
     assert_eq!(graph.try_add_edge(a, a, ()), Err(GraphError::EdgeIxLimit));
 }
+
+#[test]
+fn degrees() {
+    use petgraph::visit::Degree;
+
+    fn check<G: Degree>(g: G, node: G::NodeId, expected: (usize, usize, usize)) {
+        assert_eq!(
+            (g.out_degree(node), g.in_degree(node), g.degree(node)),
+            expected
+        );
+    }
+
+    // 0 -> 1, 0 -> 1, 1 -> 2, 2 -> 2
+    let mut g = DiGraph::<(), ()>::from_edges([(0, 1), (0, 1), (1, 2), (2, 2)]);
+    assert_eq!(
+        (g.out_degree(n(0)), g.in_degree(n(0)), g.degree(n(0))),
+        (2, 0, 2)
+    );
+    check(&g, n(1), (1, 2, 3));
+    check(&g, n(2), (1, 2, 3));
+    check(Reversed(&g), n(1), (2, 1, 3));
+    assert_eq!(g.degree(n(7)), 0);
+    let total: usize = g.node_indices().map(|v| g.degree(v)).sum();
+    assert_eq!(total, 2 * g.edge_count());
+
+    let mut ug = g.clone().into_edge_type::<Undirected>();
+    check(&ug, n(1), (3, 3, 3));
+    check(&ug, n(2), (3, 3, 3));
+    // Unlike the degree, the neighbors list a self loop once.
+    assert_eq!(ug.neighbors(n(2)).count(), 2);
+
+    g.remove_edge(g.find_edge(n(2), n(2)).unwrap());
+    check(&g, n(2), (0, 1, 1));
+    ug.add_edge(n(0), n(0), ());
+    check(&ug, n(0), (4, 4, 4));
+
+    #[cfg(feature = "stable_graph")]
+    {
+        let mut sg = StableDiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 2), (2, 0)]);
+        sg.remove_node(n(1));
+        check(&sg, n(2), (2, 1, 3));
+        check(&sg, n(0), (0, 1, 1));
+        check(&sg, n(1), (0, 0, 0));
+    }
+
+    #[cfg(feature = "graphmap")]
+    {
+        let gm = DiGraphMap::<u32, ()>::from_edges([(0, 1), (1, 2), (2, 2), (2, 0)]);
+        check(&gm, 2, (2, 2, 4));
+        check(&gm, 0, (1, 1, 2));
+        let ugm = UnGraphMap::<u32, ()>::from_edges([(0, 1), (1, 2), (2, 2)]);
+        check(&ugm, 2, (3, 3, 3));
+        check(&ugm, 1, (2, 2, 2));
+        assert_eq!(ugm.degree(5), 0);
+    }
+}
+
+#[test]
+fn flat_arrays() {
+    use petgraph::graph::FlatArrays;
+
+    let mut g = UnGraph::<char, u8, u16>::default();
+    let a = g.add_node('a');
+    let b = g.add_node('b');
+    let c = g.add_node('c');
+    g.extend_with_edges([(b, a, 1), (c, c, 2), (a, c, 3), (a, c, 4)]);
+
+    let flat = g.to_flat_arrays();
+    assert_eq!((flat.node_count(), flat.edge_count()), (3, 4));
+    assert_eq!(flat.sources, [1u16, 2, 0, 0]);
+    assert_eq!(flat.targets, [0u16, 2, 2, 2]);
+    assert_eq!(flat, g.clone().into_flat_arrays());
+
+    let copy = UnGraph::from_flat_arrays(flat.clone()).unwrap();
+    assert_eq!(copy.raw_nodes().len(), 3);
+    for (x, y) in g.raw_edges().iter().zip(copy.raw_edges()) {
+        assert_eq!(
+            (x.source(), x.target(), x.weight),
+            (y.source(), y.target(), y.weight)
+        );
+    }
+    assert_eq!(copy.to_flat_arrays(), flat);
+
+    let bad = FlatArrays {
+        node_weights: vec!['a'],
+        sources: vec![0u32],
+        targets: vec![1u32],
+        edge_weights: vec![()],
+    };
+    assert_eq!(
+        DiGraph::from_flat_arrays(bad).unwrap_err(),
+        GraphError::NodeOutBounds
+    );
+    let empty = DiGraph::<(), ()>::from_flat_arrays(FlatArrays::default()).unwrap();
+    assert_eq!(empty.node_count(), 0);
+}
+
+#[test]
+fn graph_builder() {
+    use petgraph::graph::GraphBuilder;
+
+    let (g, indices) = DiGraph::<u8, &str>::from_edges_with_keys(vec![
+        (String::from("x"), String::from("y"), "xy"),
+        (String::from("y"), String::from("x"), "yx"),
+        (String::from("y"), String::from("z"), "yz"),
+    ]);
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(indices["x"], n(0));
+    assert_eq!(indices["z"], n(2));
+    let edge = g.find_edge(indices["y"], indices["x"]).unwrap();
+    assert_eq!(g[edge], "yx");
+
+    let mut builder = GraphBuilder::<u32, char, (), Undirected, u16>::default();
+    builder.add_edge(7, 3, ());
+    assert_eq!(builder.add_node(3, 'c'), n(1));
+    assert_eq!(builder.add_node(9, 'n'), n(2));
+    assert_eq!(builder.node(7), n(0));
+    assert_eq!(builder.node_index(&4), None);
+    assert_eq!(builder.graph().edge_count(), 1);
+    let (g, indices) = builder.build();
+    assert_eq!(g.node_weights().collect::<String>(), "\0cn");
+    assert_eq!(indices.len(), 3);
+}
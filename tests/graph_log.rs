@@ -0,0 +1,106 @@
+#![cfg(all(feature = "stable_graph", feature = "std"))]
+
+use petgraph::stable_graph::log::{read_graph_log, GraphLogError, LoggedGraph};
+use petgraph::stable_graph::StableGraph;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::{Directed, Undirected};
+
+fn edges<N, E: Clone>(g: &StableGraph<N, E>) -> Vec<(usize, usize, usize, E)> {
+    g.edge_references()
+        .map(|e| {
+            (
+                e.id().index(),
+                e.source().index(),
+                e.target().index(),
+                e.weight().clone(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn round_trip_with_removals() {
+    let mut log = Vec::new();
+    let mut g = LoggedGraph::<String, i32, Directed, u32, _>::new(&mut log).unwrap();
+    let a = g.add_node("a".to_string()).unwrap();
+    let b = g.add_node("line\nbreak \\ and space".to_string()).unwrap();
+    let c = g.add_node(String::new()).unwrap();
+    g.add_edge(a, b, 1).unwrap();
+    let bc = g.add_edge(b, c, -2).unwrap();
+    g.add_edge(c, a, 3).unwrap();
+    assert_eq!(g.remove_edge(bc).unwrap(), Some(-2));
+    assert_eq!(g.remove_edge(bc).unwrap(), None);
+    assert!(g.remove_node(a).unwrap().is_some());
+    // Removed indices are reused, and the replay must reuse them the same way.
+    let d = g.add_node("d".to_string()).unwrap();
+    assert_eq!(d, a);
+    g.add_edge(d, c, 4).unwrap();
+    g.add_edge(b, d, 5).unwrap();
+    let (g, _) = g.into_inner();
+
+    let replayed: StableGraph<String, i32> = read_graph_log(&log[..]).unwrap();
+    assert_eq!(replayed.node_count(), g.node_count());
+    for n in g.node_indices() {
+        assert_eq!(replayed[n], g[n]);
+    }
+    assert_eq!(edges(&replayed), edges(&g));
+}
+
+#[test]
+fn resume_and_truncated_tail() {
+    let mut log = Vec::new();
+    let mut g = LoggedGraph::<u8, u8, Undirected, u32, _>::new(&mut log).unwrap();
+    let a = g.add_node(1).unwrap();
+    let b = g.add_node(2).unwrap();
+    g.add_edge(a, b, 7).unwrap();
+    drop(g);
+
+    let graph: StableGraph<u8, u8, Undirected> = read_graph_log(&log[..]).unwrap();
+    let mut g = LoggedGraph::resume(graph, &mut log);
+    let c = g.add_node(3).unwrap();
+    g.add_edge(b, c, 8).unwrap();
+    drop(g);
+
+    // A partial record, as left by an interrupted write, is ignored.
+    log.extend_from_slice(b"e 2 0 ");
+    let replayed: StableGraph<u8, u8, Undirected> = read_graph_log(&log[..]).unwrap();
+    assert_eq!(replayed.node_count(), 3);
+    assert_eq!(replayed.edge_count(), 2);
+    assert!(replayed.find_edge(c, b).is_some());
+}
+
+#[test]
+fn errors() {
+    let read = |text: &str| read_graph_log::<u8, u8, Directed, u32, _>(text.as_bytes()).err();
+
+    assert!(matches!(read(""), Some(GraphLogError::InvalidHeader)));
+    assert!(matches!(
+        read("petgraph-log 2 directed\n"),
+        Some(GraphLogError::InvalidHeader)
+    ));
+    assert!(matches!(
+        read("petgraph-log 1 undirected\n"),
+        Some(GraphLogError::EdgeTypeMismatch)
+    ));
+    assert!(matches!(
+        read("petgraph-log 1 directed\nn 0 1\nx 1\n"),
+        Some(GraphLogError::InvalidRecord(3))
+    ));
+    assert!(matches!(
+        read("petgraph-log 1 directed\nn 0 300\n"),
+        Some(GraphLogError::InvalidWeight(2))
+    ));
+    assert!(matches!(
+        read("petgraph-log 1 directed\nn 1 0\n"),
+        Some(GraphLogError::Inconsistent(2))
+    ));
+    assert!(matches!(
+        read("petgraph-log 1 directed\nn 0 0\ne 0 0 1 0\n"),
+        Some(GraphLogError::Inconsistent(3))
+    ));
+    assert!(matches!(
+        read("petgraph-log 1 directed\n-n 0\n"),
+        Some(GraphLogError::Inconsistent(2))
+    ));
+    assert!(read("petgraph-log 1 directed\r\nn 0 1\r\n").is_none());
+}
@@ -0,0 +1,139 @@
+use fixedbitset::FixedBitSet;
+use petgraph::algo::{connected_components, has_path_connecting, toposort};
+use petgraph::data::DataMap;
+use petgraph::graph::{node_index as n, DiGraph, EdgeReference, UnGraph};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableGraph;
+use petgraph::visit::{
+    Degree, EdgeCount, EdgeRef, GetAdjacencyMatrix, GraphView, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+    IntoNodeReferences, NodeCount, NodeRef,
+};
+use petgraph::{Incoming, Outgoing};
+
+fn diamond() -> DiGraph<u32, u32> {
+    // 0 → 1 → 3, 0 → 2 → 3, 3 → 4
+    let mut g = DiGraph::new();
+    for i in 0..5 {
+        g.add_node(i * 10);
+    }
+    g.extend_with_edges([(0, 1, 1), (1, 3, 2), (0, 2, 3), (2, 3, 4), (3, 4, 5)]);
+    g
+}
+
+#[test]
+fn filter_nodes() {
+    let g = diamond();
+    let view = (&g).filter_nodes(|a| a != n(1));
+    assert_eq!(view.node_count(), 4);
+    assert_eq!(view.edge_count(), 3);
+    assert_eq!(view.neighbors(n(0)).collect::<Vec<_>>(), [n(2)]);
+    assert_eq!(view.neighbors(n(1)).count(), 0);
+    assert_eq!(
+        view.neighbors_directed(n(3), Incoming).collect::<Vec<_>>(),
+        [n(2)]
+    );
+    assert_eq!(view.edges_directed(n(3), Incoming).count(), 1);
+    assert_eq!(view.node_identifiers().count(), 4);
+    assert_eq!(view.node_references().map(|r| *r.weight()).sum::<u32>(), 90);
+    assert_eq!(view.node_weight(n(1)), None);
+    assert_eq!(view.node_weight(n(2)), Some(&20));
+
+    let matrix = view.adjacency_matrix();
+    assert!(view.is_adjacent(&matrix, n(2), n(3)));
+    assert!(!view.is_adjacent(&matrix, n(0), n(1)));
+
+    // A bit set of the nodes to keep.
+    let mut keep = FixedBitSet::with_capacity(5);
+    keep.insert_range(2..5);
+    let view = (&g).filter_nodes(&keep);
+    assert_eq!(toposort(view, None).unwrap(), [n(2), n(3), n(4)]);
+}
+
+#[test]
+fn filter_edges() {
+    let g = diamond();
+    let view = (&g).filter_edges(|e: EdgeReference<u32>| e.weight() % 2 == 1);
+    assert_eq!(view.node_count(), 5);
+    assert_eq!(view.edge_count(), 3);
+    assert_eq!(view.neighbors(n(1)).count(), 0);
+    assert_eq!(
+        view.neighbors_directed(n(3), Incoming).collect::<Vec<_>>(),
+        Vec::new()
+    );
+    assert_eq!(
+        view.neighbors_directed(n(2), Incoming).collect::<Vec<_>>(),
+        [n(0)]
+    );
+    assert!(!has_path_connecting(view, n(0), n(3), None));
+    // `NodeCompactIndexable` is kept.
+    assert_eq!(connected_components(view), 2);
+
+    let un = UnGraph::<(), u32>::from_edges([(0, 1, 1), (1, 2, 2), (2, 3, 1)]);
+    let view = (&un).filter_edges(|e: EdgeReference<u32>| *e.weight() == 1);
+    assert_eq!(view.neighbors(n(1)).collect::<Vec<_>>(), [n(0)]);
+    assert_eq!(connected_components(view), 2);
+}
+
+#[test]
+fn map_weights() {
+    let g = diamond();
+    let view = (&g).map_node_weights(|a, w| (a.index() as u32) + w);
+    let weights: Vec<_> = view.node_references().map(|r| *r.weight()).collect();
+    assert_eq!(weights, [0, 11, 22, 33, 44]);
+    assert_eq!(view.out_degree(n(0)), 2);
+    assert_eq!(view.node_count(), 5);
+
+    let view = (&g).map_edge_weights(|e| e.weight() * 100 + e.source().index() as u32);
+    let weights: Vec<_> = view.edge_references().map(|e| *e.weight()).collect();
+    assert_eq!(weights, [100, 201, 300, 402, 503]);
+    let e = view.edges(n(3)).next().unwrap();
+    assert_eq!((e.source(), e.target(), *e.weight()), (n(3), n(4), 503));
+    assert_eq!(*e.as_unmapped().weight(), 5);
+    assert_eq!(view.edges_directed(n(3), Incoming).count(), 2);
+    assert_eq!(view.edge_count(), 5);
+}
+
+#[test]
+fn chains() {
+    let g = diamond();
+    // Reversed, filtered, and mapped, in any order.
+    let view = (&g)
+        .reversed()
+        .filter_nodes(|a| a != n(2))
+        .map_edge_weights(|e| -(*e.weight() as i64));
+    let edges: Vec<_> = view
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+        .collect();
+    assert_eq!(edges, [(1, 0, -1), (3, 1, -2), (4, 3, -5)]);
+    assert_eq!(
+        view.edges(n(3)).map(|e| e.target()).collect::<Vec<_>>(),
+        [n(1)]
+    );
+    assert_eq!(
+        view.edges_directed(n(3), Outgoing)
+            .map(|e| *e.weight())
+            .collect::<Vec<_>>(),
+        [-2]
+    );
+    assert_eq!(toposort(view, None).unwrap(), [n(4), n(3), n(1), n(0)]);
+
+    let view = (&g)
+        .map_edge_weights(|e| *e.weight() > 2)
+        .filter_edges(|e: petgraph::visit::MappedEdgeReference<_, bool>| *e.weight())
+        .reversed();
+    assert_eq!(view.neighbors(n(3)).collect::<Vec<_>>(), [n(2)]);
+    assert_eq!(view.neighbors_directed(n(3), Outgoing).count(), 1);
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn chains_with_holes() {
+    // Stable graphs, with holes in their indices.
+    let mut sg = StableGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+    sg.remove_node(n(0));
+    let view = (&sg).filter_nodes(|a| a != n(3)).reversed();
+    assert_eq!(view.node_count(), 2);
+    assert_eq!(view.neighbors(n(2)).collect::<Vec<_>>(), [n(1)]);
+}
@@ -0,0 +1,193 @@
+#![cfg(feature = "graphml")]
+
+use petgraph::graph::{DiGraph, UnGraph};
+use petgraph::graphml::{
+    from_graphml, AttrValue, Attributes, GraphMl, GraphMlEdge, GraphMlError, GraphMlNode,
+};
+use petgraph::stable_graph::StableDiGraph;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+
+#[test]
+fn round_trip() {
+    let mut graph = DiGraph::<(&str, i64), (f64, bool)>::new();
+    let a = graph.add_node(("<a & b>", 1));
+    let b = graph.add_node(("\"quoted\"", -7));
+    let c = graph.add_node(("", 0));
+    graph.add_edge(a, b, (0.5, true));
+    graph.add_edge(b, c, (-1e300, false));
+    graph.add_edge(c, c, (3.0, true));
+
+    let node_attributes = |(_, &(label, size)): (_, &(&str, i64))| {
+        Attributes::from([
+            ("label".into(), AttrValue::from(label)),
+            ("size".into(), AttrValue::from(size)),
+        ])
+    };
+    let edge_attributes = |edge: petgraph::graph::EdgeReference<(f64, bool)>| {
+        let (weight, flag) = *edge.weight();
+        Attributes::from([
+            ("weight".into(), AttrValue::from(weight)),
+            ("flag".into(), AttrValue::from(flag)),
+        ])
+    };
+    let xml = GraphMl::with_attr_getters(&graph, &node_attributes, &edge_attributes).to_string();
+    assert!(xml.contains(r#"attr.name="size" attr.type="long""#));
+    assert!(xml.contains(r#"edgedefault="directed""#));
+
+    let parsed: DiGraph<GraphMlNode, GraphMlEdge> = from_graphml(&xml).unwrap();
+    assert_eq!(parsed.node_count(), 3);
+    for node in graph.node_indices() {
+        let weight = &parsed[node];
+        assert_eq!(weight.id, format!("n{}", node.index()));
+        assert_eq!(weight.attributes, node_attributes((node, &graph[node])));
+    }
+    assert_eq!(parsed.edge_count(), 3);
+    for (edge, original) in parsed.edge_references().zip(graph.edge_references()) {
+        assert_eq!(
+            (edge.source(), edge.target()),
+            (original.source(), original.target())
+        );
+        assert_eq!(edge.weight().attributes, edge_attributes(original));
+    }
+
+    // Exporting the parsed graph with its own attributes gives the same document.
+    let again = GraphMl::with_attr_getters(
+        &parsed,
+        &|(_, node): (_, &GraphMlNode)| node.attributes.clone(),
+        &|edge: petgraph::graph::EdgeReference<GraphMlEdge>| edge.weight().attributes.clone(),
+    );
+    assert_eq!(again.to_string(), xml);
+}
+
+#[test]
+fn mixed_types_are_strings() {
+    let graph = UnGraph::<i32, ()>::from_edges([(0, 1)]);
+    let xml = GraphMl::with_attr_getters(
+        &graph,
+        &|(index, _): (petgraph::graph::NodeIndex, _)| {
+            let value = if index.index() == 0 {
+                AttrValue::from(1)
+            } else {
+                AttrValue::from("one")
+            };
+            Attributes::from([("x".into(), value)])
+        },
+        &|_| Attributes::new(),
+    )
+    .to_string();
+    assert!(xml.contains(r#"attr.name="x" attr.type="string""#));
+    let parsed: UnGraph<GraphMlNode, GraphMlEdge> = from_graphml(&xml).unwrap();
+    assert_eq!(
+        parsed.raw_nodes()[0].weight.attributes["x"],
+        AttrValue::from("1")
+    );
+    assert_eq!(
+        parsed.raw_nodes()[1].weight.attributes["x"],
+        AttrValue::from("one")
+    );
+}
+
+#[test]
+fn reading() {
+    // Defaults, keys for all domains, ids out of order, edges before their
+    // nodes, nested graphs, yEd graphics and comments.
+    let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<!DOCTYPE graphml>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:y="http://www.yworks.com/xml/graphml">
+  <key id="w" for="edge" attr.name="weight" attr.type="float"><default>1</default></key>
+  <key id="v" for="all" attr.name="visible" attr.type="boolean"/>
+  <key id="g" for="node" yfiles.type="nodegraphics"/>
+  <graph id="G" edgedefault="directed">
+    <!-- An edge before its nodes. -->
+    <edge source="z" target="y"><data key="w">2.5</data></edge>
+    <node id="z">
+      <data key="g"><y:ShapeNode><y:Geometry x="1" y="2"/></y:ShapeNode></data>
+      <data key="v">true</data>
+    </node>
+    <node id="y">
+      <graph id="inner" edgedefault="directed">
+        <node id="x"/>
+        <edge id="inner-edge" source="x" target="y"/>
+      </graph>
+    </node>
+  </graph>
+</graphml>"#;
+    let graph: StableDiGraph<GraphMlNode, GraphMlEdge> = from_graphml(xml).unwrap();
+    let ids: Vec<_> = graph.node_weights().map(|node| node.id.as_str()).collect();
+    assert_eq!(ids, ["z", "x", "y"]);
+    let z = &graph.node_weights().next().unwrap().attributes;
+    assert_eq!(z["visible"], AttrValue::Boolean(true));
+    assert_eq!(z["g"], AttrValue::from(""));
+    let edges: Vec<_> = (&graph)
+        .edge_references()
+        .map(|edge| {
+            (
+                graph[edge.source()].id.as_str(),
+                graph[edge.target()].id.as_str(),
+                edge.weight().id.as_deref(),
+                edge.weight().attributes["weight"].clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        edges,
+        [
+            ("z", "y", None, AttrValue::Float(2.5)),
+            ("x", "y", Some("inner-edge"), AttrValue::Float(1.0)),
+        ]
+    );
+}
+
+#[test]
+fn errors() {
+    let parse = |xml: &str| from_graphml::<DiGraph<GraphMlNode, GraphMlEdge>>(xml).map(|_| ());
+    let document = |body: &str| format!("<graphml><graph>{body}</graph></graphml>");
+
+    assert!(matches!(
+        parse("<graphml><graph></graphml>"),
+        Err(GraphMlError::Syntax { line: 1, .. })
+    ));
+    assert!(matches!(
+        parse("<graphml>\n<graph>\n<node id='a'>\n</graph></graphml>"),
+        Err(GraphMlError::Syntax { line: 4, .. })
+    ));
+    assert!(matches!(parse("<gexf/>"), Err(GraphMlError::Syntax { .. })));
+    assert!(matches!(parse(""), Err(GraphMlError::Syntax { .. })));
+    assert!(matches!(
+        parse(&document("<node/>")),
+        Err(GraphMlError::Syntax { .. })
+    ));
+    assert_eq!(
+        parse(&document("<node id='a'/><node id='a'/>")),
+        Err(GraphMlError::DuplicateNode("a".into()))
+    );
+    assert_eq!(
+        parse(&document("<node id='a'/><edge source='a' target='b'/>")),
+        Err(GraphMlError::UnknownNode("b".into()))
+    );
+    assert_eq!(
+        parse(&document("<node id='a'><data key='k'>1</data></node>")),
+        Err(GraphMlError::UnknownKey("k".into()))
+    );
+    assert_eq!(
+        parse(&format!(
+            "<graphml><key id='k' attr.type='int'/><graph>{}</graph></graphml>",
+            "<node id='a'><data key='k'>1.5</data></node>"
+        )),
+        Err(GraphMlError::InvalidValue {
+            key: "k".into(),
+            value: "1.5".into()
+        })
+    );
+    // Entities and CDATA are decoded.
+    let graph: DiGraph<GraphMlNode, GraphMlEdge> = from_graphml(
+        "<graphml><key id='k'/><graph><node id='&lt;&#65;&#x42;&gt;'>\
+         <data key='k'><![CDATA[<&>]]> &amp;</data></node></graph></graphml>",
+    )
+    .unwrap();
+    assert_eq!(graph.raw_nodes()[0].weight.id, "<AB>");
+    assert_eq!(
+        graph.raw_nodes()[0].weight.attributes["k"],
+        AttrValue::from("<&> &")
+    );
+}
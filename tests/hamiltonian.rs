@@ -0,0 +1,93 @@
+use petgraph::algo::{hamiltonian_cycle, hamiltonian_path};
+use petgraph::graph::NodeIndex;
+use petgraph::{Graph, Undirected};
+
+fn is_cycle<N, E>(graph: &Graph<N, E, Undirected>, cycle: &[NodeIndex]) -> bool {
+    let mut sorted = cycle.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    sorted.len() == graph.node_count()
+        && (0..cycle.len()).all(|i| {
+            graph
+                .find_edge(cycle[i], cycle[(i + 1) % cycle.len()])
+                .is_some()
+        })
+}
+
+#[test]
+fn hamiltonian_petersen() {
+    // The Petersen graph has a Hamiltonian path but no Hamiltonian cycle.
+    let graph: Graph<(), (), Undirected> = Graph::from_edges([
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 0),
+        (0, 5),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 7),
+        (7, 9),
+        (9, 6),
+        (6, 8),
+        (8, 5),
+    ]);
+    assert_eq!(hamiltonian_cycle(&graph), None);
+    let path = hamiltonian_path(&graph).unwrap();
+    assert_eq!(path.len(), 10);
+    assert!(path
+        .windows(2)
+        .all(|w| graph.find_edge(w[0], w[1]).is_some()));
+}
+
+#[test]
+fn hamiltonian_cycle_cube() {
+    let graph: Graph<(), (), Undirected> = Graph::from_edges([
+        (0, 1),
+        (1, 3),
+        (3, 2),
+        (2, 0),
+        (4, 5),
+        (5, 7),
+        (7, 6),
+        (6, 4),
+        (0, 4),
+        (1, 5),
+        (2, 6),
+        (3, 7),
+    ]);
+    let cycle = hamiltonian_cycle(&graph).unwrap();
+    assert!(is_cycle(&graph, &cycle));
+}
+
+#[test]
+fn hamiltonian_small_graphs() {
+    let mut graph: Graph<(), (), Undirected> = Graph::new_undirected();
+    assert_eq!(hamiltonian_path(&graph), None);
+    assert_eq!(hamiltonian_cycle(&graph), None);
+    let a = graph.add_node(());
+    assert_eq!(hamiltonian_path(&graph), Some(vec![a]));
+    assert_eq!(hamiltonian_cycle(&graph), None);
+    let b = graph.add_node(());
+    graph.add_edge(a, b, ());
+    assert_eq!(hamiltonian_path(&graph).map(|p| p.len()), Some(2));
+    assert_eq!(hamiltonian_cycle(&graph), None);
+
+    let directed = Graph::<(), ()>::from_edges([(0, 1), (1, 0)]);
+    assert_eq!(hamiltonian_cycle(&directed).map(|c| c.len()), Some(2));
+}
+
+#[test]
+fn hamiltonian_directed() {
+    let mut graph = Graph::<(), ()>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges([(a, b), (b, c), (a, c)]);
+    assert_eq!(hamiltonian_path(&graph), Some(vec![a, b, c]));
+    assert_eq!(hamiltonian_cycle(&graph), None);
+    graph.add_edge(c, a, ());
+    assert_eq!(hamiltonian_cycle(&graph), Some(vec![a, b, c]));
+}
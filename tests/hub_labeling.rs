@@ -0,0 +1,72 @@
+use petgraph::algo::pruned_landmark_labeling;
+use petgraph::graph::UnGraph;
+#[cfg(feature = "rand")]
+use petgraph::{algo::dijkstra, generators::barabasi_albert_graph};
+#[cfg(feature = "rand")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A preferential attachment graph, where a few nodes have a high degree,
+/// with edge weights between 1 and 9.
+#[cfg(feature = "rand")]
+fn random_graph(rng: &mut StdRng, n: usize, m: usize) -> UnGraph<(), u32> {
+    let mut graph: UnGraph<(), u32> = barabasi_albert_graph(n, m, rng);
+    for weight in graph.edge_weights_mut() {
+        *weight = rng.gen_range(1..10);
+    }
+    graph
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn hub_labels_weighted_exact() {
+    let mut rng = StdRng::seed_from_u64(4);
+    for _ in 0..5 {
+        let graph = random_graph(&mut rng, 80, 2);
+        let labels = pruned_landmark_labeling(&graph, |e| *e.weight());
+        for a in graph.node_indices() {
+            let exact = dijkstra(&graph, a, None, |e| *e.weight());
+            for b in graph.node_indices() {
+                assert_eq!(labels.distance(a, b), exact.get(&b).copied());
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn hub_labels_unweighted_and_statistics() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let graph = random_graph(&mut rng, 200, 3);
+    let labels = pruned_landmark_labeling(&graph, |_| 1u32);
+    for a in graph.node_indices().step_by(13) {
+        let exact = dijkstra(&graph, a, None, |_| 1u32);
+        for b in graph.node_indices() {
+            assert_eq!(labels.distance(a, b), exact.get(&b).copied());
+        }
+    }
+
+    let total: usize = graph.node_indices().map(|n| labels.label_size(n)).sum();
+    assert_eq!(total, labels.total_label_size());
+    assert!(labels.max_label_size() >= 1);
+    assert!(labels.average_label_size() >= 1.0);
+    // Far fewer entries than all pairs distances.
+    assert!(labels.total_label_size() < 200 * 200 / 4);
+    for n in graph.node_indices() {
+        assert!(labels.label(n).any(|(hub, d)| hub == n && d == 0));
+    }
+}
+
+#[test]
+fn hub_labels_disconnected_and_empty() {
+    let graph = UnGraph::<(), f64>::from_edges([(0, 1, 0.5), (2, 3, 1.5)]);
+    let labels = pruned_landmark_labeling(&graph, |e| *e.weight());
+    assert_eq!(labels.distance(0.into(), 1.into()), Some(0.5));
+    assert_eq!(labels.distance(1.into(), 3.into()), None);
+    assert_eq!(labels.distance(1.into(), 7.into()), None);
+    assert_eq!(labels.label_size(7.into()), 0);
+
+    let empty = UnGraph::<(), u32>::new_undirected();
+    let labels = pruned_landmark_labeling(&empty, |_| 1u32);
+    assert_eq!(labels.total_label_size(), 0);
+    assert_eq!(labels.average_label_size(), 0.0);
+}
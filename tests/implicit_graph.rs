@@ -0,0 +1,74 @@
+use petgraph::algo::has_path_connecting;
+use petgraph::visit::{depth_first_search, Bfs, Control, Dfs, DfsEvent, ImplicitGraph, Walker};
+
+/// Water jug puzzle: two jugs of 3 and 5 liters, which can be filled,
+/// emptied, or poured into each other.
+fn jug_moves((a, b): (u8, u8)) -> Vec<(u8, u8)> {
+    let pour_ab = a.min(5 - b);
+    let pour_ba = b.min(3 - a);
+    vec![
+        (3, b),
+        (a, 5),
+        (0, b),
+        (a, 0),
+        (a - pour_ab, b + pour_ab),
+        (a + pour_ba, b - pour_ba),
+    ]
+}
+
+#[test]
+fn implicit_bfs_shortest_moves() {
+    let graph = ImplicitGraph::new(jug_moves);
+
+    // Breadth-first search visits states by increasing number of moves.
+    let mut depth = std::collections::HashMap::new();
+    depth.insert((0, 0), 0);
+    let mut bfs = Bfs::new(&graph, (0, 0));
+    while let Some(state) = bfs.next(&graph) {
+        let d = depth[&state];
+        for next in graph.successors(state) {
+            depth.entry(next).or_insert(d + 1);
+        }
+    }
+    assert_eq!(depth[&(0, 4)], 7);
+    assert_eq!(depth.len(), 16);
+}
+
+#[test]
+fn implicit_dfs_and_paths() {
+    let graph = ImplicitGraph::new(jug_moves);
+    let reached: Vec<_> = Dfs::new(&graph, (0, 0)).iter(&graph).collect();
+    assert_eq!(reached.len(), 16);
+    assert!(has_path_connecting(&graph, (0, 0), (3, 1), None));
+    assert!(!has_path_connecting(&graph, (0, 0), (2, 2), None));
+}
+
+#[test]
+fn implicit_depth_first_search_early_exit() {
+    // An infinite graph: the search has to stop by itself.
+    let graph = ImplicitGraph::new(|n: u64| [2 * n, 2 * n + 1]);
+    let found = depth_first_search(&graph, Some(1), |event| {
+        if let DfsEvent::Discover(n, _) = event {
+            if n > 1000 {
+                return Control::Break(n);
+            }
+        }
+        Control::Continue
+    });
+    assert_eq!(found.break_value(), Some(1024));
+}
+
+#[test]
+fn implicit_component_labels() {
+    // Moves on a 4x4 grid that stay within the same row.
+    let graph =
+        ImplicitGraph::new(|(r, c): (u8, u8)| [(r, c.saturating_sub(1)), (r, (c + 1).min(3))]);
+    let roots = (0..4).flat_map(|r| (0..4).map(move |c| (r, c)));
+    let labels = graph.component_labels(roots);
+    assert_eq!(labels.len(), 16);
+    for r in 0..4 {
+        for c in 0..4 {
+            assert_eq!(labels[&(r, c)], r as usize);
+        }
+    }
+}
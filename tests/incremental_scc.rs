@@ -0,0 +1,95 @@
+use petgraph::algo::{tarjan_scc, IncrementalScc};
+use petgraph::graph::{DiGraph, NodeIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Check the components against those computed from scratch, and their
+/// order against the edges.
+fn check(sccs: &IncrementalScc<usize>, graph: &DiGraph<(), ()>) {
+    let expected = tarjan_scc(graph);
+    assert_eq!(sccs.component_count(), expected.len());
+    assert_eq!(sccs.edge_count(), graph.edge_count());
+    for component in &expected {
+        let a = component[0].index();
+        let mut nodes: Vec<usize> = component.iter().map(|n| n.index()).collect();
+        nodes.sort_unstable();
+        let mut found = sccs.component_nodes(a).to_vec();
+        found.sort_unstable();
+        assert_eq!(found, nodes);
+        for n in component {
+            assert_eq!(sccs.component(n.index()), sccs.component(a));
+        }
+    }
+
+    let mut rank = vec![0; graph.node_count()];
+    for (i, component) in sccs.components().into_iter().enumerate() {
+        for &a in component {
+            rank[a] = i;
+        }
+    }
+    for edge in graph.raw_edges() {
+        assert!(rank[edge.source().index()] <= rank[edge.target().index()]);
+    }
+}
+
+#[test]
+fn insert_edges() {
+    let mut sccs = IncrementalScc::<usize>::new(6);
+    let mut graph = DiGraph::<(), ()>::new();
+    for _ in 0..6 {
+        graph.add_node(());
+    }
+    for (a, b, merges) in [
+        (0, 1, false),
+        (1, 2, false),
+        (3, 4, false),
+        (4, 0, false),
+        (2, 3, true),
+        (2, 3, false),
+        (5, 5, false),
+        (0, 5, false),
+        (5, 1, true),
+    ] {
+        assert_eq!(sccs.insert_edge(a, b), merges, "{a} -> {b}");
+        graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+        check(&sccs, &graph);
+    }
+    assert_eq!(sccs.component_count(), 1);
+
+    let a = sccs.add_node();
+    graph.add_node(());
+    assert_eq!(a, 6);
+    assert!(!sccs.insert_edge(a, 0));
+    graph.add_edge(NodeIndex::new(a), NodeIndex::new(0), ());
+    check(&sccs, &graph);
+}
+
+#[test]
+fn from_graph() {
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0), (1, 2), (3, 2), (2, 4), (4, 3)]);
+    let sccs = IncrementalScc::<usize>::from_graph(&graph);
+    check(&sccs, &graph);
+    assert_eq!(sccs.component_count(), 2);
+}
+
+#[test]
+fn random_edges() {
+    let mut rng = StdRng::seed_from_u64(1372);
+    for n in [1, 4, 15, 60] {
+        for _ in 0..5 {
+            let mut sccs = IncrementalScc::<usize>::new(n);
+            let mut graph = DiGraph::<(), ()>::with_capacity(n, 0);
+            for _ in 0..n {
+                graph.add_node(());
+            }
+            for _ in 0..2 * n {
+                let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+                let before = sccs.component_count();
+                let merged = sccs.insert_edge(a, b);
+                graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+                assert_eq!(merged, sccs.component_count() < before);
+                check(&sccs, &graph);
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use petgraph::algo::connected_components;
+use petgraph::graph::{Graph, UnGraph};
+use petgraph::interned::{InternedGraph, Interner};
+
+#[test]
+fn interner_stores_values_once() {
+    let mut interner = Interner::new();
+    let a = interner.intern("a".to_string());
+    let b = interner.intern("b".to_string());
+    assert_eq!(interner.intern("a".to_string()), a);
+    assert_ne!(a, b);
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.get("b"), Some(b));
+    assert_eq!(interner.get("c"), None);
+    assert_eq!(interner[a], "a");
+    let values: Vec<_> = interner
+        .iter()
+        .map(|(s, v)| (s.index(), v.as_str()))
+        .collect();
+    assert_eq!(values, vec![(0, "a"), (1, "b")]);
+}
+
+#[test]
+fn interned_graph_round_trip() {
+    let mut graph = Graph::<String, String>::new();
+    let labels = ["red", "green", "red", "blue", "red"];
+    let nodes: Vec<_> = labels
+        .iter()
+        .map(|l| graph.add_node(l.to_string()))
+        .collect();
+    for w in nodes.windows(2) {
+        graph.add_edge(w[0], w[1], "next".to_string());
+    }
+    graph.add_edge(nodes[4], nodes[0], "back".to_string());
+
+    let interned = InternedGraph::from_graph(graph.clone());
+    assert_eq!(interned.node_count(), 5);
+    assert_eq!(interned.edge_count(), 5);
+    assert_eq!(interned.node_interner().len(), 3);
+    assert_eq!(interned.edge_interner().len(), 2);
+    for &n in &nodes {
+        assert_eq!(interned[n], graph[n]);
+    }
+    let red: Vec<_> = interned.nodes_with_weight("red").collect();
+    assert_eq!(red, vec![nodes[0], nodes[2], nodes[4]]);
+    assert_eq!(interned.nodes_with_weight("black").count(), 0);
+
+    let back = interned.into_graph();
+    for e in graph.edge_indices() {
+        assert_eq!(back.edge_endpoints(e), graph.edge_endpoints(e));
+        assert_eq!(back[e], graph[e]);
+    }
+}
+
+#[test]
+fn interned_graph_removal() {
+    let mut graph = InternedGraph::<&str, u8, _>::new_undirected();
+    let a = graph.add_node("x");
+    let b = graph.add_node("y");
+    let c = graph.add_node("x");
+    let ab = graph.add_edge(a, b, 1);
+    graph.add_edge(b, c, 1);
+    assert_eq!(connected_components(graph.graph()), 1);
+
+    let symbol = graph.remove_edge(ab).unwrap();
+    assert_eq!(graph.edge_interner()[symbol], 1);
+    assert_eq!(connected_components(graph.graph()), 2);
+    // The last node takes the index of the removed one.
+    graph.remove_node(a);
+    assert_eq!(graph.node_weight(a), Some(&"x"));
+    assert_eq!(graph.node_weight(c), None);
+    let _: &UnGraph<_, _> = graph.graph();
+}
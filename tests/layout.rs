@@ -0,0 +1,237 @@
+#![cfg(feature = "std")]
+
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+use petgraph::layout::{
+    force_atlas2, fruchterman_reingold, layered_layout, Cooling, CrossingHeuristic,
+    ForceAtlas2Config, FruchtermanReingoldConfig, LayeredConfig, LayeredLayout, Point,
+};
+use petgraph::visit::EdgeRef;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn grid(side: usize) -> UnGraph<(), ()> {
+    let mut graph = UnGraph::new_undirected();
+    for _ in 0..side * side {
+        graph.add_node(());
+    }
+    for r in 0..side {
+        for c in 0..side {
+            let v = NodeIndex::new(r * side + c);
+            if c + 1 < side {
+                graph.add_edge(v, NodeIndex::new(r * side + c + 1), ());
+            }
+            if r + 1 < side {
+                graph.add_edge(v, NodeIndex::new((r + 1) * side + c), ());
+            }
+        }
+    }
+    graph
+}
+
+/// Return the mean length of the edges divided by the mean distance between
+/// all nodes: small for a good layout.
+fn quality(graph: &UnGraph<(), ()>, positions: &hashbrown::HashMap<NodeIndex, Point>) -> f64 {
+    assert_eq!(positions.len(), graph.node_count());
+    assert!(positions
+        .values()
+        .all(|p| p.x.is_finite() && p.y.is_finite()));
+    let edges: f64 = graph
+        .edge_indices()
+        .map(|e| {
+            let (a, b) = graph.edge_endpoints(e).unwrap();
+            positions[&a].distance(positions[&b])
+        })
+        .sum::<f64>()
+        / graph.edge_count() as f64;
+    let n = graph.node_count();
+    let mut all = 0.0;
+    for a in graph.node_indices() {
+        for b in graph.node_indices() {
+            all += positions[&a].distance(positions[&b]);
+        }
+    }
+    edges / (all / (n * (n - 1)) as f64)
+}
+
+#[test]
+fn fruchterman_reingold_layouts() {
+    let graph = grid(8);
+    let exact = fruchterman_reingold(&graph, &FruchtermanReingoldConfig::default());
+    // In a drawing of the grid as a square, the ratio is about 0.2.
+    assert!(quality(&graph, &exact) < 0.3);
+    // The layout is deterministic.
+    assert_eq!(
+        exact,
+        fruchterman_reingold(&graph, &FruchtermanReingoldConfig::default())
+    );
+
+    let config = FruchtermanReingoldConfig {
+        iterations: 200,
+        cooling: Cooling::Exponential(0.98),
+        theta: Some(1.0),
+        ..FruchtermanReingoldConfig::default()
+    };
+    let approximate = fruchterman_reingold(&graph, &config);
+    assert!(quality(&graph, &approximate) < 0.3);
+
+    let empty = UnGraph::<(), ()>::new_undirected();
+    assert!(fruchterman_reingold(&empty, &config).is_empty());
+    let mut single = UnGraph::<(), ()>::new_undirected();
+    single.add_node(());
+    single.add_edge(0.into(), 0.into(), ());
+    let positions = fruchterman_reingold(&single, &config);
+    assert!(positions[&NodeIndex::new(0)].x.is_finite());
+}
+
+#[test]
+fn force_atlas2_layouts() {
+    let graph = grid(8);
+    let exact = force_atlas2(&graph, &ForceAtlas2Config::default(), |_| 1.0);
+    assert!(quality(&graph, &exact) < 0.3);
+
+    let config = ForceAtlas2Config {
+        lin_log: true,
+        theta: Some(1.2),
+        ..ForceAtlas2Config::default()
+    };
+    let lin_log = force_atlas2(&graph, &config, |_| 1.0);
+    assert!(quality(&graph, &lin_log) < 0.3);
+
+    // Strong gravity makes the layout more compact.
+    let config = ForceAtlas2Config {
+        strong_gravity: true,
+        theta: Some(1.2),
+        ..ForceAtlas2Config::default()
+    };
+    let compact = force_atlas2(&graph, &config, |_| 1.0);
+    let radius = |positions: &hashbrown::HashMap<NodeIndex, Point>| {
+        positions
+            .values()
+            .map(|p| p.distance(Point::default()))
+            .fold(0.0, f64::max)
+    };
+    assert!(radius(&compact) < radius(&exact));
+}
+
+#[test]
+fn force_atlas2_separates_clusters() {
+    // Two cliques joined by a single edge.
+    let mut graph = UnGraph::<(), f64>::new_undirected();
+    let nodes: Vec<_> = (0..12).map(|_| graph.add_node(())).collect();
+    for cluster in [&nodes[..6], &nodes[6..]] {
+        for (i, &a) in cluster.iter().enumerate() {
+            for &b in &cluster[i + 1..] {
+                graph.add_edge(a, b, 1.0);
+            }
+        }
+    }
+    graph.add_edge(nodes[0], nodes[6], 0.1);
+
+    let positions = force_atlas2(&graph, &ForceAtlas2Config::default(), |e| *e.weight());
+    let center = |cluster: &[NodeIndex]| {
+        let (x, y) = cluster.iter().fold((0.0, 0.0), |(x, y), v| {
+            (x + positions[v].x, y + positions[v].y)
+        });
+        Point::new(x / 6.0, y / 6.0)
+    };
+    let (left, right) = (center(&nodes[..6]), center(&nodes[6..]));
+    let spread = nodes[..6]
+        .iter()
+        .map(|v| positions[v].distance(left))
+        .fold(0.0, f64::max);
+    assert!(left.distance(right) > 1.5 * spread);
+}
+
+/// Count the crossings between the segments of the edges that join
+/// consecutive layers.
+fn count_crossings(layout: &LayeredLayout<NodeIndex>) -> usize {
+    let segments: Vec<(Point, Point)> = layout
+        .edges()
+        .iter()
+        .flat_map(|edge| edge.points.windows(2).map(|w| (w[0], w[1])))
+        .filter(|(a, b)| a.y != b.y)
+        .map(|(a, b)| if a.y < b.y { (a, b) } else { (b, a) })
+        .collect();
+    let mut crossings = 0;
+    for (i, &(a1, b1)) in segments.iter().enumerate() {
+        for &(a2, b2) in &segments[i + 1..] {
+            if a1.y == a2.y && (a1.x - a2.x) * (b1.x - b2.x) < 0.0 {
+                crossings += 1;
+            }
+        }
+    }
+    crossings
+}
+
+#[test]
+fn layered_layouts() {
+    let mut rng = StdRng::seed_from_u64(26);
+    for heuristic in [CrossingHeuristic::Barycenter, CrossingHeuristic::Median] {
+        let config = LayeredConfig {
+            crossing_heuristic: heuristic,
+            node_spacing: 10.0,
+            ..LayeredConfig::default()
+        };
+        for _ in 0..100 {
+            let n = rng.gen_range(1..15);
+            let mut graph = DiGraph::<(), ()>::new();
+            for _ in 0..n {
+                graph.add_node(());
+            }
+            let cyclic = rng.gen_bool(0.5);
+            for _ in 0..rng.gen_range(0..2 * n) {
+                let a = rng.gen_range(0..n);
+                let b = rng.gen_range(0..n);
+                if cyclic || a < b {
+                    graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+                }
+            }
+            let layout = layered_layout(&graph, &config);
+
+            assert_eq!(layout.positions().len(), n);
+            assert_eq!(layout.layers().iter().map(Vec::len).sum::<usize>(), n);
+            for (l, layer) in layout.layers().iter().enumerate() {
+                for pair in layer.windows(2) {
+                    let (a, b) = (layout.position(pair[0]), layout.position(pair[1]));
+                    assert!(b.unwrap().x - a.unwrap().x >= 10.0 - 1e-9);
+                }
+                for &v in layer {
+                    assert_eq!(layout.layer(v), Some(l));
+                    assert_eq!(layout.position(v).unwrap().y, l as f64 * 50.0);
+                }
+            }
+            assert_eq!(layout.edges().len(), graph.edge_count());
+            for (edge, e) in layout.edges().iter().zip(graph.edge_references()) {
+                assert_eq!((edge.source, edge.target), (e.source(), e.target()));
+                assert_eq!(edge.points[0], layout.position(e.source()).unwrap());
+                assert_eq!(
+                    *edge.points.last().unwrap(),
+                    layout.position(e.target()).unwrap()
+                );
+                if e.source() == e.target() {
+                    assert_eq!(edge.points.len(), 2);
+                    continue;
+                }
+                assert!(!edge.reversed || cyclic);
+                // The edge goes down, or up if it was reversed, one layer at
+                // a time.
+                let step = if edge.reversed { -50.0 } else { 50.0 };
+                for w in edge.points.windows(2) {
+                    assert_eq!(w[1].y - w[0].y, step);
+                }
+            }
+            assert_eq!(layout.crossings(), count_crossings(&layout));
+        }
+    }
+
+    // A complete binary tree is drawn without crossings.
+    let tree = DiGraph::<(), ()>::from_edges((1..31u32).map(|i| ((i - 1) / 2, i)));
+    let layout = layered_layout(&tree, &LayeredConfig::default());
+    assert_eq!(layout.layers().len(), 5);
+    assert_eq!(layout.crossings(), 0);
+    // Parents are centered above their children.
+    for v in 0..15 {
+        let x = |i: u32| layout.position(NodeIndex::new(i as usize)).unwrap().x;
+        assert!((x(v) - (x(2 * v + 1) + x(2 * v + 2)) / 2.0).abs() < 1e-9);
+    }
+}
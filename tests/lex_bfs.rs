@@ -0,0 +1,71 @@
+use petgraph::graph::{NodeIndex, UnGraph};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableUnGraph;
+use petgraph::visit::{LexBfs, Walker};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Check that `order` is a LexBFS order: each node has a lexicographically
+/// largest label among the unvisited nodes.
+fn assert_lex_bfs_order(graph: &UnGraph<(), ()>, order: &[NodeIndex]) {
+    let n = graph.node_count();
+    assert_eq!(order.len(), n);
+    let mut labels = vec![Vec::new(); n];
+    let mut visited = vec![false; n];
+    for (i, &v) in order.iter().enumerate() {
+        assert!(!visited[v.index()]);
+        let largest = (0..n).filter(|&w| !visited[w]).map(|w| &labels[w]).max();
+        assert_eq!(Some(&labels[v.index()]), largest);
+        visited[v.index()] = true;
+        let mut neighbors: Vec<_> = graph.neighbors(v).map(|w| w.index()).collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        for w in neighbors {
+            if !visited[w] {
+                labels[w].push(n - i);
+            }
+        }
+    }
+}
+
+#[test]
+fn lex_bfs_random_graphs() {
+    let mut rng = StdRng::seed_from_u64(31);
+    for _ in 0..100 {
+        let n = rng.gen_range(0..25);
+        let mut graph = UnGraph::<(), ()>::new_undirected();
+        for _ in 0..n {
+            graph.add_node(());
+        }
+        let p = rng.gen_range(0.0..0.4);
+        for a in 0..n {
+            for b in a..n {
+                if rng.gen_bool(p) {
+                    graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+                }
+            }
+        }
+        let order: Vec<_> = LexBfs::new(&graph).iter(&graph).collect();
+        assert_lex_bfs_order(&graph, &order);
+        if n > 0 {
+            let start = NodeIndex::new(rng.gen_range(0..n));
+            let order: Vec<_> = LexBfs::with_start(&graph, start).iter(&graph).collect();
+            assert_eq!(order[0], start);
+            assert_lex_bfs_order(&graph, &order);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn lex_bfs_skips_removed_nodes() {
+    let mut graph = StableUnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[4], ());
+    graph.add_edge(nodes[4], nodes[2], ());
+    graph.remove_node(nodes[1]);
+    graph.remove_node(nodes[3]);
+    let order: Vec<_> = LexBfs::with_start(&graph, nodes[4]).iter(&graph).collect();
+    assert_eq!(order.len(), 3);
+    assert_eq!(order[0], nodes[4]);
+}
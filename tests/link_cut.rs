@@ -0,0 +1,107 @@
+use petgraph::link_cut::LinkCutTree;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The path between `a` and `b` in the forest of `edges`, from scratch.
+fn path(n: usize, edges: &[(usize, usize)], a: usize, b: usize) -> Option<Vec<usize>> {
+    let mut previous = vec![usize::MAX; n];
+    previous[a] = a;
+    let mut stack = vec![a];
+    while let Some(x) = stack.pop() {
+        for &(u, v) in edges {
+            for (y, z) in [(u, v), (v, u)] {
+                if y == x && previous[z] == usize::MAX {
+                    previous[z] = x;
+                    stack.push(z);
+                }
+            }
+        }
+    }
+    if previous[b] == usize::MAX {
+        return None;
+    }
+    let mut path = vec![b];
+    while *path.last().unwrap() != a {
+        path.push(previous[*path.last().unwrap()]);
+    }
+    Some(path)
+}
+
+#[test]
+fn link_and_cut() {
+    let mut forest = LinkCutTree::<usize, i32>::new(4);
+    assert!(forest.link(0, 1));
+    assert!(forest.link(2, 1));
+    assert!(!forest.link(0, 2));
+    assert!(!forest.cut(0, 2));
+    assert!(!forest.cut(0, 3));
+    assert!(forest.connected(0, 2));
+    assert!(forest.cut(1, 0));
+    assert!(!forest.connected(0, 2));
+    assert!(forest.connected(1, 2));
+
+    forest.make_root(1);
+    assert_eq!(forest.find_root(2), 1);
+    assert!(forest.link(3, 2));
+    forest.make_root(1);
+    assert_eq!(forest.find_root(3), 1);
+    assert_eq!(forest.lca(3, 2), Some(2));
+    assert_eq!(forest.lca(3, 1), Some(1));
+    assert_eq!(forest.lca(3, 0), None);
+
+    assert_eq!(forest.set_value(3, 5), 0);
+    assert_eq!(forest.value(3), 5);
+    assert_eq!(forest.path_sum(1, 3), Some(5));
+    assert_eq!(forest.add_node(-1), 4);
+    assert!(forest.link(4, 1));
+    assert_eq!(forest.path_min(4, 3), Some(-1));
+    assert_eq!(forest.path_max(4, 3), Some(5));
+}
+
+#[test]
+fn random_operations() {
+    let mut rng = StdRng::seed_from_u64(1374);
+    let n = 40;
+    let mut values: Vec<i64> = (0..n).map(|_| rng.gen_range(-100..100)).collect();
+    let mut forest = LinkCutTree::<u32, i64>::from_values(values.clone());
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    for _ in 0..5000 {
+        let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+        let path = path(n, &edges, a, b);
+        match rng.gen_range(0..5) {
+            0 => {
+                let linked = forest.link(a as u32, b as u32);
+                assert_eq!(linked, path.is_none());
+                if linked {
+                    edges.push((a, b));
+                }
+            }
+            1 if !edges.is_empty() => {
+                let (u, v) = edges.swap_remove(rng.gen_range(0..edges.len()));
+                assert!(forest.cut(v as u32, u as u32));
+                assert!(!forest.cut(u as u32, v as u32));
+            }
+            2 => {
+                let value = rng.gen_range(-100..100);
+                assert_eq!(forest.set_value(a as u32, value), values[a]);
+                values[a] = value;
+            }
+            _ => {
+                assert_eq!(forest.connected(a as u32, b as u32), path.is_some());
+                let expected = path.map(|p| p.iter().map(|&x| values[x]).collect::<Vec<_>>());
+                assert_eq!(
+                    forest.path_sum(a as u32, b as u32),
+                    expected.as_ref().map(|v| v.iter().sum())
+                );
+                assert_eq!(
+                    forest.path_min(a as u32, b as u32),
+                    expected.as_ref().and_then(|v| v.iter().copied().min())
+                );
+                assert_eq!(
+                    forest.path_max(a as u32, b as u32),
+                    expected.as_ref().and_then(|v| v.iter().copied().max())
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use petgraph::algo::link_prediction::{link_scores, predict_links, LinkScore};
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::visit::NodeIndexable;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const SCORES: &[LinkScore] = &[
+    LinkScore::CommonNeighbors,
+    LinkScore::Jaccard,
+    #[cfg(feature = "std")]
+    LinkScore::AdamicAdar,
+    LinkScore::ResourceAllocation,
+    LinkScore::PreferentialAttachment,
+];
+
+#[test]
+fn predicted_links_match_pair_scores() {
+    let mut rng = StdRng::seed_from_u64(60);
+    for _ in 0..20 {
+        let n = 12;
+        let mut graph = UnGraph::<(), ()>::with_capacity(n, 0);
+        for _ in 0..n {
+            graph.add_node(());
+        }
+        for _ in 0..20 {
+            let a = NodeIndex::new(rng.gen_range(0..n));
+            let b = NodeIndex::new(rng.gen_range(0..n));
+            graph.add_edge(a, b, ());
+        }
+        let mut pairs = Vec::new();
+        for a in graph.node_indices() {
+            for b in graph.node_indices().filter(|&b| b > a) {
+                if !graph.contains_edge(a, b) {
+                    pairs.push((a, b));
+                }
+            }
+        }
+        for &score in SCORES {
+            let values = link_scores(&graph, &pairs, score);
+            for threshold in [-1.0, 0.0, 0.5] {
+                let mut expected: Vec<_> = pairs
+                    .iter()
+                    .zip(&values)
+                    .filter(|&(_, &value)| value > threshold)
+                    .map(|(&(a, b), &value)| (a, b, value))
+                    .collect();
+                expected.sort_by(|x, y| y.2.partial_cmp(&x.2).unwrap().then(x.0.cmp(&y.0)));
+                let links = predict_links(&graph, score, threshold);
+                assert_eq!(links.len(), expected.len(), "{score:?} {threshold}");
+                for (link, expected) in links.iter().zip(&expected) {
+                    assert_eq!((link.0, link.1), (expected.0, expected.1));
+                    assert!((link.2 - expected.2).abs() < 1e-12);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn link_scores_ignore_directions_and_multi_edges() {
+    let graph = petgraph::graph::DiGraph::<(), ()>::from_edges([
+        (0, 1),
+        (1, 0),
+        (2, 1),
+        (2, 2),
+        (2, 3),
+        (0, 3),
+    ]);
+    let pairs = [(graph.from_index(0), graph.from_index(2))];
+    assert_eq!(
+        link_scores(&graph, &pairs, LinkScore::CommonNeighbors),
+        [2.0]
+    );
+    assert_eq!(link_scores(&graph, &pairs, LinkScore::Jaccard), [1.0]);
+    #[cfg(feature = "std")]
+    {
+        let adamic_adar = link_scores(&graph, &pairs, LinkScore::AdamicAdar)[0];
+        assert!((adamic_adar - 2.0 / 2f64.ln()).abs() < 1e-12);
+    }
+    assert_eq!(
+        link_scores(&graph, &pairs, LinkScore::PreferentialAttachment),
+        [4.0]
+    );
+}
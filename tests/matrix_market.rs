@@ -0,0 +1,105 @@
+use petgraph::graph::{DiGraph, EdgeReference, UnGraph};
+use petgraph::io::adjacency_matrix::{from_adjacency_matrix, to_adjacency_matrix};
+use petgraph::io::matrix_market::{from_matrix_market, MatrixMarket, MatrixMarketError};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableDiGraph;
+use petgraph::visit::EdgeRef;
+
+#[test]
+fn round_trip() {
+    let graph = DiGraph::<(), f64>::from_edges([(0, 1, 0.5), (1, 2, 1.25), (2, 0, -3.0)]);
+    let weight = |edge: EdgeReference<f64>| *edge.weight();
+    let mtx = MatrixMarket::with_weights(&graph, &weight).to_string();
+    assert!(mtx.starts_with("%%MatrixMarket matrix coordinate real general\n3 3 3\n"));
+
+    let parsed: DiGraph<(), f64> = from_matrix_market(&mtx).unwrap();
+    assert_eq!(parsed.node_count(), 3);
+    let edges: Vec<_> = parsed
+        .edge_references()
+        .map(|edge| (edge.source().index(), edge.target().index(), *edge.weight()))
+        .collect();
+    assert_eq!(edges, [(0, 1, 0.5), (1, 2, 1.25), (2, 0, -3.0)]);
+}
+
+#[test]
+fn symmetric_matrices() {
+    let mtx = "%%MatrixMarket matrix coordinate pattern symmetric\n3 3 3\n2 1\n3 1\n3 3\n";
+    let undirected: UnGraph<(), u8> = from_matrix_market(mtx).unwrap();
+    assert_eq!(undirected.edge_count(), 3);
+    assert_eq!(MatrixMarket::new(&undirected).to_string(), mtx);
+
+    let directed: DiGraph<(), u8> = from_matrix_market(mtx).unwrap();
+    assert_eq!(directed.edge_count(), 5);
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn symmetric_matrices_into_stable_graphs() {
+    let mtx = "%%MatrixMarket matrix coordinate pattern symmetric\n3 3 3\n2 1\n3 1\n3 3\n";
+    let directed: StableDiGraph<(), u8> = from_matrix_market(mtx).unwrap();
+    assert_eq!(directed.edge_count(), 5);
+}
+
+#[test]
+fn errors() {
+    let unsupported = from_matrix_market::<DiGraph<(), f64>>(
+        "%%MatrixMarket matrix array real general\n2 2\n1\n0\n0\n1\n",
+    );
+    assert_eq!(
+        unsupported.unwrap_err(),
+        MatrixMarketError::Unsupported("array".into())
+    );
+    let out_of_range = from_matrix_market::<DiGraph<(), f64>>(
+        "%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n",
+    );
+    assert!(matches!(
+        out_of_range,
+        Err(MatrixMarketError::Syntax { line: 3, .. })
+    ));
+    let missing = from_matrix_market::<DiGraph<(), f64>>(
+        "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 2 1.0\n",
+    );
+    assert!(missing.is_err());
+    // A corrupt size line is an error, not an allocation of its size.
+    let huge = from_matrix_market::<DiGraph<(), f64>>(
+        "%%MatrixMarket matrix coordinate real general\n99999999999999999 99999999999999999 0",
+    );
+    assert!(matches!(
+        huge,
+        Err(MatrixMarketError::Syntax { line: 2, .. })
+    ));
+    let entries = from_matrix_market::<DiGraph<(), f64>>(
+        "%%MatrixMarket matrix coordinate real general\n2 2 99999999999999999\n1 2 1.0\n",
+    );
+    assert!(entries.is_err());
+}
+
+#[test]
+fn dense_adjacency_matrices() {
+    let graph = UnGraph::<(), u32>::from_edges([(0, 1, 2), (1, 2, 3), (1, 2, 1), (2, 2, 5)]);
+    let matrix = to_adjacency_matrix(&graph, |edge| *edge.weight());
+    assert_eq!(matrix, [[0, 2, 0], [2, 0, 4], [0, 4, 5]]);
+
+    let parsed: UnGraph<(), u32> = from_adjacency_matrix(&matrix);
+    assert_eq!(to_adjacency_matrix(&parsed, |edge| *edge.weight()), matrix);
+    let directed: DiGraph<(), u32> = from_adjacency_matrix(&matrix);
+    assert_eq!(directed.edge_count(), 5);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn ndarray_matrices() {
+    use petgraph::io::adjacency_matrix::{from_ndarray, to_ndarray};
+
+    let graph = DiGraph::<(), f64>::from_edges([(0, 1, 0.5), (1, 0, 1.5)]);
+    let matrix = to_ndarray(&graph, |edge| *edge.weight());
+    assert_eq!(matrix, ndarray::array![[0.0, 0.5], [1.5, 0.0]]);
+    let parsed: DiGraph<(), f64> = from_ndarray(matrix.view());
+    assert_eq!(
+        to_adjacency_matrix(&parsed, |edge| *edge.weight()),
+        matrix
+            .outer_iter()
+            .map(|row| row.to_vec())
+            .collect::<Vec<_>>()
+    );
+}
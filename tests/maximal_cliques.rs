@@ -1,13 +1,18 @@
 extern crate petgraph;
 use core::hash::Hash;
 use hashbrown::HashSet;
-use petgraph::graph::{DiGraph, UnGraph};
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
 use petgraph::{
-    algo::maximal_cliques,
+    algo::{
+        maximal_cliques, maximal_cliques::largest_maximal_clique, maximum_clique,
+        maximum_weight_clique,
+    },
     graph::Graph,
     visit::{GetAdjacencyMatrix, IntoNeighbors, IntoNodeIdentifiers},
     Undirected,
 };
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 /// (reference implementation)
 /// Finds maximal cliques containing all the vertices in r, some of the
@@ -351,3 +356,63 @@ fn test_maximal_cliques_ref_directed() {
         assert!(cliques.contains(&v.iter().cloned().collect()));
     }
 }
+
+fn is_clique(g: &UnGraph<u32, ()>, nodes: &[NodeIndex]) -> bool {
+    nodes
+        .iter()
+        .enumerate()
+        .all(|(i, &a)| nodes[i + 1..].iter().all(|&b| g.find_edge(a, b).is_some()))
+}
+
+#[test]
+fn test_maximum_clique_random_graphs() {
+    let mut rng = StdRng::seed_from_u64(3);
+    for _ in 0..30 {
+        let n = rng.gen_range(0..25);
+        let mut g = UnGraph::<u32, ()>::new_undirected();
+        for _ in 0..n {
+            let weight = rng.gen_range(0..10);
+            g.add_node(weight);
+        }
+        for a in 0..n {
+            for b in a + 1..n {
+                if rng.gen_bool(0.5) {
+                    g.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+                }
+            }
+        }
+        let cliques = maximal_cliques(&g);
+
+        let clique = maximum_clique(&g);
+        assert!(is_clique(&g, &clique));
+        let expected = cliques.iter().map(|c| c.len()).max().unwrap_or(0);
+        assert_eq!(clique.len(), expected);
+        assert_eq!(largest_maximal_clique(&g).len(), expected);
+
+        let (weight, clique) = maximum_weight_clique(&g, |v| g[v]);
+        assert!(is_clique(&g, &clique));
+        assert_eq!(weight, clique.iter().map(|&v| g[v]).sum::<u32>());
+        let expected = cliques
+            .iter()
+            .map(|c| c.iter().map(|&v| g[v]).sum::<u32>())
+            .max()
+            .unwrap_or(0);
+        assert_eq!(weight, expected);
+    }
+}
+
+#[test]
+fn test_maximum_clique_directed_and_loops() {
+    // A symmetric directed graph with a loop.
+    let g = DiGraph::<(), ()>::from_edges([(0, 1), (1, 0), (1, 2), (2, 1), (0, 2), (2, 0), (3, 3)]);
+    let mut clique = maximum_clique(&g);
+    clique.sort();
+    assert_eq!(clique, vec![0.into(), 1.into(), 2.into()]);
+
+    let (weight, clique) = maximum_weight_clique(&g, |v| if v.index() == 3 { 10.0 } else { 1.0 });
+    assert_eq!(weight, 10.0);
+    assert_eq!(clique, vec![3.into()]);
+
+    let empty = UnGraph::<(), ()>::new_undirected();
+    assert!(maximum_clique(&empty).is_empty());
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "rand")]
+
+use petgraph::algo::{karger_min_cut, karger_stein_min_cut, MinCut};
+use petgraph::generators::gnp_random_graph;
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::visit::EdgeRef;
+use rand::{rngs::StdRng, SeedableRng};
+
+/// The size of a minimum cut, by trying every partition.
+fn exact_min_cut(graph: &UnGraph<(), ()>) -> usize {
+    let n = graph.node_count();
+    (1..1usize << (n - 1))
+        .map(|mask| {
+            graph
+                .edge_references()
+                .filter(|e| (mask >> e.source().index() & 1) != (mask >> e.target().index() & 1))
+                .count()
+        })
+        .min()
+        .unwrap()
+}
+
+/// Check that the cut is a partition of the nodes with `cut_size` edges
+/// across.
+fn check_cut(graph: &UnGraph<(), ()>, cut: &MinCut<NodeIndex>) {
+    let (first, second) = &cut.partition;
+    assert!(!first.is_empty() && !second.is_empty());
+    assert_eq!(first.len() + second.len(), graph.node_count());
+    let across = graph
+        .edge_references()
+        .filter(|e| first.contains(&e.source()) != first.contains(&e.target()))
+        .count();
+    assert_eq!(across, cut.cut_size);
+    assert!((0.0..=1.0).contains(&cut.success_probability));
+}
+
+#[test]
+fn random_graphs() {
+    let mut rng = StdRng::seed_from_u64(1328);
+    for n in 2..13 {
+        let graph: UnGraph<(), ()> = gnp_random_graph(n, 0.4, &mut rng);
+        let expected = exact_min_cut(&graph);
+
+        let cut = karger_min_cut(&graph, 3 * n * n, &mut rng).unwrap();
+        check_cut(&graph, &cut);
+        assert_eq!(cut.cut_size, expected);
+
+        let cut = karger_stein_min_cut(&graph, 10, &mut rng).unwrap();
+        check_cut(&graph, &cut);
+        assert_eq!(cut.cut_size, expected);
+        assert!(cut.success_probability > 0.9);
+    }
+}
+
+#[test]
+fn special_cases() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let single = UnGraph::<(), ()>::from_edges([(0, 0)]);
+    assert!(karger_min_cut(&single, 1, &mut rng).is_none());
+    assert!(karger_stein_min_cut(&UnGraph::<(), ()>::default(), 1, &mut rng).is_none());
+
+    // Two disconnected cliques, with loops and parallel edges.
+    let mut graph = UnGraph::<(), ()>::default();
+    let nodes: Vec<_> = (0..20).map(|_| graph.add_node(())).collect();
+    for half in nodes.chunks(10) {
+        for (i, &a) in half.iter().enumerate() {
+            graph.add_edge(a, a, ());
+            for &b in &half[i + 1..] {
+                graph.add_edge(a, b, ());
+                graph.add_edge(b, a, ());
+            }
+        }
+    }
+    for cut in [
+        karger_min_cut(&graph, 1, &mut rng).unwrap(),
+        karger_stein_min_cut(&graph, 1, &mut rng).unwrap(),
+    ] {
+        check_cut(&graph, &cut);
+        assert_eq!(cut.cut_size, 0);
+    }
+
+    // Joining them by two edges, which are counted separately.
+    graph.add_edge(nodes[0], nodes[10], ());
+    graph.add_edge(nodes[0], nodes[10], ());
+    let cut = karger_stein_min_cut(&graph, 20, &mut rng).unwrap();
+    check_cut(&graph, &cut);
+    assert_eq!(cut.cut_size, 2);
+    assert_eq!(cut.partition.0.len(), 10);
+}
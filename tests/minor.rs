@@ -0,0 +1,157 @@
+use petgraph::graph::{DiGraph, EdgeIndex, NodeIndex, UnGraph};
+use petgraph::minor::{is_minor_model, Contract};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableDiGraph;
+use petgraph::visit::EdgeRef;
+
+/// Return the edges of a graph as sorted triples of node weights and the
+/// edge weight.
+fn edges(graph: &DiGraph<char, u32>) -> Vec<(char, char, u32)> {
+    let mut edges: Vec<_> = graph
+        .edge_references()
+        .map(|e| (graph[e.source()], graph[e.target()], *e.weight()))
+        .collect();
+    edges.sort_unstable();
+    edges
+}
+
+/// A graph with parallel paths, cycles and a loop, to merge nodes of.
+fn merge_example() -> DiGraph<char, u32> {
+    let mut graph = DiGraph::<char, u32>::new();
+    let [a, b, c, d, e] = ['a', 'b', 'c', 'd', 'e'].map(|w| graph.add_node(w));
+    graph.extend_with_edges([
+        (a, b, 1),
+        (b, c, 2),
+        (d, c, 4),
+        (c, b, 8),
+        (d, d, 16),
+        (e, d, 32),
+        (c, e, 64),
+        (e, c, 128),
+    ]);
+    graph
+}
+
+#[test]
+fn merge_nodes() {
+    let mut graph = merge_example();
+    let [b, d] = [1, 3].map(NodeIndex::new);
+
+    let merged = graph
+        .merge_nodes(
+            &[d, b, d],
+            |w, other| *w = (*w).max(other),
+            |w, other| *w += other,
+        )
+        .unwrap();
+    assert_eq!(merged, b);
+    assert_eq!(graph.node_count(), 4);
+    assert_eq!(graph[merged], 'd');
+    // e was moved into the place of d.
+    assert_eq!(graph[d], 'e');
+    assert_eq!(
+        edges(&graph),
+        [
+            ('a', 'd', 1),
+            ('c', 'd', 8),
+            ('c', 'e', 64),
+            ('d', 'c', 6),
+            ('e', 'c', 128),
+            ('e', 'd', 32),
+        ]
+    );
+
+    assert_eq!(graph.merge_nodes(&[], |_, _| (), |_, _| ()), None);
+    assert_eq!(
+        graph.contract_edge(EdgeIndex::new(99), |_, _| (), |_, _| ()),
+        None
+    );
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn merge_stable_nodes() {
+    let graph = merge_example();
+    let [b, d, e] = [1, 3, 4].map(NodeIndex::new);
+    let mut stable = StableDiGraph::from(graph.clone());
+
+    let merged = stable
+        .merge_nodes(
+            &[d, b],
+            |w, other| *w = (*w).max(other),
+            |w, other| *w += other,
+        )
+        .unwrap();
+    assert_eq!(merged, b);
+    assert!(!stable.contains_node(d));
+    assert_eq!(stable[e], 'e');
+    assert_eq!(
+        edges(&DiGraph::from(stable)),
+        [
+            ('a', 'd', 1),
+            ('c', 'd', 8),
+            ('c', 'e', 64),
+            ('d', 'c', 6),
+            ('e', 'c', 128),
+            ('e', 'd', 32),
+        ]
+    );
+}
+
+#[test]
+fn contract_to_minor() {
+    // Contracting the spokes of a wheel with 5 spokes leaves the hub with
+    // nothing but merged edges, while contracting its rim gives K2.
+    let mut edges: Vec<(u32, u32)> = (1..=5).map(|i| (0, i)).collect();
+    edges.extend((1..=5).map(|i| (i, i % 5 + 1)));
+    let wheel = UnGraph::<u32, u32>::from_edges(&edges);
+
+    let mut graph = wheel.clone();
+    for _ in 0..4 {
+        let rim = graph
+            .edge_references()
+            .find(|e| e.source().index() != 0 && e.target().index() != 0)
+            .unwrap()
+            .id();
+        graph.contract_edge(rim, |_, _| (), |w, other| *w += other);
+    }
+    assert_eq!(graph.node_count(), 2);
+    assert_eq!(graph.edge_count(), 1);
+    assert_eq!(graph[EdgeIndex::new(0)], 0);
+
+    let triangle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0)]);
+    let sets = |sets: &[&[usize]]| -> Vec<Vec<NodeIndex>> {
+        sets.iter()
+            .map(|set| set.iter().map(|&v| NodeIndex::new(v)).collect())
+            .collect()
+    };
+    assert!(is_minor_model(
+        &wheel,
+        &triangle,
+        &sets(&[&[0], &[1, 2], &[3, 4, 5]])
+    ));
+    // The set 3, 5 is not connected without 4.
+    assert!(!is_minor_model(
+        &wheel,
+        &triangle,
+        &sets(&[&[0], &[1, 2], &[3, 5]])
+    ));
+    // The sets overlap.
+    assert!(!is_minor_model(
+        &wheel,
+        &triangle,
+        &sets(&[&[0], &[1, 2], &[2, 3]])
+    ));
+    // A set is empty.
+    assert!(!is_minor_model(
+        &wheel,
+        &triangle,
+        &sets(&[&[0], &[1, 2], &[]])
+    ));
+    // The sets 1 and 3 are not adjacent.
+    assert!(!is_minor_model(
+        &wheel,
+        &triangle,
+        &sets(&[&[1], &[2], &[3]])
+    ));
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "mmap")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use petgraph::algo::{connected_components, dijkstra};
+use petgraph::graph::{DiGraph, UnGraph};
+use petgraph::mmap::{MmapCsr, MmapCsrError};
+use petgraph::visit::IntoNeighbors;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("petgraph-{}-{name}.csr", std::process::id()))
+}
+
+#[test]
+fn directed_round_trip() {
+    let graph = DiGraph::<(), u32>::from_edges([(0, 1, 7), (2, 0, 1), (0, 2, 2), (3, 1, 4)]);
+    let path = temp_path("directed");
+    let mut bytes = Vec::new();
+    MmapCsr::<u32>::write(&graph, |e| *e.weight(), &mut bytes).unwrap();
+    fs::write(&path, &bytes).unwrap();
+
+    let mapped = unsafe { MmapCsr::<u32>::open(&path) }.unwrap();
+    assert_eq!((mapped.node_count(), mapped.edge_count()), (4, 4));
+    let g = mapped.graph();
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.neighbors(0).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(g.edges_slice(0), [7, 2]);
+    assert_eq!(g.neighbors(1).count(), 0);
+    let expected = dijkstra(&graph, 0.into(), None, |e| *e.weight());
+    let distances = dijkstra(g, 0, None, |e| *e.weight());
+    for (node, distance) in expected {
+        assert_eq!(distances[&(node.index() as u32)], distance);
+    }
+    drop(mapped);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn undirected_unweighted() {
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 1), (3, 4)]);
+    let path = temp_path("undirected");
+    MmapCsr::<(), u8>::write(&graph, |_| (), fs::File::create(&path).unwrap()).unwrap();
+
+    let mapped = unsafe { MmapCsr::<(), u8>::open(&path) }.unwrap();
+    // Both directions of the edges, but the self loop once.
+    assert_eq!(mapped.edge_count(), 5);
+    let g = mapped.graph();
+    assert_eq!(g.neighbors(1).collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(connected_components(g), 3);
+
+    // The types are checked when the file is mapped.
+    let err = unsafe { MmapCsr::<f64, u8>::open(&path) }.unwrap_err();
+    assert!(matches!(err, MmapCsrError::TypeMismatch));
+    let err = unsafe { MmapCsr::<(), u32>::open(&path) }.unwrap_err();
+    assert!(matches!(err, MmapCsrError::TypeMismatch));
+    drop(mapped);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn invalid_files() {
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+    let mut bytes = Vec::new();
+    MmapCsr::<()>::write(&graph, |_| (), &mut bytes).unwrap();
+    let path = temp_path("invalid");
+
+    fs::write(&path, &bytes[..bytes.len() - 8]).unwrap();
+    let err = unsafe { MmapCsr::<()>::open(&path) }.unwrap_err();
+    assert!(matches!(err, MmapCsrError::UnexpectedLength));
+
+    let mut corrupt = bytes.clone();
+    corrupt[0] = b'X';
+    fs::write(&path, &corrupt).unwrap();
+    let err = unsafe { MmapCsr::<()>::open(&path) }.unwrap_err();
+    assert!(matches!(err, MmapCsrError::InvalidMagic));
+
+    // The target of the last edge is not a node.
+    let mut corrupt = bytes.clone();
+    let targets = 40 + 4 * core::mem::size_of::<usize>();
+    corrupt[targets + 4..targets + 8].copy_from_slice(&9u32.to_ne_bytes());
+    fs::write(&path, &corrupt).unwrap();
+    let err = unsafe { MmapCsr::<()>::open(&path) }.unwrap_err();
+    assert!(matches!(err, MmapCsrError::Invalid(_)));
+
+    // Counts whose array lengths overflow, even to the length of the file.
+    for (node_count, entry_count) in [(u64::MAX, 2), (3, u64::MAX), (3, 2 + (1 << 62))] {
+        let mut corrupt = bytes.clone();
+        corrupt[24..32].copy_from_slice(&node_count.to_ne_bytes());
+        corrupt[32..40].copy_from_slice(&entry_count.to_ne_bytes());
+        fs::write(&path, &corrupt).unwrap();
+        let err = unsafe { MmapCsr::<()>::open(&path) }.unwrap_err();
+        assert!(matches!(err, MmapCsrError::UnexpectedLength));
+    }
+    fs::remove_file(&path).unwrap();
+
+    let err = unsafe { MmapCsr::<()>::open(temp_path("missing")) }.unwrap_err();
+    assert!(matches!(err, MmapCsrError::Io(_)));
+}
@@ -0,0 +1,115 @@
+use fixedbitset::FixedBitSet;
+use petgraph::algo::morphology::{dilate, erode, inner_boundary, outer_boundary};
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableUnGraph;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn ones(set: &FixedBitSet) -> Vec<usize> {
+    set.ones().collect()
+}
+
+/// The distance from `set` to every node, by breadth-first search.
+fn distances(graph: &UnGraph<(), ()>, set: &FixedBitSet) -> Vec<usize> {
+    let mut distance = vec![usize::MAX; graph.node_count()];
+    let mut frontier: Vec<usize> = set.ones().collect();
+    let mut d = 0;
+    while !frontier.is_empty() {
+        let mut next = Vec::new();
+        for v in frontier {
+            if distance[v] != usize::MAX {
+                continue;
+            }
+            distance[v] = d;
+            next.extend(graph.neighbors(NodeIndex::new(v)).map(|w| w.index()));
+        }
+        frontier = next;
+        d += 1;
+    }
+    distance
+}
+
+#[test]
+fn dilation_and_erosion_are_dual() {
+    let mut rng = StdRng::seed_from_u64(41);
+    for _ in 0..50 {
+        let n = 30;
+        let mut graph = UnGraph::<(), ()>::with_capacity(n, 0);
+        for _ in 0..n {
+            graph.add_node(());
+        }
+        for _ in 0..40 {
+            let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+        }
+        let set: FixedBitSet = (0..n).filter(|_| rng.gen_bool(0.6)).collect();
+        let mut complement = FixedBitSet::with_capacity(n);
+        complement.insert_range(..);
+        complement.difference_with(&set);
+
+        let to_set = distances(&graph, &set);
+        let to_complement = distances(&graph, &complement);
+        for k in 0..4 {
+            let dilated = dilate(&graph, &set, k);
+            assert_eq!(
+                ones(&dilated),
+                (0..n).filter(|&v| to_set[v] <= k).collect::<Vec<_>>()
+            );
+            let eroded = erode(&graph, &set, k);
+            assert_eq!(
+                ones(&eroded),
+                (0..n).filter(|&v| to_complement[v] > k).collect::<Vec<_>>()
+            );
+        }
+        let mut inner = set.clone();
+        inner.difference_with(&erode(&graph, &set, 1));
+        assert_eq!(ones(&inner_boundary(&graph, &set)), ones(&inner));
+        let mut outer = dilate(&graph, &set, 1);
+        outer.difference_with(&set);
+        assert_eq!(ones(&outer_boundary(&graph, &set)), ones(&outer));
+    }
+}
+
+#[test]
+fn directed_graphs() {
+    // 0 -> 1 -> 2 -> 3, 4 -> 2
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (4, 2)]);
+    let set: FixedBitSet = [1].into_iter().collect();
+    assert_eq!(ones(&dilate(&graph, &set, 5)), [1, 2, 3]);
+    let set: FixedBitSet = [0, 1, 2, 4].into_iter().collect();
+    assert_eq!(ones(&erode(&graph, &set, 1)), [0, 1, 4]);
+    assert_eq!(ones(&erode(&graph, &set, 2)), [0]);
+    assert_eq!(ones(&inner_boundary(&graph, &set)), [2]);
+    assert_eq!(ones(&outer_boundary(&graph, &set)), [3]);
+
+    // Bits beyond the node bound are ignored.
+    let mut big = FixedBitSet::with_capacity(100);
+    big.insert_range(..);
+    assert_eq!(dilate(&graph, &big, 1).len(), 5);
+    assert_eq!(ones(&erode(&graph, &big, 3)), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn stable_graphs() {
+    use petgraph::algo::morphology::closure;
+
+    let mut stable = StableUnGraph::<u32, ()>::default();
+    let nodes: Vec<_> = (0..6).map(|i| stable.add_node(i)).collect();
+    for pair in nodes.windows(2) {
+        stable.add_edge(pair[0], pair[1], ());
+    }
+    stable.remove_node(nodes[3]);
+    let seed: FixedBitSet = [0].into_iter().collect();
+    assert_eq!(ones(&closure(&stable, &seed, |_| true)), [0, 1, 2]);
+    let set: FixedBitSet = [0, 1, 2].into_iter().collect();
+    // The removed node is not outside of the set.
+    assert_eq!(ones(&erode(&stable, &set, 3)), [0, 1, 2]);
+    let seed: FixedBitSet = [5].into_iter().collect();
+    let mut calls = 0;
+    let region = closure(&stable, &seed, |v| {
+        calls += 1;
+        stable[v] % 2 == 0
+    });
+    assert_eq!((ones(&region), calls), (vec![4, 5], 1));
+}
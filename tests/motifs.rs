@@ -0,0 +1,179 @@
+use petgraph::algo::{graphlet_census, graphlet_counts, triad_census, Graphlet, Orbit, Triad};
+use petgraph::graph::{DiGraph, UnGraph};
+
+/// A small pseudo random graph with `n` nodes.
+fn random_edges(n: u32, seed: u32, density: u32) -> Vec<(u32, u32)> {
+    let mut state = seed;
+    let mut edges = Vec::new();
+    for a in 0..n {
+        for b in 0..n {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            if a != b && (state >> 16) % 100 < density {
+                edges.push((a, b));
+            }
+        }
+    }
+    edges
+}
+
+/// The classes of the triad census, as arcs between the nodes 0, 1 and 2.
+fn canonical_triads() -> [Vec<(u32, u32)>; 16] {
+    [
+        vec![],
+        vec![(0, 1)],
+        vec![(0, 1), (1, 0)],
+        vec![(0, 1), (0, 2)],
+        vec![(1, 0), (2, 0)],
+        vec![(0, 1), (1, 2)],
+        vec![(0, 1), (1, 0), (2, 0)],
+        vec![(0, 1), (1, 0), (0, 2)],
+        vec![(0, 1), (1, 2), (0, 2)],
+        vec![(0, 1), (1, 2), (2, 0)],
+        vec![(0, 1), (1, 0), (0, 2), (2, 0)],
+        vec![(0, 1), (0, 2), (1, 2), (2, 1)],
+        vec![(1, 0), (2, 0), (1, 2), (2, 1)],
+        vec![(0, 1), (1, 2), (0, 2), (2, 0)],
+        vec![(0, 1), (1, 0), (0, 2), (2, 0), (1, 2)],
+        vec![(0, 1), (1, 0), (0, 2), (2, 0), (1, 2), (2, 1)],
+    ]
+}
+
+#[test]
+fn triads() {
+    let canonical = canonical_triads();
+    for (triad, arcs) in Triad::ALL.iter().zip(&canonical) {
+        let mut graph = DiGraph::<(), ()>::from_edges(arcs);
+        while graph.node_count() < 3 {
+            graph.add_node(());
+        }
+        let census = triad_census(&graph);
+        assert_eq!(census[*triad as usize], 1, "{}", triad.name());
+        assert_eq!(census.iter().sum::<u64>(), 1);
+    }
+
+    // Compare with the classes of all the triples.
+    let edges = random_edges(12, 7, 20);
+    let graph = DiGraph::<(), ()>::from_edges(&edges);
+    let census = triad_census(&graph);
+    let arc = |a: u32, b: u32| edges.contains(&(a, b));
+    let mut expected = [0u64; 16];
+    for a in 0..12 {
+        for b in a + 1..12 {
+            for c in b + 1..12 {
+                let nodes = [a, b, c];
+                let class = canonical
+                    .iter()
+                    .position(|arcs| {
+                        let perms = [
+                            [0, 1, 2],
+                            [0, 2, 1],
+                            [1, 0, 2],
+                            [1, 2, 0],
+                            [2, 0, 1],
+                            [2, 1, 0],
+                        ];
+                        perms.iter().any(|p| {
+                            (0..3).all(|i| {
+                                (0..3).all(|j| {
+                                    i == j
+                                        || arc(nodes[p[i]], nodes[p[j]])
+                                            == arcs.contains(&(i as u32, j as u32))
+                                })
+                            })
+                        })
+                    })
+                    .unwrap();
+                expected[class] += 1;
+            }
+        }
+    }
+    assert_eq!(census, expected);
+}
+
+#[test]
+fn graphlets() {
+    let edges = random_edges(14, 3, 25);
+    let graph = UnGraph::<(), ()>::from_edges(&edges);
+    let census = graphlet_census(&graph);
+    let adjacent = |a: usize, b: usize| {
+        edges.contains(&(a as u32, b as u32)) || edges.contains(&(b as u32, a as u32))
+    };
+
+    // Classify every connected induced subgraph of up to four nodes by its
+    // degree sequence, and each node by its degree in it.
+    let mut counts = [0u64; 9];
+    let mut orbits = vec![[0u64; 15]; 14];
+    let n = 14;
+    let mut subsets: Vec<Vec<usize>> = Vec::new();
+    for a in 0..n {
+        for b in a + 1..n {
+            subsets.push(vec![a, b]);
+            for c in b + 1..n {
+                subsets.push(vec![a, b, c]);
+                for d in c + 1..n {
+                    subsets.push(vec![a, b, c, d]);
+                }
+            }
+        }
+    }
+    for nodes in subsets {
+        let degrees: Vec<usize> = nodes
+            .iter()
+            .map(|&v| nodes.iter().filter(|&&w| adjacent(v, w)).count())
+            .collect();
+        let mut sorted = degrees.clone();
+        sorted.sort_unstable();
+        let (graphlet, orbit_of_degree): (Graphlet, &[Orbit]) = match sorted[..] {
+            [1, 1] => (Graphlet::Edge, &[Orbit::Edge, Orbit::Edge]),
+            [1, 1, 2] => (Graphlet::Path3, &[Orbit::Path3End, Orbit::Path3Center]),
+            [2, 2, 2] => (Graphlet::Triangle, &[Orbit::Triangle, Orbit::Triangle]),
+            [1, 1, 2, 2] => (Graphlet::Path4, &[Orbit::Path4End, Orbit::Path4Inner]),
+            [1, 1, 1, 3] => (
+                Graphlet::Star,
+                &[Orbit::StarLeaf, Orbit::StarLeaf, Orbit::StarCenter],
+            ),
+            [2, 2, 2, 2] => (Graphlet::Cycle4, &[Orbit::Cycle4, Orbit::Cycle4]),
+            [1, 2, 2, 3] => (
+                Graphlet::TailedTriangle,
+                &[
+                    Orbit::TailedTriangleTail,
+                    Orbit::TailedTriangleSide,
+                    Orbit::TailedTriangleCenter,
+                ],
+            ),
+            [2, 2, 3, 3] => (
+                Graphlet::Diamond,
+                &[
+                    Orbit::DiamondOuter,
+                    Orbit::DiamondOuter,
+                    Orbit::DiamondInner,
+                ],
+            ),
+            [3, 3, 3, 3] => (
+                Graphlet::Clique4,
+                &[Orbit::Clique4, Orbit::Clique4, Orbit::Clique4],
+            ),
+            _ => continue,
+        };
+        counts[graphlet as usize] += 1;
+        for (&v, &d) in nodes.iter().zip(&degrees) {
+            let orbit = orbit_of_degree[d - 1];
+            assert_eq!(orbit.graphlet(), graphlet);
+            orbits[v][orbit as usize] += 1;
+        }
+    }
+    for graphlet in Graphlet::ALL {
+        assert_eq!(
+            census.count(graphlet),
+            counts[graphlet as usize],
+            "{:?}",
+            graphlet
+        );
+    }
+    for (v, expected) in orbits.iter().enumerate() {
+        assert_eq!(census.orbit_counts(v), expected, "node {}", v);
+    }
+    assert!(census.count(Graphlet::Clique4) > 0);
+    assert_eq!(census.counts(), &counts);
+    assert_eq!(graphlet_counts(&graph), counts);
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "graphmap")]
+
+use petgraph::algo::dijkstra;
+use petgraph::multigraphmap::{DiMultiGraphMap, UnMultiGraphMap};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::Direction::Incoming;
+
+#[test]
+fn parallel_edges() {
+    let mut gr = DiMultiGraphMap::<&str, u32>::new();
+    let a = gr.add_edge("A", "B", 1);
+    let b = gr.add_edge("A", "B", 2);
+    let c = gr.add_edge("B", "A", 3);
+    let d = gr.add_edge("B", "B", 4);
+    assert_eq!(gr.node_count(), 2);
+    assert_eq!(gr.edge_count(), 4);
+    assert_eq!(
+        gr.edges_connecting("A", "B")
+            .map(|edge| *edge.weight())
+            .collect::<Vec<_>>(),
+        [1, 2]
+    );
+    assert_eq!(gr.edges_connecting("B", "A").count(), 1);
+    assert_eq!(gr.edge_endpoints(c), Some(("B", "A")));
+    assert_eq!(gr.neighbors("A").collect::<Vec<_>>(), ["B", "B"]);
+    assert_eq!(gr.out_degree("B"), 2);
+    assert_eq!(gr.in_degree("B"), 3);
+    assert_eq!(
+        gr.edges_directed("B", Incoming)
+            .map(|edge| edge.id())
+            .collect::<Vec<_>>(),
+        [a, b, d]
+    );
+
+    gr[b] = 5;
+    assert_eq!(gr.remove_edge(a), Some(1));
+    assert_eq!(gr.remove_edge(a), None);
+    assert_eq!(gr.edge_weight(b), Some(&5));
+    assert!(gr.contains_edge("A", "B"));
+    gr.remove_edge(b);
+    assert!(!gr.contains_edge("A", "B"));
+    assert!(gr.contains_edge("B", "A"));
+
+    // Keys are not reused.
+    let e = gr.add_edge("A", "B", 6);
+    assert!(e > d);
+    assert!(gr.remove_node("B"));
+    assert_eq!(gr.edge_count(), 0);
+    assert!(!gr.contains_edge_key(c));
+    assert_eq!(gr.nodes().collect::<Vec<_>>(), ["A"]);
+}
+
+#[test]
+fn undirected() {
+    let mut gr = UnMultiGraphMap::<u32, f64>::new();
+    gr.add_edge(1, 2, 1.5);
+    gr.add_edge(2, 1, 0.5);
+    gr.add_edge(2, 3, 2.0);
+    gr.add_edge(3, 3, 1.0);
+    assert_eq!(gr.edges_connecting(2, 1).count(), 2);
+    assert_eq!(gr.neighbors(2).collect::<Vec<_>>(), [1, 1, 3]);
+    assert!(gr.edges(2).all(|edge| edge.source() == 2));
+    assert_eq!(gr.out_degree(3), 2);
+
+    let distances = dijkstra(&gr, 1, None, |edge| *edge.weight());
+    assert_eq!(distances[&3], 2.5);
+
+    let graph = gr.into_graph::<u32>();
+    assert_eq!(graph.node_count(), 3);
+    assert_eq!(graph.edge_count(), 4);
+}
+
+#[test]
+fn from_edges() {
+    let gr = DiMultiGraphMap::<_, ()>::from_edges([(0, 1), (0, 1), (1, 2)]);
+    assert_eq!(gr.edge_count(), 3);
+    let mut keys: Vec<_> = gr.edge_references().map(|edge| edge.id()).collect();
+    keys.dedup();
+    assert_eq!(keys.len(), 3);
+}
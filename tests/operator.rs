@@ -1,6 +1,14 @@
-use petgraph::operator::complement;
+#[cfg(feature = "stable_graph")]
+use petgraph::operator::edge_subgraph;
+#[cfg(any(feature = "stable_graph", feature = "graphmap"))]
+use petgraph::operator::subgraph;
+use petgraph::operator::{
+    cartesian_product, complement, complement_graph, difference, disjoint_union, intersection,
+    join, lexicographic_product, line_graph, quotient_graph, strong_product, symmetric_difference,
+    tensor_product, union, union_by_key,
+};
 use petgraph::prelude::*;
-use petgraph::Graph;
+use petgraph::{EdgeType, Graph};
 
 #[test]
 fn test_complement() {
@@ -37,4 +45,376 @@ fn test_complement() {
             assert_eq!(output.contains_edge(x, y), expected_res.contains_edge(x, y));
         }
     }
+
+    // Each undirected edge is added from both of its endpoints.
+    let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+    let mut output = UnGraph::<(), ()>::default();
+    complement(&path, &mut output, ());
+    assert_eq!(output.edge_count(), 6);
+    assert_eq!(complement_graph(&path, ()).edge_count(), 3);
+}
+
+/// Check a product of `g1` and `g2` against the definition of its
+/// adjacency, without parallel edges.
+fn check_product<Ty: EdgeType>(
+    g1: &Graph<u8, (), Ty>,
+    g2: &Graph<u8, (), Ty>,
+    product: &Graph<(u8, u8), (), Ty>,
+    adjacent: impl Fn(usize, usize, usize, usize) -> bool,
+) {
+    let m = g2.node_count();
+    let node = |u: usize, v: usize| NodeIndex::new(u * m + v);
+    let mut edges = 0;
+    for u in 0..g1.node_count() {
+        for v in 0..m {
+            assert_eq!(
+                product[node(u, v)],
+                (g1[NodeIndex::new(u)], g2[NodeIndex::new(v)])
+            );
+            for x in 0..g1.node_count() {
+                for y in 0..m {
+                    let expected = adjacent(u, v, x, y);
+                    assert_eq!(product.contains_edge(node(u, v), node(x, y)), expected);
+                    edges += usize::from(expected);
+                }
+            }
+        }
+    }
+    let edges = if product.is_directed() {
+        edges
+    } else {
+        edges / 2
+    };
+    assert_eq!(product.edge_count(), edges);
+}
+
+/// Check each product of two simple graphs.
+fn check_products<Ty: EdgeType>(g1: &Graph<u8, (), Ty>, g2: &Graph<u8, (), Ty>) {
+    let adj1 = |a: usize, b: usize| g1.contains_edge(NodeIndex::new(a), NodeIndex::new(b));
+    let adj2 = |a: usize, b: usize| g2.contains_edge(NodeIndex::new(a), NodeIndex::new(b));
+    let pair = |&u: &u8, &v: &u8| (u, v);
+    check_product(g1, g2, &cartesian_product(g1, g2, pair), |u, v, x, y| {
+        (u == x && adj2(v, y)) || (adj1(u, x) && v == y)
+    });
+    check_product(g1, g2, &tensor_product(g1, g2, pair), |u, v, x, y| {
+        adj1(u, x) && adj2(v, y)
+    });
+    check_product(g1, g2, &strong_product(g1, g2, pair), |u, v, x, y| {
+        (u != x || v != y) && (u == x || adj1(u, x)) && (v == y || adj2(v, y))
+    });
+    check_product(
+        g1,
+        g2,
+        &lexicographic_product(g1, g2, pair),
+        |u, v, x, y| adj1(u, x) || (u == x && adj2(v, y)),
+    );
+}
+
+/// Number the nodes of a graph in their weights.
+fn weights<Ty: EdgeType>(mut graph: Graph<u8, (), Ty>) -> Graph<u8, (), Ty> {
+    for (i, weight) in graph.node_weights_mut().enumerate() {
+        *weight = i as u8;
+    }
+    graph
+}
+
+#[test]
+fn products() {
+    let path = weights(UnGraph::from_edges([(0, 1), (1, 2)]));
+    let star = weights(UnGraph::from_edges([(0, 1), (0, 2), (0, 3)]));
+    check_products(&path, &star);
+    check_products(&star, &path);
+    check_products(&path, &UnGraph::new_undirected());
+
+    let cycle = weights(DiGraph::from_edges([(0, 1), (1, 2), (2, 0)]));
+    let tree = weights(DiGraph::from_edges([(0, 1), (0, 2), (2, 3), (1, 0)]));
+    check_products(&cycle, &tree);
+    check_products(&tree, &cycle);
+
+    // A self loop makes a node adjacent to itself.
+    let looped = weights(UnGraph::from_edges([(0, 0), (0, 1)]));
+    let product: UnGraph<(u8, u8), ()> = tensor_product(&looped, &path, |&u, &v| (u, v));
+    assert!(product.contains_edge(NodeIndex::new(0), NodeIndex::new(1)));
+    // The edges of the path in the copy of node 0, and the edges between
+    // the copies of nodes 0 and 1.
+    assert_eq!(product.edge_count(), 2 + 2 * 2);
+}
+
+/// Return the edges of a graph as sorted pairs of node weights.
+fn edge_weights<Ty: EdgeType>(graph: &Graph<char, u8, Ty>) -> Vec<(char, char, u8)> {
+    let mut edges: Vec<_> = graph
+        .edge_references()
+        .map(|e| (graph[e.source()], graph[e.target()], *e.weight()))
+        .collect();
+    edges.sort_unstable();
+    edges
+}
+
+fn labeled<Ty: EdgeType>(edges: &[(char, char, u8)]) -> Graph<char, u8, Ty> {
+    let mut graph = Graph::default();
+    let mut nodes = Vec::<(char, NodeIndex)>::new();
+    let mut node = |graph: &mut Graph<char, u8, Ty>, w: char| match nodes.iter().find(|n| n.0 == w)
+    {
+        Some(&(_, v)) => v,
+        None => {
+            let v = graph.add_node(w);
+            nodes.push((w, v));
+            v
+        }
+    };
+    for &(a, b, weight) in edges {
+        let (a, b) = (node(&mut graph, a), node(&mut graph, b));
+        graph.add_edge(a, b, weight);
+    }
+    graph
+}
+
+#[test]
+fn set_operations() {
+    let g1 = labeled::<Directed>(&[('a', 'b', 1), ('b', 'c', 2), ('c', 'a', 3), ('a', 'b', 4)]);
+    let mut g2 = labeled::<Directed>(&[('b', 'a', 5), ('b', 'c', 6), ('c', 'd', 7)]);
+    g2.add_node('e');
+
+    let u = union(&g1, &g2);
+    assert_eq!(u.node_weights().copied().collect::<String>(), "abcde");
+    assert_eq!(
+        edge_weights(&u),
+        [
+            ('a', 'b', 1),
+            ('b', 'a', 5),
+            ('b', 'c', 2),
+            ('c', 'a', 3),
+            ('c', 'd', 7),
+        ]
+    );
+
+    let i = intersection(&g1, &g2);
+    assert_eq!(i.node_weights().copied().collect::<String>(), "abc");
+    assert_eq!(edge_weights(&i), [('b', 'c', 2)]);
+
+    let d = difference(&g1, &g2);
+    assert_eq!(d.node_count(), 3);
+    assert_eq!(edge_weights(&d), [('a', 'b', 1), ('c', 'a', 3)]);
+
+    let s = symmetric_difference(&g1, &g2);
+    assert_eq!(s.node_count(), 5);
+    assert_eq!(
+        edge_weights(&s),
+        [('a', 'b', 1), ('b', 'a', 5), ('c', 'a', 3), ('c', 'd', 7)]
+    );
+
+    // In an undirected graph, the edges a - b and b - a are the same.
+    let g1 = labeled::<Undirected>(&[('a', 'b', 1), ('b', 'c', 2)]);
+    let g2 = labeled::<Undirected>(&[('b', 'a', 5), ('c', 'c', 6)]);
+    assert_eq!(edge_weights(&intersection(&g1, &g2)), [('a', 'b', 1)]);
+    assert_eq!(
+        edge_weights(&symmetric_difference(&g1, &g2)),
+        [('b', 'c', 2), ('c', 'c', 6)]
+    );
+
+    // Nodes with the same key are merged, keeping the first weight.
+    let merged = union_by_key(&g1, &g2, |w| *w == 'a');
+    assert_eq!(merged.node_weights().copied().collect::<String>(), "ab");
+    assert_eq!(edge_weights(&merged), [('a', 'b', 1), ('b', 'b', 2)]);
+}
+
+#[test]
+fn disjoint_union_and_join() {
+    let g1 = labeled::<Directed>(&[('a', 'b', 1), ('b', 'b', 2)]);
+    let g2 = labeled::<Directed>(&[('a', 'c', 3)]);
+    let (graph, first, second) = disjoint_union(&g1, &g2);
+    assert_eq!(graph.node_weights().copied().collect::<String>(), "abac");
+    assert_eq!(first, [NodeIndex::new(0), NodeIndex::new(1)]);
+    assert_eq!(second, [NodeIndex::new(2), NodeIndex::new(3)]);
+    assert_eq!(
+        graph
+            .edge_references()
+            .map(|e| *e.weight())
+            .collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+    assert!(graph.contains_edge(second[0], second[1]));
+
+    let (graph, first, second) = join(&g1, &g2, |a, b| (10 * a.index() + b.index()) as u8);
+    assert_eq!(graph.edge_count(), 7);
+    for &a in &first {
+        for &b in &second {
+            let edge = graph.find_edge(a, b).unwrap();
+            assert_eq!(graph[edge] as usize, 10 * a.index() + b.index());
+            assert!(graph.find_edge(b, a).is_none());
+        }
+    }
+
+    let empty = Graph::<char, u8, Undirected>::default();
+    let (graph, _, second) = join(&empty, &labeled(&[('x', 'y', 0)]), |_, _| 0);
+    assert_eq!(graph.edge_count(), 1);
+    assert_eq!(second, [NodeIndex::new(0), NodeIndex::new(1)]);
+}
+
+#[test]
+fn complement_and_line_graph() {
+    // Each edge of an undirected complement is added once.
+    let path = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3)]);
+    let complement = complement_graph(&path, ());
+    assert_eq!(complement.edge_count(), 3);
+    for a in path.node_indices() {
+        for b in path.node_indices() {
+            assert_eq!(
+                complement.contains_edge(a, b),
+                a != b && !path.contains_edge(a, b)
+            );
+        }
+    }
+    assert_eq!(complement_graph(&complement, ()).edge_count(), 3);
+
+    let directed = DiGraph::<(), ()>::from_edges([(0, 1), (1, 1)]);
+    let complement = complement_graph(&directed, ());
+    assert_eq!(complement.edge_count(), 1);
+    assert!(complement.contains_edge(1.into(), 0.into()));
+
+    // The line graph of a triangle with a pendant edge.
+    let mut graph = Graph::<char, u8, Undirected>::default();
+    let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|w| graph.add_node(w));
+    graph.extend_with_edges([(a, b, 0), (b, c, 1), (c, a, 2), (c, d, 3)]);
+    let lines = line_graph(&graph);
+    assert_eq!(
+        lines.node_weights().copied().collect::<Vec<_>>(),
+        [0, 1, 2, 3]
+    );
+    let mut edges: Vec<_> = lines
+        .edge_references()
+        .map(|e| {
+            let (x, y) = (e.source().index(), e.target().index());
+            (x.min(y), x.max(y), *e.weight())
+        })
+        .collect();
+    edges.sort_unstable();
+    assert_eq!(
+        edges,
+        [
+            (0, 1, 'b'),
+            (0, 2, 'a'),
+            (1, 2, 'c'),
+            (1, 3, 'c'),
+            (2, 3, 'c')
+        ]
+    );
+
+    // Parallel edges are linked once.
+    let parallel = UnGraph::<(), ()>::from_edges([(0, 1), (1, 0)]);
+    assert_eq!(line_graph(&parallel).edge_count(), 1);
+
+    let directed = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 1), (2, 0)]);
+    let lines = line_graph(&directed);
+    let mut edges: Vec<_> = lines
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index()))
+        .collect();
+    edges.sort_unstable();
+    assert_eq!(edges, [(0, 1), (0, 2), (1, 3), (2, 1), (2, 2), (3, 0)]);
+}
+
+#[test]
+fn quotient() {
+    let graph = DiGraph::<(), u32>::from_edges([
+        (0, 1, 1),
+        (1, 0, 2),
+        (1, 2, 4),
+        (2, 1, 8),
+        (3, 1, 16),
+        (4, 0, 32),
+        (4, 2, 64),
+    ]);
+    // Block 1 is empty, and node 3 is left out.
+    let labels = [0, 0, 2, usize::MAX, 3];
+    let quotient = quotient_graph(&graph, &labels, |count: &mut (usize, u32), edge| {
+        count.0 += 1;
+        count.1 += *edge.weight();
+    });
+    assert_eq!(quotient.node_count(), 4);
+    assert_eq!(
+        quotient.node_weights().map(Vec::len).collect::<Vec<_>>(),
+        [2, 0, 1, 1]
+    );
+    let mut edges: Vec<_> = quotient
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+        .collect();
+    edges.sort_unstable();
+    assert_eq!(
+        edges,
+        [
+            (0, 0, (2, 3)),
+            (0, 2, (1, 4)),
+            (2, 0, (1, 8)),
+            (3, 0, (1, 32)),
+            (3, 2, (1, 64)),
+        ]
+    );
+
+    // In an undirected graph, the edges in both directions are aggregated.
+    let undirected = UnGraph::<(), u32>::from_edges([(0, 1, 1), (1, 0, 2), (1, 2, 4)]);
+    let quotient = quotient_graph(&undirected, &[0, 1, 1], |w: &mut u32, e| *w += *e.weight());
+    assert_eq!(quotient.edge_count(), 2);
+    let between = quotient.find_edge(1.into(), 0.into()).unwrap();
+    assert_eq!(quotient[between], 3);
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn induced_subgraphs() {
+    let mut graph = StableDiGraph::<char, u8>::new();
+    let [a, b, c, d, e] = ['a', 'b', 'c', 'd', 'e'].map(|w| graph.add_node(w));
+    let ab = graph.add_edge(a, b, 1);
+    let bc = graph.add_edge(b, c, 2);
+    let cd = graph.add_edge(c, d, 3);
+    let db = graph.add_edge(d, b, 4);
+    let ea = graph.add_edge(e, a, 5);
+    graph.remove_node(a);
+
+    // The removed node and the repeated one are ignored.
+    let sub = subgraph(&graph, [d, b, a, b, e]);
+    assert_eq!(sub.graph().node_count(), 3);
+    assert_eq!(sub.graph().edge_count(), 1);
+    for v in [b, d, e] {
+        let w = sub.node(v).unwrap();
+        assert_eq!(sub.original_node(w), Some(v));
+        assert_eq!(sub.graph()[w], graph[v]);
+    }
+    assert_eq!(sub.node(c), None);
+    let w = sub.edge(db).unwrap();
+    assert_eq!(sub.original_edge(w), Some(db));
+    assert_eq!(sub.graph()[w], 4);
+    assert_eq!(
+        sub.graph().edge_endpoints(w),
+        Some((sub.node(d).unwrap(), sub.node(b).unwrap()))
+    );
+    for e in [ab, bc, cd, ea] {
+        assert_eq!(sub.edge(e), None);
+    }
+
+    let sub = edge_subgraph(&graph, [cd, ab, cd]);
+    assert_eq!(sub.graph().node_count(), 2);
+    assert_eq!(sub.graph().edge_count(), 1);
+    // The edge `d -> b` is between kept nodes, but not kept.
+    let sub = edge_subgraph(&graph, [bc, cd]);
+    assert_eq!(sub.graph().node_count(), 3);
+    assert_eq!(sub.graph().edge_count(), 2);
+    assert_eq!(sub.edge(db), None);
+    assert_eq!(
+        sub.graph().node_weights().copied().collect::<Vec<_>>(),
+        ['b', 'c', 'd']
+    );
+}
+
+#[test]
+#[cfg(feature = "graphmap")]
+fn induced_subgraph_of_graphmap() {
+    let graph = UnGraphMap::<u32, ()>::from_edges([(1, 2), (2, 3), (3, 1), (3, 4)]);
+    let sub = subgraph(&graph, [1, 3, 4]);
+    assert_eq!(sub.graph().node_count(), 3);
+    assert_eq!(sub.graph().edge_count(), 2);
+    assert!(sub.graph().contains_edge(4, 3));
+    assert_eq!(sub.node(4), Some(4));
+    assert_eq!(sub.edge((3, 1)).or(sub.edge((1, 3))), Some((1, 3)));
 }
@@ -0,0 +1,119 @@
+use petgraph::graph::DiGraph;
+use petgraph::io::pajek::{from_pajek, PajekEdge, PajekError, PajekNode};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableUnGraph;
+use petgraph::visit::EdgeRef;
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn round_trip() {
+    use petgraph::graph::UnGraph;
+    use petgraph::io::pajek::Pajek;
+
+    let mut graph = StableUnGraph::<&str, f64>::default();
+    let a = graph.add_node("a \"b\"");
+    let removed = graph.add_node("removed");
+    let c = graph.add_node("c d");
+    graph.add_edge(a, c, 0.25);
+    graph.add_edge(c, c, -2.0);
+    graph.remove_node(removed);
+
+    let net = Pajek::with_attr_getters(
+        &graph,
+        &|(_, label): (_, &&str)| Some(label.to_string()),
+        &|edge: petgraph::stable_graph::EdgeReference<f64>| Some(*edge.weight()),
+    )
+    .to_string();
+    assert_eq!(
+        net,
+        "*Vertices 2\n1 \"a 'b'\"\n2 \"c d\"\n*Edges\n1 2 0.25\n2 2 -2\n"
+    );
+
+    let parsed: UnGraph<PajekNode, PajekEdge> = from_pajek(&net).unwrap();
+    let labels: Vec<_> = parsed.raw_nodes().iter().map(|n| &n.weight.label).collect();
+    assert_eq!(labels, ["a 'b'", "c d"]);
+    let edges: Vec<_> = parsed
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), e.weight().clone()))
+        .collect();
+    let edge = |a, b, weight| {
+        (
+            a,
+            b,
+            PajekEdge {
+                directed: false,
+                weight: Some(weight),
+            },
+        )
+    };
+    assert_eq!(edges, [edge(0, 1, 0.25), edge(1, 1, -2.0)]);
+
+    let unlabeled = Pajek::new(&DiGraph::<(), ()>::from_edges([(1, 0)])).to_string();
+    assert_eq!(unlabeled, "*Vertices 2\n*Arcs\n2 1\n");
+}
+
+#[test]
+fn lists_and_matrix() {
+    let net = "\
+*Network example
+*vertices 3
+3 c 0.5 0.5 ic Red
+*arcslist
+1 2 3
+*EdgesList
+2 3
+*Matrix
+0 0 0
+0 0 4.5
+*Partition ignored
+1
+2
+";
+    let graph: DiGraph<PajekNode, PajekEdge> = from_pajek(net).unwrap();
+    assert_eq!(graph.raw_nodes()[2].weight.label, "c");
+    assert_eq!(graph.raw_nodes()[2].weight.coordinates, [0.5, 0.5]);
+    let edges: Vec<_> = graph
+        .edge_references()
+        .map(|e| {
+            let w = e.weight();
+            (e.source().index(), e.target().index(), w.directed, w.weight)
+        })
+        .collect();
+    assert_eq!(
+        edges,
+        [
+            (0, 1, true, None),
+            (0, 2, true, None),
+            (1, 2, false, None),
+            (1, 2, true, Some(4.5)),
+        ]
+    );
+}
+
+#[test]
+fn errors() {
+    let parse = |net: &str| from_pajek::<DiGraph<PajekNode, PajekEdge>>(net).unwrap_err();
+    assert_eq!(parse("*Vertices 2\n*Arcs\n1 3"), PajekError::UnknownNode(3));
+    assert_eq!(parse("*Vertices 2\n0 \"zero\""), PajekError::UnknownNode(0));
+    assert_eq!(
+        parse("*Arcs\n1 2"),
+        PajekError::Syntax {
+            line: 1,
+            message: "edges before the vertices section"
+        }
+    );
+    assert_eq!(
+        parse("*Vertices 1\n*Matrix\n1\n1"),
+        PajekError::Syntax {
+            line: 4,
+            message: "more matrix rows than vertices"
+        }
+    );
+    assert_eq!(
+        parse("*Vertices 99999999999999999"),
+        PajekError::Syntax {
+            line: 1,
+            message: "too many vertices for the length of the network"
+        }
+    );
+}
@@ -0,0 +1,88 @@
+#![cfg(all(feature = "rayon", feature = "rand"))]
+
+use petgraph::algo::{par_bfs, par_multi_source_bfs};
+use petgraph::generators::gnm_random_graph;
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+use petgraph::stable_graph::StableDiGraph;
+use petgraph::visit::{IntoNeighbors, NodeIndexable};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// The distances from the sources, with a sequential breadth-first search.
+fn distances<G>(graph: G, sources: &[G::NodeId]) -> Vec<Option<usize>>
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    let mut distance = vec![None; graph.node_bound()];
+    let mut level = Vec::new();
+    for &source in sources {
+        if distance[graph.to_index(source)].is_none() {
+            distance[graph.to_index(source)] = Some(0);
+            level.push(source);
+        }
+    }
+    let mut d = 0;
+    while !level.is_empty() {
+        d += 1;
+        let mut next = Vec::new();
+        for a in level {
+            for b in graph.neighbors(a) {
+                if distance[graph.to_index(b)].is_none() {
+                    distance[graph.to_index(b)] = Some(d);
+                    next.push(b);
+                }
+            }
+        }
+        level = next;
+    }
+    distance
+}
+
+#[test]
+fn par_bfs_path() {
+    let graph = DiGraph::<(), ()>::from_edges((0..999).map(|i| (i, i + 1)));
+    let expected: Vec<Option<usize>> = (0..1000).map(Some).collect();
+    assert_eq!(par_bfs(&graph, NodeIndex::new(0)), expected);
+    let mut expected = vec![None; 1000];
+    expected[999] = Some(0);
+    assert_eq!(par_bfs(&graph, NodeIndex::new(999)), expected);
+    assert_eq!(par_multi_source_bfs(&graph, &[]), vec![None; 1000]);
+}
+
+#[test]
+fn par_bfs_random() {
+    let mut rng = StdRng::seed_from_u64(1376);
+    for (n, m) in [(50, 60), (2_000, 3_000), (10_000, 80_000)] {
+        let mut graph: DiGraph<(), ()> = gnm_random_graph(n, m, &mut rng);
+        graph.extend_with_edges([(n as u32 - 1, n as u32 - 1)]);
+        let sources = [NodeIndex::new(0), NodeIndex::new(n / 2), NodeIndex::new(0)];
+        assert_eq!(
+            par_bfs(&graph, sources[0]),
+            distances(&graph, &sources[..1])
+        );
+        assert_eq!(
+            par_multi_source_bfs(&graph, &sources),
+            distances(&graph, &sources)
+        );
+
+        let graph: UnGraph<(), ()> = gnm_random_graph(n, m, &mut rng);
+        assert_eq!(
+            par_multi_source_bfs(&graph, &sources[..2]),
+            distances(&graph, &sources[..2])
+        );
+    }
+}
+
+#[test]
+fn par_bfs_stable_graph() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let mut graph: StableDiGraph<(), ()> = gnm_random_graph(1_000, 5_000, &mut rng);
+    for i in (1..1_000).step_by(9) {
+        graph.remove_node(NodeIndex::new(i));
+    }
+    let sources = [NodeIndex::new(0)];
+    assert_eq!(
+        par_multi_source_bfs(&graph, &sources),
+        distances(&graph, &sources)
+    );
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "rayon")]
+
+use petgraph::algo::{kosaraju_scc, par_scc};
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+use petgraph::stable_graph::StableDiGraph;
+use petgraph::visit::{
+    GraphProp, IntoEdgeReferences, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable,
+    Visitable,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The components as sorted vectors of node indices, in sorted order.
+fn normalize<G>(g: G, sccs: Vec<Vec<G::NodeId>>) -> Vec<Vec<usize>>
+where
+    G: NodeIndexable,
+{
+    let mut sccs: Vec<Vec<usize>> = sccs
+        .into_iter()
+        .map(|scc| {
+            let mut scc: Vec<usize> = scc.into_iter().map(|n| g.to_index(n)).collect();
+            scc.sort_unstable();
+            scc
+        })
+        .collect();
+    sccs.sort_unstable();
+    sccs
+}
+
+fn check<G>(g: G)
+where
+    G: IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + Visitable
+        + NodeIndexable
+        + GraphProp
+        + Sync,
+    G::NodeId: Send,
+{
+    assert_eq!(normalize(g, par_scc(g)), normalize(g, kosaraju_scc(g)));
+}
+
+/// A random graph of `n` nodes and `m` edges, mostly going forward in the
+/// order of the nodes, so that it has many components of various sizes.
+fn random_edges(rng: &mut StdRng, n: usize, m: usize) -> Vec<(u32, u32)> {
+    (0..m)
+        .map(|_| {
+            let a = rng.gen_range(0..n);
+            let b = if rng.gen_range(0..20) == 0 {
+                rng.gen_range(0..n)
+            } else {
+                (a + 1 + rng.gen_range(0..8)) % n
+            };
+            (a as u32, b as u32)
+        })
+        .collect()
+}
+
+#[test]
+fn par_scc_small() {
+    check(&DiGraph::<(), ()>::new());
+    check(&DiGraph::<(), ()>::from_edges([
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 3),
+        (4, 3),
+    ]));
+    check(&UnGraph::<(), ()>::from_edges([(0, 1), (2, 3), (3, 4)]));
+}
+
+#[test]
+fn par_scc_random() {
+    let mut rng = StdRng::seed_from_u64(1375);
+    for (n, m) in [(100, 150), (5_000, 6_000), (20_000, 40_000)] {
+        let edges = random_edges(&mut rng, n, m);
+        let mut graph = DiGraph::<(), ()>::from_edges(&edges);
+        graph.extend_with_edges((graph.node_count()..n).map(|i| (i as u32, i as u32)));
+        check(&graph);
+        check(&UnGraph::<(), ()>::from_edges(&edges));
+    }
+}
+
+#[test]
+fn par_scc_stable_graph() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let edges = random_edges(&mut rng, 3_000, 3_500);
+    let mut graph = StableDiGraph::<(), ()>::from_edges(&edges);
+    for i in (0..3_000).step_by(7) {
+        graph.remove_node(NodeIndex::new(i));
+    }
+    check(&graph);
+}
@@ -0,0 +1,104 @@
+use petgraph::algo::partition::{kernighan_lin_bisection, multilevel_partition, Partition};
+use petgraph::graph::{NodeIndex, UnGraph};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableUnGraph;
+use petgraph::visit::EdgeRef;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// `groups` dense random groups of `size` nodes, with a few edges between
+/// them.
+fn planted_partition(rng: &mut StdRng, groups: usize, size: usize) -> UnGraph<(), ()> {
+    let n = groups * size;
+    let mut graph = UnGraph::new_undirected();
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for a in 0..n {
+        for b in a + 1..n {
+            let p = if a / size == b / size { 0.3 } else { 0.002 };
+            if rng.gen_bool(p) {
+                graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+            }
+        }
+    }
+    graph
+}
+
+fn assert_consistent(graph: &UnGraph<(), ()>, partition: &Partition) {
+    assert_eq!(partition.labels.len(), graph.node_count());
+    assert!(partition.labels.iter().all(|&l| l < partition.parts));
+    let cut = graph
+        .edge_references()
+        .filter(|e| partition.labels[e.source().index()] != partition.labels[e.target().index()])
+        .count();
+    assert_eq!(partition.cut, cut);
+}
+
+#[test]
+fn bisection_of_planted_partition() {
+    let mut rng = StdRng::seed_from_u64(35);
+    let graph = planted_partition(&mut rng, 2, 100);
+    let between = graph
+        .edge_references()
+        .filter(|e| (e.source().index() < 100) != (e.target().index() < 100))
+        .count();
+
+    let partition = kernighan_lin_bisection(&graph, 0.02, |_| 1);
+    assert_consistent(&graph, &partition);
+    assert!(partition.part_sizes().iter().all(|&size| size <= 102));
+    assert!(partition.cut <= between);
+
+    let partition = multilevel_partition(&graph, 2, 0.02, |_| 1);
+    assert_consistent(&graph, &partition);
+    assert!(partition.part_sizes().iter().all(|&size| size <= 102));
+    assert!(partition.cut <= between);
+}
+
+#[test]
+fn multilevel_k_way() {
+    let mut rng = StdRng::seed_from_u64(36);
+    let graph = planted_partition(&mut rng, 5, 200);
+    let between = graph
+        .edge_references()
+        .filter(|e| e.source().index() / 200 != e.target().index() / 200)
+        .count();
+    for k in [1, 3, 5, 8] {
+        let partition = multilevel_partition(&graph, k, 0.05, |_| 1);
+        assert_consistent(&graph, &partition);
+        assert_eq!(partition.part_sizes().iter().sum::<usize>(), 1000);
+        let max = (1000.0 / k as f64 * 1.05 * 1.05 * 1.05).ceil() as usize;
+        assert!(partition.part_sizes().iter().all(|&size| size <= max));
+        if k == 1 {
+            assert_eq!(partition.cut, 0);
+        }
+        if k == 5 {
+            assert!(partition.cut <= between * 2);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn partition_weights_and_holes() {
+    // The heavy edges must not be cut.
+    let mut graph = StableUnGraph::<(), u32>::default();
+    let removed = graph.add_node(());
+    let nodes: Vec<_> = (0..8).map(|_| graph.add_node(())).collect();
+    for i in 0..8 {
+        let weight = if i % 2 == 0 { 10 } else { 1 };
+        graph.add_edge(nodes[i], nodes[(i + 1) % 8], weight);
+    }
+    graph.remove_node(removed);
+    graph.add_edge(nodes[0], nodes[0], 100);
+
+    let partition = kernighan_lin_bisection(&graph, 0.0, |e| *e.weight() as usize);
+    assert_eq!(partition.labels[removed.index()], usize::MAX);
+    assert_eq!(partition.cut, 2);
+    assert_eq!(partition.part_sizes(), vec![4, 4]);
+
+    let empty = UnGraph::<(), ()>::new_undirected();
+    let partition = multilevel_partition(&empty, 3, 0.0, |_| 1);
+    assert_eq!(partition.cut, 0);
+    assert_eq!(partition.part_sizes(), vec![0, 0, 0]);
+}
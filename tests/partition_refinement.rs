@@ -0,0 +1,89 @@
+use petgraph::partition_refinement::PartitionRefinement;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn sorted_sets(partition: &PartitionRefinement) -> Vec<Vec<usize>> {
+    partition
+        .sets()
+        .map(|set| {
+            let mut set = set.to_vec();
+            set.sort_unstable();
+            set
+        })
+        .collect()
+}
+
+#[test]
+fn refine_and_remove() {
+    let mut partition = PartitionRefinement::new(5);
+    assert_eq!(partition.set_count(), 1);
+    let whole = partition.first().unwrap();
+
+    assert_eq!(partition.refine([0, 1, 2, 3, 4]), vec![]);
+    let splits = partition.refine([4, 2, 4, 7]);
+    assert_eq!(splits.len(), 1);
+    let (old, new) = splits[0];
+    assert_eq!(old, whole);
+    assert_eq!(partition.first(), Some(new));
+    assert_eq!(partition.next(new), Some(old));
+    assert_eq!(partition.prev(old), Some(new));
+    assert_eq!(sorted_sets(&partition), vec![vec![2, 4], vec![0, 1, 3]]);
+
+    assert!(partition.remove(2));
+    assert!(!partition.remove(2));
+    assert!(partition.remove(4));
+    assert!(!partition.contains(4));
+    assert_eq!(partition.set_count(), 1);
+    assert_eq!(partition.first(), Some(old));
+    assert_eq!(partition.len(), 3);
+    assert_eq!(partition.set_of(2), None);
+
+    assert_eq!(PartitionRefinement::new(0).sets().count(), 0);
+}
+
+#[test]
+fn refine_matches_naive_partition() {
+    let mut rng = StdRng::seed_from_u64(13);
+    for _ in 0..50 {
+        let n = rng.gen_range(1..30);
+        let mut partition = PartitionRefinement::new(n);
+        let mut naive: Vec<Vec<usize>> = vec![(0..n).collect()];
+        for _ in 0..20 {
+            if rng.gen_bool(0.2) {
+                let element = rng.gen_range(0..n);
+                let removed = partition.remove(element);
+                let before = naive.iter().map(Vec::len).sum::<usize>();
+                for set in &mut naive {
+                    set.retain(|&e| e != element);
+                }
+                naive.retain(|set| !set.is_empty());
+                assert_eq!(removed, naive.iter().map(Vec::len).sum::<usize>() < before);
+            } else {
+                let pivot: Vec<usize> = (0..n).filter(|_| rng.gen_bool(0.3)).collect();
+                let splits = partition.refine(pivot.iter().copied());
+                let mut refined = Vec::new();
+                let mut count = 0;
+                for set in &naive {
+                    let (inside, outside): (Vec<usize>, Vec<usize>) =
+                        set.iter().partition(|e| pivot.contains(e));
+                    if !inside.is_empty() && !outside.is_empty() {
+                        count += 1;
+                        refined.push(inside);
+                        refined.push(outside);
+                    } else {
+                        refined.push(set.clone());
+                    }
+                }
+                naive = refined;
+                assert_eq!(splits.len(), count);
+            }
+            assert_eq!(sorted_sets(&partition), naive);
+            assert_eq!(partition.set_count(), naive.len());
+            assert_eq!(partition.len(), naive.iter().map(Vec::len).sum::<usize>());
+            for set in &naive {
+                let id = partition.set_of(set[0]).unwrap();
+                assert!(set.iter().all(|&e| partition.set_of(e) == Some(id)));
+            }
+        }
+    }
+}
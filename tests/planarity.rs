@@ -0,0 +1,227 @@
+use std::collections::{HashMap, HashSet};
+
+use petgraph::algo::planarity::{KuratowskiKind, KuratowskiSubgraph, PlanarEmbedding, Planarity};
+use petgraph::algo::{is_planar, planarity};
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::unionfind::UnionFind;
+
+fn simple_edges(graph: &UnGraph<(), ()>) -> HashSet<(usize, usize)> {
+    graph
+        .edge_indices()
+        .filter_map(|e| {
+            let (a, b) = graph.edge_endpoints(e).unwrap();
+            let (a, b) = (a.index().min(b.index()), a.index().max(b.index()));
+            (a != b).then_some((a, b))
+        })
+        .collect()
+}
+
+/// A rotation system is planar if and only if it satisfies Euler's formula
+/// in every connected component.
+fn assert_planar_embedding(graph: &UnGraph<(), ()>, embedding: &PlanarEmbedding<NodeIndex>) {
+    let edges = simple_edges(graph);
+    assert_eq!(embedding.node_count(), graph.node_count());
+    assert_eq!(embedding.edge_count(), edges.len());
+    for v in graph.node_indices() {
+        let mut around: Vec<_> = embedding.neighbors(v).map(|w| w.index()).collect();
+        around.sort_unstable();
+        let mut expected: Vec<_> = graph
+            .neighbors(v)
+            .map(|w| w.index())
+            .filter(|&w| w != v.index())
+            .collect();
+        expected.sort_unstable();
+        expected.dedup();
+        assert_eq!(around, expected);
+    }
+
+    let mut components = UnionFind::new(graph.node_count());
+    for &(a, b) in &edges {
+        components.union(a, b);
+    }
+    let mut nontrivial = HashSet::new();
+    let mut nodes = 0;
+    for v in 0..graph.node_count() {
+        if edges.iter().any(|&(a, b)| a == v || b == v) {
+            nontrivial.insert(components.find(v));
+            nodes += 1;
+        }
+    }
+    let faces = embedding.faces().len();
+    assert_eq!(
+        nodes + faces,
+        edges.len() + 2 * nontrivial.len(),
+        "Euler's formula"
+    );
+}
+
+/// Check that the witness is a subdivision of K5 or K3,3 in the graph.
+fn assert_kuratowski(graph: &UnGraph<(), ()>, witness: &KuratowskiSubgraph<NodeIndex>) {
+    let edges = simple_edges(graph);
+    let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &(a, b) in &witness.edges {
+        let (a, b) = (a.index(), b.index());
+        assert!(edges.contains(&(a.min(b), a.max(b))));
+        adjacency.entry(a).or_default().push(b);
+        adjacency.entry(b).or_default().push(a);
+    }
+    let branch: HashSet<usize> = witness.branch_nodes.iter().map(|v| v.index()).collect();
+    let branch_degree = match witness.kind {
+        KuratowskiKind::K5 => 4,
+        KuratowskiKind::K33 => 3,
+    };
+    assert_eq!(
+        branch.len(),
+        if witness.kind == KuratowskiKind::K5 {
+            5
+        } else {
+            6
+        }
+    );
+    for (v, list) in &adjacency {
+        let expected = if branch.contains(v) { branch_degree } else { 2 };
+        assert_eq!(list.len(), expected);
+    }
+
+    // Follow the paths between branch nodes.
+    let mut reached = HashSet::new();
+    let mut paths = HashSet::new();
+    for &start in &branch {
+        reached.insert(start);
+        for &first in &adjacency[&start] {
+            let (mut prev, mut cur) = (start, first);
+            while !branch.contains(&cur) {
+                reached.insert(cur);
+                let next = adjacency[&cur]
+                    .iter()
+                    .copied()
+                    .find(|&x| x != prev)
+                    .unwrap();
+                prev = cur;
+                cur = next;
+            }
+            assert_ne!(cur, start);
+            assert!(paths.insert((start, cur)), "parallel paths");
+        }
+    }
+    assert_eq!(reached.len(), adjacency.len());
+    if witness.kind == KuratowskiKind::K33 {
+        // The branch nodes are 2-colorable along the paths.
+        let mut color = HashMap::new();
+        let first = *branch.iter().next().unwrap();
+        color.insert(first, 0);
+        for _ in 0..3 {
+            for &(a, b) in &paths {
+                if let Some(&c) = color.get(&a) {
+                    color.insert(b, 1 - c);
+                }
+            }
+        }
+        for &(a, b) in &paths {
+            assert_ne!(color[&a], color[&b]);
+        }
+    }
+}
+
+#[test]
+fn planarity_small_graphs() {
+    let empty = UnGraph::<(), ()>::new_undirected();
+    assert!(is_planar(&empty));
+
+    let k5 = UnGraph::<(), ()>::from_edges((0..5).flat_map(|a| (a + 1..5).map(move |b| (a, b))));
+    assert!(!is_planar(&k5));
+    match planarity(&k5) {
+        Planarity::NonPlanar(witness) => {
+            assert_eq!(witness.kind, KuratowskiKind::K5);
+            assert_kuratowski(&k5, &witness);
+        }
+        _ => panic!("K5 is not planar"),
+    }
+
+    let k33 = UnGraph::<(), ()>::from_edges((0..3).flat_map(|a| (3..6).map(move |b| (a, b))));
+    assert!(!is_planar(&k33));
+    let witness = match planarity(&k33) {
+        Planarity::NonPlanar(witness) => witness,
+        _ => panic!("K3,3 is not planar"),
+    };
+    assert_eq!(witness.kind, KuratowskiKind::K33);
+    assert_eq!(witness.edges.len(), 9);
+
+    // K5 minus an edge, with loops and parallel edges.
+    let mut graph = k5.clone();
+    let e = graph.find_edge(0.into(), 1.into()).unwrap();
+    graph.remove_edge(e);
+    graph.extend_with_edges([(2, 2), (2, 3), (3, 2)]);
+    match planarity(&graph) {
+        Planarity::Planar(embedding) => assert_planar_embedding(&graph, &embedding),
+        _ => panic!("K5 minus an edge is planar"),
+    }
+}
+
+#[test]
+fn planarity_grid_and_path() {
+    // A long path must not overflow the stack.
+    let path = UnGraph::<(), ()>::from_edges((0..100_000u32).map(|i| (i, i + 1)));
+    assert!(is_planar(&path));
+
+    let side = 30;
+    let mut grid = UnGraph::<(), ()>::new_undirected();
+    let nodes: Vec<_> = (0..side * side).map(|_| grid.add_node(())).collect();
+    for r in 0..side {
+        for c in 0..side {
+            if c + 1 < side {
+                grid.add_edge(nodes[r * side + c], nodes[r * side + c + 1], ());
+            }
+            if r + 1 < side {
+                grid.add_edge(nodes[r * side + c], nodes[(r + 1) * side + c], ());
+            }
+            // Diagonals keep the grid planar.
+            if r + 1 < side && c + 1 < side {
+                grid.add_edge(nodes[r * side + c], nodes[(r + 1) * side + c + 1], ());
+            }
+        }
+    }
+    match planarity(&grid) {
+        Planarity::Planar(embedding) => {
+            assert_planar_embedding(&grid, &embedding);
+            assert_eq!(embedding.faces().len(), 2 * (side - 1) * (side - 1) + 1);
+        }
+        _ => panic!("the grid is planar"),
+    }
+    // Crossing diagonals in one square of the grid.
+    grid.add_edge(nodes[side + 2], nodes[2 * side + 1], ());
+    assert!(!is_planar(&grid));
+    match planarity(&grid) {
+        Planarity::NonPlanar(witness) => assert_kuratowski(&grid, &witness),
+        _ => panic!("the grid with crossing diagonals is not planar"),
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn planarity_random_graphs() {
+    use petgraph::generators::gnm_random_graph;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(22);
+    let mut planar = 0;
+    for _ in 0..400 {
+        let n = rng.gen_range(1..14);
+        let max = n * (n - 1) / 2;
+        let m = rng.gen_range(n.min(max)..=(3 * n).min(max));
+        let graph: UnGraph<(), ()> = gnm_random_graph(n, m, &mut rng);
+        match planarity(&graph) {
+            Planarity::Planar(embedding) => {
+                planar += 1;
+                assert!(is_planar(&graph));
+                assert_planar_embedding(&graph, &embedding);
+            }
+            Planarity::NonPlanar(witness) => {
+                assert!(!is_planar(&graph));
+                assert_kuratowski(&graph, &witness);
+            }
+        }
+    }
+    // Both outcomes are exercised.
+    assert!(planar > 50 && planar < 350);
+}
@@ -0,0 +1,163 @@
+#![cfg(feature = "rayon")]
+
+use petgraph::algo::page_rank::{parallel_page_rank, parallel_page_rank_of_nodes};
+use petgraph::algo::pregel::{Pregel, VertexProgram};
+use petgraph::algo::{page_rank, page_rank_of_nodes};
+use petgraph::graph::{DiGraph, UnGraph};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The distances, in number of edges, from the nodes of distance *0*.
+struct Hops;
+
+impl VertexProgram for Hops {
+    /// The distance of the node, and whether it changed in the last superstep.
+    type Value = (Option<usize>, bool);
+    type Message = usize;
+
+    fn message(&self, value: &Self::Value, _out_degree: usize) -> Option<usize> {
+        match *value {
+            (Some(d), true) => Some(d + 1),
+            _ => None,
+        }
+    }
+
+    fn combine(&self, a: usize, b: usize) -> usize {
+        a.min(b)
+    }
+
+    fn compute(&self, _: usize, value: &Self::Value, message: Option<usize>) -> Self::Value {
+        match (value.0, message) {
+            (None, Some(m)) => (Some(m), true),
+            (d, _) => (d, false),
+        }
+    }
+}
+
+/// The number of messages received by each node.
+struct Count;
+
+impl VertexProgram for Count {
+    type Value = usize;
+    type Message = usize;
+
+    fn message(&self, _: &usize, _out_degree: usize) -> Option<usize> {
+        Some(1)
+    }
+
+    fn combine(&self, a: usize, b: usize) -> usize {
+        a + b
+    }
+
+    fn compute(&self, _: usize, _: &usize, message: Option<usize>) -> usize {
+        message.unwrap_or(0)
+    }
+}
+
+/// Random edges, with loops and parallel edges.
+fn random_edges(rng: &mut StdRng, n: usize, m: usize) -> Vec<(u32, u32)> {
+    (0..m)
+        .map(|_| (rng.gen_range(0..n) as u32, rng.gen_range(0..n) as u32))
+        .collect()
+}
+
+fn assert_close(ranks: &[f64], expected: &[f64]) {
+    assert_eq!(ranks.len(), expected.len());
+    for (r, e) in ranks.iter().zip(expected) {
+        assert!((r - e).abs() < 1e-12, "{r} != {e}");
+    }
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn pregel_hops() {
+    use petgraph::algo::dijkstra;
+    use petgraph::graph::NodeIndex;
+    use petgraph::stable_graph::StableDiGraph;
+
+    let mut rng = StdRng::seed_from_u64(1377);
+    let mut graph = StableDiGraph::<(), ()>::from_edges(random_edges(&mut rng, 2_000, 6_000));
+    for i in (1..2_000).step_by(11) {
+        graph.remove_node(NodeIndex::new(i));
+    }
+    let source = NodeIndex::new(0);
+    let mut pregel = Pregel::new(&graph, |a| {
+        if a == source {
+            (Some(0), true)
+        } else {
+            (None, false)
+        }
+    });
+    assert_eq!(pregel.nodes().len(), graph.node_count());
+    let mut supersteps = 0;
+    while pregel.superstep(&Hops) {
+        supersteps += 1;
+    }
+
+    let expected = dijkstra(&graph, source, None, |_| 1usize);
+    let depth = expected.values().copied().max().unwrap();
+    assert!(depth <= supersteps && supersteps <= depth + 1);
+    for (a, &(d, _)) in pregel.nodes().iter().zip(pregel.values()) {
+        assert_eq!(d, expected.get(a).copied());
+    }
+}
+
+#[test]
+fn pregel_parallel_edges() {
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 2), (2, 2)]);
+    let mut pregel = Pregel::new(&graph, |_| 0);
+    pregel.superstep(&Count);
+    assert_eq!(pregel.values(), [0, 1, 3]);
+
+    pregel.dedup_edges();
+    pregel.superstep(&Count);
+    assert_eq!(pregel.values(), [0, 1, 2]);
+    let out_degree: Vec<usize> = (0..3).map(|i| pregel.out_degree(i)).collect();
+    assert_eq!(out_degree, [3, 0, 1]);
+
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (1, 2)]);
+    let mut pregel = Pregel::new(&graph, |_| 0);
+    pregel.superstep(&Count);
+    assert_eq!(pregel.values(), [1, 3, 2]);
+}
+
+#[test]
+fn parallel_page_rank_multigraph() {
+    let graph = DiGraph::<(), ()>::from_edges([(0, 1), (0, 1), (1, 2), (2, 0), (0, 2)]);
+    let ranks = parallel_page_rank(&graph, 0.85_f64, 20, Some(0.0));
+    assert_close(&ranks, &page_rank(&graph, 0.85_f64, 20));
+
+    let mut rng = StdRng::seed_from_u64(1377);
+    for _ in 0..3 {
+        let edges = random_edges(&mut rng, 300, 900);
+        let graph = DiGraph::<(), ()>::from_edges(&edges);
+        let ranks = parallel_page_rank(&graph, 0.85_f64, 20, Some(0.0));
+        assert_close(&ranks, &page_rank(&graph, 0.85_f64, 20));
+
+        let graph = UnGraph::<(), ()>::from_edges(&edges);
+        let ranks = parallel_page_rank(&graph, 0.85_f64, 20, Some(0.0));
+        assert_close(&ranks, &page_rank(&graph, 0.85_f64, 20));
+    }
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn parallel_page_rank_with_holes() {
+    use petgraph::graph::NodeIndex;
+    use petgraph::stable_graph::StableDiGraph;
+
+    let mut rng = StdRng::seed_from_u64(1377);
+    let mut graph = StableDiGraph::<(), ()>::from_edges(random_edges(&mut rng, 300, 900));
+    for i in (0..300).step_by(7) {
+        graph.remove_node(NodeIndex::new(i));
+    }
+    let expected = page_rank_of_nodes(&graph, 0.85_f64, 20);
+    assert_eq!(expected.len(), graph.node_count());
+    // The holes change nothing but the indices.
+    assert_close(
+        &page_rank(&DiGraph::from(graph.clone()), 0.85_f64, 20),
+        &expected,
+    );
+    let ranks = parallel_page_rank_of_nodes(&graph, 0.85_f64, 20, Some(0.0));
+    assert_close(&ranks, &expected);
+}
@@ -0,0 +1,106 @@
+use petgraph::algo::{connected_components, dijkstra};
+use petgraph::property::{
+    PropertyEdge, PropertyGraph, PropertyNode, PropertyValue, UnPropertyGraph,
+};
+use petgraph::visit::{IntoEdgeReferences, IntoNodeReferences};
+
+fn movies() -> PropertyGraph {
+    let mut g = PropertyGraph::new();
+    let keanu = g.add_node(
+        PropertyNode::new(["Person", "Actor"])
+            .with("name", "Keanu")
+            .with("born", 1964),
+    );
+    let lana = g.add_node(PropertyNode::new(["Person", "Director"]).with("name", "Lana"));
+    let matrix = g.add_node(
+        PropertyNode::new(["Movie"])
+            .with("title", "The Matrix")
+            .with("rating", 8.7),
+    );
+    let speed = g.add_node(PropertyNode::new(["Movie"]).with("title", "Speed"));
+    g.add_edge(
+        keanu,
+        matrix,
+        PropertyEdge::new("ACTED_IN").with("roles", vec!["Neo".into()]),
+    );
+    g.add_edge(keanu, speed, PropertyEdge::new("ACTED_IN"));
+    g.add_edge(lana, matrix, PropertyEdge::new("DIRECTED"));
+    g.add_edge(lana, keanu, PropertyEdge::new("KNOWS").with("weight", 3));
+    g
+}
+
+#[test]
+fn properties() {
+    let mut g = movies();
+    let keanu = g.find_node("name", &"Keanu".into()).unwrap();
+    assert!(g[keanu].has_label("Actor"));
+    assert!(!g[keanu].has_label("Movie"));
+    assert_eq!(
+        g[keanu].get("born").and_then(PropertyValue::as_int),
+        Some(1964)
+    );
+    assert_eq!(g[keanu].get("height"), None);
+    assert_eq!(g.find_node("name", &"Trinity".into()), None);
+
+    let old = g[keanu].set("born", PropertyValue::Null);
+    assert_eq!(old, Some(PropertyValue::Int(1964)));
+    assert!(g[keanu].get("born").unwrap().is_null());
+
+    let acted = g
+        .edges(keanu)
+        .find(|e| e.weight().label == "ACTED_IN" && e.weight().get("roles").is_some());
+    let roles = acted.unwrap().weight().get("roles").unwrap();
+    assert_eq!(roles.as_list().unwrap()[0].as_str(), Some("Neo"));
+    assert_eq!(roles.to_string(), r#"["Neo"]"#);
+
+    let matrix = g.find_node("title", &"The Matrix".into()).unwrap();
+    assert_eq!(
+        g[matrix].get("rating").and_then(PropertyValue::as_float),
+        Some(8.7)
+    );
+    assert_eq!(PropertyValue::from(None::<i32>), PropertyValue::Null);
+}
+
+#[test]
+fn node_views() {
+    let g = movies();
+    let movies = g.nodes_labeled("Movie");
+    let titles: Vec<_> = (&movies)
+        .node_references()
+        .map(|(_, node)| node.get("title").unwrap().as_str().unwrap())
+        .collect();
+    assert_eq!(titles, ["The Matrix", "Speed"]);
+    assert_eq!((&movies).edge_references().count(), 0);
+
+    let people = g.nodes_labeled("Person");
+    let edges: Vec<_> = (&people)
+        .edge_references()
+        .map(|e| e.weight().label.as_str())
+        .collect();
+    assert_eq!(edges, ["KNOWS"]);
+
+    let lana = g.nodes_with_property("name", &"Lana".into());
+    assert_eq!((&lana).node_references().count(), 1);
+}
+
+#[test]
+fn edge_views() {
+    let g = movies();
+    let acted = g.edges_labeled("ACTED_IN");
+    assert_eq!((&acted).edge_references().count(), 2);
+
+    let weighted = g.edges_where(|edge| edge.get("weight").is_some());
+    let lana = g.find_node("name", &"Lana".into()).unwrap();
+    let costs = dijkstra(&weighted, lana, None, |e| {
+        e.weight().get("weight").unwrap().as_int().unwrap()
+    });
+    assert_eq!(costs.len(), 2);
+
+    let mut un = UnPropertyGraph::<u32>::default();
+    let a = un.add_node(PropertyNode::new(["A"]));
+    let b = un.add_node(PropertyNode::new(["B"]));
+    let c = un.add_node(PropertyNode::new(["A"]));
+    un.add_edge(a, b, PropertyEdge::new("X"));
+    un.add_edge(b, c, PropertyEdge::new("Y"));
+    assert_eq!(connected_components(&un.edges_labeled("X")), 2);
+}
@@ -25,7 +25,9 @@ use hashbrown::{HashMap, HashSet};
 use itertools::assert_equal;
 use itertools::cloned;
 use quickcheck::{Arbitrary, Gen};
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
 
 #[cfg(feature = "stable_graph")]
 use petgraph::algo::steiner_tree;
@@ -271,13 +273,13 @@ fn stable_graph_retain_edges() {
 fn isomorphism_1() {
     // using small weights so that duplicates are likely
     fn prop<Ty: EdgeType>(g: Small<Graph<i8, i8, Ty>>) -> bool {
-        let mut rng = rand::thread_rng();
+        let mut rng = StdRng::seed_from_u64(0);
         // several trials of different isomorphisms of the same graph
         // mapping of node indices
         let mut map = g.node_indices().collect::<Vec<_>>();
         let mut ng = Graph::<_, _, Ty>::with_capacity(g.node_count(), g.edge_count());
         for _ in 0..1 {
-            rng.shuffle(&mut map);
+            map.shuffle(&mut rng);
             ng.clear();
 
             for _ in g.node_indices() {
@@ -573,6 +575,14 @@ fn graph_condensation_acyclic() {
     quickcheck::quickcheck(prop as fn(_) -> bool);
 }
 
+/// Return a random float in the range [0, 1.)
+fn random_01<G: Gen>(g: &mut G) -> f64 {
+    let bits = 53;
+    let scale = 1. / ((1u64 << bits) as f64);
+    let x: u64 = g.next_u64();
+    (x >> (64 - bits)) as f64 * scale
+}
+
 #[derive(Debug, Clone)]
 struct Dag<N: Default + Clone + Send + 'static>(Graph<N, ()>);
 
@@ -582,23 +592,23 @@ impl<N: Default + Clone + Send + 'static> Arbitrary for Dag<N> {
         if nodes == 0 {
             return Dag(Graph::with_capacity(0, 0));
         }
-        let split = g.gen_range(0., 1.);
+        let split = random_01(g);
         let max_width = f64::sqrt(nodes as f64) as usize;
         let tall = (max_width as f64 * split) as usize;
         let fat = max_width - tall;
 
-        let edge_prob = 1. - (1. - g.gen_range(0., 1.)) * (1. - g.gen_range(0., 1.));
+        let edge_prob = 1. - (1. - random_01(g)) * (1. - random_01(g));
         let edges = ((nodes as f64).powi(2) * edge_prob) as usize;
         let mut gr = Graph::with_capacity(nodes, edges);
         let mut nodes = 0;
         for _ in 0..tall {
-            let cur_nodes = g.gen_range(0, fat);
+            let cur_nodes = (random_01(g) * fat as f64) as usize;
             for _ in 0..cur_nodes {
                 gr.add_node(N::default());
             }
             for j in 0..nodes {
                 for k in 0..cur_nodes {
-                    if g.gen_range(0., 1.) < edge_prob {
+                    if random_01(g) < edge_prob {
                         gr.add_edge(NodeIndex::new(j), NodeIndex::new(k + nodes), ());
                     }
                 }
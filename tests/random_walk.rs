@@ -0,0 +1,95 @@
+#![cfg(feature = "rand")]
+
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::visit::{random_walk_sample, Node2VecWalk, RandomWalk};
+use petgraph::SeededRng;
+
+#[test]
+fn random_walk_frequencies() {
+    // On a connected undirected graph, a long walk visits each node in
+    // proportion to its (weighted) degree.
+    let graph = UnGraph::<(), f64>::from_edges([
+        (0, 1, 1.0),
+        (1, 2, 1.0),
+        (2, 0, 1.0),
+        (2, 3, 3.0),
+        (3, 4, 1.0),
+    ]);
+    let degrees = [2.0, 2.0, 5.0, 4.0, 1.0];
+    let total: f64 = degrees.iter().sum();
+    let steps = 200_000;
+    let mut rng = SeededRng::new(59);
+    let mut visits = [0usize; 5];
+    for v in RandomWalk::weighted(&graph, NodeIndex::new(0), &mut rng, |e| *e.weight()).take(steps)
+    {
+        visits[v.index()] += 1;
+    }
+    for (v, &count) in visits.iter().enumerate() {
+        let frequency = count as f64 / steps as f64;
+        assert!(
+            (frequency - degrees[v] / total).abs() < 0.01,
+            "{v}: {frequency}"
+        );
+    }
+
+    // A walk ends at a node without outgoing edges.
+    let graph = petgraph::graph::DiGraph::<(), ()>::from_edges([(0, 1), (1, 2)]);
+    let walk: Vec<_> = RandomWalk::new(&graph, NodeIndex::new(0), &mut rng).collect();
+    assert_eq!(walk, [0, 1, 2].map(NodeIndex::new));
+}
+
+#[test]
+fn node2vec_bias() {
+    // A cycle of 8 nodes with a chord 0 - 4: with a huge return parameter,
+    // the walk never goes back to the node it came from.
+    let mut edges: Vec<(u32, u32)> = (0..8).map(|i| (i, (i + 1) % 8)).collect();
+    edges.push((0, 4));
+    let graph = UnGraph::<(), ()>::from_edges(&edges);
+    let mut rng = SeededRng::new(1);
+    let walk: Vec<_> = Node2VecWalk::new(&graph, NodeIndex::new(0), 1e9, 1.0, &mut rng)
+        .take(1000)
+        .collect();
+    assert!(walk.windows(3).all(|w| w[0] != w[2]));
+    assert!(walk.windows(2).all(|w| graph.contains_edge(w[0], w[1])));
+
+    // With a tiny return parameter, it almost always does.
+    let walk: Vec<_> = Node2VecWalk::new(&graph, NodeIndex::new(0), 1e-9, 1.0, &mut rng)
+        .take(1000)
+        .collect();
+    let returns = walk.windows(3).filter(|w| w[0] == w[2]).count();
+    assert!(returns > 990);
+
+    // In a triangle with a pendant node, a large in-out parameter keeps the
+    // walk in the triangle: from 1, coming from 0, the node 2 is a common
+    // neighbor and 3 is not.
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 0), (1, 3)]);
+    let mut outside = 0;
+    for _ in 0..1000 {
+        let mut walk = Node2VecWalk::new(&graph, NodeIndex::new(0), 1e9, 100.0, &mut rng);
+        walk.next();
+        if walk.next() == Some(NodeIndex::new(1)) && walk.next() == Some(NodeIndex::new(3)) {
+            outside += 1;
+        }
+    }
+    assert!(outside < 30, "{outside}");
+}
+
+#[test]
+fn walk_sample() {
+    let edges: Vec<(u32, u32)> = (0..50).map(|i| (i, (i + 1) % 50)).collect();
+    let mut graph = UnGraph::<(), ()>::from_edges(&edges);
+    let far = graph.add_node(());
+    let mut rng = SeededRng::new(4);
+    let sample = random_walk_sample(&graph, NodeIndex::new(0), 20, 0.1, &mut rng);
+    assert_eq!(sample.len(), 20);
+    assert_eq!(sample[0], NodeIndex::new(0));
+    let mut sorted = sample.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), 20);
+    assert!(!sample.contains(&far));
+
+    // Only the reachable nodes are sampled.
+    let sample = random_walk_sample(&graph, far, 5, 0.1, &mut rng);
+    assert_eq!(sample, [far]);
+}
@@ -0,0 +1,100 @@
+use petgraph::algo::rcm::{bandwidth, cuthill_mckee, rcm, reindexed};
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+use petgraph::visit::EdgeRef;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// A grid of `rows` by `columns` nodes, numbered in a random order.
+fn shuffled_grid(rng: &mut StdRng, rows: usize, columns: usize) -> UnGraph<usize, ()> {
+    let mut labels: Vec<usize> = (0..rows * columns).collect();
+    labels.shuffle(rng);
+    let mut graph = UnGraph::new_undirected();
+    for i in 0..rows * columns {
+        graph.add_node(i);
+    }
+    for r in 0..rows {
+        for c in 0..columns {
+            let v = NodeIndex::new(labels[r * columns + c]);
+            if c + 1 < columns {
+                graph.add_edge(v, NodeIndex::new(labels[r * columns + c + 1]), ());
+            }
+            if r + 1 < rows {
+                graph.add_edge(v, NodeIndex::new(labels[(r + 1) * columns + c]), ());
+            }
+        }
+    }
+    graph
+}
+
+#[test]
+fn rcm_reduces_bandwidth() {
+    let mut rng = StdRng::seed_from_u64(33);
+    let graph = shuffled_grid(&mut rng, 10, 40);
+    assert!(bandwidth(&graph, graph.node_indices()) > 100);
+
+    let order = rcm(&graph);
+    let mut sorted = order.clone();
+    sorted.sort();
+    assert_eq!(sorted, graph.node_indices().collect::<Vec<_>>());
+    // The bandwidth of a grid is its smaller side.
+    assert!(bandwidth(&graph, order.iter().copied()) <= 11);
+
+    let mut reverse = cuthill_mckee(&graph);
+    reverse.reverse();
+    assert_eq!(reverse, order);
+}
+
+#[test]
+fn rcm_components_and_reindexing() {
+    let mut rng = StdRng::seed_from_u64(34);
+    for _ in 0..50 {
+        let n = rng.gen_range(0..40);
+        let mut graph = DiGraph::<usize, u32>::new();
+        for i in 0..n {
+            graph.add_node(i);
+        }
+        for i in 0..n * 2 {
+            if n > 0 {
+                let a = NodeIndex::new(rng.gen_range(0..n));
+                let b = NodeIndex::new(rng.gen_range(0..n));
+                graph.add_edge(a, b, i as u32);
+            }
+        }
+        let order = rcm(&graph);
+        assert_eq!(order.len(), n);
+
+        let copy = reindexed(&graph, &order);
+        assert_eq!(copy.node_count(), n);
+        assert_eq!(copy.edge_count(), graph.edge_count());
+        for (i, &v) in order.iter().enumerate() {
+            assert_eq!(copy[NodeIndex::new(i)], graph[v]);
+        }
+        let mut position = vec![0; n];
+        for (i, v) in order.iter().enumerate() {
+            position[v.index()] = i;
+        }
+        let mut expected: Vec<_> = graph
+            .edge_references()
+            .map(|e| {
+                let (a, b) = (position[e.source().index()], position[e.target().index()]);
+                (a, b, *e.weight())
+            })
+            .collect();
+        let mut actual: Vec<_> = copy
+            .edge_references()
+            .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+            .collect();
+        expected.sort_unstable();
+        // The edges of the copy are sorted by their endpoints.
+        assert!(actual
+            .windows(2)
+            .all(|w| (w[0].0, w[0].1) <= (w[1].0, w[1].1)));
+        actual.sort_unstable();
+        assert_eq!(actual, expected);
+        assert_eq!(
+            bandwidth(&copy, copy.node_indices()),
+            bandwidth(&graph, order.iter().copied())
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use petgraph::algo::{connected_components, is_cyclic_directed};
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+use petgraph::repair::{make_acyclic, make_connected, make_simple};
+
+#[test]
+fn connect_and_break_cycles() {
+    let mut graph = DiGraph::<(), u32>::from_edges([(1, 2, 1), (3, 4, 1), (4, 3, 1)]);
+    graph.add_node(());
+    let report = make_connected(&mut graph, |a, b| (10 * a.index() + b.index()) as u32);
+    assert!(report.removed.is_empty());
+    // Node 0 is linked to the first node of each other component.
+    let added: Vec<_> = report
+        .added
+        .iter()
+        .map(|&e| (graph.edge_endpoints(e).unwrap(), graph[e]))
+        .collect();
+    let n = NodeIndex::new;
+    assert_eq!(
+        added,
+        [((n(0), n(1)), 1), ((n(0), n(3)), 3), ((n(0), n(5)), 5)]
+    );
+    assert_eq!(connected_components(&graph), 1);
+    assert!(make_connected(&mut graph, |_, _| 0).is_empty());
+
+    graph.add_edge(n(2), n(2), 7);
+    let report = make_acyclic(&mut graph);
+    assert!(!is_cyclic_directed(&graph));
+    assert!(report.removed.contains(&(n(2), n(2), 7)));
+    assert_eq!(graph.edge_count(), 6 + 1 - report.removed.len());
+    assert!(make_acyclic(&mut graph).is_empty());
+
+    let mut empty = UnGraph::<(), ()>::new_undirected();
+    assert!(make_connected(&mut empty, |_, _| ()).is_empty());
+}
+
+#[test]
+fn merge_parallel_edges() {
+    let mut graph = DiGraph::<(), Vec<u32>>::new();
+    let n: Vec<_> = (0..3).map(|_| graph.add_node(())).collect();
+    for (i, (a, b)) in [(0, 1), (1, 0), (0, 1), (1, 2), (2, 2), (0, 1), (1, 2)]
+        .into_iter()
+        .enumerate()
+    {
+        graph.add_edge(n[a], n[b], vec![i as u32]);
+    }
+    let report = make_simple(&mut graph, |total, weight| total.extend_from_slice(weight));
+    // The report lists the removed edges in their original order.
+    let removed: Vec<_> = report
+        .removed
+        .iter()
+        .map(|(a, b, w)| (a.index(), b.index(), w[0]))
+        .collect();
+    assert_eq!(removed, [(0, 1, 2), (2, 2, 4), (0, 1, 5), (1, 2, 6)]);
+    assert_eq!(graph.edge_count(), 3);
+    // The weights are merged in the order of the edges.
+    assert_eq!(graph[graph.find_edge(n[0], n[1]).unwrap()], [0, 2, 5]);
+    assert_eq!(graph[graph.find_edge(n[1], n[0]).unwrap()], [1]);
+    assert_eq!(graph[graph.find_edge(n[1], n[2]).unwrap()], [3, 6]);
+
+    // Undirected parallel edges can go either way.
+    let mut graph = UnGraph::<(), u32>::from_edges([(0, 1, 1), (1, 0, 2), (1, 2, 4)]);
+    let report = make_simple(&mut graph, |total, weight| *total += weight);
+    assert_eq!(report.removed.len(), 1);
+    assert_eq!(graph.edge_weights().copied().collect::<Vec<_>>(), [3, 4]);
+}
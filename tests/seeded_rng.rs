@@ -0,0 +1,49 @@
+#![cfg(feature = "rand")]
+
+use petgraph::SeededRng;
+use rand::{Rng, RngCore, SeedableRng};
+
+#[test]
+fn seeded_rng_is_reproducible() {
+    let mut a = SeededRng::new(7);
+    let mut b = SeededRng::seed_from_u64(7);
+    let xs: Vec<u64> = (0..100).map(|_| a.next_u64()).collect();
+    let ys: Vec<u64> = (0..100).map(|_| b.next_u64()).collect();
+    assert_eq!(xs, ys);
+
+    let mut c = SeededRng::new(8);
+    assert_ne!(xs[0], c.next_u64());
+}
+
+#[test]
+fn seeded_rng_known_output() {
+    // The output stream is fixed, so that results are reproducible across
+    // platforms and versions.
+    let mut rng = SeededRng::new(0);
+    let xs: Vec<u64> = (0..3).map(|_| rng.next_u64()).collect();
+    assert_eq!(
+        xs,
+        vec![
+            5987356902031041503,
+            7051070477665621255,
+            6633766593972829180
+        ]
+    );
+}
+
+#[test]
+fn seeded_rng_from_seed() {
+    let mut seed = [0; 32];
+    seed[0] = 1;
+    let mut rng = SeededRng::from_seed(seed);
+    let mut bytes = [0u8; 13];
+    rng.fill_bytes(&mut bytes);
+    assert!(bytes.iter().any(|&b| b != 0));
+
+    // The all-zero state is replaced by a valid one.
+    let mut zero = SeededRng::from_seed([0; 32]);
+    assert_eq!(zero.next_u64(), SeededRng::new(0).next_u64());
+
+    let x: f64 = rng.gen();
+    assert!((0.0..1.0).contains(&x));
+}
@@ -0,0 +1,91 @@
+use petgraph::algo::{dijkstra, shortest_path_dag};
+use petgraph::visit::EdgeRef;
+use petgraph::{Graph, Undirected};
+
+#[test]
+fn shortest_path_dag_matches_dijkstra() {
+    let mut graph = Graph::<(), u32>::new();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    graph.extend_with_edges([
+        (nodes[0], nodes[1], 2),
+        (nodes[0], nodes[2], 1),
+        (nodes[2], nodes[1], 1),
+        (nodes[1], nodes[3], 3),
+        (nodes[2], nodes[3], 4),
+        (nodes[3], nodes[4], 1),
+        (nodes[4], nodes[0], 1),
+    ]);
+
+    let dag = shortest_path_dag(&graph, nodes[0], None, |e| *e.weight());
+    let distances = dijkstra(&graph, nodes[0], None, |e| *e.weight());
+    assert_eq!(dag.source(), nodes[0]);
+    assert_eq!(dag.nodes().len(), distances.len());
+    for (&node, &dist) in &distances {
+        assert_eq!(dag.distance(node), Some(dist));
+    }
+    assert_eq!(dag.distance(nodes[5]), None);
+
+    // Every DAG edge is tight.
+    for (source, target, edge) in dag.edges() {
+        assert_eq!(graph.edge_endpoints(edge), Some((source, target)));
+        assert_eq!(
+            distances[&source] + graph[edge],
+            distances[&target],
+            "edge {:?} is not on a shortest path",
+            edge
+        );
+    }
+    // Every tight edge is in the DAG.
+    let tight = graph
+        .edge_references()
+        .filter(
+            |e| match (distances.get(&e.source()), distances.get(&e.target())) {
+                (Some(&s), Some(&t)) => s + e.weight() == t,
+                _ => false,
+            },
+        )
+        .count();
+    assert_eq!(dag.edges().count(), tight);
+
+    assert_eq!(dag.count_paths(nodes[1]), 2);
+    assert_eq!(dag.count_paths(nodes[3]), 3);
+    assert_eq!(dag.count_paths(nodes[5]), 0);
+    assert_eq!(dag.paths_to(nodes[3], None).len(), 3);
+}
+
+#[test]
+fn shortest_path_dag_bfs_to_graph() {
+    // Unweighted undirected 4-cycle: two shortest paths to the opposite node.
+    let graph: Graph<(), (), Undirected> = Graph::from_edges([(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let start = 0.into();
+    let dag = shortest_path_dag(&graph, start, None, |_| 1);
+    assert_eq!(dag.count_paths(2.into()), 2);
+
+    let dag_graph = dag.to_graph();
+    assert_eq!(dag_graph.node_count(), 4);
+    assert_eq!(dag_graph.edge_count(), 4);
+    assert_eq!(dag_graph[petgraph::graph::NodeIndex::new(0)], start);
+    assert!(!petgraph::algo::is_cyclic_directed(&dag_graph));
+}
+
+#[test]
+fn shortest_path_dag_parallel_edges() {
+    let mut graph = Graph::<(), u32>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.extend_with_edges([(a, b, 1), (a, b, 1), (b, c, 1)]);
+
+    let dag = shortest_path_dag(&graph, a, None, |e| *e.weight());
+    assert_eq!(dag.predecessor_edges(b).len(), 2);
+    assert_eq!(dag.count_paths(c), 1);
+    assert_eq!(dag.paths_to(c, None), vec![vec![a, b, c]]);
+}
+
+#[test]
+fn shortest_path_dag_goal() {
+    let graph = Graph::<(), u32>::from_edges([(0, 1, 1), (1, 2, 1), (2, 3, 1)]);
+    let dag = shortest_path_dag(&graph, 0.into(), Some(1.into()), |e| *e.weight());
+    assert_eq!(dag.distance(1.into()), Some(1));
+    assert_eq!(dag.distance(3.into()), None);
+}
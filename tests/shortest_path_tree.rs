@@ -0,0 +1,71 @@
+use petgraph::algo::{dijkstra, shortest_path_tree};
+use petgraph::graph::{DiGraph, NodeIndex};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableGraph;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[test]
+fn shortest_path_tree_matches_dijkstra() {
+    let mut rng = StdRng::seed_from_u64(34);
+    for _ in 0..50 {
+        let n = rng.gen_range(1..60);
+        let mut graph = DiGraph::<(), u32>::new();
+        for _ in 0..n {
+            graph.add_node(());
+        }
+        for _ in 0..rng.gen_range(0..4 * n) {
+            let a = NodeIndex::new(rng.gen_range(0..n));
+            let b = NodeIndex::new(rng.gen_range(0..n));
+            graph.add_edge(a, b, rng.gen_range(0..20));
+        }
+        let start = NodeIndex::new(rng.gen_range(0..n));
+        let tree = shortest_path_tree(&graph, start, |e| *e.weight());
+        let expected = dijkstra(&graph, start, None, |e| *e.weight());
+        assert_eq!(tree.len(), n);
+        assert_eq!(tree.source_index(), start.index());
+        assert_eq!(tree.parent(start.index()), None);
+
+        for v in graph.node_indices() {
+            assert_eq!(tree.distance(v.index()), expected.get(&v).copied());
+            let mut path: Vec<_> = tree.path(&graph, v).collect();
+            if !tree.is_reached(v.index()) {
+                assert!(path.is_empty());
+                continue;
+            }
+            path.reverse();
+            assert_eq!(path[0], start);
+            assert_eq!(*path.last().unwrap(), v);
+            let cost: u32 = path
+                .windows(2)
+                .map(|w| {
+                    graph
+                        .edges_connecting(w[0], w[1])
+                        .map(|e| *e.weight())
+                        .min()
+                        .unwrap()
+                })
+                .sum();
+            assert_eq!(Some(cost), tree.distance(v.index()));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn shortest_path_tree_with_holes() {
+    let mut graph = StableGraph::<(), f64>::new();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    graph.add_edge(nodes[0], nodes[2], 1.5);
+    graph.add_edge(nodes[2], nodes[4], 0.5);
+    graph.add_edge(nodes[0], nodes[1], 1.0);
+    graph.remove_node(nodes[1]);
+    graph.remove_node(nodes[3]);
+
+    let tree = shortest_path_tree(&graph, nodes[0], |e| *e.weight());
+    assert!(!tree.is_reached(1));
+    assert_eq!(tree.distance(4), Some(2.0));
+    assert_eq!(tree.path_indices(4).collect::<Vec<_>>(), vec![4, 2, 0]);
+    assert_eq!(tree.path_indices(3).count(), 0);
+    assert_eq!(tree.path_indices(17).count(), 0);
+}
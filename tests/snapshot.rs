@@ -0,0 +1,152 @@
+use petgraph::csr::Csr;
+use petgraph::graph::{DiGraph, UnGraph};
+use petgraph::io::snapshot::{SnapshotError, SnapshotView, SNAPSHOT_VERSION};
+#[cfg(feature = "stable_graph")]
+use petgraph::stable_graph::StableGraph;
+use petgraph::visit::EdgeRef;
+use petgraph::Undirected;
+
+#[test]
+fn graph_round_trip() {
+    let mut graph = UnGraph::<(u32, Option<char>), Vec<f64>>::new_undirected();
+    let a = graph.add_node((1, Some('a')));
+    let b = graph.add_node((2, None));
+    graph.add_edge(a, b, vec![0.5, 1.5]);
+    graph.add_edge(b, b, vec![]);
+
+    let bytes = graph.to_snapshot();
+    let copy = UnGraph::<(u32, Option<char>), Vec<f64>>::from_snapshot(&bytes).unwrap();
+    assert_eq!(
+        copy.node_weights().collect::<Vec<_>>(),
+        [&(1, Some('a')), &(2, None)]
+    );
+    let edges: Vec<_> = copy
+        .edge_references()
+        .map(|edge| {
+            (
+                edge.source().index(),
+                edge.target().index(),
+                edge.weight().clone(),
+            )
+        })
+        .collect();
+    assert_eq!(edges, [(0, 1, vec![0.5, 1.5]), (1, 1, vec![])]);
+
+    assert_eq!(
+        DiGraph::<(u32, Option<char>), Vec<f64>>::from_snapshot(&bytes).unwrap_err(),
+        SnapshotError::EdgeTypeMismatch
+    );
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn stable_graph_keeps_node_indices() {
+    use petgraph::graph::NodeIndex;
+
+    let mut graph = StableGraph::<&str, u8>::new();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    graph.add_edge(a, d, 1);
+    graph.add_edge(c, a, 2);
+    graph.remove_node(b);
+
+    let bytes = graph.to_snapshot();
+    let view = SnapshotView::new(&bytes).unwrap();
+    assert_eq!(
+        (view.node_bound(), view.node_count(), view.edge_count()),
+        (4, 3, 2)
+    );
+    assert_eq!(view.node_indices().collect::<Vec<_>>(), [0, 2, 3]);
+    assert_eq!(view.edge_endpoints().collect::<Vec<_>>(), [(0, 3), (2, 0)]);
+
+    let mut copy = StableGraph::<&str, u8>::from_snapshot(&bytes).unwrap();
+    assert_eq!(copy[c], "c");
+    assert_eq!(copy[d], "d");
+    assert!(!copy.contains_node(b));
+    assert_eq!(copy.find_edge(c, a).map(|edge| copy[edge]), Some(2));
+    // The vacant node is reused.
+    assert_eq!(copy.add_node("e"), b);
+
+    // The vacant nodes are bounded by the length of the snapshot.
+    let mut sparse = StableGraph::<(), ()>::new();
+    sparse.add_node(());
+    let mut sparse_bytes = sparse.to_snapshot();
+    sparse_bytes[10..18].copy_from_slice(&3_000_000_000u64.to_le_bytes());
+    sparse_bytes[34..38].copy_from_slice(&2_999_999_999u32.to_le_bytes());
+    assert_eq!(
+        SnapshotView::new(&sparse_bytes).unwrap().node_bound(),
+        3_000_000_000
+    );
+    assert!(matches!(
+        StableGraph::<(), ()>::from_snapshot(&sparse_bytes).unwrap_err(),
+        SnapshotError::Invalid(_)
+    ));
+    assert_eq!(
+        DiGraph::<(), ()>::from_snapshot(&sparse_bytes)
+            .unwrap()
+            .node_count(),
+        1
+    );
+
+    // A `Graph` compacts the node indices.
+    let compact = DiGraph::<&str, u8>::from_snapshot(&bytes).unwrap();
+    assert_eq!(compact[NodeIndex::new(2)], "d");
+    assert_eq!(compact.edge_count(), 2);
+}
+
+#[test]
+fn csr_round_trip() {
+    let mut graph: Csr<u8, u16, Undirected> = Csr::new();
+    let a = graph.add_node(1);
+    let b = graph.add_node(2);
+    let c = graph.add_node(3);
+    graph.add_edge(a, b, 10);
+    graph.add_edge(c, b, 20);
+    graph.add_edge(c, c, 30);
+
+    let bytes = graph.to_snapshot();
+    assert_eq!(SnapshotView::new(&bytes).unwrap().edge_count(), 3);
+    let copy = Csr::<u8, u16, Undirected>::from_snapshot(&bytes).unwrap();
+    assert_eq!(copy.edge_count(), 3);
+    assert_eq!(copy.neighbors_slice(b), [a, c]);
+    assert_eq!(copy.edges_slice(b), [10, 20]);
+    assert_eq!(copy[c], 3);
+
+    let from_graph = UnGraph::<u8, u16>::from_snapshot(&bytes).unwrap();
+    assert_eq!(from_graph.edge_count(), 3);
+}
+
+#[test]
+fn invalid_snapshots() {
+    let graph = DiGraph::<String, ()>::from_edges([(0, 1)]);
+    let mut bytes = graph.to_snapshot();
+    assert_eq!(
+        DiGraph::<String, ()>::from_snapshot(&bytes[..bytes.len() - 1]).unwrap_err(),
+        SnapshotError::UnexpectedEnd
+    );
+    assert_eq!(
+        SnapshotView::new(b"not a snapshot").unwrap_err(),
+        SnapshotError::InvalidMagic
+    );
+    bytes[6..8].copy_from_slice(&(SNAPSHOT_VERSION + 1).to_le_bytes());
+    assert_eq!(
+        SnapshotView::new(&bytes).unwrap_err(),
+        SnapshotError::UnsupportedVersion(SNAPSHOT_VERSION + 1)
+    );
+
+    // The node bound of the header must match the node indices.
+    for node_bound in [0, 1, 3, 5_000_000_000_000_000, u64::MAX] {
+        let mut bytes = graph.to_snapshot();
+        bytes[10..18].copy_from_slice(&node_bound.to_le_bytes());
+        assert!(matches!(
+            SnapshotView::new(&bytes).unwrap_err(),
+            SnapshotError::Invalid(_)
+        ));
+        assert!(matches!(
+            DiGraph::<String, ()>::from_snapshot(&bytes).unwrap_err(),
+            SnapshotError::Invalid(_)
+        ));
+    }
+}
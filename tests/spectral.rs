@@ -0,0 +1,186 @@
+#![cfg(feature = "linalg")]
+
+use petgraph::algo::spectral::{
+    effective_resistance, fiedler_vector, resistance_matrix, LanczosConfig, LaplacianKind,
+};
+use petgraph::graph::{NodeIndex, UnGraph};
+#[cfg(feature = "rand")]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A G(n, m) random graph with edge weights between 0.5 and 2.
+#[cfg(feature = "rand")]
+fn random_graph(rng: &mut StdRng, n: usize, m: usize) -> UnGraph<(), f64> {
+    let mut graph: UnGraph<(), f64> = petgraph::generators::gnm_random_graph(n, m, rng);
+    for weight in graph.edge_weights_mut() {
+        *weight = rng.gen_range(0.5..2.0);
+    }
+    graph
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn laplacian_is_symmetric_with_zero_row_sums() {
+    use petgraph::algo::spectral::laplacian_matrix;
+
+    let mut rng = StdRng::seed_from_u64(36);
+    for _ in 0..20 {
+        let graph = random_graph(&mut rng, 12, 25);
+        let laplacian = laplacian_matrix(&graph, LaplacianKind::Combinatorial, |e| *e.weight());
+        let dense = laplacian.to_dense();
+        for (i, row) in dense.iter().enumerate() {
+            assert!(row.iter().sum::<f64>().abs() < 1e-9);
+            for (j, &value) in row.iter().enumerate() {
+                assert_eq!(value, dense[j][i]);
+                assert_eq!(value, laplacian.get(i, j));
+            }
+        }
+
+        // The normalized Laplacian has a unit diagonal for non-isolated
+        // nodes, and annihilates the square roots of the degrees.
+        let normalized = laplacian_matrix(&graph, LaplacianKind::Normalized, |e| *e.weight());
+        let roots: Vec<f64> = (0..12).map(|v| dense[v][v].sqrt()).collect();
+        for (v, value) in normalized.mul_vec(&roots).into_iter().enumerate() {
+            assert!(value.abs() < 1e-9);
+            let diagonal = normalized.get(v, v);
+            assert!(if dense[v][v] > 0.0 {
+                (diagonal - 1.0).abs() < 1e-12
+            } else {
+                diagonal == 0.0
+            });
+        }
+    }
+}
+
+#[test]
+fn fiedler_vector_of_known_graphs() {
+    let config = LanczosConfig::default();
+    // The path on n nodes has algebraic connectivity 2 - 2cos(π/n).
+    for n in [2, 3, 10, 60] {
+        let edges: Vec<(u32, u32)> = (1..n).map(|i| (i - 1, i)).collect();
+        let graph = UnGraph::<(), ()>::from_edges(&edges);
+        let fiedler =
+            fiedler_vector(&graph, LaplacianKind::Combinatorial, |_| 1.0, &config).unwrap();
+        let expected = 2.0 - 2.0 * (core::f64::consts::PI / n as f64).cos();
+        assert!(
+            (fiedler.value - expected).abs() < 1e-7,
+            "{n}: {}",
+            fiedler.value
+        );
+        assert!(fiedler.residual < 1e-6);
+        assert!(fiedler.vector.iter().sum::<f64>().abs() < 1e-9);
+    }
+
+    // A disconnected graph has algebraic connectivity 0, and the vector
+    // separates the components.
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (3, 4)]);
+    let fiedler = fiedler_vector(&graph, LaplacianKind::Normalized, |_| 1.0, &config).unwrap();
+    assert!(fiedler.value.abs() < 1e-9);
+    let v = &fiedler.vector;
+    assert!(v[0] * v[3] < 0.0);
+
+    // The complete graph on n nodes has eigenvalue n, or n / (n - 1)
+    // normalized.
+    let mut complete = UnGraph::<(), ()>::new_undirected();
+    let nodes: Vec<_> = (0..7).map(|_| complete.add_node(())).collect();
+    for (i, &a) in nodes.iter().enumerate() {
+        for &b in &nodes[i + 1..] {
+            complete.add_edge(a, b, ());
+        }
+    }
+    let fiedler =
+        fiedler_vector(&complete, LaplacianKind::Combinatorial, |_| 1.0, &config).unwrap();
+    assert!((fiedler.value - 7.0).abs() < 1e-9);
+    let fiedler = fiedler_vector(&complete, LaplacianKind::Normalized, |_| 1.0, &config).unwrap();
+    assert!((fiedler.value - 7.0 / 6.0).abs() < 1e-9);
+
+    let single = UnGraph::<(), ()>::from_edges([(0, 0)]);
+    assert!(fiedler_vector(&single, LaplacianKind::Combinatorial, |_| 1.0, &config).is_none());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn bisection_recovers_planted_clusters() {
+    use petgraph::algo::spectral::spectral_bisection;
+    use petgraph::generators::stochastic_block_model;
+    use petgraph::visit::EdgeRef;
+
+    let mut rng = StdRng::seed_from_u64(1323);
+    let config = LanczosConfig::default();
+    for kind in [LaplacianKind::Combinatorial, LaplacianKind::Normalized] {
+        for _ in 0..5 {
+            // Two dense clusters of 30 nodes with a few edges between them.
+            let probabilities = [[0.4, 0.01], [0.01, 0.4]];
+            let (graph, _): (UnGraph<(), ()>, _) =
+                stochastic_block_model(&[30, 30], &probabilities, &mut rng);
+            let (first, second) = spectral_bisection(&graph, kind, |_| 1.0, &config);
+            assert_eq!((first.len(), second.len()), (30, 30));
+            let side = first[0].index() < 30;
+            assert!(first.iter().all(|v| (v.index() < 30) == side));
+            assert!(second.iter().all(|v| (v.index() < 30) != side));
+
+            let cut = graph
+                .edge_references()
+                .filter(|e| first.contains(&e.source()) != first.contains(&e.target()))
+                .count();
+            assert!(cut < 30);
+        }
+    }
+}
+
+#[test]
+fn effective_resistances() {
+    // Two nodes of the complete graph on n nodes are at resistance 2 / n.
+    let n = 6;
+    let mut edges = Vec::new();
+    for a in 0..n {
+        for b in a + 1..n {
+            edges.push((a, b));
+        }
+    }
+    let graph = UnGraph::<(), ()>::from_edges(&edges);
+    let resistance = resistance_matrix(&graph, |_| 1.0);
+    let single = effective_resistance(&graph, NodeIndex::new(1), NodeIndex::new(4), |_| 1.0);
+    assert!((resistance[1][4] - 2.0 / n as f64).abs() < 1e-9);
+    assert!((single - 2.0 / n as f64).abs() < 1e-9);
+
+    // Both computations agree, and Foster's theorem holds: the weighted
+    // resistances of the edges of a connected graph add up to |V| - 1.
+    #[cfg(feature = "rand")]
+    {
+        use petgraph::visit::EdgeRef;
+
+        let mut rng = StdRng::seed_from_u64(58);
+        for _ in 0..10 {
+            let mut graph = random_graph(&mut rng, 10, 30);
+            for i in 1..10 {
+                graph.add_edge(NodeIndex::new(i - 1), NodeIndex::new(i), 1.0);
+            }
+            let resistance = resistance_matrix(&graph, |e| *e.weight());
+            let mut foster = 0.0;
+            for edge in graph.edge_references() {
+                let (a, b) = (edge.source().index(), edge.target().index());
+                foster += edge.weight() * resistance[a][b];
+            }
+            assert!((foster - 9.0).abs() < 1e-9, "{foster}");
+            for (a, row) in resistance.iter().enumerate() {
+                assert_eq!(row[a], 0.0);
+                for (b, &r) in row.iter().enumerate() {
+                    assert!((r - resistance[b][a]).abs() < 1e-9);
+                    let single =
+                        effective_resistance(&graph, NodeIndex::new(a), NodeIndex::new(b), |e| {
+                            *e.weight()
+                        });
+                    assert!((r - single).abs() < 1e-7);
+                }
+            }
+        }
+    }
+
+    // Nodes in different components are at infinite resistance.
+    let graph = UnGraph::<(), ()>::from_edges([(0, 1), (2, 3)]);
+    let resistance = resistance_matrix(&graph, |_| 1.0);
+    assert_eq!(resistance[0][1], 1.0);
+    assert_eq!(resistance[1][2], f64::INFINITY);
+    let single = effective_resistance(&graph, NodeIndex::new(0), NodeIndex::new(3), |_| 1.0);
+    assert_eq!(single, f64::INFINITY);
+}
@@ -493,3 +493,90 @@ fn weights_mut_iterator() {
     assert_eq!(gr.node_weights_mut().count(), gr.node_count());
     assert_eq!(gr.edge_weights_mut().count(), gr.edge_count());
 }
+
+#[test]
+fn flat_arrays() {
+    let mut g = StableGraph::<_, _>::new();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    let c = g.add_node("c");
+    let d = g.add_node("d");
+    g.add_edge(a, b, 1);
+    g.add_edge(b, c, 2);
+    g.add_edge(d, a, 3);
+    g.add_edge(c, d, 4);
+    g.remove_node(b);
+
+    // The nodes are renumbered without the hole left by `b`.
+    let flat = g.to_flat_arrays();
+    assert_eq!(flat.node_weights, ["a", "c", "d"]);
+    assert_eq!(flat.sources, [2, 1]);
+    assert_eq!(flat.targets, [0, 2]);
+    assert_eq!(flat.edge_weights, [3, 4]);
+
+    let copy = StableDiGraph::from_flat_arrays(flat.clone()).unwrap();
+    assert_eq!((copy.node_count(), copy.node_bound()), (3, 3));
+    assert_eq!(copy.to_flat_arrays(), flat);
+    assert!(copy.contains_edge(n(2), n(0)));
+
+    let mut bad = flat;
+    bad.targets[0] = 3;
+    assert!(StableDiGraph::from_flat_arrays(bad).is_err());
+}
+
+#[test]
+fn compact() {
+    let mut g = StableGraph::<_, _>::new();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    let c = g.add_node("c");
+    let d = g.add_node("d");
+    let ab = g.add_edge(a, b, 1);
+    let cd = g.add_edge(c, d, 2);
+    let da = g.add_edge(d, a, 3);
+    g.remove_node(b);
+    g.add_node("e");
+    let f = g.add_node("f");
+    g.remove_node(f);
+
+    let compaction = g.compact();
+    assert_eq!((g.node_count(), g.node_bound()), (4, 4));
+    assert_eq!(g.edge_bound(), 2);
+    assert_eq!(compaction.node(a), Some(n(0)));
+    assert_eq!(compaction.node(c), Some(n(2)));
+    assert_eq!(compaction.node(f), None);
+    assert_eq!(compaction.edge(ab), None);
+    assert_eq!(compaction.edge(cd), Some(e(0)));
+    let da = compaction.edge(da).unwrap();
+    assert_eq!(g[da], 3);
+    assert_eq!(g.edge_endpoints(da), Some((n(3), n(0))));
+    assert_eq!(g[n(1)], "e");
+
+    // The graph stays usable after the compaction.
+    let new = g.add_node("g");
+    assert_eq!(new, n(4));
+    g.add_edge(new, n(0), 4);
+    assert_eq!(g.neighbors_directed(n(0), Incoming).count(), 2);
+
+    let (graph, compaction) = g.clone().into_compact_graph();
+    assert_eq!(graph.node_count(), 5);
+    assert_eq!(compaction.node(n(4)), Some(n(4)));
+}
+
+#[test]
+fn adjacency_matrix_with_holes() {
+    use petgraph::visit::GetAdjacencyMatrix;
+
+    let mut g = StableGraph::<(), ()>::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    g.add_edge(b, c, ());
+    g.add_edge(c, a, ());
+    g.remove_node(a);
+
+    let matrix = g.adjacency_matrix();
+    assert!(g.is_adjacent(&matrix, b, c));
+    assert!(!g.is_adjacent(&matrix, c, b));
+    assert!(!g.is_adjacent(&matrix, c, a));
+}
@@ -0,0 +1,112 @@
+use petgraph::algo::{connected_components, dijkstra};
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::temporal::{TemporalGraph, TimeInterval};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+
+fn n(i: usize) -> NodeIndex {
+    NodeIndex::new(i)
+}
+
+/// A chain 0 → 1 → 2 → 3 with a slow shortcut 0 → 3.
+fn chain() -> TemporalGraph<(), u32, u32> {
+    let mut g = TemporalGraph::new();
+    for _ in 0..5 {
+        g.add_node(());
+    }
+    g.add_edge(n(0), n(1), TimeInterval::new(1, 2), 1);
+    g.add_edge(n(1), n(2), TimeInterval::new(2, 2), 1);
+    g.add_edge(n(2), n(3), TimeInterval::new(4, 5), 1);
+    g.add_edge(n(0), n(3), TimeInterval::new(3, 10), 1);
+    // Too early to be followed after 0 → 1.
+    g.add_edge(n(1), n(4), TimeInterval::new(0, 1), 1);
+    g
+}
+
+#[test]
+fn earliest_arrival() {
+    let g = chain();
+    let arrival = g.earliest_arrival(n(0), 0);
+    assert_eq!(arrival.len(), 4);
+    assert_eq!(arrival[&n(0)], 0);
+    assert_eq!(arrival[&n(1)], 2);
+    // An edge of no duration is followed at the time it is reached.
+    assert_eq!(arrival[&n(2)], 2);
+    assert_eq!(arrival[&n(3)], 5);
+    assert!(!arrival.contains_key(&n(4)));
+
+    let arrival = g.earliest_arrival(n(0), 2);
+    assert_eq!(arrival[&n(3)], 10);
+    assert!(!arrival.contains_key(&n(1)));
+}
+
+#[test]
+fn latest_departure() {
+    let g = chain();
+    let departure = g.latest_departure(n(3), 10);
+    assert_eq!(departure[&n(3)], 10);
+    assert_eq!(departure[&n(2)], 4);
+    assert_eq!(departure[&n(1)], 2);
+    assert_eq!(departure[&n(0)], 3);
+    assert!(!departure.contains_key(&n(4)));
+
+    let departure = g.latest_departure(n(3), 5);
+    assert_eq!(departure[&n(0)], 1);
+    assert!(g.latest_departure(n(3), 4).get(&n(0)).is_none());
+}
+
+#[test]
+fn undirected() {
+    let mut g = TemporalGraph::<(), (), u32, _>::new_undirected();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    g.add_edge(b, a, TimeInterval::new(1, 1), ());
+    g.add_edge(c, b, TimeInterval::new(3, 4), ());
+    let arrival = g.earliest_arrival(a, 0);
+    assert_eq!(arrival[&c], 4);
+    // The edges are followed in both directions, but not back in time.
+    let departure = g.latest_departure(a, 9);
+    assert_eq!(departure[&b], 1);
+    assert!(!departure.contains_key(&c));
+    assert_eq!(g.latest_departure(c, 9)[&a], 1);
+}
+
+#[test]
+fn snapshots() {
+    let mut g = chain();
+    let snapshot = g.snapshot_at(2);
+    let edges: Vec<_> = (&snapshot)
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index()))
+        .collect();
+    assert_eq!(edges, [(0, 1), (1, 2)]);
+    assert_eq!(connected_components(&snapshot), 3);
+
+    let snapshot = g.snapshot(TimeInterval::new(4, 20));
+    let costs = dijkstra(&snapshot, n(0), None, |e| e.weight().weight);
+    assert_eq!(costs.len(), 2);
+    assert_eq!(costs[&n(3)], 1);
+
+    *g.edge_weight_mut(EdgeIndex::new(3)).unwrap() = 7;
+    assert_eq!(g[EdgeIndex::new(3)].weight, 7);
+    assert_eq!(
+        g.interval(EdgeIndex::new(3)),
+        Some(TimeInterval::new(3, 10))
+    );
+}
+
+#[test]
+#[should_panic]
+fn reversed_interval() {
+    let mut g = TemporalGraph::<(), (), f64>::new();
+    let a = g.add_node(());
+    g.add_edge(
+        a,
+        a,
+        TimeInterval {
+            start: 2.0,
+            end: 1.0,
+        },
+        (),
+    );
+}
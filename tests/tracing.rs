@@ -0,0 +1,133 @@
+#![cfg(feature = "tracing")]
+
+use core::fmt;
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use petgraph::algo::{dijkstra, dsatur_coloring, ford_fulkerson, is_isomorphic, minimum_coloring};
+use petgraph::graph::{DiGraph, NodeIndex, UnGraph};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Dispatch, Event, Metadata, Subscriber};
+
+/// An event: its message and its integer fields.
+#[derive(Debug, Default)]
+struct Recorded {
+    message: String,
+    fields: Vec<(&'static str, u64)>,
+}
+
+impl Visit for Recorded {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.fields.push((field.name(), value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        }
+    }
+}
+
+impl Recorded {
+    fn field(&self, name: &str) -> u64 {
+        self.fields
+            .iter()
+            .find(|field| field.0 == name)
+            .unwrap_or_else(|| panic!("no field {} in {:?}", name, self))
+            .1
+    }
+}
+
+/// A subscriber that records the events and the names of the spans.
+#[derive(Default)]
+struct Recorder {
+    next_id: AtomicU64,
+    spans: Mutex<Vec<&'static str>>,
+    events: Mutex<Vec<Recorded>>,
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.spans.lock().unwrap().push(span.metadata().name());
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut recorded = Recorded::default();
+        event.record(&mut recorded);
+        self.events.lock().unwrap().push(recorded);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+/// Run `f` with a recorder, and return the names of the spans and the
+/// events.
+fn record(f: impl FnOnce()) -> (Vec<&'static str>, Vec<Recorded>) {
+    let dispatch = Dispatch::new(Recorder::default());
+    tracing::dispatcher::with_default(&dispatch, f);
+    let recorder = dispatch.downcast_ref::<Recorder>().unwrap();
+    let spans = core::mem::take(&mut *recorder.spans.lock().unwrap());
+    let events = core::mem::take(&mut *recorder.events.lock().unwrap());
+    (spans, events)
+}
+
+#[test]
+fn dijkstra_counters() {
+    let graph = DiGraph::<(), u32>::from_edges([(0, 1, 1), (1, 2, 1), (0, 2, 5), (2, 3, 1)]);
+    let (spans, events) = record(|| {
+        dijkstra(&graph, NodeIndex::new(0), None, |e| *e.weight());
+    });
+    assert_eq!(spans, ["dijkstra"]);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].message, "dijkstra finished");
+    assert_eq!(events[0].field("nodes_expanded"), 4);
+    // Node 2 is reached through the direct edge, then through node 1.
+    assert_eq!(events[0].field("edges_relaxed"), 4);
+}
+
+#[test]
+fn max_flow_counters() {
+    let graph = DiGraph::<(), u32>::from_edges([(0, 1, 2), (1, 3, 2), (0, 2, 3), (2, 3, 3)]);
+    let (spans, events) = record(|| {
+        let (flow, _) = ford_fulkerson(&graph, NodeIndex::new(0), NodeIndex::new(3));
+        assert_eq!(flow, 5);
+    });
+    assert_eq!(spans, ["ford_fulkerson"]);
+    assert_eq!(events[0].field("augmenting_paths"), 2);
+}
+
+#[test]
+fn coloring_and_isomorphism_events() {
+    let cycle = UnGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    let (spans, events) = record(|| {
+        dsatur_coloring(&cycle);
+        minimum_coloring(&cycle);
+        assert!(is_isomorphic(&cycle, &cycle));
+    });
+    assert_eq!(spans, ["dsatur_coloring", "minimum_coloring", "vf2"]);
+    let messages: Vec<_> = events.iter().map(|e| e.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        [
+            "dsatur_coloring finished",
+            "minimum_coloring bounds",
+            "minimum_coloring finished",
+            "vf2 finished",
+        ]
+    );
+    assert_eq!(events[0].field("colors"), 3);
+    assert_eq!(events[2].field("colors"), 3);
+    assert!(events[3].field("states_explored") >= 5);
+}
@@ -0,0 +1,155 @@
+#![cfg(feature = "stable_graph")]
+
+use petgraph::stable_graph::transaction::TransactionalGraph;
+use petgraph::stable_graph::{EdgeIndex, NodeIndex, StableGraph};
+use petgraph::visit::{EdgeIndexable, EdgeRef, NodeIndexable};
+use petgraph::{Directed, Direction, EdgeType, Undirected};
+
+type Fingerprint = (
+    Vec<Option<u32>>,
+    Vec<Option<(usize, usize, u32)>>,
+    Vec<(Vec<usize>, Vec<usize>)>,
+    (usize, usize),
+);
+
+/// Everything observable about a graph, including the order of the edges of
+/// each node and the indices of the next node and edge to be added.
+fn fingerprint<Ty: EdgeType>(graph: &StableGraph<u32, u32, Ty>) -> Fingerprint {
+    let nodes = (0..graph.node_bound())
+        .map(|i| graph.node_weight(NodeIndex::new(i)).copied())
+        .collect();
+    let edges = (0..graph.edge_bound())
+        .map(|i| {
+            let e = EdgeIndex::new(i);
+            let (a, b) = graph.edge_endpoints(e)?;
+            Some((a.index(), b.index(), graph[e]))
+        })
+        .collect();
+    let lists = graph
+        .node_indices()
+        .map(|a| {
+            let ids = |dir| {
+                graph
+                    .edges_directed(a, dir)
+                    .map(|e| e.id().index())
+                    .collect()
+            };
+            (ids(Direction::Outgoing), ids(Direction::Incoming))
+        })
+        .collect();
+    let mut next = graph.clone();
+    let a = next.add_node(0);
+    let e = next.add_edge(a, a, 0);
+    (nodes, edges, lists, (a.index(), e.index()))
+}
+
+/// A small deterministic pseudo-random number generator.
+struct Lcg(u64);
+
+impl Lcg {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.0 >> 33) as usize) % n
+    }
+}
+
+/// Apply `count` random changes to `graph`.
+fn random_changes<Ty: EdgeType>(
+    graph: &mut TransactionalGraph<u32, u32, Ty>,
+    rng: &mut Lcg,
+    count: usize,
+) {
+    for step in 0..count as u32 {
+        let nodes: Vec<_> = graph.node_indices().collect();
+        let edges: Vec<_> = graph.edge_indices().collect();
+        match rng.below(6) {
+            0 => {
+                graph.add_node(step);
+            }
+            1 | 2 if !nodes.is_empty() => {
+                let a = nodes[rng.below(nodes.len())];
+                let b = nodes[rng.below(nodes.len())];
+                graph.add_edge(a, b, step);
+            }
+            3 if !nodes.is_empty() => {
+                graph.remove_node(nodes[rng.below(nodes.len())]);
+            }
+            4 if !edges.is_empty() => {
+                graph.remove_edge(edges[rng.below(edges.len())]);
+            }
+            5 if !edges.is_empty() => {
+                graph.set_edge_weight(edges[rng.below(edges.len())], step);
+            }
+            _ => {
+                if let Some(&a) = nodes.first() {
+                    graph.set_node_weight(a, step);
+                }
+            }
+        }
+    }
+}
+
+fn check_rollbacks<Ty: EdgeType>() {
+    let mut rng = Lcg(7);
+    let mut graph = TransactionalGraph::<u32, u32, Ty>::default();
+    random_changes(&mut graph, &mut rng, 40);
+    graph.commit();
+    assert_eq!(graph.pending_changes(), 0);
+
+    for _ in 0..50 {
+        let before = fingerprint(graph.graph());
+        random_changes(&mut graph, &mut rng, 10);
+        let middle = fingerprint(graph.graph());
+        let savepoint = graph.savepoint();
+        random_changes(&mut graph, &mut rng, 10);
+        graph.rollback_to(savepoint);
+        assert_eq!(fingerprint(graph.graph()), middle);
+        graph.rollback();
+        assert_eq!(fingerprint(graph.graph()), before);
+
+        // Keep some of the changes.
+        random_changes(&mut graph, &mut rng, 5);
+        graph.commit();
+    }
+}
+
+#[test]
+fn rollback_restores_the_graph() {
+    check_rollbacks::<Directed>();
+    check_rollbacks::<Undirected>();
+}
+
+#[test]
+fn removed_weights() {
+    let graph = StableGraph::<&str, u32>::from_edges([(0, 1, 1), (1, 2, 2), (2, 0, 3)]);
+    let mut graph = TransactionalGraph::new(graph);
+    let a = NodeIndex::new(1);
+    assert_eq!(graph.set_node_weight(a, "b"), Some(&""));
+    assert_eq!(graph.remove_node(a), Some(&"b"));
+    assert_eq!(graph.remove_node(a), None);
+    assert_eq!(graph.remove_edge(EdgeIndex::new(0)), None);
+    assert_eq!(graph.remove_edge(EdgeIndex::new(2)), Some(&3));
+    assert_eq!(graph.node_count(), 2);
+    assert_eq!(graph.edge_count(), 0);
+    // Removing the node removed its two edges.
+    assert_eq!(graph.pending_changes(), 5);
+
+    graph.rollback();
+    assert_eq!(graph.edge_count(), 3);
+    assert_eq!(graph[a], "");
+    assert_eq!(graph[EdgeIndex::new(1)], 2);
+}
+
+#[test]
+#[should_panic(expected = "no longer valid")]
+fn committed_savepoint() {
+    let mut graph = TransactionalGraph::<(), ()>::default();
+    let savepoint = graph.savepoint();
+    graph.add_node(());
+    graph.commit();
+    graph.add_node(());
+    graph.rollback_to(savepoint);
+}
@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+
+use petgraph::algo::treewidth::{tree_decomposition, EliminationHeuristic, TreeDecomposition};
+use petgraph::algo::{connected_components, is_cyclic_undirected};
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::visit::{Dfs, NodeFiltered};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const HEURISTICS: [EliminationHeuristic; 2] = [
+    EliminationHeuristic::MinDegree,
+    EliminationHeuristic::MinFillIn,
+];
+
+fn assert_valid(graph: &UnGraph<(), ()>, decomposition: &TreeDecomposition<NodeIndex>) {
+    let tree = &decomposition.tree;
+    assert_eq!(tree.node_count(), graph.node_count());
+    if tree.node_count() > 0 {
+        assert_eq!(tree.edge_count(), tree.node_count() - 1);
+        assert_eq!(connected_components(tree), 1);
+        assert!(!is_cyclic_undirected(tree));
+    }
+    let width = tree.node_weights().map(|bag| bag.len() - 1).max();
+    assert_eq!(decomposition.width, width.unwrap_or(0));
+
+    let bags: Vec<HashSet<NodeIndex>> = tree
+        .node_weights()
+        .map(|bag| bag.iter().copied().collect())
+        .collect();
+    for e in graph.edge_indices() {
+        let (a, b) = graph.edge_endpoints(e).unwrap();
+        assert!(bags.iter().any(|bag| bag.contains(&a) && bag.contains(&b)));
+    }
+    // The bags that contain a node are connected.
+    for v in graph.node_indices() {
+        let containing =
+            NodeFiltered::from_fn(tree, |bag: NodeIndex| bags[bag.index()].contains(&v));
+        let count = bags.iter().filter(|bag| bag.contains(&v)).count();
+        assert!(count > 0);
+        let mut dfs = Dfs::new(&containing, tree_node_with(&bags, v));
+        let mut reached = 0;
+        while dfs.next(&containing).is_some() {
+            reached += 1;
+        }
+        assert_eq!(reached, count);
+    }
+
+    let mut order = decomposition.elimination_order.clone();
+    order.sort_unstable();
+    assert_eq!(order, graph.node_indices().collect::<Vec<_>>());
+}
+
+fn tree_node_with(bags: &[HashSet<NodeIndex>], v: NodeIndex) -> NodeIndex {
+    NodeIndex::new(bags.iter().position(|bag| bag.contains(&v)).unwrap())
+}
+
+#[test]
+fn tree_decomposition_known_widths() {
+    for heuristic in HEURISTICS {
+        let empty = UnGraph::<(), ()>::new_undirected();
+        let decomposition = tree_decomposition(&empty, heuristic);
+        assert_eq!(decomposition.width, 0);
+        assert_eq!(decomposition.tree.node_count(), 0);
+
+        // A forest of two stars and an isolated node.
+        let mut forest = UnGraph::<(), ()>::from_edges([(0, 1), (0, 2), (0, 3), (4, 5), (4, 6)]);
+        forest.add_node(());
+        let decomposition = tree_decomposition(&forest, heuristic);
+        assert_eq!(decomposition.width, 1);
+        assert_valid(&forest, &decomposition);
+
+        let complete =
+            UnGraph::<(), ()>::from_edges((0..6).flat_map(|a| (a + 1..6).map(move |b| (a, b))));
+        let decomposition = tree_decomposition(&complete, heuristic);
+        assert_eq!(decomposition.width, 5);
+        assert_valid(&complete, &decomposition);
+
+        // A grid of 5 by 5 nodes has treewidth 5.
+        let mut grid = UnGraph::<(), ()>::new_undirected();
+        let nodes: Vec<_> = (0..25).map(|_| grid.add_node(())).collect();
+        for r in 0..5 {
+            for c in 0..5 {
+                if c + 1 < 5 {
+                    grid.add_edge(nodes[r * 5 + c], nodes[r * 5 + c + 1], ());
+                }
+                if r + 1 < 5 {
+                    grid.add_edge(nodes[r * 5 + c], nodes[(r + 1) * 5 + c], ());
+                }
+            }
+        }
+        let decomposition = tree_decomposition(&grid, heuristic);
+        assert!(decomposition.width >= 5 && decomposition.width <= 7);
+        assert_valid(&grid, &decomposition);
+    }
+
+    // A cycle with chords that form a fan is series-parallel.
+    let mut fan = UnGraph::<(), ()>::from_edges((0..10).map(|i| (i, (i + 1) % 10)));
+    fan.extend_with_edges((2..9).map(|i| (0, i)));
+    let decomposition = tree_decomposition(&fan, EliminationHeuristic::MinFillIn);
+    assert_eq!(decomposition.width, 2);
+    assert_valid(&fan, &decomposition);
+}
+
+#[test]
+fn tree_decomposition_random_graphs() {
+    let mut rng = StdRng::seed_from_u64(28);
+    for _ in 0..200 {
+        let n = rng.gen_range(1..20);
+        let mut graph = UnGraph::<(), ()>::new_undirected();
+        for _ in 0..n {
+            graph.add_node(());
+        }
+        for _ in 0..rng.gen_range(0..3 * n) {
+            let a = NodeIndex::new(rng.gen_range(0..n));
+            let b = NodeIndex::new(rng.gen_range(0..n));
+            graph.add_edge(a, b, ());
+        }
+        for heuristic in HEURISTICS {
+            assert_valid(&graph, &tree_decomposition(&graph, heuristic));
+        }
+    }
+}
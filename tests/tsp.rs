@@ -0,0 +1,69 @@
+use petgraph::algo::tsp::{christofides, nearest_neighbor_tour, two_opt};
+use petgraph::graph::NodeIndex;
+use petgraph::{Graph, Undirected};
+
+/// Complete graph of points on a line, where the cost is their distance.
+fn line_points(positions: &[i32]) -> Graph<i32, i32, Undirected> {
+    let mut graph = Graph::new_undirected();
+    let nodes: Vec<_> = positions.iter().map(|&p| graph.add_node(p)).collect();
+    for (i, &a) in nodes.iter().enumerate() {
+        for &b in &nodes[i + 1..] {
+            graph.add_edge(a, b, (graph[a] - graph[b]).abs());
+        }
+    }
+    graph
+}
+
+#[test]
+fn tsp_line_points() {
+    // The optimal tour on a line goes to one end and back: twice the span.
+    let graph = line_points(&[0, 7, 3, 9, 1, 4]);
+    let (cost, tour) = christofides(&graph, |e| *e.weight()).unwrap();
+    assert_eq!(tour.len(), 6);
+    assert!(cost <= 18 * 3 / 2);
+
+    let start = tour[0];
+    let (nn_cost, nn_tour) = nearest_neighbor_tour(&graph, start, |e| *e.weight()).unwrap();
+    assert_eq!(nn_tour[0], start);
+    let (opt_cost, opt_tour) = two_opt(&graph, &nn_tour, |e| *e.weight()).unwrap();
+    assert_eq!(opt_tour[0], start);
+    assert!(opt_cost <= nn_cost);
+    assert_eq!(opt_cost, 18);
+}
+
+#[test]
+fn tsp_incomplete_graph() {
+    let mut graph = line_points(&[0, 1, 2, 3]);
+    let e = graph
+        .find_edge(NodeIndex::new(0), NodeIndex::new(2))
+        .unwrap();
+    graph.remove_edge(e);
+    assert_eq!(christofides(&graph, |e| *e.weight()), None);
+    assert!(nearest_neighbor_tour(&graph, NodeIndex::new(0), |e| *e.weight()).is_some());
+}
+
+#[test]
+fn two_opt_rejects_invalid_tour() {
+    let graph = line_points(&[0, 1, 2, 3]);
+    let n = |i| NodeIndex::new(i);
+    assert_eq!(two_opt(&graph, &[n(0), n(1), n(2)], |e| *e.weight()), None);
+    assert_eq!(
+        two_opt(&graph, &[n(0), n(1), n(1), n(2)], |e| *e.weight()),
+        None
+    );
+}
+
+#[test]
+fn christofides_many_odd_nodes() {
+    // A star-shaped spanning tree has many odd-degree nodes, which exercises
+    // the greedy matching fallback.
+    let positions: Vec<i32> = (0..30).map(|i| (i * 37) % 101).collect();
+    let graph = line_points(&positions);
+    let (cost, tour) = christofides(&graph, |e| *e.weight()).unwrap();
+    let mut sorted = tour.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), 30);
+    let span = positions.iter().max().unwrap() - positions.iter().min().unwrap();
+    assert!(cost >= 2 * span);
+}
@@ -1,8 +1,9 @@
 extern crate petgraph;
 extern crate rand;
 
-use petgraph::unionfind::UnionFind;
-use rand::{thread_rng, ChaChaRng, Rng, SeedableRng};
+use petgraph::unionfind::{RollbackUnionFind, UnionFind, WeightedUnionFind};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::collections::HashSet;
 
 #[test]
@@ -123,11 +124,11 @@ fn uf_test_with_checked_equiv() {
 #[test]
 fn uf_rand() {
     let n = 1 << 14;
-    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut rng = StdRng::seed_from_u64(0);
     let mut u = UnionFind::new(n);
     for _ in 0..100 {
-        let a = rng.gen_range(0, n);
-        let b = rng.gen_range(0, n);
+        let a = rng.gen_range(0..n);
+        let b = rng.gen_range(0..n);
         let ar = u.find(a);
         let br = u.find(b);
         assert_eq!(ar != br, u.union(a, b));
@@ -137,7 +138,7 @@ fn uf_rand() {
 #[test]
 fn uf_u8() {
     let n = 256;
-    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut rng = StdRng::seed_from_u64(1);
     let mut u = UnionFind::<u8>::new(n);
     for _ in 0..(n * 8) {
         let a = rng.gen();
@@ -151,7 +152,7 @@ fn uf_u8() {
 #[test]
 fn uf_u8_checked() {
     let n = 256;
-    let mut rng = ChaChaRng::from_rng(thread_rng()).unwrap();
+    let mut rng = StdRng::seed_from_u64(2);
     let mut u = UnionFind::<u8>::new(n);
     for _ in 0..(n * 8) {
         let a = rng.gen();
@@ -233,3 +234,65 @@ fn uf_test_out_of_bounds() {
     assert_eq!(u.try_equiv(30, 50), Err(30));
     assert_eq!(u.try_equiv(50, 30), Err(50));
 }
+
+#[test]
+fn uf_rollback_rand() {
+    let n = 64;
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut u = RollbackUnionFind::<u32>::new(n);
+    // The labelings at each snapshot, to compare with after rolling back.
+    let mut snapshots = Vec::new();
+    for _ in 0..1000 {
+        if rng.gen_range(0..4) == 0 && !snapshots.is_empty() {
+            let (snapshot, labeling) = snapshots.swap_remove(rng.gen_range(0..snapshots.len()));
+            u.rollback(snapshot);
+            snapshots.retain(|&(s, _)| s <= snapshot);
+            let current = (0..n as u32).map(|i| u.find(i)).collect::<Vec<_>>();
+            assert_eq!(current, labeling);
+        } else {
+            if rng.gen_range(0..8) == 0 {
+                let labeling = (0..n as u32).map(|i| u.find(i)).collect::<Vec<_>>();
+                snapshots.push((u.snapshot(), labeling));
+            }
+            let a = rng.gen_range(0..n as u32);
+            let b = rng.gen_range(0..n as u32);
+            let ar = u.find(a);
+            let br = u.find(b);
+            assert_eq!(ar != br, u.union(a, b));
+            assert!(u.equiv(a, b));
+        }
+    }
+    u.rollback(0);
+    assert!((0..n as u32).all(|i| u.find(i) == i));
+    assert!(!u.undo());
+}
+
+#[test]
+fn uf_weighted_rand() {
+    let n = 200;
+    let mut rng = StdRng::seed_from_u64(4);
+    let potential = (0..n)
+        .map(|_| rng.gen_range(-1000..1000))
+        .collect::<Vec<i64>>();
+    let mut u = WeightedUnionFind::<u32, i64>::new(n);
+    let mut sets = UnionFind::<u32>::new(n);
+    for step in 0..2000 {
+        let a = rng.gen_range(0..n as u32);
+        let b = rng.gen_range(0..n as u32);
+        let w = potential[b as usize] - potential[a as usize];
+        if rng.gen_range(0..4) == 0 && sets.equiv(a, b) {
+            assert_eq!(u.union(a, b, w + 1), Err(w));
+        } else {
+            assert_eq!(u.union(a, b, w), Ok(sets.union(a, b)));
+        }
+        if step % 50 == 0 {
+            for x in 0..n as u32 {
+                let y = rng.gen_range(0..n as u32);
+                let expected = Some(potential[y as usize] - potential[x as usize])
+                    .filter(|_| sets.equiv(x, y));
+                assert_eq!(u.difference(x, y), expected);
+                assert_eq!(u.find_mut(x).0, u.find(x).0);
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use petgraph::algo::{approximate_unit_maximum_flow, ford_fulkerson, ApproximateFlow};
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn random_network(rng: &mut StdRng, n: usize, m: usize) -> DiGraph<(), u32> {
+    let mut graph = DiGraph::new();
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for _ in 0..m {
+        let a = NodeIndex::new(rng.gen_range(0..n));
+        let b = NodeIndex::new(rng.gen_range(0..n));
+        graph.add_edge(a, b, 1);
+    }
+    graph
+}
+
+/// Check that the edge flows are a flow of the reported value.
+fn assert_valid_flow(
+    graph: &DiGraph<(), u32>,
+    source: NodeIndex,
+    destination: NodeIndex,
+    flow: &ApproximateFlow,
+) {
+    let mut balance = vec![0i64; graph.node_count()];
+    for edge in graph.edge_references() {
+        if flow.edge_flows[edge.id().index()] {
+            assert_ne!(edge.source(), edge.target());
+            balance[edge.source().index()] -= 1;
+            balance[edge.target().index()] += 1;
+        }
+    }
+    for v in graph.node_indices() {
+        let expected = if source == destination {
+            0
+        } else if v == source {
+            -(flow.value as i64)
+        } else if v == destination {
+            flow.value as i64
+        } else {
+            0
+        };
+        assert_eq!(balance[v.index()], expected);
+    }
+    assert!(flow.value <= flow.upper_bound);
+}
+
+#[test]
+fn unit_flow_exact() {
+    let mut rng = StdRng::seed_from_u64(32);
+    for _ in 0..100 {
+        let n = rng.gen_range(2..30);
+        let m = rng.gen_range(0..5 * n);
+        let graph = random_network(&mut rng, n, m);
+        let source = NodeIndex::new(rng.gen_range(0..n));
+        let destination = NodeIndex::new(rng.gen_range(0..n));
+        let flow = approximate_unit_maximum_flow(&graph, source, destination, 0.0, None);
+        assert_valid_flow(&graph, source, destination, &flow);
+        assert!(flow.is_exact());
+        if source != destination {
+            let (max_flow, _) = ford_fulkerson(&graph, source, destination);
+            assert_eq!(flow.value, max_flow as usize);
+        }
+    }
+}
+
+#[test]
+fn unit_flow_early_exit() {
+    let mut rng = StdRng::seed_from_u64(33);
+    for _ in 0..20 {
+        let n = 2000;
+        let graph = random_network(&mut rng, n, 20 * n);
+        let (source, destination) = (NodeIndex::new(0), NodeIndex::new(1));
+        let (max_flow, _) = ford_fulkerson(&graph, source, destination);
+        let max_flow = max_flow as usize;
+
+        let flow = approximate_unit_maximum_flow(&graph, source, destination, 0.2, None);
+        assert_valid_flow(&graph, source, destination, &flow);
+        assert!(flow.upper_bound >= max_flow);
+        assert!(flow.value as f64 >= 0.8 * max_flow as f64);
+
+        let flow = approximate_unit_maximum_flow(&graph, source, destination, 0.0, Some(1));
+        assert_valid_flow(&graph, source, destination, &flow);
+        assert!(flow.phases <= 1);
+        assert!(flow.value <= max_flow && max_flow <= flow.upper_bound);
+    }
+}